@@ -154,6 +154,20 @@ fn configure_validation(builder: Builder) -> Builder {
             ("VectorParams.size", "range(min = 1, max = 65536)"),
             ("VectorParams.hnsw_config", ""),
             ("VectorParams.quantization_config", ""),
+            ("VectorParams.multivector_config", ""),
+            ("VectorParams.matryoshka_dim", "range(min = 1)"),
+            ("VectorParams.vamana_config", ""),
+            ("VectorParams.ivf_config", ""),
+            ("VamanaConfig.max_degree", "range(min = 4, max = 512)"),
+            ("VamanaConfig.build_list_size", "range(min = 4)"),
+            ("VamanaConfig.beam_width", "range(min = 1, max = 64)"),
+            ("IvfConfig.num_lists", "range(min = 1, max = 65536)"),
+            ("IvfConfig.nprobe", "range(min = 1)"),
+            ("MultiVectorConfig.fde", ""),
+            ("FdeConfig.ksim", "range(min = 1, max = 8)"),
+            ("FdeConfig.dim_proj", "range(min = 1, max = 1024)"),
+            ("FdeConfig.repetitions", "range(min = 1, max = 64)"),
+            ("FdeConfig.candidates_factor", "range(min = 1, max = 100)"),
             ("VectorParamsMap.map", ""),
             ("VectorParamsDiff.hnsw_config", ""),
             ("VectorParamsDiff.quantization_config", ""),
@@ -216,6 +230,8 @@ fn configure_validation(builder: Builder) -> Builder {
             ("DeletePointVectors.points_selector", ""),
             ("PointVectors.vectors", ""),
             ("GetPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
+            ("GetPoints.with_join", ""),
+            ("WithJoin.key", "length(min = 1)"),
             ("SetPayloadPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("SetPayloadPoints.points_selector", ""),
             ("DeletePayloadPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
@@ -251,10 +267,12 @@ fn configure_validation(builder: Builder) -> Builder {
             ("SearchPointGroups.timeout", "range(min = 1)"),
             ("SearchParams.quantization", ""),
             ("SearchParams.acorn", ""),
+            ("SearchParams.ivf_nprobe", "range(min = 1)"),
             ("QuantizationSearchParams.oversampling", "range(min = 1.0)"),
             ("ScrollPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("ScrollPoints.filter", ""),
             ("ScrollPoints.limit", "range(min = 1)"),
+            ("ScrollPoints.with_join", ""),
             ("RecommendPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("RecommendPoints.filter", ""),
             ("RecommendPoints.params", ""),
@@ -313,6 +331,9 @@ fn configure_validation(builder: Builder) -> Builder {
             ("FeedbackStrategy.variant", ""),
             ("FeedbackItem.example", ""),
             ("NaiveFeedbackStrategy.b", "range(min = 0.0)"),
+            ("RocchioFeedbackStrategy.beta", "range(min = 0.0)"),
+            ("RocchioFeedbackStrategy.gamma", "range(min = 0.0)"),
+            ("ReweightFeedbackStrategy.regularization", "range(min = 0.0)"),
             ("Formula.expression", ""),
             ("Expression.variant", ""),
             ("MultExpression.mult", ""),
@@ -328,6 +349,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("Mmr.diversity", "range(min = 0.0, max = 1.0)"),
             ("Mmr.candidates_limit", "range(max = 16_384)"),
             ("Rrf.k", "range(min = 1)"),
+            ("StratifiedSample.per_value", "range(min = 1)"),
             ("Query.variant", ""),
             ("PrefetchQuery.prefetch", ""),
             ("PrefetchQuery.query", ""),
@@ -341,6 +363,7 @@ fn configure_validation(builder: Builder) -> Builder {
             ("QueryPoints.filter", ""),
             ("QueryPoints.params", ""),
             ("QueryPoints.timeout", "range(min = 1)"),
+            ("QueryPoints.with_join", ""),
             ("QueryBatchPoints.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("QueryBatchPoints.query_points", ""),
             ("QueryBatchPoints.timeout", "range(min = 1)"),
@@ -353,6 +376,15 @@ fn configure_validation(builder: Builder) -> Builder {
             ("QueryPointGroups.group_size", "range(min = 1)"),
             ("QueryPointGroups.limit", "range(min = 1)"),
             ("QueryPointGroups.timeout", "range(min = 1)"),
+            ("FederatedQueryPoints.collections", "length(min = 1, max = 16)"),
+            ("FederatedQueryPoints.prefetch", ""),
+            ("FederatedQueryPoints.query", ""),
+            ("FederatedQueryPoints.filter", ""),
+            ("FederatedQueryPoints.params", ""),
+            ("FederatedQueryPoints.limit", "range(min = 1)"),
+            ("FederatedQueryPoints.timeout", "range(min = 1)"),
+            ("FederatedQueryPoints.merge", ""),
+            ("FederatedMerge.variant", ""),
             ("FacetCounts.collection_name", "length(min = 1, max = 255), custom(function = \"common::validation::validate_collection_name_legacy\")"),
             ("FacetCounts.key", "length(min = 1)"),
             ("FacetCounts.filter", ""),
@@ -9,10 +9,13 @@ use common::counter::hardware_data::HardwareData;
 use common::types::ScoreType;
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
-use segment::common::operation_error::OperationError;
+use segment::common::operation_error::{
+    OperationError, PointVersionConflict as PointVersionConflictInternal,
+};
+use segment::data_types::explain::{SearchStrategy, SegmentExplanation};
 use segment::data_types::index::{
-    BoolIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType, IntegerIndexType,
-    KeywordIndexType, SnowballLanguage, TextIndexType, UuidIndexType,
+    BoolIndexType, CompositeIndexType, DatetimeIndexType, FloatIndexType, GeoIndexType,
+    IntegerIndexType, KeywordIndexType, SnowballLanguage, TextIndexType, UuidIndexType,
 };
 use segment::data_types::modifier::Modifier;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedMultiDenseVector, VectorInternal};
@@ -29,15 +32,16 @@ use tonic::Status;
 use uuid::Uuid;
 
 use super::qdrant::{
-    BinaryQuantization, BoolIndexParams, CompressionRatio, DatetimeIndexParams, DatetimeRange,
-    Direction, FacetHit, FacetHitInternal, FacetValue, FacetValueInternal, FieldType,
-    FloatIndexParams, GeoIndexParams, GeoLineString, GroupId, HardwareUsage, HasVectorCondition,
-    KeywordIndexParams, LookupLocation, MaxOptimizationThreads, MultiVectorComparator,
-    MultiVectorConfig, OrderBy, OrderValue, Range, RawVector, RecommendStrategy, RetrievedPoint,
+    BinaryQuantization, BoolIndexParams, CompositeIndexField, CompositeIndexParams,
+    CompressionRatio, DatetimeIndexParams, DatetimeRange, Direction, FacetHit, FacetHitInternal,
+    FacetValue, FacetValueInternal, FdeConfig, FieldType, FloatIndexParams, GeoIndexParams,
+    GeoLineString, GroupId, HardwareUsage, HasVectorCondition, IvfConfig, KeywordIndexParams,
+    KeywordRange, LookupLocation, MaxOptimizationThreads, MultiVectorComparator, MultiVectorConfig,
+    OrderBy, OrderByCursor, OrderValue, Range, RawVector, RecommendStrategy, RetrievedPoint,
     SearchMatrixPair, SearchPointGroups, SearchPoints, ShardKeySelector, StartFrom,
     StrictModeMultivector, StrictModeMultivectorConfig, StrictModeSparse, StrictModeSparseConfig,
-    TurboQuantBitSize, TurboQuantization, UuidIndexParams, VectorsOutput, WithLookup, raw_query,
-    start_from,
+    ThenBy, TurboQuantBitSize, TurboQuantization, UuidIndexParams, VamanaConfig, VectorsOutput,
+    WithLookup, raw_query, start_from,
 };
 use super::stemming_algorithm::StemmingParams;
 use super::{Expression, Formula, RecoQuery, SnowballParams, StemmingAlgorithm, Usage};
@@ -49,11 +53,12 @@ use crate::grpc::qdrant::point_id::PointIdOptions;
 use crate::grpc::qdrant::with_payload_selector::SelectorOptions;
 use crate::grpc::qdrant::{
     AcornSearchParams, CollectionDescription, CollectionOperationResponse, Condition, Distance,
-    FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius, HasIdCondition,
-    HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition, IsNullCondition,
-    ListCollectionsResponse, ListShardKeysResponse, Match, MinShould, NamedVectors,
-    NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector, PayloadIndexParams,
-    PayloadSchemaInfo, PayloadSchemaType, PointId, PointStruct, PointsOperationResponse,
+    ExpectedPointVersion, FieldCondition, Filter, GeoBoundingBox, GeoPoint, GeoPolygon, GeoRadius,
+    HasIdCondition, HealthCheckReply, HnswConfigDiff, IntegerIndexParams, IsEmptyCondition,
+    IsNullCondition, ListCollectionsResponse, ListShardKeysResponse, Match, MinShould,
+    NamedVectors, NestedCondition, PayloadExcludeSelector, PayloadIncludeSelector,
+    PayloadIndexParams, PayloadSchemaInfo, PayloadSchemaType, PointId, PointStruct,
+    PointVersionConflict, PointVersionConflicts, PointsOperationResponse,
     PointsOperationResponseInternal, ProductQuantization, QuantizationConfig,
     QuantizationSearchParams, QuantizationType, RepeatedIntegers, RepeatedStrings,
     ScalarQuantization, ScoredPoint, SearchParams, ShardKey, ShardKeyDescription, StopwordsSet,
@@ -65,7 +70,7 @@ use crate::grpc::{
     self, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding, DecayParamsExpression,
     DivExpression, GeoDistance, MultExpression, PowExpression, SumExpression,
 };
-use crate::rest::models::{CollectionsResponse, ShardKeysResponse, VersionInfo};
+use crate::rest::models::{self, CollectionsResponse, ShardKeysResponse, VersionInfo};
 use crate::rest::schema as rest;
 
 pub fn convert_shard_key_to_grpc(value: segment::types::ShardKey) -> ShardKey {
@@ -338,6 +343,35 @@ impl From<segment::data_types::index::UuidIndexParams> for PayloadIndexParams {
     }
 }
 
+impl From<segment::data_types::index::CompositeIndexParams> for PayloadIndexParams {
+    fn from(params: segment::data_types::index::CompositeIndexParams) -> Self {
+        let segment::data_types::index::CompositeIndexParams {
+            r#type: _,
+            field_type,
+            fields,
+            on_disk,
+            enable_hnsw,
+        } = params;
+        let schema_type = |field_type: segment::data_types::index::CompositeFieldType| {
+            PayloadSchemaType::from(segment::types::PayloadSchemaType::from(field_type)) as i32
+        };
+        PayloadIndexParams {
+            index_params: Some(IndexParams::CompositeIndexParams(CompositeIndexParams {
+                field_type: schema_type(field_type),
+                fields: fields
+                    .into_iter()
+                    .map(|field| CompositeIndexField {
+                        key: field.key.to_string(),
+                        r#type: schema_type(field.r#type),
+                    })
+                    .collect(),
+                on_disk,
+                enable_hnsw,
+            })),
+        }
+    }
+}
+
 impl From<segment::data_types::index::DatetimeIndexParams> for PayloadIndexParams {
     fn from(params: segment::data_types::index::DatetimeIndexParams) -> Self {
         let segment::data_types::index::DatetimeIndexParams {
@@ -476,6 +510,7 @@ impl From<segment::types::PayloadSchemaParams> for PayloadIndexParams {
             segment::types::PayloadSchemaParams::Bool(p) => p.into(),
             segment::types::PayloadSchemaParams::Datetime(p) => p.into(),
             segment::types::PayloadSchemaParams::Uuid(p) => p.into(),
+            segment::types::PayloadSchemaParams::Composite(p) => p.into(),
         }
     }
 }
@@ -694,6 +729,56 @@ impl TryFrom<UuidIndexParams> for segment::data_types::index::UuidIndexParams {
     }
 }
 
+fn composite_field_type_from_grpc(
+    field_type: i32,
+) -> Result<segment::data_types::index::CompositeFieldType, Status> {
+    use segment::data_types::index::CompositeFieldType;
+    match PayloadSchemaType::try_from(field_type) {
+        Ok(PayloadSchemaType::Keyword) => Ok(CompositeFieldType::Keyword),
+        Ok(PayloadSchemaType::Integer) => Ok(CompositeFieldType::Integer),
+        Ok(PayloadSchemaType::Float) => Ok(CompositeFieldType::Float),
+        Ok(PayloadSchemaType::Datetime) => Ok(CompositeFieldType::Datetime),
+        Ok(PayloadSchemaType::Uuid) => Ok(CompositeFieldType::Uuid),
+        Ok(
+            PayloadSchemaType::UnknownType
+            | PayloadSchemaType::Geo
+            | PayloadSchemaType::Text
+            | PayloadSchemaType::Bool,
+        )
+        | Err(_) => Err(Status::invalid_argument(format!(
+            "unsupported composite index field type: {field_type}"
+        ))),
+    }
+}
+
+impl TryFrom<CompositeIndexParams> for segment::data_types::index::CompositeIndexParams {
+    type Error = Status;
+    fn try_from(params: CompositeIndexParams) -> Result<Self, Self::Error> {
+        let CompositeIndexParams {
+            field_type,
+            fields,
+            on_disk,
+            enable_hnsw,
+        } = params;
+        let fields = fields
+            .into_iter()
+            .map(|CompositeIndexField { key, r#type }| {
+                Ok(segment::data_types::index::CompositeIndexField {
+                    key: json::json_path_from_proto(&key)?,
+                    r#type: composite_field_type_from_grpc(r#type)?,
+                })
+            })
+            .collect::<Result<_, Status>>()?;
+        Ok(segment::data_types::index::CompositeIndexParams {
+            r#type: CompositeIndexType::Composite,
+            field_type: composite_field_type_from_grpc(field_type)?,
+            fields,
+            on_disk,
+            enable_hnsw,
+        })
+    }
+}
+
 impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
     type Error = Status;
 
@@ -723,6 +808,9 @@ impl TryFrom<IndexParams> for segment::types::PayloadSchemaParams {
             IndexParams::UuidIndexParams(p) => {
                 segment::types::PayloadSchemaParams::Uuid(p.try_into()?)
             }
+            IndexParams::CompositeIndexParams(p) => {
+                segment::types::PayloadSchemaParams::Composite(p.try_into()?)
+            }
         })
     }
 }
@@ -906,22 +994,43 @@ impl From<segment::types::AcornSearchParams> for AcornSearchParams {
     }
 }
 
-impl From<SearchParams> for segment::types::SearchParams {
-    fn from(params: SearchParams) -> Self {
+impl TryFrom<SearchParams> for segment::types::SearchParams {
+    type Error = Status;
+
+    fn try_from(params: SearchParams) -> Result<Self, Self::Error> {
         let SearchParams {
             hnsw_ef,
             exact,
             quantization,
             indexed_only,
             acorn,
+            multivector_comparator,
+            ivf_nprobe,
+            radius,
+            matryoshka,
         } = params;
-        Self {
+        let multivector_comparator = multivector_comparator
+            .map(|comparator| {
+                MultiVectorComparator::try_from(comparator).map_err(|_| {
+                    Status::invalid_argument(format!(
+                        "Unknown multivector comparator: {comparator}"
+                    ))
+                })
+            })
+            .transpose()?;
+        Ok(Self {
             hnsw_ef: hnsw_ef.map(|x| x as usize),
             exact: exact.unwrap_or(false),
             quantization: quantization.map(QuantizationSearchParams::into),
             indexed_only: indexed_only.unwrap_or(false),
             acorn: acorn.map(segment::types::AcornSearchParams::from),
-        }
+            multivector_comparator: multivector_comparator
+                .map(segment::types::MultiVectorComparator::from),
+            ivf_nprobe: ivf_nprobe.map(|x| x as usize),
+            radius: radius.map(OrderedFloat),
+            matryoshka: matryoshka.unwrap_or(false),
+            score_start_after: None,
+        })
     }
 }
 
@@ -933,6 +1042,11 @@ impl From<segment::types::SearchParams> for SearchParams {
             quantization,
             indexed_only,
             acorn,
+            multivector_comparator,
+            ivf_nprobe,
+            radius,
+            matryoshka,
+            score_start_after: _,
         } = params;
         Self {
             hnsw_ef: hnsw_ef.map(|x| x as u64),
@@ -940,6 +1054,11 @@ impl From<segment::types::SearchParams> for SearchParams {
             quantization: quantization.map(Into::into),
             indexed_only: Some(indexed_only),
             acorn: acorn.map(AcornSearchParams::from),
+            multivector_comparator: multivector_comparator
+                .map(|comparator| MultiVectorComparator::from(comparator) as i32),
+            ivf_nprobe: ivf_nprobe.map(|x| x as u64),
+            radius: radius.map(OrderedFloat::into_inner),
+            matryoshka: Some(matryoshka),
         }
     }
 }
@@ -996,6 +1115,8 @@ impl TryFrom<rest::Record> for RetrievedPoint {
             vector,
             shard_key,
             order_value,
+            then_by_values,
+            version,
         } = record;
         let retrieved_point = Self {
             id: Some(PointId::from(id)),
@@ -1003,6 +1124,8 @@ impl TryFrom<rest::Record> for RetrievedPoint {
             vectors: vector.map(VectorsOutput::try_from).transpose()?,
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(From::from),
+            version,
+            then_by_values: then_by_values_to_proto(then_by_values),
         };
         Ok(retrieved_point)
     }
@@ -1017,6 +1140,7 @@ impl From<segment::data_types::order_by::OrderValue> for OrderValue {
         let variant = match value {
             segment_::OrderValue::Float(value) => Variant::Float(value),
             segment_::OrderValue::Int(value) => Variant::Int(value),
+            segment_::OrderValue::Keyword(value) => Variant::Keyword(value),
         };
 
         Self {
@@ -1042,12 +1166,38 @@ impl TryFrom<OrderValue> for segment::data_types::order_by::OrderValue {
         let value = match variant {
             Variant::Float(value) => segment_::OrderValue::Float(value),
             Variant::Int(value) => segment_::OrderValue::Int(value),
+            Variant::Keyword(value) => segment_::OrderValue::Keyword(value),
         };
 
         Ok(value)
     }
 }
 
+/// Convert values of `then_by` keys, a missing value is represented by an empty `OrderValue`
+pub fn then_by_values_to_proto(
+    values: Vec<Option<segment::data_types::order_by::OrderValue>>,
+) -> Vec<OrderValue> {
+    values
+        .into_iter()
+        .map(|value| value.map(OrderValue::from).unwrap_or_default())
+        .collect()
+}
+
+pub fn then_by_values_from_proto(
+    values: Vec<OrderValue>,
+) -> Result<Vec<Option<segment::data_types::order_by::OrderValue>>, Status> {
+    values
+        .into_iter()
+        .map(|value| {
+            value
+                .variant
+                .is_some()
+                .then(|| value.try_into())
+                .transpose()
+        })
+        .collect()
+}
+
 impl From<segment::types::ScoredPoint> for ScoredPoint {
     fn from(point: segment::types::ScoredPoint) -> Self {
         let segment::types::ScoredPoint {
@@ -1058,6 +1208,8 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            then_by_values,
+            stratum,
         } = point;
         Self {
             id: Some(PointId::from(id)),
@@ -1067,6 +1219,8 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vectors: vector.map(VectorsOutput::from),
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(OrderValue::from),
+            then_by_values: then_by_values_to_proto(then_by_values),
+            stratum: stratum.map(FacetValue::from),
         }
     }
 }
@@ -1082,6 +1236,8 @@ impl TryFrom<rest::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            then_by_values,
+            stratum,
         } = point;
         Ok(Self {
             id: Some(PointId::from(id)),
@@ -1091,6 +1247,8 @@ impl TryFrom<rest::ScoredPoint> for ScoredPoint {
             vectors: vector.map(VectorsOutput::try_from).transpose()?,
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(OrderValue::from),
+            then_by_values: then_by_values_to_proto(then_by_values),
+            stratum: stratum.map(FacetValue::from),
         })
     }
 }
@@ -1107,6 +1265,12 @@ impl From<segment::data_types::groups::GroupId> for GroupId {
             segment::data_types::groups::GroupId::NumberI64(n) => Self {
                 kind: Some(crate::grpc::qdrant::group_id::Kind::IntegerValue(n)),
             },
+            // Grouping by several keys is not available in gRPC, keep their values as a string
+            tuple @ segment::data_types::groups::GroupId::Tuple(_) => Self {
+                kind: Some(crate::grpc::qdrant::group_id::Kind::StringValue(
+                    serde_json::Value::from(tuple).to_string(),
+                )),
+            },
         }
     }
 }
@@ -1176,6 +1340,91 @@ impl TryFrom<PointId> for segment::types::PointIdType {
     }
 }
 
+impl From<segment::types::ExpectedPointVersion> for ExpectedPointVersion {
+    fn from(value: segment::types::ExpectedPointVersion) -> Self {
+        let segment::types::ExpectedPointVersion { id, version } = value;
+        Self {
+            id: Some(PointId::from(id)),
+            version,
+        }
+    }
+}
+
+impl TryFrom<ExpectedPointVersion> for segment::types::ExpectedPointVersion {
+    type Error = Status;
+
+    fn try_from(value: ExpectedPointVersion) -> Result<Self, Self::Error> {
+        let ExpectedPointVersion { id, version } = value;
+        let id = id
+            .ok_or_else(|| Status::invalid_argument("Expected point version without id"))?
+            .try_into()?;
+        Ok(Self { id, version })
+    }
+}
+
+impl From<PointVersionConflictInternal> for PointVersionConflict {
+    fn from(value: PointVersionConflictInternal) -> Self {
+        let PointVersionConflictInternal {
+            point_id,
+            expected,
+            actual,
+        } = value;
+        Self {
+            id: Some(PointId::from(point_id)),
+            expected,
+            actual,
+        }
+    }
+}
+
+impl TryFrom<PointVersionConflict> for PointVersionConflictInternal {
+    type Error = Status;
+
+    fn try_from(value: PointVersionConflict) -> Result<Self, Self::Error> {
+        let PointVersionConflict {
+            id,
+            expected,
+            actual,
+        } = value;
+        let point_id = id
+            .ok_or_else(|| Status::invalid_argument("Point version conflict without id"))?
+            .try_into()?;
+        Ok(Self {
+            point_id,
+            expected,
+            actual,
+        })
+    }
+}
+
+/// Encode version conflicts into the details of the `ABORTED` status
+pub fn version_conflicts_to_status(
+    message: impl Into<String>,
+    conflicts: Vec<PointVersionConflictInternal>,
+) -> Status {
+    let details = PointVersionConflicts {
+        conflicts: conflicts.into_iter().map(From::from).collect(),
+    };
+    Status::with_details(
+        tonic::Code::Aborted,
+        message,
+        prost::Message::encode_to_vec(&details).into(),
+    )
+}
+
+/// Decode version conflicts from the details of the `ABORTED` status
+pub fn version_conflicts_from_status(
+    status: &Status,
+) -> Result<Vec<PointVersionConflictInternal>, Status> {
+    let details: PointVersionConflicts = prost::Message::decode(status.details())
+        .map_err(|err| Status::internal(format!("Invalid version conflict details: {err}")))?;
+    details
+        .conflicts
+        .into_iter()
+        .map(TryFrom::try_from)
+        .collect()
+}
+
 impl From<segment::types::ScalarQuantization> for ScalarQuantization {
     fn from(value: segment::types::ScalarQuantization) -> Self {
         let segment::types::ScalarQuantization { scalar } = value;
@@ -1183,6 +1432,7 @@ impl From<segment::types::ScalarQuantization> for ScalarQuantization {
         ScalarQuantization {
             r#type: match config.r#type {
                 segment::types::ScalarType::Int8 => QuantizationType::Int8 as i32,
+                segment::types::ScalarType::Int4 => QuantizationType::Int4 as i32,
             },
             quantile: config.quantile,
             always_ram: config.always_ram,
@@ -1203,6 +1453,7 @@ impl TryFrom<ScalarQuantization> for segment::types::ScalarQuantization {
             scalar: segment::types::ScalarQuantizationConfig {
                 r#type: match QuantizationType::try_from(r#type).ok() {
                     Some(QuantizationType::Int8) => segment::types::ScalarType::Int8,
+                    Some(QuantizationType::Int4) => segment::types::ScalarType::Int4,
                     Some(QuantizationType::UnknownQuantization) | None => {
                         return Err(Status::invalid_argument("Unknown quantization type"));
                     }
@@ -1220,16 +1471,12 @@ impl From<segment::types::ProductQuantization> for ProductQuantization {
         let segment::types::ProductQuantizationConfig {
             compression,
             always_ram,
+            opq,
         } = product;
         ProductQuantization {
-            compression: match compression {
-                segment::types::CompressionRatio::X4 => CompressionRatio::X4 as i32,
-                segment::types::CompressionRatio::X8 => CompressionRatio::X8 as i32,
-                segment::types::CompressionRatio::X16 => CompressionRatio::X16 as i32,
-                segment::types::CompressionRatio::X32 => CompressionRatio::X32 as i32,
-                segment::types::CompressionRatio::X64 => CompressionRatio::X64 as i32,
-            },
+            compression: CompressionRatio::from(compression) as i32,
             always_ram,
+            opq,
         }
     }
 }
@@ -1241,27 +1488,130 @@ impl TryFrom<ProductQuantization> for segment::types::ProductQuantization {
         let ProductQuantization {
             compression,
             always_ram,
+            opq,
         } = value;
         Ok(segment::types::ProductQuantization {
             product: segment::types::ProductQuantizationConfig {
-                compression: match CompressionRatio::try_from(compression) {
-                    Err(_) => {
-                        return Err(Status::invalid_argument(
-                            "Unknown compression ratio".to_string(),
-                        ));
-                    }
-                    Ok(CompressionRatio::X4) => segment::types::CompressionRatio::X4,
-                    Ok(CompressionRatio::X8) => segment::types::CompressionRatio::X8,
-                    Ok(CompressionRatio::X16) => segment::types::CompressionRatio::X16,
-                    Ok(CompressionRatio::X32) => segment::types::CompressionRatio::X32,
-                    Ok(CompressionRatio::X64) => segment::types::CompressionRatio::X64,
-                },
+                compression: convert_compression_ratio(compression)?,
                 always_ram,
+                opq,
             },
         })
     }
 }
 
+impl From<segment::types::CompressionRatio> for CompressionRatio {
+    fn from(value: segment::types::CompressionRatio) -> Self {
+        match value {
+            segment::types::CompressionRatio::X4 => CompressionRatio::X4,
+            segment::types::CompressionRatio::X8 => CompressionRatio::X8,
+            segment::types::CompressionRatio::X16 => CompressionRatio::X16,
+            segment::types::CompressionRatio::X32 => CompressionRatio::X32,
+            segment::types::CompressionRatio::X64 => CompressionRatio::X64,
+        }
+    }
+}
+
+fn convert_compression_ratio(compression: i32) -> Result<segment::types::CompressionRatio, Status> {
+    match CompressionRatio::try_from(compression) {
+        Err(_) => Err(Status::invalid_argument(
+            "Unknown compression ratio".to_string(),
+        )),
+        Ok(CompressionRatio::X4) => Ok(segment::types::CompressionRatio::X4),
+        Ok(CompressionRatio::X8) => Ok(segment::types::CompressionRatio::X8),
+        Ok(CompressionRatio::X16) => Ok(segment::types::CompressionRatio::X16),
+        Ok(CompressionRatio::X32) => Ok(segment::types::CompressionRatio::X32),
+        Ok(CompressionRatio::X64) => Ok(segment::types::CompressionRatio::X64),
+    }
+}
+
+impl From<segment::types::VamanaConfig> for VamanaConfig {
+    fn from(value: segment::types::VamanaConfig) -> Self {
+        let segment::types::VamanaConfig {
+            max_degree,
+            build_list_size,
+            beam_width,
+            compression,
+            full_scan_threshold,
+            max_indexing_threads,
+        } = value;
+        Self {
+            max_degree: Some(max_degree as u64),
+            build_list_size: Some(build_list_size as u64),
+            beam_width: Some(beam_width as u64),
+            compression: Some(CompressionRatio::from(compression) as i32),
+            full_scan_threshold: Some(full_scan_threshold as u64),
+            max_indexing_threads: Some(max_indexing_threads as u64),
+        }
+    }
+}
+
+impl TryFrom<VamanaConfig> for segment::types::VamanaConfig {
+    type Error = Status;
+
+    fn try_from(value: VamanaConfig) -> Result<Self, Self::Error> {
+        let VamanaConfig {
+            max_degree,
+            build_list_size,
+            beam_width,
+            compression,
+            full_scan_threshold,
+            max_indexing_threads,
+        } = value;
+        let default = segment::types::VamanaConfig::default();
+        Ok(Self {
+            max_degree: max_degree.map_or(default.max_degree, |v| v as usize),
+            build_list_size: build_list_size.map_or(default.build_list_size, |v| v as usize),
+            beam_width: beam_width.map_or(default.beam_width, |v| v as usize),
+            compression: compression
+                .map(convert_compression_ratio)
+                .transpose()?
+                .unwrap_or(default.compression),
+            full_scan_threshold: full_scan_threshold
+                .map_or(default.full_scan_threshold, |v| v as usize),
+            max_indexing_threads: max_indexing_threads
+                .map_or(default.max_indexing_threads, |v| v as usize),
+        })
+    }
+}
+
+impl From<segment::types::IvfConfig> for IvfConfig {
+    fn from(value: segment::types::IvfConfig) -> Self {
+        let segment::types::IvfConfig {
+            num_lists,
+            nprobe,
+            full_scan_threshold,
+            max_indexing_threads,
+        } = value;
+        Self {
+            num_lists: num_lists.map(|v| v as u64),
+            nprobe: Some(nprobe as u64),
+            full_scan_threshold: Some(full_scan_threshold as u64),
+            max_indexing_threads: Some(max_indexing_threads as u64),
+        }
+    }
+}
+
+impl From<IvfConfig> for segment::types::IvfConfig {
+    fn from(value: IvfConfig) -> Self {
+        let IvfConfig {
+            num_lists,
+            nprobe,
+            full_scan_threshold,
+            max_indexing_threads,
+        } = value;
+        let default = segment::types::IvfConfig::default();
+        Self {
+            num_lists: num_lists.map(|v| v as usize),
+            nprobe: nprobe.map_or(default.nprobe, |v| v as usize),
+            full_scan_threshold: full_scan_threshold
+                .map_or(default.full_scan_threshold, |v| v as usize),
+            max_indexing_threads: max_indexing_threads
+                .map_or(default.max_indexing_threads, |v| v as usize),
+        }
+    }
+}
+
 impl From<segment::types::BinaryQuantizationEncoding> for BinaryQuantizationEncoding {
     fn from(value: segment::types::BinaryQuantizationEncoding) -> Self {
         match value {
@@ -1498,9 +1848,47 @@ impl From<segment::types::BinaryQuantizationQueryEncoding> for BinaryQuantizatio
 
 impl From<segment::types::MultiVectorConfig> for MultiVectorConfig {
     fn from(value: segment::types::MultiVectorConfig) -> Self {
-        let segment::types::MultiVectorConfig { comparator } = value;
+        let segment::types::MultiVectorConfig { comparator, fde } = value;
         Self {
             comparator: MultiVectorComparator::from(comparator) as i32,
+            fde: fde.map(FdeConfig::from),
+        }
+    }
+}
+
+impl From<segment::types::FdeConfig> for FdeConfig {
+    fn from(value: segment::types::FdeConfig) -> Self {
+        let segment::types::FdeConfig {
+            ksim,
+            dim_proj,
+            repetitions,
+            candidates_factor,
+        } = value;
+        Self {
+            ksim: Some(ksim as u64),
+            dim_proj: Some(dim_proj as u64),
+            repetitions: Some(repetitions as u64),
+            candidates_factor: Some(candidates_factor as u64),
+        }
+    }
+}
+
+impl From<FdeConfig> for segment::types::FdeConfig {
+    fn from(value: FdeConfig) -> Self {
+        let FdeConfig {
+            ksim,
+            dim_proj,
+            repetitions,
+            candidates_factor,
+        } = value;
+        let default = segment::types::FdeConfig::default();
+        Self {
+            ksim: ksim.map_or(default.ksim, |ksim| ksim as usize),
+            dim_proj: dim_proj.map_or(default.dim_proj, |dim_proj| dim_proj as usize),
+            repetitions: repetitions
+                .map_or(default.repetitions, |repetitions| repetitions as usize),
+            candidates_factor: candidates_factor
+                .map_or(default.candidates_factor, |factor| factor as usize),
         }
     }
 }
@@ -1509,6 +1897,10 @@ impl From<segment::types::MultiVectorComparator> for MultiVectorComparator {
     fn from(value: segment::types::MultiVectorComparator) -> Self {
         match value {
             segment::types::MultiVectorComparator::MaxSim => MultiVectorComparator::MaxSim,
+            segment::types::MultiVectorComparator::Chamfer => MultiVectorComparator::Chamfer,
+            segment::types::MultiVectorComparator::MeanSim => MultiVectorComparator::MeanSim,
+            segment::types::MultiVectorComparator::MaxMax => MultiVectorComparator::MaxMax,
+            segment::types::MultiVectorComparator::MeanPool => MultiVectorComparator::MeanPool,
         }
     }
 }
@@ -1517,12 +1909,16 @@ impl TryFrom<MultiVectorConfig> for segment::types::MultiVectorConfig {
     type Error = Status;
 
     fn try_from(value: MultiVectorConfig) -> Result<Self, Self::Error> {
-        let MultiVectorConfig { comparator } = value;
+        let MultiVectorConfig { comparator, fde } = value;
         let comparator = MultiVectorComparator::try_from(comparator)
             .map_err(|_| Status::invalid_argument("Unknown multi vector comparator"))?;
-        Ok(segment::types::MultiVectorConfig {
+        let config = segment::types::MultiVectorConfig {
             comparator: segment::types::MultiVectorComparator::from(comparator),
-        })
+            fde: fde.map(segment::types::FdeConfig::from),
+        };
+        validator::Validate::validate(&config)
+            .map_err(|err| Status::invalid_argument(format!("Invalid MultiVectorConfig: {err}")))?;
+        Ok(config)
     }
 }
 
@@ -1530,6 +1926,10 @@ impl From<MultiVectorComparator> for segment::types::MultiVectorComparator {
     fn from(value: MultiVectorComparator) -> Self {
         match value {
             MultiVectorComparator::MaxSim => segment::types::MultiVectorComparator::MaxSim,
+            MultiVectorComparator::Chamfer => segment::types::MultiVectorComparator::Chamfer,
+            MultiVectorComparator::MeanSim => segment::types::MultiVectorComparator::MeanSim,
+            MultiVectorComparator::MaxMax => segment::types::MultiVectorComparator::MaxMax,
+            MultiVectorComparator::MeanPool => segment::types::MultiVectorComparator::MeanPool,
         }
     }
 }
@@ -1808,6 +2208,7 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
             datetime_range,
             is_empty,
             is_null,
+            keyword_range,
         } = value;
 
         let geo_bounding_box =
@@ -1821,6 +2222,9 @@ impl TryFrom<FieldCondition> for segment::types::FieldCondition {
                 .map(segment::types::RangeInterface::try_from)
                 .transpose()?;
         }
+        if range.is_none() {
+            range = keyword_range.map(segment::types::RangeInterface::from);
+        }
 
         Ok(Self {
             key: json::json_path_from_proto(&key)?,
@@ -1850,10 +2254,17 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             is_null,
         } = value;
 
-        let (range, datetime_range) = match range {
-            Some(segment::types::RangeInterface::Float(range)) => (Some(Range::from(range)), None),
-            Some(segment::types::RangeInterface::DateTime(range)) => (None, Some(range.into())),
-            None => (None, None),
+        let (range, datetime_range, keyword_range) = match range {
+            Some(segment::types::RangeInterface::Float(range)) => {
+                (Some(Range::from(range)), None, None)
+            }
+            Some(segment::types::RangeInterface::DateTime(range)) => {
+                (None, Some(range.into()), None)
+            }
+            Some(segment::types::RangeInterface::Keyword(range)) => {
+                (None, None, Some(range.keyword.into()))
+            }
+            None => (None, None, None),
         };
 
         Self {
@@ -1867,6 +2278,7 @@ impl From<segment::types::FieldCondition> for FieldCondition {
             datetime_range,
             is_empty,
             is_null,
+            keyword_range,
         }
     }
 }
@@ -2048,6 +2460,22 @@ impl From<segment::types::Range<DateTimePayloadType>> for DatetimeRange {
     }
 }
 
+impl From<KeywordRange> for segment::types::RangeInterface {
+    fn from(value: KeywordRange) -> Self {
+        let KeywordRange { lt, gt, gte, lte } = value;
+        Self::Keyword(segment::types::KeywordRange {
+            keyword: segment::types::Range { lt, gt, gte, lte },
+        })
+    }
+}
+
+impl From<segment::types::Range<String>> for KeywordRange {
+    fn from(value: segment::types::Range<String>) -> Self {
+        let segment::types::Range { lt, gt, gte, lte } = value;
+        Self { lt, gt, gte, lte }
+    }
+}
+
 impl From<ValuesCount> for segment::types::ValuesCount {
     fn from(value: ValuesCount) -> Self {
         let ValuesCount { lt, gt, gte, lte } = value;
@@ -2175,6 +2603,8 @@ impl TryFrom<OrderBy> for segment::data_types::order_by::OrderBy {
             key,
             direction,
             start_from,
+            then_by,
+            start_after,
         } = value;
 
         let direction = direction
@@ -2197,14 +2627,26 @@ impl TryFrom<OrderBy> for segment::data_types::order_by::OrderBy {
                             Status::invalid_argument(format!("Malformed datetime: {e}"))
                         })?,
                     )),
+                    Value::Keyword(keyword) => Ok(StartFrom::Keyword(keyword)),
                 }
             })
             .transpose()?;
 
+        let then_by = then_by
+            .into_iter()
+            .map(segment::data_types::order_by::ThenBy::try_from)
+            .collect::<Result<_, _>>()?;
+
+        let start_after = start_after
+            .map(segment::data_types::order_by::OrderByCursor::try_from)
+            .transpose()?;
+
         Ok(Self {
             key: json::json_path_from_proto(&key)?,
             direction,
             start_from,
+            then_by,
+            start_after,
         })
     }
 }
@@ -2215,11 +2657,74 @@ impl From<segment::data_types::order_by::OrderBy> for OrderBy {
             key,
             direction,
             start_from,
+            then_by,
+            start_after,
         } = value;
         Self {
             key: key.to_string(),
             direction: direction.map(|d| Direction::from(d) as i32),
             start_from: start_from.map(Into::into),
+            then_by: then_by.into_iter().map(ThenBy::from).collect(),
+            start_after: start_after.map(OrderByCursor::from),
+        }
+    }
+}
+
+impl TryFrom<ThenBy> for segment::data_types::order_by::ThenBy {
+    type Error = Status;
+
+    fn try_from(value: ThenBy) -> Result<Self, Self::Error> {
+        use crate::conversions::json;
+
+        let ThenBy { key, direction } = value;
+
+        let direction = direction
+            .and_then(|x|
+                // XXX: Invalid values silently converted to None
+                Direction::try_from(x).ok())
+            .map(segment::data_types::order_by::Direction::from);
+
+        Ok(Self {
+            key: json::json_path_from_proto(&key)?,
+            direction,
+        })
+    }
+}
+
+impl From<segment::data_types::order_by::ThenBy> for ThenBy {
+    fn from(value: segment::data_types::order_by::ThenBy) -> Self {
+        let segment::data_types::order_by::ThenBy { key, direction } = value;
+        Self {
+            key: key.to_string(),
+            direction: direction.map(|d| Direction::from(d) as i32),
+        }
+    }
+}
+
+impl TryFrom<OrderByCursor> for segment::data_types::order_by::OrderByCursor {
+    type Error = Status;
+
+    fn try_from(value: OrderByCursor) -> Result<Self, Self::Error> {
+        let OrderByCursor { value, then_by, id } = value;
+        Ok(Self {
+            value: value
+                .ok_or_else(|| Status::invalid_argument("OrderByCursor should have a value"))?
+                .try_into()?,
+            then_by: then_by_values_from_proto(then_by)?,
+            id: id
+                .ok_or_else(|| Status::invalid_argument("OrderByCursor should have an id"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<segment::data_types::order_by::OrderByCursor> for OrderByCursor {
+    fn from(value: segment::data_types::order_by::OrderByCursor) -> Self {
+        let segment::data_types::order_by::OrderByCursor { value, then_by, id } = value;
+        Self {
+            value: Some(OrderValue::from(value)),
+            then_by: then_by_values_to_proto(then_by),
+            id: Some(PointId::from(id)),
         }
     }
 }
@@ -2234,6 +2739,9 @@ impl From<segment::data_types::order_by::StartFrom> for StartFrom {
                 segment::data_types::order_by::StartFrom::Float(float) => {
                     start_from::Value::Float(float)
                 }
+                segment::data_types::order_by::StartFrom::Keyword(keyword) => {
+                    start_from::Value::Keyword(keyword)
+                }
                 segment::data_types::order_by::StartFrom::Datetime(datetime) => {
                     start_from::Value::Timestamp(date_time_to_proto(datetime))
                 }
@@ -2346,6 +2854,7 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfig {
             unindexed_filtering_update,
             search_max_hnsw_ef,
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling,
             upsert_max_batchsize,
             search_max_batchsize,
@@ -2369,6 +2878,7 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfig {
             unindexed_filtering_update,
             search_max_hnsw_ef: search_max_hnsw_ef.map(|i| i as usize),
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling: search_max_oversampling.map(f64::from),
             upsert_max_batchsize: upsert_max_batchsize.map(|i| i as usize),
             search_max_batchsize: search_max_batchsize.map(|i| i as usize),
@@ -2475,6 +2985,7 @@ impl From<segment::types::StrictModeConfigOutput> for StrictModeConfig {
             unindexed_filtering_update,
             search_max_hnsw_ef,
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling,
             upsert_max_batchsize,
             search_max_batchsize,
@@ -2498,6 +3009,7 @@ impl From<segment::types::StrictModeConfigOutput> for StrictModeConfig {
             unindexed_filtering_update,
             search_max_hnsw_ef: search_max_hnsw_ef.map(|i| i as u32),
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling: search_max_oversampling.map(|i| i as f32),
             upsert_max_batchsize: upsert_max_batchsize.map(|i| i as u64),
             search_max_batchsize: search_max_batchsize.map(|i| i as u64),
@@ -2526,6 +3038,7 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfigOutput {
             unindexed_filtering_update,
             search_max_hnsw_ef,
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling,
             upsert_max_batchsize,
             search_max_batchsize,
@@ -2549,6 +3062,7 @@ impl From<StrictModeConfig> for segment::types::StrictModeConfigOutput {
             unindexed_filtering_update,
             search_max_hnsw_ef: search_max_hnsw_ef.map(|i| i as usize),
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling: search_max_oversampling.map(f64::from),
             upsert_max_batchsize: upsert_max_batchsize.map(|i| i as usize),
             search_max_batchsize: search_max_batchsize.map(|i| i as usize),
@@ -2672,6 +3186,8 @@ impl TryFrom<Distance> for segment::types::Distance {
             Distance::Euclid => segment::types::Distance::Euclid,
             Distance::Dot => segment::types::Distance::Dot,
             Distance::Manhattan => segment::types::Distance::Manhattan,
+            Distance::Hamming => segment::types::Distance::Hamming,
+            Distance::Jaccard => segment::types::Distance::Jaccard,
         })
     }
 }
@@ -3034,6 +3550,7 @@ impl TryFrom<SearchPoints> for rest::SearchRequestInternal {
             timeout: _,
             shard_key_selector: _,
             sparse_indices,
+            explain: _,
         } = value;
 
         let vector_internal =
@@ -3053,7 +3570,7 @@ impl TryFrom<SearchPoints> for rest::SearchRequestInternal {
         Ok(Self {
             vector,
             filter: filter.map(|f| f.try_into()).transpose()?,
-            params: params.map(SearchParams::into),
+            params: params.map(TryInto::try_into).transpose()?,
             limit: limit as usize,
             offset: offset.map(|x| x as usize),
             with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
@@ -3104,6 +3621,7 @@ impl TryFrom<SearchPointGroups> for rest::SearchGroupsRequestInternal {
             timeout,
             shard_key_selector,
             sparse_indices,
+            explain: None,
         };
 
         if let Some(sparse_indices) = &search_points.sparse_indices {
@@ -3135,12 +3653,13 @@ impl TryFrom<SearchPointGroups> for rest::SearchGroupsRequestInternal {
             with_vector,
             score_threshold,
             group_request: rest::BaseGroupRequest {
-                group_by: json::json_path_from_proto(&group_by)?,
+                group_by: json::json_path_from_proto(&group_by)?.into(),
                 limit,
                 group_size,
                 with_lookup: with_lookup
                     .map(rest::WithLookupInterface::try_from)
                     .transpose()?,
+                subgroups: None,
             },
         })
     }
@@ -3285,6 +3804,38 @@ impl From<segment_facets::FacetValue> for FacetValue {
     }
 }
 
+/// UUID values are received as strings, same as they are sent
+impl TryFrom<FacetValue> for segment_facets::FacetValue {
+    type Error = Status;
+
+    fn try_from(value: FacetValue) -> Result<Self, Self::Error> {
+        use super::qdrant::facet_value::Variant;
+
+        let FacetValue { variant } = value;
+        let variant =
+            variant.ok_or_else(|| Status::invalid_argument("FacetValue should have a variant"))?;
+        Ok(match variant {
+            Variant::StringValue(value) => segment_facets::FacetValue::Keyword(value),
+            Variant::IntegerValue(value) => segment_facets::FacetValue::Int(value),
+            Variant::BoolValue(value) => segment_facets::FacetValue::Bool(value),
+        })
+    }
+}
+
+impl From<rest::FacetValue> for FacetValue {
+    fn from(value: rest::FacetValue) -> Self {
+        use super::qdrant::facet_value::Variant;
+
+        Self {
+            variant: Some(match value {
+                rest::FacetValue::String(value) => Variant::StringValue(value),
+                rest::FacetValue::Integer(value) => Variant::IntegerValue(value),
+                rest::FacetValue::Bool(value) => Variant::BoolValue(value),
+            }),
+        }
+    }
+}
+
 impl From<rest::SearchMatrixPair> for SearchMatrixPair {
     fn from(pair: rest::SearchMatrixPair) -> Self {
         let rest::SearchMatrixPair { a, b, score } = pair;
@@ -3344,6 +3895,111 @@ impl From<HardwareUsage> for HardwareData {
     }
 }
 
+impl From<models::Explanation> for grpc::Explanation {
+    fn from(value: models::Explanation) -> Self {
+        let models::Explanation {
+            stages,
+            unexplained_shards,
+        } = value;
+
+        Self {
+            stages: stages.into_iter().map(From::from).collect(),
+            unexplained_shards,
+        }
+    }
+}
+
+impl From<models::StageExplanation> for grpc::StageExplanation {
+    fn from(value: models::StageExplanation) -> Self {
+        let models::StageExplanation {
+            stage,
+            kind,
+            using,
+            shards,
+        } = value;
+
+        let kind = match kind {
+            models::StageKind::Search => grpc::StageKind::Search,
+            models::StageKind::Scroll => grpc::StageKind::Scroll,
+            models::StageKind::Count => grpc::StageKind::Count,
+        };
+
+        Self {
+            stage,
+            kind: kind as i32,
+            using,
+            shards: shards.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+impl From<models::ShardExplanation> for grpc::ShardExplanation {
+    fn from(value: models::ShardExplanation) -> Self {
+        let models::ShardExplanation {
+            shard_id,
+            found,
+            time,
+            segments,
+        } = value;
+
+        Self {
+            shard_id,
+            found: found as u64,
+            time,
+            segments: segments.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+impl From<models::SegmentStageExplanation> for grpc::SegmentStageExplanation {
+    fn from(value: models::SegmentStageExplanation) -> Self {
+        let models::SegmentStageExplanation {
+            segment_id,
+            explanation,
+            found,
+            time,
+        } = value;
+
+        let SegmentExplanation {
+            strategy,
+            available_points,
+            estimated_cardinality,
+            actual_cardinality,
+            indexes_used,
+        } = explanation;
+
+        Self {
+            segment_id,
+            strategy: grpc::SearchStrategy::from(strategy) as i32,
+            available_points: available_points as u64,
+            estimated_cardinality: Some(grpc::EstimatedCardinality {
+                min: estimated_cardinality.min as u64,
+                exp: estimated_cardinality.exp as u64,
+                max: estimated_cardinality.max as u64,
+            }),
+            actual_cardinality: actual_cardinality as u64,
+            indexes_used: indexes_used.iter().map(ToString::to_string).collect(),
+            found: found as u64,
+            time,
+        }
+    }
+}
+
+impl From<SearchStrategy> for grpc::SearchStrategy {
+    fn from(value: SearchStrategy) -> Self {
+        match value {
+            SearchStrategy::Exact => grpc::SearchStrategy::Exact,
+            SearchStrategy::FullScan => grpc::SearchStrategy::FullScan,
+            SearchStrategy::PayloadIndex => grpc::SearchStrategy::PayloadIndex,
+            SearchStrategy::Hnsw => grpc::SearchStrategy::Hnsw,
+            SearchStrategy::Acorn => grpc::SearchStrategy::Acorn,
+            SearchStrategy::Vamana => grpc::SearchStrategy::Vamana,
+            SearchStrategy::Ivf => grpc::SearchStrategy::Ivf,
+            SearchStrategy::SparseIndex => grpc::SearchStrategy::SparseIndex,
+        }
+    }
+}
+
 impl From<NaiveFeedbackCoefficients> for grpc::NaiveFeedbackStrategy {
     fn from(value: NaiveFeedbackCoefficients) -> Self {
         let NaiveFeedbackCoefficients { a, b, c } = value;
@@ -3572,6 +4228,7 @@ fn convert_datatype_from_proto(
         grpc::Datatype::Float32 => Ok(Some(VectorStorageDatatype::Float32)),
         grpc::Datatype::Float16 => Ok(Some(VectorStorageDatatype::Float16)),
         grpc::Datatype::Uint8 => Ok(Some(VectorStorageDatatype::Uint8)),
+        grpc::Datatype::Bit => Ok(Some(VectorStorageDatatype::Bit)),
     }
 }
 
@@ -3600,6 +4257,8 @@ impl From<segment::data_types::vector_name_config::VectorNameConfig>
                     Distance::Euclid => grpc::Distance::Euclid,
                     Distance::Dot => grpc::Distance::Dot,
                     Distance::Manhattan => grpc::Distance::Manhattan,
+                    Distance::Hamming => grpc::Distance::Hamming,
+                    Distance::Jaccard => grpc::Distance::Jaccard,
                 };
                 grpc::create_vector_name_request::VectorConfig::DenseConfig(
                     grpc::DenseVectorCreationConfig {
@@ -3627,5 +4286,6 @@ fn datatype_to_grpc(dt: VectorStorageDatatype) -> grpc::Datatype {
         VectorStorageDatatype::Float32 => grpc::Datatype::Float32,
         VectorStorageDatatype::Float16 => grpc::Datatype::Float16,
         VectorStorageDatatype::Uint8 => grpc::Datatype::Uint8,
+        VectorStorageDatatype::Bit => grpc::Datatype::Bit,
     }
 }
@@ -267,6 +267,9 @@ pub struct FieldCondition {
     /// Check if field is null
     #[prost(bool, optional, tag = "10")]
     pub is_null: ::core::option::Option<bool>,
+    /// Check if keyword lies in a given lexicographic range
+    #[prost(message, optional, tag = "11")]
+    pub keyword_range: ::core::option::Option<KeywordRange>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -361,6 +364,19 @@ pub struct DatetimeRange {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct KeywordRange {
+    #[prost(string, optional, tag = "1")]
+    pub lt: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub gt: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub gte: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "4")]
+    pub lte: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GeoBoundingBox {
     /// north-west corner
     #[prost(message, optional, tag = "1")]
@@ -453,7 +469,23 @@ pub struct VectorParams {
     pub datatype: ::core::option::Option<i32>,
     /// Configuration for multi-vector search
     #[prost(message, optional, tag = "7")]
+    #[validate(nested)]
     pub multivector_config: ::core::option::Option<MultiVectorConfig>,
+    /// Number of leading dimensions of Matryoshka embeddings to index separately.
+    /// Nearest queries with the `matryoshka` search param are scored on the first dimensions of the vectors.
+    #[prost(uint64, optional, tag = "8")]
+    #[validate(range(min = 1))]
+    pub matryoshka_dim: ::core::option::Option<u64>,
+    /// Configuration of disk-resident Vamana graph index.
+    /// If set - optimized segments use it instead of HNSW index
+    #[prost(message, optional, tag = "9")]
+    #[validate(nested)]
+    pub vamana_config: ::core::option::Option<VamanaConfig>,
+    /// Configuration of inverted file (IVF) index.
+    /// If set - optimized segments use it instead of HNSW index
+    #[prost(message, optional, tag = "10")]
+    #[validate(nested)]
+    pub ivf_config: ::core::option::Option<IvfConfig>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -558,6 +590,29 @@ pub struct SparseVectorConfig {
         SparseVectorParams,
     >,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FdeConfig {
+    /// Number of SimHash hyperplanes in each repetition, splits the space into `2^ksim` buckets. Default: 4
+    #[prost(uint64, optional, tag = "1")]
+    #[validate(range(min = 1, max = 8))]
+    pub ksim: ::core::option::Option<u64>,
+    /// Size of the random projection of each bucket. Default: 16
+    #[prost(uint64, optional, tag = "2")]
+    #[validate(range(min = 1, max = 1024))]
+    pub dim_proj: ::core::option::Option<u64>,
+    /// Number of independent repetitions of the encoding. Default: 10
+    #[prost(uint64, optional, tag = "3")]
+    #[validate(range(min = 1, max = 64))]
+    pub repetitions: ::core::option::Option<u64>,
+    /// How many candidates to retrieve with the encoding for each requested result. Default: 4
+    #[prost(uint64, optional, tag = "4")]
+    #[validate(range(min = 1, max = 100))]
+    pub candidates_factor: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -565,6 +620,11 @@ pub struct MultiVectorConfig {
     /// Comparator for multi-vector search
     #[prost(enumeration = "MultiVectorComparator", tag = "1")]
     pub comparator: i32,
+    /// If set, the HNSW index is built on fixed-dimensional encodings of multivectors
+    /// Requires Dot or Cosine distance and the MaxSim comparator
+    #[prost(message, optional, tag = "2")]
+    #[validate(nested)]
+    pub fde: ::core::option::Option<FdeConfig>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -752,6 +812,56 @@ pub struct HnswConfigDiff {
     #[prost(bool, optional, tag = "7")]
     pub inline_storage: ::core::option::Option<bool>,
 }
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VamanaConfig {
+    /// Maximal number of edges per node in the graph. Default: 64
+    #[prost(uint64, optional, tag = "1")]
+    #[validate(range(min = 4, max = 512))]
+    pub max_degree: ::core::option::Option<u64>,
+    /// Number of candidates to consider during the index building. Default: 128
+    #[prost(uint64, optional, tag = "2")]
+    #[validate(range(min = 4))]
+    pub build_list_size: ::core::option::Option<u64>,
+    /// Number of nodes read from disk at once during search. Default: 4
+    #[prost(uint64, optional, tag = "3")]
+    #[validate(range(min = 1, max = 64))]
+    pub beam_width: ::core::option::Option<u64>,
+    /// Compression ratio of the product-quantized vectors kept in RAM. Default: x16
+    #[prost(enumeration = "CompressionRatio", optional, tag = "4")]
+    pub compression: ::core::option::Option<i32>,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over graph search.
+    #[prost(uint64, optional, tag = "5")]
+    pub full_scan_threshold: ::core::option::Option<u64>,
+    /// Number of parallel threads used for background index building.
+    /// If 0 - automatically select.
+    #[prost(uint64, optional, tag = "6")]
+    pub max_indexing_threads: ::core::option::Option<u64>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IvfConfig {
+    /// Number of lists to partition vectors into.
+    /// If not set - the square root of the number of vectors in a segment is used
+    #[prost(uint64, optional, tag = "1")]
+    #[validate(range(min = 1, max = 65536))]
+    pub num_lists: ::core::option::Option<u64>,
+    /// Number of lists to scan during search. Default: 16
+    #[prost(uint64, optional, tag = "2")]
+    #[validate(range(min = 1))]
+    pub nprobe: ::core::option::Option<u64>,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over IVF search.
+    #[prost(uint64, optional, tag = "3")]
+    pub full_scan_threshold: ::core::option::Option<u64>,
+    /// Number of parallel threads used for background index building.
+    /// If 0 - automatically select.
+    #[prost(uint64, optional, tag = "4")]
+    pub max_indexing_threads: ::core::option::Option<u64>,
+}
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -890,6 +1000,9 @@ pub struct ProductQuantization {
     /// If true - quantized vectors always will be stored in RAM, ignoring the config of main storage
     #[prost(bool, optional, tag = "2")]
     pub always_ram: ::core::option::Option<bool>,
+    /// If true - learn an orthogonal rotation (OPQ) before quantization. Default: false
+    #[prost(bool, optional, tag = "3")]
+    pub opq: ::core::option::Option<bool>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1063,6 +1176,9 @@ pub struct StrictModeConfig {
     /// Whether exact search is allowed.
     #[prost(bool, optional, tag = "7")]
     pub search_allow_exact: ::core::option::Option<bool>,
+    /// Whether radius search is allowed.
+    #[prost(bool, optional, tag = "22")]
+    pub search_allow_radius: ::core::option::Option<bool>,
     /// Max oversampling value allowed in search
     #[prost(float, optional, tag = "8")]
     pub search_max_oversampling: ::core::option::Option<f32>,
@@ -1157,6 +1273,64 @@ pub struct StrictModeMultivector {
     #[validate(range(min = 1))]
     pub max_vectors: ::core::option::Option<u64>,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadPropertySchema {
+    /// Expected type of the value. Any type is allowed if not specified
+    #[prost(enumeration = "PayloadValueType", optional, tag = "1")]
+    pub r#type: ::core::option::Option<i32>,
+    /// If not empty, the value must be one of the listed strings, integers or booleans
+    #[prost(message, repeated, tag = "2")]
+    pub values: ::prost::alloc::vec::Vec<Value>,
+    /// Minimal allowed value for numbers, inclusive
+    #[prost(double, optional, tag = "3")]
+    pub minimum: ::core::option::Option<f64>,
+    /// Maximal allowed value for numbers, inclusive
+    #[prost(double, optional, tag = "4")]
+    pub maximum: ::core::option::Option<f64>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadValidationConfig {
+    /// What to do with writes which violate the schema. Default: reject
+    #[prost(enumeration = "PayloadValidationMode", optional, tag = "1")]
+    pub mode: ::core::option::Option<i32>,
+    /// Schemas of known top-level payload fields
+    #[prost(map = "string, message", tag = "2")]
+    pub properties: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        PayloadPropertySchema,
+    >,
+    /// Fields which must be present in the payload of every inserted point
+    #[prost(string, repeated, tag = "3")]
+    pub required: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Whether fields not listed in `properties` are allowed. Default: true
+    #[prost(bool, optional, tag = "4")]
+    pub additional_properties: ::core::option::Option<bool>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PayloadValidationConfigDiff {
+    #[prost(oneof = "payload_validation_config_diff::Config", tags = "1, 2")]
+    pub config: ::core::option::Option<payload_validation_config_diff::Config>,
+}
+/// Nested message and enum types in `PayloadValidationConfigDiff`.
+pub mod payload_validation_config_diff {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Config {
+        /// Replace the current schema
+        #[prost(message, tag = "1")]
+        Enabled(super::PayloadValidationConfig),
+        /// Stop validating payloads
+        #[prost(message, tag = "2")]
+        Disabled(super::Disabled),
+    }
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1222,6 +1396,12 @@ pub struct CreateCollection {
     /// Arbitrary JSON metadata for the collection
     #[prost(map = "string, message", tag = "18")]
     pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+    /// Schema, which payloads of inserted and updated points are validated against
+    #[prost(message, optional, tag = "19")]
+    pub payload_validation: ::core::option::Option<PayloadValidationConfig>,
+    /// If true - count writes made to each point and return the count as point version
+    #[prost(bool, optional, tag = "20")]
+    pub point_versions: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1273,6 +1453,9 @@ pub struct UpdateCollection {
     /// already stored metadata
     #[prost(map = "string, message", tag = "10")]
     pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+    /// Schema to validate payloads against, or `disabled` to stop validating payloads
+    #[prost(message, optional, tag = "11")]
+    pub payload_validation: ::core::option::Option<PayloadValidationConfigDiff>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1336,6 +1519,9 @@ pub struct CollectionParams {
     /// Define number of milliseconds to wait before attempting to read from another replica.
     #[prost(uint64, optional, tag = "11")]
     pub read_fan_out_delay_ms: ::core::option::Option<u64>,
+    /// If true - writes made to each point are counted and returned as point version
+    #[prost(bool, tag = "12")]
+    pub point_versions: bool,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -1385,6 +1571,9 @@ pub struct CollectionConfig {
     /// Arbitrary JSON metadata for the collection
     #[prost(map = "string, message", tag = "7")]
     pub metadata: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+    /// Schema, which payloads of inserted and updated points are validated against
+    #[prost(message, optional, tag = "8")]
+    pub payload_validation: ::core::option::Option<PayloadValidationConfig>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1577,6 +1766,36 @@ pub struct UuidIndexParams {
     #[prost(bool, optional, tag = "3")]
     pub enable_hnsw: ::core::option::Option<bool>,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompositeIndexField {
+    /// Payload key of the field
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Type of values of the field: keyword, integer, float, datetime or uuid
+    #[prost(enumeration = "PayloadSchemaType", tag = "2")]
+    pub r#type: i32,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CompositeIndexParams {
+    /// Type of values of the indexed field: keyword, integer, float, datetime or uuid
+    #[prost(enumeration = "PayloadSchemaType", tag = "1")]
+    pub field_type: i32,
+    /// Other fields of the composite key, in order
+    #[prost(message, repeated, tag = "2")]
+    pub fields: ::prost::alloc::vec::Vec<CompositeIndexField>,
+    /// If true - store the regular index of the indexed field on disk.
+    #[prost(bool, optional, tag = "3")]
+    pub on_disk: ::core::option::Option<bool>,
+    /// Enable HNSW graph building for this payload field.
+    /// If true, builds additional HNSW links (Need payload_m > 0).
+    /// Default: true.
+    #[prost(bool, optional, tag = "4")]
+    pub enable_hnsw: ::core::option::Option<bool>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1584,7 +1803,7 @@ pub struct UuidIndexParams {
 pub struct PayloadIndexParams {
     #[prost(
         oneof = "payload_index_params::IndexParams",
-        tags = "3, 2, 4, 5, 1, 6, 7, 8"
+        tags = "3, 2, 4, 5, 1, 6, 7, 8, 9"
     )]
     #[validate(nested)]
     pub index_params: ::core::option::Option<payload_index_params::IndexParams>,
@@ -1619,6 +1838,9 @@ pub mod payload_index_params {
         /// Parameters for uuid index
         #[prost(message, tag = "8")]
         UuidIndexParams(super::UuidIndexParams),
+        /// Parameters for composite index over several fields
+        #[prost(message, tag = "9")]
+        CompositeIndexParams(super::CompositeIndexParams),
     }
 }
 #[derive(serde::Serialize)]
@@ -2148,6 +2370,8 @@ pub enum Datatype {
     Float32 = 1,
     Uint8 = 2,
     Float16 = 3,
+    /// Binary vector, 8 dimensions packed into a byte
+    Bit = 4,
 }
 impl Datatype {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2160,6 +2384,7 @@ impl Datatype {
             Datatype::Float32 => "Float32",
             Datatype::Uint8 => "Uint8",
             Datatype::Float16 => "Float16",
+            Datatype::Bit => "Bit",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2169,6 +2394,7 @@ impl Datatype {
             "Float32" => Some(Self::Float32),
             "Uint8" => Some(Self::Uint8),
             "Float16" => Some(Self::Float16),
+            "Bit" => Some(Self::Bit),
             _ => None,
         }
     }
@@ -2205,7 +2431,16 @@ impl Modifier {
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum MultiVectorComparator {
+    /// Sum of the best matching similarities for each query vector
     MaxSim = 0,
+    /// Symmetric Chamfer similarity
+    Chamfer = 1,
+    /// Mean similarity over all pairs of inner vectors
+    MeanSim = 2,
+    /// Similarity of the single best matching pair of inner vectors
+    MaxMax = 3,
+    /// Similarity of the mean-pooled multivectors
+    MeanPool = 4,
 }
 impl MultiVectorComparator {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2215,12 +2450,20 @@ impl MultiVectorComparator {
     pub fn as_str_name(&self) -> &'static str {
         match self {
             MultiVectorComparator::MaxSim => "MaxSim",
+            MultiVectorComparator::Chamfer => "Chamfer",
+            MultiVectorComparator::MeanSim => "MeanSim",
+            MultiVectorComparator::MaxMax => "MaxMax",
+            MultiVectorComparator::MeanPool => "MeanPool",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
             "MaxSim" => Some(Self::MaxSim),
+            "Chamfer" => Some(Self::Chamfer),
+            "MeanSim" => Some(Self::MeanSim),
+            "MaxMax" => Some(Self::MaxMax),
+            "MeanPool" => Some(Self::MeanPool),
             _ => None,
         }
     }
@@ -2234,6 +2477,9 @@ pub enum Distance {
     Euclid = 2,
     Dot = 3,
     Manhattan = 4,
+    Hamming = 5,
+    /// Also known as Tanimoto distance
+    Jaccard = 6,
 }
 impl Distance {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2247,6 +2493,8 @@ impl Distance {
             Distance::Euclid => "Euclid",
             Distance::Dot => "Dot",
             Distance::Manhattan => "Manhattan",
+            Distance::Hamming => "Hamming",
+            Distance::Jaccard => "Jaccard",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2257,6 +2505,8 @@ impl Distance {
             "Euclid" => Some(Self::Euclid),
             "Dot" => Some(Self::Dot),
             "Manhattan" => Some(Self::Manhattan),
+            "Hamming" => Some(Self::Hamming),
+            "Jaccard" => Some(Self::Jaccard),
             _ => None,
         }
     }
@@ -2355,6 +2605,7 @@ impl PayloadSchemaType {
 pub enum QuantizationType {
     UnknownQuantization = 0,
     Int8 = 1,
+    Int4 = 2,
 }
 impl QuantizationType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -2365,6 +2616,7 @@ impl QuantizationType {
         match self {
             QuantizationType::UnknownQuantization => "UnknownQuantization",
             QuantizationType::Int8 => "Int8",
+            QuantizationType::Int4 => "Int4",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -2372,6 +2624,7 @@ impl QuantizationType {
         match value {
             "UnknownQuantization" => Some(Self::UnknownQuantization),
             "Int8" => Some(Self::Int8),
+            "Int4" => Some(Self::Int4),
             _ => None,
         }
     }
@@ -2507,6 +2760,79 @@ impl ShardingMethod {
 #[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum PayloadValidationMode {
+    /// Reject the whole operation
+    Reject = 0,
+    /// Apply the operation, but report violations as collection issues
+    Warn = 1,
+}
+impl PayloadValidationMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PayloadValidationMode::Reject => "PayloadValidationModeReject",
+            PayloadValidationMode::Warn => "PayloadValidationModeWarn",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PayloadValidationModeReject" => Some(Self::Reject),
+            "PayloadValidationModeWarn" => Some(Self::Warn),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PayloadValueType {
+    String = 0,
+    Integer = 1,
+    Number = 2,
+    Boolean = 3,
+    Object = 4,
+    /// String in one of the datetime formats accepted by datetime payload index
+    Datetime = 5,
+    /// String containing a UUID
+    Uuid = 6,
+}
+impl PayloadValueType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PayloadValueType::String => "PayloadValueTypeString",
+            PayloadValueType::Integer => "PayloadValueTypeInteger",
+            PayloadValueType::Number => "PayloadValueTypeNumber",
+            PayloadValueType::Boolean => "PayloadValueTypeBoolean",
+            PayloadValueType::Object => "PayloadValueTypeObject",
+            PayloadValueType::Datetime => "PayloadValueTypeDatetime",
+            PayloadValueType::Uuid => "PayloadValueTypeUuid",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PayloadValueTypeString" => Some(Self::String),
+            "PayloadValueTypeInteger" => Some(Self::Integer),
+            "PayloadValueTypeNumber" => Some(Self::Number),
+            "PayloadValueTypeBoolean" => Some(Self::Boolean),
+            "PayloadValueTypeObject" => Some(Self::Object),
+            "PayloadValueTypeDatetime" => Some(Self::Datetime),
+            "PayloadValueTypeUuid" => Some(Self::Uuid),
+            _ => None,
+        }
+    }
+}
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum TokenizerType {
     Unknown = 0,
     Prefix = 1,
@@ -5236,6 +5562,40 @@ pub struct ShardKeySelector {
     #[prost(message, optional, tag = "2")]
     pub fallback: ::core::option::Option<ShardKey>,
 }
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpectedPointVersion {
+    /// Point id
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<PointId>,
+    /// Expected version of the point
+    #[prost(uint64, tag = "2")]
+    pub version: u64,
+}
+/// Point, which version did not match the expected one
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PointVersionConflict {
+    /// Point id
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<PointId>,
+    /// Expected version of the point
+    #[prost(uint64, tag = "2")]
+    pub expected: u64,
+    /// Current version of the point, absent if the point does not exist
+    #[prost(uint64, optional, tag = "3")]
+    pub actual: ::core::option::Option<u64>,
+}
+/// Details of the `ABORTED` status, returned if points have unexpected versions
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PointVersionConflicts {
+    #[prost(message, repeated, tag = "1")]
+    pub conflicts: ::prost::alloc::vec::Vec<PointVersionConflict>,
+}
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -5271,6 +5631,11 @@ pub struct UpsertPoints {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[prost(enumeration = "UpdateMode", optional, tag = "8")]
     pub update_mode: ::core::option::Option<i32>,
+    /// Versions, which points are expected to have.
+    /// If any of them differs, the shard, which holds the point, rejects the upsert.
+    /// Other shards are updated independently.
+    #[prost(message, repeated, tag = "9")]
+    pub if_version: ::prost::alloc::vec::Vec<ExpectedPointVersion>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5299,6 +5664,11 @@ pub struct DeletePoints {
     /// Timeout for the request in seconds
     #[prost(uint64, optional, tag = "6")]
     pub timeout: ::core::option::Option<u64>,
+    /// Versions, which points are expected to have.
+    /// If any of them differs, the shard, which holds the point, rejects the deletion.
+    /// Other shards are updated independently.
+    #[prost(message, repeated, tag = "7")]
+    pub if_version: ::prost::alloc::vec::Vec<ExpectedPointVersion>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5330,6 +5700,10 @@ pub struct GetPoints {
     /// If set, overrides global timeout setting for this request. Unit is seconds.
     #[prost(uint64, optional, tag = "8")]
     pub timeout: ::core::option::Option<u64>,
+    /// Embed payload of points from another collection, referenced in the payload of the results
+    #[prost(message, optional, tag = "9")]
+    #[validate(nested)]
+    pub with_join: ::core::option::Option<WithJoin>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5443,6 +5817,11 @@ pub struct SetPayloadPoints {
     /// Timeout for the request in seconds
     #[prost(uint64, optional, tag = "9")]
     pub timeout: ::core::option::Option<u64>,
+    /// Versions, which points are expected to have.
+    /// If any of them differs, the shard, which holds the point, rejects the payload update.
+    /// Other shards are updated independently.
+    #[prost(message, repeated, tag = "10")]
+    pub if_version: ::prost::alloc::vec::Vec<ExpectedPointVersion>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5867,6 +6246,24 @@ pub struct SearchParams {
     #[prost(message, optional, tag = "5")]
     #[validate(nested)]
     pub acorn: ::core::option::Option<AcornSearchParams>,
+    /// Override the comparator of a multivector for this search
+    /// Ignored by indexes built on fixed-dimensional encodings
+    #[prost(enumeration = "MultiVectorComparator", optional, tag = "6")]
+    pub multivector_comparator: ::core::option::Option<i32>,
+    /// Params relevant to IVF index. Number of lists to scan.
+    /// Larger the value - more accurate the result, more time required for search.
+    #[prost(uint64, optional, tag = "7")]
+    #[validate(range(min = 1))]
+    pub ivf_nprobe: ::core::option::Option<u64>,
+    /// Radius search. If set, graph search finds points with scores better than the radius.
+    /// Used as `score_threshold`, if it is not set. Results are limited by `limit`,
+    /// use the cursor of the query to get all of them page by page.
+    #[prost(float, optional, tag = "8")]
+    pub radius: ::core::option::Option<f32>,
+    /// Search the truncated view of Matryoshka embeddings, see `matryoshka_dim` of the vector.
+    /// The query must have exactly `matryoshka_dim` dimensions.
+    #[prost(bool, optional, tag = "9")]
+    pub matryoshka: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5922,6 +6319,9 @@ pub struct SearchPoints {
     pub shard_key_selector: ::core::option::Option<ShardKeySelector>,
     #[prost(message, optional, tag = "15")]
     pub sparse_indices: ::core::option::Option<SparseIndices>,
+    /// If true, the response also explains how each shard and segment executed the request
+    #[prost(bool, optional, tag = "16")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -5964,6 +6364,31 @@ pub struct WithLookup {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WithJoin {
+    /// Payload field holding a point ID, or an array of point IDs, of the other collection.
+    /// The field doesn't need to be included into the returned payload.
+    #[prost(string, tag = "1")]
+    #[validate(length(min = 1))]
+    pub key: ::prost::alloc::string::String,
+    /// Name of the collection to look up the referenced points in
+    #[prost(string, tag = "2")]
+    pub collection: ::prost::alloc::string::String,
+    /// Options for specifying which payload of the referenced points to include (or not)
+    #[prost(message, optional, tag = "3")]
+    pub with_payload: ::core::option::Option<WithPayloadSelector>,
+    /// Payload key to put the referenced points under. Default is the name of the other collection.
+    /// The request fails if a returned payload already has this key.
+    ///
+    /// A single ID is embedded as `{"id": ..., "payload": ...}`, or `null` if the point doesn't exist.
+    /// An array of IDs is embedded as an array of such objects, skipping missing points.
+    /// The `key` field itself is left as is.
+    #[prost(string, optional, tag = "4")]
+    pub target: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchPointGroups {
     /// Name of the collection
     #[prost(string, tag = "1")]
@@ -6029,7 +6454,7 @@ pub struct SearchPointGroups {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StartFrom {
-    #[prost(oneof = "start_from::Value", tags = "1, 2, 3, 4")]
+    #[prost(oneof = "start_from::Value", tags = "1, 2, 3, 4, 5")]
     pub value: ::core::option::Option<start_from::Value>,
 }
 /// Nested message and enum types in `StartFrom`.
@@ -6046,6 +6471,8 @@ pub mod start_from {
         Timestamp(::prost_wkt_types::Timestamp),
         #[prost(string, tag = "4")]
         Datetime(::prost::alloc::string::String),
+        #[prost(string, tag = "5")]
+        Keyword(::prost::alloc::string::String),
     }
 }
 #[derive(serde::Serialize)]
@@ -6061,6 +6488,37 @@ pub struct OrderBy {
     /// Start from this value
     #[prost(message, optional, tag = "3")]
     pub start_from: ::core::option::Option<StartFrom>,
+    /// Payload keys to order points with equal values of the previous keys
+    #[prost(message, repeated, tag = "4")]
+    pub then_by: ::prost::alloc::vec::Vec<ThenBy>,
+    /// Continue right after this point of the previous page, takes precedence over `start_from`
+    #[prost(message, optional, tag = "5")]
+    pub start_after: ::core::option::Option<OrderByCursor>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ThenBy {
+    /// Payload key to order by
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Ascending or descending order
+    #[prost(enumeration = "Direction", optional, tag = "2")]
+    pub direction: ::core::option::Option<i32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderByCursor {
+    /// Order-by value of the last point
+    #[prost(message, optional, tag = "1")]
+    pub value: ::core::option::Option<OrderValue>,
+    /// Values of `then_by` keys of the last point, empty `OrderValue` if the point has no value
+    #[prost(message, repeated, tag = "2")]
+    pub then_by: ::prost::alloc::vec::Vec<OrderValue>,
+    /// Id of the last point
+    #[prost(message, optional, tag = "3")]
+    pub id: ::core::option::Option<PointId>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6102,6 +6560,10 @@ pub struct ScrollPoints {
     /// If set, overrides global timeout setting for this request. Unit is seconds.
     #[prost(uint64, optional, tag = "11")]
     pub timeout: ::core::option::Option<u64>,
+    /// Embed payload of points from another collection, referenced in the payload of the results
+    #[prost(message, optional, tag = "12")]
+    #[validate(nested)]
+    pub with_join: ::core::option::Option<WithJoin>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -6440,6 +6902,9 @@ pub struct CountPoints {
     /// If set, overrides global timeout setting for this request. Unit is seconds.
     #[prost(uint64, optional, tag = "6")]
     pub timeout: ::core::option::Option<u64>,
+    /// If true, the response also explains how each shard and segment executed the request
+    #[prost(bool, optional, tag = "7")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -6532,7 +6997,7 @@ pub struct FeedbackItem {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FeedbackStrategy {
-    #[prost(oneof = "feedback_strategy::Variant", tags = "1")]
+    #[prost(oneof = "feedback_strategy::Variant", tags = "1, 2, 3")]
     #[validate(nested)]
     pub variant: ::core::option::Option<feedback_strategy::Variant>,
 }
@@ -6545,6 +7010,12 @@ pub mod feedback_strategy {
         /// a * score + sim(confidence^b * c * delta)
         #[prost(message, tag = "1")]
         Naive(super::NaiveFeedbackStrategy),
+        /// alpha * target + beta * mean(relevant) - gamma * mean(non-relevant)
+        #[prost(message, tag = "2")]
+        Rocchio(super::RocchioFeedbackStrategy),
+        /// target * weights, with weights fitted to the feedback scores
+        #[prost(message, tag = "3")]
+        Reweight(super::ReweightFeedbackStrategy),
     }
 }
 #[derive(validator::Validate)]
@@ -6564,6 +7035,56 @@ pub struct NaiveFeedbackStrategy {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RocchioFeedbackStrategy {
+    /// Weight of the target vector. Default is 1.0
+    #[prost(float, optional, tag = "1")]
+    pub alpha: ::core::option::Option<f32>,
+    /// Weight of the relevant examples, scored above the mean. Default is 0.75
+    #[prost(float, optional, tag = "2")]
+    #[validate(range(min = 0.0))]
+    pub beta: ::core::option::Option<f32>,
+    /// Weight of the non-relevant examples, scored below the mean. Default is 0.15
+    #[prost(float, optional, tag = "3")]
+    #[validate(range(min = 0.0))]
+    pub gamma: ::core::option::Option<f32>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReweightFeedbackStrategy {
+    /// Strength of the regularization, which keeps the weights close to 1. Default is 1.0
+    #[prost(float, optional, tag = "1")]
+    #[validate(range(min = 0.0))]
+    pub regularization: ::core::option::Option<f32>,
+}
+/// Sample up to `per_value` random points for each distinct value of a payload field
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StratifiedSample {
+    /// Payload key to stratify by, values are listed by its payload index if it has one
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+    /// Number of points to sample for each distinct value
+    #[prost(uint64, tag = "2")]
+    #[validate(range(min = 1))]
+    pub per_value: u64,
+}
+/// Sample points with probability proportional to a numeric payload field
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WeightedSample {
+    /// Payload key holding the weight of the point
+    #[prost(string, tag = "1")]
+    pub key: ::prost::alloc::string::String,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Formula {
     #[prost(message, optional, tag = "1")]
     #[validate(nested)]
@@ -6784,7 +7305,7 @@ pub struct Rrf {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Query {
-    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "query::Variant", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13")]
     #[validate(nested)]
     pub variant: ::core::option::Option<query::Variant>,
 }
@@ -6827,6 +7348,12 @@ pub mod query {
         /// Search with feedback from some oracle.
         #[prost(message, tag = "11")]
         RelevanceFeedback(super::RelevanceFeedbackInput),
+        /// Sample points for each distinct value of a payload field.
+        #[prost(message, tag = "12")]
+        StratifiedSample(super::StratifiedSample),
+        /// Sample points proportionally to a numeric payload field.
+        #[prost(message, tag = "13")]
+        WeightedSample(super::WeightedSample),
     }
 }
 #[derive(validator::Validate)]
@@ -6932,6 +7459,17 @@ pub struct QueryPoints {
     #[prost(uint64, optional, tag = "15")]
     #[validate(range(min = 1))]
     pub timeout: ::core::option::Option<u64>,
+    /// Continue the results of a previous page, from the `next_cursor` of its response.
+    /// Can't be combined with offset.
+    #[prost(string, optional, tag = "16")]
+    pub cursor: ::core::option::Option<::prost::alloc::string::String>,
+    /// Embed payload of points from another collection, referenced in the payload of the results
+    #[prost(message, optional, tag = "17")]
+    #[validate(nested)]
+    pub with_join: ::core::option::Option<WithJoin>,
+    /// If true, the response also explains how each shard and segment executed the request
+    #[prost(bool, optional, tag = "18")]
+    pub explain: ::core::option::Option<bool>,
 }
 #[derive(validator::Validate)]
 #[derive(serde::Serialize)]
@@ -7033,6 +7571,94 @@ pub struct QueryPointGroups {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FederatedQueryPoints {
+    /// Names of the collections to query, each at most once.
+    /// Collections should have the same parameters of the vectors used by the query.
+    #[prost(string, repeated, tag = "1")]
+    #[validate(length(min = 1, max = 16))]
+    pub collections: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Sub-requests to perform first.
+    /// If present, the query will be performed on the results of the prefetches.
+    #[prost(message, repeated, tag = "2")]
+    #[validate(nested)]
+    pub prefetch: ::prost::alloc::vec::Vec<PrefetchQuery>,
+    /// Query to perform. If missing, returns points ordered by their IDs.
+    #[prost(message, optional, tag = "3")]
+    #[validate(nested)]
+    pub query: ::core::option::Option<Query>,
+    /// Define which vector to use for querying.
+    /// If missing, the default vector is used.
+    #[prost(string, optional, tag = "4")]
+    pub using: ::core::option::Option<::prost::alloc::string::String>,
+    /// Filter conditions - return only those points that satisfy the specified conditions.
+    #[prost(message, optional, tag = "5")]
+    #[validate(nested)]
+    pub filter: ::core::option::Option<Filter>,
+    /// Search params for when there is no prefetch.
+    #[prost(message, optional, tag = "6")]
+    #[validate(nested)]
+    pub params: ::core::option::Option<SearchParams>,
+    /// Return points with scores better than this threshold.
+    #[prost(float, optional, tag = "7")]
+    pub score_threshold: ::core::option::Option<f32>,
+    /// Max number of points. Default is 10.
+    #[prost(uint64, optional, tag = "8")]
+    #[validate(range(min = 1))]
+    pub limit: ::core::option::Option<u64>,
+    /// Offset of the merged result. Skip this many points. Default is 0.
+    #[prost(uint64, optional, tag = "9")]
+    pub offset: ::core::option::Option<u64>,
+    /// Options for specifying which vectors to include into the response.
+    #[prost(message, optional, tag = "10")]
+    pub with_vectors: ::core::option::Option<WithVectorsSelector>,
+    /// Options for specifying which payload to include or not.
+    #[prost(message, optional, tag = "11")]
+    pub with_payload: ::core::option::Option<WithPayloadSelector>,
+    /// Options for specifying read consistency guarantees.
+    #[prost(message, optional, tag = "12")]
+    pub read_consistency: ::core::option::Option<ReadConsistency>,
+    /// The location to use for IDs lookup.
+    /// If not specified - use the queried collection and the 'using' vector.
+    #[prost(message, optional, tag = "13")]
+    pub lookup_from: ::core::option::Option<LookupLocation>,
+    /// If set, overrides global timeout setting for this request. Unit is seconds.
+    #[prost(uint64, optional, tag = "14")]
+    #[validate(range(min = 1))]
+    pub timeout: ::core::option::Option<u64>,
+    /// How to merge the results of the collections.
+    /// If missing, points are merged by score, which requires the scores of all collections to be comparable.
+    #[prost(message, optional, tag = "15")]
+    #[validate(nested)]
+    pub merge: ::core::option::Option<FederatedMerge>,
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FederatedMerge {
+    #[prost(oneof = "federated_merge::Variant", tags = "1, 2")]
+    #[validate(nested)]
+    pub variant: ::core::option::Option<federated_merge::Variant>,
+}
+/// Nested message and enum types in `FederatedMerge`.
+pub mod federated_merge {
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Variant {
+        /// Fuse the results of the collections.
+        #[prost(enumeration = "super::Fusion", tag = "1")]
+        Fusion(i32),
+        /// Apply reciprocal rank fusion to the results of the collections.
+        /// Weights, if specified, correspond to the collections.
+        #[prost(message, tag = "2")]
+        Rrf(super::Rrf),
+    }
+}
+#[derive(validator::Validate)]
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FacetCounts {
     /// Name of the collection
     #[prost(string, tag = "1")]
@@ -7387,7 +8013,7 @@ pub struct UpdateResult {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct OrderValue {
-    #[prost(oneof = "order_value::Variant", tags = "1, 2")]
+    #[prost(oneof = "order_value::Variant", tags = "1, 2, 3")]
     pub variant: ::core::option::Option<order_value::Variant>,
 }
 /// Nested message and enum types in `OrderValue`.
@@ -7400,6 +8026,8 @@ pub mod order_value {
         Int(i64),
         #[prost(double, tag = "2")]
         Float(f64),
+        #[prost(string, tag = "3")]
+        Keyword(::prost::alloc::string::String),
     }
 }
 #[derive(serde::Serialize)]
@@ -7427,6 +8055,12 @@ pub struct ScoredPoint {
     /// Order by value
     #[prost(message, optional, tag = "8")]
     pub order_value: ::core::option::Option<OrderValue>,
+    /// Order-by values of `then_by` keys, empty `OrderValue` if the point has no value
+    #[prost(message, repeated, tag = "9")]
+    pub then_by_values: ::prost::alloc::vec::Vec<OrderValue>,
+    /// Value of the field, which the point was drawn for by a stratified sample
+    #[prost(message, optional, tag = "10")]
+    pub stratum: ::core::option::Option<FacetValue>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7485,6 +8119,9 @@ pub struct SearchResponse {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
+    /// How the request was executed, returned if `explain` is set
+    #[prost(message, optional, tag = "4")]
+    pub explain: ::core::option::Option<Explanation>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7497,6 +8134,13 @@ pub struct QueryResponse {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
+    /// Cursor to fetch the next page of results.
+    /// Missing if there are no more results, or if results of the query are not ordered.
+    #[prost(string, optional, tag = "4")]
+    pub next_cursor: ::core::option::Option<::prost::alloc::string::String>,
+    /// How the request was executed, returned if `explain` is set
+    #[prost(message, optional, tag = "5")]
+    pub explain: ::core::option::Option<Explanation>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7525,6 +8169,28 @@ pub struct QueryGroupsResponse {
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FederatedScoredPoint {
+    /// Name of the collection the point belongs to
+    #[prost(string, tag = "1")]
+    pub collection_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub point: ::core::option::Option<ScoredPoint>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FederatedQueryResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub result: ::prost::alloc::vec::Vec<FederatedScoredPoint>,
+    /// Time spent to process
+    #[prost(double, tag = "2")]
+    pub time: f64,
+    #[prost(message, optional, tag = "3")]
+    pub usage: ::core::option::Option<Usage>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BatchResult {
     #[prost(message, repeated, tag = "1")]
     pub result: ::prost::alloc::vec::Vec<ScoredPoint>,
@@ -7564,6 +8230,9 @@ pub struct CountResponse {
     pub time: f64,
     #[prost(message, optional, tag = "3")]
     pub usage: ::core::option::Option<Usage>,
+    /// How the request was executed, returned if `explain` is set
+    #[prost(message, optional, tag = "4")]
+    pub explain: ::core::option::Option<Explanation>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7603,6 +8272,12 @@ pub struct RetrievedPoint {
     /// Order-by value
     #[prost(message, optional, tag = "6")]
     pub order_value: ::core::option::Option<OrderValue>,
+    /// Point version
+    #[prost(uint64, optional, tag = "7")]
+    pub version: ::core::option::Option<u64>,
+    /// Order-by values of `then_by` keys, empty `OrderValue` if the point has no value
+    #[prost(message, repeated, tag = "8")]
+    pub then_by_values: ::prost::alloc::vec::Vec<OrderValue>,
 }
 #[derive(serde::Serialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -7813,6 +8488,85 @@ pub struct HardwareUsage {
     pub vector_io_write: u64,
 }
 #[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Explanation {
+    /// Stages, which read points from the segments, in the order of the request
+    #[prost(message, repeated, tag = "1")]
+    pub stages: ::prost::alloc::vec::Vec<StageExplanation>,
+    /// Selected shards, which have no replica on the peer that explains the request
+    #[prost(uint32, repeated, tag = "2")]
+    pub unexplained_shards: ::prost::alloc::vec::Vec<u32>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StageExplanation {
+    /// Position of the stage in the request, e.g. `prefetch\[0\].prefetch\[1\]`, or `root`
+    #[prost(string, tag = "1")]
+    pub stage: ::prost::alloc::string::String,
+    #[prost(enumeration = "StageKind", tag = "2")]
+    pub kind: i32,
+    /// Name of the vector, used by the search
+    #[prost(string, optional, tag = "3")]
+    pub using: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "4")]
+    pub shards: ::prost::alloc::vec::Vec<ShardExplanation>,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShardExplanation {
+    #[prost(uint32, tag = "1")]
+    pub shard_id: u32,
+    /// Number of points, returned by the stage from the shard
+    #[prost(uint64, tag = "2")]
+    pub found: u64,
+    /// Time spent by the stage in the shard, in seconds
+    #[prost(double, tag = "3")]
+    pub time: f64,
+    #[prost(message, repeated, tag = "4")]
+    pub segments: ::prost::alloc::vec::Vec<SegmentStageExplanation>,
+}
+/// Number of points matching a filter, estimated from the payload indexes
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimatedCardinality {
+    #[prost(uint64, tag = "1")]
+    pub min: u64,
+    #[prost(uint64, tag = "2")]
+    pub exp: u64,
+    #[prost(uint64, tag = "3")]
+    pub max: u64,
+}
+#[derive(serde::Serialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SegmentStageExplanation {
+    #[prost(string, tag = "1")]
+    pub segment_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "SearchStrategy", tag = "2")]
+    pub strategy: i32,
+    /// Number of available points in the segment
+    #[prost(uint64, tag = "3")]
+    pub available_points: u64,
+    #[prost(message, optional, tag = "4")]
+    pub estimated_cardinality: ::core::option::Option<EstimatedCardinality>,
+    /// Exact number of points in the segment, which match the filter
+    #[prost(uint64, tag = "5")]
+    pub actual_cardinality: u64,
+    /// Payload indexes, which can select the matching points without a full scan
+    #[prost(string, repeated, tag = "6")]
+    pub indexes_used: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Number of points, found by the segment: the top of a search, or all points matching a filter
+    #[prost(uint64, tag = "7")]
+    pub found: u64,
+    /// Time spent by the stage in the segment, in seconds
+    #[prost(double, tag = "8")]
+    pub time: f64,
+}
+#[derive(serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum WriteOrderingType {
@@ -8122,6 +8876,90 @@ impl UpdateStatus {
         }
     }
 }
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum StageKind {
+    Search = 0,
+    Scroll = 1,
+    Count = 2,
+}
+impl StageKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            StageKind::Search => "Search",
+            StageKind::Scroll => "Scroll",
+            StageKind::Count => "Count",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Search" => Some(Self::Search),
+            "Scroll" => Some(Self::Scroll),
+            "Count" => Some(Self::Count),
+            _ => None,
+        }
+    }
+}
+/// How a segment finds the points of a search or a filter
+#[derive(serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SearchStrategy {
+    /// All matching vectors are scored without approximation, as requested by `exact` param
+    Exact = 0,
+    /// All points of the segment are checked against the filter and scored
+    FullScan = 1,
+    /// Points are selected with payload indexes first, and then checked and scored
+    PayloadIndex = 2,
+    /// Search in the HNSW graph, checking the filter on visited points
+    Hnsw = 3,
+    /// Search in the HNSW graph, which also explores neighbours of filtered out points
+    Acorn = 4,
+    /// Search in the Vamana graph
+    Vamana = 5,
+    /// Search in the nearest lists of the IVF index
+    Ivf = 6,
+    /// Search in the inverted index of sparse vectors
+    SparseIndex = 7,
+}
+impl SearchStrategy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SearchStrategy::Exact => "Exact",
+            SearchStrategy::FullScan => "FullScan",
+            SearchStrategy::PayloadIndex => "PayloadIndex",
+            SearchStrategy::Hnsw => "Hnsw",
+            SearchStrategy::Acorn => "Acorn",
+            SearchStrategy::Vamana => "Vamana",
+            SearchStrategy::Ivf => "Ivf",
+            SearchStrategy::SparseIndex => "SparseIndex",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Exact" => Some(Self::Exact),
+            "FullScan" => Some(Self::FullScan),
+            "PayloadIndex" => Some(Self::PayloadIndex),
+            "Hnsw" => Some(Self::Hnsw),
+            "Acorn" => Some(Self::Acorn),
+            "Vamana" => Some(Self::Vamana),
+            "Ivf" => Some(Self::Ivf),
+            "SparseIndex" => Some(Self::SparseIndex),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod points_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -8889,6 +9727,32 @@ pub mod points_client {
             req.extensions_mut().insert(GrpcMethod::new("qdrant.Points", "QueryGroups"));
             self.inner.unary(req, path, codec).await
         }
+        /// Universally query points in several collections at once and merge the results.
+        pub async fn federated_query(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FederatedQueryPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::FederatedQueryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/qdrant.Points/FederatedQuery",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("qdrant.Points", "FederatedQuery"));
+            self.inner.unary(req, path, codec).await
+        }
         /// Perform facet counts.
         /// For each value in the field, count the number of points that have this
         /// value and match the conditions.
@@ -9204,6 +10068,14 @@ pub mod points_server {
             tonic::Response<super::QueryGroupsResponse>,
             tonic::Status,
         >;
+        /// Universally query points in several collections at once and merge the results.
+        async fn federated_query(
+            &self,
+            request: tonic::Request<super::FederatedQueryPoints>,
+        ) -> std::result::Result<
+            tonic::Response<super::FederatedQueryResponse>,
+            tonic::Status,
+        >;
         /// Perform facet counts.
         /// For each value in the field, count the number of points that have this
         /// value and match the conditions.
@@ -10517,6 +11389,52 @@ pub mod points_server {
                     };
                     Box::pin(fut)
                 }
+                "/qdrant.Points/FederatedQuery" => {
+                    #[allow(non_camel_case_types)]
+                    struct FederatedQuerySvc<T: Points>(pub Arc<T>);
+                    impl<
+                        T: Points,
+                    > tonic::server::UnaryService<super::FederatedQueryPoints>
+                    for FederatedQuerySvc<T> {
+                        type Response = super::FederatedQueryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FederatedQueryPoints>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Points>::federated_query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FederatedQuerySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/qdrant.Points/Facet" => {
                     #[allow(non_camel_case_types)]
                     struct FacetSvc<T: Points>(pub Arc<T>);
@@ -10739,6 +11657,9 @@ pub struct SyncPointsInternal {
     /// When absent, falls back to `wait` (backward compatible with older nodes).
     #[prost(enumeration = "WaitUntil", optional, tag = "4")]
     pub wait_override: ::core::option::Option<i32>,
+    /// Versions of points copied from another replica
+    #[prost(message, repeated, tag = "5")]
+    pub point_versions: ::prost::alloc::vec::Vec<ExpectedPointVersion>,
 }
 #[derive(serde::Serialize)]
 #[derive(validator::Validate)]
@@ -10756,6 +11677,9 @@ pub struct UpsertPointsInternal {
     /// When absent, falls back to `wait` (backward compatible with older nodes).
     #[prost(enumeration = "WaitUntil", optional, tag = "4")]
     pub wait_override: ::core::option::Option<i32>,
+    /// Versions of points copied from another replica
+    #[prost(message, repeated, tag = "5")]
+    pub point_versions: ::prost::alloc::vec::Vec<ExpectedPointVersion>,
 }
 #[derive(serde::Serialize)]
 #[derive(validator::Validate)]
@@ -11385,6 +12309,9 @@ pub struct QueryShardPoints {
     pub with_payload: ::core::option::Option<WithPayloadSelector>,
     #[prost(message, optional, tag = "10")]
     pub with_vectors: ::core::option::Option<WithVectorsSelector>,
+    /// Return only points after this position, `offset` is the expected number of points before it
+    #[prost(message, optional, tag = "11")]
+    pub start_after: ::core::option::Option<query_shard_points::ScorePosition>,
 }
 /// Nested message and enum types in `QueryShardPoints`.
 pub mod query_shard_points {
@@ -11393,7 +12320,7 @@ pub mod query_shard_points {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Query {
-        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7")]
+        #[prost(oneof = "query::Score", tags = "1, 2, 3, 4, 5, 6, 7, 8, 9")]
         pub score: ::core::option::Option<query::Score>,
     }
     /// Nested message and enum types in `Query`.
@@ -11423,6 +12350,12 @@ pub mod query_shard_points {
             /// Parameterized RRF fusion
             #[prost(message, tag = "7")]
             Rrf(super::super::Rrf),
+            /// Sample points for each distinct value of a payload field
+            #[prost(message, tag = "8")]
+            StratifiedSample(super::super::StratifiedSample),
+            /// Sample points proportionally to a numeric payload field
+            #[prost(message, tag = "9")]
+            WeightedSample(super::super::WeightedSample),
         }
     }
     #[derive(serde::Serialize)]
@@ -11444,6 +12377,15 @@ pub mod query_shard_points {
         #[prost(float, optional, tag = "7")]
         pub score_threshold: ::core::option::Option<f32>,
     }
+    #[derive(serde::Serialize)]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ScorePosition {
+        #[prost(float, tag = "1")]
+        pub score: f32,
+        #[prost(message, optional, tag = "2")]
+        pub id: ::core::option::Option<super::PointId>,
+    }
 }
 #[derive(serde::Serialize)]
 #[derive(validator::Validate)]
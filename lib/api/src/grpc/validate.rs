@@ -2,7 +2,7 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 
 use common::validation::{validate_range_generic, validate_shard_different_peers};
-use segment::data_types::index::validate_integer_index_params;
+use segment::data_types::index::{validate_composite_index_fields, validate_integer_index_params};
 use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::qdrant as grpc;
@@ -263,6 +263,7 @@ impl Validate for grpc::FieldCondition {
             values_count,
             is_empty,
             is_null,
+            keyword_range,
         } = self;
 
         let all_fields_none = r#match.is_none()
@@ -273,7 +274,8 @@ impl Validate for grpc::FieldCondition {
             && geo_polygon.is_none()
             && values_count.is_none()
             && is_empty.is_none()
-            && is_null.is_none();
+            && is_null.is_none()
+            && keyword_range.is_none();
 
         if all_fields_none {
             let mut errors = ValidationErrors::new();
@@ -382,13 +384,24 @@ impl Validate for super::qdrant::query::Variant {
             grpc::query::Variant::Formula(q) => q.validate(),
             grpc::query::Variant::Rrf(q) => q.validate(),
             grpc::query::Variant::RelevanceFeedback(q) => q.validate(),
+            grpc::query::Variant::StratifiedSample(q) => q.validate(),
             grpc::query::Variant::Sample(_)
+            | grpc::query::Variant::WeightedSample(_)
             | grpc::query::Variant::Fusion(_)
             | grpc::query::Variant::OrderBy(_) => Ok(()),
         }
     }
 }
 
+impl Validate for super::qdrant::federated_merge::Variant {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            grpc::federated_merge::Variant::Fusion(_) => Ok(()),
+            grpc::federated_merge::Variant::Rrf(rrf) => rrf.validate(),
+        }
+    }
+}
+
 impl Validate for super::qdrant::vector_input::Variant {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
@@ -443,6 +456,12 @@ impl Validate for grpc::feedback_strategy::Variant {
             grpc::feedback_strategy::Variant::Naive(naive_feedback_strategy) => {
                 naive_feedback_strategy.validate()
             }
+            grpc::feedback_strategy::Variant::Rocchio(rocchio_feedback_strategy) => {
+                rocchio_feedback_strategy.validate()
+            }
+            grpc::feedback_strategy::Variant::Reweight(reweight_feedback_strategy) => {
+                reweight_feedback_strategy.validate()
+            }
         }
     }
 }
@@ -509,6 +528,9 @@ impl Validate for super::qdrant::payload_index_params::IndexParams {
             grpc::payload_index_params::IndexParams::BoolIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::DatetimeIndexParams(_) => Ok(()),
             grpc::payload_index_params::IndexParams::UuidIndexParams(_) => Ok(()),
+            grpc::payload_index_params::IndexParams::CompositeIndexParams(
+                composite_index_params,
+            ) => composite_index_params.validate(),
         }
     }
 }
@@ -526,6 +548,12 @@ impl Validate for super::qdrant::IntegerIndexParams {
     }
 }
 
+impl Validate for super::qdrant::CompositeIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        validate_composite_index_fields(self.fields.iter().map(|field| &field.key))
+    }
+}
+
 impl Validate for super::qdrant::points_selector::PointsSelectorOneOf {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
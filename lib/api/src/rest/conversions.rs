@@ -85,6 +85,8 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector,
             shard_key,
             order_value,
+            then_by_values,
+            stratum,
         } = value;
         ScoredPoint {
             id,
@@ -94,6 +96,8 @@ impl From<segment::types::ScoredPoint> for ScoredPoint {
             vector: vector.map(VectorStructOutput::from),
             shard_key,
             order_value,
+            then_by_values,
+            stratum: stratum.map(FacetValue::from),
         }
     }
 }
@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use ahash::HashMap;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
+use segment::data_types::explain::SegmentExplanation;
 use segment::types::ShardKey;
 use serde::{self, Deserialize, Serialize};
 
@@ -48,6 +49,8 @@ pub struct ApiResponse<D> {
     pub time: f64,
     #[serde(skip_serializing_if = "is_usage_none_or_empty")]
     pub usage: Option<Usage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explain: Option<Explanation>,
 }
 
 /// Usage of the hardware resources, spent to process the request
@@ -129,6 +132,76 @@ pub struct ModelUsage {
     pub tokens: u64,
 }
 
+/// How the request was executed, returned if `explain` parameter is set
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Explanation {
+    /// Stages, which read points from the segments, in the order of the request
+    pub stages: Vec<StageExplanation>,
+    /// Selected shards, which have no replica on the peer that explains the request
+    pub unexplained_shards: Vec<u32>,
+}
+
+impl Explanation {
+    /// Merge explanations of the same request in other shards
+    pub fn merge(&mut self, other: Self) {
+        for stage in other.stages {
+            match self
+                .stages
+                .iter_mut()
+                .find(|existing| existing.stage == stage.stage)
+            {
+                Some(existing) => existing.shards.extend(stage.shards),
+                None => self.stages.push(stage),
+            }
+        }
+        self.unexplained_shards.extend(other.unexplained_shards);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct StageExplanation {
+    /// Position of the stage in the request, e.g. `prefetch[0].prefetch[1]`, or `root`
+    pub stage: String,
+    pub kind: StageKind,
+    /// Name of the vector, used by the search
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub using: Option<String>,
+    pub shards: Vec<ShardExplanation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StageKind {
+    Search,
+    Scroll,
+    Count,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ShardExplanation {
+    pub shard_id: u32,
+    /// Number of points, returned by the stage from the shard
+    pub found: usize,
+    /// Time spent by the stage in the shard, in seconds
+    pub time: f64,
+    pub segments: Vec<SegmentStageExplanation>,
+}
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SegmentStageExplanation {
+    pub segment_id: String,
+    #[serde(flatten)]
+    pub explanation: SegmentExplanation,
+    /// Number of points, found by the segment: the top of a search, or all points matching a filter
+    pub found: usize,
+    /// Time spent by the stage in the segment, in seconds
+    pub time: f64,
+}
+
 #[derive(Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct CollectionDescription {
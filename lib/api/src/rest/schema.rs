@@ -12,15 +12,16 @@ use segment::data_types::order_by::OrderByInterface;
 use segment::data_types::vectors::{DenseVector, MultiDenseVector};
 use segment::json_path::JsonPath;
 use segment::types::{
-    Condition, Filter, GeoPoint, IntPayloadType, Payload, PointIdType, SearchParams, ShardKey,
-    VectorNameBuf, WithPayloadInterface, WithVector,
+    Condition, ExpectedPointVersion, Filter, GeoPoint, IntPayloadType, Payload, PointIdType,
+    QuantizationConfig, QuantizationSearchParams, SearchParams, ShardKey, VectorNameBuf,
+    WithPayloadInterface, WithVector,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use sparse::common::sparse_vector::SparseVector;
 use validator::{Validate, ValidationError, ValidationErrors};
 
-use crate::rest::validate::validate_relevance_feedback_input;
+use crate::rest::validate::{validate_group_lookup, validate_relevance_feedback_input};
 
 /// Reject zero-length dense vectors at the API boundary.
 ///
@@ -475,6 +476,12 @@ pub struct ScoredPoint {
     /// Order-by value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_value: Option<segment::data_types::order_by::OrderValue>,
+    /// Order-by values of `then_by` keys
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub then_by_values: Vec<Option<segment::data_types::order_by::OrderValue>>,
+    /// Value of the field, which the point was drawn for by a stratified sample
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stratum: Option<FacetValue>,
 }
 
 /// Point data
@@ -494,6 +501,13 @@ pub struct Record {
     pub shard_key: Option<segment::types::ShardKey>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_value: Option<segment::data_types::order_by::OrderValue>,
+    /// Order-by values of `then_by` keys
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub then_by_values: Vec<Option<segment::data_types::order_by::OrderValue>>,
+    /// Number of writes made to the point, can be used as `if_version` precondition of the next update.
+    /// Only present if point versions are enabled for the collection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<segment::types::SeqNumberType>,
 }
 
 /// Vector data separator for named and unnamed modes
@@ -606,6 +620,11 @@ pub struct QueryRequestInternal {
     /// Note: the other collection vectors should have the same vector size as the 'using' vector in the current collection
     #[serde(default)]
     pub lookup_from: Option<LookupLocation>,
+
+    /// Continue the results of a previous page, from the `next_cursor` of its response.
+    /// Unlike `offset`, points skipped by the cursor are not fetched again. Can't be combined with `offset`.
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -614,6 +633,9 @@ pub struct QueryRequest {
     #[serde(flatten)]
     pub internal: QueryRequestInternal,
     pub shard_key: Option<ShardKeySelector>,
+    /// Embed payload of points from another collection, referenced in the payload of the results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_join: Option<WithJoin>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -625,6 +647,51 @@ pub struct QueryRequestBatch {
 #[derive(Debug, Serialize, JsonSchema)]
 pub struct QueryResponse {
     pub points: Vec<ScoredPoint>,
+    /// Cursor to fetch the next page of results.
+    /// Missing if there are no more results, or if results of the query are not ordered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// Query several collections at once and merge the results.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct FederatedQueryRequest {
+    /// Names of the collections to query, each at most once.
+    /// Collections should have the same parameters of the vectors used by the query.
+    #[validate(length(min = 1, max = 16))]
+    pub collections: Vec<String>,
+    #[validate(nested)]
+    #[serde(flatten)]
+    pub internal: QueryRequestInternal,
+    /// How to merge the results of the collections.
+    /// If missing, points are merged by score, which requires the scores of all collections to be comparable.
+    #[validate(nested)]
+    pub merge: Option<FederatedMerge>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum FederatedMerge {
+    /// Fuse the results of the collections.
+    Fusion(FusionQuery),
+
+    /// Apply reciprocal rank fusion to the results of the collections.
+    /// Weights, if specified, correspond to the collections.
+    Rrf(RrfQuery),
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FederatedQueryResponse {
+    pub points: Vec<FederatedScoredPoint>,
+}
+
+/// Search result tagged with its source collection
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FederatedScoredPoint {
+    /// Name of the collection the point belongs to
+    pub collection: String,
+    #[serde(flatten)]
+    pub point: ScoredPoint,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -906,6 +973,8 @@ pub struct FeedbackItem {
 #[serde(untagged)]
 pub enum FeedbackStrategy {
     Naive(NaiveFeedbackStrategy),
+    Rocchio(RocchioFeedbackStrategy),
+    Reweight(ReweightFeedbackStrategy),
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -922,6 +991,43 @@ pub struct NaiveFeedbackStrategyParams {
     pub c: f32,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct RocchioFeedbackStrategy {
+    #[validate(nested)]
+    pub rocchio: RocchioFeedbackStrategyParams,
+}
+
+/// Classic Rocchio update of the query vector: `alpha * target + beta * mean(relevant) - gamma * mean(non-relevant)`.
+///
+/// Examples scored above the mean feedback score are relevant, the ones scored below it are non-relevant.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct RocchioFeedbackStrategyParams {
+    /// Weight of the target vector. Default is 1.0
+    pub alpha: Option<f32>,
+    /// Weight of the relevant examples. Default is 0.75
+    #[validate(range(min = 0.0))]
+    pub beta: Option<f32>,
+    /// Weight of the non-relevant examples. Default is 0.15
+    #[validate(range(min = 0.0))]
+    pub gamma: Option<f32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ReweightFeedbackStrategy {
+    #[validate(nested)]
+    pub reweight: ReweightFeedbackStrategyParams,
+}
+
+/// Reweight the dimensions of the target vector, so its similarity to the examples follows their feedback scores.
+///
+/// The weights are fitted with ridge regression.
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+pub struct ReweightFeedbackStrategyParams {
+    /// Strength of the regularization, which keeps the weights close to 1. Default is 1.0
+    #[validate(range(min = 0.0))]
+    pub regularization: Option<f32>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum Expression {
@@ -1081,6 +1187,30 @@ pub struct DecayParamsExpression {
 #[serde(rename_all = "snake_case")]
 pub enum Sample {
     Random,
+    /// Sample up to `per_value` random points for each distinct value of a payload field
+    Stratified(StratifiedSample),
+    /// Sample points with probability proportional to a numeric payload field
+    Weighted(WeightedSample),
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct StratifiedSample {
+    /// Payload field to stratify by.
+    /// Points with several values may be sampled for any of them, points without a keyword, integer,
+    /// UUID or bool value are skipped. Values are listed by the payload index of the field, if it has one.
+    pub key: JsonPath,
+    /// Number of points to sample for each distinct value
+    #[validate(range(min = 1))]
+    pub per_value: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct WeightedSample {
+    /// Payload field holding the weight of the point.
+    /// Points without a positive numeric value are skipped.
+    pub key: JsonPath,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -1111,6 +1241,32 @@ pub enum WithLookupInterface {
     WithLookup(WithLookup),
 }
 
+/// Embed payload of points from another collection, referenced by IDs in the payload of each result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WithJoin {
+    /// Payload field holding a point ID, or an array of point IDs, of the other collection.
+    /// The field doesn't need to be included into the returned payload.
+    #[schemars(length(min = 1))]
+    pub key: JsonPath,
+
+    /// Name of the collection to look up the referenced points in
+    #[serde(rename = "collection")]
+    pub collection_name: String,
+
+    /// Options for specifying which payload of the referenced points to include (or not)
+    #[serde(default = "default_with_payload")]
+    pub with_payload: Option<WithPayloadInterface>,
+
+    /// Payload key to put the referenced points under. Default is the name of the other collection.
+    /// The request fails if a returned payload already has this key.
+    ///
+    /// A single ID is embedded as `{"id": ..., "payload": ...}`, or `null` if the point doesn't exist.
+    /// An array of IDs is embedded as an array of such objects, skipping missing points.
+    /// The `key` field itself is left as is.
+    #[serde(default)]
+    pub target: Option<String>,
+}
+
 /// Defines a location to use for looking up the vector.
 /// Specifies collection and vector field name.
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq)]
@@ -1128,13 +1284,74 @@ pub struct LookupLocation {
     pub shard_key: Option<ShardKeySelector>,
 }
 
+/// Payload field to group by, or several fields to group by the combination of their values
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum GroupByInterface {
+    Field(JsonPath),
+    Fields(Vec<JsonPath>),
+}
+
+impl GroupByInterface {
+    pub fn into_paths(self) -> Vec<JsonPath> {
+        match self {
+            GroupByInterface::Field(path) => vec![path],
+            GroupByInterface::Fields(paths) => paths,
+        }
+    }
+
+    pub fn paths(&self) -> &[JsonPath] {
+        match self {
+            GroupByInterface::Field(path) => std::slice::from_ref(path),
+            GroupByInterface::Fields(paths) => paths,
+        }
+    }
+}
+
+impl From<JsonPath> for GroupByInterface {
+    fn from(path: JsonPath) -> Self {
+        GroupByInterface::Field(path)
+    }
+}
+
+impl Validate for GroupByInterface {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        if self.paths().is_empty() {
+            let mut err = ValidationError::new("empty_group_by");
+            err.message = Some(Cow::Borrowed("must contain at least one field"));
+            let mut errors = ValidationErrors::new();
+            errors.add("group_by", err);
+            return Err(errors);
+        }
+        Ok(())
+    }
+}
+
+/// Second level of grouping, applied to the points of each group
 #[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+pub struct SubgroupRequest {
+    /// Payload field to group the points of each group by, or several fields
+    #[validate(nested)]
+    pub group_by: GroupByInterface,
+
+    /// Maximum amount of points to return per subgroup. Default is 3.
+    #[validate(range(min = 1))]
+    pub group_size: Option<usize>,
+
+    /// Maximum amount of subgroups to return per group. Default is 10.
+    #[validate(range(min = 1))]
+    pub limit: Option<usize>,
+}
+
+#[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[validate(schema(function = "validate_base_group_request"))]
 pub struct BaseGroupRequest {
     /// Payload field to group by, must be a string or number field.
     /// If the field contains more than 1 value, all values will be used for grouping.
     /// One point can be in multiple groups.
-    #[schemars(length(min = 1))]
-    pub group_by: JsonPath,
+    /// If several fields are given, points are grouped by the combination of their values.
+    #[validate(nested)]
+    pub group_by: GroupByInterface,
 
     /// Maximum amount of points to return per group
     #[validate(range(min = 1))]
@@ -1144,8 +1361,13 @@ pub struct BaseGroupRequest {
     #[validate(range(min = 1))]
     pub limit: u32,
 
-    /// Look for points in another collection using the group ids
+    /// Look for points in another collection using the group ids.
+    /// Not supported when grouping by several fields.
     pub with_lookup: Option<WithLookupInterface>,
+
+    /// Group the hits of each group again, by another field
+    #[validate(nested)]
+    pub subgroups: Option<SubgroupRequest>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone)]
@@ -1216,12 +1438,14 @@ pub struct SearchRequestInternal {
 }
 
 #[derive(Validate, Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[validate(schema(function = "validate_query_base_group_request"))]
 pub struct QueryBaseGroupRequest {
     /// Payload field to group by, must be a string or number field.
     /// If the field contains more than 1 value, all values will be used for grouping.
     /// One point can be in multiple groups.
-    #[schemars(length(min = 1))]
-    pub group_by: JsonPath,
+    /// If several fields are given, points are grouped by the combination of their values.
+    #[validate(nested)]
+    pub group_by: GroupByInterface,
 
     /// Maximum amount of points to return per group. Default is 3.
     #[validate(range(min = 1))]
@@ -1231,8 +1455,23 @@ pub struct QueryBaseGroupRequest {
     #[validate(range(min = 1))]
     pub limit: Option<usize>,
 
-    /// Look for points in another collection using the group ids
+    /// Look for points in another collection using the group ids.
+    /// Not supported when grouping by several fields.
     pub with_lookup: Option<WithLookupInterface>,
+
+    /// Group the hits of each group again, by another field
+    #[validate(nested)]
+    pub subgroups: Option<SubgroupRequest>,
+}
+
+fn validate_base_group_request(request: &BaseGroupRequest) -> Result<(), ValidationError> {
+    validate_group_lookup(&request.group_by, request.with_lookup.as_ref())
+}
+
+fn validate_query_base_group_request(
+    request: &QueryBaseGroupRequest,
+) -> Result<(), ValidationError> {
+    validate_group_lookup(&request.group_by, request.with_lookup.as_ref())
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
@@ -1378,7 +1617,7 @@ pub struct FacetRequest {
     pub shard_key: Option<ShardKeySelector>,
 }
 
-#[derive(Debug, Serialize, JsonSchema)]
+#[derive(Clone, Debug, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum FacetValue {
     String(String),
@@ -1397,6 +1636,227 @@ pub struct FacetResponse {
     pub hits: Vec<FacetValueHit>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct QuantizationTuningRequest {
+    /// Define which vector name to tune quantization for. If missing, the default vector is used.
+    pub using: Option<VectorNameBuf>,
+    /// Sample only points which satisfy these conditions
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    /// How many random points to sample. Default is 1000.
+    #[validate(range(min = 2, max = 100000))]
+    pub sample: Option<usize>,
+    /// How many of the sampled points to use as queries. Default is 100.
+    #[validate(range(min = 1, max = 10000))]
+    pub queries: Option<usize>,
+    /// How many nearest neighbours of each query to compare with exact search. Default is 10.
+    #[validate(range(min = 1, max = 1000))]
+    pub limit: Option<usize>,
+    /// Quantization configs to evaluate. If missing, a default set of scalar, product, binary and
+    /// turbo quantization configs is evaluated.
+    #[validate(nested)]
+    pub candidates: Option<Vec<QuantizationTuningCandidate>>,
+    /// Minimal recall the recommended config should reach on the sample.
+    /// If missing, no config is recommended.
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub target_recall: Option<f64>,
+    /// If true - apply the recommended quantization config to the vector. Default: false
+    ///
+    /// Oversampling and rescoring are not part of the quantization config, so searches must use
+    /// `search_params` of the response to reach the target recall.
+    pub apply: Option<bool>,
+    /// Specify in which shards to sample points, if not specified - sample from all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct QuantizationTuningCandidate {
+    /// Quantization config to evaluate
+    #[validate(nested)]
+    pub quantization: QuantizationConfig,
+    /// Search params to evaluate the config with
+    #[validate(nested)]
+    pub params: Option<QuantizationSearchParams>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QuantizationTuningResult {
+    /// Evaluated quantization config
+    pub quantization: QuantizationConfig,
+    /// Search params used for evaluation
+    pub params: QuantizationSearchParams,
+    /// Fraction of exact nearest neighbours, also found with this config on the sample
+    pub recall: f64,
+    /// Estimated size of quantized vectors of the whole collection, in bytes
+    pub memory_bytes: usize,
+    /// Average time of a full-scan search over the sample, in milliseconds
+    pub latency_ms: f64,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QuantizationTuningResponse {
+    /// Number of sampled points
+    pub sample: usize,
+    /// Average time of an exact full-scan search over the sample, in milliseconds
+    pub exact_latency_ms: f64,
+    /// Evaluation results, in order of candidates
+    pub results: Vec<QuantizationTuningResult>,
+    /// The cheapest config, which reaches `target_recall`
+    pub recommended: Option<QuantizationTuningResult>,
+    /// Search params, which searches must use for the recommended config to reach `target_recall`
+    pub search_params: Option<SearchParams>,
+    /// Whether the recommended config was applied to the collection
+    pub applied: bool,
+}
+
+/// Clustering algorithm
+///
+/// * `kmeans` - Lloyd's k-means, every iteration visits all clustered points
+/// * `mini_batch_kmeans` - every iteration updates the centroids from a random batch of points. Faster on large sets of points, but less precise
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ClusteringMethod {
+    #[default]
+    Kmeans,
+    MiniBatchKmeans,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct ClusteringRequest {
+    /// Define which vector name to cluster by. If missing, the default vector is used.
+    pub using: Option<VectorNameBuf>,
+    /// Cluster only points which satisfy these conditions
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    /// Number of clusters
+    #[validate(range(min = 1, max = 10000))]
+    pub clusters: usize,
+    /// How many random points to cluster, at most 100000.
+    /// If missing, all points are clustered, which fails if there are more than 100000 of them.
+    #[validate(range(min = 1, max = 100000))]
+    pub sample: Option<usize>,
+    /// Clustering algorithm. Default is `kmeans`.
+    pub method: Option<ClusteringMethod>,
+    /// Maximal number of iterations of the algorithm. Default is 100.
+    #[validate(range(min = 1))]
+    pub max_iterations: Option<usize>,
+    /// Number of random points in each iteration of `mini_batch_kmeans`. Default is 1024.
+    #[validate(range(min = 1))]
+    pub batch_size: Option<usize>,
+    /// If true - return the cluster of every clustered point. Default: true
+    pub with_assignments: Option<bool>,
+    /// Payload field to write the cluster id of every clustered point into.
+    /// Requires write access. Payload is written cluster by cluster, so if writing fails
+    /// or times out, only a part of the points may be updated.
+    /// If missing, payload is not changed.
+    #[validate(length(min = 1))]
+    pub payload_key: Option<String>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct PointCluster {
+    /// Id of the cluster
+    pub id: usize,
+    /// Center of the cluster
+    pub centroid: DenseVector,
+    /// Number of clustered points in the cluster
+    pub size: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct ClusterAssignment {
+    /// Id of the point
+    pub id: PointIdType,
+    /// Id of the cluster of the point
+    pub cluster: usize,
+    /// Euclidean distance from the point to the centroid of its cluster
+    pub distance: f32,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct ClusteringResponse {
+    /// Number of clustered points
+    pub points: usize,
+    /// Found clusters, in order of their ids
+    pub clusters: Vec<PointCluster>,
+    /// Sum of squared distances from the points to the centroids of their clusters
+    pub inertia: f64,
+    /// Cluster of every clustered point
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignments: Option<Vec<ClusterAssignment>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema, Validate)]
+#[serde(rename_all = "snake_case")]
+pub struct NearDuplicatesRequest {
+    /// Define which vector name to compare points by. If missing, the default vector is used.
+    pub using: Option<VectorNameBuf>,
+    /// Look for duplicates only among points which satisfy these conditions
+    #[validate(nested)]
+    pub filter: Option<Filter>,
+    /// Points with scores better than this threshold are considered duplicates.
+    /// For distances where lower is better (Euclid, Manhattan) - points closer than the threshold.
+    pub score_threshold: f32,
+    /// How many duplicates per point to look for. Default is 10.
+    #[validate(range(min = 1, max = 1000))]
+    pub limit: Option<usize>,
+    /// Search params for looking up the neighbours of the points
+    #[validate(nested)]
+    pub params: Option<SearchParams>,
+    /// How many random points to look up duplicates of, at most 100000.
+    /// Duplicates are still looked up among all points, which satisfy the filter.
+    /// If missing, duplicates of all points are looked up, which fails if there are more than 100000 of them.
+    #[validate(range(min = 1, max = 100000))]
+    pub sample: Option<usize>,
+    /// If true - keep the representative of every group and delete the rest of its points.
+    /// Requires write access. Default: false
+    pub delete_duplicates: Option<bool>,
+    /// Specify in which shards to look for the points, if not specified - look in all shards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shard_key: Option<ShardKeySelector>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct DuplicatePoint {
+    /// Id of the duplicate
+    pub id: PointIdType,
+    /// Score of the duplicate against the representative of the group
+    pub score: f32,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct DuplicateGroup {
+    /// Point, which is kept if duplicates are deleted
+    pub representative: PointIdType,
+    /// Points within the threshold of the representative, best scores first.
+    /// Duplicates are not necessarily within the threshold of each other, and a point,
+    /// which is only similar to a duplicate, is a part of another group.
+    pub duplicates: Vec<DuplicatePoint>,
+}
+
+#[derive(Debug, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct NearDuplicatesResponse {
+    /// Groups of near-duplicate points, in order of their representatives
+    pub groups: Vec<DuplicateGroup>,
+    /// Number of deleted duplicates
+    pub deleted: usize,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema, Validate)]
 #[serde(rename_all = "snake_case")]
 pub struct PointStruct {
@@ -1443,6 +1903,51 @@ pub struct PointsBatch {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+
+    /// Versions, which points are expected to have.
+    /// If any of the points has a different version, the shard, which holds it,
+    /// rejects the upsert. Other shards are updated independently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_version: Option<Vec<ExpectedPointVersion>>,
+}
+
+/// Point, which version did not match the expected one
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PointVersionConflict {
+    /// Point id
+    pub id: PointIdType,
+    /// Expected version of the point
+    pub expected: segment::types::SeqNumberType,
+    /// Current version of the point, absent if the point does not exist
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual: Option<segment::types::SeqNumberType>,
+}
+
+/// Result of the update, which was rejected because of unexpected point versions
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct VersionConflictResult {
+    pub conflicts: Vec<PointVersionConflict>,
+}
+
+impl From<Vec<segment::common::operation_error::PointVersionConflict>> for VersionConflictResult {
+    fn from(conflicts: Vec<segment::common::operation_error::PointVersionConflict>) -> Self {
+        let conflicts = conflicts
+            .into_iter()
+            .map(|conflict| {
+                let segment::common::operation_error::PointVersionConflict {
+                    point_id,
+                    expected,
+                    actual,
+                } = conflict;
+                PointVersionConflict {
+                    id: point_id,
+                    expected,
+                    actual,
+                }
+            })
+            .collect();
+        Self { conflicts }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
@@ -1481,6 +1986,12 @@ pub struct PointsList {
     /// Mode of the upsert operation: insert_only, upsert (default), update_only
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+
+    /// Versions, which points are expected to have.
+    /// If any of the points has a different version, the shard, which holds it,
+    /// rejects the upsert. Other shards are updated independently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_version: Option<Vec<ExpectedPointVersion>>,
 }
 
 impl<'de> serde::Deserialize<'de> for PointInsertOperations {
@@ -6,9 +6,9 @@ use validator::{Validate, ValidationError, ValidationErrors};
 
 use super::schema::validate_non_empty_dense;
 use super::{
-    Batch, BatchVectorStruct, ContextInput, Expression, FormulaQuery, Fusion, NamedVectorStruct,
-    PointVectors, Query, QueryInterface, RecommendInput, RelevanceFeedbackInput, Sample,
-    VectorInput,
+    Batch, BatchVectorStruct, ContextInput, Expression, FederatedMerge, FormulaQuery, Fusion,
+    GroupByInterface, NamedVectorStruct, PointVectors, Query, QueryInterface, RecommendInput,
+    RelevanceFeedbackInput, Sample, VectorInput, WithLookupInterface,
 };
 use crate::rest::FeedbackStrategy;
 
@@ -48,6 +48,15 @@ impl Validate for Query {
     }
 }
 
+impl Validate for FederatedMerge {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        match self {
+            FederatedMerge::Fusion(fusion) => fusion.validate(),
+            FederatedMerge::Rrf(rrf) => rrf.validate(),
+        }
+    }
+}
+
 impl Validate for VectorInput {
     fn validate(&self) -> Result<(), validator::ValidationErrors> {
         match self {
@@ -102,6 +111,12 @@ impl Validate for FeedbackStrategy {
             FeedbackStrategy::Naive(simple_feedback_strategy) => {
                 simple_feedback_strategy.validate()
             }
+            FeedbackStrategy::Rocchio(rocchio_feedback_strategy) => {
+                rocchio_feedback_strategy.validate()
+            }
+            FeedbackStrategy::Reweight(reweight_feedback_strategy) => {
+                reweight_feedback_strategy.validate()
+            }
         }
     }
 }
@@ -148,7 +163,8 @@ impl Validate for FormulaQuery {
 impl Validate for Sample {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
-            Sample::Random => Ok(()),
+            Sample::Random | Sample::Weighted(_) => Ok(()),
+            Sample::Stratified(stratified) => stratified.validate(),
         }
     }
 }
@@ -282,6 +298,22 @@ impl Validate for Expression {
 }
 
 /// Struct level validation for `FeedbackInput`
+/// Group ids of several fields are tuples, which can't be used as ids of points to look up
+pub fn validate_group_lookup(
+    group_by: &GroupByInterface,
+    with_lookup: Option<&WithLookupInterface>,
+) -> Result<(), ValidationError> {
+    if with_lookup.is_some() && group_by.paths().len() > 1 {
+        let mut err = ValidationError::new("with_lookup");
+        err.message = Some(Cow::from(
+            "with_lookup is not supported when grouping by several fields",
+        ));
+        return Err(err);
+    }
+
+    Ok(())
+}
+
 pub fn validate_relevance_feedback_input(
     relevance_feedback_input: &RelevanceFeedbackInput,
 ) -> Result<(), ValidationError> {
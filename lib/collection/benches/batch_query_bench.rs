@@ -72,6 +72,7 @@ fn setup() -> (TempDir, LocalShard, Runtime) {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     };
 
     let optimizers_config = collection_config.optimizer_config.clone();
@@ -126,6 +127,7 @@ fn create_rnd_batch() -> CollectionUpdateOperations {
             id: (i as u64).into(),
             vector: VectorStructInternal::from(vectors).into(),
             payload: Some(Payload(payload_map)),
+            version: None,
         };
         points.push(point);
     }
@@ -179,6 +181,7 @@ fn batch_search_bench(c: &mut Criterion) {
                             params: None,
                             limit: 10,
                             offset: 0,
+                            start_after: None,
                             with_payload: WithPayloadInterface::Bool(true),
                             with_vector: WithVector::Bool(false),
                             score_threshold: None,
@@ -280,6 +283,7 @@ fn batch_rrf_query_bench(c: &mut Criterion) {
                             params: None,
                             limit: 10,
                             offset: 0,
+                            start_after: None,
                             with_payload: WithPayloadInterface::Bool(true),
                             with_vector: WithVector::Bool(false),
                             score_threshold: None,
@@ -337,6 +341,7 @@ fn batch_rescore_bench(c: &mut Criterion) {
                             params: None,
                             limit: 10,
                             offset: 0,
+                            start_after: None,
                             with_payload: WithPayloadInterface::Bool(true),
                             with_vector: WithVector::Bool(false),
                             score_threshold: None,
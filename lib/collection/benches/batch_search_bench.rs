@@ -43,6 +43,7 @@ fn create_rnd_batch() -> CollectionUpdateOperations {
             id: (i as u64).into(),
             vector: VectorStructInternal::from(vectors).into(),
             payload: Some(Payload(payload_map)),
+            version: None,
         };
         points.push(point);
     }
@@ -90,6 +91,7 @@ fn batch_search_bench(c: &mut Criterion) {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     };
 
     let optimizers_config = collection_config.optimizer_config.clone();
@@ -0,0 +1,486 @@
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use api::rest::{
+    ClusterAssignment, ClusteringMethod, ClusteringRequest, ClusteringResponse, PointCluster,
+    VectorOutput, VectorStructOutput,
+};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use quantization::EncodingError;
+use quantization::encoded_vectors_pq::KMEANS_ACCURACY;
+use quantization::kmeans::{assign_to_centroids, kmeans, mini_batch_kmeans};
+use rand::seq::SliceRandom;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, DenseVector, VectorRef};
+use segment::types::{
+    Condition, Filter, HasVectorCondition, PointIdType, VectorName, VectorNameBuf,
+    WithPayloadInterface, WithVector,
+};
+use shard::common::stopping_guard::StoppingGuard;
+use shard::scroll::ScrollRequestInternal;
+use tokio_util::task::AbortOnDropHandle;
+
+use crate::collection::Collection;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult, ScrollResult};
+use crate::operations::universal_query::shard_query::{
+    SampleInternal, ScoringQuery, ShardQueryRequest,
+};
+
+/// Number of points to read at once, if all points are clustered
+const SCROLL_PAGE_SIZE: usize = 1000;
+
+/// Maximal number of points to cluster, as all of their vectors are kept in memory
+pub const MAX_CLUSTERED_POINTS: usize = 100_000;
+
+/// Internal representation of the clustering request, used to convert from REST.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionClusteringRequest {
+    pub using: VectorNameBuf,
+    pub filter: Option<Filter>,
+    pub clusters: usize,
+    /// Number of random points to cluster, all points if `None`
+    pub sample_size: Option<usize>,
+    pub method: ClusteringMethod,
+    pub max_iterations: usize,
+    pub batch_size: usize,
+    /// Whether cluster ids are written into payload afterwards, which requires write access
+    pub write_payload: bool,
+}
+
+impl CollectionClusteringRequest {
+    pub const DEFAULT_MAX_ITERATIONS: usize = 100;
+    pub const DEFAULT_BATCH_SIZE: usize = 1024;
+}
+
+impl From<ClusteringRequest> for CollectionClusteringRequest {
+    fn from(request: ClusteringRequest) -> Self {
+        let ClusteringRequest {
+            using,
+            filter,
+            clusters,
+            sample,
+            method,
+            max_iterations,
+            batch_size,
+            with_assignments: _,
+            payload_key,
+            shard_key: _,
+        } = request;
+        Self {
+            using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+            filter,
+            clusters,
+            sample_size: sample,
+            method: method.unwrap_or_default(),
+            max_iterations: max_iterations.unwrap_or(Self::DEFAULT_MAX_ITERATIONS),
+            batch_size: batch_size.unwrap_or(Self::DEFAULT_BATCH_SIZE),
+            write_payload: payload_key.is_some(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CollectionClusteringResponse {
+    pub centroids: Vec<DenseVector>,
+    /// Clustered points with the index of their cluster and the squared distance to its centroid
+    pub assignments: Vec<(PointIdType, usize, f32)>,
+}
+
+impl CollectionClusteringResponse {
+    /// Ids of the points in each cluster
+    pub fn cluster_members(&self) -> Vec<Vec<PointIdType>> {
+        let mut members = vec![Vec::new(); self.centroids.len()];
+        for &(id, cluster, _) in &self.assignments {
+            members[cluster].push(id);
+        }
+        members
+    }
+}
+
+impl From<CollectionClusteringResponse> for ClusteringResponse {
+    fn from(response: CollectionClusteringResponse) -> Self {
+        let CollectionClusteringResponse {
+            centroids,
+            assignments,
+        } = response;
+
+        let mut sizes = vec![0; centroids.len()];
+        let mut inertia = 0.0;
+        for &(_, cluster, squared_distance) in &assignments {
+            sizes[cluster] += 1;
+            inertia += f64::from(squared_distance);
+        }
+
+        let clusters = centroids
+            .into_iter()
+            .zip(sizes)
+            .enumerate()
+            .map(|(id, (centroid, size))| PointCluster { id, centroid, size })
+            .collect();
+
+        let points = assignments.len();
+        let assignments = assignments
+            .into_iter()
+            .map(|(id, cluster, squared_distance)| ClusterAssignment {
+                id,
+                cluster,
+                distance: squared_distance.sqrt(),
+            })
+            .collect();
+
+        Self {
+            points,
+            clusters,
+            inertia,
+            assignments: Some(assignments),
+        }
+    }
+}
+
+impl Collection {
+    /// Cluster points by a dense vector with k-means.
+    ///
+    /// Clusters a random sample of the points, or all of them if the sample size is not set.
+    /// At most [`MAX_CLUSTERED_POINTS`] points are clustered, more of them are rejected.
+    pub async fn cluster_points(
+        &self,
+        request: CollectionClusteringRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<CollectionClusteringResponse> {
+        let start = std::time::Instant::now();
+        let CollectionClusteringRequest {
+            using,
+            filter,
+            clusters,
+            sample_size,
+            method,
+            max_iterations,
+            batch_size,
+            write_payload: _,
+        } = request;
+        if sample_size.is_some_and(|sample_size| sample_size > MAX_CLUSTERED_POINTS) {
+            return Err(CollectionError::bad_input(format!(
+                "Can't cluster more than {MAX_CLUSTERED_POINTS} points",
+            )));
+        }
+        if clusters == 0 || sample_size == Some(0) {
+            return Ok(Default::default());
+        }
+
+        {
+            let collection_config = self.collection_config.read().await;
+            collection_config.params.check_vector_exists(&using)?;
+            let Some(vector_params) = collection_config.params.vectors.get_params(&using) else {
+                return Err(CollectionError::bad_input(format!(
+                    "Clustering is not supported for sparse vector {using}",
+                )));
+            };
+            if vector_params.multivector_config.is_some() {
+                return Err(CollectionError::bad_input(format!(
+                    "Clustering is not supported for multivector {using}",
+                )));
+            }
+        }
+
+        // make sure the vector is present in the point
+        let has_vector = Filter::new_must(Condition::HasVector(HasVectorCondition::from(
+            using.clone(),
+        )));
+
+        // merge user's filter with the has_vector filter
+        let filter = Some(
+            filter
+                .map(|filter| filter.merge(&has_vector))
+                .unwrap_or(has_vector),
+        );
+
+        let mut points: Vec<(PointIdType, DenseVector)> = match sample_size {
+            Some(sample_size) => {
+                // sample random points
+                let sampling_query = ShardQueryRequest {
+                    prefetches: vec![],
+                    query: Some(ScoringQuery::Sample(SampleInternal::Random)),
+                    filter,
+                    score_threshold: None,
+                    limit: sample_size,
+                    offset: 0,
+                    start_after: None,
+                    params: None,
+                    with_vector: WithVector::Selector(vec![using.clone()]), // retrieve the vector
+                    with_payload: Default::default(),
+                };
+
+                let sampled_points = self
+                    .query(
+                        sampling_query,
+                        read_consistency,
+                        shard_selection,
+                        timeout,
+                        hw_measurement_acc,
+                    )
+                    .await?;
+
+                sampled_points
+                    .into_iter()
+                    .take(sample_size)
+                    .filter_map(|point| match point.vector.as_ref()?.get(&using)? {
+                        VectorRef::Dense(vector) => Some((point.id, vector.to_vec())),
+                        VectorRef::Sparse(_) | VectorRef::MultiDense(_) => None,
+                    })
+                    .collect()
+            }
+            None => {
+                // read all points page by page
+                let mut points = Vec::new();
+                let mut offset = None;
+                loop {
+                    let scroll_request = ScrollRequestInternal {
+                        offset,
+                        limit: Some(SCROLL_PAGE_SIZE),
+                        filter: filter.clone(),
+                        with_payload: Some(WithPayloadInterface::Bool(false)),
+                        with_vector: WithVector::Selector(vec![using.clone()]),
+                        order_by: None,
+                    };
+                    let ScrollResult {
+                        points: page,
+                        next_page_offset,
+                    } = self
+                        .scroll_by(
+                            scroll_request,
+                            read_consistency,
+                            &shard_selection,
+                            timeout.map(|timeout| timeout.saturating_sub(start.elapsed())),
+                            hw_measurement_acc.clone(),
+                        )
+                        .await?;
+
+                    points.extend(page.into_iter().filter_map(|record| {
+                        Some((record.id, dense_vector(record.vector?, &using)?))
+                    }));
+                    if points.len() > MAX_CLUSTERED_POINTS {
+                        return Err(CollectionError::bad_input(format!(
+                            "Can't cluster more than {MAX_CLUSTERED_POINTS} points, set `sample` to cluster a random subset of them",
+                        )));
+                    }
+
+                    match next_page_offset {
+                        Some(next_page_offset) => offset = Some(next_page_offset),
+                        None => break,
+                    }
+                }
+                points
+            }
+        };
+
+        if points.len() < clusters {
+            return Err(CollectionError::bad_input(format!(
+                "Not enough points with vector {using} to form {clusters} clusters, found {}",
+                points.len(),
+            )));
+        }
+
+        // initial centroids are the first points, so they have to be random
+        points.shuffle(&mut rand::rng());
+        let (ids, vectors): (Vec<_>, Vec<_>) = points.into_iter().unzip();
+
+        let max_threads = self.shared_storage_config.search_thread_count.max(1);
+
+        let stopping_guard = StoppingGuard::new();
+        let task = self.search_runtime.spawn_blocking({
+            let is_stopped = stopping_guard.get_is_stopped();
+            move || {
+                run_clustering(
+                    vectors,
+                    clusters,
+                    method,
+                    max_iterations,
+                    batch_size,
+                    max_threads,
+                    &is_stopped,
+                )
+            }
+        });
+        let task = AbortOnDropHandle::new(task);
+
+        let (centroids, assignments) = match timeout {
+            Some(timeout) => {
+                let timeout = timeout.saturating_sub(start.elapsed());
+                tokio::time::timeout(timeout, task)
+                    .await
+                    .map_err(|_| CollectionError::timeout(timeout, "cluster_points"))???
+            }
+            None => task.await??,
+        };
+
+        let assignments = ids
+            .into_iter()
+            .zip(assignments)
+            .map(|(id, (cluster, squared_distance))| (id, cluster as usize, squared_distance))
+            .collect();
+
+        Ok(CollectionClusteringResponse {
+            centroids,
+            assignments,
+        })
+    }
+}
+
+/// Dense vector with the given name, if the point has one
+fn dense_vector(vector: VectorStructOutput, using: &VectorName) -> Option<DenseVector> {
+    match vector {
+        VectorStructOutput::Single(vector) => (using == DEFAULT_VECTOR_NAME).then_some(vector),
+        VectorStructOutput::MultiDense(_) => None,
+        VectorStructOutput::Named(mut vectors) => match vectors.remove(using)? {
+            VectorOutput::Dense(vector) => Some(vector),
+            VectorOutput::Sparse(_) | VectorOutput::MultiDense(_) => None,
+        },
+    }
+}
+
+/// Find the centroids and the nearest centroid of every vector
+fn run_clustering(
+    vectors: Vec<DenseVector>,
+    clusters: usize,
+    method: ClusteringMethod,
+    max_iterations: usize,
+    batch_size: usize,
+    max_threads: usize,
+    stopped: &AtomicBool,
+) -> CollectionResult<(Vec<DenseVector>, Vec<(u32, f32)>)> {
+    let dim = vectors.first().map_or(0, Vec::len);
+    if dim == 0 {
+        return Err(CollectionError::bad_input("Can't cluster empty vectors"));
+    }
+    let data = vectors.concat();
+
+    let centroids = match method {
+        ClusteringMethod::Kmeans => kmeans(
+            &data,
+            clusters,
+            dim,
+            max_iterations,
+            max_threads,
+            KMEANS_ACCURACY,
+            stopped,
+        ),
+        ClusteringMethod::MiniBatchKmeans => mini_batch_kmeans(
+            &data,
+            clusters,
+            dim,
+            max_iterations,
+            batch_size,
+            KMEANS_ACCURACY,
+            stopped,
+        ),
+    }
+    .map_err(clustering_error)?;
+
+    let assignments =
+        assign_to_centroids(&data, &centroids, dim, max_threads).map_err(clustering_error)?;
+
+    let centroids = centroids.chunks_exact(dim).map(<[f32]>::to_vec).collect();
+    Ok((centroids, assignments))
+}
+
+fn clustering_error(error: EncodingError) -> CollectionError {
+    match error {
+        EncodingError::Stopped => CollectionError::cancelled("Clustering was stopped"),
+        EncodingError::IOError(_)
+        | EncodingError::EncodingError(_)
+        | EncodingError::ArgumentsError(_) => {
+            CollectionError::service_error(format!("Clustering failed: {error}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_response() -> CollectionClusteringResponse {
+        CollectionClusteringResponse {
+            centroids: vec![vec![0.0, 0.0], vec![10.0, 10.0], vec![-5.0, 5.0]],
+            assignments: vec![
+                (1.into(), 0, 1.0),
+                (2.into(), 1, 4.0),
+                (3.into(), 0, 0.25),
+                (4.into(), 1, 0.0),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_clustering_response_conversion() {
+        let response = ClusteringResponse::from(fixture_response());
+
+        assert_eq!(response.points, 4);
+        assert_eq!(response.inertia, 5.25);
+        assert_eq!(
+            response.clusters,
+            vec![
+                PointCluster {
+                    id: 0,
+                    centroid: vec![0.0, 0.0],
+                    size: 2,
+                },
+                PointCluster {
+                    id: 1,
+                    centroid: vec![10.0, 10.0],
+                    size: 2,
+                },
+                // clusters may be empty
+                PointCluster {
+                    id: 2,
+                    centroid: vec![-5.0, 5.0],
+                    size: 0,
+                },
+            ],
+        );
+        assert_eq!(
+            response.assignments.unwrap()[1],
+            ClusterAssignment {
+                id: 2.into(),
+                cluster: 1,
+                distance: 2.0,
+            },
+        );
+    }
+
+    #[test]
+    fn test_cluster_members() {
+        let members = fixture_response().cluster_members();
+        assert_eq!(
+            members,
+            vec![vec![1.into(), 3.into()], vec![2.into(), 4.into()], vec![]],
+        );
+    }
+
+    #[test]
+    fn test_run_clustering() {
+        let vectors = (0..60)
+            .map(|i| {
+                let center = (i % 2) as f32 * 100.0;
+                vec![center + (i % 5) as f32, center - (i % 3) as f32]
+            })
+            .collect::<Vec<_>>();
+        let stopped = AtomicBool::new(false);
+
+        for method in [ClusteringMethod::Kmeans, ClusteringMethod::MiniBatchKmeans] {
+            let (centroids, assignments) =
+                run_clustering(vectors.clone(), 2, method, 100, 16, 1, &stopped).unwrap();
+
+            assert_eq!(centroids.len(), 2);
+            assert_eq!(assignments.len(), vectors.len());
+            // even and odd points are in different clusters
+            assert_ne!(assignments[0].0, assignments[1].0);
+            for (i, (cluster, _)) in assignments.iter().enumerate() {
+                assert_eq!(*cluster, assignments[i % 2].0);
+            }
+        }
+    }
+}
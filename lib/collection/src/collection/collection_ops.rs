@@ -11,6 +11,7 @@ use shard::operations::optimization::{OptimizationsRequestOptions, Optimizations
 
 use super::Collection;
 use crate::operations::config_diff::*;
+use crate::operations::payload_validation::PayloadValidationConfig;
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::*;
 use crate::shards::replica_set::Change;
@@ -126,31 +127,19 @@ impl Collection {
     ) -> CollectionResult<()> {
         {
             let mut config = self.collection_config.write().await;
-            match quantization_config_diff {
-                QuantizationConfigDiff::Scalar(scalar) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Scalar(scalar));
-                }
+            let quantization_config = match quantization_config_diff {
+                QuantizationConfigDiff::Scalar(scalar) => Some(QuantizationConfig::Scalar(scalar)),
                 QuantizationConfigDiff::Product(product) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Product(product));
-                }
-                QuantizationConfigDiff::Binary(binary) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Binary(binary));
-                }
-                QuantizationConfigDiff::Turbo(turbo) => {
-                    config
-                        .quantization_config
-                        .replace(QuantizationConfig::Turbo(turbo));
-                }
-                QuantizationConfigDiff::Disabled(_) => {
-                    config.quantization_config = None;
+                    Some(QuantizationConfig::Product(product))
                 }
+                QuantizationConfigDiff::Binary(binary) => Some(QuantizationConfig::Binary(binary)),
+                QuantizationConfigDiff::Turbo(turbo) => Some(QuantizationConfig::Turbo(turbo)),
+                QuantizationConfigDiff::Disabled(_) => None,
+            };
+            if quantization_config.is_some() {
+                config.params.vectors.check_quantization_supported()?;
             }
+            config.quantization_config = quantization_config;
         }
         self.collection_config.read().await.save(&self.path)?;
         Ok(())
@@ -172,6 +161,18 @@ impl Collection {
         Ok(())
     }
 
+    /// Replaces the payload schema of the collection and saves it to disk.
+    ///
+    /// If none - payload validation is disabled.
+    pub async fn update_payload_validation(
+        &self,
+        payload_validation: Option<PayloadValidationConfig>,
+    ) -> CollectionResult<()> {
+        self.collection_config.write().await.payload_validation = payload_validation;
+        self.collection_config.read().await.save(&self.path)?;
+        Ok(())
+    }
+
     /// Updates the strict mode configuration and saves it to disk.
     ///
     /// Order matters: rate limiters on each shard are updated *before* the new
@@ -369,6 +370,14 @@ impl Collection {
             .clone()
     }
 
+    pub async fn payload_validation_config(&self) -> Option<PayloadValidationConfig> {
+        self.collection_config
+            .read()
+            .await
+            .payload_validation
+            .clone()
+    }
+
     pub async fn vectors_config(&self) -> VectorsConfig {
         self.collection_config.read().await.params.vectors.clone()
     }
@@ -182,6 +182,7 @@ impl Collection {
             score_threshold: None,
             limit: sample_size,
             offset: 0,
+            start_after: None,
             params: None,
             with_vector: WithVector::Selector(vec![using.clone()]), // retrieve the vector
             with_payload: Default::default(),
@@ -241,6 +242,7 @@ impl Collection {
                 with_vector: WithVector::Bool(false),
                 with_payload: WithPayloadInterface::Bool(false),
                 lookup_from: None,
+                cursor: None,
             };
 
             queries.push((query_request, shard_selection.clone()));
@@ -300,6 +302,8 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
         }
     }
 
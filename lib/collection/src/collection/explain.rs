@@ -0,0 +1,138 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use api::rest::models::Explanation;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use futures::future;
+use segment::types::Filter;
+use tokio::time::Instant;
+
+use super::Collection;
+use crate::common::fetch_vectors::{
+    build_vector_resolver_queries, resolve_referenced_vectors_batch,
+};
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionResult, CoreSearchRequest};
+use crate::operations::universal_query::collection_query::CollectionQueryRequest;
+use crate::operations::universal_query::shard_query::ShardQueryRequest;
+
+impl Collection {
+    /// Explain how the query is executed by the segments of the selected shards.
+    ///
+    /// Only shards with a replica on this peer are explained, others are listed as unexplained.
+    pub async fn explain_query<F, Fut>(
+        &self,
+        request: CollectionQueryRequest,
+        shard_selection: ShardSelectorInternal,
+        collection_by_name: F,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Explanation>
+    where
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Option<Arc<Collection>>>,
+    {
+        let start = Instant::now();
+
+        let requests = vec![(request, shard_selection)];
+        let resolver_requests = build_vector_resolver_queries(&requests);
+        let ids_to_vectors = resolve_referenced_vectors_batch(
+            &resolver_requests,
+            self,
+            collection_by_name,
+            read_consistency,
+            timeout,
+            hw_measurement_acc.clone(),
+        )
+        .await?;
+
+        // update timeout
+        let timeout = timeout.map(|timeout| timeout.saturating_sub(start.elapsed()));
+
+        let mut explanation = Explanation::default();
+        for (request, shard_selection) in requests {
+            let shard_request = request.try_into_shard_request(&self.id, &ids_to_vectors)?;
+            let shards_explanation = self
+                .explain_shard_query(
+                    shard_request,
+                    &shard_selection,
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+                .await?;
+            explanation.merge(shards_explanation);
+        }
+
+        Ok(explanation)
+    }
+
+    /// Explain how the search is executed by the segments of the selected shards.
+    ///
+    /// Only shards with a replica on this peer are explained, others are listed as unexplained.
+    pub async fn explain_search(
+        &self,
+        request: CoreSearchRequest,
+        shard_selection: &ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Explanation> {
+        self.explain_shard_query(
+            ShardQueryRequest::from(request),
+            shard_selection,
+            timeout,
+            hw_measurement_acc,
+        )
+        .await
+    }
+
+    /// Explain how the segments of the selected shards find the points to count.
+    ///
+    /// Only shards with a replica on this peer are explained, others are listed as unexplained.
+    pub async fn explain_count(
+        &self,
+        filter: Option<&Filter>,
+        shard_selection: &ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Explanation> {
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let explanations = shards.into_iter().map(|(shard, _shard_key)| {
+            shard.explain_count_local(filter, timeout, hw_measurement_acc.clone())
+        });
+
+        let explanations = future::try_join_all(explanations).await?;
+        Ok(merge_explanations(explanations))
+    }
+
+    async fn explain_shard_query(
+        &self,
+        request: ShardQueryRequest,
+        shard_selection: &ShardSelectorInternal,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Explanation> {
+        let shards_holder = self.shards_holder.read().await;
+        let shards = shards_holder.select_shards(shard_selection)?;
+
+        let explanations = shards.into_iter().map(|(shard, _shard_key)| {
+            shard.explain_query_local(request.clone(), timeout, hw_measurement_acc.clone())
+        });
+
+        let explanations = future::try_join_all(explanations).await?;
+        Ok(merge_explanations(explanations))
+    }
+}
+
+/// Merge explanations of the shards
+fn merge_explanations(explanations: Vec<Explanation>) -> Explanation {
+    let mut merged = Explanation::default();
+    for explanation in explanations {
+        merged.merge(explanation);
+    }
+    merged
+}
@@ -0,0 +1,432 @@
+use std::num::NonZeroU64;
+
+use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use segment::common::reciprocal_rank_fusion::rrf_scoring;
+use segment::common::score_fusion::{ScoreFusion, score_fusion};
+use segment::data_types::modifier::Modifier;
+use segment::types::{Distance, MultiVectorConfig, Order, ScoredPoint, VectorName, VectorNameBuf};
+
+use super::Collection;
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::universal_query::collection_query::{
+    CollectionPrefetch, CollectionQueryRequest, Query, VectorQuery,
+};
+use crate::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
+
+/// Maximum number of collections in a single federated query
+pub const MAX_FEDERATED_COLLECTIONS: usize = 16;
+
+/// Results of a single collection in a federated query.
+pub struct FederatedCollectionResult {
+    pub collection_name: String,
+    /// Order of the scores, `None` if the scores can't be compared
+    pub order: Option<Order>,
+    pub points: Vec<ScoredPoint>,
+}
+
+/// Parameters of a vector, which define the scores of a query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryVectorSchema {
+    Dense {
+        size: NonZeroU64,
+        distance: Distance,
+        multivector_config: Option<MultiVectorConfig>,
+    },
+    Sparse {
+        modifier: Option<Modifier>,
+    },
+}
+
+impl Collection {
+    /// Parameters of the vectors, which are searched by the given query and its prefetches.
+    pub async fn query_vector_schemas(
+        &self,
+        request: &CollectionQueryRequest,
+    ) -> CollectionResult<Vec<(VectorNameBuf, QueryVectorSchema)>> {
+        let mut vector_names = Vec::new();
+        collect_vector_names(
+            request.query.as_ref(),
+            &request.using,
+            &request.prefetch,
+            &mut vector_names,
+        );
+
+        let config = self.collection_config.read().await;
+        vector_names
+            .into_iter()
+            .unique()
+            .map(|vector_name| {
+                config.params.check_vector_exists(vector_name)?;
+                let schema = match config.params.vectors.get_params(vector_name) {
+                    Some(params) => QueryVectorSchema::Dense {
+                        size: params.size,
+                        distance: params.distance,
+                        multivector_config: params.multivector_config,
+                    },
+                    None => QueryVectorSchema::Sparse {
+                        modifier: config
+                            .params
+                            .get_sparse_vector_params_opt(vector_name)
+                            .and_then(|params| params.modifier),
+                    },
+                };
+                Ok((vector_name.to_owned(), schema))
+            })
+            .collect()
+    }
+
+    /// Order of the scores of the results of the given query in this collection.
+    ///
+    /// Returns `None` if results are not ordered by score, e.g. for random sampling or MMR.
+    pub async fn query_result_order(
+        &self,
+        request: &CollectionQueryRequest,
+    ) -> CollectionResult<Option<Order>> {
+        let order = match &request.query {
+            Some(Query::Vector(vector_query)) => match vector_query {
+                VectorQuery::Nearest(_) | VectorQuery::RecommendAverageVector(_) => Some(
+                    self.collection_config
+                        .read()
+                        .await
+                        .params
+                        .get_distance(&request.using)?
+                        .distance_order(),
+                ),
+                VectorQuery::RecommendBestScore(_)
+                | VectorQuery::RecommendSumScores(_)
+                | VectorQuery::Discover(_)
+                | VectorQuery::Context(_)
+                | VectorQuery::Feedback(_) => Some(Order::LargeBetter),
+                // MMR cannot be reordered
+                VectorQuery::NearestWithMmr(_) => None,
+            },
+            Some(Query::Fusion(_) | Query::Formula(_)) => Some(Order::LargeBetter),
+            Some(Query::OrderBy(order_by)) => Some(Order::from(order_by.direction())),
+            // Sampling keys of weighted samples are comparable across collections
+            Some(Query::Sample(SampleInternal::Weighted(_))) => Some(Order::LargeBetter),
+            // Random sample does not require ordering, strata are sampled in each collection
+            Some(Query::Sample(SampleInternal::Random | SampleInternal::Stratified(_))) => None,
+            // Points are ordered by id
+            None => None,
+        };
+        Ok(order)
+    }
+}
+
+fn collect_vector_names<'a>(
+    query: Option<&Query>,
+    using: &'a VectorName,
+    prefetches: &'a [CollectionPrefetch],
+    vector_names: &mut Vec<&'a VectorName>,
+) {
+    if let Some(Query::Vector(_)) = query {
+        vector_names.push(using);
+    }
+    for prefetch in prefetches {
+        collect_vector_names(
+            prefetch.query.as_ref(),
+            &prefetch.using,
+            &prefetch.prefetch,
+            vector_names,
+        );
+    }
+}
+
+/// Check that the collections of a federated query search the same kind of vectors.
+///
+/// Otherwise, the query would be invalid in some of the collections, or their scores would not be
+/// comparable.
+pub fn check_federated_vector_schemas(
+    schemas: &[(String, Vec<(VectorNameBuf, QueryVectorSchema)>)],
+) -> CollectionResult<()> {
+    let Some(((first_collection, first_schemas), other)) = schemas.split_first() else {
+        return Ok(());
+    };
+
+    for (collection_name, schemas) in other {
+        let mismatch = first_schemas
+            .iter()
+            .zip(schemas)
+            .find(|((_, first_schema), (_, schema))| first_schema != schema);
+        if let Some(((vector_name, first_schema), (_, schema))) = mismatch {
+            return Err(CollectionError::bad_input(format!(
+                "Vector `{vector_name}` of collection `{collection_name}` is not compatible with collection `{first_collection}`: {schema:?} != {first_schema:?}",
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Merge results of the same query in several collections.
+///
+/// Without fusion, points are merged by score, which requires all collections to order scores in
+/// the same way. With fusion, the results of each collection are ranked as a separate source.
+/// Points are never deduplicated, as the same id in different collections is a different point.
+pub fn merge_federated_results(
+    results: Vec<FederatedCollectionResult>,
+    fusion: Option<&FusionInternal>,
+    offset: usize,
+    limit: usize,
+) -> CollectionResult<Vec<(String, ScoredPoint)>> {
+    let (order, results) = match fusion {
+        None => {
+            let mut orders = results.iter().map(|result| result.order);
+            let order = orders.next().flatten();
+            let Some(order) = order.filter(|&order| orders.all(|other| other == Some(order)))
+            else {
+                return Err(CollectionError::bad_input(
+                    "Scores of the queried collections can't be compared, use fusion to merge the results",
+                ));
+            };
+            (order, results)
+        }
+        Some(FusionInternal::Rrf { k, weights }) => {
+            if let Some(weights) = weights
+                && weights.len() != results.len()
+            {
+                return Err(CollectionError::bad_input(format!(
+                    "Number of weights in RRF should match number of collections: got {}, expected {}",
+                    weights.len(),
+                    results.len(),
+                )));
+            }
+            let results = results
+                .into_iter()
+                .enumerate()
+                .map(|(idx, result)| {
+                    let weight = weights.as_ref().map(|weights| [weights[idx].into_inner()]);
+                    let points =
+                        rrf_scoring(vec![result.points], *k, weight.as_ref().map(|w| &w[..]))?;
+                    Ok(FederatedCollectionResult { points, ..result })
+                })
+                .collect::<CollectionResult<Vec<_>>>()?;
+            (Order::LargeBetter, results)
+        }
+        Some(FusionInternal::Dbsf) => {
+            let results = results
+                .into_iter()
+                .map(|result| {
+                    let points = score_fusion([result.points], ScoreFusion::dbsf());
+                    FederatedCollectionResult { points, ..result }
+                })
+                .collect();
+            (Order::LargeBetter, results)
+        }
+    };
+
+    let mut merged: Vec<(String, ScoredPoint)> = results
+        .into_iter()
+        .flat_map(|result| {
+            let collection_name = result.collection_name;
+            result
+                .points
+                .into_iter()
+                .map(move |point| (collection_name.clone(), point))
+        })
+        .collect();
+
+    // Stable sort keeps the order of equally scored points within a collection
+    merged.sort_by(|(_, a), (_, b)| {
+        let ordering = OrderedFloat(b.score).cmp(&OrderedFloat(a.score));
+        match order {
+            Order::LargeBetter => ordering,
+            Order::SmallBetter => ordering.reverse(),
+        }
+    });
+
+    Ok(merged.into_iter().skip(offset).take(limit).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(id: u64, score: f32) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
+        }
+    }
+
+    fn result(
+        name: &str,
+        order: Option<Order>,
+        points: Vec<ScoredPoint>,
+    ) -> FederatedCollectionResult {
+        FederatedCollectionResult {
+            collection_name: name.to_string(),
+            order,
+            points,
+        }
+    }
+
+    fn ids(merged: &[(String, ScoredPoint)]) -> Vec<(&str, String)> {
+        merged
+            .iter()
+            .map(|(name, point)| (name.as_str(), point.id.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_by_score() {
+        let merged = merge_federated_results(
+            vec![
+                result(
+                    "a",
+                    Some(Order::LargeBetter),
+                    vec![point(1, 0.9), point(2, 0.5)],
+                ),
+                result(
+                    "b",
+                    Some(Order::LargeBetter),
+                    vec![point(1, 0.7), point(3, 0.6)],
+                ),
+            ],
+            None,
+            0,
+            3,
+        )
+        .unwrap();
+
+        assert_eq!(
+            ids(&merged),
+            vec![
+                ("a", "1".to_string()),
+                ("b", "1".to_string()),
+                ("b", "3".to_string()),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_merge_by_distance_with_offset() {
+        let merged = merge_federated_results(
+            vec![
+                result(
+                    "a",
+                    Some(Order::SmallBetter),
+                    vec![point(1, 0.1), point(2, 0.5)],
+                ),
+                result("b", Some(Order::SmallBetter), vec![point(3, 0.3)]),
+            ],
+            None,
+            1,
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(
+            ids(&merged),
+            vec![("b", "3".to_string()), ("a", "2".to_string())],
+        );
+    }
+
+    #[test]
+    fn test_merge_incomparable_scores() {
+        let results = || {
+            vec![
+                result("a", Some(Order::LargeBetter), vec![point(1, 0.9)]),
+                result("b", Some(Order::SmallBetter), vec![point(2, 0.1)]),
+            ]
+        };
+
+        assert!(merge_federated_results(results(), None, 0, 10).is_err());
+
+        let fusion = FusionInternal::Rrf {
+            k: 2,
+            weights: None,
+        };
+        let merged = merge_federated_results(results(), Some(&fusion), 0, 10).unwrap();
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_with_weighted_rrf() {
+        let fusion = FusionInternal::Rrf {
+            k: 2,
+            weights: Some(vec![OrderedFloat(1.0), OrderedFloat(3.0)]),
+        };
+        let merged = merge_federated_results(
+            vec![
+                result("a", None, vec![point(1, 0.9), point(2, 0.8)]),
+                result("b", None, vec![point(1, 0.1), point(2, 0.05)]),
+            ],
+            Some(&fusion),
+            0,
+            10,
+        )
+        .unwrap();
+
+        assert_eq!(
+            ids(&merged),
+            vec![
+                ("b", "1".to_string()),
+                ("b", "2".to_string()),
+                ("a", "1".to_string()),
+                ("a", "2".to_string()),
+            ],
+        );
+
+        let fusion = FusionInternal::Rrf {
+            k: 2,
+            weights: Some(vec![OrderedFloat(1.0)]),
+        };
+        assert!(
+            merge_federated_results(
+                vec![result("a", None, vec![]), result("b", None, vec![])],
+                Some(&fusion),
+                0,
+                10,
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_federated_vector_schemas() {
+        let dense = |distance| QueryVectorSchema::Dense {
+            size: NonZeroU64::new(4).unwrap(),
+            distance,
+            multivector_config: None,
+        };
+        let sparse = QueryVectorSchema::Sparse { modifier: None };
+        let schemas = |name: &str, schema: &QueryVectorSchema| {
+            (
+                name.to_string(),
+                vec![
+                    ("dense".to_string(), schema.clone()),
+                    ("sparse".to_string(), sparse.clone()),
+                ],
+            )
+        };
+
+        check_federated_vector_schemas(&[
+            schemas("a", &dense(Distance::Cosine)),
+            schemas("b", &dense(Distance::Cosine)),
+        ])
+        .unwrap();
+
+        assert!(
+            check_federated_vector_schemas(&[
+                schemas("a", &dense(Distance::Cosine)),
+                schemas("b", &dense(Distance::Euclid)),
+            ])
+            .is_err()
+        );
+        assert!(
+            check_federated_vector_schemas(&[
+                schemas("a", &dense(Distance::Cosine)),
+                schemas("b", &sparse),
+            ])
+            .is_err()
+        );
+    }
+}
@@ -1,11 +1,17 @@
 mod clean;
+pub mod clustering;
 mod collection_ops;
 pub mod distance_matrix;
+mod explain;
 mod facet;
+pub mod federated_query;
 pub mod mmr;
+pub mod near_duplicates;
 pub mod payload_index_schema;
 mod point_ops;
+pub mod quantization_tuning;
 pub mod query;
+pub mod recall_monitor;
 mod resharding;
 mod search;
 mod shard_transfer;
@@ -26,6 +32,7 @@ use clean::ShardCleanTasks;
 use common::budget::ResourceBudget;
 use common::save_on_disk::SaveOnDisk;
 use common::storage_version::StorageVersion;
+use recall_monitor::RecallMonitor;
 use segment::types::{SeqNumberType, ShardKey};
 use semver::Version;
 use shard::operations::optimization::{OptimizationsRequestOptions, OptimizationsResponse};
@@ -95,6 +102,8 @@ pub struct Collection {
     // Coordinates background optimizer recreation: at most one runs at a time, and requests that
     // arrive while one is running are coalesced into a single re-run.
     recreate_optimizers_state: Arc<RecreateOptimizersState>,
+    // Background task measuring recall of vector indexes in local shards
+    recall_monitor: RecallMonitor,
 }
 
 pub type RequestShardTransfer = Arc<dyn Fn(ShardTransfer) + Send + Sync>;
@@ -178,6 +187,13 @@ impl Collection {
             Self::estimate_collection_size_stats(&shared_shard_holder).await?,
         );
 
+        let recall_monitor = RecallMonitor::start(
+            shared_storage_config.recall_monitor,
+            name.clone(),
+            shared_shard_holder.clone(),
+            shared_collection_config.clone(),
+        );
+
         // Once the config is persisted - the collection is considered to be successfully created.
         CollectionVersion::save(path)?;
         collection_config.save(path)?;
@@ -205,6 +221,7 @@ impl Collection {
             collection_stats_cache,
             shard_clean_tasks: Default::default(),
             recreate_optimizers_state: Default::default(),
+            recall_monitor,
         })
     }
 
@@ -302,6 +319,13 @@ impl Collection {
                 .expect("Failed to load collection size stats"),
         );
 
+        let recall_monitor = RecallMonitor::start(
+            shared_storage_config.recall_monitor,
+            collection_id.clone(),
+            shared_shard_holder.clone(),
+            shared_collection_config.clone(),
+        );
+
         Self {
             id: collection_id.clone(),
             shards_holder: shared_shard_holder,
@@ -325,6 +349,7 @@ impl Collection {
             collection_stats_cache,
             shard_clean_tasks: Default::default(),
             recreate_optimizers_state: Default::default(),
+            recall_monitor,
         }
     }
 
@@ -0,0 +1,460 @@
+use std::time::Duration;
+
+use ahash::AHashSet;
+use api::rest::{DuplicateGroup, DuplicatePoint, NearDuplicatesRequest, NearDuplicatesResponse};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorInternal, VectorStructInternal};
+use segment::types::{
+    Condition, Filter, HasVectorCondition, PointIdType, ScoredPoint, SearchParams, VectorNameBuf,
+    WithPayloadInterface, WithVector,
+};
+use shard::count::CountRequestInternal;
+use shard::operations::point_ops::VectorStructPersisted;
+use shard::scroll::ScrollRequestInternal;
+
+use crate::collection::Collection;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult, ScrollResult};
+use crate::operations::universal_query::collection_query::{
+    CollectionQueryRequest, Query, VectorInputInternal, VectorQuery,
+};
+use crate::operations::universal_query::shard_query::{
+    SampleInternal, ScoringQuery, ShardQueryRequest,
+};
+
+/// Number of points to read and look up neighbours for at once
+const SCROLL_PAGE_SIZE: usize = 256;
+
+/// Maximal number of points to look up duplicates of, as a search is done for each of them
+pub const MAX_SCANNED_POINTS: usize = 100_000;
+
+/// Internal representation of the near-duplicates request, used to convert from REST.
+pub struct CollectionNearDuplicatesRequest {
+    pub using: VectorNameBuf,
+    pub filter: Option<Filter>,
+    pub score_threshold: f32,
+    pub limit_per_point: usize,
+    pub params: Option<SearchParams>,
+    pub sample_size: Option<usize>,
+    pub delete_duplicates: bool,
+}
+
+impl CollectionNearDuplicatesRequest {
+    pub const DEFAULT_LIMIT_PER_POINT: usize = 10;
+}
+
+impl From<NearDuplicatesRequest> for CollectionNearDuplicatesRequest {
+    fn from(request: NearDuplicatesRequest) -> Self {
+        let NearDuplicatesRequest {
+            using,
+            filter,
+            score_threshold,
+            limit,
+            params,
+            sample,
+            delete_duplicates,
+            shard_key: _,
+        } = request;
+        Self {
+            using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+            filter,
+            score_threshold,
+            limit_per_point: limit.unwrap_or(Self::DEFAULT_LIMIT_PER_POINT),
+            params,
+            sample_size: sample,
+            delete_duplicates: delete_duplicates.unwrap_or(false),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CollectionNearDuplicatesResponse {
+    /// Representatives with their duplicates
+    pub groups: Vec<(PointIdType, Vec<ScoredPoint>)>,
+}
+
+impl CollectionNearDuplicatesResponse {
+    /// Ids of all duplicates, without the representatives
+    pub fn duplicate_ids(&self) -> Vec<PointIdType> {
+        self.groups
+            .iter()
+            .flat_map(|(_, duplicates)| duplicates.iter().map(|point| point.id))
+            .collect()
+    }
+}
+
+impl From<CollectionNearDuplicatesResponse> for NearDuplicatesResponse {
+    fn from(response: CollectionNearDuplicatesResponse) -> Self {
+        let groups = response
+            .groups
+            .into_iter()
+            .map(|(representative, duplicates)| DuplicateGroup {
+                representative,
+                duplicates: duplicates
+                    .into_iter()
+                    .map(|point| DuplicatePoint {
+                        id: point.id,
+                        score: point.score,
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self { groups, deleted: 0 }
+    }
+}
+
+impl Collection {
+    /// Find groups of points with vectors within the score threshold of each other.
+    ///
+    /// Points are visited in order of their ids, or in random order if a sample is requested.
+    /// Every visited point, which is not in a group yet, becomes a representative of the not
+    /// grouped points among its nearest neighbours. Neighbours are looked up with the vector
+    /// index, so no pairs of points are compared explicitly.
+    ///
+    /// Groups are not merged transitively: every duplicate is within the threshold of its
+    /// representative, but not necessarily of the other duplicates of the group. A point, which is
+    /// only similar to a duplicate, becomes a part of another group.
+    ///
+    /// At most [`MAX_SCANNED_POINTS`] points are visited, more of them are rejected.
+    pub async fn find_near_duplicates(
+        &self,
+        request: CollectionNearDuplicatesRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<CollectionNearDuplicatesResponse> {
+        let start = std::time::Instant::now();
+        let CollectionNearDuplicatesRequest {
+            using,
+            filter,
+            score_threshold,
+            limit_per_point,
+            params,
+            sample_size,
+            delete_duplicates: _,
+        } = request;
+        if sample_size.is_some_and(|sample_size| sample_size > MAX_SCANNED_POINTS) {
+            return Err(CollectionError::bad_input(format!(
+                "Can't look up duplicates of more than {MAX_SCANNED_POINTS} points",
+            )));
+        }
+        if limit_per_point == 0 || sample_size == Some(0) {
+            return Ok(Default::default());
+        }
+
+        self.collection_config
+            .read()
+            .await
+            .params
+            .check_vector_exists(&using)?;
+
+        // make sure the vector is present in the point
+        let has_vector = Filter::new_must(Condition::HasVector(HasVectorCondition::from(
+            using.clone(),
+        )));
+
+        // merge user's filter with the has_vector filter
+        let filter = filter
+            .map(|filter| filter.merge(&has_vector))
+            .unwrap_or(has_vector);
+
+        let points: Vec<(PointIdType, VectorInternal)> = match sample_size {
+            Some(sample_size) => {
+                // sample random points
+                let sampling_query = ShardQueryRequest {
+                    prefetches: vec![],
+                    query: Some(ScoringQuery::Sample(SampleInternal::Random)),
+                    filter: Some(filter.clone()),
+                    score_threshold: None,
+                    limit: sample_size,
+                    offset: 0,
+                    start_after: None,
+                    params: None,
+                    with_vector: WithVector::Selector(vec![using.clone()]), // retrieve the vector
+                    with_payload: Default::default(),
+                };
+
+                let sampled_points = self
+                    .query(
+                        sampling_query,
+                        read_consistency,
+                        shard_selection.clone(),
+                        timeout,
+                        hw_measurement_acc.clone(),
+                    )
+                    .await?;
+
+                sampled_points
+                    .into_iter()
+                    .take(sample_size)
+                    .filter_map(|point| {
+                        let vector = point.vector.as_ref()?.get(&using)?.to_owned();
+                        Some((point.id, vector))
+                    })
+                    .collect()
+            }
+            None => {
+                // a search is done for every point, so check the number of them beforehand
+                let count_request = CountRequestInternal {
+                    filter: Some(filter.clone()),
+                    exact: true,
+                };
+                let count = self
+                    .count(
+                        count_request,
+                        read_consistency,
+                        &shard_selection,
+                        timeout.map(|timeout| timeout.saturating_sub(start.elapsed())),
+                        hw_measurement_acc.clone(),
+                    )
+                    .await?
+                    .count;
+                if count > MAX_SCANNED_POINTS {
+                    return Err(CollectionError::bad_input(format!(
+                        "Can't look up duplicates of more than {MAX_SCANNED_POINTS} points, set `sample` to look up duplicates of a random subset of them",
+                    )));
+                }
+
+                // read all points page by page
+                let mut points = Vec::with_capacity(count);
+                let mut offset = None;
+                loop {
+                    let scroll_request = ScrollRequestInternal {
+                        offset,
+                        limit: Some(SCROLL_PAGE_SIZE),
+                        filter: Some(filter.clone()),
+                        with_payload: Some(WithPayloadInterface::Bool(false)),
+                        with_vector: WithVector::Selector(vec![using.clone()]),
+                        order_by: None,
+                    };
+                    let ScrollResult {
+                        points: page,
+                        next_page_offset,
+                    } = self
+                        .scroll_by(
+                            scroll_request,
+                            read_consistency,
+                            &shard_selection,
+                            timeout.map(|timeout| timeout.saturating_sub(start.elapsed())),
+                            hw_measurement_acc.clone(),
+                        )
+                        .await?;
+
+                    for record in page {
+                        let Some(vector) = record.vector else {
+                            continue;
+                        };
+                        let vector =
+                            VectorStructInternal::try_from(VectorStructPersisted::from(vector))?;
+                        if let Some(vector) = vector.get(&using) {
+                            points.push((record.id, vector.to_owned()));
+                        }
+                    }
+                    // points could be inserted after the count
+                    if points.len() > MAX_SCANNED_POINTS {
+                        return Err(CollectionError::bad_input(format!(
+                            "Can't look up duplicates of more than {MAX_SCANNED_POINTS} points, set `sample` to look up duplicates of a random subset of them",
+                        )));
+                    }
+
+                    match next_page_offset {
+                        Some(next_page_offset) => offset = Some(next_page_offset),
+                        None => break,
+                    }
+                }
+                points
+            }
+        };
+
+        // points which are already representatives or duplicates
+        let mut grouped = AHashSet::new();
+        let mut groups = Vec::new();
+
+        let mut points = points.into_iter().peekable();
+        while points.peek().is_some() {
+            // duplicates of the previous pages don't need a lookup
+            let mut page_ids = Vec::with_capacity(SCROLL_PAGE_SIZE);
+            let mut queries = Vec::with_capacity(SCROLL_PAGE_SIZE);
+            for (id, vector) in points.by_ref().take(SCROLL_PAGE_SIZE) {
+                if grouped.contains(&id) {
+                    continue;
+                }
+
+                let query_request = CollectionQueryRequest {
+                    prefetch: vec![],
+                    query: Some(Query::Vector(VectorQuery::Nearest(
+                        VectorInputInternal::Vector(vector),
+                    ))),
+                    using: using.clone(),
+                    filter: Some(filter.clone()),
+                    score_threshold: Some(score_threshold),
+                    limit: limit_per_point + 1, // +1 to exclude the point itself afterward
+                    offset: 0,
+                    params,
+                    with_vector: WithVector::Bool(false),
+                    with_payload: WithPayloadInterface::Bool(false),
+                    lookup_from: None,
+                    cursor: None,
+                };
+
+                page_ids.push(id);
+                queries.push((query_request, shard_selection.clone()));
+            }
+
+            if queries.is_empty() {
+                continue;
+            }
+
+            // We know by construction that lookup_from is not used in the queries
+            // so can use placeholder closure here
+            let collection_by_name = |_name: String| async move { None };
+
+            let neighbours = self
+                .query_batch(
+                    queries,
+                    collection_by_name,
+                    read_consistency,
+                    timeout.map(|timeout| timeout.saturating_sub(start.elapsed())),
+                    hw_measurement_acc.clone(),
+                )
+                .await?;
+
+            for (id, neighbours) in page_ids.into_iter().zip(neighbours) {
+                if let Some(group) = group_neighbours(id, neighbours, &mut grouped) {
+                    groups.push(group);
+                }
+            }
+        }
+
+        Ok(CollectionNearDuplicatesResponse { groups })
+    }
+}
+
+/// Make a group of the point and its neighbours, which are not grouped yet.
+///
+/// Returns `None` if the point is already grouped, or none of its neighbours are left.
+fn group_neighbours(
+    id: PointIdType,
+    neighbours: Vec<ScoredPoint>,
+    grouped: &mut AHashSet<PointIdType>,
+) -> Option<(PointIdType, Vec<ScoredPoint>)> {
+    if grouped.contains(&id) {
+        return None;
+    }
+
+    let duplicates: Vec<_> = neighbours
+        .into_iter()
+        .filter(|point| point.id != id && !grouped.contains(&point.id))
+        .collect();
+    if duplicates.is_empty() {
+        return None;
+    }
+
+    grouped.insert(id);
+    grouped.extend(duplicates.iter().map(|point| point.id));
+    Some((id, duplicates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_scored_point(id: u64, score: f32) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
+        }
+    }
+
+    #[test]
+    fn test_group_neighbours() {
+        let mut grouped = AHashSet::new();
+
+        // the point itself is not a duplicate
+        let group = group_neighbours(
+            1.into(),
+            vec![make_scored_point(1, 1.0), make_scored_point(2, 0.9)],
+            &mut grouped,
+        );
+        assert_eq!(
+            group.map(|(id, duplicates)| (id, duplicates.len())),
+            Some((1.into(), 1)),
+        );
+
+        // grouped points are neither representatives nor duplicates
+        assert!(
+            group_neighbours(2.into(), vec![make_scored_point(3, 0.9)], &mut grouped).is_none()
+        );
+        let group = group_neighbours(
+            3.into(),
+            vec![
+                make_scored_point(3, 1.0),
+                make_scored_point(1, 0.95),
+                make_scored_point(4, 0.9),
+            ],
+            &mut grouped,
+        );
+        let (id, duplicates) = group.unwrap();
+        assert_eq!(id, 3.into());
+        assert_eq!(
+            duplicates.iter().map(|point| point.id).collect::<Vec<_>>(),
+            vec![4.into()],
+        );
+
+        // no duplicates left
+        assert!(
+            group_neighbours(5.into(), vec![make_scored_point(5, 1.0)], &mut grouped).is_none()
+        );
+    }
+
+    #[test]
+    fn test_near_duplicates_response_conversion() {
+        let response = CollectionNearDuplicatesResponse {
+            groups: vec![
+                (
+                    1.into(),
+                    vec![make_scored_point(2, 0.9), make_scored_point(3, 0.8)],
+                ),
+                (4.into(), vec![make_scored_point(5, 0.7)]),
+            ],
+        };
+        let expected_ids: Vec<PointIdType> = vec![2.into(), 3.into(), 5.into()];
+        assert_eq!(response.duplicate_ids(), expected_ids);
+
+        let expected = NearDuplicatesResponse {
+            groups: vec![
+                DuplicateGroup {
+                    representative: 1.into(),
+                    duplicates: vec![
+                        DuplicatePoint {
+                            id: 2.into(),
+                            score: 0.9,
+                        },
+                        DuplicatePoint {
+                            id: 3.into(),
+                            score: 0.8,
+                        },
+                    ],
+                },
+                DuplicateGroup {
+                    representative: 4.into(),
+                    duplicates: vec![DuplicatePoint {
+                        id: 5.into(),
+                        score: 0.7,
+                    }],
+                },
+            ],
+            deleted: 0,
+        };
+        assert_eq!(NearDuplicatesResponse::from(response), expected);
+    }
+}
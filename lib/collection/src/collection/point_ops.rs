@@ -7,7 +7,7 @@ use common::types::DeferredBehavior;
 use futures::stream::FuturesUnordered;
 use futures::{StreamExt as _, TryFutureExt, TryStreamExt as _, future};
 use itertools::Itertools;
-use segment::data_types::order_by::{Direction, OrderBy};
+use segment::data_types::order_by::OrderBy;
 use segment::types::{ShardKey, WithPayload, WithPayloadInterface};
 use shard::count::CountRequestInternal;
 use shard::retrieve::record_internal::RecordInternal;
@@ -378,12 +378,11 @@ impl Collection {
             Some(order_by) => {
                 retrieved_iter
                     // Get top results
-                    .kmerge_by(|a, b| match order_by.direction() {
-                        Direction::Asc => (a.order_value, a.id) < (b.order_value, b.id),
-                        Direction::Desc => (a.order_value, a.id) > (b.order_value, b.id),
-                    })
+                    .kmerge_by(|a, b| order_by.cmp_keys(a.order_key(), b.order_key()).is_lt())
                     .dedup_by(|record_a, record_b| {
-                        (record_a.order_value, record_a.id) == (record_b.order_value, record_b.id)
+                        order_by
+                            .cmp_keys(record_a.order_key(), record_b.order_key())
+                            .is_eq()
                     })
                     .map(api::rest::Record::from)
                     .take(limit)
@@ -0,0 +1,495 @@
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use api::rest::{
+    QuantizationTuningCandidate, QuantizationTuningRequest, QuantizationTuningResponse,
+    QuantizationTuningResult,
+};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, DenseVector, VectorRef};
+use segment::types::{
+    BinaryQuantizationConfig, BinaryQuantizationEncoding, BinaryQuantizationQueryEncoding,
+    CompressionRatio, Condition, Distance, Filter, HasVectorCondition, ProductQuantizationConfig,
+    QuantizationConfig, QuantizationSearchParams, ScalarQuantizationConfig, ScalarType,
+    SearchParams, TurboQuantBitSize, TurboQuantQuantizationConfig, TurboQuantization,
+    VectorNameBuf, WithVector,
+};
+use segment::vector_storage::quantized::quantization_tuning::{
+    QuantizationEvaluation, QuantizationTuningSample,
+};
+use shard::common::stopping_guard::StoppingGuard;
+use shard::count::CountRequestInternal;
+use tokio_util::task::AbortOnDropHandle;
+
+use crate::collection::Collection;
+use crate::operations::consistency_params::ReadConsistency;
+use crate::operations::shard_selector_internal::ShardSelectorInternal;
+use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::universal_query::shard_query::{
+    SampleInternal, ScoringQuery, ShardQueryRequest,
+};
+
+/// Internal representation of the quantization tuning request, used to convert from REST.
+pub struct CollectionQuantizationTuningRequest {
+    pub using: VectorNameBuf,
+    pub filter: Option<Filter>,
+    pub sample_size: usize,
+    pub num_queries: usize,
+    pub limit: usize,
+    pub candidates: Vec<QuantizationTuningCandidate>,
+    pub target_recall: Option<f64>,
+}
+
+impl CollectionQuantizationTuningRequest {
+    pub const DEFAULT_SAMPLE: usize = 1000;
+    pub const DEFAULT_QUERIES: usize = 100;
+    pub const DEFAULT_LIMIT: usize = 10;
+
+    /// Candidates evaluated if the request doesn't specify any.
+    ///
+    /// Covers every quantization type, with oversampling and rescoring where it is needed to
+    /// compensate the loss of precision.
+    pub fn default_candidates() -> Vec<QuantizationTuningCandidate> {
+        let rescored = |oversampling| {
+            Some(QuantizationSearchParams {
+                ignore: false,
+                rescore: Some(true),
+                oversampling: Some(oversampling),
+            })
+        };
+        let scalar = |r#type| {
+            QuantizationConfig::from(ScalarQuantizationConfig {
+                r#type,
+                quantile: None,
+                always_ram: None,
+            })
+        };
+        let binary = |encoding, query_encoding| {
+            QuantizationConfig::from(BinaryQuantizationConfig {
+                always_ram: None,
+                encoding: Some(encoding),
+                query_encoding: Some(query_encoding),
+            })
+        };
+        let turbo = |bits| {
+            QuantizationConfig::Turbo(TurboQuantization {
+                turbo: TurboQuantQuantizationConfig {
+                    always_ram: None,
+                    bits: Some(bits),
+                },
+            })
+        };
+
+        [
+            (scalar(ScalarType::Int8), None),
+            (scalar(ScalarType::Int4), rescored(2.0)),
+            (
+                QuantizationConfig::from(ProductQuantizationConfig {
+                    compression: CompressionRatio::X16,
+                    always_ram: None,
+                    opq: None,
+                }),
+                rescored(2.0),
+            ),
+            (
+                binary(
+                    BinaryQuantizationEncoding::OneBit,
+                    BinaryQuantizationQueryEncoding::Default,
+                ),
+                rescored(2.0),
+            ),
+            (
+                binary(
+                    BinaryQuantizationEncoding::OneBit,
+                    BinaryQuantizationQueryEncoding::Default,
+                ),
+                rescored(4.0),
+            ),
+            (
+                binary(
+                    BinaryQuantizationEncoding::OneBit,
+                    BinaryQuantizationQueryEncoding::Scalar8Bits,
+                ),
+                rescored(2.0),
+            ),
+            (
+                binary(
+                    BinaryQuantizationEncoding::OneAndHalfBits,
+                    BinaryQuantizationQueryEncoding::Default,
+                ),
+                rescored(2.0),
+            ),
+            (
+                binary(
+                    BinaryQuantizationEncoding::TwoBits,
+                    BinaryQuantizationQueryEncoding::Default,
+                ),
+                rescored(2.0),
+            ),
+            (turbo(TurboQuantBitSize::Bits4), rescored(1.5)),
+            (turbo(TurboQuantBitSize::Bits2), rescored(2.0)),
+        ]
+        .into_iter()
+        .map(|(quantization, params)| QuantizationTuningCandidate {
+            quantization,
+            params,
+        })
+        .collect()
+    }
+}
+
+impl From<QuantizationTuningRequest> for CollectionQuantizationTuningRequest {
+    fn from(request: QuantizationTuningRequest) -> Self {
+        let QuantizationTuningRequest {
+            using,
+            filter,
+            sample,
+            queries,
+            limit,
+            candidates,
+            target_recall,
+            apply: _,
+            shard_key: _,
+        } = request;
+        Self {
+            using: using.unwrap_or_else(|| DEFAULT_VECTOR_NAME.to_owned()),
+            filter,
+            sample_size: sample.unwrap_or(Self::DEFAULT_SAMPLE),
+            num_queries: queries.unwrap_or(Self::DEFAULT_QUERIES),
+            limit: limit.unwrap_or(Self::DEFAULT_LIMIT),
+            candidates: candidates.unwrap_or_else(Self::default_candidates),
+            target_recall,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CollectionQuantizationTuningResponse {
+    pub sample_size: usize,
+    pub exact_latency: Duration,
+    pub results: Vec<QuantizationTuningResult>,
+    /// Index of the cheapest result, which reaches the target recall
+    pub recommended: Option<usize>,
+}
+
+impl CollectionQuantizationTuningResponse {
+    pub fn recommended(&self) -> Option<&QuantizationTuningResult> {
+        self.recommended.map(|idx| &self.results[idx])
+    }
+}
+
+impl From<CollectionQuantizationTuningResponse> for QuantizationTuningResponse {
+    fn from(response: CollectionQuantizationTuningResponse) -> Self {
+        let recommended = response.recommended().cloned();
+        let CollectionQuantizationTuningResponse {
+            sample_size,
+            exact_latency,
+            results,
+            recommended: _,
+        } = response;
+        let search_params = recommended.as_ref().map(|result| SearchParams {
+            quantization: Some(result.params),
+            ..Default::default()
+        });
+        Self {
+            sample: sample_size,
+            exact_latency_ms: exact_latency.as_secs_f64() * 1000.0,
+            results,
+            recommended,
+            search_params,
+            applied: false,
+        }
+    }
+}
+
+/// Find the result with the smallest memory footprint among ones reaching `target_recall`.
+/// Latency breaks ties.
+fn find_recommended(results: &[QuantizationTuningResult], target_recall: f64) -> Option<usize> {
+    results
+        .iter()
+        .enumerate()
+        .filter(|(_, result)| result.recall >= target_recall)
+        .min_by(|(_, a), (_, b)| {
+            a.memory_bytes
+                .cmp(&b.memory_bytes)
+                .then(a.latency_ms.total_cmp(&b.latency_ms))
+        })
+        .map(|(idx, _)| idx)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn evaluate_candidates(
+    vectors: Vec<DenseVector>,
+    distance: Distance,
+    num_queries: usize,
+    limit: usize,
+    candidates: &[QuantizationTuningCandidate],
+    path: &Path,
+    max_threads: usize,
+    stopped: &AtomicBool,
+) -> CollectionResult<(Duration, Vec<QuantizationEvaluation>)> {
+    let sample = QuantizationTuningSample::new(vectors, distance, num_queries, limit, stopped)?;
+    let evaluations = candidates
+        .iter()
+        .enumerate()
+        .map(|(idx, candidate)| {
+            let candidate_path = path.join(format!("candidate-{idx}"));
+            std::fs::create_dir_all(&candidate_path)?;
+            let evaluation = sample.evaluate(
+                &candidate.quantization,
+                candidate.params.unwrap_or_default(),
+                &candidate_path,
+                max_threads,
+                stopped,
+            )?;
+            // Quantized data is not needed anymore, free disk space for the next candidate
+            std::fs::remove_dir_all(&candidate_path)?;
+            Ok(evaluation)
+        })
+        .collect::<CollectionResult<_>>()?;
+    Ok((sample.exact_latency(), evaluations))
+}
+
+impl Collection {
+    /// Evaluate quantization configs on a random sample of points.
+    ///
+    /// Every candidate is trained on the sample and compared with exact search by recall.
+    /// Memory is estimated for all points of the collection, which have the vector.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn tune_quantization(
+        &self,
+        request: CollectionQuantizationTuningRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        temp_dir: &Path,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<CollectionQuantizationTuningResponse> {
+        let start = std::time::Instant::now();
+        let CollectionQuantizationTuningRequest {
+            using,
+            filter,
+            sample_size,
+            num_queries,
+            limit,
+            candidates,
+            target_recall,
+        } = request;
+
+        let distance = {
+            let collection_config = self.collection_config.read().await;
+            collection_config.params.check_vector_exists(&using)?;
+            let Some(vector_params) = collection_config.params.vectors.get_params(&using) else {
+                return Err(CollectionError::bad_input(format!(
+                    "Quantization tuning is not supported for sparse vector {using}",
+                )));
+            };
+            if vector_params.multivector_config.is_some() {
+                return Err(CollectionError::bad_input(format!(
+                    "Quantization tuning is not supported for multivector {using}",
+                )));
+            }
+            vector_params.distance
+        };
+
+        // make sure the vector is present in the point
+        let has_vector = Filter::new_must(Condition::HasVector(HasVectorCondition::from(
+            using.clone(),
+        )));
+
+        let vectors_count = self
+            .count(
+                CountRequestInternal {
+                    filter: Some(has_vector.clone()),
+                    exact: false,
+                },
+                read_consistency,
+                &shard_selection,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?
+            .count;
+
+        // merge user's filter with the has_vector filter
+        let filter = Some(
+            filter
+                .map(|filter| filter.merge(&has_vector))
+                .unwrap_or(has_vector),
+        );
+
+        // sample random points
+        let sampling_query = ShardQueryRequest {
+            prefetches: vec![],
+            query: Some(ScoringQuery::Sample(SampleInternal::Random)),
+            filter,
+            score_threshold: None,
+            limit: sample_size,
+            offset: 0,
+            start_after: None,
+            params: None,
+            with_vector: WithVector::Selector(vec![using.clone()]), // retrieve the vector
+            with_payload: Default::default(),
+        };
+
+        let sampled_points = self
+            .query(
+                sampling_query,
+                read_consistency,
+                shard_selection,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        let vectors: Vec<DenseVector> = sampled_points
+            .iter()
+            .take(sample_size)
+            .filter_map(|point| match point.vector.as_ref()?.get(&using)? {
+                VectorRef::Dense(vector) => Some(vector.to_vec()),
+                VectorRef::Sparse(_) | VectorRef::MultiDense(_) => None,
+            })
+            .collect();
+
+        // if we have less than 2 points, there is nothing to compare with
+        if vectors.len() < 2 {
+            return Err(CollectionError::bad_input(format!(
+                "Not enough points with vector {using} to tune quantization",
+            )));
+        }
+
+        let sample_size = vectors.len();
+        let tuning_dir = tempfile::Builder::new()
+            .prefix("quantization-tuning-")
+            .tempdir_in(temp_dir)
+            .map_err(|err| {
+                CollectionError::service_error(format!(
+                    "failed to create temporary quantization tuning directory in {}: {err}",
+                    temp_dir.display(),
+                ))
+            })?;
+        let max_threads = self.shared_storage_config.search_thread_count.max(1);
+
+        let stopping_guard = StoppingGuard::new();
+        let task = self.search_runtime.spawn_blocking({
+            let candidates = candidates.clone();
+            let path = tuning_dir.path().to_path_buf();
+            let is_stopped = stopping_guard.get_is_stopped();
+            move || {
+                evaluate_candidates(
+                    vectors,
+                    distance,
+                    num_queries,
+                    limit,
+                    &candidates,
+                    &path,
+                    max_threads,
+                    &is_stopped,
+                )
+            }
+        });
+        let task = AbortOnDropHandle::new(task);
+
+        let (exact_latency, evaluations) = match timeout {
+            Some(timeout) => {
+                let timeout = timeout.saturating_sub(start.elapsed());
+                tokio::time::timeout(timeout, task)
+                    .await
+                    .map_err(|_| CollectionError::timeout(timeout, "tune_quantization"))???
+            }
+            None => task.await??,
+        };
+
+        let results: Vec<_> = candidates
+            .into_iter()
+            .zip(evaluations)
+            .map(|(candidate, evaluation)| {
+                let QuantizationEvaluation {
+                    recall,
+                    vector_size_bytes,
+                    latency,
+                } = evaluation;
+                QuantizationTuningResult {
+                    quantization: candidate.quantization,
+                    params: candidate.params.unwrap_or_default(),
+                    recall,
+                    memory_bytes: vector_size_bytes * vectors_count,
+                    latency_ms: latency.as_secs_f64() * 1000.0,
+                }
+            })
+            .collect();
+
+        let recommended =
+            target_recall.and_then(|target_recall| find_recommended(&results, target_recall));
+
+        Ok(CollectionQuantizationTuningResponse {
+            sample_size,
+            exact_latency,
+            results,
+            recommended,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_result(recall: f64, memory_bytes: usize, latency_ms: f64) -> QuantizationTuningResult {
+        QuantizationTuningResult {
+            quantization: QuantizationConfig::from(ScalarQuantizationConfig {
+                r#type: ScalarType::Int8,
+                quantile: None,
+                always_ram: None,
+            }),
+            params: QuantizationSearchParams::default(),
+            recall,
+            memory_bytes,
+            latency_ms,
+        }
+    }
+
+    #[test]
+    fn test_find_recommended() {
+        let results = vec![
+            make_result(0.99, 400, 1.0),
+            make_result(0.80, 50, 0.5),
+            make_result(0.95, 100, 0.8),
+            make_result(0.96, 100, 0.6),
+        ];
+
+        assert_eq!(find_recommended(&results, 0.9), Some(3));
+        assert_eq!(find_recommended(&results, 0.5), Some(1));
+        assert_eq!(find_recommended(&results, 0.98), Some(0));
+        assert_eq!(find_recommended(&results, 0.999), None);
+    }
+
+    #[test]
+    fn test_recommended_search_params() {
+        let params = QuantizationSearchParams {
+            ignore: false,
+            rescore: Some(true),
+            oversampling: Some(2.0),
+        };
+        let mut rescored = make_result(0.95, 100, 0.8);
+        rescored.params = params;
+        let response = CollectionQuantizationTuningResponse {
+            sample_size: 100,
+            exact_latency: Duration::from_millis(1),
+            results: vec![make_result(0.99, 400, 1.0), rescored],
+            recommended: Some(1),
+        };
+
+        let response = QuantizationTuningResponse::from(response);
+        let search_params = response.search_params.unwrap();
+        assert_eq!(search_params.quantization, Some(params));
+        assert!(!search_params.exact);
+
+        let response = QuantizationTuningResponse::from(CollectionQuantizationTuningResponse {
+            recommended: None,
+            ..Default::default()
+        });
+        assert!(response.search_params.is_none());
+    }
+}
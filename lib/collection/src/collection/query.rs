@@ -27,7 +27,8 @@ use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::universal_query::collection_query::CollectionQueryRequest;
 use crate::operations::universal_query::shard_query::{
-    self, FusionInternal, MmrInternal, ScoringQuery, ShardQueryRequest, ShardQueryResponse,
+    self, FusionInternal, MmrInternal, SampleInternal, ScoringQuery, ShardQueryRequest,
+    ShardQueryResponse,
 };
 
 /// A factor which determines if we need to use the 2-step search or not.
@@ -345,7 +346,7 @@ impl Collection {
     /// Finalizes queries like fusion and mmr after collecting from all shards.
     /// For other kind of queries it just passes the results through.
     ///
-    /// Handles offset and limit.
+    /// Handles offset and limit, or the position to continue from instead of offset.
     async fn intermediates_to_final_list(
         &self,
         mut intermediates: Vec<Vec<ScoredPoint>>,
@@ -360,6 +361,7 @@ impl Collection {
             score_threshold,
             limit,
             offset,
+            start_after,
             params: _,
             with_vector,
             with_payload: _,
@@ -377,6 +379,8 @@ impl Collection {
                     }
                     FusionInternal::Dbsf => score_fusion(intermediates, ScoreFusion::dbsf()),
                 };
+                // Fused scores are often equal, order them by IDs to keep pages consistent
+                fused.sort_by(|a, b| ScoredPointTies(b).cmp(&ScoredPointTies(a)));
                 if let Some(&score_threshold) = score_threshold.as_ref() {
                     fused = fused
                         .into_iter()
@@ -419,11 +423,21 @@ impl Collection {
                 };
                 mmr_result
             }
+            Some(ScoringQuery::Sample(SampleInternal::Stratified(stratified))) => {
+                // Each shard samples up to `per_value` points for each value
+                debug_assert_eq!(intermediates.len(), 1);
+                let merged = intermediates.pop().ok_or_else(|| {
+                    CollectionError::service_error(
+                        "Query response was expected to have one list of results.",
+                    )
+                })?;
+                stratified.restrict_merged(merged)
+            }
             None
             | Some(ScoringQuery::Vector(_))
             | Some(ScoringQuery::OrderBy(_))
             | Some(ScoringQuery::Formula(_))
-            | Some(ScoringQuery::Sample(_)) => {
+            | Some(ScoringQuery::Sample(SampleInternal::Random | SampleInternal::Weighted(_))) => {
                 // Otherwise, it will be a list with a single list of scored points.
                 debug_assert_eq!(intermediates.len(), 1);
                 intermediates.pop().ok_or_else(|| {
@@ -434,7 +448,22 @@ impl Collection {
             }
         };
 
-        let result: Vec<ScoredPoint> = result.into_iter().skip(*offset).take(*limit).collect();
+        let result: Vec<ScoredPoint> = match start_after {
+            // Shards skip points up to the position, but fusion is only computed here
+            Some(position) => {
+                let collection_params = self.collection_config.read().await.params.clone();
+                let order = shard_query::query_result_order(query.as_ref(), &collection_params)?
+                    .ok_or_else(|| {
+                        CollectionError::bad_request("Results of the query can't be continued")
+                    })?;
+                result
+                    .into_iter()
+                    .filter(|point| position.is_before(point, order))
+                    .take(*limit)
+                    .collect()
+            }
+            None => result.into_iter().skip(*offset).take(*limit).collect(),
+        };
 
         Ok(result)
     }
@@ -651,7 +680,18 @@ impl Collection {
             // if the `kmerge_by` function were able to work with reference predicates.
             // Either::Left and Either::Right are used to allow type inference to work.
             //
-            let intermediate_result = if let Some(order) = order {
+            let intermediate_result = if let Some(ScoringQuery::OrderBy(order_by)) =
+                query_info.scoring_query
+                && order_by.resolves_ties()
+            {
+                // Values of `then_by` keys are not reflected in the score
+                shards_results
+                    .into_iter()
+                    .kmerge_by(|a, b| order_by.cmp_keys(a.order_key(), b.order_key()).is_lt())
+                    .dedup()
+                    .take(query_info.take)
+                    .collect()
+            } else if let Some(order) = order {
                 let best_last_result = Self::get_best_last_shard_result(&shards_results, order);
 
                 let merged: Vec<_> = match order {
@@ -732,11 +772,18 @@ fn intermediate_query_infos(request: &ShardQueryRequest) -> Vec<IntermediateQuer
                 take: *candidates_limit,
             }]
         }
+        Some(ScoringQuery::Sample(SampleInternal::Stratified(_))) => {
+            // In case of stratified sample, keep all the points to restrict them per value
+            vec![IntermediateQueryInfo {
+                scoring_query: request.query.as_ref(),
+                take: usize::MAX,
+            }]
+        }
         None
         | Some(ScoringQuery::Vector(_))
         | Some(ScoringQuery::OrderBy(_))
         | Some(ScoringQuery::Formula(_))
-        | Some(ScoringQuery::Sample(_)) => {
+        | Some(ScoringQuery::Sample(SampleInternal::Random | SampleInternal::Weighted(_))) => {
             // Otherwise, we expect the root result
             vec![IntermediateQueryInfo {
                 scoring_query: request.query.as_ref(),
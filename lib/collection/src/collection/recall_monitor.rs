@@ -0,0 +1,459 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use parking_lot::Mutex;
+use rand::seq::SliceRandom;
+use segment::data_types::vectors::{NamedQuery, VectorInternal};
+use segment::types::{
+    Condition, Filter, HasVectorCondition, PointIdType, ScoredPoint, SearchParams, VectorNameBuf,
+    WithVector,
+};
+use serde::{Deserialize, Serialize};
+use shard::query::query_enum::QueryEnum;
+use shard::search::{CoreSearchRequest, CoreSearchRequestBatch};
+use tokio::sync::RwLock;
+use tokio_util::task::AbortOnDropHandle;
+use validator::Validate;
+
+use crate::config::CollectionConfigInternal;
+use crate::operations::types::CollectionResult;
+use crate::operations::universal_query::shard_query::{
+    SampleInternal, ScoringQuery, ShardQueryRequest,
+};
+use crate::shards::CollectionId;
+use crate::shards::replica_set::ShardReplicaSet;
+use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::shard_holder::SharedShardHolder;
+use crate::telemetry::VectorRecallTelemetry;
+
+const DEFAULT_INTERVAL_SEC: u64 = 600;
+const DEFAULT_QUERIES: usize = 10;
+const DEFAULT_LIMIT: usize = 10;
+const DEFAULT_CPU_BUDGET: f64 = 0.01;
+
+/// Configuration of the background recall monitor.
+///
+/// If enabled, every collection periodically searches some of its stored vectors both through
+/// the vector index and exactly, and reports the overlap of the results as recall@k.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Validate)]
+pub struct RecallMonitorConfig {
+    /// Minimal interval between two measurements, in seconds.
+    #[serde(default = "default_interval_sec")]
+    #[validate(range(min = 1))]
+    pub interval_sec: u64,
+    /// How many stored vectors to use as queries, per named vector and local shard.
+    #[serde(default = "default_queries")]
+    #[validate(range(min = 1, max = 100))]
+    pub queries: usize,
+    /// How many nearest neighbours to compare, the `k` in recall@k.
+    #[serde(default = "default_limit")]
+    #[validate(range(min = 1, max = 100))]
+    pub limit: usize,
+    /// Fraction of a single CPU the monitor may use on average, measured as CPU time of the searches.
+    /// A measurement stops once it used the budget of one interval.
+    /// If it used more, the next one is delayed.
+    #[serde(default = "default_cpu_budget")]
+    #[validate(range(min = 0.0001, max = 1.0))]
+    pub cpu_budget: f64,
+}
+
+const fn default_interval_sec() -> u64 {
+    DEFAULT_INTERVAL_SEC
+}
+
+const fn default_queries() -> usize {
+    DEFAULT_QUERIES
+}
+
+const fn default_limit() -> usize {
+    DEFAULT_LIMIT
+}
+
+const fn default_cpu_budget() -> f64 {
+    DEFAULT_CPU_BUDGET
+}
+
+impl Default for RecallMonitorConfig {
+    fn default() -> Self {
+        Self {
+            interval_sec: DEFAULT_INTERVAL_SEC,
+            queries: DEFAULT_QUERIES,
+            limit: DEFAULT_LIMIT,
+            cpu_budget: DEFAULT_CPU_BUDGET,
+        }
+    }
+}
+
+impl RecallMonitorConfig {
+    /// Delay before the next measurement, given the CPU and wall time spent on the last one.
+    fn next_delay(&self, cpu_time: Duration, wall_time: Duration) -> Duration {
+        let interval = Duration::from_secs(self.interval_sec);
+        let budgeted = cpu_time.div_f64(self.cpu_budget).saturating_sub(wall_time);
+        interval.max(budgeted)
+    }
+
+    /// CPU time a single measurement may use, also bounds the wall time of each search.
+    fn cpu_allowance(&self) -> Duration {
+        Duration::from_secs(self.interval_sec).mul_f64(self.cpu_budget)
+    }
+}
+
+/// Found and expected exact nearest neighbours of a measurement.
+#[derive(Debug, Default, Clone, Copy)]
+struct RecallCounts {
+    found: usize,
+    expected: usize,
+    queries: usize,
+}
+
+impl RecallCounts {
+    fn add(&mut self, other: Self) {
+        self.found += other.found;
+        self.expected += other.expected;
+        self.queries += other.queries;
+    }
+}
+
+/// Background task, which measures recall of the vector indexes in the local shards of a
+/// collection.
+///
+/// The task is stopped when the monitor is dropped.
+#[derive(Default)]
+pub(crate) struct RecallMonitor {
+    recall: Arc<Mutex<HashMap<VectorNameBuf, VectorRecallTelemetry>>>,
+    _task: Option<AbortOnDropHandle<()>>,
+}
+
+impl RecallMonitor {
+    /// Start the monitor, if it is enabled in the config.
+    pub fn start(
+        config: Option<RecallMonitorConfig>,
+        collection_id: CollectionId,
+        shards_holder: SharedShardHolder,
+        collection_config: Arc<RwLock<CollectionConfigInternal>>,
+    ) -> Self {
+        let Some(config) = config else {
+            return Self::default();
+        };
+
+        let recall = Arc::new(Mutex::new(HashMap::new()));
+        let task = tokio::spawn(Self::run(
+            config,
+            collection_id,
+            shards_holder,
+            collection_config,
+            recall.clone(),
+        ));
+
+        Self {
+            recall,
+            _task: Some(AbortOnDropHandle::new(task)),
+        }
+    }
+
+    /// Recall of the last measurement per named vector, `None` if nothing was measured yet.
+    pub fn telemetry(&self) -> Option<HashMap<VectorNameBuf, VectorRecallTelemetry>> {
+        let recall = self.recall.lock();
+        (!recall.is_empty()).then(|| recall.clone())
+    }
+
+    async fn run(
+        config: RecallMonitorConfig,
+        collection_id: CollectionId,
+        shards_holder: SharedShardHolder,
+        collection_config: Arc<RwLock<CollectionConfigInternal>>,
+        recall: Arc<Mutex<HashMap<VectorNameBuf, VectorRecallTelemetry>>>,
+    ) {
+        let mut delay = Duration::from_secs(config.interval_sec);
+        loop {
+            tokio::time::sleep(delay).await;
+
+            let timer = Instant::now();
+            let hw_measurement_acc = HwMeasurementAcc::disposable();
+            let cpu_utilization = hw_measurement_acc.cpu_utilization();
+            match Self::measure(
+                &config,
+                &shards_holder,
+                &collection_config,
+                &hw_measurement_acc,
+            )
+            .await
+            {
+                Ok(counts) => {
+                    let mut recall = recall.lock();
+                    recall.retain(|name, _| counts.contains_key(name));
+                    for (name, counts) in counts {
+                        if counts.expected == 0 {
+                            continue;
+                        }
+                        let entry = recall.entry(name).or_insert(VectorRecallTelemetry {
+                            recall: 0.0,
+                            limit: config.limit,
+                            queries: 0,
+                            measurements: 0,
+                        });
+                        entry.recall = counts.found as f64 / counts.expected as f64;
+                        entry.limit = config.limit;
+                        entry.queries = counts.queries;
+                        entry.measurements += 1;
+                    }
+                }
+                Err(err) => {
+                    log::warn!("Failed to measure recall of collection {collection_id}: {err}");
+                }
+            }
+            let wall_time = timer.elapsed();
+            // Thread CPU time is only available on Linux, assume the searches were busy otherwise
+            let cpu_time = if cfg!(target_os = "linux") {
+                cpu_utilization.cpu_time()
+            } else {
+                wall_time
+            };
+            delay = config.next_delay(cpu_time, wall_time);
+        }
+    }
+
+    /// Measure recall of every dense vector in all readable local shards.
+    ///
+    /// Stops early once the CPU allowance of a measurement is used,
+    /// the rest of the shards is measured next time.
+    async fn measure(
+        config: &RecallMonitorConfig,
+        shards_holder: &SharedShardHolder,
+        collection_config: &RwLock<CollectionConfigInternal>,
+        hw_measurement_acc: &HwMeasurementAcc,
+    ) -> CollectionResult<HashMap<VectorNameBuf, RecallCounts>> {
+        let vector_names: Vec<VectorNameBuf> = collection_config
+            .read()
+            .await
+            .params
+            .vectors
+            .params_iter()
+            .map(|(name, _)| name.to_owned())
+            .collect();
+
+        // Don't hold the shard holder lock while searching
+        let mut shards: Vec<Arc<ShardReplicaSet>> =
+            shards_holder.read().await.all_shards().cloned().collect();
+        // Start with a random shard, so all of them are measured eventually
+        shards.shuffle(&mut rand::rng());
+
+        let cpu_utilization = hw_measurement_acc.cpu_utilization();
+        let mut counts: HashMap<VectorNameBuf, RecallCounts> = HashMap::new();
+        for replica_set in shards {
+            if cpu_utilization.cpu_time() >= config.cpu_allowance() {
+                log::debug!("Recall measurement stopped, CPU budget of the interval is used");
+                break;
+            }
+
+            let is_readable = replica_set
+                .peer_state(replica_set.this_peer_id())
+                .is_some_and(ReplicaState::is_readable);
+            if !is_readable || !replica_set.has_local_shard().await {
+                continue;
+            }
+
+            for name in &vector_names {
+                let shard_counts =
+                    Self::measure_shard(config, &replica_set, name, hw_measurement_acc.clone())
+                        .await?;
+                counts.entry(name.clone()).or_default().add(shard_counts);
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Search random stored vectors of a local shard through the index and exactly.
+    async fn measure_shard(
+        config: &RecallMonitorConfig,
+        replica_set: &ShardReplicaSet,
+        using: &VectorNameBuf,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<RecallCounts> {
+        let timeout = Some(config.cpu_allowance());
+
+        let sampling_query = ShardQueryRequest {
+            prefetches: vec![],
+            query: Some(ScoringQuery::Sample(SampleInternal::Random)),
+            filter: Some(Filter::new_must(Condition::HasVector(
+                HasVectorCondition::from(using.clone()),
+            ))),
+            score_threshold: None,
+            limit: config.queries,
+            offset: 0,
+            start_after: None,
+            params: None,
+            with_vector: WithVector::Selector(vec![using.clone()]),
+            with_payload: Default::default(),
+        };
+
+        let sampled_points = replica_set
+            .query_batch(
+                Arc::new(vec![sampling_query]),
+                None,
+                true,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+
+        let queries: Vec<(PointIdType, VectorInternal)> = sampled_points
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|point| {
+                let vector = point.vector.as_ref()?.get(using)?.to_owned();
+                Some((point.id, vector))
+            })
+            .collect();
+
+        if queries.is_empty() {
+            return Ok(RecallCounts::default());
+        }
+
+        let search = |exact| CoreSearchRequestBatch {
+            searches: queries
+                .iter()
+                .map(|(_, vector)| CoreSearchRequest {
+                    query: QueryEnum::Nearest(NamedQuery::new(vector.clone(), using.clone())),
+                    filter: None,
+                    params: Some(SearchParams {
+                        exact,
+                        ..Default::default()
+                    }),
+                    // One extra result, because the query itself is always among the candidates
+                    limit: config.limit + 1,
+                    offset: 0,
+                    with_payload: None,
+                    with_vector: None,
+                    score_threshold: None,
+                })
+                .collect(),
+        };
+
+        let indexed = replica_set
+            .core_search(
+                Arc::new(search(false)),
+                None,
+                true,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+        let exact = replica_set
+            .core_search(
+                Arc::new(search(true)),
+                None,
+                true,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await?;
+
+        let mut counts = RecallCounts::default();
+        for (((query_id, _), indexed), exact) in queries.iter().zip(&indexed).zip(&exact) {
+            counts.add(RecallCounts::compare(
+                *query_id,
+                indexed,
+                exact,
+                config.limit,
+            ));
+        }
+
+        Ok(counts)
+    }
+}
+
+impl RecallCounts {
+    /// Compare the results of a query by a stored vector, the stored point itself is not counted.
+    fn compare(
+        query_id: PointIdType,
+        indexed: &[ScoredPoint],
+        exact: &[ScoredPoint],
+        limit: usize,
+    ) -> Self {
+        let neighbours = |points: &[ScoredPoint]| -> Vec<PointIdType> {
+            points
+                .iter()
+                .map(|point| point.id)
+                .filter(|id| *id != query_id)
+                .take(limit)
+                .collect()
+        };
+        let indexed = neighbours(indexed);
+        let exact = neighbours(exact);
+        Self {
+            found: exact.iter().filter(|id| indexed.contains(id)).count(),
+            expected: exact.len(),
+            queries: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_delay_respects_cpu_budget() {
+        let config = RecallMonitorConfig {
+            interval_sec: 60,
+            cpu_budget: 0.1,
+            ..Default::default()
+        };
+
+        // Cheap measurement, the interval dominates
+        assert_eq!(
+            config.next_delay(Duration::from_secs(1), Duration::from_secs(1)),
+            Duration::from_secs(60),
+        );
+
+        // Expensive measurement, wait until the average usage drops to the budget
+        assert_eq!(
+            config.next_delay(Duration::from_secs(10), Duration::from_secs(10)),
+            Duration::from_secs(90),
+        );
+
+        // Only the CPU time counts, not the time spent waiting
+        assert_eq!(
+            config.next_delay(Duration::from_secs(1), Duration::from_secs(100)),
+            Duration::from_secs(60),
+        );
+        assert_eq!(
+            config.next_delay(Duration::from_secs(40), Duration::from_secs(20)),
+            Duration::from_secs(380),
+        );
+
+        assert_eq!(config.cpu_allowance(), Duration::from_secs(6));
+    }
+
+    #[test]
+    fn test_compare_excludes_query_point() {
+        let point = |id: u64| ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score: 0.0,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
+        };
+        let points = |ids: &[u64]| ids.iter().copied().map(point).collect::<Vec<_>>();
+
+        let counts =
+            RecallCounts::compare(1.into(), &points(&[1, 2, 5, 4]), &points(&[1, 2, 3, 4]), 2);
+        assert_eq!(counts.found, 1);
+        assert_eq!(counts.expected, 2);
+        assert_eq!(counts.queries, 1);
+
+        // The query point may be missing from the results of the index
+        let counts = RecallCounts::compare(1.into(), &points(&[2, 3]), &points(&[1, 2, 3]), 2);
+        assert_eq!(counts.found, 2);
+        assert_eq!(counts.expected, 2);
+    }
+}
@@ -154,6 +154,7 @@ impl Collection {
                 strict_mode_config,
                 uuid: _,
                 metadata,
+                payload_validation,
             } = &new_config;
 
             let is_core_config_updated = params != &config.params
@@ -162,6 +163,7 @@ impl Collection {
                 || quantization_config != &config.quantization_config;
 
             let is_metadata_updated = metadata != &config.metadata;
+            let is_payload_validation_updated = payload_validation != &config.payload_validation;
 
             let is_wal_config_updated = wal_config != &config.wal_config;
             let is_strict_mode_config_updated = strict_mode_config != &config.strict_mode_config;
@@ -169,7 +171,8 @@ impl Collection {
             let is_config_updated = is_core_config_updated
                 || is_wal_config_updated
                 || is_strict_mode_config_updated
-                || is_metadata_updated;
+                || is_metadata_updated
+                || is_payload_validation_updated;
 
             if !is_config_updated {
                 return Ok(());
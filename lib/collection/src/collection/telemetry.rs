@@ -45,6 +45,7 @@ impl Collection {
             transfers,
             resharding,
             shard_clean_tasks: (!shard_clean_tasks.is_empty()).then_some(shard_clean_tasks),
+            recall: self.recall_monitor.telemetry(),
         })
     }
 }
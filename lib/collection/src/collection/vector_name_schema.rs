@@ -22,6 +22,23 @@ impl Collection {
         config: VectorNameConfig,
         hw_acc: HwMeasurementAcc,
     ) -> CollectionResult<()> {
+        // Collection-level quantization would apply to the new vector as well
+        if let VectorNameConfig::Dense(wrapper) = &config
+            && !dense_config_to_params(&wrapper.dense).is_quantization_supported()
+            && self
+                .collection_config
+                .read()
+                .await
+                .quantization_config
+                .is_some()
+        {
+            return Err(CollectionError::bad_input(format!(
+                "Quantization is not supported for vector `{vector_name}`: \
+                 bit vectors and vectors with Hamming or Jaccard distance can't be quantized, \
+                 but the collection has quantization configured"
+            )));
+        }
+
         self.update_collection_vector_config(|params| {
             add_vector_to_config(params, &vector_name, &config)
         })
@@ -107,6 +124,9 @@ fn dense_config_to_params(config: &DenseVectorConfig) -> VectorParams {
         on_disk: None,
         datatype: datatype.map(storage_datatype_to_collection),
         multivector_config: *multivector_config,
+        matryoshka_dim: None,
+        vamana_config: None,
+        ivf_config: None,
     }
 }
 
@@ -243,5 +263,6 @@ fn storage_datatype_to_collection(
             crate::operations::types::Datatype::Float16
         }
         segment::types::VectorStorageDatatype::Uint8 => crate::operations::types::Datatype::Uint8,
+        segment::types::VectorStorageDatatype::Bit => crate::operations::types::Datatype::Bit,
     }
 }
@@ -150,26 +150,31 @@ mod tests {
                 id: 11.into(),
                 vector: VectorStructPersisted::from(VectorStructInternal::from(vec11)),
                 payload: None,
+                version: None,
             },
             PointStructPersisted {
                 id: 12.into(),
                 vector: VectorStructPersisted::from(VectorStructInternal::from(vec12)),
                 payload: None,
+                version: None,
             },
             PointStructPersisted {
                 id: 13.into(),
                 vector: VectorStructPersisted::from(VectorStructInternal::from(vec13)),
                 payload: Some(payload_json! { "color": "red" }),
+                version: None,
             },
             PointStructPersisted {
                 id: 14.into(),
                 vector: VectorStructPersisted::Single(vec![0., 0., 0., 0.]),
                 payload: None,
+                version: None,
             },
             PointStructPersisted {
                 id: 500.into(),
                 vector: VectorStructPersisted::Single(vec![2., 0., 2., 0.]),
                 payload: None,
+                version: None,
             },
         ];
 
@@ -202,11 +207,13 @@ mod tests {
                 id: 1.into(),
                 vector: VectorStructPersisted::Single(vec![2., 2., 2., 2.]),
                 payload: None,
+                version: None,
             },
             PointStructPersisted {
                 id: 500.into(),
                 vector: VectorStructPersisted::Single(vec![2., 0., 2., 0.]),
                 payload: None,
+                version: None,
             },
         ];
 
@@ -293,6 +300,7 @@ mod tests {
                 points: Some(points.clone()),
                 filter: None,
                 key: None,
+                if_version: None,
             }),
             &hw_counter,
         )
@@ -450,6 +458,7 @@ mod tests {
                 points: Some(points.clone()),
                 filter: None,
                 key: Some(meta_key_path.clone()),
+                if_version: None,
             }),
             &hw_counter,
         )
@@ -514,6 +523,7 @@ mod tests {
                 points: Some(points.clone()),
                 filter: None,
                 key: Some(meta_key_path.clone()),
+                if_version: None,
             }),
             &hw_counter,
         )
@@ -46,6 +46,8 @@ mod tests {
                         on_disk: None,
                         hnsw_config: HnswConfig::default(),
                         quantization_config: None,
+                        vamana_config: None,
+                        ivf_config: None,
                     });
                 (name.clone(), cfg)
             })
@@ -136,6 +138,8 @@ mod tests {
                 on_disk: None,
                 hnsw_config,
                 quantization_config: None,
+                vamana_config: None,
+                ivf_config: None,
             },
         );
         let optimizer_config = segment_optimizer_config(&base_segment_config, &dense_overrides);
@@ -183,6 +187,8 @@ mod tests {
                 on_disk: None,
                 hnsw_config: changed_hnsw_config,
                 quantization_config: None,
+                vamana_config: None,
+                ivf_config: None,
             },
         );
         let changed_optimizer_config =
@@ -289,6 +295,8 @@ mod tests {
                 on_disk: Some(true),
                 hnsw_config: hnsw_config_vector1,
                 quantization_config: None,
+                vamana_config: None,
+                ivf_config: None,
             },
         );
         dense_overrides.insert(
@@ -297,6 +305,8 @@ mod tests {
                 on_disk: None,
                 hnsw_config: hnsw_config_vector2,
                 quantization_config: None,
+                vamana_config: None,
+                ivf_config: None,
             },
         );
         let optimizer_config = segment_optimizer_config(&base_segment_config, &dense_overrides);
@@ -343,6 +353,8 @@ mod tests {
                 on_disk: None,
                 hnsw_config: hnsw_config_vector2_changed,
                 quantization_config: None,
+                vamana_config: None,
+                ivf_config: None,
             },
         );
         let changed_optimizer_config =
@@ -454,6 +466,8 @@ mod tests {
                 on_disk: None,
                 hnsw_config: HnswConfig::default(),
                 quantization_config: Some(quantization_config_vector1.clone()),
+                vamana_config: None,
+                ivf_config: None,
             },
         );
         dense_overrides.insert(
@@ -462,6 +476,8 @@ mod tests {
                 on_disk: None,
                 hnsw_config: HnswConfig::default(),
                 quantization_config: Some(quantization_config_collection.clone()),
+                vamana_config: None,
+                ivf_config: None,
             },
         );
         let optimizer_config = segment_optimizer_config(&base_segment_config, &dense_overrides);
@@ -503,6 +519,7 @@ mod tests {
             product: ProductQuantizationConfig {
                 compression: CompressionRatio::X32,
                 always_ram: Some(true),
+                opq: None,
             },
         });
         dense_overrides.insert(
@@ -511,6 +528,8 @@ mod tests {
                 on_disk: None,
                 hnsw_config: HnswConfig::default(),
                 quantization_config: Some(quantization_config_vector2.clone()),
+                vamana_config: None,
+                ivf_config: None,
             },
         );
         let changed_optimizer_config =
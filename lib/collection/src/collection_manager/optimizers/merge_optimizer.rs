@@ -61,6 +61,8 @@ mod tests {
                     on_disk: None,
                     hnsw_config: HnswConfig::default(),
                     quantization_config: None,
+                    vamana_config: None,
+                    ivf_config: None,
                 },
             );
         }
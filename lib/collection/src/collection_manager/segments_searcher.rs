@@ -3,12 +3,14 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use ahash::AHashMap;
+use api::rest::models::SegmentStageExplanation;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::{DeferredBehavior, ScoreType};
 use futures::stream::FuturesUnordered;
 use futures::{FutureExt, TryStreamExt};
 use itertools::Itertools;
 use ordered_float::Float;
+use parking_lot::Mutex;
 use segment::common::operation_error::OperationError;
 use segment::data_types::modifier::Modifier;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
@@ -32,7 +34,7 @@ use crate::collection_manager::holders::segment_holder::LockedSegment;
 use crate::collection_manager::probabilistic_search_sampling::find_search_sampling_over_point_distribution;
 use crate::common::adaptive_handle::AdaptiveSearchHandle;
 use crate::config::CollectionConfigInternal;
-use crate::operations::types::{CollectionError, CollectionResult};
+use crate::operations::types::{CollectionError, CollectionResult, CoreSearchRequest};
 
 type BatchOffset = usize;
 type SegmentOffset = usize;
@@ -470,6 +472,82 @@ impl SegmentsSearcher {
         AbortOnDropHandle::new(points).await?
     }
 
+    /// Explain how each segment executes the search, and measure the search in it.
+    pub async fn explain_search(
+        segments: LockedSegmentHolder,
+        request: Arc<CoreSearchRequest>,
+        runtime_handle: &AdaptiveSearchHandle,
+        query_context: QueryContext,
+        timeout: Duration,
+    ) -> CollectionResult<Vec<SegmentStageExplanation>> {
+        let query_context = Arc::new(query_context);
+
+        // Collect the segments first so we don't lock the segment holder during the operations.
+        let segments: Vec<_> = {
+            let Some(segments_lock) = segments.try_read_for(timeout) else {
+                return Err(CollectionError::timeout(timeout, "explain search"));
+            };
+            segments_lock
+                .non_appendable_then_appendable_segments()
+                .collect()
+        };
+
+        let explanations = segments.into_iter().map(|segment| {
+            let (request, query_context) = (request.clone(), query_context.clone());
+            let handle = runtime_handle.spawn_blocking(move || {
+                let segment_query_context = query_context.get_segment_query_context();
+                explain_search_in_segment(segment, &request, &segment_query_context, timeout)
+            });
+            AbortOnDropHandle::new(handle)
+        });
+
+        futures::future::try_join_all(explanations)
+            .await?
+            .into_iter()
+            .collect()
+    }
+
+    /// Explain how each segment selects the points matching the filter, and measure it.
+    pub async fn explain_filter(
+        segments: LockedSegmentHolder,
+        filter: Option<&Filter>,
+        runtime_handle: &AdaptiveSearchHandle,
+        hw_measurement_acc: HwMeasurementAcc,
+        timeout: Duration,
+    ) -> CollectionResult<Vec<SegmentStageExplanation>> {
+        let stopping_guard = StoppingGuard::new();
+        let filter = filter.cloned();
+        let explanations = runtime_handle.spawn_blocking(move || {
+            let is_stopped = stopping_guard.get_is_stopped();
+
+            let segments: Vec<_> = segments
+                .try_read_for(timeout)
+                .ok_or_else(|| CollectionError::timeout(timeout, "explain filter"))?
+                .non_appendable_then_appendable_segments()
+                .collect();
+
+            let hw_counter = hw_measurement_acc.get_counter_cell();
+
+            segments
+                .into_iter()
+                .map(|segment| {
+                    let locked_segment = segment.get();
+                    let read_segment = locked_segment.read();
+                    let start = Instant::now();
+                    let explanation =
+                        read_segment.explain_filter(filter.as_ref(), &is_stopped, &hw_counter)?;
+                    Ok(SegmentStageExplanation {
+                        segment_id: read_segment.segment_uuid().to_string(),
+                        found: explanation.actual_cardinality,
+                        explanation,
+                        time: start.elapsed().as_secs_f64(),
+                    })
+                })
+                .collect::<CollectionResult<Vec<_>>>()
+        });
+        AbortOnDropHandle::new(explanations).await?
+    }
+
     /// Rescore results with a formula that can reference payload values.
     ///
     /// Aggregates rescores from the segments.
@@ -745,6 +823,58 @@ fn execute_batch_search(
     Ok((res, further_results))
 }
 
+fn explain_search_in_segment(
+    segment: LockedSegment,
+    request: &CoreSearchRequest,
+    segment_query_context: &SegmentQueryContext,
+    timeout: Duration,
+) -> CollectionResult<SegmentStageExplanation> {
+    let locked_segment = segment.get();
+    let Some(read_segment) = locked_segment.try_read_for(timeout) else {
+        return Err(CollectionError::timeout(timeout, "explain search"));
+    };
+
+    let vector_name = request.query.get_vector_name();
+    let query_vector: QueryVector = request.query.clone().into();
+    let filter = request.filter.as_ref();
+    let params = request.params.as_ref();
+
+    // The search records the strategy it actually uses, so the explanation reports it
+    let search_strategy = Mutex::new(None);
+    let segment_query_context = segment_query_context
+        .fork()
+        .with_search_strategy(&search_strategy);
+
+    // Search without payload and vectors, as they are fetched after merging the results of segments
+    let start = Instant::now();
+    let found = read_segment.search_batch(
+        vector_name,
+        &[&query_vector],
+        &WithPayload::from(false),
+        &WithVector::Bool(false),
+        filter,
+        request.limit + request.offset,
+        params,
+        &segment_query_context,
+    )?;
+    let time = start.elapsed().as_secs_f64();
+
+    let explanation = read_segment.explain_search(
+        vector_name,
+        &query_vector,
+        filter,
+        params,
+        &segment_query_context,
+    )?;
+
+    Ok(SegmentStageExplanation {
+        segment_id: read_segment.segment_uuid().to_string(),
+        explanation,
+        found: found.iter().map(Vec::len).sum(),
+        time,
+    })
+}
+
 /// Find the HNSW ef_construct for a named vector
 ///
 /// If the given named vector has no HNSW index, `None` is returned.
@@ -753,7 +883,7 @@ fn get_hnsw_ef_construct(config: &SegmentConfig, vector_name: &VectorName) -> Op
         .vector_data
         .get(vector_name)
         .and_then(|config| match &config.index {
-            Indexes::Plain {} => None,
+            Indexes::Plain {} | Indexes::Vamana(_) | Indexes::Ivf(_) => None,
             Indexes::Hnsw(hnsw) => Some(hnsw),
         })
         .map(|hnsw| hnsw.ef_construct)
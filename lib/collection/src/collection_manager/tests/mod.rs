@@ -8,15 +8,20 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::DeferredBehavior;
 use itertools::Itertools;
 use parking_lot::RwLock;
+use segment::common::operation_error::{OperationError, PointVersionConflict};
 use segment::data_types::vectors::{VectorStructInternal, only_default_vector};
 use segment::entry::entry_point::{ReadSegmentEntry, SegmentEntry};
 use segment::json_path::JsonPath;
 use segment::payload_json;
-use segment::types::{ExtendedPointId, PayloadContainer, PointIdType, WithPayload, WithVector};
+use segment::types::{
+    ExpectedPointVersion, ExtendedPointId, Filter, PayloadContainer, PointIdType, WithPayload,
+    WithVector,
+};
 use shard::retrieve::record_internal::RecordInternal;
 use shard::retrieve::retrieve_blocking::retrieve_blocking;
+use shard::segment_holder::PointVersions;
 use shard::segment_holder::locked::LockedSegmentHolder;
-use shard::update::{delete_points, set_payload, upsert_points};
+use shard::update::{delete_points, process_point_operation, set_payload, upsert_points};
 use tempfile::Builder;
 
 use crate::collection_manager::fixtures::{
@@ -24,7 +29,10 @@ use crate::collection_manager::fixtures::{
 };
 use crate::collection_manager::holders::proxy_segment::ProxySegment;
 use crate::collection_manager::holders::segment_holder::{LockedSegment, SegmentHolder, SegmentId};
-use crate::operations::point_ops::{PointStructPersisted, VectorStructPersisted};
+use crate::operations::point_ops::{
+    ConditionalDeleteOperationInternal, ConditionalInsertOperationInternal,
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+};
 
 mod test_search_aggregation;
 
@@ -69,11 +77,13 @@ fn test_update_proxy_segments() {
                 id: (100 * i + 1).into(),
                 vector: VectorStructPersisted::from(VectorStructInternal::from(vectors[0].clone())),
                 payload: None,
+                version: None,
             },
             PointStructPersisted {
                 id: (100 * i + 2).into(),
                 vector: VectorStructPersisted::from(VectorStructInternal::from(vectors[1].clone())),
                 payload: None,
+                version: None,
             },
         ];
         upsert_points(&segments.read(), 1000 + i, &points, &hw_counter).unwrap();
@@ -128,11 +138,13 @@ fn test_move_points_to_copy_on_write() {
             id: 1.into(),
             vector: VectorStructPersisted::from(vec![0.0, 0.0, 0.0, 0.0]),
             payload: None,
+            version: None,
         },
         PointStructPersisted {
             id: 2.into(),
             vector: VectorStructPersisted::from(vec![0.0, 0.0, 0.0, 0.0]),
             payload: None,
+            version: None,
         },
     ];
 
@@ -145,11 +157,13 @@ fn test_move_points_to_copy_on_write() {
             id: 2.into(),
             vector: VectorStructPersisted::from(vec![0.0, 0.0, 0.0, 0.0]),
             payload: None,
+            version: None,
         },
         PointStructPersisted {
             id: 3.into(),
             vector: VectorStructPersisted::from(vec![0.0, 0.0, 0.0, 0.0]),
             payload: None,
+            version: None,
         },
     ];
 
@@ -241,6 +255,7 @@ fn test_upsert_points_in_smallest_segment() {
                 0.0, 0.0, 0.0, 0.0,
             ])),
             payload: None,
+            version: None,
         })
         .collect();
     upsert_points(&segments.read(), 1000, &points, &hw_counter).unwrap();
@@ -319,6 +334,8 @@ fn test_delete_all_point_versions() {
                 payload: None,
                 shard_key: None,
                 order_value: None,
+                then_by_values: Vec::new(),
+                version: None,
             }
         )])
     );
@@ -359,6 +376,210 @@ fn test_delete_all_point_versions() {
     assert!(retrieved.is_empty());
 }
 
+/// Test that updates with `if_version` are only applied if points have the expected versions.
+#[test]
+fn test_point_version_preconditions() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let point_id = ExtendedPointId::from(123);
+    let vector = vec![0.0, 1.0, 2.0, 3.0];
+
+    let mut segment = empty_segment(dir.path());
+    segment
+        .upsert_point(
+            100,
+            point_id,
+            segment::data_types::vectors::only_default_vector(&vector),
+            &hw_counter,
+        )
+        .unwrap();
+
+    let mut holder = SegmentHolder::default();
+    holder.point_versions = true;
+    let sid = holder.add_new(segment);
+    let segments = LockedSegmentHolder::new(holder);
+
+    let upsert = |op_num, expected_version| {
+        let operation =
+            PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
+                points_op: PointInsertOperationsInternal::PointsList(vec![PointStructPersisted {
+                    id: point_id,
+                    vector: VectorStructPersisted::from(VectorStructInternal::from(vector.clone())),
+                    payload: None,
+                    version: None,
+                }]),
+                condition: Filter::default(),
+                update_mode: None,
+                if_version: Some(vec![ExpectedPointVersion {
+                    id: point_id,
+                    version: expected_version,
+                }]),
+            });
+        process_point_operation(&segments.read(), op_num, operation, &hw_counter)
+    };
+
+    let versions = || segments.read().select_point_versions(&[point_id]);
+
+    // Point without writes counted has version 0, outdated version is rejected
+    let err = upsert(101, 1).unwrap_err();
+    assert!(matches!(err, OperationError::VersionConflict { .. }));
+    assert_eq!(
+        versions(),
+        AHashMap::from([(
+            point_id,
+            PointVersions {
+                op_num: 100,
+                version: 0,
+            }
+        )]),
+    );
+
+    // Current version is accepted and incremented
+    upsert(102, 0).unwrap();
+    assert_eq!(
+        versions(),
+        AHashMap::from([(
+            point_id,
+            PointVersions {
+                op_num: 102,
+                version: 1,
+            }
+        )]),
+    );
+
+    // Any write increments the version, even without a precondition
+    set_payload(
+        &segments.read(),
+        103,
+        &payload_json! {"color": "red"},
+        &[point_id],
+        &None,
+        &hw_counter,
+    )
+    .unwrap();
+    assert_eq!(versions()[&point_id].version, 2);
+
+    // Re-applying an operation from WAL is not a conflict
+    upsert(102, 0).unwrap();
+    assert_eq!(versions()[&point_id].version, 2);
+
+    // Version is returned with the record, but not as a part of the payload
+    let retrieved = retrieve_blocking(
+        segments.clone(),
+        &[point_id],
+        &WithPayload::from(true),
+        &WithVector::from(false),
+        TEST_TIMEOUT,
+        &AtomicBool::new(false),
+        HwMeasurementAcc::new(),
+        DeferredBehavior::Exclude,
+    )
+    .unwrap();
+    let record = &retrieved[&point_id];
+    assert_eq!(record.version, Some(2));
+    assert_eq!(record.payload, Some(payload_json! {"color": "red"}));
+
+    // Delete with an outdated version is rejected
+    let delete = PointOperations::DeletePointsConditional(ConditionalDeleteOperationInternal {
+        ids: vec![point_id],
+        if_version: vec![ExpectedPointVersion {
+            id: point_id,
+            version: 1,
+        }],
+    });
+    let err = process_point_operation(&segments.read(), 104, delete, &hw_counter).unwrap_err();
+    let OperationError::VersionConflict { conflicts } = err else {
+        panic!("expected version conflict, got {err:?}");
+    };
+    assert_eq!(
+        conflicts,
+        vec![PointVersionConflict {
+            point_id,
+            expected: 1,
+            actual: Some(2),
+        }],
+    );
+    assert!(
+        segments
+            .read()
+            .get(sid)
+            .unwrap()
+            .get()
+            .read()
+            .has_point(point_id)
+    );
+
+    // Delete with the current version is applied
+    let delete = PointOperations::DeletePointsConditional(ConditionalDeleteOperationInternal {
+        ids: vec![point_id],
+        if_version: vec![ExpectedPointVersion {
+            id: point_id,
+            version: 2,
+        }],
+    });
+    process_point_operation(&segments.read(), 105, delete, &hw_counter).unwrap();
+    assert!(
+        !segments
+            .read()
+            .get(sid)
+            .unwrap()
+            .get()
+            .read()
+            .has_point(point_id)
+    );
+
+    // Expecting a version of a missing point is a conflict
+    let err = upsert(106, 2).unwrap_err();
+    assert!(matches!(err, OperationError::VersionConflict { .. }));
+}
+
+/// Test that writes are not counted and `if_version` is rejected if point versions are disabled.
+#[test]
+fn test_point_versions_disabled() {
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+
+    let point_id = ExtendedPointId::from(123);
+    let vector = vec![0.0, 1.0, 2.0, 3.0];
+
+    let mut holder = SegmentHolder::default();
+    holder.add_new(empty_segment(dir.path()));
+    let segments = LockedSegmentHolder::new(holder);
+
+    let points = [PointStructPersisted {
+        id: point_id,
+        vector: VectorStructPersisted::from(VectorStructInternal::from(vector.clone())),
+        payload: None,
+        version: None,
+    }];
+    upsert_points(&segments.read(), 100, &points, &hw_counter).unwrap();
+    upsert_points(&segments.read(), 101, &points, &hw_counter).unwrap();
+
+    let retrieved = retrieve_blocking(
+        segments.clone(),
+        &[point_id],
+        &WithPayload::from(false),
+        &WithVector::from(false),
+        TEST_TIMEOUT,
+        &AtomicBool::new(false),
+        HwMeasurementAcc::new(),
+        DeferredBehavior::Exclude,
+    )
+    .unwrap();
+    assert_eq!(retrieved[&point_id].version, None);
+
+    let delete = PointOperations::DeletePointsConditional(ConditionalDeleteOperationInternal {
+        ids: vec![point_id],
+        if_version: vec![ExpectedPointVersion {
+            id: point_id,
+            version: 0,
+        }],
+    });
+    let err = process_point_operation(&segments.read(), 102, delete, &hw_counter).unwrap_err();
+    assert!(matches!(err, OperationError::ValidationError { .. }));
+}
+
 #[test]
 fn test_proxy_shared_updates() {
     // Testing that multiple proxies that share point with the same id but different versions
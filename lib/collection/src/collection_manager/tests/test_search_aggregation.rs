@@ -12,6 +12,8 @@ fn score_point(id: usize, score: ScoreType, version: SeqNumberType) -> ScoredPoi
         vector: None,
         shard_key: None,
         order_value: None,
+        then_by_values: Vec::new(),
+        stratum: None,
     }
 }
 
@@ -22,6 +22,7 @@ use validator::Validate;
 use wal::WalOptions;
 
 use crate::operations::config_diff::{DiffConfig, QuantizationConfigDiff};
+use crate::operations::payload_validation::PayloadValidationConfig;
 use crate::operations::types::{
     CollectionError, CollectionResult, CollectionWarning, Datatype, SparseVectorParams,
     SparseVectorsConfig, VectorParams, VectorParamsDiff, VectorsConfig, VectorsConfigDiff,
@@ -137,6 +138,13 @@ pub struct CollectionParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[validate(nested)]
     pub sparse_vectors: Option<BTreeMap<VectorNameBuf, SparseVectorParams>>,
+    /// If true - count writes made to each point and return them as point versions.
+    /// Point versions can be used as `if_version` preconditions of updates.
+    /// Can only be set on collection creation.
+    ///
+    /// Default: false
+    #[serde(default)]
+    pub point_versions: bool,
 }
 
 impl CollectionParams {
@@ -155,6 +163,7 @@ impl CollectionParams {
             read_fan_out_delay_ms: _, // May be changed,
             on_disk_payload: _, // May be changed
             sparse_vectors: _, // Sets may differ via named vector CRUD
+            point_versions,  // Not changeable
         } = other;
 
         let this_sharding_method = self.sharding_method.unwrap_or_default();
@@ -168,6 +177,15 @@ impl CollectionParams {
             )));
         }
 
+        if self.point_versions != *point_versions {
+            return Err(CollectionError::bad_input(format!(
+                "point versions are incompatible: \
+                 origin point versions: {}, \
+                 while other point versions: {point_versions}",
+                self.point_versions,
+            )));
+        }
+
         Ok(())
     }
 
@@ -192,18 +210,19 @@ impl CollectionParams {
                     .then_some(params)
             })
             .map(|params| {
-                let element_bytes = match params.datatype {
-                    Some(Datatype::Float16) => 2,
-                    Some(Datatype::Uint8) => 1,
-                    Some(Datatype::Float32) | None => 4,
-                };
-
                 let dim = params.size.get() as usize;
 
+                let dense_bytes = match params.datatype {
+                    Some(Datatype::Float16) => 2 * dim,
+                    Some(Datatype::Uint8) => dim,
+                    Some(Datatype::Bit) => dim.div_ceil(8),
+                    Some(Datatype::Float32) | None => 4 * dim,
+                };
+
                 let vector_bytes = if params.multivector_config.is_some() {
-                    element_bytes * dim * MULTIVECTOR_SIZE
+                    dense_bytes * MULTIVECTOR_SIZE
                 } else {
-                    element_bytes * dim
+                    dense_bytes
                 };
 
                 let deferred_from = threshold_bytes.div_ceil(vector_bytes);
@@ -252,6 +271,10 @@ pub struct CollectionConfigInternal {
     /// such as creation time, migration data, inference model info, etc.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Schema, which payloads of inserted and updated points are validated against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub payload_validation: Option<PayloadValidationConfig>,
 }
 
 impl CollectionConfigInternal {
@@ -341,6 +364,7 @@ impl CollectionParams {
             read_fan_out_delay_ms: None,
             on_disk_payload: default_on_disk_payload(),
             sparse_vectors: None,
+            point_versions: false,
         }
     }
 
@@ -461,6 +485,24 @@ impl CollectionParams {
         &mut self,
         update_vectors_diff: &VectorsConfigDiff,
     ) -> CollectionResult<()> {
+        // Check all vectors before applying anything, to not leave the config half-updated
+        for (vector_name, update_params) in update_vectors_diff.0.iter() {
+            let quantization_enabled = update_params
+                .quantization_config
+                .as_ref()
+                .is_some_and(|diff| !matches!(diff, QuantizationConfigDiff::Disabled(_)));
+            let quantization_supported = self
+                .vectors
+                .get_params(vector_name)
+                .is_none_or(VectorParams::is_quantization_supported);
+            if quantization_enabled && !quantization_supported {
+                return Err(CollectionError::bad_input(format!(
+                    "Quantization is not supported for vector `{vector_name}`: \
+                     bit vectors and vectors with Hamming or Jaccard distance can't be quantized"
+                )));
+            }
+        }
+
         for (vector_name, update_params) in update_vectors_diff.0.iter() {
             let vector_params = self.get_vector_params_mut(vector_name)?;
             let VectorParamsDiff {
@@ -551,6 +593,9 @@ impl CollectionParams {
                     on_disk,
                     datatype,
                     multivector_config,
+                    matryoshka_dim,
+                    vamana_config: _,
+                    ivf_config: _,
                 } = params;
 
                 (
@@ -573,6 +618,7 @@ impl CollectionParams {
                         },
                         multivector_config: *multivector_config,
                         datatype: datatype.map(VectorStorageDatatype::from),
+                        matryoshka_dim: *matryoshka_dim,
                     },
                 )
             })
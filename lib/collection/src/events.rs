@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use segment::json_path::JsonPath;
 use segment::types::{Filter, PayloadFieldSchema};
 
+use crate::operations::payload_validation::PayloadSchemaViolation;
 use crate::shards::CollectionId;
 
 pub struct CollectionDeletedEvent {
@@ -19,3 +20,12 @@ pub struct IndexCreatedEvent {
     pub collection_id: CollectionId,
     pub field_name: JsonPath,
 }
+
+pub struct PayloadSchemaViolationEvent {
+    pub collection_id: CollectionId,
+    pub violations: Vec<PayloadSchemaViolation>,
+}
+
+pub struct PayloadValidationUpdatedEvent {
+    pub collection_id: CollectionId,
+}
@@ -15,7 +15,7 @@ type Hits = AHashMap<PointIdType, ScoredPoint>;
 pub(super) struct GroupsAggregator {
     groups: AHashMap<GroupId, Hits>,
     max_group_size: usize,
-    grouped_by: JsonPath,
+    grouped_by: Vec<JsonPath>,
     max_groups: usize,
     full_groups: AHashSet<GroupId>,
     group_best_scores: AHashMap<GroupId, ScoredPoint>,
@@ -27,7 +27,7 @@ impl GroupsAggregator {
     pub(super) fn new(
         groups: usize,
         group_size: usize,
-        grouped_by: JsonPath,
+        grouped_by: Vec<JsonPath>,
         order: Option<Order>,
     ) -> Self {
         Self {
@@ -44,30 +44,38 @@ impl GroupsAggregator {
 
     /// Adds a point to the group that corresponds based on the group_by field, assumes that the point has the group_by field
     fn add_point(&mut self, point: &ScoredPoint) -> Result<(), AggregatorError> {
-        // extract all values from the group_by field
-        let payload_values: Vec<_> = point
-            .payload
-            .as_ref()
-            .map(|p| {
-                p.get_value(&self.grouped_by)
-                    .into_iter()
-                    .flat_map(|v| match v {
-                        Value::Array(arr) => arr.iter().collect(),
-                        Value::Null
-                        | Value::Bool(_)
-                        | Value::Number(_)
-                        | Value::String(_)
-                        | Value::Object(_) => vec![v],
-                    })
-                    .collect()
-            })
-            .ok_or(AggregatorError::KeyNotFound)?;
+        let payload = point.payload.as_ref().ok_or(AggregatorError::KeyNotFound)?;
 
-        let unique_group_keys: Vec<_> =
-            itertools::process_results(payload_values.into_iter().map(GroupId::try_from), |iter| {
-                iter.unique().collect()
+        // extract all values from each of the group_by fields
+        let keys_per_field = self
+            .grouped_by
+            .iter()
+            .map(|path| {
+                let payload_values = payload.get_value(path).into_iter().flat_map(|v| match v {
+                    Value::Array(arr) => arr.iter().collect(),
+                    Value::Null
+                    | Value::Bool(_)
+                    | Value::Number(_)
+                    | Value::String(_)
+                    | Value::Object(_) => vec![v],
+                });
+
+                itertools::process_results(payload_values.map(GroupId::try_from), |iter| {
+                    iter.unique().collect_vec()
+                })
+                .map_err(|_| AggregatorError::BadKeyType)
             })
-            .map_err(|_| AggregatorError::BadKeyType)?;
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // With several fields, the point is in a group of each combination of their values
+        let unique_group_keys = match <[_; 1]>::try_from(keys_per_field) {
+            Ok([keys]) => keys,
+            Err(keys_per_field) => keys_per_field
+                .into_iter()
+                .multi_cartesian_product()
+                .map(GroupId::Tuple)
+                .collect(),
+        };
 
         for group_key in unique_group_keys {
             let group = self
@@ -146,18 +154,17 @@ impl GroupsAggregator {
     }
 
     /// Gets the keys of the groups that have less than the max group size
-    pub(super) fn keys_of_unfilled_best_groups(&self) -> Vec<Value> {
+    pub(super) fn keys_of_unfilled_best_groups(&self) -> Vec<GroupId> {
         let best_group_keys: AHashSet<_> = self.best_group_keys().into_iter().collect();
         best_group_keys
             .difference(&self.full_groups)
             .cloned()
-            .map_into()
             .collect()
     }
 
     /// Gets the keys of the groups that have reached the max group size
-    pub(super) fn keys_of_filled_groups(&self) -> Vec<Value> {
-        self.full_groups.iter().cloned().map_into().collect()
+    pub(super) fn keys_of_filled_groups(&self) -> Vec<GroupId> {
+        self.full_groups.iter().cloned().collect()
     }
 
     /// Gets the amount of best groups that have reached the max group size
@@ -203,6 +210,7 @@ mod unit_tests {
 
     use common::types::ScoreType;
     use segment::payload_json;
+    use segment::types::Payload;
     use serde_json::json;
 
     use super::*;
@@ -216,6 +224,8 @@ mod unit_tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
         }
     }
 
@@ -228,6 +238,8 @@ mod unit_tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
         }
     }
 
@@ -239,8 +251,12 @@ mod unit_tests {
             point(3, 0.75, json!("b")),
         ];
 
-        let mut aggregator =
-            GroupsAggregator::new(3, 2, "docId".parse().unwrap(), Some(Order::LargeBetter));
+        let mut aggregator = GroupsAggregator::new(
+            3,
+            2,
+            vec!["docId".parse().unwrap()],
+            Some(Order::LargeBetter),
+        );
         for point in &scored_points {
             aggregator.add_point(point).unwrap();
         }
@@ -258,6 +274,57 @@ mod unit_tests {
         assert_eq!(result[1].hits[1].id, 3.into());
     }
 
+    #[test]
+    fn test_group_by_multiple_fields() {
+        let point = |idx: u64, score: ScoreType, payload: Payload| ScoredPoint {
+            id: idx.into(),
+            version: 0,
+            score,
+            payload: Some(payload),
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
+        };
+
+        let scored_points = vec![
+            point(1, 0.99, payload_json! { "author": "a", "year": 2020 }),
+            point(2, 0.95, payload_json! { "author": "a", "year": 2021 }),
+            point(3, 0.9, payload_json! { "author": ["a", "b"], "year": 2020 }),
+            point(4, 0.85, payload_json! { "author": "b" }),
+        ];
+
+        let mut aggregator = GroupsAggregator::new(
+            10,
+            2,
+            vec!["author".parse().unwrap(), "year".parse().unwrap()],
+            Some(Order::LargeBetter),
+        );
+        for point in &scored_points {
+            aggregator.add_point(point).unwrap();
+        }
+
+        let result = aggregator
+            .distill()
+            .into_iter()
+            .map(|group| {
+                let ids = group.hits.iter().map(|hit| hit.id).collect_vec();
+                (group.key, ids)
+            })
+            .collect_vec();
+
+        let key = |author: &str, year: u64| GroupId::Tuple(vec![author.into(), year.into()]);
+        assert_eq!(
+            result,
+            vec![
+                (key("a", 2020), vec![1.into(), 3.into()]),
+                (key("a", 2021), vec![2.into()]),
+                (key("b", 2020), vec![3.into()]),
+            ],
+        );
+    }
+
     struct Case {
         point: ScoredPoint,
         key: Value,
@@ -286,8 +353,12 @@ mod unit_tests {
 
     #[test]
     fn it_adds_single_points() {
-        let mut aggregator =
-            GroupsAggregator::new(4, 3, "docId".parse().unwrap(), Some(Order::LargeBetter));
+        let mut aggregator = GroupsAggregator::new(
+            4,
+            3,
+            vec!["docId".parse().unwrap()],
+            Some(Order::LargeBetter),
+        );
 
         // cases
         #[rustfmt::skip]
@@ -335,7 +406,10 @@ mod unit_tests {
         // assert final groups
         assert_eq!(aggregator.full_groups.len(), 3);
 
-        assert_eq!(aggregator.keys_of_unfilled_best_groups(), vec![json!("d")]);
+        assert_eq!(
+            aggregator.keys_of_unfilled_best_groups(),
+            vec![GroupId::from("d")],
+        );
 
         assert_eq!(aggregator.len_of_filled_best_groups(), 3);
 
@@ -390,8 +464,12 @@ mod unit_tests {
 
     #[test]
     fn test_aggregate_less_groups() {
-        let mut aggregator =
-            GroupsAggregator::new(3, 2, "docId".parse().unwrap(), Some(Order::LargeBetter));
+        let mut aggregator = GroupsAggregator::new(
+            3,
+            2,
+            vec!["docId".parse().unwrap()],
+            Some(Order::LargeBetter),
+        );
 
         // cases
         [
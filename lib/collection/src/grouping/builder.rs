@@ -2,7 +2,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use futures::Future;
+use futures::{Future, future};
 use itertools::Itertools;
 
 use super::group_by::{GroupRequest, group_by};
@@ -85,6 +85,7 @@ where
     async fn run(self) -> CollectionResult<Vec<PointGroup>> {
         let start = std::time::Instant::now();
         let with_lookup = self.group_by.with_lookup.clone();
+        let subgroups = self.group_by.subgroups.clone();
 
         let core_group_by = self
             .group_by
@@ -98,6 +99,9 @@ where
             )
             .await?;
 
+        // Subgroups are found by the same request, restricted to the points of each group
+        let subgroups = subgroups.map(|subgroups| (core_group_by.clone(), subgroups));
+
         let mut groups = group_by(
             core_group_by,
             self.collection,
@@ -108,6 +112,29 @@ where
         )
         .await?;
 
+        if let Some((core_group_by, subgroups)) = subgroups {
+            // update timeout
+            let timeout = self
+                .timeout
+                .map(|timeout| timeout.saturating_sub(start.elapsed()));
+
+            let groups_of_subgroups = groups.iter().map(|group| {
+                group_by(
+                    core_group_by.subgroups_of(&group.id, &subgroups),
+                    self.collection,
+                    self.read_consistency,
+                    self.shard_selection.clone(),
+                    timeout,
+                    self.hw_measurement_acc.clone(),
+                )
+            });
+            let groups_of_subgroups = future::try_join_all(groups_of_subgroups).await?;
+
+            for (group, subgroups) in groups.iter_mut().zip(groups_of_subgroups) {
+                group.subgroups = Some(subgroups);
+            }
+        }
+
         if let Some(lookup) = with_lookup {
             // update timeout
             let timeout = self
@@ -7,6 +7,7 @@ use api::rest::{BaseGroupRequest, SearchGroupsRequestInternal, SearchRequestInte
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use fnv::FnvBuildHasher;
 use indexmap::IndexSet;
+use segment::data_types::groups::GroupId;
 use segment::json_path::JsonPath;
 use segment::types::{
     AnyVariants, Condition, FieldCondition, Filter, Match, ScoredPoint, WithPayloadInterface,
@@ -46,8 +47,8 @@ pub struct GroupRequest {
     /// Request to use (search or recommend)
     pub source: SourceRequest,
 
-    /// Path to the field to group by
-    pub group_by: JsonPath,
+    /// Paths to the fields to group by, several fields group by the combination of their values
+    pub group_by: Vec<JsonPath>,
 
     /// Limit of points to return per group
     pub group_size: usize,
@@ -57,6 +58,38 @@ pub struct GroupRequest {
 
     /// Options for specifying how to use the group id to lookup points in another collection
     pub with_lookup: Option<WithLookup>,
+
+    /// Options for grouping the points of each group again
+    pub subgroups: Option<SubgroupRequest>,
+}
+
+/// Second level of grouping, applied to the points of each group
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubgroupRequest {
+    /// Paths to the fields to group the points of each group by
+    pub group_by: Vec<JsonPath>,
+
+    /// Limit of points to return per subgroup
+    pub group_size: usize,
+
+    /// Limit of subgroups to return per group
+    pub limit: usize,
+}
+
+impl From<api::rest::SubgroupRequest> for SubgroupRequest {
+    fn from(request: api::rest::SubgroupRequest) -> Self {
+        let api::rest::SubgroupRequest {
+            group_by,
+            group_size,
+            limit,
+        } = request;
+
+        SubgroupRequest {
+            group_by: group_by.into_paths(),
+            group_size: group_size.unwrap_or(CollectionQueryRequest::DEFAULT_GROUP_SIZE),
+            limit: limit.unwrap_or(CollectionQueryRequest::DEFAULT_LIMIT),
+        }
+    }
 }
 
 impl GroupRequest {
@@ -72,10 +105,11 @@ impl GroupRequest {
         };
         Self {
             source,
-            group_by,
+            group_by: vec![group_by],
             group_size,
             limit,
             with_lookup: None,
+            subgroups: None,
         }
     }
 
@@ -137,8 +171,36 @@ impl GroupRequest {
 
 impl QueryGroupRequest {
     /// Make `group_by` field selector work with as `with_payload`.
-    fn group_by_to_payload_selector(group_by: &JsonPath) -> WithPayloadInterface {
-        WithPayloadInterface::Fields(vec![group_by.strip_wildcard_suffix()])
+    fn group_by_to_payload_selector(group_by: &[JsonPath]) -> WithPayloadInterface {
+        WithPayloadInterface::Fields(
+            group_by
+                .iter()
+                .map(JsonPath::strip_wildcard_suffix)
+                .collect(),
+        )
+    }
+
+    /// Request to group the points of the group with the given key by the subgroup fields
+    pub(super) fn subgroups_of(&self, key: &GroupId, subgroups: &SubgroupRequest) -> Self {
+        let mut request = self.clone();
+
+        let in_group = Filter {
+            must: Some(key_conditions(&self.group_by, key)),
+            ..Default::default()
+        };
+        request.source.filter = Some(
+            request
+                .source
+                .filter
+                .as_ref()
+                .map(|filter| filter.merge(&in_group))
+                .unwrap_or(in_group),
+        );
+
+        request.group_by.clone_from(&subgroups.group_by);
+        request.group_size = subgroups.group_size;
+        request.groups = subgroups.limit;
+        request
     }
 
     async fn r#do(
@@ -157,7 +219,15 @@ impl QueryGroupRequest {
             increase_limit_for_group(prefetch, self.group_size);
         });
 
-        let key_not_empty = Filter::new_must_not(Condition::IsEmpty(self.group_by.clone().into()));
+        let key_not_empty = Filter {
+            must_not: Some(
+                self.group_by
+                    .iter()
+                    .map(|path| Condition::IsEmpty(path.clone().into()))
+                    .collect(),
+            ),
+            ..Default::default()
+        };
         request.filter = Some(request.filter.unwrap_or_default().merge(&key_not_empty));
 
         let with_group_by_payload = Self::group_by_to_payload_selector(&self.group_by);
@@ -193,6 +263,7 @@ impl From<SearchGroupsRequestInternal> for GroupRequest {
                     group_size,
                     limit,
                     with_lookup: with_lookup_interface,
+                    subgroups,
                 },
         } = request;
 
@@ -209,10 +280,11 @@ impl From<SearchGroupsRequestInternal> for GroupRequest {
 
         GroupRequest {
             source: SourceRequest::Search(search),
-            group_by,
+            group_by: group_by.into_paths(),
             group_size: group_size as usize,
             limit: limit as usize,
             with_lookup: with_lookup_interface.map(Into::into),
+            subgroups: subgroups.map(SubgroupRequest::from),
         }
     }
 }
@@ -236,6 +308,7 @@ impl From<RecommendGroupsRequestInternal> for GroupRequest {
                     group_size,
                     limit,
                     with_lookup: with_lookup_interface,
+                    subgroups,
                 },
         } = request;
 
@@ -256,10 +329,11 @@ impl From<RecommendGroupsRequestInternal> for GroupRequest {
 
         GroupRequest {
             source: SourceRequest::Recommend(recommend),
-            group_by,
+            group_by: group_by.into_paths(),
             group_size: group_size as usize,
             limit: limit as usize,
             with_lookup: with_lookup_interface.map(Into::into),
+            subgroups: subgroups.map(SubgroupRequest::from),
         }
     }
 }
@@ -280,6 +354,7 @@ impl From<CollectionQueryGroupsRequest> for GroupRequest {
             group_size,
             limit,
             with_lookup: with_lookup_interface,
+            subgroups,
         } = request;
 
         let collection_query_request = CollectionQueryRequest {
@@ -294,6 +369,7 @@ impl From<CollectionQueryGroupsRequest> for GroupRequest {
             with_vector,
             with_payload,
             lookup_from,
+            cursor: None,
         };
 
         GroupRequest {
@@ -302,6 +378,7 @@ impl From<CollectionQueryGroupsRequest> for GroupRequest {
             group_size,
             limit,
             with_lookup: with_lookup_interface,
+            subgroups,
         }
     }
 }
@@ -339,7 +416,7 @@ pub async fn group_by(
         // Construct filter to exclude already found groups
         let full_groups = aggregator.keys_of_filled_groups();
         if !full_groups.is_empty() {
-            let except_any = except_on(&request.group_by, &full_groups);
+            let except_any = except_groups(&request.group_by, &full_groups);
             if !except_any.is_empty() {
                 let exclude_groups = Filter {
                     must: Some(except_any),
@@ -403,7 +480,7 @@ pub async fn group_by(
 
             // Construct filter to only include unsatisfied groups
             let unsatisfied_groups = aggregator.keys_of_unfilled_best_groups();
-            let match_any = match_on(&request.group_by, &unsatisfied_groups);
+            let match_any = match_groups(&request.group_by, &unsatisfied_groups);
             if !match_any.is_empty() {
                 let include_groups = Filter {
                     must: Some(match_any),
@@ -494,6 +571,66 @@ pub async fn group_by(
     Ok(groups)
 }
 
+/// Creates conditions to exclude the points of the groups with the given keys, if possible
+fn except_groups(paths: &[JsonPath], keys: &[GroupId]) -> Vec<Condition> {
+    match paths {
+        [path] => except_on(path, &keys_to_values(keys)),
+        // Each group is excluded separately, as only the combination of values identifies it
+        _ => keys
+            .iter()
+            .map(|key| {
+                Condition::Filter(Filter::new_must_not(Condition::Filter(Filter {
+                    must: Some(key_conditions(paths, key)),
+                    ..Default::default()
+                })))
+            })
+            .collect(),
+    }
+}
+
+/// Creates conditions to only include the points of the groups with the given keys, if possible
+fn match_groups(paths: &[JsonPath], keys: &[GroupId]) -> Vec<Condition> {
+    match paths {
+        [path] => match_on(path, &keys_to_values(keys)),
+        _ if keys.is_empty() => Vec::new(),
+        _ => {
+            let any_group = keys
+                .iter()
+                .map(|key| {
+                    Condition::Filter(Filter {
+                        must: Some(key_conditions(paths, key)),
+                        ..Default::default()
+                    })
+                })
+                .collect();
+            vec![Condition::Filter(Filter {
+                should: Some(any_group),
+                ..Default::default()
+            })]
+        }
+    }
+}
+
+/// Conditions which match the points of the group with the given key
+fn key_conditions(paths: &[JsonPath], key: &GroupId) -> Vec<Condition> {
+    let values = match key {
+        GroupId::Tuple(values) => values.as_slice(),
+        GroupId::String(_) | GroupId::NumberU64(_) | GroupId::NumberI64(_) => {
+            std::slice::from_ref(key)
+        }
+    };
+
+    paths
+        .iter()
+        .zip(values)
+        .flat_map(|(path, value)| match_on(path, &[Value::from(value.clone())]))
+        .collect()
+}
+
+fn keys_to_values(keys: &[GroupId]) -> Vec<Value> {
+    keys.iter().cloned().map(Value::from).collect()
+}
+
 /// Uses the set of values to create Match::Except's, if possible
 fn except_on(path: &JsonPath, values: &[Value]) -> Vec<Condition> {
     values_to_any_variants(values)
@@ -554,9 +691,11 @@ fn increase_limit_for_group(shard_prefetch: &mut ShardPrefetch, group_size: usiz
 mod tests {
     use ahash::AHashMap;
     use segment::data_types::groups::GroupId;
+    use segment::json_path::JsonPath;
     use segment::payload_json;
-    use segment::types::{Payload, ScoredPoint};
+    use segment::types::{Condition, Filter, Payload, ScoredPoint};
 
+    use super::{except_groups, key_conditions, match_groups};
     use crate::grouping::types::Group;
 
     fn make_scored_point(id: u64, score: f32, payload: Option<Payload>) -> ScoredPoint {
@@ -568,6 +707,8 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
         }
     }
 
@@ -634,4 +775,38 @@ mod tests {
                 .all(|x| x.payload.as_ref() == Some(&payload_b)),
         );
     }
+
+    #[test]
+    fn test_tuple_group_conditions() {
+        let paths: Vec<JsonPath> = vec!["author".parse().unwrap(), "year".parse().unwrap()];
+        let keys = vec![
+            GroupId::Tuple(vec![GroupId::from("a"), GroupId::from(2020u64)]),
+            GroupId::Tuple(vec![GroupId::from("b"), GroupId::from(2021u64)]),
+        ];
+
+        // Each key matches all of its fields
+        assert_eq!(key_conditions(&paths, &keys[0]).len(), 2);
+
+        // Each group is excluded separately
+        let except = except_groups(&paths, &keys);
+        assert_eq!(except.len(), 2);
+        assert!(matches!(
+            &except[0],
+            Condition::Filter(Filter { must_not: Some(conditions), .. }) if conditions.len() == 1
+        ));
+
+        // Any of the groups is matched
+        let include = match_groups(&paths, &keys);
+        assert_eq!(include.len(), 1);
+        let Condition::Filter(Filter {
+            should: Some(any_group),
+            ..
+        }) = &include[0]
+        else {
+            panic!("expected a filter matching any of the groups");
+        };
+        assert_eq!(any_group.len(), 2);
+
+        assert!(match_groups(&paths, &[]).is_empty());
+    }
 }
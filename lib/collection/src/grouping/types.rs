@@ -38,6 +38,7 @@ impl From<Group> for PointGroup {
                 .collect(),
             id: group.key,
             lookup: None,
+            subgroups: None,
         }
     }
 }
@@ -47,8 +48,8 @@ pub struct QueryGroupRequest {
     /// Query request to use
     pub source: ShardQueryRequest,
 
-    /// Path to the field to group by
-    pub group_by: JsonPath,
+    /// Paths to the fields to group by
+    pub group_by: Vec<JsonPath>,
 
     /// Limit of points to return per group
     pub group_size: usize,
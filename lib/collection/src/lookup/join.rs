@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use segment::data_types::groups::GroupId;
+use segment::json_path::JsonPath;
+use segment::types::{Payload, PointIdType, WithPayloadInterface};
+use serde_json::Value;
+use shard::retrieve::record_internal::RecordInternal;
+
+use super::types::PseudoId;
+use crate::operations::types::{CollectionError, CollectionResult, PointRequestInternal};
+
+/// Embed payload of points from another collection into the payload of the results
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithJoin {
+    /// Payload field holding IDs of the referenced points
+    pub key: JsonPath,
+
+    /// Name of the collection to look up the referenced points in
+    pub collection_name: String,
+
+    /// Options for specifying which payload of the referenced points to include (or not)
+    pub with_payload: Option<WithPayloadInterface>,
+
+    /// Payload key to put the referenced points under
+    pub target: String,
+}
+
+impl From<api::rest::WithJoin> for WithJoin {
+    fn from(with_join: api::rest::WithJoin) -> Self {
+        let api::rest::WithJoin {
+            key,
+            collection_name,
+            with_payload,
+            target,
+        } = with_join;
+
+        WithJoin {
+            key,
+            target: target.unwrap_or_else(|| collection_name.clone()),
+            collection_name,
+            with_payload,
+        }
+    }
+}
+
+/// Points, referenced by the payload of a single result
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JoinReference {
+    ids: Vec<PointIdType>,
+    /// Whether the field holds multiple IDs, so they are embedded as an array
+    is_array: bool,
+}
+
+impl WithJoin {
+    /// Whether the payload holds the key. Otherwise, it has to be fetched separately, as the key
+    /// could be excluded from the returned payload.
+    pub fn has_key(&self, payload: Option<&Payload>) -> bool {
+        payload.is_some_and(|payload| !self.key.value_get(&payload.0).is_empty())
+    }
+
+    /// Request to retrieve only the key of the given points
+    pub fn key_request(&self, ids: Vec<PointIdType>) -> PointRequestInternal {
+        PointRequestInternal {
+            ids,
+            with_payload: Some(WithPayloadInterface::Fields(vec![self.key.clone()])),
+            with_vector: false.into(),
+        }
+    }
+
+    /// Points, referenced by the `key` field of the payload.
+    ///
+    /// Values which can't be point IDs are ignored.
+    pub fn reference(&self, payload: Option<&Payload>) -> JoinReference {
+        let Some(payload) = payload else {
+            return JoinReference::default();
+        };
+
+        let values = self.key.value_get(&payload.0);
+        let is_array = values.len() > 1 || values.iter().any(|value| value.is_array());
+
+        let ids = values
+            .into_iter()
+            .flat_map(|value| match value {
+                Value::Array(array) => array.iter().collect(),
+                Value::Null
+                | Value::Bool(_)
+                | Value::Number(_)
+                | Value::String(_)
+                | Value::Object(_) => vec![value],
+            })
+            .filter_map(|value| GroupId::try_from(value).ok())
+            .filter_map(|id| PointIdType::try_from(PseudoId::from(id)).ok())
+            .collect();
+
+        JoinReference { ids, is_array }
+    }
+
+    /// Request to retrieve the referenced points.
+    ///
+    /// Returns `None` if nothing is referenced.
+    pub fn point_request<'a>(
+        &self,
+        references: impl IntoIterator<Item = &'a JoinReference>,
+    ) -> Option<PointRequestInternal> {
+        let ids = references
+            .into_iter()
+            .flat_map(|reference| &reference.ids)
+            .copied()
+            .unique()
+            .collect_vec();
+
+        if ids.is_empty() {
+            return None;
+        }
+
+        Some(PointRequestInternal {
+            ids,
+            with_payload: self.with_payload.clone(),
+            with_vector: false.into(),
+        })
+    }
+
+    /// Embed the retrieved points into the payloads, which reference them.
+    ///
+    /// Fails if a payload already has the `target` key, instead of overwriting it.
+    pub fn embed<'a>(
+        &self,
+        payloads: impl IntoIterator<Item = (&'a mut Option<Payload>, JoinReference)>,
+        records: Vec<RecordInternal>,
+    ) -> CollectionResult<()> {
+        let records: HashMap<_, _> = records
+            .into_iter()
+            .map(|record| (record.id, joined_value(record)))
+            .collect();
+
+        for (payload, reference) in payloads {
+            let JoinReference { ids, is_array } = reference;
+            if ids.is_empty() && !is_array {
+                // Nothing is referenced
+                continue;
+            }
+
+            let payload = payload.get_or_insert_default();
+            if payload.0.contains_key(&self.target) {
+                return Err(CollectionError::bad_input(format!(
+                    "Payload already has key `{}`, specify another `target` of the join",
+                    self.target,
+                )));
+            }
+
+            let value = if is_array {
+                Value::Array(
+                    ids.iter()
+                        .filter_map(|id| records.get(id).cloned())
+                        .collect(),
+                )
+            } else {
+                ids.first()
+                    .and_then(|id| records.get(id).cloned())
+                    .unwrap_or(Value::Null)
+            };
+
+            payload.0.insert(self.target.clone(), value);
+        }
+
+        Ok(())
+    }
+}
+
+/// Representation of the referenced point, embedded into the payload
+fn joined_value(record: RecordInternal) -> Value {
+    serde_json::json!({
+        "id": record.id,
+        "payload": record.payload,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::payload_json;
+    use serde_json::json;
+
+    use super::*;
+
+    fn record(id: u64, payload: Payload) -> RecordInternal {
+        RecordInternal {
+            id: id.into(),
+            payload: Some(payload),
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            then_by_values: Vec::new(),
+            version: None,
+        }
+    }
+
+    fn with_join() -> WithJoin {
+        WithJoin::from(api::rest::WithJoin {
+            key: "brand_id".parse().unwrap(),
+            collection_name: "brands".to_string(),
+            with_payload: None,
+            target: None,
+        })
+    }
+
+    #[test]
+    fn test_point_request_collects_unique_ids() {
+        let with_join = with_join();
+        let payloads = [
+            payload_json! { "brand_id": 1 },
+            payload_json! { "brand_id": [2, 1, "not an id", -5] },
+            payload_json! { "other": 3 },
+        ];
+        let references = payloads
+            .iter()
+            .map(|payload| with_join.reference(Some(payload)))
+            .collect_vec();
+
+        let request = with_join.point_request(&references).unwrap();
+        assert_eq!(request.ids, vec![1.into(), 2.into()]);
+
+        assert!(with_join.point_request(&references[2..]).is_none());
+        assert!(!with_join.has_key(Some(&payloads[2])));
+        assert!(!with_join.has_key(None));
+    }
+
+    #[test]
+    fn test_embed_joined_points() {
+        let with_join = with_join();
+        let mut payloads = vec![
+            Some(payload_json! { "brand_id": 1 }),
+            Some(payload_json! { "brand_id": 3 }),
+            Some(payload_json! { "brand_id": [2, 3, 1] }),
+            Some(payload_json! { "other": 1 }),
+            // Key is fetched separately, if it's not returned
+            None,
+        ];
+        let mut references = payloads
+            .iter()
+            .map(|payload| with_join.reference(payload.as_ref()))
+            .collect_vec();
+        references[4] = with_join.reference(Some(&payload_json! { "brand_id": 2 }));
+
+        let records = vec![
+            record(1, payload_json! { "name": "Acme" }),
+            record(2, payload_json! { "name": "Globex" }),
+        ];
+
+        with_join
+            .embed(payloads.iter_mut().zip(references), records)
+            .unwrap();
+
+        let payloads = payloads.into_iter().map(Option::unwrap).collect_vec();
+        assert_eq!(
+            payloads[0].0["brands"],
+            json!({ "id": 1, "payload": { "name": "Acme" } }),
+        );
+        // Missing point is embedded as null
+        assert_eq!(payloads[1].0["brands"], Value::Null);
+        // Missing points are skipped in arrays
+        assert_eq!(
+            payloads[2].0["brands"],
+            json!([
+                { "id": 2, "payload": { "name": "Globex" } },
+                { "id": 1, "payload": { "name": "Acme" } },
+            ]),
+        );
+        assert!(!payloads[3].0.contains_key("brands"));
+        assert_eq!(
+            payloads[4].0,
+            payload_json! { "brands": { "id": 2, "payload": { "name": "Globex" } } }.0,
+        );
+    }
+
+    #[test]
+    fn test_embed_rejects_existing_target() {
+        let with_join = with_join();
+        let mut payload = Some(payload_json! { "brand_id": 1, "brands": "existing" });
+        let reference = with_join.reference(payload.as_ref());
+
+        let result = with_join.embed(
+            [(&mut payload, reference)],
+            vec![record(1, payload_json! { "name": "Acme" })],
+        );
+        assert!(result.is_err());
+        assert_eq!(payload.unwrap().0["brands"], json!("existing"));
+    }
+}
@@ -80,6 +80,8 @@ impl From<GroupId> for PseudoId {
             GroupId::String(s) => Self::String(s),
             GroupId::NumberU64(n) => Self::NumberU64(n),
             GroupId::NumberI64(n) => Self::NumberI64(n),
+            // Groups by several keys can't reference a point, keep their values as a string
+            tuple @ GroupId::Tuple(_) => Self::String(serde_json::Value::from(tuple).to_string()),
         }
     }
 }
@@ -7,8 +7,8 @@ use std::num::NonZeroU32;
 use api::rest::MaxOptimizationThreads;
 use schemars::JsonSchema;
 use segment::types::{
-    BinaryQuantization, HnswConfig, ProductQuantization, ScalarQuantization, StrictModeConfig,
-    TurboQuantization,
+    BinaryQuantization, HnswConfig, ProductQuantization, QuantizationConfig, ScalarQuantization,
+    StrictModeConfig, TurboQuantization,
 };
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationErrors};
@@ -315,6 +315,7 @@ impl DiffConfig<CollectionParamsDiff> for CollectionParams {
             sharding_method: self.sharding_method,
             sparse_vectors: self.sparse_vectors.clone(),
             vectors: self.vectors.clone(),
+            point_versions: self.point_versions,
         }
     }
 }
@@ -329,6 +330,7 @@ impl DiffConfig<StrictModeConfig> for StrictModeConfig {
             unindexed_filtering_update,
             search_max_hnsw_ef,
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling,
             upsert_max_batchsize,
             max_collection_vector_size_bytes,
@@ -355,6 +357,7 @@ impl DiffConfig<StrictModeConfig> for StrictModeConfig {
                 .or(self.unindexed_filtering_update),
             search_max_hnsw_ef: search_max_hnsw_ef.or(self.search_max_hnsw_ef),
             search_allow_exact: search_allow_exact.or(self.search_allow_exact),
+            search_allow_radius: search_allow_radius.or(self.search_allow_radius),
             search_max_oversampling: search_max_oversampling.or(self.search_max_oversampling),
             upsert_max_batchsize: upsert_max_batchsize.or(self.upsert_max_batchsize),
             search_max_batchsize: search_max_batchsize.or(self.search_max_batchsize),
@@ -434,6 +437,7 @@ impl From<CollectionParams> for CollectionParamsDiff {
             sharding_method: _,
             sparse_vectors: _,
             vectors: _,
+            point_versions: _,
         } = config;
 
         CollectionParamsDiff {
@@ -498,6 +502,17 @@ impl QuantizationConfigDiff {
     }
 }
 
+impl From<QuantizationConfig> for QuantizationConfigDiff {
+    fn from(config: QuantizationConfig) -> Self {
+        match config {
+            QuantizationConfig::Scalar(scalar) => QuantizationConfigDiff::Scalar(scalar),
+            QuantizationConfig::Product(product) => QuantizationConfigDiff::Product(product),
+            QuantizationConfig::Binary(binary) => QuantizationConfigDiff::Binary(binary),
+            QuantizationConfig::Turbo(turbo) => QuantizationConfigDiff::Turbo(turbo),
+        }
+    }
+}
+
 impl Validate for QuantizationConfigDiff {
     fn validate(&self) -> Result<(), ValidationErrors> {
         match self {
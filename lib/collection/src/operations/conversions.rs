@@ -17,15 +17,18 @@ use api::rest::{
     BaseGroupRequest, LookupLocation, MaxOptimizationThreads, ShardKeyWithFallback, schema as rest,
 };
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
 use segment::common::operation_error::OperationError;
 use segment::data_types::modifier::Modifier;
 use segment::data_types::vectors::{VectorInternal, VectorStructInternal};
 use segment::types::{
-    Distance, Filter, HnswConfig, MultiVectorConfig, QuantizationConfig, SearchParams,
-    StrictModeConfigOutput, WithPayloadInterface, WithVector,
+    Distance, ExpectedPointVersion, Filter, HnswConfig, IvfConfig, MultiVectorConfig,
+    QuantizationConfig, SearchParams, StrictModeConfigOutput, ValueVariants, VamanaConfig,
+    WithPayloadInterface, WithVector,
 };
 use shard::retrieve::record_internal::RecordInternal;
 use tonic::Status;
+use validator::Validate;
 
 use super::cluster_ops::{ReplicatePoints, ReplicatePointsOperation, ReshardingDirection};
 use super::consistency_params::ReadConsistency;
@@ -40,6 +43,7 @@ use crate::config::{
     default_write_consistency_factor,
 };
 use crate::lookup::WithLookup;
+use crate::lookup::join::WithJoin;
 use crate::lookup::types::WithLookupInterface;
 use crate::operations::ClockTag;
 use crate::operations::cluster_ops::{
@@ -52,12 +56,17 @@ use crate::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
 };
+use crate::operations::payload_validation::{
+    PayloadPropertySchema, PayloadValidationConfig, PayloadValidationConfigDiff,
+    PayloadValidationMode, PayloadValueType,
+};
 use crate::operations::point_ops::{FilterSelector, PointIdsList, PointsSelector, WriteOrdering};
 use crate::operations::shard_selector_internal::ShardSelectorInternal;
 use crate::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionStatus, CollectionWarning,
     CountResult, LocalShardInfo, OptimizersStatus, RecommendRequestInternal, RemoteShardInfo,
     ShardTransferInfo, UpdateQueueInfo, UpdateResult, UpdateStatus, VectorParams, VectorsConfig,
+    validate_vector_params,
 };
 use crate::operations::universal_query::collection_query::FeedbackStrategy;
 use crate::optimizers_builder::OptimizersConfig;
@@ -129,6 +138,8 @@ pub fn try_record_from_grpc(
         vectors,
         shard_key,
         order_value,
+        version,
+        then_by_values,
     } = point;
     let id = id
         .ok_or_else(|| Status::invalid_argument("retrieved point does not have an ID"))?
@@ -147,6 +158,7 @@ pub fn try_record_from_grpc(
         .map_err(|e| Status::invalid_argument(format!("Cannot convert vectors: {e}")))?;
 
     let order_value = order_value.map(TryFrom::try_from).transpose()?;
+    let then_by_values = then_by_values_from_proto(then_by_values)?;
 
     Ok(RecordInternal {
         id,
@@ -154,6 +166,8 @@ pub fn try_record_from_grpc(
         vector,
         shard_key: convert_shard_key_from_grpc_opt(shard_key),
         order_value,
+        then_by_values,
+        version,
     })
 }
 
@@ -211,7 +225,7 @@ pub fn try_discover_request_from_grpc(
         target,
         context: Some(context),
         filter: filter.map(|f| f.try_into()).transpose()?,
-        params: params.map(Into::into),
+        params: params.map(TryInto::try_into).transpose()?,
         limit: limit as usize,
         offset: offset.map(|x| x as usize),
         with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
@@ -404,6 +418,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             quantization_config,
             strict_mode_config,
             metadata,
+            payload_validation,
         } = config;
 
         let OptimizersConfig {
@@ -439,6 +454,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
             read_fan_out_factor,
             sharding_method,
             sparse_vectors,
+            point_versions,
         } = params;
 
         api::grpc::qdrant::CollectionInfo {
@@ -502,6 +518,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                         }
                     }),
                     read_fan_out_delay_ms,
+                    point_versions,
                 }),
                 hnsw_config: Some(api::grpc::qdrant::HnswConfigDiff {
                     m: Some(m as u64),
@@ -543,6 +560,7 @@ impl From<CollectionInfo> for api::grpc::qdrant::CollectionInfo {
                 metadata: metadata
                     .map(api::conversions::json::payload_to_proto)
                     .unwrap_or_default(),
+                payload_validation: payload_validation.map(grpc::PayloadValidationConfig::from),
             }),
             payload_schema: payload_schema
                 .into_iter()
@@ -729,8 +747,11 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
             on_disk,
             datatype,
             multivector_config,
+            matryoshka_dim,
+            vamana_config,
+            ivf_config,
         } = vector_params;
-        Ok(Self {
+        let vector_params = Self {
             size: NonZeroU64::new(size).ok_or_else(|| {
                 Status::invalid_argument("VectorParams size must be greater than zero")
             })?,
@@ -744,7 +765,13 @@ impl TryFrom<api::grpc::qdrant::VectorParams> for VectorParams {
             multivector_config: multivector_config
                 .map(MultiVectorConfig::try_from)
                 .transpose()?,
-        })
+            matryoshka_dim: matryoshka_dim.map(|dim| dim as usize),
+            vamana_config: vamana_config.map(VamanaConfig::try_from).transpose()?,
+            ivf_config: ivf_config.map(IvfConfig::from),
+        };
+        validate_vector_params(&vector_params)
+            .map_err(|err| Status::invalid_argument(format!("Invalid VectorParams: {err}")))?;
+        Ok(vector_params)
     }
 }
 
@@ -756,6 +783,7 @@ pub fn convert_datatype_from_proto(datatype: Option<i32>) -> Result<Option<Datat
                 api::grpc::qdrant::Datatype::Uint8 => Ok(Some(Datatype::Uint8)),
                 api::grpc::qdrant::Datatype::Float32 => Ok(Some(Datatype::Float32)),
                 api::grpc::qdrant::Datatype::Float16 => Ok(Some(Datatype::Float16)),
+                api::grpc::qdrant::Datatype::Bit => Ok(Some(Datatype::Bit)),
                 api::grpc::qdrant::Datatype::Default => Ok(None),
             }
         } else {
@@ -931,6 +959,7 @@ pub fn try_points_selector_from_grpc(
                 shard_key: shard_key_selector
                     .map(ShardKeySelector::try_from)
                     .transpose()?,
+                if_version: None,
             }))
         }
         Some(api::grpc::qdrant::points_selector::PointsSelectorOneOf::Filter(f)) => {
@@ -945,6 +974,23 @@ pub fn try_points_selector_from_grpc(
     }
 }
 
+/// Convert expected point versions from gRPC.
+///
+/// Empty list means, that no versions are expected.
+pub fn try_if_version_from_grpc(
+    if_version: Vec<api::grpc::qdrant::ExpectedPointVersion>,
+) -> Result<Option<Vec<ExpectedPointVersion>>, Status> {
+    if if_version.is_empty() {
+        return Ok(None);
+    }
+
+    if_version
+        .into_iter()
+        .map(ExpectedPointVersion::try_from)
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
 impl From<UpdateResult> for api::grpc::qdrant::UpdateResultInternal {
     fn from(res: UpdateResult) -> Self {
         let UpdateResult {
@@ -1104,6 +1150,28 @@ impl TryFrom<api::grpc::qdrant::WithLookup> for WithLookupInterface {
     }
 }
 
+impl TryFrom<api::grpc::qdrant::WithJoin> for WithJoin {
+    type Error = Status;
+
+    fn try_from(value: api::grpc::qdrant::WithJoin) -> Result<Self, Self::Error> {
+        let api::grpc::qdrant::WithJoin {
+            key,
+            collection,
+            with_payload,
+            target,
+        } = value;
+        Ok(Self {
+            key: json_path_from_proto(&key)?,
+            target: target.unwrap_or_else(|| collection.clone()),
+            collection_name: collection,
+            with_payload: with_payload
+                .map(|wp| wp.try_into())
+                .transpose()?
+                .or(Some(WithPayloadInterface::Bool(true))),
+        })
+    }
+}
+
 impl TryFrom<api::grpc::qdrant::TargetVector> for RecommendExample {
     type Error = Status;
 
@@ -1122,7 +1190,13 @@ impl TryFrom<api::grpc::qdrant::TargetVector> for RecommendExample {
 impl TryFrom<PointGroup> for api::grpc::qdrant::PointGroup {
     type Error = OperationError;
     fn try_from(group: PointGroup) -> Result<Self, Self::Error> {
-        let PointGroup { hits, id, lookup } = group;
+        let PointGroup {
+            hits,
+            id,
+            lookup,
+            // Subgroups are only requested through REST
+            subgroups: _,
+        } = group;
         let hits: Result<_, _> = hits
             .into_iter()
             .map(api::grpc::qdrant::ScoredPoint::try_from)
@@ -1280,7 +1354,7 @@ impl TryFrom<api::grpc::qdrant::RecommendPoints> for RecommendRequestInternal {
             negative,
             strategy: strategy.map(|s| s.try_into()).transpose()?,
             filter: filter.map(|f| f.try_into()).transpose()?,
-            params: params.map(Into::into),
+            params: params.map(TryInto::try_into).transpose()?,
             limit: limit as usize,
             offset: offset.map(|x| x as usize),
             with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
@@ -1367,10 +1441,11 @@ impl TryFrom<api::grpc::qdrant::RecommendPointGroups> for RecommendGroupsRequest
             with_vector,
             score_threshold,
             group_request: BaseGroupRequest {
-                group_by: json_path_from_proto(&group_by)?,
+                group_by: json_path_from_proto(&group_by)?.into(),
                 limit,
                 group_size,
                 with_lookup: with_lookup.map(|l| l.try_into()).transpose()?,
+                subgroups: None,
             },
         })
     }
@@ -1400,6 +1475,9 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             on_disk,
             datatype,
             multivector_config,
+            matryoshka_dim,
+            vamana_config,
+            ivf_config,
         } = value;
         api::grpc::qdrant::VectorParams {
             size: size.get(),
@@ -1408,6 +1486,8 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
                 Distance::Euclid => api::grpc::qdrant::Distance::Euclid,
                 Distance::Dot => api::grpc::qdrant::Distance::Dot,
                 Distance::Manhattan => api::grpc::qdrant::Distance::Manhattan,
+                Distance::Hamming => api::grpc::qdrant::Distance::Hamming,
+                Distance::Jaccard => api::grpc::qdrant::Distance::Jaccard,
             }
             .into(),
             hnsw_config: hnsw_config.map(HnswConfigDiff::into),
@@ -1415,6 +1495,9 @@ impl From<VectorParams> for api::grpc::qdrant::VectorParams {
             on_disk,
             datatype: datatype.map(|dt| api::grpc::qdrant::Datatype::from(dt).into()),
             multivector_config: multivector_config.map(api::grpc::qdrant::MultiVectorConfig::from),
+            matryoshka_dim: matryoshka_dim.map(|dim| dim as u64),
+            vamana_config: vamana_config.map(api::grpc::qdrant::VamanaConfig::from),
+            ivf_config: ivf_config.map(api::grpc::qdrant::IvfConfig::from),
         }
     }
 }
@@ -1425,6 +1508,7 @@ impl From<Datatype> for api::grpc::qdrant::Datatype {
             Datatype::Float32 => api::grpc::qdrant::Datatype::Float32,
             Datatype::Uint8 => api::grpc::qdrant::Datatype::Uint8,
             Datatype::Float16 => api::grpc::qdrant::Datatype::Float16,
+            Datatype::Bit => api::grpc::qdrant::Datatype::Bit,
         }
     }
 }
@@ -1859,6 +1943,7 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
             quantization_config,
             strict_mode_config,
             metadata,
+            payload_validation,
         } = config;
         Ok(Self {
             params: match params {
@@ -1874,6 +1959,7 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                         sharding_method,
                         sparse_vectors_config,
                         read_fan_out_delay_ms,
+                        point_versions,
                     } = params;
                     CollectionParams {
                         vectors: match vectors_config {
@@ -1931,6 +2017,7 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
                             .map(sharding_method_from_proto)
                             .transpose()?,
                         read_fan_out_delay_ms,
+                        point_versions,
                     }
                 }
             },
@@ -1959,16 +2046,222 @@ impl TryFrom<api::grpc::qdrant::CollectionConfig> for CollectionConfig {
             } else {
                 Some(api::conversions::json::proto_to_payloads(metadata)?)
             },
+            payload_validation: payload_validation
+                .map(PayloadValidationConfig::try_from)
+                .transpose()?,
         })
     }
 }
 
+impl From<PayloadValidationMode> for grpc::PayloadValidationMode {
+    fn from(mode: PayloadValidationMode) -> Self {
+        match mode {
+            PayloadValidationMode::Reject => grpc::PayloadValidationMode::Reject,
+            PayloadValidationMode::Warn => grpc::PayloadValidationMode::Warn,
+        }
+    }
+}
+
+impl From<grpc::PayloadValidationMode> for PayloadValidationMode {
+    fn from(mode: grpc::PayloadValidationMode) -> Self {
+        match mode {
+            grpc::PayloadValidationMode::Reject => PayloadValidationMode::Reject,
+            grpc::PayloadValidationMode::Warn => PayloadValidationMode::Warn,
+        }
+    }
+}
+
+impl From<PayloadValueType> for grpc::PayloadValueType {
+    fn from(value_type: PayloadValueType) -> Self {
+        match value_type {
+            PayloadValueType::String => grpc::PayloadValueType::String,
+            PayloadValueType::Integer => grpc::PayloadValueType::Integer,
+            PayloadValueType::Number => grpc::PayloadValueType::Number,
+            PayloadValueType::Boolean => grpc::PayloadValueType::Boolean,
+            PayloadValueType::Object => grpc::PayloadValueType::Object,
+            PayloadValueType::Datetime => grpc::PayloadValueType::Datetime,
+            PayloadValueType::Uuid => grpc::PayloadValueType::Uuid,
+        }
+    }
+}
+
+impl From<grpc::PayloadValueType> for PayloadValueType {
+    fn from(value_type: grpc::PayloadValueType) -> Self {
+        match value_type {
+            grpc::PayloadValueType::String => PayloadValueType::String,
+            grpc::PayloadValueType::Integer => PayloadValueType::Integer,
+            grpc::PayloadValueType::Number => PayloadValueType::Number,
+            grpc::PayloadValueType::Boolean => PayloadValueType::Boolean,
+            grpc::PayloadValueType::Object => PayloadValueType::Object,
+            grpc::PayloadValueType::Datetime => PayloadValueType::Datetime,
+            grpc::PayloadValueType::Uuid => PayloadValueType::Uuid,
+        }
+    }
+}
+
+impl From<PayloadPropertySchema> for grpc::PayloadPropertySchema {
+    fn from(schema: PayloadPropertySchema) -> Self {
+        let PayloadPropertySchema {
+            r#type,
+            r#enum,
+            minimum,
+            maximum,
+        } = schema;
+        Self {
+            r#type: r#type.map(|value_type| grpc::PayloadValueType::from(value_type) as i32),
+            values: r#enum
+                .unwrap_or_default()
+                .iter()
+                .map(|value| api::conversions::json::json_to_proto(value.to_value()))
+                .collect(),
+            minimum: minimum.map(|minimum| minimum.0),
+            maximum: maximum.map(|maximum| maximum.0),
+        }
+    }
+}
+
+impl TryFrom<grpc::PayloadPropertySchema> for PayloadPropertySchema {
+    type Error = Status;
+
+    fn try_from(schema: grpc::PayloadPropertySchema) -> Result<Self, Self::Error> {
+        let grpc::PayloadPropertySchema {
+            r#type,
+            values,
+            minimum,
+            maximum,
+        } = schema;
+
+        let r#type = r#type
+            .map(|value_type| {
+                grpc::PayloadValueType::try_from(value_type)
+                    .map(PayloadValueType::from)
+                    .map_err(|err| {
+                        Status::invalid_argument(format!(
+                            "Cannot convert PayloadValueType: {value_type}, error: {err}"
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        let values = values
+            .into_iter()
+            .map(|value| match value.kind {
+                Some(grpc::value::Kind::StringValue(value)) => Ok(ValueVariants::String(value)),
+                Some(grpc::value::Kind::IntegerValue(value)) => Ok(ValueVariants::Integer(value)),
+                Some(grpc::value::Kind::BoolValue(value)) => Ok(ValueVariants::Bool(value)),
+                Some(
+                    grpc::value::Kind::NullValue(_)
+                    | grpc::value::Kind::DoubleValue(_)
+                    | grpc::value::Kind::StructValue(_)
+                    | grpc::value::Kind::ListValue(_),
+                )
+                | None => Err(Status::invalid_argument(
+                    "Allowed payload values must be strings, integers or booleans",
+                )),
+            })
+            .collect::<Result<Vec<_>, Status>>()?;
+
+        Ok(Self {
+            r#type,
+            r#enum: (!values.is_empty()).then_some(values),
+            minimum: minimum.map(OrderedFloat),
+            maximum: maximum.map(OrderedFloat),
+        })
+    }
+}
+
+impl From<PayloadValidationConfig> for grpc::PayloadValidationConfig {
+    fn from(config: PayloadValidationConfig) -> Self {
+        let PayloadValidationConfig {
+            mode,
+            properties,
+            required,
+            additional_properties,
+        } = config;
+        Self {
+            mode: Some(grpc::PayloadValidationMode::from(mode) as i32),
+            properties: properties
+                .into_iter()
+                .map(|(field, schema)| (field, schema.into()))
+                .collect(),
+            required,
+            additional_properties: Some(additional_properties),
+        }
+    }
+}
+
+impl TryFrom<grpc::PayloadValidationConfig> for PayloadValidationConfig {
+    type Error = Status;
+
+    fn try_from(config: grpc::PayloadValidationConfig) -> Result<Self, Self::Error> {
+        let grpc::PayloadValidationConfig {
+            mode,
+            properties,
+            required,
+            additional_properties,
+        } = config;
+
+        let mode = mode
+            .map(|mode| {
+                grpc::PayloadValidationMode::try_from(mode)
+                    .map(PayloadValidationMode::from)
+                    .map_err(|err| {
+                        Status::invalid_argument(format!(
+                            "Cannot convert PayloadValidationMode: {mode}, error: {err}"
+                        ))
+                    })
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let config = Self {
+            mode,
+            properties: properties
+                .into_iter()
+                .map(|(field, schema)| Ok((field, schema.try_into()?)))
+                .collect::<Result<_, Status>>()?,
+            required,
+            additional_properties: additional_properties.unwrap_or(true),
+        };
+
+        config
+            .validate()
+            .map_err(|err| Status::invalid_argument(format!("Invalid payload schema: {err}")))?;
+
+        Ok(config)
+    }
+}
+
+impl TryFrom<grpc::PayloadValidationConfigDiff> for PayloadValidationConfigDiff {
+    type Error = Status;
+
+    fn try_from(diff: grpc::PayloadValidationConfigDiff) -> Result<Self, Self::Error> {
+        match diff.config {
+            Some(grpc::payload_validation_config_diff::Config::Enabled(config)) => {
+                Ok(PayloadValidationConfigDiff::Config(config.try_into()?))
+            }
+            Some(grpc::payload_validation_config_diff::Config::Disabled(_)) => {
+                Ok(PayloadValidationConfigDiff::new_disabled())
+            }
+            None => Err(Status::invalid_argument(
+                "Payload validation config diff must be either `enabled` or `disabled`",
+            )),
+        }
+    }
+}
+
 impl From<rest::FeedbackStrategy> for FeedbackStrategy {
     fn from(strategy: rest::FeedbackStrategy) -> Self {
         match strategy {
             rest::FeedbackStrategy::Naive(rest::NaiveFeedbackStrategy {
                 naive: rest::NaiveFeedbackStrategyParams { a, b, c },
             }) => FeedbackStrategy::Naive { a, b, c },
+            rest::FeedbackStrategy::Rocchio(rest::RocchioFeedbackStrategy {
+                rocchio: rest::RocchioFeedbackStrategyParams { alpha, beta, gamma },
+            }) => FeedbackStrategy::Rocchio { alpha, beta, gamma },
+            rest::FeedbackStrategy::Reweight(rest::ReweightFeedbackStrategy {
+                reweight: rest::ReweightFeedbackStrategyParams { regularization },
+            }) => FeedbackStrategy::Reweight { regularization },
         }
     }
 }
@@ -1987,6 +2280,12 @@ impl TryFrom<grpc::FeedbackStrategy> for FeedbackStrategy {
             Variant::Naive(grpc::NaiveFeedbackStrategy { a, b, c }) => {
                 FeedbackStrategy::Naive { a, b, c }
             }
+            Variant::Rocchio(grpc::RocchioFeedbackStrategy { alpha, beta, gamma }) => {
+                FeedbackStrategy::Rocchio { alpha, beta, gamma }
+            }
+            Variant::Reweight(grpc::ReweightFeedbackStrategy { regularization }) => {
+                FeedbackStrategy::Reweight { regularization }
+            }
         };
 
         Ok(strategy)
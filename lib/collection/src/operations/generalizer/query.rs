@@ -28,6 +28,7 @@ impl Generalizer for ShardQueryRequest {
             score_threshold,
             limit,
             offset,
+            start_after: _,
             params,
             with_vector,
             with_payload,
@@ -40,6 +41,7 @@ impl Generalizer for ShardQueryRequest {
             score_threshold: *score_threshold,
             limit: *limit,
             offset: *offset,
+            start_after: None,
             params: *params,
             with_vector: with_vector.clone(),
             with_payload: with_payload.clone(),
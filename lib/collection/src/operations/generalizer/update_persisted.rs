@@ -65,6 +65,9 @@ impl Generalizer for PointOperations {
             PointOperations::DeletePoints { ids } => {
                 PointOperations::DeletePoints { ids: ids.clone() }
             }
+            PointOperations::DeletePointsConditional(conditional_delete) => {
+                PointOperations::DeletePointsConditional(conditional_delete.clone())
+            }
             PointOperations::DeletePointsByFilter(filter) => {
                 PointOperations::DeletePointsByFilter(filter.clone())
             }
@@ -113,12 +116,14 @@ impl Generalizer for ConditionalInsertOperationInternal {
             points_op,
             condition,
             update_mode,
+            if_version,
         } = self;
 
         Self {
             condition: condition.clone(),
             points_op: points_op.remove_details(),
             update_mode: *update_mode,
+            if_version: if_version.clone(),
         }
     }
 }
@@ -289,6 +294,7 @@ impl Generalizer for SetPayloadOp {
             points,
             filter,
             key,
+            if_version,
         } = self;
 
         Self {
@@ -296,6 +302,7 @@ impl Generalizer for SetPayloadOp {
             points: points.clone(),
             filter: filter.clone(),
             key: key.clone(),
+            if_version: if_version.clone(),
         }
     }
 }
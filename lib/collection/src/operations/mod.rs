@@ -6,6 +6,7 @@ pub mod generalizer;
 pub mod loggable;
 pub mod operation_effect;
 pub mod payload_ops;
+pub mod payload_validation;
 pub mod point_ops;
 pub mod shard_selector_internal;
 pub mod shared_storage_config;
@@ -24,8 +25,8 @@ pub mod query_enum {
     pub use shard::query::query_enum::QueryEnum;
 }
 
-use ahash::AHashMap;
-use segment::types::ExtendedPointId;
+use ahash::{AHashMap, AHashSet};
+use segment::types::{ExpectedPointVersion, ExtendedPointId};
 pub use shard::operations::*;
 
 use crate::hash_ring::{HashRingRouter, ShardIds};
@@ -119,6 +120,24 @@ where
     OperationToShard::by_shard(op_vec_by_shard)
 }
 
+/// Select expected versions of the given points only
+///
+/// Used to route version preconditions to the same shards as the points they refer to.
+fn if_version_for_points(
+    if_version: Option<&[ExpectedPointVersion]>,
+    point_ids: &[ExtendedPointId],
+) -> Option<Vec<ExpectedPointVersion>> {
+    let if_version = if_version?;
+    let point_ids: AHashSet<_> = point_ids.iter().collect();
+    Some(
+        if_version
+            .iter()
+            .filter(|expected| point_ids.contains(&expected.id))
+            .copied()
+            .collect(),
+    )
+}
+
 /// Get the shards for a point ID
 ///
 /// Normally returns a single shard ID. Might return multiple if resharding is currently in
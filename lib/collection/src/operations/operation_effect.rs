@@ -59,6 +59,9 @@ impl EstimateOperationEffectArea for point_ops::PointOperations {
             point_ops::PointOperations::DeletePoints { ids } => {
                 OperationEffectArea::Points(Cow::Borrowed(ids))
             }
+            point_ops::PointOperations::DeletePointsConditional(conditional_delete) => {
+                OperationEffectArea::Points(Cow::Borrowed(&conditional_delete.ids))
+            }
             point_ops::PointOperations::DeletePointsByFilter(filter) => {
                 OperationEffectArea::Filter(filter)
             }
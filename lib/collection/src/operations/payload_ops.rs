@@ -1,6 +1,6 @@
 pub use shard::operations::payload_ops::*;
 
-use super::{OperationToShard, SplitByShard, split_iter_by_shard};
+use super::{OperationToShard, SplitByShard, if_version_for_points, split_iter_by_shard};
 use crate::hash_ring::HashRingRouter;
 
 impl SplitByShard for PayloadOps {
@@ -46,6 +46,7 @@ impl SplitByShard for SetPayloadOp {
             (Some(_), _) => {
                 split_iter_by_shard(self.points.unwrap(), |id| *id, ring).map(|points| {
                     SetPayloadOp {
+                        if_version: if_version_for_points(self.if_version.as_deref(), &points),
                         points: Some(points),
                         payload: self.payload.clone(),
                         filter: self.filter.clone(),
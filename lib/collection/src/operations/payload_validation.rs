@@ -0,0 +1,402 @@
+//! Optional per-collection payload schema, enforced on point writes.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use ordered_float::OrderedFloat;
+use schemars::JsonSchema;
+use segment::types::{DateTimePayloadType, Payload, ValueVariants};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::operations::config_diff::Disabled;
+
+/// What to do with writes which violate the payload schema
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadValidationMode {
+    /// Reject the whole operation
+    #[default]
+    Reject,
+    /// Apply the operation, but report violations as collection issues
+    Warn,
+}
+
+/// Expected type of a payload value
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadValueType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Object,
+    /// String in one of the datetime formats accepted by `datetime` payload index
+    Datetime,
+    /// String containing a UUID
+    Uuid,
+}
+
+impl fmt::Display for PayloadValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PayloadValueType::String => "string",
+            PayloadValueType::Integer => "integer",
+            PayloadValueType::Number => "number",
+            PayloadValueType::Boolean => "boolean",
+            PayloadValueType::Object => "object",
+            PayloadValueType::Datetime => "datetime",
+            PayloadValueType::Uuid => "uuid",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Schema of a single top-level payload field.
+///
+/// Arrays are validated element-wise, the same way payload indexes treat multi-value fields.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadPropertySchema {
+    /// Expected type of the value. Any type is allowed if not specified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<PayloadValueType>,
+    /// If specified, the value must be one of the listed values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#enum: Option<Vec<ValueVariants>>,
+    /// Minimal allowed value for numbers, inclusive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<f64>")]
+    pub minimum: Option<OrderedFloat<f64>>,
+    /// Maximal allowed value for numbers, inclusive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<f64>")]
+    pub maximum: Option<OrderedFloat<f64>>,
+}
+
+/// Schema of the payload of every point in the collection
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct PayloadValidationConfig {
+    /// What to do with writes which violate the schema. Default: reject
+    #[serde(default)]
+    pub mode: PayloadValidationMode,
+    /// Schemas of known top-level payload fields
+    #[serde(default)]
+    pub properties: BTreeMap<String, PayloadPropertySchema>,
+    /// Fields which must be present in the payload of every inserted point
+    #[serde(default)]
+    pub required: Vec<String>,
+    /// Whether fields not listed in `properties` are allowed. Default: true
+    #[serde(default = "default_additional_properties")]
+    pub additional_properties: bool,
+}
+
+const fn default_additional_properties() -> bool {
+    true
+}
+
+impl Validate for PayloadValidationConfig {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        for (field, schema) in &self.properties {
+            if let (Some(min), Some(max)) = (schema.minimum, schema.maximum)
+                && min > max
+            {
+                let mut error = ValidationError::new("range");
+                error.message = Some(
+                    format!("minimum of field `{field}` must not be larger than maximum").into(),
+                );
+                errors.add("properties", error);
+            }
+        }
+        if !self.additional_properties
+            && let Some(field) = self
+                .required
+                .iter()
+                .find(|field| !self.properties.contains_key(*field))
+        {
+            let mut error = ValidationError::new("required");
+            error.message =
+                Some(format!("required field `{field}` is not allowed by `properties`").into());
+            errors.add("required", error);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Single violation of the payload schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PayloadSchemaViolation {
+    /// Top-level payload field the violation is about
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for PayloadSchemaViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "field `{}` {}", self.field, self.message)
+    }
+}
+
+/// Payload schema to replace the current one with, or `Disabled` to stop validating payloads
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Eq, Hash)]
+#[serde(untagged)]
+pub enum PayloadValidationConfigDiff {
+    Config(PayloadValidationConfig),
+    Disabled(Disabled),
+}
+
+impl PayloadValidationConfigDiff {
+    pub fn new_disabled() -> Self {
+        PayloadValidationConfigDiff::Disabled(Disabled::Disabled)
+    }
+
+    /// Schema to validate payloads against, if validation is not disabled
+    pub fn into_config(self) -> Option<PayloadValidationConfig> {
+        match self {
+            PayloadValidationConfigDiff::Config(config) => Some(config),
+            PayloadValidationConfigDiff::Disabled(_) => None,
+        }
+    }
+}
+
+impl Validate for PayloadValidationConfigDiff {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            PayloadValidationConfigDiff::Config(config) => config.validate(),
+            PayloadValidationConfigDiff::Disabled(_) => Ok(()),
+        }
+    }
+}
+
+impl PayloadValidationConfig {
+    /// Validate the complete payload of a point, as written by upsert or overwrite payload.
+    pub fn validate_payload(&self, payload: Option<&Payload>) -> Vec<PayloadSchemaViolation> {
+        let empty = Map::new();
+        let fields = payload.map_or(&empty, |payload| &payload.0);
+
+        let mut violations: Vec<_> = self
+            .required
+            .iter()
+            .filter(|field| fields.get(*field).is_none_or(Value::is_null))
+            .map(|field| PayloadSchemaViolation {
+                field: field.clone(),
+                message: "is required".to_string(),
+            })
+            .collect();
+
+        violations.extend(self.validate_fields(fields));
+        violations
+    }
+
+    /// Validate a partial payload, as merged into existing payload by set payload.
+    ///
+    /// Required fields are not checked, as they may already be present in the stored payload.
+    pub fn validate_partial_payload(&self, payload: &Payload) -> Vec<PayloadSchemaViolation> {
+        self.validate_fields(&payload.0)
+    }
+
+    /// Validate a payload set under the given nested key.
+    ///
+    /// Only the top-level field is checked to be known, nested values are not validated.
+    pub fn validate_nested_key(&self, top_level_field: &str) -> Vec<PayloadSchemaViolation> {
+        self.check_known_field(top_level_field)
+            .into_iter()
+            .collect()
+    }
+
+    fn validate_fields(&self, fields: &Map<String, Value>) -> Vec<PayloadSchemaViolation> {
+        let mut violations = Vec::new();
+        for (field, value) in fields {
+            match self.properties.get(field) {
+                Some(schema) => {
+                    if let Some(message) = schema.check_value(value) {
+                        violations.push(PayloadSchemaViolation {
+                            field: field.clone(),
+                            message,
+                        });
+                    }
+                }
+                None => violations.extend(self.check_known_field(field)),
+            }
+        }
+        violations
+    }
+
+    fn check_known_field(&self, field: &str) -> Option<PayloadSchemaViolation> {
+        if self.additional_properties || self.properties.contains_key(field) {
+            return None;
+        }
+        Some(PayloadSchemaViolation {
+            field: field.to_string(),
+            message: "is not defined in the payload schema".to_string(),
+        })
+    }
+}
+
+impl PayloadPropertySchema {
+    /// Check the value against the schema, returns description of the first violation
+    fn check_value(&self, value: &Value) -> Option<String> {
+        match value {
+            // Null is treated as a missing value
+            Value::Null => None,
+            Value::Array(values) => values.iter().find_map(|value| self.check_single(value)),
+            value => self.check_single(value),
+        }
+    }
+
+    fn check_single(&self, value: &Value) -> Option<String> {
+        if let Some(expected) = self.r#type
+            && !type_matches(expected, value)
+        {
+            return Some(format!("must be of type {expected}, got {value}"));
+        }
+
+        if let Some(allowed) = &self.r#enum
+            && !allowed.iter().any(|variant| &variant.to_value() == value)
+        {
+            return Some(format!("must be one of the allowed values, got {value}"));
+        }
+
+        if let Some(number) = value.as_f64() {
+            if let Some(minimum) = self.minimum
+                && number < minimum.0
+            {
+                return Some(format!("must be {minimum} or larger, got {value}"));
+            }
+            if let Some(maximum) = self.maximum
+                && number > maximum.0
+            {
+                return Some(format!("must be {maximum} or smaller, got {value}"));
+            }
+        }
+
+        None
+    }
+}
+
+fn type_matches(expected: PayloadValueType, value: &Value) -> bool {
+    match expected {
+        PayloadValueType::String => value.is_string(),
+        PayloadValueType::Integer => value.is_i64() || value.is_u64(),
+        PayloadValueType::Number => value.is_number(),
+        PayloadValueType::Boolean => value.is_boolean(),
+        PayloadValueType::Object => value.is_object(),
+        PayloadValueType::Datetime => value
+            .as_str()
+            .is_some_and(|s| DateTimePayloadType::from_str(s).is_ok()),
+        PayloadValueType::Uuid => value.as_str().is_some_and(|s| Uuid::parse_str(s).is_ok()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn config() -> PayloadValidationConfig {
+        serde_json::from_value(json!({
+            "properties": {
+                "price": { "type": "number", "minimum": 0 },
+                "color": { "type": "string", "enum": ["red", "green"] },
+                "created": { "type": "datetime" },
+            },
+            "required": ["price"],
+            "additional_properties": false,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_disable_validation() {
+        let diff: PayloadValidationConfigDiff = serde_json::from_value(json!("Disabled")).unwrap();
+        assert_eq!(diff, PayloadValidationConfigDiff::new_disabled());
+        assert_eq!(diff.into_config(), None);
+
+        // Schema without constraints still enables validation
+        let diff: PayloadValidationConfigDiff = serde_json::from_value(json!({})).unwrap();
+        assert!(diff.into_config().is_some());
+
+        let diff: PayloadValidationConfigDiff =
+            serde_json::from_value(serde_json::to_value(config()).unwrap()).unwrap();
+        assert_eq!(diff.into_config(), Some(config()));
+    }
+
+    #[test]
+    fn test_grpc_round_trip() {
+        let grpc_config = api::grpc::qdrant::PayloadValidationConfig::from(config());
+        let converted = PayloadValidationConfig::try_from(grpc_config.clone()).unwrap();
+        assert_eq!(converted, config());
+
+        // Invalid schemas are rejected on conversion
+        let mut invalid = grpc_config;
+        invalid.additional_properties = Some(false);
+        invalid.required.push("size".to_string());
+        assert!(PayloadValidationConfig::try_from(invalid).is_err());
+    }
+
+    fn payload(value: Value) -> Payload {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_valid_payload() {
+        let config = config();
+        assert_eq!(config.mode, PayloadValidationMode::Reject);
+        assert!(config.validate().is_ok());
+
+        let valid = payload(json!({
+            "price": 9.5,
+            "color": ["red", "green"],
+            "created": "2024-01-01T00:00:00Z",
+        }));
+        assert!(config.validate_payload(Some(&valid)).is_empty());
+    }
+
+    #[test]
+    fn test_payload_violations() {
+        let config = config();
+
+        let violations = config.validate_payload(None);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "price");
+
+        let invalid = payload(json!({
+            "price": -1,
+            "color": ["red", "blue"],
+            "created": "yesterday",
+            "pirce": 10,
+        }));
+        let mut fields: Vec<_> = config
+            .validate_payload(Some(&invalid))
+            .into_iter()
+            .map(|violation| violation.field)
+            .collect();
+        fields.sort();
+        assert_eq!(fields, ["color", "created", "pirce", "price"]);
+
+        // Partial payloads do not need to contain required fields
+        let partial = payload(json!({ "color": "green" }));
+        assert!(config.validate_partial_payload(&partial).is_empty());
+        assert_eq!(config.validate_nested_key("pirce").len(), 1);
+        assert!(config.validate_nested_key("created").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let config: PayloadValidationConfig = serde_json::from_value(json!({
+            "properties": { "price": { "minimum": 10, "maximum": 1 } },
+        }))
+        .unwrap();
+        assert!(config.validate().is_err());
+    }
+}
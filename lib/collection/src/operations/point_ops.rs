@@ -12,7 +12,9 @@ use serde::{Deserialize, Serialize};
 pub use shard::operations::point_ops::*;
 use validator::{Validate, ValidationErrors};
 
-use super::{OperationToShard, SplitByShard, point_to_shards, split_iter_by_shard};
+use super::{
+    OperationToShard, SplitByShard, if_version_for_points, point_to_shards, split_iter_by_shard,
+};
 use crate::hash_ring::HashRingRouter;
 use crate::shards::shard::ShardId;
 
@@ -70,6 +72,9 @@ impl SplitByShard for PointOperations {
                 .map(PointOperations::UpsertPointsConditional),
             PointOperations::DeletePoints { ids } => split_iter_by_shard(ids, |id| *id, ring)
                 .map(|ids| PointOperations::DeletePoints { ids }),
+            PointOperations::DeletePointsConditional(conditional_delete) => conditional_delete
+                .split_by_shard(ring)
+                .map(PointOperations::DeletePointsConditional),
             by_filter @ PointOperations::DeletePointsByFilter(_) => {
                 OperationToShard::to_all(by_filter)
             }
@@ -102,6 +107,7 @@ impl SplitByShard for ConditionalInsertOperationInternal {
             points_op,
             condition,
             update_mode,
+            if_version,
         } = self;
 
         let points_op = points_op.split_by_shard(ring);
@@ -110,12 +116,17 @@ impl SplitByShard for ConditionalInsertOperationInternal {
                 by_shards
                     .into_iter()
                     .map(|(shard_id, upsert_operation)| {
+                        let if_version = if_version_for_points(
+                            if_version.as_deref(),
+                            &upsert_operation.point_ids(),
+                        );
                         (
                             shard_id,
                             ConditionalInsertOperationInternal {
                                 points_op: upsert_operation,
                                 condition: condition.clone(),
                                 update_mode,
+                                if_version,
                             },
                         )
                     })
@@ -125,11 +136,24 @@ impl SplitByShard for ConditionalInsertOperationInternal {
                 points_op: upsert_operation,
                 condition,
                 update_mode,
+                if_version,
             }),
         }
     }
 }
 
+impl SplitByShard for ConditionalDeleteOperationInternal {
+    fn split_by_shard(self, ring: &HashRingRouter) -> OperationToShard<Self> {
+        let ConditionalDeleteOperationInternal { ids, if_version } = self;
+
+        split_iter_by_shard(ids, |id| *id, ring).map(|ids| {
+            let if_version =
+                if_version_for_points(Some(if_version.as_slice()), &ids).unwrap_or_default();
+            ConditionalDeleteOperationInternal { ids, if_version }
+        })
+    }
+}
+
 impl SplitByShard for BatchPersisted {
     fn split_by_shard(self, ring: &HashRingRouter) -> OperationToShard<Self> {
         let batch = self;
@@ -363,6 +387,7 @@ mod tests {
                 id: *id,
                 vector: VectorStructPersisted::from(vec![0.1, 0.2, 0.3]),
                 payload: None,
+                version: None,
             })
             .collect();
 
@@ -423,6 +448,7 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            if_version: None,
         });
         assert!(batch.validate().is_err());
 
@@ -435,6 +461,7 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            if_version: None,
         });
         assert!(batch.validate().is_ok());
 
@@ -447,6 +474,7 @@ mod tests {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            if_version: None,
         });
         assert!(batch.validate().is_err());
     }
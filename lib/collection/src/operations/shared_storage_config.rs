@@ -6,6 +6,7 @@ use std::time::Duration;
 use common::load_concurrency::LoadConcurrencyConfig;
 use segment::types::HnswGlobalConfig;
 
+use crate::collection::recall_monitor::RecallMonitorConfig;
 use crate::common::snapshots_manager::SnapshotsConfig;
 use crate::operations::types::NodeType;
 use crate::shards::transfer::ShardTransferMethod;
@@ -42,6 +43,7 @@ pub struct SharedStorageConfig {
     pub hnsw_global_config: HnswGlobalConfig,
     pub load_concurrency_config: LoadConcurrencyConfig,
     pub search_thread_count: usize,
+    pub recall_monitor: Option<RecallMonitorConfig>,
 }
 
 impl Default for SharedStorageConfig {
@@ -62,6 +64,7 @@ impl Default for SharedStorageConfig {
             hnsw_global_config: HnswGlobalConfig::default(),
             load_concurrency_config: LoadConcurrencyConfig::default(),
             search_thread_count: common::defaults::search_thread_count(common::cpu::get_num_cpus()),
+            recall_monitor: None,
         }
     }
 }
@@ -84,6 +87,7 @@ impl SharedStorageConfig {
         hnsw_global_config: HnswGlobalConfig,
         load_concurrency_config: LoadConcurrencyConfig,
         search_thread_count: usize,
+        recall_monitor: Option<RecallMonitorConfig>,
     ) -> Self {
         let update_queue_size = update_queue_size.unwrap_or(match node_type {
             NodeType::Normal => DEFAULT_UPDATE_QUEUE_SIZE,
@@ -105,6 +109,7 @@ impl SharedStorageConfig {
             hnsw_global_config,
             load_concurrency_config,
             search_thread_count,
+            recall_monitor,
         }
     }
 }
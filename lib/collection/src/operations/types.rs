@@ -17,16 +17,17 @@ use common::types::ScoreType;
 use common::validation::validate_range_generic;
 use common::{defaults, save_on_disk};
 use issues::IssueRecord;
+use itertools::Itertools as _;
 use schemars::JsonSchema;
 use segment::common::anonymize::Anonymize;
-use segment::common::operation_error::{CancelledError, OperationError};
+use segment::common::operation_error::{CancelledError, OperationError, PointVersionConflict};
 use segment::data_types::groups::GroupId;
 use segment::data_types::modifier::Modifier;
-use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, DenseVector};
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, DenseVector, PackedBits};
 use segment::types::{
-    Distance, Filter, HnswConfig, MultiVectorConfig, Payload, PayloadIndexInfo, PayloadKeyType,
-    PointIdType, QuantizationConfig, SearchParams, SeqNumberType, ShardKey,
-    SparseVectorStorageType, StrictModeConfigOutput, VectorName, VectorNameBuf,
+    Distance, Filter, HnswConfig, IvfConfig, MultiVectorConfig, Payload, PayloadIndexInfo,
+    PayloadKeyType, PointIdType, QuantizationConfig, SearchParams, SeqNumberType, ShardKey,
+    SparseVectorStorageType, StrictModeConfigOutput, VamanaConfig, VectorName, VectorNameBuf,
     VectorStorageDatatype, WithPayloadInterface, WithVector,
 };
 use semver::Version;
@@ -51,6 +52,7 @@ use super::ClockTag;
 use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
 use crate::operations::cluster_ops::ReshardingDirection;
 use crate::operations::config_diff::{HnswConfigDiff, QuantizationConfigDiff};
+use crate::operations::payload_validation::PayloadValidationConfig;
 use crate::optimizers_builder::OptimizersConfig;
 use crate::shards::replica_set::replica_set_state::ReplicaState;
 use crate::shards::resharding::ReshardingStage;
@@ -177,6 +179,9 @@ pub struct CollectionConfig {
     /// such as creation time, migration data, inference model info, etc.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Schema, which payloads of inserted and updated points are validated against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_validation: Option<PayloadValidationConfig>,
 }
 
 impl From<CollectionConfigInternal> for CollectionConfig {
@@ -191,6 +196,7 @@ impl From<CollectionConfigInternal> for CollectionConfig {
             // Internal UUID to identify unique collections in consensus snapshots
             uuid: _,
             metadata,
+            payload_validation,
         } = config;
 
         CollectionConfig {
@@ -201,6 +207,7 @@ impl From<CollectionConfigInternal> for CollectionConfig {
             quantization_config,
             strict_mode_config: strict_mode_config.map(StrictModeConfigOutput::from),
             metadata,
+            payload_validation,
         }
     }
 }
@@ -489,6 +496,9 @@ pub struct ScrollRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Embed payload of points from another collection, referenced in the payload of the results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_join: Option<api::rest::WithJoin>,
 }
 
 fn points_example() -> Vec<api::rest::Record> {
@@ -507,6 +517,8 @@ fn points_example() -> Vec<api::rest::Record> {
             vector: Some(VectorStructOutput::Single(vec![0.875, 0.140625, 0.897_6])),
             shard_key: Some("region_1".into()),
             order_value: None,
+            then_by_values: Vec::new(),
+            version: None,
         },
         api::rest::Record {
             id: PointIdType::NumId(41),
@@ -514,6 +526,8 @@ fn points_example() -> Vec<api::rest::Record> {
             vector: Some(VectorStructOutput::Single(vec![0.75, 0.640625, 0.8945])),
             shard_key: Some("region_1".into()),
             order_value: None,
+            then_by_values: Vec::new(),
+            version: None,
         },
     ]
 }
@@ -565,6 +579,9 @@ pub struct PointRequest {
     /// Specify in which shards to look for the points, if not specified - look in all shards
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<ShardKeySelector>,
+    /// Embed payload of points from another collection, referenced in the payload of the results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub with_join: Option<api::rest::WithJoin>,
 }
 
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Clone, PartialEq, Hash)]
@@ -873,6 +890,9 @@ pub struct PointGroup {
     /// Record that has been looked up using the group id
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lookup: Option<api::rest::Record>,
+    /// Groups of the hits of this group by the `subgroups` key, if requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subgroups: Option<Vec<PointGroup>>,
 }
 
 #[derive(Debug, Serialize, JsonSchema)]
@@ -945,6 +965,10 @@ pub enum CollectionError {
     },
     #[error("Shard temporarily unavailable: {description}")]
     ShardUnavailable { description: String },
+    #[error("Version conflict: {}", .conflicts.iter().join(", "))]
+    VersionConflict {
+        conflicts: Vec<PointVersionConflict>,
+    },
 }
 
 impl CollectionError {
@@ -1074,6 +1098,7 @@ impl CollectionError {
             Self::StrictMode { .. } => false,
             Self::InferenceError { .. } => false,
             Self::RateLimitExceeded { .. } => false,
+            Self::VersionConflict { .. } => false,
         }
     }
 
@@ -1150,6 +1175,7 @@ impl From<OperationError> for CollectionError {
             OperationError::MissingMapIndexForFacet { .. } => Self::bad_input(err.to_string()),
             OperationError::VariableTypeError { .. } => Self::bad_input(err.to_string()),
             OperationError::NonFiniteNumber { .. } => Self::bad_input(err.to_string()),
+            OperationError::VersionConflict { conflicts } => Self::VersionConflict { conflicts },
         }
     }
 }
@@ -1220,6 +1246,14 @@ impl From<tonic::Status> for CollectionError {
             },
             tonic::Code::Cancelled => Self::cancelled(err.to_string()),
             tonic::Code::FailedPrecondition => Self::pre_condition_failed(err.to_string()),
+            tonic::Code::Aborted => {
+                match api::grpc::conversions::version_conflicts_from_status(&err) {
+                    Ok(conflicts) => Self::VersionConflict { conflicts },
+                    Err(details_err) => {
+                        Self::service_error(format!("Aborted: {err}, {details_err}"))
+                    }
+                }
+            }
             tonic::Code::ResourceExhausted => {
                 // extract retry-after from metadata
                 // the value is passed as a String containing an integer number of seconds
@@ -1244,7 +1278,6 @@ impl From<tonic::Status> for CollectionError {
             tonic::Code::Ok
             | tonic::Code::Unknown
             | tonic::Code::PermissionDenied
-            | tonic::Code::Aborted
             | tonic::Code::OutOfRange
             | tonic::Code::Unimplemented
             | tonic::Code::Unavailable
@@ -1319,6 +1352,7 @@ pub enum Datatype {
     Float32,
     Uint8,
     Float16,
+    Bit,
 }
 
 impl From<Datatype> for VectorStorageDatatype {
@@ -1327,6 +1361,7 @@ impl From<Datatype> for VectorStorageDatatype {
             Datatype::Float32 => VectorStorageDatatype::Float32,
             Datatype::Uint8 => VectorStorageDatatype::Uint8,
             Datatype::Float16 => VectorStorageDatatype::Float16,
+            Datatype::Bit => VectorStorageDatatype::Bit,
         }
     }
 }
@@ -1337,6 +1372,7 @@ impl From<Datatype> for VectorStorageDatatype {
 )]
 #[serde(rename_all = "snake_case")]
 #[anonymize(false)]
+#[validate(schema(function = "validate_vector_params"))]
 pub struct VectorParams {
     /// Size of a vectors used
     #[validate(custom(function = "validate_nonzerou64_range_min_1_max_65536"))]
@@ -1370,10 +1406,146 @@ pub struct VectorParams {
     ///   2 bytes.
     /// - For `uint8` datatype - vectors are stored as unsigned 8-bit integers, 1 byte.
     ///   It expects vector elements to be in range `[0, 255]`.
+    /// - For `bit` datatype - vectors are stored as bits, 8 dimensions per byte.
+    ///   Elements greater than zero are set, others are unset.
+    ///   Requires vector size to be a multiple of 8.
     pub datatype: Option<Datatype>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
     pub multivector_config: Option<MultiVectorConfig>,
+
+    /// Number of leading dimensions of Matryoshka embeddings to index separately.
+    /// Nearest queries with the `matryoshka` search param are scored on the first dimensions of
+    /// the stored vectors, which allows a cheap prefetch on the same vector before rescoring
+    /// with the full query.
+    /// Must be less than the vector size. Not supported for multivectors and bit vectors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub matryoshka_dim: Option<usize>,
+
+    /// If set, optimized segments use the disk-resident Vamana graph index instead of HNSW.
+    /// Full vectors and graph links are read from disk on search, while only compressed vectors
+    /// are kept in RAM.
+    /// Not supported for multivectors and bit vectors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub vamana_config: Option<VamanaConfig>,
+
+    /// If set, optimized segments use the inverted file (IVF) index instead of HNSW.
+    /// It is much faster to build, which suits collections with heavy ingestion, but is slower
+    /// to search.
+    /// Not supported for multivectors and bit vectors, and can't be combined with `vamana_config`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub ivf_config: Option<IvfConfig>,
+}
+
+impl VectorParams {
+    /// Whether vectors compared with Hamming or Jaccard distance
+    pub fn is_binary_distance(&self) -> bool {
+        match self.distance {
+            Distance::Hamming | Distance::Jaccard => true,
+            Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan => false,
+        }
+    }
+
+    /// Bit vectors and binary distances are scored on packed bits, which can't be quantized
+    pub fn is_quantization_supported(&self) -> bool {
+        !self.is_binary_distance() && self.datatype != Some(Datatype::Bit)
+    }
+}
+
+pub(crate) fn validate_vector_params(params: &VectorParams) -> Result<(), ValidationError> {
+    let is_binary_distance = params.is_binary_distance();
+    if is_binary_distance && params.quantization_config.is_some() {
+        return Err(ValidationError::new(
+            "quantization is not supported for Hamming and Jaccard distances",
+        ));
+    }
+
+    if params
+        .multivector_config
+        .is_some_and(|config| config.fde.is_some())
+    {
+        match params.distance {
+            Distance::Dot | Distance::Cosine => {}
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming | Distance::Jaccard => {
+                return Err(ValidationError::new(
+                    "fixed-dimensional encoding of multivectors requires Dot or Cosine distance",
+                ));
+            }
+        }
+    }
+
+    if let Some(matryoshka_dim) = params.matryoshka_dim {
+        if matryoshka_dim as u64 >= params.size.get() {
+            return Err(ValidationError::new(
+                "matryoshka_dim must be less than the vector size",
+            ));
+        }
+        if params.multivector_config.is_some() {
+            return Err(ValidationError::new(
+                "matryoshka_dim is not supported for multivectors",
+            ));
+        }
+        if is_binary_distance || params.datatype == Some(Datatype::Bit) {
+            return Err(ValidationError::new(
+                "matryoshka_dim is not supported for bit vectors",
+            ));
+        }
+    }
+
+    if params.vamana_config.is_some() {
+        if params.multivector_config.is_some() {
+            return Err(ValidationError::new(
+                "vamana index is not supported for multivectors",
+            ));
+        }
+        if is_binary_distance || params.datatype == Some(Datatype::Bit) {
+            return Err(ValidationError::new(
+                "vamana index is not supported for bit vectors",
+            ));
+        }
+    }
+
+    if params.ivf_config.is_some() {
+        if params.vamana_config.is_some() {
+            return Err(ValidationError::new(
+                "ivf index can't be used together with vamana index",
+            ));
+        }
+        if params.multivector_config.is_some() {
+            return Err(ValidationError::new(
+                "ivf index is not supported for multivectors",
+            ));
+        }
+        if is_binary_distance || params.datatype == Some(Datatype::Bit) {
+            return Err(ValidationError::new(
+                "ivf index is not supported for bit vectors",
+            ));
+        }
+    }
+
+    if params.datatype == Some(Datatype::Bit) {
+        if params.size.get() % PackedBits::DIMS as u64 != 0 {
+            return Err(ValidationError::new(
+                "size of bit vectors must be a multiple of 8",
+            ));
+        }
+        if params.multivector_config.is_some() {
+            return Err(ValidationError::new(
+                "multivectors are not supported for bit datatype",
+            ));
+        }
+        if params.quantization_config.is_some() {
+            return Err(ValidationError::new(
+                "quantization is not supported for bit datatype",
+            ));
+        }
+    }
+
+    Ok(())
 }
 
 /// Validate the value is in `[1, 65536]` or `None`.
@@ -1550,6 +1722,20 @@ impl VectorsConfig {
         }
     }
 
+    /// Check that collection-level quantization can be applied to every vector
+    pub fn check_quantization_supported(&self) -> CollectionResult<()> {
+        match self
+            .params_iter()
+            .find(|(_, params)| !params.is_quantization_supported())
+        {
+            Some((vector_name, _)) => Err(CollectionError::bad_input(format!(
+                "Quantization is not supported for vector `{vector_name}`: \
+                 bit vectors and vectors with Hamming or Jaccard distance can't be quantized"
+            ))),
+            None => Ok(()),
+        }
+    }
+
     pub fn check_compatible_with_segment_config(
         &self,
         other: &HashMap<VectorNameBuf, segment::types::VectorDataConfig>,
@@ -1650,6 +1836,9 @@ impl From<&VectorParams> for VectorParamsBase {
             on_disk: _,
             datatype: _,
             multivector_config: _,
+            matryoshka_dim: _,
+            vamana_config: _,
+            ivf_config: _,
         } = params;
         Self {
             size: size.get() as _, // TODO!?
@@ -1668,6 +1857,7 @@ impl From<&segment::types::VectorDataConfig> for VectorParamsBase {
             quantization_config: _,
             multivector_config: _,
             datatype: _,
+            matryoshka_dim: _,
         } = config;
         Self { size, distance }
     }
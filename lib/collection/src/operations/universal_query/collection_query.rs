@@ -17,11 +17,17 @@ use segment::vector_storage::query::{
 use serde::Serialize;
 use shard::query::query_enum::QueryEnum;
 
+use super::cursor::{CursorPosition, QueryCursor};
+use super::feedback::{
+    DEFAULT_REWEIGHT_REGULARIZATION, DEFAULT_ROCCHIO_ALPHA, DEFAULT_ROCCHIO_BETA,
+    DEFAULT_ROCCHIO_GAMMA, reweight_vector, rocchio_vector,
+};
 use super::formula::FormulaInternal;
 use super::shard_query::{
     FusionInternal, SampleInternal, ScoringQuery, ShardPrefetch, ShardQueryRequest,
 };
 use crate::common::fetch_vectors::ReferencedVectors;
+use crate::grouping::group_by::SubgroupRequest;
 use crate::lookup::WithLookup;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::universal_query::shard_query::MmrInternal;
@@ -44,6 +50,8 @@ pub struct CollectionQueryRequest {
     pub with_vector: WithVector,
     pub with_payload: WithPayloadInterface,
     pub lookup_from: Option<LookupLocation>,
+    /// Continue the results of a previous page, instead of skipping `offset` points
+    pub cursor: Option<QueryCursor>,
 }
 
 impl CollectionQueryRequest {
@@ -80,10 +88,11 @@ pub struct CollectionQueryGroupsRequest {
     pub with_vector: WithVector,
     pub with_payload: WithPayloadInterface,
     pub lookup_from: Option<LookupLocation>,
-    pub group_by: JsonPath,
+    pub group_by: Vec<JsonPath>,
     pub group_size: usize,
     pub limit: usize,
     pub with_lookup: Option<WithLookup>,
+    pub subgroups: Option<SubgroupRequest>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -216,7 +225,21 @@ impl<T> FeedbackInternal<T> {
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum FeedbackStrategy {
-    Naive { a: f32, b: f32, c: f32 },
+    Naive {
+        a: f32,
+        b: f32,
+        c: f32,
+    },
+    /// Rocchio update of the target, with the examples scored above and below the mean
+    Rocchio {
+        alpha: Option<f32>,
+        beta: Option<f32>,
+        gamma: Option<f32>,
+    },
+    /// Reweighting of the target dimensions, fitted to the feedback scores
+    Reweight {
+        regularization: Option<f32>,
+    },
 }
 
 impl VectorQuery<VectorInputInternal> {
@@ -502,6 +525,24 @@ impl VectorQuery<VectorInternal> {
                     },
                     using,
                 )),
+                FeedbackStrategy::Rocchio { alpha, beta, gamma } => {
+                    let vector = rocchio_vector(
+                        target,
+                        &feedback,
+                        alpha.unwrap_or(DEFAULT_ROCCHIO_ALPHA),
+                        beta.unwrap_or(DEFAULT_ROCCHIO_BETA),
+                        gamma.unwrap_or(DEFAULT_ROCCHIO_GAMMA),
+                    )?;
+                    QueryEnum::Nearest(NamedQuery::new(vector, using))
+                }
+                FeedbackStrategy::Reweight { regularization } => {
+                    let vector = reweight_vector(
+                        target,
+                        &feedback,
+                        regularization.unwrap_or(DEFAULT_REWEIGHT_REGULARIZATION),
+                    )?;
+                    QueryEnum::Nearest(NamedQuery::new(vector, using))
+                }
             },
         };
 
@@ -678,7 +719,7 @@ impl CollectionQueryRequest {
 
     /// Substitutes all the point ids in the request with the actual vectors, as well as editing filters so that ids are not included in the response.
     pub fn try_into_shard_request(
-        self,
+        mut self,
         collection_name: &str,
         ids_to_vectors: &ReferencedVectors,
     ) -> CollectionResult<ShardQueryRequest> {
@@ -688,6 +729,7 @@ impl CollectionQueryRequest {
             &self.prefetch,
             self.score_threshold,
         )?;
+        self.validate_cursor()?;
 
         let mut offset = self.offset;
         if matches!(self.query, Some(Query::Sample(SampleInternal::Random)))
@@ -697,6 +739,19 @@ impl CollectionQueryRequest {
             offset = 0;
         }
 
+        let mut start_after = None;
+        match self.cursor.take().map(|cursor| cursor.position) {
+            // Payload index is read right from the position
+            Some(CursorPosition::OrderBy(position)) => {
+                if let Some(Query::OrderBy(order_by)) = &mut self.query {
+                    order_by.start_after = Some(position);
+                }
+            }
+            // Searches skip points scored better than the position, shards skip the ties
+            Some(CursorPosition::Score(position)) => start_after = Some(position),
+            None => {}
+        }
+
         let query_lookup_collection = self.get_lookup_collection().cloned();
         let query_lookup_vector_name = self.get_lookup_vector_name();
         let using = self.using.clone();
@@ -733,6 +788,7 @@ impl CollectionQueryRequest {
             score_threshold: self.score_threshold.map(OrderedFloat),
             limit: self.limit,
             offset,
+            start_after,
             params: self.params,
             with_vector: self.with_vector,
             with_payload: self.with_payload,
@@ -0,0 +1,249 @@
+//! Continuation tokens of the universal query.
+//!
+//! A cursor remembers the position of the last point of a page, so the next page can continue
+//! right after it, even if points have been moved between shards or inserted in the meantime.
+
+use ordered_float::OrderedFloat;
+use segment::data_types::order_by::OrderByCursor;
+use segment::types::ScoredPoint;
+use serde::{Deserialize, Serialize};
+
+use super::collection_query::{CollectionQueryRequest, Query, VectorQuery};
+use super::shard_query::ScorePosition;
+use crate::operations::types::{CollectionError, CollectionResult};
+
+/// Position in the results of a query to continue from.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QueryCursor {
+    pub position: CursorPosition,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CursorPosition {
+    /// Last point of results ordered by score
+    Score(ScorePosition),
+    /// Last point of results ordered by payload values
+    OrderBy(OrderByCursor),
+}
+
+impl QueryCursor {
+    /// Encode the cursor as an opaque string token
+    pub fn encode(&self) -> String {
+        // Serialization of plain data into a vector can't fail
+        let bytes = serde_json::to_vec(self).unwrap_or_default();
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Decode the cursor from a token returned by [`QueryCursor::encode`]
+    pub fn decode(token: &str) -> CollectionResult<Self> {
+        let invalid = || CollectionError::bad_input(format!("Invalid query cursor: {token:?}"));
+
+        if !token.is_ascii() || token.len() % 2 != 0 {
+            return Err(invalid());
+        }
+        let bytes = (0..token.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&token[idx..idx + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid())?;
+
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    }
+}
+
+/// Kind of position the results of a query can be continued from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CursorKind {
+    Score,
+    OrderBy,
+}
+
+/// Builds the cursor of the next page from the results of a request.
+///
+/// Taken from the request before it is executed, as the request is consumed by the execution.
+#[derive(Clone, Copy, Debug)]
+pub struct QueryPagination {
+    kind: CursorKind,
+    limit: usize,
+}
+
+impl QueryPagination {
+    /// Cursor to continue after the given page, `None` if there are no more results
+    pub fn next_cursor(&self, points: &[ScoredPoint]) -> Option<QueryCursor> {
+        // A short page means the results are exhausted: shards only return fewer points after
+        // the position than requested, if their own results ended before the position filter
+        if points.len() < self.limit {
+            return None;
+        }
+        let last = points.last()?;
+
+        let position = match self.kind {
+            CursorKind::Score => CursorPosition::Score(ScorePosition {
+                score: OrderedFloat(last.score),
+                id: last.id,
+            }),
+            CursorKind::OrderBy => CursorPosition::OrderBy(OrderByCursor {
+                value: last.order_value.clone()?,
+                then_by: last.then_by_values.clone(),
+                id: last.id,
+            }),
+        };
+
+        Some(QueryCursor { position })
+    }
+}
+
+impl CollectionQueryRequest {
+    /// Pagination of the results of this request, `None` if the results are not ordered
+    /// and can't be continued with a cursor.
+    pub fn pagination(&self) -> Option<QueryPagination> {
+        let kind = cursor_kind(self.query.as_ref())?;
+        Some(QueryPagination {
+            kind,
+            limit: self.limit,
+        })
+    }
+
+    /// Check that the cursor of the request can continue its results.
+    pub(super) fn validate_cursor(&self) -> CollectionResult<()> {
+        let Some(cursor) = &self.cursor else {
+            return Ok(());
+        };
+
+        if self.offset != 0 {
+            return Err(CollectionError::bad_request(
+                "Can't use offset together with a cursor.",
+            ));
+        }
+
+        let expected = match &cursor.position {
+            CursorPosition::Score(_) => CursorKind::Score,
+            CursorPosition::OrderBy(_) => CursorKind::OrderBy,
+        };
+        if cursor_kind(self.query.as_ref()) != Some(expected) {
+            return Err(CollectionError::bad_request(
+                "Cursor doesn't belong to this query. Use the cursor with the query it was returned for.",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Kind of the cursor to continue results of the query, `None` if results are not ordered.
+fn cursor_kind(query: Option<&Query>) -> Option<CursorKind> {
+    match query {
+        Some(Query::Vector(vector_query)) => match vector_query {
+            VectorQuery::Nearest(_)
+            | VectorQuery::RecommendAverageVector(_)
+            | VectorQuery::RecommendBestScore(_)
+            | VectorQuery::RecommendSumScores(_)
+            | VectorQuery::Discover(_)
+            | VectorQuery::Context(_)
+            | VectorQuery::Feedback(_) => Some(CursorKind::Score),
+            // MMR selects points from the whole candidate list, so it can't be continued
+            VectorQuery::NearestWithMmr(_) => None,
+        },
+        Some(Query::Fusion(_) | Query::Formula(_)) => Some(CursorKind::Score),
+        Some(Query::OrderBy(_)) => Some(CursorKind::OrderBy),
+        // Random sample is not ordered
+        Some(Query::Sample(_)) => None,
+        // Points are ordered by ID, with equal scores
+        None => Some(CursorKind::Score),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use segment::data_types::order_by::OrderValue;
+    use segment::types::Order;
+
+    use super::*;
+
+    fn point(id: u64, score: f32) -> ScoredPoint {
+        ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
+        }
+    }
+
+    #[test]
+    fn test_cursor_token_roundtrip() {
+        let cursors = [
+            QueryCursor {
+                position: CursorPosition::Score(ScorePosition {
+                    score: OrderedFloat(0.25),
+                    id: 42.into(),
+                }),
+            },
+            QueryCursor {
+                position: CursorPosition::OrderBy(OrderByCursor {
+                    value: OrderValue::Keyword("b".to_string()),
+                    then_by: vec![None, Some(OrderValue::Float(1.5))],
+                    id: "a3e6e8c4-6f1e-4c2b-9b8e-2d1d5c3f0a7b".parse().unwrap(),
+                }),
+            },
+        ];
+
+        for cursor in cursors {
+            let token = cursor.encode();
+            assert_eq!(QueryCursor::decode(&token).unwrap(), cursor);
+        }
+
+        assert!(QueryCursor::decode("not a cursor").is_err());
+        assert!(QueryCursor::decode("abc").is_err());
+        assert!(QueryCursor::decode("").is_err());
+    }
+
+    #[test]
+    fn test_score_position_breaks_ties_by_id() {
+        let position = ScorePosition {
+            score: OrderedFloat(0.5),
+            id: 5.into(),
+        };
+
+        // Scores descending, ties by IDs descending
+        assert!(position.is_before(&point(1, 0.4), Order::LargeBetter));
+        assert!(position.is_before(&point(4, 0.5), Order::LargeBetter));
+        assert!(!position.is_before(&point(5, 0.5), Order::LargeBetter));
+        assert!(!position.is_before(&point(6, 0.5), Order::LargeBetter));
+        assert!(!position.is_before(&point(1, 0.6), Order::LargeBetter));
+
+        // Distances ascending, ties by IDs ascending
+        assert!(position.is_before(&point(1, 0.6), Order::SmallBetter));
+        assert!(position.is_before(&point(6, 0.5), Order::SmallBetter));
+        assert!(!position.is_before(&point(5, 0.5), Order::SmallBetter));
+        assert!(!position.is_before(&point(4, 0.5), Order::SmallBetter));
+    }
+
+    #[test]
+    fn test_next_cursor() {
+        let pagination = QueryPagination {
+            kind: CursorKind::Score,
+            limit: 2,
+        };
+
+        let cursor = pagination
+            .next_cursor(&[point(1, 0.9), point(2, 0.8)])
+            .unwrap();
+        assert_eq!(
+            cursor,
+            QueryCursor {
+                position: CursorPosition::Score(ScorePosition {
+                    score: OrderedFloat(0.8),
+                    id: 2.into(),
+                }),
+            },
+        );
+
+        // No more results after a short page
+        assert!(pagination.next_cursor(&[point(1, 0.9)]).is_none());
+    }
+}
@@ -0,0 +1,382 @@
+//! Relevance feedback strategies, which update the target vector before the search.
+//!
+//! Unlike the naive strategy, which is scored at the segments, these strategies compute
+//! a single new query vector from the target and the feedback, and search nearest to it.
+
+use segment::data_types::vectors::{DenseVector, VectorElementType, VectorInternal};
+use segment::vector_storage::query::FeedbackItem;
+
+use crate::operations::types::{CollectionError, CollectionResult};
+
+pub const DEFAULT_ROCCHIO_ALPHA: f32 = 1.0;
+pub const DEFAULT_ROCCHIO_BETA: f32 = 0.75;
+pub const DEFAULT_ROCCHIO_GAMMA: f32 = 0.15;
+
+pub const DEFAULT_REWEIGHT_REGULARIZATION: f32 = 1.0;
+
+/// Lower bound of the regularization, so the regression system is always solvable
+const MIN_REWEIGHT_REGULARIZATION: f64 = 1e-6;
+
+/// Rocchio update: `alpha * target + beta * mean(relevant) - gamma * mean(non-relevant)`.
+///
+/// Examples scored above the mean feedback score are relevant, the ones scored below it are non-relevant.
+pub fn rocchio_vector(
+    target: VectorInternal,
+    feedback: &[FeedbackItem<VectorInternal>],
+    alpha: f32,
+    beta: f32,
+    gamma: f32,
+) -> CollectionResult<VectorInternal> {
+    let (mut target, examples) = dense_feedback(target, feedback, "rocchio")?;
+
+    let mean_score = mean(examples.iter().map(|(_, score)| *score));
+
+    let mut relevant = Centroid::new(target.len());
+    let mut non_relevant = Centroid::new(target.len());
+    for (example, score) in examples {
+        if score > mean_score {
+            relevant.add(example);
+        } else if score < mean_score {
+            non_relevant.add(example);
+        }
+    }
+
+    let relevant = relevant.finish();
+    let non_relevant = non_relevant.finish();
+
+    for (i, value) in target.iter_mut().enumerate() {
+        let mut updated = f64::from(alpha) * f64::from(*value);
+        if let Some(relevant) = &relevant {
+            updated += f64::from(beta) * relevant[i];
+        }
+        if let Some(non_relevant) = &non_relevant {
+            updated -= f64::from(gamma) * non_relevant[i];
+        }
+        *value = updated as VectorElementType;
+    }
+
+    Ok(VectorInternal::from(target))
+}
+
+/// Reweight the dimensions of the target: `target * (1 + v)`.
+///
+/// With `z_i = target * example_i`, the similarity of the reweighted target to an example is
+/// `sum(z_i) + z_i · v`. The correction `v` is fitted with ridge regression, so these similarities
+/// follow the standardized feedback scores, rescaled to the spread of the original similarities.
+///
+/// Returns the target unchanged, if the feedback doesn't tell the examples apart.
+pub fn reweight_vector(
+    target: VectorInternal,
+    feedback: &[FeedbackItem<VectorInternal>],
+    regularization: f32,
+) -> CollectionResult<VectorInternal> {
+    let (mut target, examples) = dense_feedback(target, feedback, "reweight")?;
+
+    let z: Vec<Vec<f64>> = examples
+        .iter()
+        .map(|(example, _)| {
+            target
+                .iter()
+                .zip(example.iter())
+                .map(|(q, x)| f64::from(*q) * f64::from(*x))
+                .collect()
+        })
+        .collect();
+
+    let similarities: Vec<f64> = z.iter().map(|z_i| z_i.iter().sum()).collect();
+    let scores: Vec<f64> = examples.iter().map(|(_, score)| *score).collect();
+
+    let (mean_similarity, std_similarity) = mean_and_std(&similarities);
+    let (mean_score, std_score) = mean_and_std(&scores);
+
+    if std_score == 0.0 || std_similarity == 0.0 {
+        return Ok(VectorInternal::from(target));
+    }
+
+    // Difference between the desired and the current similarities
+    let residuals: Vec<f64> = scores
+        .iter()
+        .zip(&similarities)
+        .map(|(score, similarity)| {
+            std_similarity * (score - mean_score) / std_score - (similarity - mean_similarity)
+        })
+        .collect();
+
+    let n = z.len();
+    let mut gram: Vec<Vec<f64>> = z
+        .iter()
+        .map(|z_i| z.iter().map(|z_j| dot(z_i, z_j)).collect())
+        .collect();
+
+    let trace: f64 = (0..n).map(|i| gram[i][i]).sum();
+    if trace == 0.0 {
+        return Ok(VectorInternal::from(target));
+    }
+
+    // Scale-free regularization, relative to the mean squared norm of the rows
+    let ridge = f64::from(regularization).max(MIN_REWEIGHT_REGULARIZATION) * trace / n as f64;
+    for (i, row) in gram.iter_mut().enumerate() {
+        row[i] += ridge;
+    }
+
+    let coefficients = solve(gram, residuals);
+
+    for (j, value) in target.iter_mut().enumerate() {
+        let correction: f64 = z
+            .iter()
+            .zip(&coefficients)
+            .map(|(z_i, coefficient)| z_i[j] * coefficient)
+            .sum();
+        *value = (f64::from(*value) * (1.0 + correction)) as VectorElementType;
+    }
+
+    Ok(VectorInternal::from(target))
+}
+
+/// Dense example with its feedback score
+type DenseExample<'a> = (&'a [VectorElementType], f64);
+
+/// Unwrap dense target and examples, checking that their dimensions match
+fn dense_feedback<'a>(
+    target: VectorInternal,
+    feedback: &'a [FeedbackItem<VectorInternal>],
+    strategy: &str,
+) -> CollectionResult<(DenseVector, Vec<DenseExample<'a>>)> {
+    let not_dense = || {
+        CollectionError::bad_input(format!(
+            "Feedback strategy `{strategy}` only supports dense vectors"
+        ))
+    };
+
+    let target = match target {
+        VectorInternal::Dense(target) => target,
+        VectorInternal::Sparse(_) | VectorInternal::MultiDense(_) => return Err(not_dense()),
+    };
+
+    let examples = feedback
+        .iter()
+        .map(|FeedbackItem { vector, score }| {
+            let example = match vector {
+                VectorInternal::Dense(example) => example,
+                VectorInternal::Sparse(_) | VectorInternal::MultiDense(_) => {
+                    return Err(not_dense());
+                }
+            };
+            if example.len() != target.len() {
+                return Err(CollectionError::bad_input(format!(
+                    "Feedback example dimension {} doesn't match target dimension {}",
+                    example.len(),
+                    target.len(),
+                )));
+            }
+            Ok((example.as_slice(), f64::from(score.into_inner())))
+        })
+        .collect::<CollectionResult<Vec<_>>>()?;
+
+    Ok((target, examples))
+}
+
+/// Running mean of dense vectors
+struct Centroid {
+    sum: Vec<f64>,
+    count: usize,
+}
+
+impl Centroid {
+    fn new(dim: usize) -> Self {
+        Self {
+            sum: vec![0.0; dim],
+            count: 0,
+        }
+    }
+
+    fn add(&mut self, vector: &[VectorElementType]) {
+        for (sum, value) in self.sum.iter_mut().zip(vector) {
+            *sum += f64::from(*value);
+        }
+        self.count += 1;
+    }
+
+    fn finish(self) -> Option<Vec<f64>> {
+        let Self { mut sum, count } = self;
+        if count == 0 {
+            return None;
+        }
+        for value in &mut sum {
+            *value /= count as f64;
+        }
+        Some(sum)
+    }
+}
+
+fn mean(values: impl ExactSizeIterator<Item = f64>) -> f64 {
+    let len = values.len();
+    if len == 0 {
+        return 0.0;
+    }
+    values.sum::<f64>() / len as f64
+}
+
+fn mean_and_std(values: &[f64]) -> (f64, f64) {
+    let avg = mean(values.iter().copied());
+    let variance = mean(values.iter().map(|value| (value - avg).powi(2)));
+    (avg, variance.sqrt())
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Solve the linear system `matrix * x = rhs` by Gaussian elimination with partial pivoting
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Vec<f64> {
+    let n = rhs.len();
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))
+            .unwrap_or(col);
+        matrix.swap(col, pivot);
+        rhs.swap(col, pivot);
+
+        let (pivot_rows, rows) = matrix.split_at_mut(col + 1);
+        let (pivot_rhs, rows_rhs) = rhs.split_at_mut(col + 1);
+        let pivot_row = &pivot_rows[col];
+        let pivot_rhs = pivot_rhs[col];
+        if pivot_row[col] == 0.0 {
+            continue;
+        }
+
+        for (row, row_rhs) in rows.iter_mut().zip(rows_rhs) {
+            let factor = row[col] / pivot_row[col];
+            for (value, pivot_value) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * pivot_value;
+            }
+            *row_rhs -= factor * pivot_rhs;
+        }
+    }
+
+    let mut solution = vec![0.0; n];
+    for (row, (coefficients, row_rhs)) in matrix.iter().zip(&rhs).enumerate().rev() {
+        let diagonal = coefficients[row];
+        if diagonal == 0.0 {
+            continue;
+        }
+        let known = dot(&coefficients[row + 1..], &solution[row + 1..]);
+        solution[row] = (row_rhs - known) / diagonal;
+    }
+
+    solution
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::OrderedFloat;
+    use sparse::common::sparse_vector::SparseVector;
+
+    use super::*;
+
+    fn item(vector: Vec<f32>, score: f32) -> FeedbackItem<VectorInternal> {
+        FeedbackItem {
+            vector: VectorInternal::from(vector),
+            score: OrderedFloat(score),
+        }
+    }
+
+    fn dense(vector: VectorInternal) -> DenseVector {
+        match vector {
+            VectorInternal::Dense(vector) => vector,
+            VectorInternal::Sparse(_) | VectorInternal::MultiDense(_) => panic!("not dense"),
+        }
+    }
+
+    fn similarity(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(a, b)| a * b).sum()
+    }
+
+    #[test]
+    fn test_rocchio_vector() {
+        let target = VectorInternal::from(vec![1.0, 0.0]);
+        let feedback = vec![
+            item(vec![0.0, 1.0], 1.0),
+            item(vec![0.0, 3.0], 1.0),
+            item(vec![2.0, 0.0], -0.5),
+            // Exactly at the mean, neither relevant nor non-relevant
+            item(vec![100.0, 100.0], 0.5),
+        ];
+
+        let updated = dense(rocchio_vector(target, &feedback, 1.0, 0.5, 0.25).unwrap());
+
+        // 1.0 * [1, 0] + 0.5 * [0, 2] - 0.25 * [2, 0]
+        assert_eq!(updated, vec![0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_rocchio_rejects_non_dense() {
+        let target = VectorInternal::from(SparseVector::new(vec![0], vec![1.0]).unwrap());
+        let feedback = vec![item(vec![1.0], 1.0)];
+
+        assert!(rocchio_vector(target, &feedback, 1.0, 0.75, 0.15).is_err());
+
+        let target = VectorInternal::from(vec![1.0, 0.0]);
+        let feedback = vec![item(vec![1.0], 1.0)];
+        assert!(rocchio_vector(target, &feedback, 1.0, 0.75, 0.15).is_err());
+    }
+
+    #[test]
+    fn test_reweight_follows_feedback() {
+        let target = vec![1.0, 1.0, 1.0];
+        let examples = [
+            vec![1.0, 0.0, 0.2],
+            vec![0.0, 1.0, 0.2],
+            vec![0.5, 0.5, 0.0],
+        ];
+        // Same similarity to the target, but the first dimension is preferred
+        let feedback = vec![
+            item(examples[0].clone(), 1.0),
+            item(examples[1].clone(), 0.0),
+            item(examples[2].clone(), 0.5),
+        ];
+
+        let updated =
+            dense(reweight_vector(VectorInternal::from(target.clone()), &feedback, 0.01).unwrap());
+
+        let before = examples
+            .iter()
+            .map(|example| similarity(&target, example))
+            .collect::<Vec<_>>();
+        let after = examples
+            .iter()
+            .map(|example| similarity(&updated, example))
+            .collect::<Vec<_>>();
+
+        assert_eq!(before[0], before[1]);
+        assert!(after[0] > after[2]);
+        assert!(after[2] > after[1]);
+        assert!(updated[0] > updated[1]);
+    }
+
+    #[test]
+    fn test_solve() {
+        let matrix = vec![
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, 1.0, 0.0],
+            vec![2.0, 0.0, 3.0],
+        ];
+        let solution = solve(matrix, vec![7.0, 3.0, 11.0]);
+
+        for (value, expected) in solution.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_reweight_keeps_target_without_signal() {
+        let target = vec![1.0, 2.0];
+        let feedback = vec![item(vec![1.0, 0.0], 0.5), item(vec![0.0, 1.0], 0.5)];
+
+        let updated =
+            dense(reweight_vector(VectorInternal::from(target.clone()), &feedback, 1.0).unwrap());
+
+        assert_eq!(updated, target);
+    }
+}
@@ -21,6 +21,8 @@
 //! [`QueryShardPoints`]: api::grpc::qdrant::QueryShardPoints
 
 pub mod collection_query;
+pub mod cursor;
+pub mod feedback;
 pub mod shard_query;
 
 pub mod planned_query {
@@ -33,6 +33,10 @@ pub fn query_result_order(
             ScoringQuery::OrderBy(order_by) => Some(Order::from(order_by.direction())),
             // Random sample does not require ordering
             ScoringQuery::Sample(SampleInternal::Random) => None,
+            // Sampling keys of several shards are comparable
+            ScoringQuery::Sample(SampleInternal::Stratified(_) | SampleInternal::Weighted(_)) => {
+                Some(Order::LargeBetter)
+            }
             // MMR cannot be reordered
             ScoringQuery::Mmr(_) => None,
         },
@@ -19,6 +19,9 @@ impl VectorParamsBuilder {
                 on_disk: None,
                 datatype: None,
                 multivector_config: None,
+                matryoshka_dim: None,
+                vamana_config: None,
+                ivf_config: None,
             },
         }
     }
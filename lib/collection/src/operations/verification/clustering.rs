@@ -0,0 +1,26 @@
+use segment::types::{Filter, SearchParams};
+
+use super::StrictModeVerification;
+use crate::collection::clustering::CollectionClusteringRequest;
+
+impl StrictModeVerification for CollectionClusteringRequest {
+    fn query_limit(&self) -> Option<usize> {
+        self.sample_size
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        None
+    }
+}
@@ -1,8 +1,10 @@
+mod clustering;
 mod count;
 mod discover;
 mod facet;
 mod local_shard;
 mod matrix;
+mod near_duplicates;
 mod query;
 mod recommend;
 mod search;
@@ -17,6 +19,7 @@ pub use shard::operation_rate_cost;
 
 use super::types::{CollectionError, CollectionResult};
 use crate::collection::Collection;
+use crate::grouping::group_by::SubgroupRequest;
 
 // Creates a new `VerificationPass` without actually verifying anything.
 // This is useful in situations where we don't need to check for strict mode, but still
@@ -345,6 +348,14 @@ impl StrictModeVerification for SearchParams {
             strict_mode_config.search_max_hnsw_ef,
             "hnsw_ef",
         )?;
+
+        // Radius search may visit a large part of the collection
+        if strict_mode_config.search_allow_radius == Some(false) && self.radius.is_some() {
+            return Err(CollectionError::strict_mode(
+                "Radius search disabled!",
+                "Remove radius from search params.",
+            ));
+        }
         Ok(())
     }
 
@@ -369,27 +380,41 @@ impl StrictModeVerification for SearchParams {
     }
 }
 
+/// Number of points a group request may return, including the subgroups found for each group
+pub fn group_query_limit(
+    limit: usize,
+    group_size: usize,
+    subgroups: Option<&SubgroupRequest>,
+) -> usize {
+    let subgroups_size = subgroups.map_or(0, |subgroups| {
+        subgroups.limit.saturating_mul(subgroups.group_size)
+    });
+    limit.saturating_mul(group_size.saturating_add(subgroups_size))
+}
+
 pub fn check_grouping_field(
-    group_by: &JsonPath,
+    group_by: &[JsonPath],
     collection: &Collection,
     strict_mode_config: &StrictModeConfig,
 ) -> CollectionResult<()> {
     // check for unindexed fields targeted by group_by
     if strict_mode_config.unindexed_filtering_retrieve == Some(false) {
-        // check the group_by field is indexed and support `match` statement
-        if let Some(schema) = collection.payload_key_index_schema(group_by) {
-            if !schema.supports_match() {
-                let schema_kind = schema.kind();
+        for group_by in group_by {
+            // check the group_by field is indexed and support `match` statement
+            if let Some(schema) = collection.payload_key_index_schema(group_by) {
+                if !schema.supports_match() {
+                    let schema_kind = schema.kind();
+                    return Err(CollectionError::strict_mode(
+                        format!("Index of type \"{schema_kind:?}\" found for \"{group_by}\""),
+                        "Create an index supporting `match` for this key.",
+                    ));
+                }
+            } else {
                 return Err(CollectionError::strict_mode(
-                    format!("Index of type \"{schema_kind:?}\" found for \"{group_by}\""),
+                    format!("Index required but not found for \"{group_by}\""),
                     "Create an index supporting `match` for this key.",
                 ));
             }
-        } else {
-            return Err(CollectionError::strict_mode(
-                format!("Index required but not found for \"{group_by}\""),
-                "Create an index supporting `match` for this key.",
-            ));
         }
     }
     Ok(())
@@ -402,6 +427,7 @@ mod test {
     use api::rest::{PointInsertOperations, PointStruct, PointsList, SearchRequestInternal};
     use common::budget::ResourceBudget;
     use common::counter::hardware_accumulator::HwMeasurementAcc;
+    use ordered_float::OrderedFloat;
     use segment::types::{
         Condition, FieldCondition, Filter, Match, PayloadFieldSchema, PayloadSchemaType,
         SearchParams, StrictModeConfig, ValueVariants,
@@ -465,6 +491,16 @@ mod test {
             collection,
         )
         .await;
+
+        let radius_params = SearchParams {
+            radius: Some(OrderedFloat(0.5)),
+            ..search_params_fixture(false)
+        };
+        assert_strict_mode_error(
+            discover_fixture(None, None, Some(radius_params)),
+            collection,
+        )
+        .await;
     }
 
     async fn test_filter_write(collection: &Collection) {
@@ -594,6 +630,7 @@ mod test {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            if_version: None,
         });
         assert!(insert.consumes_memory());
 
@@ -603,6 +640,7 @@ mod test {
             filter: None,
             key: None,
             shard_key: None,
+            if_version: None,
         };
         assert!(set_payload.consumes_memory());
 
@@ -643,6 +681,7 @@ mod test {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            if_version: None,
         });
         assert_strict_mode_error(request, collection).await;
 
@@ -658,6 +697,7 @@ mod test {
             shard_key: None,
             update_filter: None,
             update_mode: None,
+            if_version: None,
         });
         assert_strict_mode_success(request, collection).await;
     }
@@ -733,6 +773,7 @@ mod test {
             unindexed_filtering_retrieve: Some(false),
             search_max_hnsw_ef: Some(3),
             search_allow_exact: Some(false),
+            search_allow_radius: Some(false),
             search_max_oversampling: Some(0.2),
             search_max_batchsize: Some(4),
             upsert_max_batchsize: Some(4),
@@ -755,6 +796,7 @@ mod test {
             strict_mode_config: Some(strict_mode_config.clone()),
             uuid: None,
             metadata: None,
+            payload_validation: None,
         };
 
         let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
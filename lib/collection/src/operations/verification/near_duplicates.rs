@@ -0,0 +1,26 @@
+use segment::types::{Filter, SearchParams};
+
+use super::StrictModeVerification;
+use crate::collection::near_duplicates::CollectionNearDuplicatesRequest;
+
+impl StrictModeVerification for CollectionNearDuplicatesRequest {
+    fn query_limit(&self) -> Option<usize> {
+        Some(self.limit_per_point)
+    }
+
+    fn indexed_filter_read(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    fn indexed_filter_write(&self) -> Option<&Filter> {
+        None
+    }
+
+    fn request_exact(&self) -> Option<bool> {
+        None
+    }
+
+    fn request_search_params(&self) -> Option<&SearchParams> {
+        self.params.as_ref()
+    }
+}
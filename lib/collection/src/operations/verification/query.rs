@@ -1,8 +1,9 @@
 use itertools::Itertools;
 use segment::data_types::vectors::DEFAULT_VECTOR_NAME;
 use segment::types::{Filter, StrictModeConfig};
+use shard::query::SampleInternal;
 
-use super::{StrictModeVerification, check_grouping_field};
+use super::{StrictModeVerification, check_grouping_field, group_query_limit};
 use crate::collection::Collection;
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::operations::universal_query::collection_query::{
@@ -34,6 +35,22 @@ impl Query {
                 "Create an index for this key or use a different formula expression.",
             ));
         }
+
+        // Strata are listed by the payload index, otherwise the field of all points is read
+        if strict_mode_config.unindexed_filtering_retrieve == Some(false)
+            && let Query::Sample(SampleInternal::Stratified(stratified)) = self
+            && !collection
+                .payload_key_index_schema(&stratified.key)
+                .is_some_and(|schema| schema.supports_facet())
+        {
+            let key = &stratified.key;
+            return Err(CollectionError::strict_mode(
+                format!(
+                    "Index required but not found for \"{key}\" of one of the following types: [bool, integer, keyword, uuid]",
+                ),
+                "Create an index for this key or use a different sample.",
+            ));
+        }
         Ok(())
     }
 
@@ -156,7 +173,7 @@ impl StrictModeVerification for CollectionQueryRequest {
                     )
                     .await?;
             }
-            // check for unindexed fields in formula
+            // check for unindexed fields in formula or sample
             query.check_strict_mode(collection, strict_mode_config)?
         }
 
@@ -205,7 +222,7 @@ impl StrictModeVerification for CollectionPrefetch {
                     strict_mode_config,
                 )
                 .await?;
-            // check for unindexed fields in formula
+            // check for unindexed fields in formula or sample
             query.check_strict_mode(collection, strict_mode_config)?
         }
 
@@ -251,16 +268,23 @@ impl StrictModeVerification for CollectionQueryGroupsRequest {
                     )
                     .await?;
             }
-            // check for unindexed fields in formula
+            // check for unindexed fields in formula or sample
             query.check_strict_mode(collection, strict_mode_config)?
         }
         // check for unindexed fields targeted by group_by
         check_grouping_field(&self.group_by, collection, strict_mode_config)?;
+        if let Some(subgroups) = &self.subgroups {
+            check_grouping_field(&subgroups.group_by, collection, strict_mode_config)?;
+        }
         Ok(())
     }
 
     fn query_limit(&self) -> Option<usize> {
-        Some(self.limit * self.group_size)
+        Some(group_query_limit(
+            self.limit,
+            self.group_size,
+            self.subgroups.as_ref(),
+        ))
     }
 
     fn indexed_filter_read(&self) -> Option<&segment::types::Filter> {
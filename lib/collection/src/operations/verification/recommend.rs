@@ -1,6 +1,7 @@
 use segment::types::Filter;
 
-use super::StrictModeVerification;
+use super::{StrictModeVerification, group_query_limit};
+use crate::grouping::group_by::SubgroupRequest;
 use crate::operations::types::{RecommendGroupsRequestInternal, RecommendRequestInternal};
 
 impl StrictModeVerification for RecommendRequestInternal {
@@ -27,7 +28,16 @@ impl StrictModeVerification for RecommendRequestInternal {
 
 impl StrictModeVerification for RecommendGroupsRequestInternal {
     fn query_limit(&self) -> Option<usize> {
-        Some(self.group_request.limit as usize * self.group_request.group_size as usize)
+        let subgroups = self
+            .group_request
+            .subgroups
+            .clone()
+            .map(SubgroupRequest::from);
+        Some(group_query_limit(
+            self.group_request.limit as usize,
+            self.group_request.group_size as usize,
+            subgroups.as_ref(),
+        ))
     }
 
     fn indexed_filter_read(&self) -> Option<&Filter> {
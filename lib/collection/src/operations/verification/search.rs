@@ -1,8 +1,9 @@
 use api::rest::{SearchGroupsRequestInternal, SearchRequestInternal};
 use segment::types::{Filter, SearchParams, StrictModeConfig};
 
-use super::{StrictModeVerification, check_grouping_field};
+use super::{StrictModeVerification, check_grouping_field, group_query_limit};
 use crate::collection::Collection;
+use crate::grouping::group_by::SubgroupRequest;
 use crate::operations::types::{CollectionResult, CoreSearchRequest, SearchRequestBatch};
 
 impl StrictModeVerification for SearchRequestInternal {
@@ -98,12 +99,28 @@ impl StrictModeVerification for SearchGroupsRequestInternal {
         strict_mode_config: &StrictModeConfig,
     ) -> CollectionResult<()> {
         // check for unindexed fields targeted by group_by
-        check_grouping_field(&self.group_request.group_by, collection, strict_mode_config)?;
+        check_grouping_field(
+            self.group_request.group_by.paths(),
+            collection,
+            strict_mode_config,
+        )?;
+        if let Some(subgroups) = &self.group_request.subgroups {
+            check_grouping_field(subgroups.group_by.paths(), collection, strict_mode_config)?;
+        }
         Ok(())
     }
 
     fn query_limit(&self) -> Option<usize> {
-        Some(self.group_request.limit as usize * self.group_request.group_size as usize)
+        let subgroups = self
+            .group_request
+            .subgroups
+            .clone()
+            .map(SubgroupRequest::from);
+        Some(group_query_limit(
+            self.group_request.limit as usize,
+            self.group_request.group_size as usize,
+            subgroups.as_ref(),
+        ))
     }
 
     fn indexed_filter_read(&self) -> Option<&Filter> {
@@ -199,12 +199,14 @@ impl StrictModeVerification for PointInsertOperations {
                 shard_key: _,
                 update_filter: _,
                 update_mode: _,
+                if_version: _,
             }) => None,
             PointInsertOperations::PointsList(PointsList {
                 points: _,
                 shard_key: _,
                 update_filter: _,
                 update_mode: _,
+                if_version: _,
             }) => None,
         }
     }
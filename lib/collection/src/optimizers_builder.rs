@@ -194,6 +194,9 @@ pub fn build_segment_optimizer_config(
                 on_disk,
                 datatype,
                 multivector_config,
+                matryoshka_dim,
+                vamana_config,
+                ivf_config,
             } = params;
 
             (
@@ -203,12 +206,15 @@ pub fn build_segment_optimizer_config(
                     distance: *distance,
                     on_disk: *on_disk,
                     hnsw_config: global_hnsw_config.update_opt(hnsw_config.as_ref()),
+                    vamana_config: *vamana_config,
+                    ivf_config: *ivf_config,
                     quantization_config: quantization_config
                         .as_ref()
                         .or(global_quantization_config.as_ref())
                         .cloned(),
                     multivector_config: *multivector_config,
                     datatype: datatype.map(VectorStorageDatatype::from),
+                    matryoshka_dim: *matryoshka_dim,
                 },
             )
         })
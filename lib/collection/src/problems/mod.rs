@@ -1,3 +1,5 @@
+pub mod payload_schema_violation;
 pub mod unindexed_field;
 
+pub use payload_schema_violation::PayloadSchemaViolation;
 pub use unindexed_field::UnindexedField;
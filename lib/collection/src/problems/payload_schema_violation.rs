@@ -0,0 +1,69 @@
+use std::any::TypeId;
+
+use issues::{Code, Issue, Solution};
+
+use crate::operations::payload_validation::PayloadSchemaViolation as Violation;
+
+#[derive(Debug)]
+pub struct PayloadSchemaViolation {
+    collection_name: String,
+    field_name: String,
+    message: String,
+    instance_id: String,
+}
+
+impl PayloadSchemaViolation {
+    pub fn new(collection_name: String, violation: Violation) -> Self {
+        let Violation { field, message } = violation;
+        let instance_id = Self::get_instance_id(&collection_name, &field);
+        Self {
+            collection_name,
+            field_name: field,
+            message,
+            instance_id,
+        }
+    }
+
+    pub fn get_instance_id(collection_name: &str, field_name: &str) -> String {
+        format!("{collection_name}/{field_name}")
+    }
+
+    pub fn get_collection_name(code: &Code) -> &str {
+        debug_assert!(code.issue_type == TypeId::of::<Self>());
+        code.instance_id.split('/').next().unwrap_or("") // Code format is always the same
+    }
+
+    pub fn submit_all(collection_name: &str, violations: Vec<Violation>) {
+        for violation in violations {
+            Self::new(collection_name.to_string(), violation).submit();
+        }
+    }
+}
+
+impl Issue for PayloadSchemaViolation {
+    fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    fn name() -> &'static str {
+        "PAYLOAD_SCHEMA_VIOLATION"
+    }
+
+    fn related_collection(&self) -> Option<String> {
+        Some(self.collection_name.clone())
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "Payload field '{}' written to collection '{}' violates the payload schema: {}",
+            self.field_name, self.collection_name, self.message
+        )
+    }
+
+    fn solution(&self) -> Solution {
+        Solution::Refactor(format!(
+            "Fix payloads written to field '{}' or update the payload schema of collection '{}'",
+            self.field_name, self.collection_name
+        ))
+    }
+}
@@ -244,6 +244,9 @@ fn infer_index_from_field_condition(field_condition: &FieldCondition) -> Vec<Fie
                 required_indexes.push(FieldIndexType::FloatRange);
                 required_indexes.push(FieldIndexType::IntRange);
             }
+            RangeInterface::Keyword(_) => {
+                required_indexes.push(FieldIndexType::KeywordMatch);
+            }
         }
     }
     if geo_bounding_box.is_some() || geo_radius.is_some() || geo_polygon.is_some() {
@@ -593,6 +596,13 @@ fn schema_capabilities(value: &PayloadFieldSchema) -> HashSet<FieldIndexType> {
                 index_types.insert(FieldIndexType::Text)
             }
             PayloadSchemaParams::Datetime(_) => index_types.insert(FieldIndexType::DatetimeRange),
+            PayloadSchemaParams::Composite(_) => {
+                let single_field_schema =
+                    PayloadFieldSchema::FieldParams(value.single_field_params().into_owned());
+                index_types.extend(schema_capabilities(&single_field_schema));
+                // unifying match arm types
+                true
+            }
         },
     };
 
@@ -1,5 +1,5 @@
 use api::conversions::json::payload_to_proto;
-use api::grpc::conversions::convert_shard_key_from_grpc_opt;
+use api::grpc::conversions::{convert_shard_key_from_grpc_opt, then_by_values_from_proto};
 use api::grpc::qdrant::points_selector::PointsSelectorOneOf;
 use api::grpc::qdrant::{
     ClearPayloadPoints, ClearPayloadPointsInternal, CreateFieldIndexCollection,
@@ -12,14 +12,16 @@ use api::grpc::qdrant::{
 };
 use segment::data_types::vectors::VectorStructInternal;
 use segment::json_path::JsonPath;
-use segment::types::{Filter, PayloadFieldSchema, PointIdType, ScoredPoint, VectorNameBuf};
+use segment::types::{
+    ExpectedPointVersion, Filter, PayloadFieldSchema, PointIdType, ScoredPoint, VectorNameBuf,
+};
 use tonic::Status;
 
 use crate::operations::conversions::write_ordering_to_proto;
 use crate::operations::payload_ops::{DeletePayloadOp, SetPayloadOp};
 use crate::operations::point_ops::{
-    ConditionalInsertOperationInternal, PointInsertOperationsInternal, PointSyncOperation,
-    WriteOrdering,
+    ConditionalDeleteOperationInternal, ConditionalInsertOperationInternal,
+    PointInsertOperationsInternal, PointStructPersisted, PointSyncOperation, WriteOrdering,
 };
 use crate::operations::types::CollectionResult;
 use crate::operations::vector_ops::UpdateVectorsOp;
@@ -33,6 +35,24 @@ pub(crate) fn wait_override_to_proto(wait: WaitUntil) -> Option<i32> {
     Some(i32::from(api::grpc::qdrant::WaitUntil::from(wait)))
 }
 
+/// Versions of points copied from another replica, to preserve them on the receiving replica.
+fn point_versions_to_proto(
+    points: &[PointStructPersisted],
+) -> Vec<api::grpc::qdrant::ExpectedPointVersion> {
+    points
+        .iter()
+        .filter_map(|point| {
+            let version = point.version?;
+            Some(api::grpc::qdrant::ExpectedPointVersion::from(
+                ExpectedPointVersion {
+                    id: point.id,
+                    version,
+                },
+            ))
+        })
+        .collect()
+}
+
 pub fn internal_sync_points(
     shard_id: Option<ShardId>,
     clock_tag: Option<ClockTag>,
@@ -51,6 +71,7 @@ pub fn internal_sync_points(
         shard_id,
         clock_tag: clock_tag.map(ClockTag::into),
         wait_override: wait_override_to_proto(wait),
+        point_versions: point_versions_to_proto(&points),
         sync_points: Some(SyncPoints {
             collection_name,
             wait: Some(wait.needs_callback()),
@@ -75,10 +96,16 @@ pub fn internal_upsert_points(
     wait_timeout: Option<u64>,
     ordering: Option<WriteOrdering>,
 ) -> CollectionResult<UpsertPointsInternal> {
+    let point_versions = match &point_insert_operations {
+        PointInsertOperationsInternal::PointsBatch(_) => Vec::new(),
+        PointInsertOperationsInternal::PointsList(list) => point_versions_to_proto(list),
+    };
+
     Ok(UpsertPointsInternal {
         shard_id,
         clock_tag: clock_tag.map(ClockTag::into),
         wait_override: wait_override_to_proto(wait),
+        point_versions,
         upsert_points: Some(UpsertPoints {
             collection_name,
             wait: Some(wait.needs_callback()),
@@ -94,6 +121,7 @@ pub fn internal_upsert_points(
             update_filter: None,
             timeout: wait_timeout,
             update_mode: None, // Default mode (Upsert)
+            if_version: Vec::new(),
         }),
     })
 }
@@ -113,6 +141,7 @@ pub fn internal_conditional_upsert_points(
         points_op: point_insert_operations,
         condition,
         update_mode,
+        if_version,
     } = point_condition_upsert_operations;
 
     let grpc_update_mode = update_mode.map(|mode| match mode {
@@ -125,6 +154,7 @@ pub fn internal_conditional_upsert_points(
         shard_id,
         clock_tag: clock_tag.map(ClockTag::into),
         wait_override: wait_override_to_proto(wait),
+        point_versions: Vec::new(),
         upsert_points: Some(UpsertPoints {
             collection_name,
             wait: Some(wait.needs_callback()),
@@ -140,6 +170,11 @@ pub fn internal_conditional_upsert_points(
             update_filter: Some(api::grpc::Filter::from(condition)),
             timeout: wait_timeout,
             update_mode: grpc_update_mode,
+            if_version: if_version
+                .unwrap_or_default()
+                .into_iter()
+                .map(api::grpc::qdrant::ExpectedPointVersion::from)
+                .collect(),
         }),
     })
 }
@@ -168,6 +203,41 @@ pub fn internal_delete_points(
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
             timeout: wait_timeout,
+            if_version: Vec::new(),
+        }),
+    }
+}
+
+pub fn internal_conditional_delete_points(
+    shard_id: Option<ShardId>,
+    clock_tag: Option<ClockTag>,
+    collection_name: String,
+    conditional_delete: ConditionalDeleteOperationInternal,
+    wait: WaitUntil,
+    wait_timeout: Option<u64>,
+    ordering: Option<WriteOrdering>,
+) -> DeletePointsInternal {
+    let ConditionalDeleteOperationInternal { ids, if_version } = conditional_delete;
+
+    DeletePointsInternal {
+        shard_id,
+        clock_tag: clock_tag.map(ClockTag::into),
+        wait_override: wait_override_to_proto(wait),
+        delete_points: Some(DeletePoints {
+            collection_name,
+            wait: Some(wait.needs_callback()),
+            points: Some(PointsSelector {
+                points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
+                    ids: ids.into_iter().map(PointIdType::into).collect(),
+                })),
+            }),
+            ordering: ordering.map(write_ordering_to_proto),
+            shard_key_selector: None,
+            timeout: wait_timeout,
+            if_version: if_version
+                .into_iter()
+                .map(api::grpc::qdrant::ExpectedPointVersion::from)
+                .collect(),
         }),
     }
 }
@@ -194,6 +264,7 @@ pub fn internal_delete_points_by_filter(
             ordering: ordering.map(write_ordering_to_proto),
             shard_key_selector: None,
             timeout: wait_timeout,
+            if_version: Vec::new(),
         }),
     }
 }
@@ -335,6 +406,12 @@ pub fn internal_set_payload(
             shard_key_selector: None,
             key: set_payload.key.map(|key| key.to_string()),
             timeout: wait_timeout,
+            if_version: set_payload
+                .if_version
+                .unwrap_or_default()
+                .into_iter()
+                .map(api::grpc::qdrant::ExpectedPointVersion::from)
+                .collect(),
         }),
     }
 }
@@ -555,6 +632,8 @@ pub fn try_scored_point_from_grpc(
         vectors,
         shard_key,
         order_value,
+        then_by_values,
+        stratum,
     } = point;
     let id = id
         .ok_or_else(|| Status::invalid_argument("scored point does not have an ID"))?
@@ -580,5 +659,7 @@ pub fn try_scored_point_from_grpc(
         vector,
         shard_key: convert_shard_key_from_grpc_opt(shard_key),
         order_value: order_value.map(TryFrom::try_from).transpose()?,
+        then_by_values: then_by_values_from_proto(then_by_values)?,
+        stratum: stratum.map(TryFrom::try_from).transpose()?,
     })
 }
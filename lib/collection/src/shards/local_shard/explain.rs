@@ -0,0 +1,295 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use api::rest::models::{
+    Explanation, SegmentStageExplanation, ShardExplanation, StageExplanation, StageKind,
+};
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::types::Filter;
+use shard::common::stopping_guard::StoppingGuard;
+use shard::search::CoreSearchRequestBatch;
+
+use super::LocalShard;
+use crate::collection_manager::segments_searcher::SegmentsSearcher;
+use crate::common::adaptive_handle::AdaptiveSearchHandle;
+use crate::operations::types::{CollectionResult, CoreSearchRequest};
+use crate::operations::universal_query::planned_query::{PlannedQuery, Source};
+use crate::operations::universal_query::shard_query::ShardQueryRequest;
+use crate::operations::verification::operation_rate_cost::{BASE_COST, filter_rate_cost};
+use crate::shards::shard::ShardId;
+
+/// Name of the stage of a request without prefetches
+const ROOT_STAGE: &str = "root";
+
+/// Stage of a query, which reads points from the segments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LeafStage {
+    /// Offset into the searches of the planned query
+    Search(usize),
+    /// Offset into the scrolls of the planned query
+    Scroll(usize),
+}
+
+impl LocalShard {
+    /// Explain how the segments of this shard execute the stages of the query.
+    ///
+    /// Only the stages which read points from the segments are explained. They are executed
+    /// again one by one, the same way the query executes them, so each of them can be measured
+    /// separately. Searches are also executed in each segment, to record the strategy which
+    /// the segment uses.
+    ///
+    /// The executions are billed to `hw_measurement_acc` and limited by the read rate limiter,
+    /// same as the query itself.
+    pub async fn explain_query(
+        &self,
+        shard_id: ShardId,
+        request: ShardQueryRequest,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Explanation> {
+        let timeout = self.timeout_or_default_search_timeout(timeout);
+        let has_prefetches = !request.prefetches.is_empty();
+        let planned_query = PlannedQuery::try_from(vec![request])?;
+
+        // The stages are executed twice: as a whole and in each segment
+        self.check_read_rate_limiter(&hw_measurement_acc, "explain_query", || {
+            let cost: usize = planned_query
+                .searches
+                .iter()
+                .map(|s| s.search_rate_cost())
+                .chain(planned_query.scrolls.iter().map(|s| s.scroll_rate_cost()))
+                .sum();
+            cost * 2
+        })?;
+
+        let mut leaves = Vec::new();
+        for root_plan in &planned_query.root_plans {
+            if has_prefetches {
+                collect_leaf_stages(&root_plan.merge_plan.sources, None, &mut leaves);
+            } else {
+                leaves.extend(root_plan.merge_plan.sources.iter().filter_map(|source| {
+                    let leaf = match source {
+                        Source::SearchesIdx(idx) => LeafStage::Search(*idx),
+                        Source::ScrollsIdx(idx) => LeafStage::Scroll(*idx),
+                        // Requests without prefetches have no nested stages
+                        Source::Prefetch(_) => return None,
+                    };
+                    Some((ROOT_STAGE.to_string(), leaf))
+                }));
+            }
+        }
+
+        let mut stages = Vec::with_capacity(leaves.len());
+        for (stage, leaf) in leaves {
+            let (kind, using, found, time, segments) = match leaf {
+                LeafStage::Search(idx) => {
+                    let search = &planned_query.searches[idx];
+                    let start = Instant::now();
+                    let found = self
+                        .do_search(
+                            Arc::new(CoreSearchRequestBatch {
+                                searches: vec![search.clone()],
+                            }),
+                            search_runtime_handle,
+                            timeout,
+                            hw_measurement_acc.clone(),
+                        )
+                        .await?;
+                    let time = start.elapsed().as_secs_f64();
+                    let segments = self
+                        .explain_search(
+                            search,
+                            search_runtime_handle,
+                            timeout,
+                            hw_measurement_acc.clone(),
+                        )
+                        .await?;
+                    let using = search.query.get_vector_name().to_string();
+                    let found = found.iter().map(Vec::len).sum();
+                    (StageKind::Search, Some(using), found, time, segments)
+                }
+                LeafStage::Scroll(idx) => {
+                    let scroll = &planned_query.scrolls[idx];
+                    let start = Instant::now();
+                    let found = self
+                        .query_scroll_batch(
+                            Arc::new(vec![scroll.clone()]),
+                            search_runtime_handle,
+                            timeout,
+                            hw_measurement_acc.clone(),
+                        )
+                        .await?;
+                    let time = start.elapsed().as_secs_f64();
+                    let segments = SegmentsSearcher::explain_filter(
+                        self.segments.clone(),
+                        scroll.filter.as_ref(),
+                        search_runtime_handle,
+                        hw_measurement_acc.clone(),
+                        timeout,
+                    )
+                    .await?;
+                    let found = found.iter().map(Vec::len).sum();
+                    (StageKind::Scroll, None, found, time, segments)
+                }
+            };
+
+            stages.push(StageExplanation {
+                stage,
+                kind,
+                using,
+                shards: vec![ShardExplanation {
+                    shard_id,
+                    found,
+                    time,
+                    segments,
+                }],
+            });
+        }
+
+        Ok(Explanation {
+            stages,
+            unexplained_shards: Vec::new(),
+        })
+    }
+
+    /// Explain how the segments of this shard select the points to count.
+    ///
+    /// The selection is billed to `hw_measurement_acc` and limited by the read rate limiter,
+    /// same as the count itself.
+    pub async fn explain_count(
+        &self,
+        shard_id: ShardId,
+        filter: Option<&Filter>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Explanation> {
+        self.check_read_rate_limiter(&hw_measurement_acc, "explain_count", || {
+            let mut cost = BASE_COST;
+            if let Some(filter) = filter {
+                cost += filter_rate_cost(filter);
+            }
+            cost
+        })?;
+        let timeout = self.timeout_or_default_search_timeout(timeout);
+        let start = Instant::now();
+        let segments = SegmentsSearcher::explain_filter(
+            self.segments.clone(),
+            filter,
+            search_runtime_handle,
+            hw_measurement_acc,
+            timeout,
+        )
+        .await?;
+
+        // Counting reads the same matching points, which the explanation of the segments reads
+        let found = segments.iter().map(|segment| segment.found).sum();
+
+        Ok(Explanation {
+            stages: vec![StageExplanation {
+                stage: ROOT_STAGE.to_string(),
+                kind: StageKind::Count,
+                using: None,
+                shards: vec![ShardExplanation {
+                    shard_id,
+                    found,
+                    time: start.elapsed().as_secs_f64(),
+                    segments,
+                }],
+            }],
+            unexplained_shards: Vec::new(),
+        })
+    }
+
+    async fn explain_search(
+        &self,
+        search: &CoreSearchRequest,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<SegmentStageExplanation>> {
+        let batch = CoreSearchRequestBatch {
+            searches: vec![search.clone()],
+        };
+        let is_stopped_guard = StoppingGuard::new();
+
+        let query_context = {
+            let collection_config = self.collection_config.read().await;
+            SegmentsSearcher::prepare_query_context(
+                self.segments.clone(),
+                &batch,
+                &collection_config,
+                timeout,
+                search_runtime_handle,
+                &is_stopped_guard,
+                hw_measurement_acc,
+            )
+            .await?
+        };
+
+        let Some(query_context) = query_context else {
+            // No segments to search
+            return Ok(vec![]);
+        };
+
+        SegmentsSearcher::explain_search(
+            self.segments.clone(),
+            Arc::new(search.clone()),
+            search_runtime_handle,
+            query_context,
+            timeout,
+        )
+        .await
+    }
+}
+
+/// Collect the leaf sources of the prefetches, along with their positions in the request
+fn collect_leaf_stages(
+    sources: &[Source],
+    parent: Option<&str>,
+    leaves: &mut Vec<(String, LeafStage)>,
+) {
+    for (idx, source) in sources.iter().enumerate() {
+        let stage = match parent {
+            Some(parent) => format!("{parent}.prefetch[{idx}]"),
+            None => format!("prefetch[{idx}]"),
+        };
+        match source {
+            Source::SearchesIdx(idx) => leaves.push((stage, LeafStage::Search(*idx))),
+            Source::ScrollsIdx(idx) => leaves.push((stage, LeafStage::Scroll(*idx))),
+            Source::Prefetch(merge_plan) => {
+                collect_leaf_stages(&merge_plan.sources, Some(&stage), leaves)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::universal_query::planned_query::MergePlan;
+
+    #[test]
+    fn test_collect_leaf_stages() {
+        let sources = vec![
+            Source::SearchesIdx(0),
+            Source::Prefetch(Box::new(MergePlan {
+                sources: vec![Source::ScrollsIdx(0), Source::SearchesIdx(1)],
+                rescore_stages: None,
+            })),
+        ];
+
+        let mut leaves = Vec::new();
+        collect_leaf_stages(&sources, None, &mut leaves);
+
+        assert_eq!(
+            leaves,
+            vec![
+                ("prefetch[0]".to_string(), LeafStage::Search(0)),
+                ("prefetch[1].prefetch[0]".to_string(), LeafStage::Scroll(0)),
+                ("prefetch[1].prefetch[1]".to_string(), LeafStage::Search(1)),
+            ],
+        );
+    }
+}
@@ -153,7 +153,7 @@ impl LocalShard {
         Ok(hits)
     }
 
-    async fn unique_values(
+    pub(super) async fn unique_values(
         &self,
         request: Arc<FacetParams>,
         handle: &AdaptiveSearchHandle,
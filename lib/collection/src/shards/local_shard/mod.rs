@@ -1,5 +1,6 @@
 pub mod clock_map;
 pub mod disk_usage_watcher;
+pub(super) mod explain;
 pub(super) mod facet;
 pub(super) mod formula_rescore;
 pub(super) mod query;
@@ -458,6 +459,7 @@ impl LocalShard {
             );
 
         let mut segment_holder = SegmentHolder::default();
+        segment_holder.point_versions = collection_config_read.params.point_versions;
 
         while let Some(result) = segment_stream.next().await {
             let Some(segment) = result?? else {
@@ -609,6 +611,7 @@ impl LocalShard {
             })?;
 
         let mut segment_holder = SegmentHolder::default();
+        segment_holder.point_versions = config.params.point_versions;
         let mut build_handlers = vec![];
 
         let vector_params = config
@@ -11,7 +11,10 @@ use ordered_float::OrderedFloat;
 use parking_lot::Mutex;
 use segment::common::reciprocal_rank_fusion::rrf_scoring;
 use segment::common::score_fusion::{ScoreFusion, score_fusion};
-use segment::types::{Filter, HasIdCondition, ScoredPoint, WithPayloadInterface, WithVector};
+use segment::types::{
+    Filter, HasIdCondition, Order, ScoredPoint, WithPayloadInterface, WithVector,
+};
+use segment::utils::scored_point_ties::ScoredPointTies;
 use shard::query::planned_query::RescoreStages;
 use shard::search::CoreSearchRequestBatch;
 
@@ -26,7 +29,8 @@ use crate::operations::universal_query::planned_query::{
     MergePlan, PlannedQuery, RescoreParams, RootPlan, Source,
 };
 use crate::operations::universal_query::shard_query::{
-    FusionInternal, MmrInternal, SampleInternal, ScoringQuery, ShardQueryResponse,
+    FusionInternal, MmrInternal, ScoringQuery, ShardQueryRequest, ShardQueryResponse,
+    query_result_order,
 };
 
 pub enum FetchedSource {
@@ -103,6 +107,105 @@ impl LocalShard {
         Ok(batched_scored_points)
     }
 
+    /// Completes pages of results ordered by score, so that they can be continued after their
+    /// last point.
+    ///
+    /// Segments return arbitrary points among the ones with equal scores, while pages order them
+    /// by their IDs, see [`ScorePosition`]. Requests are searched one point deeper than
+    /// requested, see [`lookahead_request`]. If that point is tied with the last point of the
+    /// page, or fewer points than requested got after the position, the request is repeated with
+    /// a doubled limit, until the whole tie is found or the results end. Then points are sorted
+    /// and cut at the end of the page, so fewer than `limit` points are returned only at the end
+    /// of the results.
+    ///
+    /// [`ScorePosition`]: crate::operations::universal_query::shard_query::ScorePosition
+    pub(super) async fn complete_pages(
+        &self,
+        requests: &[ShardQueryRequest],
+        mut responses: Vec<ShardQueryResponse>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<ShardQueryResponse>> {
+        if !requests.iter().any(completes_pages) {
+            return Ok(responses);
+        }
+
+        let start_time = Instant::now();
+        let collection_params = self.collection_config.read().await.params.clone();
+
+        for (request, response) in requests.iter().zip(responses.iter_mut()) {
+            if !completes_pages(request) {
+                continue;
+            }
+            let Some(order) = query_result_order(request.query.as_ref(), &collection_params)?
+            else {
+                continue;
+            };
+
+            let page_len = request.offset.saturating_add(request.limit);
+            let mut searched = page_len.saturating_add(1);
+            loop {
+                let [points] = response.as_mut_slice() else {
+                    return Err(CollectionError::service_error(
+                        "Query response was expected to have one list of results.",
+                    ));
+                };
+
+                let reached_end = points.len() < searched;
+                if let Some(position) = request.start_after {
+                    points.retain(|point| position.is_before(point, order));
+                }
+                match order {
+                    Order::LargeBetter => {
+                        points.sort_unstable_by(|a, b| ScoredPointTies(b).cmp(&ScoredPointTies(a)))
+                    }
+                    Order::SmallBetter => {
+                        points.sort_unstable_by(|a, b| ScoredPointTies(a).cmp(&ScoredPointTies(b)))
+                    }
+                }
+
+                let is_complete = if reached_end || page_len == 0 {
+                    true
+                } else if points.len() < page_len {
+                    // More points got before the position since the previous page
+                    false
+                } else if request.query.is_none() {
+                    // Points are scrolled by their unique IDs
+                    true
+                } else {
+                    // Points which are not searched yet are not better than the last searched
+                    // one, so they are not tied with the end of the page, if it is not either
+                    points[page_len - 1].score != points[points.len() - 1].score
+                };
+                if is_complete {
+                    points.truncate(page_len);
+                    break;
+                }
+
+                searched = searched.saturating_mul(2);
+                let deeper_request = ShardQueryRequest {
+                    offset: 0,
+                    limit: searched,
+                    ..request.clone()
+                };
+                let planned_query = PlannedQuery::try_from(vec![deeper_request])?;
+                *response = self
+                    .do_planned_query(
+                        planned_query,
+                        search_runtime_handle,
+                        timeout.saturating_sub(start_time.elapsed()),
+                        hw_measurement_acc.clone(),
+                    )
+                    .await?
+                    .pop()
+                    .unwrap_or_default();
+            }
+        }
+
+        Ok(responses)
+    }
+
     /// Fetches the payload and/or vector if required. This will filter out points if they are deleted between search and retrieve.
     ///
     /// This function always filters out deferred points.
@@ -318,6 +421,7 @@ impl LocalShard {
                     with_payload: false.into(),
                     with_vector: false.into(),
                     scroll_order: ScrollOrder::ByField(order_by),
+                    offset_id: None,
                 };
 
                 self.query_scroll_batch(
@@ -378,35 +482,34 @@ impl LocalShard {
                 )
                 .await
             }
-            ScoringQuery::Sample(sample) => match sample {
-                SampleInternal::Random => {
-                    // create single scroll request for rescoring query
-                    let filter = filter_with_sources_ids(sources.into_iter());
-
-                    // Note: score_threshold is not used in this case, as all results will have same score and order_value
-                    let scroll_request = QueryScrollRequestInternal {
-                        limit,
-                        filter: Some(filter),
-                        with_payload: false.into(),
-                        with_vector: false.into(),
-                        scroll_order: ScrollOrder::Random,
-                    };
-
-                    self.query_scroll_batch(
-                        Arc::new(vec![scroll_request]),
-                        search_runtime_handle,
-                        timeout,
-                        hw_counter_acc.clone(),
+            ScoringQuery::Sample(sample) => {
+                // create single scroll request for rescoring query
+                let filter = filter_with_sources_ids(sources.into_iter());
+
+                // Note: score_threshold is not used in this case, as scores of sampled points are random
+                let scroll_request = QueryScrollRequestInternal {
+                    limit,
+                    filter: Some(filter),
+                    with_payload: false.into(),
+                    with_vector: false.into(),
+                    scroll_order: ScrollOrder::from(sample),
+                    offset_id: None,
+                };
+
+                self.query_scroll_batch(
+                    Arc::new(vec![scroll_request]),
+                    search_runtime_handle,
+                    timeout,
+                    hw_counter_acc.clone(),
+                )
+                .await?
+                .pop()
+                .ok_or_else(|| {
+                    CollectionError::service_error(
+                        "Rescoring with sample query didn't return expected batch of results",
                     )
-                    .await?
-                    .pop()
-                    .ok_or_else(|| {
-                        CollectionError::service_error(
-                            "Rescoring with order-by query didn't return expected batch of results",
-                        )
-                    })
-                }
-            },
+                })
+            }
             ScoringQuery::Mmr(mmr) => {
                 self.mmr_rescore(
                     sources,
@@ -514,3 +617,29 @@ fn filter_with_sources_ids(sources: impl Iterator<Item = Vec<ScoredPoint>>) -> F
         point_ids,
     )))
 }
+
+/// Whether the shard completes pages of the request, see [`LocalShard::complete_pages`].
+///
+/// Fusion is computed at collection level, so points up to the position are skipped there.
+/// Other queries are not continued from a score position.
+fn completes_pages(request: &ShardQueryRequest) -> bool {
+    match &request.query {
+        None | Some(ScoringQuery::Vector(_) | ScoringQuery::Formula(_)) => true,
+        Some(
+            ScoringQuery::Fusion(_)
+            | ScoringQuery::OrderBy(_)
+            | ScoringQuery::Sample(_)
+            | ScoringQuery::Mmr(_),
+        ) => false,
+    }
+}
+
+/// Request to search one point deeper than the page, to find out whether the page ends in the
+/// middle of a tie. See [`LocalShard::complete_pages`].
+pub(super) fn lookahead_request(request: &ShardQueryRequest) -> ShardQueryRequest {
+    let mut request = request.clone();
+    if completes_pages(&request) {
+        request.limit = request.limit.saturating_add(1);
+    }
+    request
+}
@@ -11,11 +11,16 @@ use rand::RngExt;
 use rand::distr::weighted::WeightedIndex;
 use rand::rngs::StdRng;
 use segment::common::operation_error::OperationResult;
-use segment::data_types::order_by::{Direction, OrderBy};
+use segment::data_types::facets::FacetParams;
+use segment::data_types::order_by::OrderBy;
+use segment::json_path::JsonPath;
 use segment::types::{
-    ExtendedPointId, Filter, ScoredPoint, WithPayload, WithPayloadInterface, WithVector,
+    Condition, ExtendedPointId, FieldCondition, Filter, Match, PayloadFieldSchema, ScoredPoint,
+    WithPayload, WithPayloadInterface, WithVector,
 };
 use shard::common::stopping_guard::StoppingGuard;
+use shard::query::StratifiedSample;
+use shard::query::sample::{PayloadSampling, SampledPoint};
 use shard::retrieve::record_internal::RecordInternal;
 use tokio_util::task::AbortOnDropHandle;
 
@@ -73,16 +78,15 @@ impl LocalShard {
             filter,
             scroll_order,
             with_payload,
+            offset_id,
         } = request;
 
         let limit = *limit;
 
-        let offset_id = None;
-
         let record_results = match scroll_order {
             ScrollOrder::ById => {
                 self.internal_scroll_by_id(
-                    offset_id,
+                    *offset_id,
                     limit,
                     with_payload,
                     with_vector,
@@ -120,6 +124,35 @@ impl LocalShard {
                 )
                 .await?
             }
+            // Sampled points are scored by their sampling keys
+            ScrollOrder::Stratified(stratified) => {
+                return self
+                    .scroll_sampled(
+                        PayloadSampling::Stratified(stratified),
+                        limit,
+                        with_payload,
+                        with_vector,
+                        filter.as_ref(),
+                        search_runtime_handle,
+                        timeout,
+                        hw_measurement_acc,
+                    )
+                    .await;
+            }
+            ScrollOrder::Weighted(weighted) => {
+                return self
+                    .scroll_sampled(
+                        PayloadSampling::Weighted(weighted),
+                        limit,
+                        with_payload,
+                        with_vector,
+                        filter.as_ref(),
+                        search_runtime_handle,
+                        timeout,
+                        hw_measurement_acc,
+                    )
+                    .await;
+            }
         };
 
         let point_results = record_results
@@ -132,6 +165,8 @@ impl LocalShard {
                 vector: record.vector,
                 shard_key: record.shard_key,
                 order_value: record.order_value,
+                then_by_values: record.then_by_values,
+                stratum: None,
             })
             .collect();
 
@@ -299,16 +334,13 @@ impl LocalShard {
         .await
         .map_err(|_| CollectionError::timeout(timeout, "scroll_by_field"))??;
 
-        let (values, point_ids): (Vec<_>, Vec<_>) =
-            itertools::process_results(all_reads, |iter| {
-                iter.kmerge_by(|a, b| match order_by.direction() {
-                    Direction::Asc => a <= b,
-                    Direction::Desc => a >= b,
-                })
+        let ordered_points: Vec<_> = itertools::process_results(all_reads, |iter| {
+            iter.kmerge_by(|a, b| order_by.cmp_keys(a.key(), b.key()).is_le())
                 .dedup()
                 .take(limit)
-                .unzip()
-            })?;
+                .collect()
+        })?;
+        let point_ids: Vec<_> = ordered_points.iter().map(|point| point.id).collect();
 
         let with_payload = WithPayload::from(with_payload_interface);
 
@@ -334,12 +366,12 @@ impl LocalShard {
 
         drop(update_operation_lock);
 
-        let ordered_records = point_ids
-            .iter()
-            .zip(values)
-            .filter_map(|(point_id, value)| {
-                let mut record = records_map.get(point_id).cloned()?;
-                record.order_value = Some(value);
+        let ordered_records = ordered_points
+            .into_iter()
+            .filter_map(|point| {
+                let mut record = records_map.get(&point.id).cloned()?;
+                record.order_value = Some(point.value);
+                record.then_by_values = point.then_by;
                 Some(record)
             })
             .collect();
@@ -347,6 +379,199 @@ impl LocalShard {
         Ok(ordered_records)
     }
 
+    /// Sample points depending on their payload.
+    ///
+    /// Strata of an indexed field are listed by the payload index, otherwise
+    /// reads the sampled field of all points, which satisfy the filter.
+    #[allow(clippy::too_many_arguments)]
+    async fn scroll_sampled(
+        &self,
+        sampling: PayloadSampling<'_>,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<ScoredPoint>> {
+        let start = Instant::now();
+
+        let sampled = match sampling {
+            PayloadSampling::Stratified(stratified) if self.is_facet_indexed(&stratified.key) => {
+                self.sample_indexed_strata(
+                    stratified,
+                    limit,
+                    filter,
+                    search_runtime_handle,
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+                .await?
+            }
+            PayloadSampling::Stratified(_) | PayloadSampling::Weighted(_) => {
+                self.sample_by_payload(
+                    sampling,
+                    limit,
+                    filter,
+                    search_runtime_handle,
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+                .await?
+            }
+        };
+        let point_ids = sampled.iter().map(|point| point.id).collect_vec();
+
+        let with_payload = WithPayload::from(with_payload_interface);
+        // update timeout
+        let timeout = timeout.saturating_sub(start.elapsed());
+        let mut records_map = tokio::time::timeout(
+            timeout,
+            SegmentsSearcher::retrieve(
+                self.segments.clone(),
+                &point_ids,
+                &with_payload,
+                with_vector,
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc,
+                DeferredBehavior::Exclude,
+            ),
+        )
+        .await
+        .map_err(|_| CollectionError::timeout(timeout, "retrieve"))??;
+
+        let sampled_points = sampled
+            .into_iter()
+            .filter_map(|point| {
+                let record = records_map.remove(&point.id)?;
+                Some(point.into_scored_point(record))
+            })
+            .collect();
+
+        Ok(sampled_points)
+    }
+
+    /// Whether the payload index can list the distinct values of the field
+    fn is_facet_indexed(&self, key: &JsonPath) -> bool {
+        self.payload_index_schema
+            .read()
+            .schema
+            .get(key)
+            .is_some_and(PayloadFieldSchema::supports_facet)
+    }
+
+    /// Draw random points of each value of the indexed field, listed by the payload index
+    #[allow(clippy::too_many_arguments)]
+    async fn sample_indexed_strata(
+        &self,
+        stratified: &StratifiedSample,
+        limit: usize,
+        filter: Option<&Filter>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<SampledPoint>> {
+        let start = Instant::now();
+
+        let request = Arc::new(FacetParams {
+            key: stratified.key.clone(),
+            // Not used to list the values
+            limit: FacetParams::DEFAULT_LIMIT,
+            filter: filter.cloned(),
+            exact: true,
+        });
+        let values = self
+            .unique_values(
+                request,
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+
+        let draws = values.into_iter().map(|value| {
+            let match_value = Filter::new_must(Condition::Field(FieldCondition::new_match(
+                stratified.key.clone(),
+                Match::new_value(From::from(value.clone())),
+            )));
+            let filter = Filter::merge_opts(filter.cloned(), Some(match_value));
+
+            let hw_acc = hw_measurement_acc.clone();
+            async move {
+                let records = self
+                    .scroll_randomly(
+                        stratified.per_value,
+                        &WithPayloadInterface::Bool(false),
+                        &WithVector::Bool(false),
+                        filter.as_ref(),
+                        search_runtime_handle,
+                        timeout.saturating_sub(start.elapsed()),
+                        hw_acc,
+                    )
+                    .await?;
+                let members = records
+                    .into_iter()
+                    .map(|record| (value.clone(), record.id))
+                    .collect_vec();
+                CollectionResult::Ok(members)
+            }
+        });
+
+        let members =
+            tokio::time::timeout(timeout.saturating_sub(start.elapsed()), try_join_all(draws))
+                .await
+                .map_err(|_| CollectionError::timeout(timeout, "scroll_sampled"))??;
+
+        Ok(stratified.sample_members(
+            members.into_iter().flatten(),
+            limit,
+            &mut rand::make_rng::<StdRng>(),
+        ))
+    }
+
+    /// Draw points by the values of the field, read from the payload of all points
+    #[allow(clippy::too_many_arguments)]
+    async fn sample_by_payload(
+        &self,
+        sampling: PayloadSampling<'_>,
+        limit: usize,
+        filter: Option<&Filter>,
+        search_runtime_handle: &AdaptiveSearchHandle,
+        timeout: Duration,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Vec<SampledPoint>> {
+        let candidates = self
+            .internal_scroll_by_id(
+                None,
+                usize::MAX,
+                &WithPayloadInterface::Fields(vec![sampling.key().clone()]),
+                &WithVector::Bool(false),
+                filter,
+                search_runtime_handle,
+                timeout,
+                hw_measurement_acc,
+                DeferredBehavior::Exclude,
+            )
+            .await?;
+
+        let sampled = sampling.sample(
+            candidates.iter().map(|record| {
+                let values = record
+                    .payload
+                    .as_ref()
+                    .map(|payload| sampling.key().value_get(&payload.0))
+                    .unwrap_or_default();
+                (record.id, values)
+            }),
+            limit,
+            &mut rand::make_rng::<StdRng>(),
+        );
+
+        Ok(sampled)
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn scroll_randomly(
         &self,
@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use ordered_float::OrderedFloat;
 use segment::types::ScoredPoint;
 use shard::common::stopping_guard::StoppingGuard;
 use shard::query::query_enum::QueryEnum;
@@ -167,7 +168,21 @@ impl LocalShard {
                     scored_point
                 });
 
-                if let Some(threshold) = req.score_threshold {
+                // Radius of a nearest search limits the results, if there is no explicit threshold
+                let score_threshold = match req.query {
+                    QueryEnum::Nearest(_) => req.score_threshold.or_else(|| {
+                        req.params
+                            .and_then(|params| params.radius)
+                            .map(OrderedFloat::into_inner)
+                    }),
+                    QueryEnum::RecommendBestScore(_)
+                    | QueryEnum::RecommendSumScores(_)
+                    | QueryEnum::Discover(_)
+                    | QueryEnum::Context(_)
+                    | QueryEnum::FeedbackNaive(_) => req.score_threshold,
+                };
+
+                if let Some(threshold) = score_threshold {
                     processed_res
                         .take_while(|scored_point| {
                             distance.check_threshold(scored_point.score, threshold)
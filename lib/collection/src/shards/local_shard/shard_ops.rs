@@ -31,6 +31,7 @@ use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQu
 use crate::operations::verification::operation_rate_cost::{BASE_COST, filter_rate_cost};
 use crate::profiling::interface::log_request_to_collector;
 use crate::shards::local_shard::LocalShard;
+use crate::shards::local_shard::query::lookahead_request;
 use crate::shards::shard_trait::{ShardOperation, WaitUntil};
 use crate::update_handler::{OperationData, UpdateSignal};
 use crate::update_workers::internal_update_result::InternalUpdateResult;
@@ -462,7 +463,9 @@ impl ShardOperation for LocalShard {
         hw_measurement_acc: HwMeasurementAcc,
     ) -> CollectionResult<Vec<ShardQueryResponse>> {
         let start_time = Instant::now();
-        let planned_query = PlannedQuery::try_from(requests.as_ref().to_owned())?;
+
+        let planned_query =
+            PlannedQuery::try_from(requests.iter().map(lookahead_request).collect::<Vec<_>>())?;
 
         // Check read rate limiter before proceeding
         self.check_read_rate_limiter(&hw_measurement_acc, "query_batch", || {
@@ -475,14 +478,25 @@ impl ShardOperation for LocalShard {
         })?;
         let timeout = self.timeout_or_default_search_timeout(timeout);
         let cpu_utilization = hw_measurement_acc.cpu_utilization();
-        let result = self
-            .do_planned_query(
-                planned_query,
+        let result = async {
+            let responses = self
+                .do_planned_query(
+                    planned_query,
+                    search_runtime_handle,
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+                .await?;
+            self.complete_pages(
+                &requests,
+                responses,
                 search_runtime_handle,
-                timeout,
+                timeout.saturating_sub(start_time.elapsed()),
                 hw_measurement_acc,
             )
-            .await;
+            .await
+        }
+        .await;
 
         let elapsed = start_time.elapsed();
         let cpu_ratio = cpu_utilization.ratio();
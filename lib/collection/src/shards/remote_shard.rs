@@ -66,11 +66,11 @@ use crate::operations::{
 use crate::shards::CollectionId;
 use crate::shards::channel_service::ChannelService;
 use crate::shards::conversions::{
-    internal_clear_payload, internal_clear_payload_by_filter, internal_create_index,
-    internal_create_vector_name, internal_delete_index, internal_delete_payload,
-    internal_delete_points, internal_delete_points_by_filter, internal_delete_vector_name,
-    internal_set_payload, internal_sync_points, internal_upsert_points, try_scored_point_from_grpc,
-    wait_override_to_proto,
+    internal_clear_payload, internal_clear_payload_by_filter, internal_conditional_delete_points,
+    internal_create_index, internal_create_vector_name, internal_delete_index,
+    internal_delete_payload, internal_delete_points, internal_delete_points_by_filter,
+    internal_delete_vector_name, internal_set_payload, internal_sync_points,
+    internal_upsert_points, try_scored_point_from_grpc, wait_override_to_proto,
 };
 use crate::shards::replica_set::replica_set_state::ReplicaState;
 use crate::shards::shard::{PeerId, ShardId};
@@ -292,6 +292,18 @@ impl RemoteShard {
                         );
                         Update::Delete(request)
                     }
+                    PointOperations::DeletePointsConditional(conditional_delete) => {
+                        let request = internal_conditional_delete_points(
+                            shard_id,
+                            operation.clock_tag,
+                            collection_name.clone(),
+                            conditional_delete,
+                            wait,
+                            timeout,
+                            ordering,
+                        );
+                        Update::Delete(request)
+                    }
                     PointOperations::DeletePointsByFilter(filter) => {
                         let request = internal_delete_points_by_filter(
                             shard_id,
@@ -613,6 +625,22 @@ impl RemoteShard {
                     .await?
                     .into_inner()
                 }
+                PointOperations::DeletePointsConditional(conditional_delete) => {
+                    let request = &internal_conditional_delete_points(
+                        shard_id,
+                        operation.clock_tag,
+                        collection_name,
+                        conditional_delete,
+                        wait,
+                        timeout,
+                        ordering,
+                    );
+                    self.with_points_client(|mut client| async move {
+                        client.delete(tonic::Request::new(request.clone())).await
+                    })
+                    .await?
+                    .into_inner()
+                }
                 PointOperations::DeletePointsByFilter(filter) => {
                     let request = &internal_delete_points_by_filter(
                         shard_id,
@@ -1181,6 +1209,7 @@ impl ShardOperation for RemoteShard {
             shard_key_selector: None,
             order_by: order_by.map(api::grpc::qdrant::OrderBy::from),
             timeout: processed_timeout.map(|t| t.as_secs()),
+            with_join: None,
         };
         let scroll_request = &ScrollPointsInternal {
             scroll_points: Some(scroll_points),
@@ -1334,6 +1363,7 @@ impl ShardOperation for RemoteShard {
             read_consistency: None,
             shard_key_selector: None,
             timeout: processed_timeout.map(|t| t.as_secs()),
+            explain: None,
         };
 
         let count_request = &CountPointsInternal {
@@ -1355,6 +1385,7 @@ impl ShardOperation for RemoteShard {
             result,
             time: _,
             usage,
+            explain: _,
         } = count_response;
 
         if let Some(hw_usage) = usage.unwrap_or_default().hardware {
@@ -1396,6 +1427,7 @@ impl ShardOperation for RemoteShard {
             read_consistency: None,
             shard_key_selector: None,
             timeout: processed_timeout.map(|t| t.as_secs()),
+            with_join: None,
         };
         let get_request = &GetPointsInternal {
             get_points: Some(get_points),
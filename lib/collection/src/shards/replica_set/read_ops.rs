@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use api::rest::models::Explanation;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::types::DeferredBehavior;
 use futures::FutureExt as _;
@@ -15,6 +16,7 @@ use super::ShardReplicaSet;
 use crate::operations::consistency_params::ReadConsistency;
 use crate::operations::types::*;
 use crate::operations::universal_query::shard_query::{ShardQueryRequest, ShardQueryResponse};
+use crate::shards::shard::Shard;
 
 impl ShardReplicaSet {
     #[allow(clippy::too_many_arguments)]
@@ -234,6 +236,61 @@ impl ShardReplicaSet {
         }
     }
 
+    /// Explain how the local replica executes the query.
+    ///
+    /// If this peer has no local replica of the shard, it is listed as unexplained.
+    pub async fn explain_query_local(
+        &self,
+        request: ShardQueryRequest,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Explanation> {
+        let local = self.local.read().await;
+        let Some(local_shard) = local.as_ref().and_then(Shard::local_shard) else {
+            return Ok(self.unexplained());
+        };
+        local_shard
+            .explain_query(
+                self.shard_id,
+                request,
+                &self.search_runtime,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+    }
+
+    /// Explain how the local replica selects the points to count.
+    ///
+    /// If this peer has no local replica of the shard, it is listed as unexplained.
+    pub async fn explain_count_local(
+        &self,
+        filter: Option<&Filter>,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> CollectionResult<Explanation> {
+        let local = self.local.read().await;
+        let Some(local_shard) = local.as_ref().and_then(Shard::local_shard) else {
+            return Ok(self.unexplained());
+        };
+        local_shard
+            .explain_count(
+                self.shard_id,
+                filter,
+                &self.search_runtime,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+    }
+
+    fn unexplained(&self) -> Explanation {
+        Explanation {
+            stages: Vec::new(),
+            unexplained_shards: vec![self.shard_id],
+        }
+    }
+
     pub async fn query_batch(
         &self,
         requests: Arc<Vec<ShardQueryRequest>>,
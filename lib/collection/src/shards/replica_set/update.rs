@@ -1012,6 +1012,7 @@ mod tests {
             strict_mode_config: None,
             uuid: None,
             metadata: None,
+            payload_validation: None,
         };
 
         let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
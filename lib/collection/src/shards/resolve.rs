@@ -169,6 +169,7 @@ impl Resolve for Vec<ShardQueryResponse> {
 fn record_eq(this: &RecordInternal, other: &RecordInternal) -> bool {
     this.id == other.id
         && this.order_value == other.order_value
+        && this.then_by_values == other.then_by_values
         && this.vector == other.vector
         && payload_eq(&this.payload, &other.payload)
 }
@@ -177,6 +178,8 @@ fn scored_point_eq(this: &ScoredPoint, other: &ScoredPoint) -> bool {
     this.id == other.id
         && this.score == other.score
         && this.order_value == other.order_value
+        && this.then_by_values == other.then_by_values
+        && this.stratum == other.stratum
         && this.vector == other.vector
         && payload_eq(&this.payload, &other.payload)
 }
@@ -548,6 +551,8 @@ mod test {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
         }
     }
 
@@ -13,6 +13,7 @@ use crate::config::ShardingMethod;
 use crate::hash_ring::{self, HashRingRouter};
 use crate::operations::CollectionUpdateOperations;
 use crate::operations::cluster_ops::ReshardingDirection;
+use crate::operations::payload_ops::PayloadOps;
 use crate::operations::point_ops::{ConditionalInsertOperationInternal, PointOperations};
 use crate::operations::types::{CollectionError, CollectionResult};
 use crate::shards::replica_set::ShardReplicaSet;
@@ -644,6 +645,7 @@ impl OperationsByMode {
                                 points_op: operation,
                                 condition: Filter::new(), // Always true condition
                                 update_mode: Some(UpdateMode::UpdateOnly),
+                                if_version: None,
                             },
                         ),
                     )]
@@ -655,6 +657,8 @@ impl OperationsByMode {
                                 points_op: operation.points_op,
                                 condition: operation.condition,
                                 update_mode: Some(UpdateMode::UpdateOnly),
+                                // Versions are checked by the shard, which currently owns the points
+                                if_version: None,
                             },
                         ),
                     )]
@@ -665,6 +669,12 @@ impl OperationsByMode {
                         PointOperations::DeletePoints { ids },
                     )]
                 }
+                PointOperations::DeletePointsConditional(op) => {
+                    // Versions are checked by the shard, which currently owns the points
+                    vec![CollectionUpdateOperations::PointOperation(
+                        PointOperations::DeletePoints { ids: op.ids },
+                    )]
+                }
                 PointOperations::DeletePointsByFilter(op) => {
                     vec![CollectionUpdateOperations::PointOperation(
                         PointOperations::DeletePointsByFilter(op),
@@ -676,8 +686,18 @@ impl OperationsByMode {
                     )]
                 }
             },
+            CollectionUpdateOperations::PayloadOperation(mut payload_operation) => {
+                if let PayloadOps::SetPayload(op) | PayloadOps::OverwritePayload(op) =
+                    &mut payload_operation
+                {
+                    // Versions are checked by the shard, which currently owns the points
+                    op.if_version = None;
+                }
+                vec![CollectionUpdateOperations::PayloadOperation(
+                    payload_operation,
+                )]
+            }
             CollectionUpdateOperations::VectorOperation(_)
-            | CollectionUpdateOperations::PayloadOperation(_)
             | CollectionUpdateOperations::FieldIndexOperation(_)
             | CollectionUpdateOperations::VectorNameOperation(_) => {
                 vec![operation]
@@ -10,6 +10,7 @@ use serde::Serialize;
 use uuid::Uuid;
 
 use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
+use crate::operations::payload_validation::PayloadValidationConfig;
 use crate::operations::types::{OptimizersStatus, ReshardingInfo, ShardStatus, ShardTransferInfo};
 use crate::optimizers_builder::OptimizersConfig;
 use crate::shards::replica_set::replica_set_state::ReplicaState;
@@ -41,6 +42,23 @@ pub struct CollectionTelemetry {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
     pub shard_clean_tasks: Option<HashMap<ShardId, ShardCleanStatusTelemetry>>,
+
+    /// Recall of vector indexes in local shards, measured by the background recall monitor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recall: Option<HashMap<VectorNameBuf, VectorRecallTelemetry>>,
+}
+
+#[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
+pub struct VectorRecallTelemetry {
+    /// Fraction of exact nearest neighbours, also found through the index, in the last measurement
+    #[anonymize(false)]
+    pub recall: f64,
+    /// Number of nearest neighbours compared per query
+    pub limit: usize,
+    /// Number of queries in the last measurement
+    pub queries: usize,
+    /// Number of measurements since start
+    pub measurements: usize,
 }
 
 #[derive(Serialize, Clone, Debug, JsonSchema, Anonymize)]
@@ -149,6 +167,9 @@ pub struct CollectionConfigTelemetry {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[anonymize(value = None)]
     pub metadata: Option<Payload>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[anonymize(value = None)]
+    pub payload_validation: Option<PayloadValidationConfig>,
 }
 
 impl From<CollectionConfigInternal> for CollectionConfigTelemetry {
@@ -162,6 +183,7 @@ impl From<CollectionConfigInternal> for CollectionConfigTelemetry {
             strict_mode_config,
             uuid,
             metadata,
+            payload_validation,
         } = config;
         CollectionConfigTelemetry {
             params,
@@ -172,6 +194,7 @@ impl From<CollectionConfigInternal> for CollectionConfigTelemetry {
             strict_mode_config: strict_mode_config.map(StrictModeConfigOutput::from),
             uuid,
             metadata,
+            payload_validation,
         }
     }
 }
@@ -433,6 +456,7 @@ mod internal_conversions {
                 transfers,
                 resharding,
                 shard_clean_tasks,
+                recall: None, // Not provided in internal service
             })
         }
     }
@@ -447,6 +471,7 @@ mod internal_conversions {
                 transfers,
                 resharding,
                 shard_clean_tasks,
+                recall: _,
             } = value;
 
             grpc::CollectionTelemetry {
@@ -36,6 +36,7 @@ fn random_points() -> Vec<PointStructPersisted> {
             id: i.into(),
             vector: VectorStructInternal::from(random_vector(&mut rng, DIM)).into(),
             payload: None,
+            version: None,
         })
         .collect()
 }
@@ -89,6 +90,7 @@ async fn build_shard() -> (LocalShard, TempDir) {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     };
 
     let payload_index_schema_file = collection_dir.path().join("payload.json");
@@ -43,6 +43,7 @@ fn make_upsert_op(point_id: u64) -> CollectionUpdateOperations {
             ])
             .into(),
             payload: None,
+            version: None,
         }]),
     ))
 }
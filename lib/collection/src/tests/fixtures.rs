@@ -52,6 +52,7 @@ pub fn create_collection_config_with_dim(dim: usize) -> CollectionConfigInternal
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     }
 }
 
@@ -67,6 +68,7 @@ pub fn upsert_operation() -> CollectionUpdateOperations {
             payload: Some(
                 serde_json::from_str(r#"{ "location": { "lat": 10.12, "lon": 32.12  } }"#).unwrap(),
             ),
+            version: None,
         },
         PointStructPersisted {
             id: 2.into(),
@@ -74,6 +76,7 @@ pub fn upsert_operation() -> CollectionUpdateOperations {
             payload: Some(
                 serde_json::from_str(r#"{ "location": { "lat": 11.12, "lon": 34.82  } }"#).unwrap(),
             ),
+            version: None,
         },
         PointStructPersisted {
             id: 3.into(),
@@ -81,6 +84,7 @@ pub fn upsert_operation() -> CollectionUpdateOperations {
             payload: Some(
                 serde_json::from_str(r#"{ "location": [ { "lat": 12.12, "lon": 34.82  }, { "lat": 12.2, "lon": 12.82  }] }"#).unwrap(),
             ),
+            version: None,
         },
         PointStructPersisted {
             id: 4.into(),
@@ -88,6 +92,7 @@ pub fn upsert_operation() -> CollectionUpdateOperations {
             payload: Some(
                 serde_json::from_str(r#"{ "location": { "lat": 13.12, "lon": 34.82  } }"#).unwrap(),
             ),
+            version: None,
         },
         PointStructPersisted {
             id: 5.into(),
@@ -95,6 +100,7 @@ pub fn upsert_operation() -> CollectionUpdateOperations {
             payload: Some(
                 serde_json::from_str(r#"{ "location": { "lat": 14.12, "lon": 32.12  } }"#).unwrap(),
             ),
+            version: None,
         },
     ];
 
@@ -130,6 +130,7 @@ fn make_random_points_upsert_op(len: usize) -> CollectionUpdateOperations {
             id: segment::types::ExtendedPointId::NumId(i),
             vector: VectorStructInternal::from(rand_vector).into(),
             payload: None,
+            version: None,
         });
     }
 
@@ -5,7 +5,9 @@ pub mod fixtures;
 mod hw_metrics;
 mod payload;
 mod points_dedup;
+mod quantization_validation_tests;
 mod query_prefetch_offset_limit;
+mod recall_monitor;
 mod sha_256_test;
 mod shard_query;
 mod shard_telemetry;
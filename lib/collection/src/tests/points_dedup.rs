@@ -65,6 +65,7 @@ async fn fixture() -> Collection {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     };
 
     let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
@@ -125,6 +126,7 @@ async fn fixture() -> Collection {
                         "num".to_string(),
                         Value::from(-(shard_id as i32)),
                     )]))),
+                    version: None,
                 },
                 PointStructPersisted {
                     id: DUPLICATE_POINT_ID,
@@ -135,6 +137,7 @@ async fn fixture() -> Collection {
                         "num".to_string(),
                         Value::from(100 - shard_id as i32),
                     )]))),
+                    version: None,
                 },
             ])),
         ));
@@ -0,0 +1,83 @@
+use serde_json::json;
+use validator::Validate;
+
+use crate::config::CollectionParams;
+use crate::operations::types::{VectorParams, VectorsConfig, VectorsConfigDiff};
+
+fn vector_params(value: serde_json::Value) -> VectorParams {
+    serde_json::from_value(value).unwrap()
+}
+
+fn collection_params() -> CollectionParams {
+    serde_json::from_value(json!({
+        "vectors": {
+            "float": { "size": 16, "distance": "Cosine" },
+            "bits": { "size": 16, "distance": "Hamming", "datatype": "bit" },
+        },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_vector_quantization_rejected_for_bit_vectors() {
+    let scalar = json!({ "scalar": { "type": "int8" } });
+
+    let params = vector_params(json!({
+        "size": 16,
+        "distance": "Cosine",
+        "datatype": "bit",
+        "quantization_config": scalar,
+    }));
+    assert!(params.validate().is_err());
+
+    let params = vector_params(json!({
+        "size": 16,
+        "distance": "Jaccard",
+        "quantization_config": scalar,
+    }));
+    assert!(params.validate().is_err());
+
+    let params = vector_params(json!({
+        "size": 16,
+        "distance": "Cosine",
+        "quantization_config": scalar,
+    }));
+    assert!(params.validate().is_ok());
+}
+
+#[test]
+fn test_collection_quantization_rejected_for_bit_vectors() {
+    let params = collection_params();
+    assert!(params.vectors.check_quantization_supported().is_err());
+
+    let float_only = VectorsConfig::Single(vector_params(json!({
+        "size": 16,
+        "distance": "Cosine",
+    })));
+    assert!(float_only.check_quantization_supported().is_ok());
+}
+
+#[test]
+fn test_update_vector_quantization_rejected_for_bit_vectors() {
+    let mut params = collection_params();
+
+    // Nothing is applied if any of the vectors can't be quantized
+    let diff: VectorsConfigDiff = serde_json::from_value(json!({
+        "float": { "on_disk": true, "quantization_config": { "scalar": { "type": "int8" } } },
+        "bits": { "quantization_config": { "scalar": { "type": "int8" } } },
+    }))
+    .unwrap();
+    assert!(params.update_vectors_from_diff(&diff).is_err());
+    assert_eq!(params, collection_params());
+
+    // Disabling quantization is always allowed
+    let diff: VectorsConfigDiff = serde_json::from_value(json!({
+        "bits": { "on_disk": true, "quantization_config": "Disabled" },
+    }))
+    .unwrap();
+    params.update_vectors_from_diff(&diff).unwrap();
+    assert_eq!(
+        params.vectors.get_params("bits").unwrap().on_disk,
+        Some(true),
+    );
+}
@@ -63,6 +63,7 @@ async fn fixture() -> Collection {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     };
 
     let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
@@ -113,6 +114,7 @@ async fn fixture() -> Collection {
                 (0..DIM).map(|_| rng().random_range(0.0..1.0)).collect(),
             ),
             payload: None,
+            version: None,
         })
         .collect();
     let operation = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
@@ -161,6 +163,7 @@ async fn test_limit_offset_with_prefetch() {
                     filter: None,
                     params: None,
                     offset,
+                    start_after: None,
                     limit,
                     with_payload: WithPayloadInterface::Bool(false),
                     with_vector: WithVector::Bool(false),
@@ -218,6 +221,7 @@ async fn test_limit_offset_with_prefetch() {
                     filter: None,
                     params: None,
                     offset,
+                    start_after: None,
                     limit,
                     with_payload: WithPayloadInterface::Bool(false),
                     with_vector: WithVector::Bool(false),
@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use std::time::Duration;
+
+use ahash::AHashMap;
+use common::budget::ResourceBudget;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use common::types::{DetailsLevel, TelemetryDetail};
+use rand::{RngExt, rng};
+use segment::types::{Distance, ExtendedPointId};
+use tempfile::Builder;
+
+use crate::collection::recall_monitor::RecallMonitorConfig;
+use crate::collection::{Collection, RequestShardTransfer};
+use crate::config::{CollectionConfigInternal, CollectionParams, WalConfig};
+use crate::operations::CollectionUpdateOperations;
+use crate::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+    WriteOrdering,
+};
+use crate::operations::shared_storage_config::SharedStorageConfig;
+use crate::operations::types::VectorsConfig;
+use crate::operations::vector_params_builder::VectorParamsBuilder;
+use crate::optimizers_builder::OptimizersConfig;
+use crate::shards::channel_service::ChannelService;
+use crate::shards::collection_shard_distribution::CollectionShardDistribution;
+use crate::shards::replica_set::replica_set_state::ReplicaState;
+use crate::shards::replica_set::{AbortShardTransfer, ChangePeerFromState};
+use crate::shards::shard::{PeerId, ShardId};
+use crate::shards::shard_trait::WaitUntil;
+
+const DIM: u64 = 4;
+const PEER_ID: u64 = 1;
+const SHARD_COUNT: u32 = 2;
+const POINT_COUNT: usize = 200;
+const QUERIES: usize = 5;
+const LIMIT: usize = 5;
+
+/// Search of small shards is exact, so the recall of the plain index is known
+#[tokio::test(flavor = "multi_thread")]
+async fn test_recall_monitor_measures_local_shards() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+    let snapshots_path = Builder::new().prefix("test_snapshots").tempdir().unwrap();
+
+    let config = CollectionConfigInternal {
+        params: CollectionParams {
+            vectors: VectorsConfig::Single(VectorParamsBuilder::new(DIM, Distance::Dot).build()),
+            shard_number: NonZeroU32::new(SHARD_COUNT).unwrap(),
+            replication_factor: NonZeroU32::new(1).unwrap(),
+            write_consistency_factor: NonZeroU32::new(1).unwrap(),
+            ..CollectionParams::empty()
+        },
+        optimizer_config: OptimizersConfig::fixture(),
+        wal_config: WalConfig {
+            wal_capacity_mb: 1,
+            wal_segments_ahead: 0,
+            wal_retain_closed: 1,
+        },
+        hnsw_config: Default::default(),
+        quantization_config: Default::default(),
+        strict_mode_config: Default::default(),
+        uuid: None,
+        metadata: None,
+        payload_validation: None,
+    };
+
+    let storage_config = SharedStorageConfig {
+        recall_monitor: Some(RecallMonitorConfig {
+            interval_sec: 1,
+            queries: QUERIES,
+            limit: LIMIT,
+            cpu_budget: 1.0,
+        }),
+        ..Default::default()
+    };
+
+    let shards: AHashMap<ShardId, HashSet<PeerId>> = (0..SHARD_COUNT)
+        .map(|i| (i, HashSet::from([PEER_ID])))
+        .collect();
+
+    let collection = Collection::new(
+        "test".to_string(),
+        PEER_ID,
+        collection_dir.path(),
+        snapshots_path.path(),
+        &config,
+        Arc::new(storage_config),
+        CollectionShardDistribution { shards },
+        None,
+        ChannelService::default(),
+        dummy_on_replica_failure(),
+        dummy_request_shard_transfer(),
+        dummy_abort_shard_transfer(),
+        None,
+        None,
+        ResourceBudget::default(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    for shard_id in 0..SHARD_COUNT {
+        collection
+            .set_shard_replica_state(shard_id, PEER_ID, ReplicaState::Active, None)
+            .await
+            .expect("failed to activate shard");
+    }
+
+    let points = (0..POINT_COUNT)
+        .map(|i| PointStructPersisted {
+            id: ExtendedPointId::from(i as u64),
+            vector: VectorStructPersisted::Single(
+                (0..DIM).map(|_| rng().random_range(0.0..1.0)).collect(),
+            ),
+            payload: None,
+            version: None,
+        })
+        .collect();
+    let operation = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::PointsList(points),
+    ));
+    collection
+        .update_from_client(
+            operation,
+            WaitUntil::from(true),
+            None,
+            WriteOrdering::Weak,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .expect("failed to insert points");
+
+    let detail = TelemetryDetail::new(DetailsLevel::Level1, false);
+    let mut recall = None;
+    for _ in 0..100 {
+        let telemetry = collection
+            .get_telemetry_data(detail, Duration::from_secs(1))
+            .await
+            .unwrap();
+        recall = telemetry.recall.filter(|recall| {
+            recall
+                .values()
+                .any(|vector| vector.queries == QUERIES * SHARD_COUNT as usize)
+        });
+        if recall.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let recall = recall.expect("recall of all local shards is not measured");
+    let vector = &recall[""];
+    assert_eq!(vector.recall, 1.0);
+    assert_eq!(vector.limit, LIMIT);
+    assert!(vector.measurements >= 1);
+}
+
+fn dummy_on_replica_failure() -> ChangePeerFromState {
+    Arc::new(move |_peer_id, _shard_id, _from_state| {})
+}
+
+fn dummy_request_shard_transfer() -> RequestShardTransfer {
+    Arc::new(move |_transfer| {})
+}
+
+fn dummy_abort_shard_transfer() -> AbortShardTransfer {
+    Arc::new(|_transfer, _reason| {})
+}
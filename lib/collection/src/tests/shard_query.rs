@@ -3,9 +3,10 @@ use std::sync::Arc;
 use common::budget::ResourceBudget;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::save_on_disk::SaveOnDisk;
+use ordered_float::OrderedFloat;
 use segment::common::reciprocal_rank_fusion::DEFAULT_RRF_K;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal};
-use segment::types::{PointIdType, WithPayloadInterface, WithVector};
+use segment::types::{PointIdType, ScoredPoint, WithPayloadInterface, WithVector};
 use shard::query::query_enum::QueryEnum;
 use tempfile::Builder;
 use tokio::runtime::Handle;
@@ -14,7 +15,7 @@ use tokio::sync::RwLock;
 use crate::common::adaptive_handle::AdaptiveSearchHandle;
 use crate::operations::types::CollectionError;
 use crate::operations::universal_query::shard_query::{
-    FusionInternal, ScoringQuery, ShardPrefetch, ShardQueryRequest,
+    FusionInternal, ScorePosition, ScoringQuery, ShardPrefetch, ShardQueryRequest,
 };
 use crate::shards::local_shard::LocalShard;
 use crate::shards::shard_trait::{ShardOperation, WaitUntil};
@@ -74,6 +75,7 @@ async fn test_shard_query_rrf_rescoring() {
         score_threshold: None,
         limit: 0,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
@@ -113,6 +115,7 @@ async fn test_shard_query_rrf_rescoring() {
         score_threshold: None,
         limit: outer_limit,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
@@ -163,6 +166,7 @@ async fn test_shard_query_rrf_rescoring() {
         score_threshold: None,
         limit: outer_limit,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
@@ -210,6 +214,7 @@ async fn test_shard_query_rrf_rescoring() {
         score_threshold: None,
         limit: outer_limit,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
@@ -298,6 +303,7 @@ async fn test_shard_query_vector_rescoring() {
         score_threshold: None,
         limit: outer_limit,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
@@ -325,6 +331,7 @@ async fn test_shard_query_vector_rescoring() {
         score_threshold: None,
         limit: outer_limit,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
@@ -355,6 +362,7 @@ async fn test_shard_query_vector_rescoring() {
         score_threshold: None,
         limit: outer_limit,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(false),
         with_payload: WithPayloadInterface::Bool(false),
@@ -432,6 +440,7 @@ async fn test_shard_query_payload_vector() {
         score_threshold: None,
         limit: outer_limit,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(true), // requesting vector
         with_payload: WithPayloadInterface::Bool(true), // requesting payload
@@ -455,3 +464,103 @@ async fn test_shard_query_payload_vector() {
         assert!(scored_point.payload.is_some());
     });
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_shard_query_start_after() {
+    let collection_dir = Builder::new().prefix("test_collection").tempdir().unwrap();
+
+    let config = create_collection_config();
+
+    let collection_name = "test".to_string();
+
+    let update_runtime = Handle::current();
+    let current_runtime: AdaptiveSearchHandle = AdaptiveSearchHandle::current_for_tests();
+
+    let payload_index_schema_dir = Builder::new().prefix("qdrant-test").tempdir().unwrap();
+    let payload_index_schema_file = payload_index_schema_dir.path().join("payload-schema.json");
+    let payload_index_schema =
+        Arc::new(SaveOnDisk::load_or_init_default(payload_index_schema_file).unwrap());
+
+    let shard = LocalShard::build(
+        0,
+        collection_name.clone(),
+        collection_dir.path(),
+        Arc::new(RwLock::new(config.clone())),
+        Arc::new(Default::default()),
+        payload_index_schema,
+        update_runtime.clone(),
+        current_runtime.clone(),
+        ResourceBudget::default(),
+        config.optimizer_config.clone(),
+    )
+    .await
+    .unwrap();
+
+    let upsert_ops = upsert_operation();
+
+    shard
+        .update(
+            upsert_ops.into(),
+            WaitUntil::Visible,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let query = |limit, offset, start_after| ShardQueryRequest {
+        prefetches: vec![],
+        query: Some(ScoringQuery::Vector(QueryEnum::Nearest(NamedQuery::new(
+            VectorInternal::Dense(vec![1.0, 2.0, 3.0, 4.0]),
+            DEFAULT_VECTOR_NAME,
+        )))),
+        filter: None,
+        score_threshold: None,
+        limit,
+        offset,
+        start_after,
+        params: None,
+        with_vector: WithVector::Bool(false),
+        with_payload: WithPayloadInterface::Bool(false),
+    };
+
+    let all_points = shard
+        .query_batch(
+            Arc::new(vec![query(5, 0, None)]),
+            &current_runtime,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
+        .pop()
+        .unwrap();
+    assert_eq!(all_points.len(), 5);
+
+    let position = ScorePosition {
+        score: OrderedFloat(all_points[1].score),
+        id: all_points[1].id,
+    };
+
+    let page = shard
+        .query_batch(
+            Arc::new(vec![
+                query(2, 0, Some(position)),
+                query(5, 0, Some(position)),
+            ]),
+            &current_runtime,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let ids = |points: &[ScoredPoint]| points.iter().map(|point| point.id).collect::<Vec<_>>();
+
+    // Only points after the position
+    assert_eq!(ids(&page[0][0]), ids(&all_points[2..4]));
+    // Fewer points only at the end of the results
+    assert_eq!(ids(&page[1][0]), ids(&all_points[2..]));
+}
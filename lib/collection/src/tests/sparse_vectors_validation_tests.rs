@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 
 use api::rest::{
-    BaseGroupRequest, Batch, BatchVectorStruct, PointStruct, PointVectors, PointsList,
-    SearchGroupsRequestInternal, SearchRequestInternal, Vector, VectorStruct,
+    BaseGroupRequest, Batch, BatchVectorStruct, GroupByInterface, PointStruct, PointVectors,
+    PointsList, SearchGroupsRequestInternal, SearchRequestInternal, Vector, VectorStruct,
 };
 use segment::types::VectorNameBuf;
 use sparse::common::sparse_vector::SparseVector;
@@ -71,6 +71,7 @@ fn validate_error_sparse_vector_points_list() {
         shard_key: None,
         update_filter: None,
         update_mode: None,
+        if_version: None,
     });
 }
 
@@ -98,10 +99,11 @@ fn validate_error_sparse_vector_search_groups_request_internal() {
         with_vector: None,
         score_threshold: None,
         group_request: BaseGroupRequest {
-            group_by: "sparse".parse().unwrap(),
+            group_by: GroupByInterface::Field("sparse".parse().unwrap()),
             group_size: 5,
             limit: 5,
             with_lookup: None,
+            subgroups: None,
         },
     });
 }
@@ -323,6 +323,7 @@ async fn test_truncate_unapplied_wal() {
             id: i.into(),
             vector: VectorStructInternal::from(vec![i as f32, 2.0, 3.0, 4.0]).into(),
             payload: None,
+            version: None,
         };
         let op = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
             PointInsertOperationsInternal::PointsList(vec![point]),
@@ -402,6 +403,7 @@ async fn test_truncate_unapplied_wal() {
         id: 99999.into(),
         vector: VectorStructInternal::from(vec![99.0, 99.0, 99.0, 99.0]).into(),
         payload: None,
+        version: None,
     };
     let op = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
         PointInsertOperationsInternal::PointsList(vec![new_point]),
@@ -478,6 +480,7 @@ async fn test_wal_replay_loads_pending_to_queue() {
             id: i.into(),
             vector: VectorStructInternal::from(vec![1.0, 2.0, 3.0, 4.0]).into(),
             payload: None,
+            version: None,
         };
         let op = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
             PointInsertOperationsInternal::PointsList(vec![point]),
@@ -634,6 +637,7 @@ async fn test_wal_replay_with_smaller_queue_size() {
             id: i.into(),
             vector: VectorStructInternal::from(vec![1.0, 2.0, 3.0, 4.0]).into(),
             payload: None,
+            version: None,
         };
         let op = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
             PointInsertOperationsInternal::PointsList(vec![point]),
@@ -1,3 +1,4 @@
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, BufWriter};
 
@@ -19,7 +20,7 @@ use collection::shards::replica_set::replica_set_state::{ReplicaSetState, Replic
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use fs_err::File;
 use itertools::Itertools;
-use segment::data_types::order_by::{Direction, OrderBy, OrderByInterface};
+use segment::data_types::order_by::{Direction, OrderBy, OrderByCursor, OrderByInterface, ThenBy};
 use segment::data_types::vectors::VectorStructInternal;
 use segment::types::{
     Condition, ExtendedPointId, FieldCondition, Filter, HasIdCondition, Payload,
@@ -260,6 +261,7 @@ async fn test_collection_loading_with_shards(shard_number: u32) {
                 points: Some(vec![2.into(), 3.into()]),
                 filter: None,
                 key: None,
+                if_version: None,
             }));
 
         let hw_counter = HwMeasurementAcc::new();
@@ -344,11 +346,13 @@ fn test_deserialization2() {
             id: 0.into(),
             vector: VectorStructPersisted::from(vec![1.0, 0.0, 1.0, 1.0]),
             payload: None,
+            version: None,
         },
         PointStructPersisted {
             id: 1.into(),
             vector: VectorStructPersisted::from(vec![1.0, 0.0, 1.0, 0.0]),
             payload: None,
+            version: None,
         },
     ];
 
@@ -625,6 +629,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Asc),
                         start_from: None,
+                        then_by: Vec::new(),
+                        start_after: None,
                     })),
                 },
                 None,
@@ -657,6 +663,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Desc),
                         start_from: None,
+                        then_by: Vec::new(),
+                        start_after: None,
                     })),
                 },
                 None,
@@ -698,6 +706,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Asc),
                         start_from: None,
+                        then_by: Vec::new(),
+                        start_after: None,
                     })),
                 },
                 None,
@@ -738,6 +748,8 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
                         key: key.parse().unwrap(),
                         direction: Some(Direction::Desc),
                         start_from: None,
+                        then_by: Vec::new(),
+                        start_after: None,
                     })),
                 },
                 None,
@@ -802,6 +814,122 @@ async fn test_ordered_scroll_api_with_shards(shard_number: u32) {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_ordered_read_then_by() {
+    test_ordered_read_then_by_with_shards(1).await;
+    test_ordered_read_then_by_with_shards(N_SHARDS).await;
+}
+
+async fn test_ordered_read_then_by_with_shards(shard_number: u32) {
+    let collection_dir = Builder::new().prefix("collection").tempdir().unwrap();
+    let collection = simple_collection_fixture(collection_dir.path(), shard_number).await;
+
+    const NUM_POINTS: u64 = 30;
+    const PAGE_SIZE: usize = 4;
+    const PRIORITY_KEY: &str = "priority";
+    const RANK_KEY: &str = "rank";
+
+    // Few values of the key, so ties are spread over shards and pages
+    let priority = |id: u64| (id % 3) as i64;
+    let rank = |id: u64| (id % 4 != 0).then_some(((id * 7) % 5) as i64);
+
+    let payloads = (0..NUM_POINTS)
+        .map(|id| {
+            let mut payload_map = Map::new();
+            payload_map.insert(PRIORITY_KEY.to_string(), priority(id).into());
+            if let Some(rank) = rank(id) {
+                payload_map.insert(RANK_KEY.to_string(), rank.into());
+            }
+            Some(Payload(payload_map))
+        })
+        .collect();
+
+    let batch = BatchPersisted {
+        ids: (0..NUM_POINTS).map(u64::into).collect_vec(),
+        vectors: BatchVectorStructPersisted::Single(vec![
+            vec![1.0, 0.0, 0.0, 0.0];
+            NUM_POINTS as usize
+        ]),
+        payloads: Some(payloads),
+    };
+
+    let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::from(batch),
+    ));
+
+    let hw_counter = HwMeasurementAcc::new();
+    collection
+        .update_from_client_simple(
+            insert_points,
+            true,
+            None,
+            WriteOrdering::default(),
+            hw_counter.clone(),
+        )
+        .await
+        .unwrap();
+
+    collection
+        .create_payload_index_with_wait(
+            PRIORITY_KEY.parse().unwrap(),
+            PayloadFieldSchema::FieldType(PayloadSchemaType::Integer),
+            true,
+            hw_counter,
+        )
+        .await
+        .unwrap();
+
+    // Descending priority, then ascending rank with missing ranks last, then ids
+    let mut expected = (0..NUM_POINTS).collect_vec();
+    expected.sort_by_key(|&id| (Reverse(priority(id)), rank(id).is_none(), rank(id), id));
+    let expected = expected.into_iter().map(PointIdType::from).collect_vec();
+
+    let mut order_by = OrderBy {
+        key: PRIORITY_KEY.parse().unwrap(),
+        direction: Some(Direction::Desc),
+        start_from: None,
+        then_by: vec![ThenBy {
+            key: RANK_KEY.parse().unwrap(),
+            direction: Some(Direction::Asc),
+        }],
+        start_after: None,
+    };
+
+    let mut ids = Vec::new();
+    loop {
+        let page = collection
+            .scroll_by(
+                ScrollRequestInternal {
+                    offset: None,
+                    limit: Some(PAGE_SIZE),
+                    filter: None,
+                    with_payload: Some(WithPayloadInterface::Bool(false)),
+                    with_vector: false.into(),
+                    order_by: Some(OrderByInterface::Struct(order_by.clone())),
+                },
+                None,
+                &ShardSelectorInternal::All,
+                None,
+                HwMeasurementAcc::new(),
+            )
+            .await
+            .unwrap();
+
+        assert!(page.points.len() <= PAGE_SIZE);
+        let Some(last) = page.points.last() else {
+            break;
+        };
+        order_by.start_after = Some(OrderByCursor {
+            value: last.order_value.clone().unwrap(),
+            then_by: last.then_by_values.clone(),
+            id: last.id,
+        });
+        ids.extend(page.points.iter().map(|point| point.id));
+    }
+
+    assert_eq!(ids, expected);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_collection_delete_points_by_filter() {
     test_collection_delete_points_by_filter_with_shards(1).await;
@@ -58,6 +58,7 @@ pub async fn simple_collection_fixture(collection_path: &Path, shard_number: u32
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     };
 
     let snapshot_path = collection_path.join("snapshots");
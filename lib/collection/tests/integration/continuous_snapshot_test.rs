@@ -54,6 +54,7 @@ async fn test_continuous_snapshot() {
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     };
 
     let snapshots_path = Builder::new().prefix("test_snapshots").tempdir().unwrap();
@@ -132,6 +133,7 @@ async fn test_continuous_snapshot() {
                         id: i.into(),
                         vector: VectorStructPersisted::Single(vec![i as f32, 0.0, 0.0, 0.0]),
                         payload: Some(serde_json::from_str(r#"{"number": "John Doe"}"#).unwrap()),
+                        version: None,
                     };
                     let insert_points =
                         CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
@@ -181,6 +183,7 @@ async fn test_continuous_snapshot() {
                             points: Some(vec![i.into()]),
                             filter: None,
                             key: None,
+                            if_version: None,
                         }),
                     );
                     let hw_counter = HwMeasurementAcc::new();
@@ -0,0 +1,129 @@
+use api::rest::SearchRequestInternal;
+use api::rest::models::Explanation;
+use collection::operations::CollectionUpdateOperations;
+use collection::operations::point_ops::{
+    PointInsertOperationsInternal, PointOperations, PointStructPersisted, VectorStructPersisted,
+    WriteOrdering,
+};
+use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
+use segment::data_types::explain::SearchStrategy;
+use segment::types::{Condition, FieldCondition, Filter, SearchParams};
+use tempfile::Builder;
+
+use crate::common::{N_SHARDS, simple_collection_fixture};
+
+const NUM_POINTS: u64 = 100;
+
+fn assert_strategy(explanation: &Explanation, expected: SearchStrategy) {
+    for stage in &explanation.stages {
+        for shard in &stage.shards {
+            for segment in &shard.segments {
+                assert_eq!(segment.explanation.strategy, expected);
+            }
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_explain_search_and_count() {
+    let collection_dir = Builder::new()
+        .prefix("test_explain_search_and_count")
+        .tempdir()
+        .unwrap();
+
+    let collection = simple_collection_fixture(collection_dir.path(), N_SHARDS).await;
+
+    let points = (0..NUM_POINTS)
+        .map(|i| PointStructPersisted {
+            id: i.into(),
+            vector: VectorStructPersisted::Single(vec![i as f32, 0.0, 0.0, 0.0]),
+            payload: Some(serde_json::from_str(&format!(r#"{{"parity": "{}"}}"#, i % 2)).unwrap()),
+            version: None,
+        })
+        .collect();
+    let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::PointsList(points),
+    ));
+    collection
+        .update_from_client_simple(
+            insert_points,
+            true,
+            None,
+            WriteOrdering::default(),
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let search_request = |params| SearchRequestInternal {
+        vector: vec![1.0, 0.0, 0.0, 0.0].into(),
+        filter: None,
+        limit: 10,
+        offset: None,
+        with_payload: None,
+        with_vector: None,
+        params,
+        score_threshold: None,
+    };
+
+    // Strategy is reported by the search, which was executed in each segment
+    let exact_params = SearchParams {
+        exact: true,
+        ..Default::default()
+    };
+    let explanation = collection
+        .explain_search(
+            search_request(Some(exact_params)).into(),
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    assert!(explanation.unexplained_shards.is_empty());
+    assert_eq!(explanation.stages.len(), 1);
+    assert_eq!(explanation.stages[0].shards.len(), N_SHARDS as usize);
+    assert_strategy(&explanation, SearchStrategy::Exact);
+
+    // Each shard returns a top of its own
+    for shard in &explanation.stages[0].shards {
+        let segments_found: usize = shard.segments.iter().map(|segment| segment.found).sum();
+        assert_eq!(shard.found, segments_found.min(10));
+    }
+
+    let explanation = collection
+        .explain_search(
+            search_request(None).into(),
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+    assert_strategy(&explanation, SearchStrategy::FullScan);
+
+    // Points of a count are not limited by a page
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        "parity".parse().unwrap(),
+        serde_json::from_str(r#"{ "value": "0" }"#).unwrap(),
+    )));
+    let explanation = collection
+        .explain_count(
+            Some(&filter),
+            &ShardSelectorInternal::All,
+            None,
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    assert!(explanation.unexplained_shards.is_empty());
+    let found: usize = explanation.stages[0]
+        .shards
+        .iter()
+        .map(|shard| shard.found)
+        .sum();
+    assert_eq!(found, NUM_POINTS as usize / 2);
+}
@@ -3,6 +3,7 @@ mod collection_test;
 mod common;
 mod continuous_snapshot_test;
 mod distance_matrix_test;
+mod explain_test;
 mod grouping_test;
 mod lookup_test;
 mod multi_vec_test;
@@ -66,6 +66,7 @@ pub async fn multi_vec_collection_fixture(collection_path: &Path, shard_number:
         strict_mode_config: Default::default(),
         uuid: None,
         metadata: None,
+        payload_validation: None,
     };
 
     let snapshot_path = collection_path.join("snapshots");
@@ -106,6 +107,7 @@ async fn test_multi_vec_with_shards(shard_number: u32) {
             id: i.into(),
             vector: VectorStructPersisted::from(VectorStructInternal::from(vectors)),
             payload: Some(serde_json::from_str(r#"{"number": "John Doe"}"#).unwrap()),
+            version: None,
         });
     }
     let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
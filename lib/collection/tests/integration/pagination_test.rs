@@ -5,8 +5,14 @@ use collection::operations::point_ops::{
     WriteOrdering,
 };
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use collection::operations::universal_query::shard_query::query_enum::QueryEnum;
+use collection::operations::universal_query::shard_query::{
+    ScorePosition, ScoringQuery, ShardQueryRequest,
+};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use segment::types::WithPayloadInterface;
+use ordered_float::OrderedFloat;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal};
+use segment::types::{WithPayloadInterface, WithVector};
 use tempfile::Builder;
 
 use crate::common::{N_SHARDS, simple_collection_fixture};
@@ -32,6 +38,7 @@ async fn test_collection_paginated_search_with_shards(shard_number: u32) {
             id: i.into(),
             vector: VectorStructPersisted::Single(vec![i as f32, 0.0, 0.0, 0.0]),
             payload: Some(serde_json::from_str(r#"{"number": "John Doe"}"#).unwrap()),
+            version: None,
         });
     }
     let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
@@ -137,3 +144,86 @@ async fn test_collection_paginated_search_with_shards(shard_number: u32) {
         assert_eq!(page_9_result[i], reference_result[page_size * 9 + i]);
     }
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_collection_cursor_pagination_with_ties() {
+    let collection_dir = Builder::new()
+        .prefix("test_collection_cursor_pagination_with_ties")
+        .tempdir()
+        .unwrap();
+
+    let collection = simple_collection_fixture(collection_dir.path(), 2).await;
+
+    // Only 4 distinct scores, so most pages end in the middle of a tie
+    let num_points: u64 = 200;
+    let points = (0..num_points)
+        .map(|i| PointStructPersisted {
+            id: i.into(),
+            vector: VectorStructPersisted::Single(vec![(i % 4) as f32, 1.0, 0.0, 0.0]),
+            payload: None,
+            version: None,
+        })
+        .collect();
+    let insert_points = CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(
+        PointInsertOperationsInternal::PointsList(points),
+    ));
+    collection
+        .update_from_client_simple(
+            insert_points,
+            true,
+            None,
+            WriteOrdering::default(),
+            HwMeasurementAcc::new(),
+        )
+        .await
+        .unwrap();
+
+    let page_size = 7;
+    let query = |start_after| ShardQueryRequest {
+        prefetches: vec![],
+        query: Some(ScoringQuery::Vector(QueryEnum::Nearest(NamedQuery::new(
+            VectorInternal::Dense(vec![1.0, 0.0, 0.0, 0.0]),
+            DEFAULT_VECTOR_NAME,
+        )))),
+        filter: None,
+        score_threshold: None,
+        limit: page_size,
+        offset: 0,
+        start_after,
+        params: None,
+        with_vector: WithVector::Bool(false),
+        with_payload: WithPayloadInterface::Bool(false),
+    };
+
+    let mut all_points = Vec::new();
+    let mut start_after = None;
+    loop {
+        let page = collection
+            .query(
+                query(start_after),
+                None,
+                ShardSelectorInternal::All,
+                None,
+                HwMeasurementAcc::new(),
+            )
+            .await
+            .unwrap();
+        all_points.extend(page.iter().map(|point| (point.id, point.score)));
+
+        let Some(last) = page.last().filter(|_| page.len() == page_size) else {
+            break;
+        };
+        start_after = Some(ScorePosition {
+            score: OrderedFloat(last.score),
+            id: last.id,
+        });
+    }
+
+    // Every point is returned exactly once, in the order of scores
+    assert!(all_points.is_sorted_by(|a, b| a.1 >= b.1));
+    let mut ids: Vec<_> = all_points.iter().map(|(id, _)| *id).collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(all_points.len(), num_points as usize);
+    assert_eq!(ids.len(), num_points as usize);
+}
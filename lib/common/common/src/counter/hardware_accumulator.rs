@@ -142,6 +142,18 @@ impl HwMeasurementAcc {
         }
     }
 
+    /// Returns an accumulator, which reports to the same request as this one,
+    /// but accumulates metrics to the given drain.
+    /// Useful if a single request touches multiple collections.
+    pub fn with_metrics_drain(&self, metrics_drain: Arc<HwSharedDrain>) -> Self {
+        Self {
+            request_drain: self.request_drain.clone(),
+            metrics_drain,
+            disposable: self.disposable,
+            cpu_utilization: self.cpu_utilization.clone(),
+        }
+    }
+
     pub fn cpu_utilization(&self) -> CpuUtilization {
         self.cpu_utilization.clone()
     }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Tracks CPU utilization across multiple `spawn_blocking` tasks for a single request.
 ///
@@ -49,6 +49,13 @@ impl CpuUtilization {
         result
     }
 
+    /// Total thread CPU time of the measured closures.
+    ///
+    /// Always zero on platforms, where thread CPU time is not available.
+    pub fn cpu_time(&self) -> Duration {
+        Duration::from_nanos(self.inner.cpu_time_ns.load(Ordering::Relaxed))
+    }
+
     /// Returns CPU utilization ratio: `cpu_time / wall_time`, clamped to `[0.0, 1.0]`.
     ///
     /// 1.0 means pure CPU-bound, 0.0 means pure IO-bound (or no measurements).
@@ -37,6 +37,9 @@ pub fn load_new_shard() -> Result<EdgeShard, Box<dyn Error>> {
                 datatype: None,
                 on_disk: None,
                 hnsw_config: None,
+                matryoshka_dim: None,
+                vamana_config: None,
+                ivf_config: None,
             },
         )]),
         sparse_vectors: HashMap::new(),
@@ -18,7 +18,16 @@ Payload = Dict[str, Any]
 JsonPath = str
 WithPayloadType = Union[bool, List[str], "PayloadSelector"]
 WithVectorType = Union[bool, List[str]]
-ScoringQueryType = Union["Query", "Fusion", "OrderBy", "Formula", "Sample", "Mmr"]
+ScoringQueryType = Union[
+    "Query",
+    "Fusion",
+    "OrderBy",
+    "Formula",
+    "Sample",
+    "StratifiedSample",
+    "WeightedSample",
+    "Mmr",
+]
 ConditionType = Union[
     "FieldCondition",
     "IsEmptyCondition",
@@ -31,14 +40,14 @@ ConditionType = Union[
 MatchType = Union[
     "MatchValue", "MatchText", "MatchTextAny", "MatchPhrase", "MatchAny", "MatchExcept"
 ]
-RangeType = Union["RangeFloat", "RangeDateTime"]
+RangeType = Union["RangeFloat", "RangeDateTime", "RangeKeyword"]
 QuantizationConfigType = Union[
     "ScalarQuantizationConfig",
     "ProductQuantizationConfig",
     "BinaryQuantizationConfig",
     "TurboQuantQuantizationConfig",
 ]
-IndexType = Union["PlainIndexConfig", "HnswIndexConfig"]
+IndexType = Union["PlainIndexConfig", "HnswIndexConfig", "VamanaIndexConfig", "IvfIndexConfig"]
 StartFromType = Union[int, float, str]
 ExpressionType = "Expression"
 
@@ -310,6 +319,9 @@ class EdgeVectorParams:
         datatype: Optional["VectorStorageDatatype"] = None,
         quantization_config: Optional[QuantizationConfigType] = None,
         hnsw_config: Optional["HnswIndexConfig"] = None,
+        vamana_config: Optional["VamanaIndexConfig"] = None,
+        ivf_config: Optional["IvfIndexConfig"] = None,
+        matryoshka_dim: Optional[int] = None,
     ) -> None:
         """
         Create EdgeVectorParams.
@@ -322,6 +334,11 @@ class EdgeVectorParams:
             datatype: Optional storage datatype.
             quantization_config: Optional per-vector quantization override.
             hnsw_config: Optional per-vector HNSW config override.
+            vamana_config: Optional disk-resident Vamana index config, used instead of HNSW.
+            ivf_config: Optional IVF index config, used instead of HNSW.
+            matryoshka_dim: Optional number of leading dimensions of Matryoshka embeddings
+                to index separately. Nearest queries with the `matryoshka` search param
+                are scored on the first dimensions of stored vectors.
         """
         ...
 
@@ -360,6 +377,21 @@ class EdgeVectorParams:
         """HNSW config override."""
         ...
 
+    @property
+    def vamana_config(self) -> Optional["VamanaIndexConfig"]:
+        """Vamana index config."""
+        ...
+
+    @property
+    def ivf_config(self) -> Optional["IvfIndexConfig"]:
+        """IVF index config."""
+        ...
+
+    @property
+    def matryoshka_dim(self) -> Optional[int]:
+        """Number of leading dimensions indexed separately."""
+        ...
+
 class EdgeSparseVectorParams:
     """Sparse vector parameters for EdgeConfig."""
 
@@ -525,15 +557,116 @@ class HnswIndexConfig:
         """Inline storage flag."""
         ...
 
+class VamanaIndexConfig:
+    """Configuration for disk-resident Vamana graph index."""
+
+    def __init__(
+        self,
+        max_degree: int = 64,
+        build_list_size: int = 128,
+        beam_width: int = 4,
+        compression: "CompressionRatio" = CompressionRatio.X16,
+        full_scan_threshold: int = 10000,
+        max_indexing_threads: int = 0,
+    ) -> None:
+        """
+        Create a VamanaIndexConfig.
+
+        Args:
+            max_degree: Maximal number of edges per node.
+            build_list_size: Number of candidates during index construction.
+            beam_width: Number of nodes read from disk at once during search.
+            compression: Compression ratio of vectors kept in RAM.
+            full_scan_threshold: Threshold for full scan.
+            max_indexing_threads: Max threads for indexing (0 = auto).
+        """
+        ...
+
+    @property
+    def max_degree(self) -> int:
+        """Maximal number of edges per node."""
+        ...
+
+    @property
+    def build_list_size(self) -> int:
+        """Number of candidates during index construction."""
+        ...
+
+    @property
+    def beam_width(self) -> int:
+        """Number of nodes read from disk at once."""
+        ...
+
+    @property
+    def compression(self) -> "CompressionRatio":
+        """Compression ratio."""
+        ...
+
+    @property
+    def full_scan_threshold(self) -> int:
+        """Full scan threshold."""
+        ...
+
+    @property
+    def max_indexing_threads(self) -> int:
+        """Max indexing threads (0 = auto)."""
+        ...
+
+class IvfIndexConfig:
+    """Configuration for inverted file (IVF) index."""
+
+    def __init__(
+        self,
+        num_lists: Optional[int] = None,
+        nprobe: int = 16,
+        full_scan_threshold: int = 10000,
+        max_indexing_threads: int = 0,
+    ) -> None:
+        """
+        Create an IvfIndexConfig.
+
+        Args:
+            num_lists: Number of lists to partition vectors into (None = square root of vector count).
+            nprobe: Number of lists to scan during search.
+            full_scan_threshold: Threshold for full scan.
+            max_indexing_threads: Max threads for indexing (0 = auto).
+        """
+        ...
+
+    @property
+    def num_lists(self) -> Optional[int]:
+        """Number of lists."""
+        ...
+
+    @property
+    def nprobe(self) -> int:
+        """Number of lists to scan during search."""
+        ...
+
+    @property
+    def full_scan_threshold(self) -> int:
+        """Full scan threshold."""
+        ...
+
+    @property
+    def max_indexing_threads(self) -> int:
+        """Max indexing threads (0 = auto)."""
+        ...
+
 class MultiVectorConfig:
     """Configuration for multi-vector storage."""
 
-    def __init__(self, comparator: "MultiVectorComparator") -> None:
+    def __init__(
+        self,
+        comparator: "MultiVectorComparator",
+        fde: Optional["FdeConfig"] = None,
+    ) -> None:
         """
         Create a MultiVectorConfig.
 
         Args:
             comparator: Multi-vector comparator.
+            fde: If set, HNSW index is built on fixed-dimensional encodings of multivectors.
         """
         ...
 
@@ -542,6 +675,52 @@ class MultiVectorConfig:
         """Comparator."""
         ...
 
+    @property
+    def fde(self) -> Optional["FdeConfig"]:
+        """Fixed-dimensional encoding configuration."""
+        ...
+
+class FdeConfig:
+    """Configuration of fixed-dimensional encoding (MUVERA) of multivectors."""
+
+    def __init__(
+        self,
+        ksim: int = 4,
+        dim_proj: int = 16,
+        repetitions: int = 10,
+        candidates_factor: int = 4,
+    ) -> None:
+        """
+        Create an FdeConfig.
+
+        Args:
+            ksim: Number of SimHash hyperplanes, splits the space into 2^ksim buckets.
+            dim_proj: Size of the random projection of each bucket.
+            repetitions: Number of independent repetitions of the encoding.
+            candidates_factor: Candidates retrieved with the encoding per requested result.
+        """
+        ...
+
+    @property
+    def ksim(self) -> int:
+        """Number of SimHash hyperplanes."""
+        ...
+
+    @property
+    def dim_proj(self) -> int:
+        """Size of the random projection of each bucket."""
+        ...
+
+    @property
+    def repetitions(self) -> int:
+        """Number of repetitions."""
+        ...
+
+    @property
+    def candidates_factor(self) -> int:
+        """Candidates retrieved with the encoding per requested result."""
+        ...
+
 # ============================================================================
 # Quantization Configuration
 # ============================================================================
@@ -587,6 +766,7 @@ class ProductQuantizationConfig:
         self,
         compression: "CompressionRatio",
         always_ram: Optional[bool] = None,
+        opq: Optional[bool] = None,
     ) -> None:
         """
         Create a ProductQuantizationConfig.
@@ -594,6 +774,7 @@ class ProductQuantizationConfig:
         Args:
             compression: Compression ratio.
             always_ram: Whether to keep in RAM.
+            opq: Whether to learn an OPQ rotation before quantization.
         """
         ...
 
@@ -607,6 +788,11 @@ class ProductQuantizationConfig:
         """Always RAM flag."""
         ...
 
+    @property
+    def opq(self) -> Optional[bool]:
+        """OPQ rotation flag."""
+        ...
+
 class BinaryQuantizationConfig:
     """Configuration for binary quantization."""
 
@@ -686,6 +872,8 @@ class Distance(Enum):
     Euclid = ...
     Dot = ...
     Manhattan = ...
+    Hamming = ...
+    Jaccard = ...
 
 class VectorStorageDatatype(Enum):
     """Vector storage data types."""
@@ -693,16 +881,22 @@ class VectorStorageDatatype(Enum):
     Float32 = ...
     Float16 = ...
     Uint8 = ...
+    Bit = ...
 
 class MultiVectorComparator(Enum):
     """Multi-vector comparison methods."""
 
     MaxSim = ...
+    Chamfer = ...
+    MeanSim = ...
+    MaxMax = ...
+    MeanPool = ...
 
 class ScalarType(Enum):
     """Scalar quantization types."""
 
     Int8 = ...
+    Int4 = ...
 
 class CompressionRatio(Enum):
     """Product quantization compression ratios."""
@@ -895,10 +1089,20 @@ class ScoredPoint:
         ...
 
     @property
-    def order_value(self) -> Optional[Union[int, float]]:
+    def order_value(self) -> Optional[Union[int, float, str]]:
         """Order value for order_by queries."""
         ...
 
+    @property
+    def then_by_values(self) -> List[Optional[Union[int, float, str]]]:
+        """Values of `then_by` keys for order_by queries."""
+        ...
+
+    @property
+    def stratum(self) -> Optional[Union[bool, int, str]]:
+        """Value of the field, which the point was drawn for by a stratified sample."""
+        ...
+
 class Record:
     """A retrieved point record."""
 
@@ -918,10 +1122,20 @@ class Record:
         ...
 
     @property
-    def order_value(self) -> Optional[Union[int, float]]:
+    def order_value(self) -> Optional[Union[int, float, str]]:
         """Order value for order_by queries."""
         ...
 
+    @property
+    def then_by_values(self) -> List[Optional[Union[int, float, str]]]:
+        """Values of `then_by` keys for order_by queries."""
+        ...
+
+    @property
+    def version(self) -> Optional[int]:
+        """Number of writes made to the point, if point versions are enabled."""
+        ...
+
 class ShardInfo:
     """Information about a shard."""
 
@@ -976,6 +1190,7 @@ PayloadSchemaParams = Union[
     "BoolIndexParams",
     "DatetimeIndexParams",
     "UuidIndexParams",
+    "CompositeIndexParams",
 ]
 
 class KeywordIndexParams:
@@ -1216,6 +1431,79 @@ class UuidIndexParams:
         """Whether to enable HNSW index."""
         ...
 
+class CompositeIndexParams:
+    """Index parameters for a composite key over several fields, starting with the indexed one."""
+
+    def __init__(
+        self,
+        field_type: "CompositeFieldType",
+        fields: List["CompositeIndexField"],
+        on_disk: Optional[bool] = None,
+        enable_hnsw: Optional[bool] = None,
+    ) -> None:
+        """
+        Create CompositeIndexParams.
+
+        Args:
+            field_type: Type of values of the indexed field.
+            fields: Other fields of the composite key, in order.
+            on_disk: Whether to store the regular index of the indexed field on disk.
+            enable_hnsw: Whether to enable HNSW index for this field.
+        """
+        ...
+
+    @property
+    def field_type(self) -> "CompositeFieldType":
+        """Type of values of the indexed field."""
+        ...
+
+    @property
+    def fields(self) -> List["CompositeIndexField"]:
+        """Other fields of the composite key, in order."""
+        ...
+
+    @property
+    def on_disk(self) -> Optional[bool]:
+        """Whether to store the regular index of the indexed field on disk."""
+        ...
+
+    @property
+    def enable_hnsw(self) -> Optional[bool]:
+        """Whether to enable HNSW index."""
+        ...
+
+class CompositeIndexField:
+    """Field of a composite key."""
+
+    def __init__(self, key: str, type: "CompositeFieldType") -> None:
+        """
+        Create CompositeIndexField.
+
+        Args:
+            key: Payload key of the field.
+            type: Type of values of the field.
+        """
+        ...
+
+    @property
+    def key(self) -> str:
+        """Payload key of the field."""
+        ...
+
+    @property
+    def type(self) -> "CompositeFieldType":
+        """Type of values of the field."""
+        ...
+
+class CompositeFieldType(Enum):
+    """Types of values of composite key fields."""
+
+    Keyword = ...
+    Integer = ...
+    Float = ...
+    Datetime = ...
+    Uuid = ...
+
 class TextIndexParams:
     """Index parameters for text fields."""
 
@@ -1779,6 +2067,10 @@ class SearchParams:
         quantization: Optional["QuantizationSearchParams"] = None,
         indexed_only: bool = False,
         acorn: Optional["AcornSearchParams"] = None,
+        multivector_comparator: Optional["MultiVectorComparator"] = None,
+        ivf_nprobe: Optional[int] = None,
+        radius: Optional[float] = None,
+        matryoshka: bool = False,
     ) -> None:
         """
         Create SearchParams.
@@ -1789,6 +2081,10 @@ class SearchParams:
             quantization: Quantization search parameters.
             indexed_only: Whether to search only indexed vectors.
             acorn: Acorn search parameters.
+            multivector_comparator: Override of the multi-vector comparator.
+            ivf_nprobe: Number of lists to scan for IVF search.
+            radius: Find all points with scores better than the radius.
+            matryoshka: Search the truncated view of Matryoshka embeddings.
         """
         ...
 
@@ -1817,6 +2113,26 @@ class SearchParams:
         """Acorn parameters."""
         ...
 
+    @property
+    def multivector_comparator(self) -> Optional["MultiVectorComparator"]:
+        """Multi-vector comparator override."""
+        ...
+
+    @property
+    def ivf_nprobe(self) -> Optional[int]:
+        """IVF nprobe parameter."""
+        ...
+
+    @property
+    def radius(self) -> Optional[float]:
+        """Radius search parameter."""
+        ...
+
+    @property
+    def matryoshka(self) -> bool:
+        """Matryoshka search flag."""
+        ...
+
 class QuantizationSearchParams:
     """Parameters for quantization during search."""
 
@@ -1959,6 +2275,8 @@ class OrderBy:
         key: JsonPath,
         direction: Optional[Direction] = None,
         start_from: Optional[StartFromType] = None,
+        then_by: Optional[List[ThenBy]] = None,
+        start_after: Optional[OrderByCursor] = None,
     ) -> None:
         """
         Create an OrderBy.
@@ -1967,6 +2285,8 @@ class OrderBy:
             key: Payload field path.
             direction: Sort direction.
             start_from: Starting value.
+            then_by: Payload fields to order points with equal values of the previous fields.
+            start_after: Continue right after this point of the previous page.
         """
         ...
 
@@ -1985,6 +2305,117 @@ class OrderBy:
         """Starting value."""
         ...
 
+    @property
+    def then_by(self) -> List[ThenBy]:
+        """Tie-breaking fields."""
+        ...
+
+    @property
+    def start_after(self) -> Optional[OrderByCursor]:
+        """Last point of the previous page."""
+        ...
+
+class ThenBy:
+    """Order points with equal values of the previous fields by a payload field."""
+
+    def __init__(
+        self,
+        key: JsonPath,
+        direction: Optional[Direction] = None,
+    ) -> None:
+        """
+        Create a ThenBy.
+
+        Args:
+            key: Payload field path.
+            direction: Sort direction.
+        """
+        ...
+
+    @property
+    def key(self) -> str:
+        """Field key."""
+        ...
+
+    @property
+    def direction(self) -> Optional[Direction]:
+        """Sort direction."""
+        ...
+
+class OrderByCursor:
+    """Position of the last point of a page, ordered by a payload field."""
+
+    def __init__(
+        self,
+        value: Union[int, float, str],
+        id: PointId,
+        then_by: Optional[List[Optional[Union[int, float, str]]]] = None,
+    ) -> None:
+        """
+        Create an OrderByCursor.
+
+        Args:
+            value: Order value of the last point.
+            id: ID of the last point.
+            then_by: Values of `then_by` fields of the last point.
+        """
+        ...
+
+    @property
+    def value(self) -> Union[int, float, str]:
+        """Order value."""
+        ...
+
+    @property
+    def id(self) -> PointId:
+        """Point ID."""
+        ...
+
+    @property
+    def then_by(self) -> List[Optional[Union[int, float, str]]]:
+        """Values of `then_by` fields."""
+        ...
+
+class StratifiedSample:
+    """Sample random points for each distinct value of a payload field."""
+
+    def __init__(self, key: JsonPath, per_value: int) -> None:
+        """
+        Create a StratifiedSample.
+
+        Args:
+            key: Payload field path to stratify by.
+            per_value: Number of points to sample for each distinct value.
+        """
+        ...
+
+    @property
+    def key(self) -> str:
+        """Field key."""
+        ...
+
+    @property
+    def per_value(self) -> int:
+        """Number of points for each distinct value."""
+        ...
+
+class WeightedSample:
+    """Sample points with probability proportional to a numeric payload field."""
+
+    def __init__(self, key: JsonPath) -> None:
+        """
+        Create a WeightedSample.
+
+        Args:
+            key: Payload field path holding the weight of the point.
+        """
+        ...
+
+    @property
+    def key(self) -> str:
+        """Field key."""
+        ...
+
 class Mmr:
     """Maximal Marginal Relevance for result diversification."""
 
@@ -2762,6 +3193,47 @@ class RangeDateTime:
         """Less than."""
         ...
 
+class RangeKeyword:
+    """Lexicographic range condition for keyword values, compared byte-wise."""
+
+    def __init__(
+        self,
+        gte: Optional[str] = None,
+        gt: Optional[str] = None,
+        lte: Optional[str] = None,
+        lt: Optional[str] = None,
+    ) -> None:
+        """
+        Create a RangeKeyword.
+
+        Args:
+            gte: Greater than or equal.
+            gt: Greater than.
+            lte: Less than or equal.
+            lt: Less than.
+        """
+        ...
+
+    @property
+    def gte(self) -> Optional[str]:
+        """Greater than or equal."""
+        ...
+
+    @property
+    def gt(self) -> Optional[str]:
+        """Greater than."""
+        ...
+
+    @property
+    def lte(self) -> Optional[str]:
+        """Less than or equal."""
+        ...
+
+    @property
+    def lt(self) -> Optional[str]:
+        """Less than."""
+        ...
+
 class ValuesCount:
     """Condition on count of values in array field."""
 
@@ -138,6 +138,7 @@ impl PyScalarQuantizationConfig {
 #[derive(Copy, Clone, Debug)]
 pub enum PyScalarType {
     Int8,
+    Int4,
 }
 
 #[pymethods]
@@ -151,6 +152,7 @@ impl Repr for PyScalarType {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let repr = match self {
             Self::Int8 => "Int8",
+            Self::Int4 => "Int4",
         };
 
         f.simple_enum::<Self>(repr)
@@ -161,6 +163,7 @@ impl From<ScalarType> for PyScalarType {
     fn from(scalar_type: ScalarType) -> Self {
         match scalar_type {
             ScalarType::Int8 => PyScalarType::Int8,
+            ScalarType::Int4 => PyScalarType::Int4,
         }
     }
 }
@@ -169,6 +172,7 @@ impl From<PyScalarType> for ScalarType {
     fn from(scalar_type: PyScalarType) -> Self {
         match scalar_type {
             PyScalarType::Int8 => ScalarType::Int8,
+            PyScalarType::Int4 => ScalarType::Int4,
         }
     }
 }
@@ -182,11 +186,16 @@ pub struct PyProductQuantizationConfig(ProductQuantizationConfig);
 #[pymethods]
 impl PyProductQuantizationConfig {
     #[new]
-    #[pyo3(signature = (compression, always_ram = None))]
-    pub fn new(compression: PyCompressionRatio, always_ram: Option<bool>) -> Self {
+    #[pyo3(signature = (compression, always_ram = None, opq = None))]
+    pub fn new(
+        compression: PyCompressionRatio,
+        always_ram: Option<bool>,
+        opq: Option<bool>,
+    ) -> Self {
         Self(ProductQuantizationConfig {
             compression: CompressionRatio::from(compression),
             always_ram,
+            opq,
         })
     }
 
@@ -200,6 +209,11 @@ impl PyProductQuantizationConfig {
         self.0.always_ram
     }
 
+    #[getter]
+    pub fn opq(&self) -> Option<bool> {
+        self.0.opq
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -211,6 +225,7 @@ impl PyProductQuantizationConfig {
         let ProductQuantizationConfig {
             compression: _,
             always_ram: _,
+            opq: _,
         } = self.0;
     }
 }
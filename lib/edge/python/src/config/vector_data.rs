@@ -17,6 +17,8 @@ pub enum PyDistance {
     Euclid,
     Dot,
     Manhattan,
+    Hamming,
+    Jaccard,
 }
 
 #[pymethods]
@@ -33,6 +35,8 @@ impl Repr for PyDistance {
             Self::Euclid => "Euclid",
             Self::Dot => "Dot",
             Self::Manhattan => "Manhattan",
+            Self::Hamming => "Hamming",
+            Self::Jaccard => "Jaccard",
         };
 
         f.simple_enum::<Self>(repr)
@@ -46,6 +50,8 @@ impl From<Distance> for PyDistance {
             Distance::Euclid => PyDistance::Euclid,
             Distance::Dot => PyDistance::Dot,
             Distance::Manhattan => PyDistance::Manhattan,
+            Distance::Hamming => PyDistance::Hamming,
+            Distance::Jaccard => PyDistance::Jaccard,
         }
     }
 }
@@ -57,6 +63,8 @@ impl From<PyDistance> for Distance {
             PyDistance::Euclid => Distance::Euclid,
             PyDistance::Dot => Distance::Dot,
             PyDistance::Manhattan => Distance::Manhattan,
+            PyDistance::Hamming => Distance::Hamming,
+            PyDistance::Jaccard => Distance::Jaccard,
         }
     }
 }
@@ -73,18 +81,24 @@ impl FromPyObject<'_, '_> for PyIndexes {
         enum Helper {
             Plain(PyPlainIndexConfig),
             Hnsw(PyHnswIndexConfig),
+            Vamana(PyVamanaIndexConfig),
+            Ivf(PyIvfIndexConfig),
         }
 
         fn _variants(indexes: Indexes) {
             match indexes {
                 Indexes::Plain {} => (),
                 Indexes::Hnsw(_) => (),
+                Indexes::Vamana(_) => (),
+                Indexes::Ivf(_) => (),
             }
         }
 
         let indexes = match indexes.extract()? {
             Helper::Plain(_) => Indexes::Plain {},
             Helper::Hnsw(hnsw) => Indexes::Hnsw(HnswConfig::from(hnsw)),
+            Helper::Vamana(vamana) => Indexes::Vamana(VamanaConfig::from(vamana)),
+            Helper::Ivf(ivf) => Indexes::Ivf(IvfConfig::from(ivf)),
         };
 
         Ok(Self(indexes))
@@ -100,6 +114,8 @@ impl<'py> IntoPyObject<'py> for PyIndexes {
         match self.0 {
             Indexes::Plain {} => PyPlainIndexConfig.into_bound_py_any(py),
             Indexes::Hnsw(hnsw) => PyHnswIndexConfig(hnsw).into_bound_py_any(py),
+            Indexes::Vamana(vamana) => PyVamanaIndexConfig(vamana).into_bound_py_any(py),
+            Indexes::Ivf(ivf) => PyIvfIndexConfig(ivf).into_bound_py_any(py),
         }
     }
 }
@@ -109,6 +125,8 @@ impl Repr for PyIndexes {
         match &self.0 {
             Indexes::Plain {} => PyPlainIndexConfig.fmt(f),
             Indexes::Hnsw(hnsw) => PyHnswIndexConfig::wrap_ref(hnsw).fmt(f),
+            Indexes::Vamana(vamana) => PyVamanaIndexConfig::wrap_ref(vamana).fmt(f),
+            Indexes::Ivf(ivf) => PyIvfIndexConfig::wrap_ref(ivf).fmt(f),
         }
     }
 }
@@ -215,6 +233,144 @@ impl PyHnswIndexConfig {
     }
 }
 
+#[pyclass(name = "VamanaIndexConfig", from_py_object)]
+#[derive(Copy, Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyVamanaIndexConfig(pub VamanaConfig);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyVamanaIndexConfig {
+    #[new]
+    #[pyo3(signature = (max_degree=64, build_list_size=128, beam_width=4, compression=PyCompressionRatio::X16, full_scan_threshold=DEFAULT_FULL_SCAN_THRESHOLD, max_indexing_threads=0))]
+    pub fn new(
+        max_degree: usize,
+        build_list_size: usize,
+        beam_width: usize,
+        compression: PyCompressionRatio,
+        full_scan_threshold: usize,
+        max_indexing_threads: usize,
+    ) -> Self {
+        Self(VamanaConfig {
+            max_degree,
+            build_list_size,
+            beam_width,
+            compression: CompressionRatio::from(compression),
+            full_scan_threshold,
+            max_indexing_threads,
+        })
+    }
+
+    #[getter]
+    pub fn max_degree(&self) -> usize {
+        self.0.max_degree
+    }
+
+    #[getter]
+    pub fn build_list_size(&self) -> usize {
+        self.0.build_list_size
+    }
+
+    #[getter]
+    pub fn beam_width(&self) -> usize {
+        self.0.beam_width
+    }
+
+    #[getter]
+    pub fn compression(&self) -> PyCompressionRatio {
+        PyCompressionRatio::from(self.0.compression)
+    }
+
+    #[getter]
+    pub fn full_scan_threshold(&self) -> usize {
+        self.0.full_scan_threshold
+    }
+
+    #[getter]
+    pub fn max_indexing_threads(&self) -> usize {
+        self.0.max_indexing_threads
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyVamanaIndexConfig {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let VamanaConfig {
+            max_degree: _,
+            build_list_size: _,
+            beam_width: _,
+            compression: _,
+            full_scan_threshold: _,
+            max_indexing_threads: _, // not relevant for Qdrant Edge
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "IvfIndexConfig", from_py_object)]
+#[derive(Copy, Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyIvfIndexConfig(pub IvfConfig);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyIvfIndexConfig {
+    #[new]
+    #[pyo3(signature = (num_lists=None, nprobe=16, full_scan_threshold=DEFAULT_FULL_SCAN_THRESHOLD, max_indexing_threads=0))]
+    pub fn new(
+        num_lists: Option<usize>,
+        nprobe: usize,
+        full_scan_threshold: usize,
+        max_indexing_threads: usize,
+    ) -> Self {
+        Self(IvfConfig {
+            num_lists,
+            nprobe,
+            full_scan_threshold,
+            max_indexing_threads,
+        })
+    }
+
+    #[getter]
+    pub fn num_lists(&self) -> Option<usize> {
+        self.0.num_lists
+    }
+
+    #[getter]
+    pub fn nprobe(&self) -> usize {
+        self.0.nprobe
+    }
+
+    #[getter]
+    pub fn full_scan_threshold(&self) -> usize {
+        self.0.full_scan_threshold
+    }
+
+    #[getter]
+    pub fn max_indexing_threads(&self) -> usize {
+        self.0.max_indexing_threads
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyIvfIndexConfig {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let IvfConfig {
+            num_lists: _,
+            nprobe: _,
+            full_scan_threshold: _,
+            max_indexing_threads: _, // not relevant for Qdrant Edge
+        } = self.0;
+    }
+}
+
 #[pyclass(name = "MultiVectorConfig", from_py_object)]
 #[derive(Copy, Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
@@ -224,9 +380,11 @@ pub struct PyMultiVectorConfig(MultiVectorConfig);
 #[pymethods]
 impl PyMultiVectorConfig {
     #[new]
-    pub fn new(comparator: PyMultiVectorComparator) -> Self {
+    #[pyo3(signature = (comparator, fde=None))]
+    pub fn new(comparator: PyMultiVectorComparator, fde: Option<PyFdeConfig>) -> Self {
         Self(MultiVectorConfig {
             comparator: MultiVectorComparator::from(comparator),
+            fde: fde.map(FdeConfig::from),
         })
     }
 
@@ -235,6 +393,11 @@ impl PyMultiVectorConfig {
         PyMultiVectorComparator::from(self.0.comparator)
     }
 
+    #[getter]
+    pub fn fde(&self) -> Option<PyFdeConfig> {
+        self.0.fde.map(PyFdeConfig)
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -243,7 +406,66 @@ impl PyMultiVectorConfig {
 impl PyMultiVectorConfig {
     fn _getters(self) {
         // Every field should have a getter method
-        let MultiVectorConfig { comparator: _ } = self.0;
+        let MultiVectorConfig {
+            comparator: _,
+            fde: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "FdeConfig", from_py_object)]
+#[derive(Copy, Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyFdeConfig(FdeConfig);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyFdeConfig {
+    #[new]
+    #[pyo3(signature = (ksim=DEFAULT_FDE_KSIM, dim_proj=DEFAULT_FDE_DIM_PROJ, repetitions=DEFAULT_FDE_REPETITIONS, candidates_factor=DEFAULT_FDE_CANDIDATES_FACTOR))]
+    pub fn new(ksim: usize, dim_proj: usize, repetitions: usize, candidates_factor: usize) -> Self {
+        Self(FdeConfig {
+            ksim,
+            dim_proj,
+            repetitions,
+            candidates_factor,
+        })
+    }
+
+    #[getter]
+    pub fn ksim(&self) -> usize {
+        self.0.ksim
+    }
+
+    #[getter]
+    pub fn dim_proj(&self) -> usize {
+        self.0.dim_proj
+    }
+
+    #[getter]
+    pub fn repetitions(&self) -> usize {
+        self.0.repetitions
+    }
+
+    #[getter]
+    pub fn candidates_factor(&self) -> usize {
+        self.0.candidates_factor
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyFdeConfig {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let FdeConfig {
+            ksim: _,
+            dim_proj: _,
+            repetitions: _,
+            candidates_factor: _,
+        } = self.0;
     }
 }
 
@@ -251,6 +473,10 @@ impl PyMultiVectorConfig {
 #[derive(Copy, Clone, Debug)]
 pub enum PyMultiVectorComparator {
     MaxSim,
+    Chamfer,
+    MeanSim,
+    MaxMax,
+    MeanPool,
 }
 
 #[pymethods]
@@ -264,6 +490,10 @@ impl Repr for PyMultiVectorComparator {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let repr = match self {
             Self::MaxSim => "MaxSim",
+            Self::Chamfer => "Chamfer",
+            Self::MeanSim => "MeanSim",
+            Self::MaxMax => "MaxMax",
+            Self::MeanPool => "MeanPool",
         };
 
         f.simple_enum::<Self>(repr)
@@ -274,6 +504,10 @@ impl From<MultiVectorComparator> for PyMultiVectorComparator {
     fn from(comparator: MultiVectorComparator) -> Self {
         match comparator {
             MultiVectorComparator::MaxSim => PyMultiVectorComparator::MaxSim,
+            MultiVectorComparator::Chamfer => PyMultiVectorComparator::Chamfer,
+            MultiVectorComparator::MeanSim => PyMultiVectorComparator::MeanSim,
+            MultiVectorComparator::MaxMax => PyMultiVectorComparator::MaxMax,
+            MultiVectorComparator::MeanPool => PyMultiVectorComparator::MeanPool,
         }
     }
 }
@@ -282,6 +516,10 @@ impl From<PyMultiVectorComparator> for MultiVectorComparator {
     fn from(comparator: PyMultiVectorComparator) -> Self {
         match comparator {
             PyMultiVectorComparator::MaxSim => MultiVectorComparator::MaxSim,
+            PyMultiVectorComparator::Chamfer => MultiVectorComparator::Chamfer,
+            PyMultiVectorComparator::MeanSim => MultiVectorComparator::MeanSim,
+            PyMultiVectorComparator::MaxMax => MultiVectorComparator::MaxMax,
+            PyMultiVectorComparator::MeanPool => MultiVectorComparator::MeanPool,
         }
     }
 }
@@ -292,6 +530,7 @@ pub enum PyVectorStorageDatatype {
     Float32,
     Float16,
     Uint8,
+    Bit,
 }
 
 #[pymethods]
@@ -307,6 +546,7 @@ impl Repr for PyVectorStorageDatatype {
             Self::Float32 => "Float32",
             Self::Float16 => "Float16",
             Self::Uint8 => "Uint8",
+            Self::Bit => "Bit",
         };
 
         f.simple_enum::<Self>(repr)
@@ -319,6 +559,7 @@ impl From<VectorStorageDatatype> for PyVectorStorageDatatype {
             VectorStorageDatatype::Float32 => PyVectorStorageDatatype::Float32,
             VectorStorageDatatype::Float16 => PyVectorStorageDatatype::Float16,
             VectorStorageDatatype::Uint8 => PyVectorStorageDatatype::Uint8,
+            VectorStorageDatatype::Bit => PyVectorStorageDatatype::Bit,
         }
     }
 }
@@ -329,6 +570,7 @@ impl From<PyVectorStorageDatatype> for VectorStorageDatatype {
             PyVectorStorageDatatype::Float32 => VectorStorageDatatype::Float32,
             PyVectorStorageDatatype::Float16 => VectorStorageDatatype::Float16,
             PyVectorStorageDatatype::Uint8 => VectorStorageDatatype::Uint8,
+            PyVectorStorageDatatype::Bit => VectorStorageDatatype::Bit,
         }
     }
 }
@@ -359,7 +601,7 @@ impl PyEdgeVectorParams {
 #[pymethods]
 impl PyEdgeVectorParams {
     #[new]
-    #[pyo3(signature = (size, distance, on_disk=None, multivector_config=None, datatype=None, quantization_config=None, hnsw_config=None))]
+    #[pyo3(signature = (size, distance, on_disk=None, multivector_config=None, datatype=None, quantization_config=None, hnsw_config=None, vamana_config=None, ivf_config=None, matryoshka_dim=None))]
     pub fn new(
         size: usize,
         distance: PyDistance,
@@ -368,6 +610,9 @@ impl PyEdgeVectorParams {
         datatype: Option<PyVectorStorageDatatype>,
         quantization_config: Option<PyQuantizationConfig>,
         hnsw_config: Option<PyHnswIndexConfig>,
+        vamana_config: Option<PyVamanaIndexConfig>,
+        ivf_config: Option<PyIvfIndexConfig>,
+        matryoshka_dim: Option<usize>,
     ) -> Self {
         Self(EdgeVectorParams {
             size,
@@ -377,6 +622,9 @@ impl PyEdgeVectorParams {
             datatype: datatype.map(VectorStorageDatatype::from),
             quantization_config: quantization_config.map(QuantizationConfig::from),
             hnsw_config: hnsw_config.map(|h| h.0),
+            vamana_config: vamana_config.map(|v| v.0),
+            ivf_config: ivf_config.map(|v| v.0),
+            matryoshka_dim,
         })
     }
 
@@ -415,6 +663,21 @@ impl PyEdgeVectorParams {
         self.0.hnsw_config.map(PyHnswIndexConfig)
     }
 
+    #[getter]
+    pub fn vamana_config(&self) -> Option<PyVamanaIndexConfig> {
+        self.0.vamana_config.map(PyVamanaIndexConfig)
+    }
+
+    #[getter]
+    pub fn ivf_config(&self) -> Option<PyIvfIndexConfig> {
+        self.0.ivf_config.map(PyIvfIndexConfig)
+    }
+
+    #[getter]
+    pub fn matryoshka_dim(&self) -> Option<usize> {
+        self.0.matryoshka_dim
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
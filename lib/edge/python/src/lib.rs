@@ -45,8 +45,9 @@ mod qdrant_edge {
     use super::config::sparse_vector_data::{PyEdgeSparseVectorParams, PyModifier};
     #[pymodule_export]
     use super::config::vector_data::{
-        PyDistance, PyEdgeVectorParams, PyHnswIndexConfig, PyMultiVectorComparator,
-        PyMultiVectorConfig, PyPlainIndexConfig, PyVectorStorageDatatype,
+        PyDistance, PyEdgeVectorParams, PyFdeConfig, PyHnswIndexConfig, PyIvfIndexConfig,
+        PyMultiVectorComparator, PyMultiVectorConfig, PyPlainIndexConfig, PyVamanaIndexConfig,
+        PyVectorStorageDatatype,
     };
     #[pymodule_export]
     use super::config::{PyEdgeConfig, PyEdgeOptimizersConfig};
@@ -56,7 +57,8 @@ mod qdrant_edge {
     use super::facet::{PyFacetHit, PyFacetRequest, PyFacetResponse};
     #[pymodule_export]
     use super::query::{
-        PyDirection, PyFusion, PyMmr, PyOrderBy, PyPrefetch, PyQueryRequest, PySample,
+        PyDirection, PyFusion, PyMmr, PyOrderBy, PyOrderByCursor, PyPrefetch, PyQueryRequest,
+        PySample, PyStratifiedSample, PyThenBy, PyWeightedSample,
     };
     #[pymodule_export]
     use super::scroll::PyScrollRequest;
@@ -69,16 +71,16 @@ mod qdrant_edge {
         PyFieldCondition, PyFilter, PyGeoBoundingBox, PyGeoPoint, PyGeoPolygon, PyGeoRadius,
         PyHasIdCondition, PyHasVectorCondition, PyIsEmptyCondition, PyIsNullCondition, PyMatchAny,
         PyMatchExcept, PyMatchPhrase, PyMatchText, PyMatchTextAny, PyMatchValue, PyMinShould,
-        PyNestedCondition, PyRangeDateTime, PyRangeFloat, PyValuesCount,
+        PyNestedCondition, PyRangeDateTime, PyRangeFloat, PyRangeKeyword, PyValuesCount,
     };
     #[pymodule_export]
     use super::types::formula::{PyDecayKind, PyExpressionInterface, PyFormula};
     #[pymodule_export]
     use super::types::payload_schema::{
-        PyBoolIndexParams, PyDatetimeIndexParams, PyFloatIndexParams, PyGeoIndexParams,
-        PyIntegerIndexParams, PyKeywordIndexParams, PyLanguage, PyPayloadSchemaType,
-        PySnowballLanguage, PySnowballParams, PyStopwordsSet, PyTextIndexParams, PyTokenizerType,
-        PyUuidIndexParams,
+        PyBoolIndexParams, PyCompositeFieldType, PyCompositeIndexField, PyCompositeIndexParams,
+        PyDatetimeIndexParams, PyFloatIndexParams, PyGeoIndexParams, PyIntegerIndexParams,
+        PyKeywordIndexParams, PyLanguage, PyPayloadSchemaType, PySnowballLanguage,
+        PySnowballParams, PyStopwordsSet, PyTextIndexParams, PyTokenizerType, PyUuidIndexParams,
     };
     #[pymodule_export]
     use super::types::query::{
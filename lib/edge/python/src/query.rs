@@ -4,9 +4,10 @@ use bytemuck::{TransparentWrapper, TransparentWrapperAlloc as _};
 use derive_more::Into;
 use ordered_float::OrderedFloat;
 use pyo3::IntoPyObjectExt;
-use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use segment::data_types::order_by::{Direction, OrderBy, OrderByInterface, StartFrom};
+use segment::data_types::order_by::{
+    Direction, OrderBy, OrderByCursor, OrderByInterface, OrderValue, StartFrom, ThenBy,
+};
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorInternal};
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use segment::json_path::JsonPath;
@@ -51,6 +52,7 @@ impl PyQueryRequest {
             prefetches: PyPrefetch::peel_vec(prefetches.unwrap_or_default()),
             limit,
             offset: offset.unwrap_or(0),
+            start_after: None,
             with_vector: with_vector.map(WithVector::from).unwrap_or_default(),
             with_payload: with_payload
                 .map(WithPayloadInterface::from)
@@ -124,6 +126,8 @@ impl PyQueryRequest {
             score_threshold: _,
             limit: _,
             offset: _,
+            // Continuation of results between the shards of a collection only
+            start_after: _,
             params: _,
             with_vector: _,
             with_payload: _,
@@ -242,6 +246,8 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
             OrderBy(PyOrderBy),
             Formula(PyFormula),
             Sample(PySample),
+            StratifiedSample(PyStratifiedSample),
+            WeightedSample(PyWeightedSample),
             Mmr(PyMmr),
         }
 
@@ -262,6 +268,12 @@ impl FromPyObject<'_, '_> for PyScoringQuery {
             Helper::OrderBy(order_by) => ScoringQuery::OrderBy(OrderBy::from(order_by)),
             Helper::Formula(formula) => ScoringQuery::Formula(ParsedFormula::from(formula)),
             Helper::Sample(sample) => ScoringQuery::Sample(SampleInternal::from(sample)),
+            Helper::StratifiedSample(stratified) => ScoringQuery::Sample(
+                SampleInternal::Stratified(StratifiedSample::from(stratified)),
+            ),
+            Helper::WeightedSample(weighted) => {
+                ScoringQuery::Sample(SampleInternal::Weighted(WeightedSample::from(weighted)))
+            }
             Helper::Mmr(mmr) => ScoringQuery::Mmr(MmrInternal::from(mmr)),
         };
 
@@ -280,7 +292,13 @@ impl<'py> IntoPyObject<'py> for PyScoringQuery {
             ScoringQuery::Fusion(fusion) => PyFusion::from(fusion).into_bound_py_any(py),
             ScoringQuery::OrderBy(order_by) => PyOrderBy(order_by).into_bound_py_any(py),
             ScoringQuery::Formula(formula) => PyFormula(formula).into_bound_py_any(py),
-            ScoringQuery::Sample(sample) => PySample::from(sample).into_bound_py_any(py),
+            ScoringQuery::Sample(SampleInternal::Random) => PySample::Random.into_bound_py_any(py),
+            ScoringQuery::Sample(SampleInternal::Stratified(stratified)) => {
+                PyStratifiedSample(stratified).into_bound_py_any(py)
+            }
+            ScoringQuery::Sample(SampleInternal::Weighted(weighted)) => {
+                PyWeightedSample(weighted).into_bound_py_any(py)
+            }
             ScoringQuery::Mmr(mmr) => PyMmr(mmr).into_bound_py_any(py),
         }
     }
@@ -303,7 +321,13 @@ impl Repr for PyScoringQuery {
             ScoringQuery::Fusion(fusion) => PyFusion::from(fusion.clone()).fmt(f),
             ScoringQuery::OrderBy(order_by) => PyOrderBy::wrap_ref(order_by).fmt(f),
             ScoringQuery::Formula(_formula) => f.unimplemented(), // TODO!
-            ScoringQuery::Sample(sample) => PySample::from(*sample).fmt(f),
+            ScoringQuery::Sample(SampleInternal::Random) => PySample::Random.fmt(f),
+            ScoringQuery::Sample(SampleInternal::Stratified(stratified)) => {
+                PyStratifiedSample::wrap_ref(stratified).fmt(f)
+            }
+            ScoringQuery::Sample(SampleInternal::Weighted(weighted)) => {
+                PyWeightedSample::wrap_ref(weighted).fmt(f)
+            }
             ScoringQuery::Mmr(mmr) => PyMmr::wrap_ref(mmr).fmt(f),
         }
     }
@@ -375,16 +399,20 @@ pub struct PyOrderBy(OrderBy);
 #[pymethods]
 impl PyOrderBy {
     #[new]
-    #[pyo3(signature = (key, direction = None, start_from = None))]
+    #[pyo3(signature = (key, direction = None, start_from = None, then_by = None, start_after = None))]
     pub fn new(
         key: PyJsonPath,
         direction: Option<PyDirection>,
         start_from: Option<PyStartFrom>,
+        then_by: Option<Vec<PyThenBy>>,
+        start_after: Option<PyOrderByCursor>,
     ) -> PyResult<Self> {
         let order_by = OrderBy {
             key: JsonPath::from(key),
             direction: direction.map(Direction::from),
             start_from: start_from.map(StartFrom::from),
+            then_by: PyThenBy::peel_vec(then_by.unwrap_or_default()),
+            start_after: start_after.map(OrderByCursor::from),
         };
 
         Ok(Self(order_by))
@@ -402,7 +430,17 @@ impl PyOrderBy {
 
     #[getter]
     pub fn start_from(&self) -> Option<PyStartFrom> {
-        self.0.start_from.map(PyStartFrom)
+        self.0.start_from.clone().map(PyStartFrom)
+    }
+
+    #[getter]
+    pub fn then_by(&self) -> &[PyThenBy] {
+        PyThenBy::wrap_slice(&self.0.then_by)
+    }
+
+    #[getter]
+    pub fn start_after(&self) -> Option<PyOrderByCursor> {
+        self.0.start_after.clone().map(PyOrderByCursor)
     }
 
     pub fn __repr__(&self) -> String {
@@ -417,6 +455,8 @@ impl PyOrderBy {
             key: _,
             direction: _,
             start_from: _,
+            then_by: _,
+            start_after: _,
         } = self.0;
     }
 }
@@ -433,6 +473,108 @@ impl From<PyOrderBy> for OrderByInterface {
     }
 }
 
+#[pyclass(name = "ThenBy", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyThenBy(ThenBy);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyThenBy {
+    #[new]
+    #[pyo3(signature = (key, direction = None))]
+    pub fn new(key: PyJsonPath, direction: Option<PyDirection>) -> Self {
+        Self(ThenBy {
+            key: JsonPath::from(key),
+            direction: direction.map(Direction::from),
+        })
+    }
+
+    #[getter]
+    pub fn key(&self) -> &PyJsonPath {
+        PyJsonPath::wrap_ref(&self.0.key)
+    }
+
+    #[getter]
+    pub fn direction(&self) -> Option<PyDirection> {
+        self.0.direction.map(PyDirection::from)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyThenBy {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let ThenBy {
+            key: _,
+            direction: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "OrderByCursor", from_py_object)]
+#[derive(Clone, Debug, Into)]
+pub struct PyOrderByCursor(OrderByCursor);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyOrderByCursor {
+    #[new]
+    #[pyo3(signature = (value, id, then_by = None))]
+    pub fn new(
+        value: PyOrderValue,
+        id: PyPointId,
+        then_by: Option<Vec<Option<PyOrderValue>>>,
+    ) -> Self {
+        Self(OrderByCursor {
+            value: OrderValue::from(value),
+            then_by: then_by
+                .unwrap_or_default()
+                .into_iter()
+                .map(|value| value.map(OrderValue::from))
+                .collect(),
+            id: id.0,
+        })
+    }
+
+    #[getter]
+    pub fn value(&self) -> PyOrderValue {
+        PyOrderValue::from(self.0.value.clone())
+    }
+
+    #[getter]
+    pub fn id(&self) -> PyPointId {
+        PyPointId(self.0.id)
+    }
+
+    #[getter]
+    pub fn then_by(&self) -> Vec<Option<PyOrderValue>> {
+        self.0
+            .then_by
+            .iter()
+            .map(|value| value.clone().map(PyOrderValue::from))
+            .collect()
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyOrderByCursor {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let OrderByCursor {
+            value: _,
+            then_by: _,
+            id: _,
+        } = self.0;
+    }
+}
+
 #[pyclass(name = "Direction", from_py_object)]
 #[derive(Copy, Clone, Debug)]
 pub enum PyDirection {
@@ -476,7 +618,7 @@ impl From<PyDirection> for Direction {
     }
 }
 
-#[derive(Copy, Clone, Debug, Into)]
+#[derive(Clone, Debug, Into)]
 pub struct PyStartFrom(StartFrom);
 
 impl FromPyObject<'_, '_> for PyStartFrom {
@@ -487,13 +629,14 @@ impl FromPyObject<'_, '_> for PyStartFrom {
         enum Helper {
             Integer(IntPayloadType),
             Float(FloatPayloadType),
-            DateTime(String),
+            Keyword(String),
         }
 
         fn _variants(start_from: StartFrom) {
             match start_from {
                 StartFrom::Integer(_) => {}
                 StartFrom::Float(_) => {}
+                StartFrom::Keyword(_) => {}
                 StartFrom::Datetime(_) => {}
             }
         }
@@ -501,13 +644,8 @@ impl FromPyObject<'_, '_> for PyStartFrom {
         let start_from = match start_from.extract()? {
             Helper::Integer(int) => StartFrom::Integer(int),
             Helper::Float(float) => StartFrom::Float(float),
-            Helper::DateTime(date_time) => {
-                let date_time = date_time.parse().map_err(|err| {
-                    PyValueError::new_err(format!("failed to parse date-time: {err}"))
-                })?;
-
-                StartFrom::Datetime(date_time)
-            }
+            // Parsed as a date-time, unless ordering by a keyword index
+            Helper::Keyword(keyword) => StartFrom::Keyword(keyword),
         };
 
         Ok(Self(start_from))
@@ -533,6 +671,7 @@ impl<'py> IntoPyObject<'py> for &PyStartFrom {
         match &self.0 {
             StartFrom::Integer(int) => int.into_bound_py_any(py),
             StartFrom::Float(float) => float.into_bound_py_any(py),
+            StartFrom::Keyword(keyword) => keyword.into_bound_py_any(py),
             StartFrom::Datetime(date_time) => date_time.to_string().into_bound_py_any(py),
         }
     }
@@ -540,9 +679,10 @@ impl<'py> IntoPyObject<'py> for &PyStartFrom {
 
 impl Repr for PyStartFrom {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self.0 {
+        match &self.0 {
             StartFrom::Integer(int) => int.fmt(f),
             StartFrom::Float(float) => float.fmt(f),
+            StartFrom::Keyword(keyword) => keyword.fmt(f),
             StartFrom::Datetime(date_time) => date_time.to_string().fmt(f),
         }
     }
@@ -571,14 +711,6 @@ impl Repr for PySample {
     }
 }
 
-impl From<SampleInternal> for PySample {
-    fn from(sample: SampleInternal) -> Self {
-        match sample {
-            SampleInternal::Random => PySample::Random,
-        }
-    }
-}
-
 impl From<PySample> for SampleInternal {
     fn from(sample: PySample) -> Self {
         match sample {
@@ -587,6 +719,79 @@ impl From<PySample> for SampleInternal {
     }
 }
 
+#[pyclass(name = "StratifiedSample", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyStratifiedSample(StratifiedSample);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyStratifiedSample {
+    #[new]
+    pub fn new(key: PyJsonPath, per_value: usize) -> Self {
+        Self(StratifiedSample {
+            key: JsonPath::from(key),
+            per_value,
+        })
+    }
+
+    #[getter]
+    pub fn key(&self) -> &PyJsonPath {
+        PyJsonPath::wrap_ref(&self.0.key)
+    }
+
+    #[getter]
+    pub fn per_value(&self) -> usize {
+        self.0.per_value
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyStratifiedSample {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let StratifiedSample {
+            key: _,
+            per_value: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "WeightedSample", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyWeightedSample(WeightedSample);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyWeightedSample {
+    #[new]
+    pub fn new(key: PyJsonPath) -> Self {
+        Self(WeightedSample {
+            key: JsonPath::from(key),
+        })
+    }
+
+    #[getter]
+    pub fn key(&self) -> &PyJsonPath {
+        PyJsonPath::wrap_ref(&self.0.key)
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.repr()
+    }
+}
+
+impl PyWeightedSample {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let WeightedSample { key: _ } = self.0;
+    }
+}
+
 #[pyclass(name = "Mmr", from_py_object)]
 #[derive(Clone, Debug, Into, TransparentWrapper)]
 #[repr(transparent)]
@@ -124,6 +124,10 @@ impl PySearchParams {
         quantization = None,
         indexed_only = false,
         acorn = None,
+        multivector_comparator = None,
+        ivf_nprobe = None,
+        radius = None,
+        matryoshka = false,
     ))]
     pub fn new(
         hnsw_ef: Option<usize>,
@@ -131,6 +135,10 @@ impl PySearchParams {
         quantization: Option<PyQuantizationSearchParams>,
         indexed_only: bool,
         acorn: Option<PyAcornSearchParams>,
+        multivector_comparator: Option<PyMultiVectorComparator>,
+        ivf_nprobe: Option<usize>,
+        radius: Option<f32>,
+        matryoshka: bool,
     ) -> Self {
         Self(SearchParams {
             hnsw_ef,
@@ -138,6 +146,11 @@ impl PySearchParams {
             quantization: quantization.map(QuantizationSearchParams::from),
             indexed_only,
             acorn: acorn.map(AcornSearchParams::from),
+            multivector_comparator: multivector_comparator.map(MultiVectorComparator::from),
+            ivf_nprobe,
+            radius: radius.map(OrderedFloat),
+            matryoshka,
+            score_start_after: None,
         })
     }
 
@@ -166,6 +179,28 @@ impl PySearchParams {
         self.0.acorn.map(PyAcornSearchParams)
     }
 
+    #[getter]
+    pub fn multivector_comparator(&self) -> Option<PyMultiVectorComparator> {
+        self.0
+            .multivector_comparator
+            .map(PyMultiVectorComparator::from)
+    }
+
+    #[getter]
+    pub fn ivf_nprobe(&self) -> Option<usize> {
+        self.0.ivf_nprobe
+    }
+
+    #[getter]
+    pub fn radius(&self) -> Option<f32> {
+        self.0.radius.map(OrderedFloat::into_inner)
+    }
+
+    #[getter]
+    pub fn matryoshka(&self) -> bool {
+        self.0.matryoshka
+    }
+
     pub fn __repr__(&self) -> String {
         self.repr()
     }
@@ -180,6 +215,11 @@ impl PySearchParams {
             quantization: _,
             indexed_only: _,
             acorn: _,
+            multivector_comparator: _,
+            ivf_nprobe: _,
+            radius: _,
+            matryoshka: _,
+            score_start_after: _, // internal
         } = self.0;
     }
 }
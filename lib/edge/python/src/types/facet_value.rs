@@ -0,0 +1,34 @@
+use std::fmt;
+
+use pyo3::prelude::*;
+use segment::data_types::facets::FacetValue;
+
+use crate::repr::*;
+
+#[derive(Clone, Debug, IntoPyObject)]
+pub enum PyFacetValue {
+    Bool(bool),
+    Int(i64),
+    Keyword(String),
+}
+
+impl Repr for PyFacetValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(bool) => bool.fmt(f),
+            Self::Int(int) => int.fmt(f),
+            Self::Keyword(keyword) => keyword.fmt(f),
+        }
+    }
+}
+
+impl From<FacetValue> for PyFacetValue {
+    fn from(value: FacetValue) -> Self {
+        match value {
+            FacetValue::Keyword(keyword) => Self::Keyword(keyword),
+            FacetValue::Int(int) => Self::Int(int),
+            FacetValue::Uuid(uuid) => Self::Keyword(uuid::Uuid::from_u128(uuid).to_string()),
+            FacetValue::Bool(bool) => Self::Bool(bool),
+        }
+    }
+}
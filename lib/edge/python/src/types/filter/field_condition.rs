@@ -64,7 +64,7 @@ impl PyFieldCondition {
 
     #[getter]
     pub fn range(&self) -> Option<PyRange> {
-        self.0.range.map(PyRange::from)
+        self.0.range.clone().map(PyRange::from)
     }
 
     #[getter]
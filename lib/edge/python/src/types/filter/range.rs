@@ -8,10 +8,11 @@ use segment::types::*;
 
 use crate::repr::*;
 
-#[derive(Copy, Clone, Debug, FromPyObject, IntoPyObject)]
+#[derive(Clone, Debug, FromPyObject, IntoPyObject)]
 pub enum PyRange {
     Float(PyRangeFloat),
     DateTime(PyRangeDateTime),
+    Keyword(PyRangeKeyword),
 }
 
 impl Repr for PyRange {
@@ -19,6 +20,7 @@ impl Repr for PyRange {
         match self {
             PyRange::Float(float) => float.fmt(f),
             PyRange::DateTime(date_time) => date_time.fmt(f),
+            PyRange::Keyword(keyword) => keyword.fmt(f),
         }
     }
 }
@@ -28,6 +30,7 @@ impl From<RangeInterface> for PyRange {
         match range {
             RangeInterface::Float(float) => PyRange::Float(PyRangeFloat(float)),
             RangeInterface::DateTime(date_time) => PyRange::DateTime(PyRangeDateTime(date_time)),
+            RangeInterface::Keyword(keyword) => PyRange::Keyword(PyRangeKeyword(keyword.keyword)),
         }
     }
 }
@@ -37,6 +40,9 @@ impl From<PyRange> for RangeInterface {
         match range {
             PyRange::Float(float) => RangeInterface::Float(float.0),
             PyRange::DateTime(date_time) => RangeInterface::DateTime(date_time.0),
+            PyRange::Keyword(keyword) => {
+                RangeInterface::Keyword(KeywordRange { keyword: keyword.0 })
+            }
         }
     }
 }
@@ -153,6 +159,57 @@ impl PyRangeDateTime {
     }
 }
 
+#[pyclass(name = "RangeKeyword", from_py_object)]
+#[derive(Clone, Debug, Into)]
+pub struct PyRangeKeyword(pub Range<String>);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyRangeKeyword {
+    #[new]
+    #[pyo3(signature = (gte=None, gt=None, lte=None, lt=None))]
+    pub fn new(
+        gte: Option<String>,
+        gt: Option<String>,
+        lte: Option<String>,
+        lt: Option<String>,
+    ) -> Self {
+        Self(Range { gte, gt, lte, lt })
+    }
+
+    #[getter]
+    pub fn gte(&self) -> Option<&str> {
+        self.0.gte.as_deref()
+    }
+
+    #[getter]
+    pub fn gt(&self) -> Option<&str> {
+        self.0.gt.as_deref()
+    }
+
+    #[getter]
+    pub fn lte(&self) -> Option<&str> {
+        self.0.lte.as_deref()
+    }
+
+    #[getter]
+    pub fn lt(&self) -> Option<&str> {
+        self.0.lt.as_deref()
+    }
+}
+
+impl PyRangeKeyword {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let Range {
+            gte: _,
+            gt: _,
+            lte: _,
+            lt: _,
+        } = self.0;
+    }
+}
+
 fn parse_datetime_opt(date_time: Option<&str>) -> PyResult<Option<DateTimeWrapper>> {
     date_time.map(parse_datetime).transpose()
 }
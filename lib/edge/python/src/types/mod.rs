@@ -1,3 +1,4 @@
+pub mod facet_value;
 pub mod filter;
 pub mod formula;
 pub mod json_path;
@@ -14,6 +15,7 @@ pub mod value;
 pub mod vector;
 pub mod vector_internal;
 
+pub use self::facet_value::*;
 pub use self::filter::*;
 pub use self::formula::*;
 pub use self::json_path::*;
@@ -5,10 +5,11 @@ use segment::data_types::order_by::OrderValue;
 
 use crate::repr::*;
 
-#[derive(IntoPyObject)]
+#[derive(Clone, Debug, FromPyObject, IntoPyObject)]
 pub enum PyOrderValue {
     Int(i64),
     Float(f64),
+    Keyword(String),
 }
 
 impl Repr for PyOrderValue {
@@ -16,6 +17,7 @@ impl Repr for PyOrderValue {
         match self {
             Self::Int(int) => int.fmt(f),
             Self::Float(float) => float.fmt(f),
+            Self::Keyword(keyword) => keyword.fmt(f),
         }
     }
 }
@@ -25,6 +27,17 @@ impl From<OrderValue> for PyOrderValue {
         match value {
             OrderValue::Int(int) => Self::Int(int),
             OrderValue::Float(float) => Self::Float(float),
+            OrderValue::Keyword(keyword) => Self::Keyword(keyword),
+        }
+    }
+}
+
+impl From<PyOrderValue> for OrderValue {
+    fn from(value: PyOrderValue) -> Self {
+        match value {
+            PyOrderValue::Int(int) => Self::Int(int),
+            PyOrderValue::Float(float) => Self::Float(float),
+            PyOrderValue::Keyword(keyword) => Self::Keyword(keyword),
         }
     }
 }
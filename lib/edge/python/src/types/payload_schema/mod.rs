@@ -11,6 +11,7 @@ use segment::types::{PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType}
 
 pub use self::text_index::*;
 use crate::repr::*;
+use crate::types::PyJsonPath;
 
 #[derive(Clone, Debug, Into)]
 pub struct PyPayloadFieldSchema(PayloadFieldSchema);
@@ -119,6 +120,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
             Bool(PyBoolIndexParams),
             Datetime(PyDatetimeIndexParams),
             Uuid(PyUuidIndexParams),
+            Composite(PyCompositeIndexParams),
         }
 
         fn _variants(schema_params: PayloadSchemaParams) {
@@ -131,6 +133,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
                 PayloadSchemaParams::Bool(_) => {}
                 PayloadSchemaParams::Datetime(_) => {}
                 PayloadSchemaParams::Uuid(_) => {}
+                PayloadSchemaParams::Composite(_) => {}
             }
         }
 
@@ -143,6 +146,7 @@ impl FromPyObject<'_, '_> for PyPayloadSchemaParams {
             Helper::Bool(bool) => PayloadSchemaParams::Bool(bool.into()),
             Helper::Datetime(date_time) => PayloadSchemaParams::Datetime(date_time.into()),
             Helper::Uuid(uuid) => PayloadSchemaParams::Uuid(uuid.into()),
+            Helper::Composite(composite) => PayloadSchemaParams::Composite(composite.into()),
         };
 
         Ok(Self(schema_params))
@@ -168,6 +172,9 @@ impl<'py> IntoPyObject<'py> for PyPayloadSchemaParams {
                 PyDatetimeIndexParams(date_time).into_bound_py_any(py)
             }
             PayloadSchemaParams::Uuid(uuid) => PyUuidIndexParams(uuid).into_bound_py_any(py),
+            PayloadSchemaParams::Composite(composite) => {
+                PyCompositeIndexParams(composite).into_bound_py_any(py)
+            }
         }
     }
 }
@@ -195,6 +202,9 @@ impl Repr for PyPayloadSchemaParams {
                 PyDatetimeIndexParams::wrap_ref(date_time).fmt(f)
             }
             PayloadSchemaParams::Uuid(uuid) => PyUuidIndexParams::wrap_ref(uuid).fmt(f),
+            PayloadSchemaParams::Composite(composite) => {
+                PyCompositeIndexParams::wrap_ref(composite).fmt(f)
+            }
         }
     }
 }
@@ -541,3 +551,154 @@ impl PyUuidIndexParams {
         } = self.0;
     }
 }
+
+#[pyclass(name = "CompositeIndexParams", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyCompositeIndexParams(CompositeIndexParams);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyCompositeIndexParams {
+    #[new]
+    #[pyo3(signature = (field_type, fields, on_disk = None, enable_hnsw = None))]
+    pub fn new(
+        field_type: PyCompositeFieldType,
+        fields: Vec<PyCompositeIndexField>,
+        on_disk: Option<bool>,
+        enable_hnsw: Option<bool>,
+    ) -> Self {
+        Self(CompositeIndexParams {
+            r#type: Default::default(),
+            field_type: field_type.into(),
+            fields: PyCompositeIndexField::peel_vec(fields),
+            on_disk,
+            enable_hnsw,
+        })
+    }
+
+    #[getter]
+    pub fn field_type(&self) -> PyCompositeFieldType {
+        self.0.field_type.into()
+    }
+
+    #[getter]
+    pub fn fields(&self) -> &[PyCompositeIndexField] {
+        PyCompositeIndexField::wrap_slice(&self.0.fields)
+    }
+
+    #[getter]
+    pub fn on_disk(&self) -> Option<bool> {
+        self.0.on_disk
+    }
+
+    #[getter]
+    pub fn enable_hnsw(&self) -> Option<bool> {
+        self.0.enable_hnsw
+    }
+}
+
+impl PyCompositeIndexParams {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let CompositeIndexParams {
+            r#type: _, // not relevant for Qdrant Edge
+            field_type: _,
+            fields: _,
+            on_disk: _,
+            enable_hnsw: _,
+        } = self.0;
+    }
+}
+
+#[pyclass(name = "CompositeIndexField", from_py_object)]
+#[derive(Clone, Debug, Into, TransparentWrapper)]
+#[repr(transparent)]
+pub struct PyCompositeIndexField(CompositeIndexField);
+
+#[pyclass_repr]
+#[pymethods]
+impl PyCompositeIndexField {
+    #[new]
+    pub fn new(key: PyJsonPath, r#type: PyCompositeFieldType) -> Self {
+        Self(CompositeIndexField {
+            key: key.into(),
+            r#type: r#type.into(),
+        })
+    }
+
+    #[getter]
+    pub fn key(&self) -> &PyJsonPath {
+        PyJsonPath::wrap_ref(&self.0.key)
+    }
+
+    #[getter]
+    pub fn r#type(&self) -> PyCompositeFieldType {
+        self.0.r#type.into()
+    }
+}
+
+impl PyCompositeIndexField {
+    fn _getters(self) {
+        // Every field should have a getter method
+        let CompositeIndexField { key: _, r#type: _ } = self.0;
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &PyCompositeIndexField {
+    type Target = PyCompositeIndexField;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        IntoPyObject::into_pyobject(self.clone(), py)
+    }
+}
+
+#[pyclass(name = "CompositeFieldType", from_py_object)]
+#[derive(Copy, Clone, Debug)]
+pub enum PyCompositeFieldType {
+    Keyword,
+    Integer,
+    Float,
+    Datetime,
+    Uuid,
+}
+
+impl Repr for PyCompositeFieldType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Keyword => "Keyword",
+            Self::Integer => "Integer",
+            Self::Float => "Float",
+            Self::Datetime => "Datetime",
+            Self::Uuid => "Uuid",
+        };
+
+        f.simple_enum::<Self>(repr)
+    }
+}
+
+impl From<CompositeFieldType> for PyCompositeFieldType {
+    fn from(field_type: CompositeFieldType) -> Self {
+        match field_type {
+            CompositeFieldType::Keyword => PyCompositeFieldType::Keyword,
+            CompositeFieldType::Integer => PyCompositeFieldType::Integer,
+            CompositeFieldType::Float => PyCompositeFieldType::Float,
+            CompositeFieldType::Datetime => PyCompositeFieldType::Datetime,
+            CompositeFieldType::Uuid => PyCompositeFieldType::Uuid,
+        }
+    }
+}
+
+impl From<PyCompositeFieldType> for CompositeFieldType {
+    fn from(field_type: PyCompositeFieldType) -> Self {
+        match field_type {
+            PyCompositeFieldType::Keyword => CompositeFieldType::Keyword,
+            PyCompositeFieldType::Integer => CompositeFieldType::Integer,
+            PyCompositeFieldType::Float => CompositeFieldType::Float,
+            PyCompositeFieldType::Datetime => CompositeFieldType::Datetime,
+            PyCompositeFieldType::Uuid => CompositeFieldType::Uuid,
+        }
+    }
+}
@@ -22,6 +22,7 @@ impl PyPoint {
             id: PointIdType::from(id),
             vector: VectorStructPersisted::from(vector),
             payload: payload.map(Payload::from),
+            version: None,
         };
 
         Self(point)
@@ -54,6 +55,7 @@ impl PyPoint {
             id: _,
             vector: _,
             payload: _,
+            version: _, // Only set for points copied between replicas
         } = self.0;
     }
 }
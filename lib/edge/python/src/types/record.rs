@@ -31,7 +31,21 @@ impl PyRecord {
 
     #[getter]
     pub fn order_value(&self) -> Option<PyOrderValue> {
-        self.0.order_value.map(PyOrderValue::from)
+        self.0.order_value.clone().map(PyOrderValue::from)
+    }
+
+    #[getter]
+    pub fn then_by_values(&self) -> Vec<Option<PyOrderValue>> {
+        self.0
+            .then_by_values
+            .iter()
+            .map(|value| value.clone().map(PyOrderValue::from))
+            .collect()
+    }
+
+    #[getter]
+    pub fn version(&self) -> Option<u64> {
+        self.0.version
     }
 
     pub fn __repr__(&self) -> String {
@@ -48,6 +62,8 @@ impl PyRecord {
             vector: _,
             shard_key: _, // not relevant for Qdrant Edge
             order_value: _,
+            then_by_values: _,
+            version: _,
         } = self.0;
     }
 }
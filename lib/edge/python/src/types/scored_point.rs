@@ -3,7 +3,7 @@ use derive_more::Into;
 use pyo3::prelude::*;
 use segment::types::ScoredPoint;
 
-use super::PyOrderValue;
+use super::{PyFacetValue, PyOrderValue};
 use crate::repr::*;
 use crate::{PyPayload, PyPointId, PyVectorInternal};
 
@@ -42,7 +42,21 @@ impl PyScoredPoint {
 
     #[getter]
     pub fn order_value(&self) -> Option<PyOrderValue> {
-        self.0.order_value.map(PyOrderValue::from)
+        self.0.order_value.clone().map(PyOrderValue::from)
+    }
+
+    #[getter]
+    pub fn then_by_values(&self) -> Vec<Option<PyOrderValue>> {
+        self.0
+            .then_by_values
+            .iter()
+            .map(|value| value.clone().map(PyOrderValue::from))
+            .collect()
+    }
+
+    #[getter]
+    pub fn stratum(&self) -> Option<PyFacetValue> {
+        self.0.stratum.clone().map(PyFacetValue::from)
     }
 
     pub fn __repr__(&self) -> String {
@@ -61,6 +75,8 @@ impl PyScoredPoint {
             payload: _,
             shard_key: _, // not relevant for Qdrant Edge
             order_value: _,
+            then_by_values: _,
+            stratum: _,
         } = self.0;
     }
 }
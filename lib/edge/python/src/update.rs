@@ -34,6 +34,7 @@ impl PyUpdateOperation {
                     points_op: points,
                     condition: Filter::from(condition),
                     update_mode: mode,
+                    if_version: None,
                 },
             ),
             (None, Some(mode)) => point_ops::PointOperations::UpsertPointsConditional(
@@ -41,6 +42,7 @@ impl PyUpdateOperation {
                     points_op: points,
                     condition: Filter::default(),
                     update_mode: Some(mode),
+                    if_version: None,
                 },
             ),
             // Default case: regular upsert
@@ -106,6 +108,7 @@ impl PyUpdateOperation {
             points: Some(PyPointId::peel_vec(point_ids)),
             filter: None,
             key: key.map(JsonPath::from),
+            if_version: None,
         });
 
         Self(CollectionUpdateOperations::PayloadOperation(operation))
@@ -123,6 +126,7 @@ impl PyUpdateOperation {
             points: None,
             filter: Some(Filter::from(filter)),
             key: key.map(JsonPath::from),
+            if_version: None,
         });
 
         Self(CollectionUpdateOperations::PayloadOperation(operation))
@@ -177,6 +181,7 @@ impl PyUpdateOperation {
             points: Some(PyPointId::peel_vec(point_ids)),
             filter: None,
             key: key.map(JsonPath::from),
+            if_version: None,
         });
 
         Self(CollectionUpdateOperations::PayloadOperation(operation))
@@ -194,6 +199,7 @@ impl PyUpdateOperation {
             points: None,
             filter: Some(Filter::from(filter)),
             key: key.map(JsonPath::from),
+            if_version: None,
         });
 
         Self(CollectionUpdateOperations::PayloadOperation(operation))
@@ -88,7 +88,9 @@ impl EdgeConfig {
         let hnsw_configs: Vec<HnswConfig> = vector_data
             .values()
             .filter_map(|v| match &v.index {
-                segment::types::Indexes::Plain {} => None,
+                segment::types::Indexes::Plain {}
+                | segment::types::Indexes::Vamana(_)
+                | segment::types::Indexes::Ivf(_) => None,
                 segment::types::Indexes::Hnsw(h) => Some(*h),
             })
             .collect();
@@ -7,8 +7,9 @@
 use segment::data_types::modifier::Modifier;
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use segment::types::{
-    Distance, HnswConfig, Indexes, MultiVectorConfig, QuantizationConfig, SparseVectorDataConfig,
-    SparseVectorStorageType, VectorDataConfig, VectorStorageDatatype, VectorStorageType,
+    Distance, HnswConfig, Indexes, IvfConfig, MultiVectorConfig, QuantizationConfig,
+    SparseVectorDataConfig, SparseVectorStorageType, VamanaConfig, VectorDataConfig,
+    VectorStorageDatatype, VectorStorageType,
 };
 use serde::{Deserialize, Serialize};
 use shard::optimizers::config::DenseVectorOptimizerConfig;
@@ -34,6 +35,15 @@ pub struct EdgeVectorParams {
     pub quantization_config: Option<QuantizationConfig>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hnsw_config: Option<HnswConfig>,
+    /// If set, the disk-resident Vamana index is built instead of HNSW.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vamana_config: Option<VamanaConfig>,
+    /// If set, the IVF index is built instead of HNSW.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ivf_config: Option<IvfConfig>,
+    /// Number of leading dimensions of Matryoshka embeddings to index separately.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matryoshka_dim: Option<usize>,
 }
 
 impl EdgeVectorParams {
@@ -50,6 +60,9 @@ impl EdgeVectorParams {
             datatype,
             quantization_config,
             hnsw_config: _, // edge does not use per-vector HNSW config
+            vamana_config: _,
+            ivf_config: _,
+            matryoshka_dim,
         } = self;
 
         let resolved_quantization_config = quantization_config.as_ref().or(global_quantization);
@@ -63,6 +76,7 @@ impl EdgeVectorParams {
             quantization_config,
             multivector_config: *multivector_config,
             datatype: *datatype,
+            matryoshka_dim: *matryoshka_dim,
         }
     }
 
@@ -79,10 +93,15 @@ impl EdgeVectorParams {
             datatype: _,
             quantization_config,
             hnsw_config,
+            vamana_config,
+            ivf_config,
+            matryoshka_dim: _,
         } = self;
         DenseVectorOptimizerConfig {
             on_disk: *on_disk,
             hnsw_config: hnsw_config.unwrap_or(*global_hnsw_config),
+            vamana_config: *vamana_config,
+            ivf_config: *ivf_config,
             quantization_config: quantization_config
                 .clone()
                 .or_else(|| global_quantization_config.cloned()),
@@ -98,6 +117,7 @@ impl EdgeVectorParams {
             quantization_config, // edge uses global only
             multivector_config,
             datatype,
+            matryoshka_dim,
         } = v;
         Self {
             size: *size,
@@ -107,9 +127,18 @@ impl EdgeVectorParams {
             datatype: *datatype,
             quantization_config: quantization_config.clone(),
             hnsw_config: match index {
-                Indexes::Plain {} => None,
+                Indexes::Plain {} | Indexes::Vamana(_) | Indexes::Ivf(_) => None,
                 Indexes::Hnsw(hnsw_config) => Some(*hnsw_config),
             },
+            vamana_config: match index {
+                Indexes::Plain {} | Indexes::Hnsw(_) | Indexes::Ivf(_) => None,
+                Indexes::Vamana(vamana_config) => Some(*vamana_config),
+            },
+            ivf_config: match index {
+                Indexes::Plain {} | Indexes::Hnsw(_) | Indexes::Vamana(_) => None,
+                Indexes::Ivf(ivf_config) => Some(*ivf_config),
+            },
+            matryoshka_dim: *matryoshka_dim,
         }
     }
 }
@@ -717,6 +717,9 @@ mod tests {
                     datatype: None,
                     on_disk: None,
                     hnsw_config: None,
+                    matryoshka_dim: None,
+                    vamana_config: None,
+                    ivf_config: None,
                 },
             )]),
             sparse_vectors: HashMap::new(),
@@ -734,6 +737,7 @@ mod tests {
                 VectorInternal::from(vec![id as f32]),
             )]))),
             payload: None,
+            version: None,
         }
     }
 
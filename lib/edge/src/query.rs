@@ -27,6 +27,12 @@ use crate::DEFAULT_EDGE_TIMEOUT;
 
 impl EdgeShard {
     pub fn query(&self, request: ShardQueryRequest) -> OperationResult<Vec<ScoredPoint>> {
+        // Strata of the sampled points are only needed to merge the results of several shards
+        let is_stratified = matches!(
+            request.query,
+            Some(ScoringQuery::Sample(SampleInternal::Stratified(_)))
+        );
+
         let planned_query = PlannedQuery::try_from(vec![request])?;
 
         let PlannedQuery {
@@ -47,13 +53,19 @@ impl EdgeShard {
 
         let mut scored_points_batch = Vec::new();
         for root_plan in root_plans {
-            let scored_points = self.resolve_plan(
+            let mut scored_points = self.resolve_plan(
                 root_plan,
                 &mut search_results,
                 &mut scroll_results,
                 HwMeasurementAcc::disposable_edge(),
             )?;
 
+            if is_stratified {
+                scored_points
+                    .iter_mut()
+                    .for_each(|point| point.order_value = None);
+            }
+
             scored_points_batch.push(scored_points)
         }
 
@@ -221,6 +233,7 @@ impl EdgeShard {
                     with_payload: false.into(),
                     with_vector: false.into(),
                     scroll_order: ScrollOrder::ByField(order_by),
+                    offset_id: None,
                 };
 
                 self.query_scroll(&scroll_request)
@@ -252,23 +265,22 @@ impl EdgeShard {
                 hw_counter_acc,
             ),
 
-            ScoringQuery::Sample(sample) => match sample {
-                SampleInternal::Random => {
-                    // create single scroll request for rescoring query
-                    let filter = filter_by_point_ids(&sources);
-
-                    // Note: score_threshold is not used in this case, as all results will have same score and order_value
-                    let scroll_request = QueryScrollRequestInternal {
-                        limit,
-                        filter: Some(filter),
-                        with_payload: false.into(),
-                        with_vector: false.into(),
-                        scroll_order: ScrollOrder::Random,
-                    };
-
-                    self.query_scroll(&scroll_request)
-                }
-            },
+            ScoringQuery::Sample(sample) => {
+                // create single scroll request for rescoring query
+                let filter = filter_by_point_ids(&sources);
+
+                // Note: score_threshold is not used in this case, as scores of sampled points are random
+                let scroll_request = QueryScrollRequestInternal {
+                    limit,
+                    filter: Some(filter),
+                    with_payload: false.into(),
+                    with_vector: false.into(),
+                    scroll_order: ScrollOrder::from(sample),
+                    offset_id: None,
+                };
+
+                self.query_scroll(&scroll_request)
+            }
 
             ScoringQuery::Mmr(mmr) => self.mmr_rescore(sources, mmr, limit, hw_counter_acc),
         }
@@ -49,7 +49,8 @@ mod reexports_from_qdrant_crates {
     pub use shard::query::query_enum::QueryEnum;
     pub use shard::query::{
         FusionInternal as Fusion, MmrInternal as Mmr, SampleInternal as Sample, ScoringQuery,
-        ShardPrefetch as Prefetch, ShardQueryRequest as QueryRequest,
+        ShardPrefetch as Prefetch, ShardQueryRequest as QueryRequest, StratifiedSample,
+        WeightedSample,
     };
     pub use shard::retrieve::record_internal::RecordInternal as Record;
     pub use shard::scroll::ScrollRequestInternal as ScrollRequest;
@@ -8,8 +8,9 @@ use rand::RngExt;
 use rand::distr::weighted::WeightedIndex;
 use rand::rngs::StdRng;
 use segment::common::operation_error::{OperationError, OperationResult};
-use segment::data_types::order_by::{Direction, OrderBy};
+use segment::data_types::order_by::OrderBy;
 use segment::types::*;
+use shard::query::sample::PayloadSampling;
 use shard::query::scroll::{QueryScrollRequestInternal, ScrollOrder};
 use shard::retrieve::record_internal::RecordInternal;
 use shard::retrieve::retrieve_blocking::retrieve_blocking;
@@ -84,11 +85,12 @@ impl EdgeShard {
             filter,
             scroll_order,
             with_payload,
+            offset_id,
         } = request;
 
         let records = match scroll_order {
             ScrollOrder::ById => self.scroll_by_id(
-                None,
+                *offset_id,
                 *limit,
                 with_payload,
                 with_vector,
@@ -110,6 +112,27 @@ impl EdgeShard {
                 filter.as_ref(),
                 HwMeasurementAcc::disposable_edge(),
             )?,
+            // Sampled points are scored by their sampling keys
+            ScrollOrder::Stratified(stratified) => {
+                return self.scroll_sampled(
+                    PayloadSampling::Stratified(stratified),
+                    *limit,
+                    with_payload,
+                    with_vector,
+                    filter.as_ref(),
+                    HwMeasurementAcc::disposable_edge(),
+                );
+            }
+            ScrollOrder::Weighted(weighted) => {
+                return self.scroll_sampled(
+                    PayloadSampling::Weighted(weighted),
+                    *limit,
+                    with_payload,
+                    with_vector,
+                    filter.as_ref(),
+                    HwMeasurementAcc::disposable_edge(),
+                );
+            }
         };
 
         let point_results = records
@@ -122,6 +145,8 @@ impl EdgeShard {
                 vector: record.vector,
                 shard_key: record.shard_key,
                 order_value: record.order_value,
+                then_by_values: record.then_by_values,
+                stratum: None,
             })
             .collect();
 
@@ -201,15 +226,13 @@ impl EdgeShard {
             })
             .collect::<Result<_, _>>()?;
 
-        let (order_values, point_ids): (Vec<_>, Vec<_>) = read_results
+        let ordered_points: Vec<_> = read_results
             .into_iter()
-            .kmerge_by(|a, b| match order_by.direction() {
-                Direction::Asc => a <= b,
-                Direction::Desc => a >= b,
-            })
+            .kmerge_by(|a, b| order_by.cmp_keys(a.key(), b.key()).is_le())
             .dedup()
             .take(limit)
-            .unzip();
+            .collect();
+        let point_ids: Vec<_> = ordered_points.iter().map(|point| point.id).collect();
 
         let points = retrieve_blocking(
             self.segments.clone(),
@@ -222,12 +245,12 @@ impl EdgeShard {
             DeferredBehavior::Exclude,
         )?;
 
-        let ordered_points = point_ids
-            .iter()
-            .zip(order_values)
-            .filter_map(|(point_id, value)| {
-                let mut record = points.get(point_id).cloned()?;
-                record.order_value = Some(value);
+        let ordered_points = ordered_points
+            .into_iter()
+            .filter_map(|point| {
+                let mut record = points.get(&point.id).cloned()?;
+                record.order_value = Some(point.value);
+                record.then_by_values = point.then_by;
                 Some(record)
             })
             .collect();
@@ -235,6 +258,63 @@ impl EdgeShard {
         Ok(ordered_points)
     }
 
+    /// Sample points depending on their payload.
+    ///
+    /// Reads the sampled field of all points, which satisfy the filter.
+    fn scroll_sampled(
+        &self,
+        sampling: PayloadSampling<'_>,
+        limit: usize,
+        with_payload_interface: &WithPayloadInterface,
+        with_vector: &WithVector,
+        filter: Option<&Filter>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> OperationResult<Vec<ScoredPoint>> {
+        let candidates = self.scroll_by_id(
+            None,
+            usize::MAX,
+            &WithPayloadInterface::Fields(vec![sampling.key().clone()]),
+            &WithVector::Bool(false),
+            filter,
+            hw_measurement_acc.clone(),
+        )?;
+
+        let sampled = sampling.sample(
+            candidates.iter().map(|record| {
+                let values = record
+                    .payload
+                    .as_ref()
+                    .map(|payload| sampling.key().value_get(&payload.0))
+                    .unwrap_or_default();
+                (record.id, values)
+            }),
+            limit,
+            &mut rand::make_rng::<StdRng>(),
+        );
+        let point_ids = sampled.iter().map(|point| point.id).collect_vec();
+
+        let mut points = retrieve_blocking(
+            self.segments.clone(),
+            &point_ids,
+            &WithPayload::from(with_payload_interface),
+            with_vector,
+            DEFAULT_EDGE_TIMEOUT,
+            &AtomicBool::new(false),
+            hw_measurement_acc,
+            DeferredBehavior::Exclude,
+        )?;
+
+        let sampled_points = sampled
+            .into_iter()
+            .filter_map(|point| {
+                let record = points.remove(&point.id)?;
+                Some(point.into_scored_point(record))
+            })
+            .collect();
+
+        Ok(sampled_points)
+    }
+
     fn scroll_randomly(
         &self,
         limit: usize,
@@ -25,6 +25,7 @@ impl PointStruct {
             id: id.into(),
             vector: VectorStructPersisted::from(vectors.into().0),
             payload: Some(payload),
+            version: None,
         })
     }
 }
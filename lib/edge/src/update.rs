@@ -92,6 +92,9 @@ impl EdgeShard {
                                     datatype: wrapper.dense.datatype,
                                     quantization_config: None,
                                     hnsw_config: None,
+                                    matryoshka_dim: None,
+                                    vamana_config: None,
+                                    ivf_config: None,
                                 },
                             );
                         }
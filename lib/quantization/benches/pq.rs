@@ -33,6 +33,7 @@ fn encode_bench(c: &mut Criterion) {
         &vector_parameters,
         vectors_count,
         2,
+        false,
         2,
         None,
         &AtomicBool::new(false),
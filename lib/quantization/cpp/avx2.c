@@ -203,3 +203,74 @@ EXPORT uint32_t impl_xor_popcnt_scalar4_avx_uint128(
     HSUM256_PS(result_mm256, mul_scalar);
     return (uint32_t)mul_scalar;
 }
+
+// Unpack 32 4-bit codes: low nibbles hold even dimensions, high nibbles hold odd dimensions
+#define UNPACK_INT4_CODES(PTR, CODES1, CODES2) \
+    __m128i CODES1, CODES2; \
+    { \
+    __m128i packed = _mm_loadu_si128((const __m128i*)(PTR)); \
+    __m128i mask = _mm_set1_epi8(0x0F); \
+    __m128i low = _mm_and_si128(packed, mask); \
+    __m128i high = _mm_and_si128(_mm_srli_epi16(packed, 4), mask); \
+    CODES1 = _mm_unpacklo_epi8(low, high); \
+    CODES2 = _mm_unpackhi_epi8(low, high); \
+    }
+
+#define INT4_CODES_PS(CODES) _mm256_cvtepi32_ps(_mm256_cvtepu8_epi32(CODES))
+
+EXPORT float impl_score_int4_dot_avx(
+    const float* weights_ptr,
+    const uint8_t* codes_ptr,
+    uint32_t dim
+) {
+    __m256 sum1 = _mm256_setzero_ps();
+    __m256 sum2 = _mm256_setzero_ps();
+    // the vector sizes are assumed to be multiples of 32
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        UNPACK_INT4_CODES(codes_ptr, codes1, codes2);
+        codes_ptr += 16;
+
+        sum1 = _mm256_fmadd_ps(INT4_CODES_PS(codes1), _mm256_loadu_ps(weights_ptr), sum1);
+        sum2 = _mm256_fmadd_ps(INT4_CODES_PS(_mm_srli_si128(codes1, 8)), _mm256_loadu_ps(weights_ptr + 8), sum2);
+        sum1 = _mm256_fmadd_ps(INT4_CODES_PS(codes2), _mm256_loadu_ps(weights_ptr + 16), sum1);
+        sum2 = _mm256_fmadd_ps(INT4_CODES_PS(_mm_srli_si128(codes2, 8)), _mm256_loadu_ps(weights_ptr + 24), sum2);
+        weights_ptr += 32;
+    }
+    __m256 sum = _mm256_add_ps(sum1, sum2);
+    HSUM256_PS(sum, result);
+    return result;
+}
+
+EXPORT float impl_score_int4_l1_avx(
+    const float* alphas_ptr,
+    const float* biases_ptr,
+    const uint8_t* codes_ptr,
+    uint32_t dim
+) {
+    // clearing the sign bit gives the absolute value
+    const __m256 sign_mask = _mm256_set1_ps(-0.0f);
+    __m256 sum = _mm256_setzero_ps();
+    // the vector sizes are assumed to be multiples of 32
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        UNPACK_INT4_CODES(codes_ptr, codes1, codes2);
+        codes_ptr += 16;
+
+        __m256i parts[4] = {
+            _mm256_cvtepu8_epi32(codes1),
+            _mm256_cvtepu8_epi32(_mm_srli_si128(codes1, 8)),
+            _mm256_cvtepu8_epi32(codes2),
+            _mm256_cvtepu8_epi32(_mm_srli_si128(codes2, 8)),
+        };
+        for (int k = 0; k < 4; k++) {
+            __m256 diff = _mm256_fmadd_ps(
+                _mm256_cvtepi32_ps(parts[k]),
+                _mm256_loadu_ps(alphas_ptr),
+                _mm256_loadu_ps(biases_ptr));
+            sum = _mm256_add_ps(sum, _mm256_andnot_ps(sign_mask, diff));
+            alphas_ptr += 8;
+            biases_ptr += 8;
+        }
+    }
+    HSUM256_PS(sum, result);
+    return result;
+}
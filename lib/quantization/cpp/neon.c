@@ -463,3 +463,68 @@ EXPORT float impl_score_l1_neon(
 
     return (float) sum;
 }
+
+// Unpack 32 4-bit codes: low nibbles hold even dimensions, high nibbles hold odd dimensions
+static inline uint8x16x2_t unpack_int4_codes(const uint8_t* ptr) {
+    uint8x16_t packed = vld1q_u8(ptr);
+    uint8x16_t low = vandq_u8(packed, vdupq_n_u8(0x0F));
+    uint8x16_t high = vshrq_n_u8(packed, 4);
+    return vzipq_u8(low, high);
+}
+
+EXPORT float impl_score_int4_dot_neon(
+    const float* weights_ptr,
+    const uint8_t* codes_ptr,
+    uint32_t dim
+) {
+    float32x4_t sum1 = vdupq_n_f32(0.0f);
+    float32x4_t sum2 = vdupq_n_f32(0.0f);
+    // the vector sizes are assumed to be multiples of 32
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        uint8x16x2_t codes = unpack_int4_codes(codes_ptr);
+        codes_ptr += 16;
+
+        for (int p = 0; p < 2; p++) {
+            uint16x8_t c_low = vmovl_u8(vget_low_u8(codes.val[p]));
+            uint16x8_t c_high = vmovl_u8(vget_high_u8(codes.val[p]));
+            sum1 = vfmaq_f32(sum1, vcvtq_f32_u32(vmovl_u16(vget_low_u16(c_low))), vld1q_f32(weights_ptr));
+            sum2 = vfmaq_f32(sum2, vcvtq_f32_u32(vmovl_u16(vget_high_u16(c_low))), vld1q_f32(weights_ptr + 4));
+            sum1 = vfmaq_f32(sum1, vcvtq_f32_u32(vmovl_u16(vget_low_u16(c_high))), vld1q_f32(weights_ptr + 8));
+            sum2 = vfmaq_f32(sum2, vcvtq_f32_u32(vmovl_u16(vget_high_u16(c_high))), vld1q_f32(weights_ptr + 12));
+            weights_ptr += 16;
+        }
+    }
+    return vaddvq_f32(vaddq_f32(sum1, sum2));
+}
+
+EXPORT float impl_score_int4_l1_neon(
+    const float* alphas_ptr,
+    const float* biases_ptr,
+    const uint8_t* codes_ptr,
+    uint32_t dim
+) {
+    float32x4_t sum = vdupq_n_f32(0.0f);
+    // the vector sizes are assumed to be multiples of 32
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        uint8x16x2_t codes = unpack_int4_codes(codes_ptr);
+        codes_ptr += 16;
+
+        for (int p = 0; p < 2; p++) {
+            uint16x8_t c16[2] = {
+                vmovl_u8(vget_low_u8(codes.val[p])),
+                vmovl_u8(vget_high_u8(codes.val[p])),
+            };
+            for (int k = 0; k < 2; k++) {
+                float32x4_t c_low = vcvtq_f32_u32(vmovl_u16(vget_low_u16(c16[k])));
+                float32x4_t c_high = vcvtq_f32_u32(vmovl_u16(vget_high_u16(c16[k])));
+                float32x4_t diff_low = vfmaq_f32(vld1q_f32(biases_ptr), c_low, vld1q_f32(alphas_ptr));
+                float32x4_t diff_high = vfmaq_f32(vld1q_f32(biases_ptr + 4), c_high, vld1q_f32(alphas_ptr + 4));
+                sum = vaddq_f32(sum, vabsq_f32(diff_low));
+                sum = vaddq_f32(sum, vabsq_f32(diff_high));
+                alphas_ptr += 8;
+                biases_ptr += 8;
+            }
+        }
+    }
+    return vaddvq_f32(sum);
+}
@@ -511,3 +511,72 @@ EXPORT float impl_score_l1_sse(
 
     return (float) sum;
 }
+
+// Unpack 32 4-bit codes: low nibbles hold even dimensions, high nibbles hold odd dimensions
+#define UNPACK_INT4_CODES(PTR, CODES) \
+    __m128i CODES[2]; \
+    { \
+    __m128i packed = _mm_loadu_si128((const __m128i*)(PTR)); \
+    __m128i mask = _mm_set1_epi8(0x0F); \
+    __m128i low = _mm_and_si128(packed, mask); \
+    __m128i high = _mm_and_si128(_mm_srli_epi16(packed, 4), mask); \
+    CODES[0] = _mm_unpacklo_epi8(low, high); \
+    CODES[1] = _mm_unpackhi_epi8(low, high); \
+    }
+
+EXPORT float impl_score_int4_dot_sse(
+    const float* weights_ptr,
+    const uint8_t* codes_ptr,
+    uint32_t dim
+) {
+    __m128 sum = _mm_setzero_ps();
+    // the vector sizes are assumed to be multiples of 32
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        UNPACK_INT4_CODES(codes_ptr, codes);
+        codes_ptr += 16;
+
+        for (int p = 0; p < 2; p++) {
+            __m128i c = codes[p];
+            for (int k = 0; k < 4; k++) {
+                __m128 c_ps = _mm_cvtepi32_ps(_mm_cvtepu8_epi32(c));
+                sum = _mm_add_ps(sum, _mm_mul_ps(c_ps, _mm_loadu_ps(weights_ptr)));
+                weights_ptr += 4;
+                c = _mm_srli_si128(c, 4);
+            }
+        }
+    }
+    HSUM128_PS(sum, result);
+    return result;
+}
+
+EXPORT float impl_score_int4_l1_sse(
+    const float* alphas_ptr,
+    const float* biases_ptr,
+    const uint8_t* codes_ptr,
+    uint32_t dim
+) {
+    // clearing the sign bit gives the absolute value
+    const __m128 sign_mask = _mm_set1_ps(-0.0f);
+    __m128 sum = _mm_setzero_ps();
+    // the vector sizes are assumed to be multiples of 32
+    for (uint32_t _i = 0; _i < dim / 32; _i++) {
+        UNPACK_INT4_CODES(codes_ptr, codes);
+        codes_ptr += 16;
+
+        for (int p = 0; p < 2; p++) {
+            __m128i c = codes[p];
+            for (int k = 0; k < 4; k++) {
+                __m128 c_ps = _mm_cvtepi32_ps(_mm_cvtepu8_epi32(c));
+                __m128 diff = _mm_add_ps(
+                    _mm_mul_ps(c_ps, _mm_loadu_ps(alphas_ptr)),
+                    _mm_loadu_ps(biases_ptr));
+                sum = _mm_add_ps(sum, _mm_andnot_ps(sign_mask, diff));
+                alphas_ptr += 4;
+                biases_ptr += 4;
+                c = _mm_srli_si128(c, 4);
+            }
+        }
+    }
+    HSUM128_PS(sum, result);
+    return result;
+}
@@ -20,8 +20,11 @@ use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
 use crate::encoded_storage::{EncodedStorage, EncodedStorageBuilder};
-use crate::encoded_vectors::{EncodedVectors, VectorParameters, validate_vector_parameters};
+use crate::encoded_vectors::{
+    DistanceType, EncodedVectors, VectorParameters, validate_vector_parameters,
+};
 use crate::kmeans::kmeans;
+use crate::opq::{OPQ_SAMPLE_SIZE, OpqRotation};
 use crate::{ConditionalVariable, EncodingError};
 
 pub const KMEANS_SAMPLE_SIZE: usize = 10_000;
@@ -33,6 +36,8 @@ pub struct EncodedVectorsPQ<TStorage: EncodedStorage> {
     encoded_vectors: TStorage,
     metadata: Metadata,
     metadata_path: Option<PathBuf>,
+    /// OPQ rotation, applied to vectors and queries before the quantization
+    rotation: Option<OpqRotation>,
 }
 
 /// PQ lookup table
@@ -47,6 +52,9 @@ pub struct Metadata {
     pub centroids: Vec<Vec<f32>>,
     pub vector_division: Vec<Range<usize>>,
     pub vector_parameters: VectorParameters,
+    /// Centroids are trained on OPQ rotated vectors, the rotation is stored in a separate file
+    #[serde(default)]
+    pub opq: bool,
 }
 
 impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
@@ -61,6 +69,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
     /// * `storage_builder` - encoding result storage builder
     /// * `vector_parameters` - parameters of original vector data (dimension, distance, etc)
     /// * `chunk_size` - Max size of f32 chunk that replaced by centroid index (in original vector dimension)
+    /// * `opq` - Learn OPQ rotation and quantize rotated vectors. Ignored for L1 distance, which is not rotation invariant
     /// * `max_threads` - Max allowed threads for kmeans and encodind process
     /// * `stopped` - Atomic bool that indicates if encoding should be stopped
     #[allow(clippy::too_many_arguments)]
@@ -70,6 +79,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
         vector_parameters: &VectorParameters,
         count: usize,
         chunk_size: usize,
+        opq: bool,
         max_kmeans_threads: usize,
         meta_path: Option<&Path>,
         stopped: &AtomicBool,
@@ -79,26 +89,42 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
         // first, divide vector into chunks
         let vector_division = Self::get_vector_division(vector_parameters.dim, chunk_size);
 
-        // then, find flattened centroid positions
-        let centroids = Self::find_centroids(
-            data.clone(),
-            &vector_division,
-            vector_parameters,
-            count,
-            CENTROIDS_COUNT,
-            max_kmeans_threads,
-            stopped,
-        )?;
+        // learn rotation, which balances variance between chunks
+        let rotation = match vector_parameters.distance_type {
+            DistanceType::Dot | DistanceType::Cosine | DistanceType::L2 if opq && count > 0 => {
+                Some(Self::train_rotation(
+                    data.clone(),
+                    &vector_division,
+                    vector_parameters,
+                    count,
+                    stopped,
+                )?)
+            }
+            DistanceType::Dot | DistanceType::Cosine | DistanceType::L2 | DistanceType::L1 => None,
+        };
 
-        // finally, encode data
-        Self::encode_storage(
-            data,
-            &mut storage_builder,
-            &vector_division,
-            &centroids,
-            max_kmeans_threads,
-            stopped,
-        )?;
+        // then, find flattened centroid positions and encode data
+        let centroids = if let Some(rotation) = &rotation {
+            Self::train_and_encode(
+                data.map(|vector| rotation.rotate(vector.as_ref())),
+                &mut storage_builder,
+                &vector_division,
+                vector_parameters,
+                count,
+                max_kmeans_threads,
+                stopped,
+            )?
+        } else {
+            Self::train_and_encode(
+                data,
+                &mut storage_builder,
+                &vector_division,
+                vector_parameters,
+                count,
+                max_kmeans_threads,
+                stopped,
+            )?
+        };
 
         let encoded_vectors = storage_builder
             .build()
@@ -108,6 +134,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
             centroids,
             vector_division,
             vector_parameters: *vector_parameters,
+            opq: rotation.is_some(),
         };
         if let Some(meta_path) = meta_path {
             meta_path
@@ -124,6 +151,13 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
                         "Failed to create metadata directory: {e}",
                     ))
                 })?;
+            if let Some(rotation) = &rotation {
+                rotation
+                    .save(&Self::get_rotation_path(meta_path))
+                    .map_err(|e| {
+                        EncodingError::EncodingError(format!("Failed to save OPQ rotation: {e}",))
+                    })?;
+            }
             atomic_save_json(meta_path, &metadata).map_err(|e| {
                 EncodingError::EncodingError(format!("Failed to save metadata: {e}",))
             })?;
@@ -134,6 +168,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
                 encoded_vectors,
                 metadata,
                 metadata_path: meta_path.map(PathBuf::from),
+                rotation,
             })
         } else {
             Err(EncodingError::Stopped)
@@ -143,14 +178,89 @@ impl<TStorage: EncodedStorage> EncodedVectorsPQ<TStorage> {
     pub fn load(encoded_vectors: TStorage, meta_path: &Path) -> std::io::Result<Self> {
         let contents = fs::read_to_string(meta_path)?;
         let metadata: Metadata = serde_json::from_str(&contents)?;
+        let rotation = if metadata.opq {
+            Some(OpqRotation::load(
+                &Self::get_rotation_path(meta_path),
+                metadata.vector_parameters.dim,
+            )?)
+        } else {
+            None
+        };
         let result = Self {
             encoded_vectors,
             metadata,
             metadata_path: Some(meta_path.to_path_buf()),
+            rotation,
         };
         Ok(result)
     }
 
+    fn get_rotation_path(meta_path: &Path) -> PathBuf {
+        meta_path.with_extension("opq.bin")
+    }
+
+    /// Learn OPQ rotation on a random subset of data
+    fn train_rotation<'a>(
+        data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        vector_division: &[Range<usize>],
+        vector_parameters: &VectorParameters,
+        count: usize,
+        stopped: &AtomicBool,
+    ) -> Result<OpqRotation, EncodingError> {
+        let sample_size = OPQ_SAMPLE_SIZE.min(count);
+        let mut selected_vectors: Vec<usize> = permutation_iterator::Permutor::new(count as u64)
+            .map(|i| i as usize)
+            .take(sample_size)
+            .collect();
+        selected_vectors.sort_unstable();
+
+        let mut sample = Vec::with_capacity(sample_size * vector_parameters.dim);
+        let mut selected = selected_vectors.iter().peekable();
+        for (vector_index, vector_data) in data.enumerate() {
+            if selected.peek().is_none() {
+                break;
+            }
+            if selected.next_if_eq(&&vector_index).is_some() {
+                sample.extend_from_slice(vector_data.as_ref());
+            }
+        }
+
+        OpqRotation::train(&sample, vector_parameters.dim, vector_division, stopped)
+    }
+
+    /// Find centroids and encode data with them, returns flattened centroid positions
+    #[allow(clippy::too_many_arguments)]
+    fn train_and_encode<'a>(
+        data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone + Send,
+        storage_builder: &mut (impl EncodedStorageBuilder<Storage = TStorage> + Send),
+        vector_division: &[Range<usize>],
+        vector_parameters: &VectorParameters,
+        count: usize,
+        max_kmeans_threads: usize,
+        stopped: &AtomicBool,
+    ) -> Result<Vec<Vec<f32>>, EncodingError> {
+        let centroids = Self::find_centroids(
+            data.clone(),
+            vector_division,
+            vector_parameters,
+            count,
+            CENTROIDS_COUNT,
+            max_kmeans_threads,
+            stopped,
+        )?;
+
+        Self::encode_storage(
+            data,
+            storage_builder,
+            vector_division,
+            &centroids,
+            max_kmeans_threads,
+            stopped,
+        )?;
+
+        Ok(centroids)
+    }
+
     fn get_vector_division(dim: usize, chunk_size: usize) -> Vec<Range<usize>> {
         (0..dim)
             .step_by(chunk_size)
@@ -503,6 +613,14 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsPQ<TStorage> {
     }
 
     fn encode_query(&self, query: &[f32]) -> EncodedQueryPQ {
+        let rotated_query;
+        let query = if let Some(rotation) = &self.rotation {
+            rotated_query = rotation.rotate(query);
+            rotated_query.as_slice()
+        } else {
+            query
+        };
+
         let lut_capacity = self.metadata.vector_division.len() * self.metadata.centroids.len();
         let mut lut = Vec::with_capacity(lut_capacity);
         for range in &self.metadata.vector_division {
@@ -637,6 +755,9 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsPQ<TStorage> {
         let mut files = self.encoded_vectors.files();
         if let Some(meta_path) = &self.metadata_path {
             files.push(meta_path.clone());
+            if self.rotation.is_some() {
+                files.push(Self::get_rotation_path(meta_path));
+            }
         }
         files
     }
@@ -645,6 +766,9 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsPQ<TStorage> {
         let mut files = self.encoded_vectors.immutable_files();
         if let Some(meta_path) = &self.metadata_path {
             files.push(meta_path.clone());
+            if self.rotation.is_some() {
+                files.push(Self::get_rotation_path(meta_path));
+            }
         }
         files
     }
@@ -663,7 +787,11 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsPQ<TStorage> {
                 .sum::<usize>();
         let vector_division_heap =
             self.metadata.vector_division.capacity() * std::mem::size_of::<Range<usize>>();
-        storage_heap + centroids_heap + vector_division_heap
+        let rotation_heap = self
+            .rotation
+            .as_ref()
+            .map_or(0, |rotation| rotation.heap_size_bytes());
+        storage_heap + centroids_heap + vector_division_heap + rotation_heap
     }
 
     type SupportsBytes = True;
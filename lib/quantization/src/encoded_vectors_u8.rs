@@ -16,16 +16,23 @@ use crate::encoded_storage::{EncodedStorage, EncodedStorageBuilder};
 use crate::encoded_vectors::{
     DistanceType, EncodedVectors, VectorParameters, validate_vector_parameters,
 };
-use crate::quantile::{find_min_max_from_iter, find_quantile_interval};
+use crate::quantile::{
+    SAMPLE_SIZE, find_min_max_from_iter, find_quantile_interval,
+    find_quantile_interval_per_coordinate_with_preprocess,
+};
 
 pub const ALIGNMENT: usize = 16;
 // Each encoded vector stores an additional f32 at the beginning. Define it's size here.
 const ADDITIONAL_CONSTANT_SIZE: usize = std::mem::size_of::<f32>();
+/// Largest 4-bit code
+const INT4_MAX_CODE: f32 = 15.0;
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ScalarQuantizationMethod {
     Int8,
-    // Future methods can be added here
+    /// 4 bits per dimension, two dimensions packed in a byte.
+    /// Each dimension has its own value range, and queries are not quantized.
+    Int4,
 }
 
 pub struct EncodedVectorsU8<TStorage: EncodedStorage> {
@@ -36,46 +43,32 @@ pub struct EncodedVectorsU8<TStorage: EncodedStorage> {
 
 pub struct EncodedQueryU8 {
     offset: f32,
+    /// Quantized query of `Int8` method
     encoded_query: Vec<u8>,
+    /// Weights of 4-bit codes of `Int4` method
+    weights: Vec<f32>,
 }
 
 #[derive(Serialize, Deserialize)]
 #[serde(untagged)]
 enum Metadata {
     Int8(MetadataInt8),
+    Int4(MetadataInt4),
 }
 
 impl Metadata {
     pub fn vector_parameters(&self) -> &VectorParameters {
         match self {
             Metadata::Int8(meta) => &meta.vector_parameters,
+            Metadata::Int4(meta) => &meta.vector_parameters,
         }
     }
 
-    pub fn actual_dim(&self) -> usize {
+    /// Size of the codes of a vector, without the additional constant
+    pub fn code_size(&self) -> usize {
         match self {
             Metadata::Int8(meta) => meta.actual_dim,
-        }
-    }
-
-    pub fn postprocess_score(&self, score: f32, query_offset: f32, vector_offset: f32) -> f32 {
-        match self {
-            Metadata::Int8(metadata) => {
-                metadata.postprocess_score(score, query_offset, vector_offset)
-            }
-        }
-    }
-
-    pub fn postprocess_internal_score(
-        &self,
-        score: f32,
-        query_offset: f32,
-        vector_offset: f32,
-    ) -> f32 {
-        match self {
-            Metadata::Int8(metadata) => {
-                metadata.postprocess_internal_score(score, query_offset, vector_offset)
-            }
+            Metadata::Int4(meta) => meta.actual_dim / 2,
         }
     }
 }
@@ -131,6 +124,175 @@ impl MetadataInt8 {
     }
 }
 
+/// Per-dimension uniform 4-bit quantization.
+///
+/// Value of dimension `i` is restored as `offsets[i] + alphas[i] * code`.
+/// Queries are kept in full precision: for dot product and L2 a query is turned into
+/// per-dimension weights of the codes, for L1 into per-dimension biases.
+#[derive(Serialize, Deserialize)]
+struct MetadataInt4 {
+    actual_dim: usize,
+    /// Step between consecutive codes, zero for padding dimensions
+    alphas: Vec<f32>,
+    /// Value of the zero code, zero for padding dimensions
+    offsets: Vec<f32>,
+    vector_parameters: VectorParameters,
+}
+
+impl MetadataInt4 {
+    fn new(ranges: &[(f32, f32)], vector_parameters: &VectorParameters) -> Self {
+        let actual_dim = get_actual_dim_int4(vector_parameters);
+        let mut alphas = vec![0.0; actual_dim];
+        let mut offsets = vec![0.0; actual_dim];
+        for (i, &(min, max)) in ranges.iter().enumerate() {
+            alphas[i] = (max - min).max(0.0) / INT4_MAX_CODE;
+            offsets[i] = min;
+        }
+        Self {
+            actual_dim,
+            alphas,
+            offsets,
+            vector_parameters: *vector_parameters,
+        }
+    }
+
+    #[inline]
+    fn encode_value(&self, i: usize, value: f32) -> u8 {
+        if self.alphas[i] == 0.0 {
+            return 0;
+        }
+        let code = (value - self.offsets[i]) / self.alphas[i];
+        code.clamp(0.0, INT4_MAX_CODE).round() as u8
+    }
+
+    #[inline]
+    fn decode_value(&self, i: usize, code: u8) -> f32 {
+        self.offsets[i] + self.alphas[i] * f32::from(code)
+    }
+
+    /// Encoded vector: the additional constant followed by packed codes.
+    /// Low nibble of a byte holds an even dimension, high nibble holds the next one.
+    fn encode_vector(&self, vector: &[f32]) -> Vec<u8> {
+        let mut encoded_vector = Vec::with_capacity(ADDITIONAL_CONSTANT_SIZE + self.actual_dim / 2);
+        encoded_vector.extend_from_slice(&f32::default().to_ne_bytes());
+        encoded_vector.resize(ADDITIONAL_CONSTANT_SIZE + self.actual_dim / 2, 0);
+
+        let codes = &mut encoded_vector[ADDITIONAL_CONSTANT_SIZE..];
+        for (i, &value) in vector.iter().enumerate() {
+            codes[i / 2] |= self.encode_value(i, value) << (4 * (i % 2));
+        }
+
+        let vector_offset: f32 = match self.vector_parameters.distance_type {
+            DistanceType::Dot | DistanceType::Cosine | DistanceType::L1 => 0.0,
+            // Squared norm of the restored vector
+            DistanceType::L2 => (0..self.actual_dim)
+                .map(|i| self.decode_value(i, get_int4_code(codes, i)).powi(2))
+                .sum(),
+        };
+        encoded_vector[0..ADDITIONAL_CONSTANT_SIZE].copy_from_slice(&vector_offset.to_ne_bytes());
+        encoded_vector
+    }
+
+    fn decode_vector(&self, codes: &[u8]) -> Vec<f32> {
+        (0..self.vector_parameters.dim)
+            .map(|i| self.decode_value(i, get_int4_code(codes, i)))
+            .collect()
+    }
+
+    fn encode_query(&self, query: &[f32]) -> EncodedQueryU8 {
+        let mut weights = vec![0.0; self.actual_dim];
+        let offset = match self.vector_parameters.distance_type {
+            // x * q = sum(offset * q) + sum(alpha * q * code)
+            DistanceType::Dot | DistanceType::Cosine => {
+                let mut offset = 0.0;
+                for (i, &value) in query.iter().enumerate() {
+                    weights[i] = self.alphas[i] * value;
+                    offset += self.offsets[i] * value;
+                }
+                offset
+            }
+            // |x - q|^2 = |x|^2 - 2 * x * q + |q|^2, where |x|^2 is stored within the vector
+            DistanceType::L2 => {
+                let mut offset = 0.0;
+                for (i, &value) in query.iter().enumerate() {
+                    weights[i] = -2.0 * self.alphas[i] * value;
+                    offset += value * value - 2.0 * self.offsets[i] * value;
+                }
+                offset
+            }
+            // |x - q| = |alpha * code + (offset - q)|, weights are the biases
+            DistanceType::L1 => {
+                for (i, &value) in query.iter().enumerate() {
+                    weights[i] = self.offsets[i] - value;
+                }
+                0.0
+            }
+        };
+        EncodedQueryU8 {
+            offset,
+            encoded_query: Vec::new(),
+            weights,
+        }
+    }
+
+    /// Score of the query, `dot` and `l1` compute the sums over codes of
+    /// [`impl_score_int4_dot`] and [`impl_score_int4_l1`] respectively
+    #[inline]
+    fn score(
+        &self,
+        query: &EncodedQueryU8,
+        vector_offset: f32,
+        codes_ptr: *const u8,
+        dot: impl FnOnce(*const f32, *const u8, usize) -> f32,
+        l1: impl FnOnce(*const f32, *const f32, *const u8, usize) -> f32,
+    ) -> f32 {
+        let score = match self.vector_parameters.distance_type {
+            DistanceType::Dot | DistanceType::Cosine | DistanceType::L2 => {
+                dot(query.weights.as_ptr(), codes_ptr, self.actual_dim)
+                    + query.offset
+                    + vector_offset
+            }
+            DistanceType::L1 => l1(
+                self.alphas.as_ptr(),
+                query.weights.as_ptr(),
+                codes_ptr,
+                self.actual_dim,
+            ),
+        };
+        self.postprocess_score(score)
+    }
+
+    /// Score between two stored vectors, computed on restored values
+    fn score_internal(&self, codes_1: &[u8], codes_2: &[u8]) -> f32 {
+        let values = (0..self.actual_dim).map(|i| {
+            (
+                self.decode_value(i, get_int4_code(codes_1, i)),
+                self.decode_value(i, get_int4_code(codes_2, i)),
+            )
+        });
+        let score: f32 = match self.vector_parameters.distance_type {
+            DistanceType::Dot | DistanceType::Cosine => values.map(|(x, y)| x * y).sum(),
+            DistanceType::L1 => values.map(|(x, y)| (x - y).abs()).sum(),
+            DistanceType::L2 => values.map(|(x, y)| (x - y) * (x - y)).sum(),
+        };
+        self.postprocess_score(score)
+    }
+
+    #[inline]
+    fn postprocess_score(&self, score: f32) -> f32 {
+        if self.vector_parameters.invert {
+            -score
+        } else {
+            score
+        }
+    }
+}
+
+#[inline]
+fn get_int4_code(codes: &[u8], i: usize) -> u8 {
+    (codes[i / 2] >> (4 * (i % 2))) & 0x0F
+}
+
 impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
     pub fn storage(&self) -> &TStorage {
         &self.encoded_vectors
@@ -147,46 +309,81 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
         meta_path: Option<&Path>,
         stopped: &AtomicBool,
     ) -> Result<Self, EncodingError> {
-        assert_eq!(method, ScalarQuantizationMethod::Int8);
-        let actual_dim = get_actual_dim(vector_parameters);
+        let metadata = if count == 0 {
+            match method {
+                ScalarQuantizationMethod::Int8 => Metadata::Int8(MetadataInt8 {
+                    actual_dim: get_actual_dim(vector_parameters),
+                    alpha: 0.0,
+                    offset: 0.0,
+                    multiplier: 0.0,
+                    vector_parameters: *vector_parameters,
+                }),
+                ScalarQuantizationMethod::Int4 => {
+                    Metadata::Int4(MetadataInt4::new(&[], vector_parameters))
+                }
+            }
+        } else {
+            debug_assert!(validate_vector_parameters(orig_data.clone(), vector_parameters).is_ok());
+            match method {
+                ScalarQuantizationMethod::Int8 => Self::encode_int8(
+                    orig_data,
+                    &mut storage_builder,
+                    vector_parameters,
+                    count,
+                    quantile,
+                    stopped,
+                )?,
+                ScalarQuantizationMethod::Int4 => Self::encode_int4(
+                    orig_data,
+                    &mut storage_builder,
+                    vector_parameters,
+                    count,
+                    quantile,
+                    stopped,
+                )?,
+            }
+        };
 
-        if count == 0 {
-            let metadata = Metadata::Int8(MetadataInt8 {
-                actual_dim,
-                alpha: 0.0,
-                offset: 0.0,
-                multiplier: 0.0,
-                vector_parameters: *vector_parameters,
-            });
-            if let Some(meta_path) = meta_path {
-                meta_path
-                    .parent()
-                    .ok_or_else(|| {
-                        std::io::Error::new(
-                            std::io::ErrorKind::InvalidInput,
-                            "Path must have a parent directory",
-                        )
-                    })
-                    .and_then(fs::create_dir_all)
-                    .map_err(|e| {
-                        EncodingError::EncodingError(format!(
-                            "Failed to create metadata directory: {e}",
-                        ))
-                    })?;
-                atomic_save_json(meta_path, &metadata).map_err(|e| {
-                    EncodingError::EncodingError(format!("Failed to save metadata: {e}",))
+        let encoded_vectors = storage_builder
+            .build()
+            .map_err(|e| EncodingError::EncodingError(format!("Failed to build storage: {e}",)))?;
+
+        if let Some(meta_path) = meta_path {
+            meta_path
+                .parent()
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "Path must have a parent directory",
+                    )
+                })
+                .and_then(fs::create_dir_all)
+                .map_err(|e| {
+                    EncodingError::EncodingError(format!(
+                        "Failed to create metadata directory: {e}",
+                    ))
                 })?;
-            }
-            return Ok(EncodedVectorsU8 {
-                encoded_vectors: storage_builder.build().map_err(|e| {
-                    EncodingError::EncodingError(format!("Failed to build storage: {e}",))
-                })?,
-                metadata,
-                metadata_path: meta_path.map(PathBuf::from),
-            });
+            atomic_save_json(meta_path, &metadata).map_err(|e| {
+                EncodingError::EncodingError(format!("Failed to save metadata: {e}",))
+            })?;
         }
 
-        debug_assert!(validate_vector_parameters(orig_data.clone(), vector_parameters).is_ok());
+        Ok(EncodedVectorsU8 {
+            encoded_vectors,
+            metadata,
+            metadata_path: meta_path.map(PathBuf::from),
+        })
+    }
+
+    fn encode_int8<'a>(
+        orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        storage_builder: &mut impl EncodedStorageBuilder<Storage = TStorage>,
+        vector_parameters: &VectorParameters,
+        count: usize,
+        quantile: Option<f32>,
+        stopped: &AtomicBool,
+    ) -> Result<Metadata, EncodingError> {
+        let actual_dim = get_actual_dim(vector_parameters);
         let (alpha, offset) = Self::find_alpha_offset_size_dim(orig_data.clone());
         let (alpha, offset) = if let Some(quantile) = quantile {
             if let Some((min, max)) = find_quantile_interval(
@@ -280,36 +477,60 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                 })?;
         }
 
-        let encoded_vectors = storage_builder
-            .build()
-            .map_err(|e| EncodingError::EncodingError(format!("Failed to build storage: {e}",)))?;
+        Ok(Metadata::Int8(metadata))
+    }
 
-        let metadata = Metadata::Int8(metadata);
-        if let Some(meta_path) = meta_path {
-            meta_path
-                .parent()
-                .ok_or_else(|| {
-                    std::io::Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        "Path must have a parent directory",
-                    )
-                })
-                .and_then(fs::create_dir_all)
+    fn encode_int4<'a>(
+        orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
+        storage_builder: &mut impl EncodedStorageBuilder<Storage = TStorage>,
+        vector_parameters: &VectorParameters,
+        count: usize,
+        quantile: Option<f32>,
+        stopped: &AtomicBool,
+    ) -> Result<Metadata, EncodingError> {
+        let dim = vector_parameters.dim;
+        let ranges = match quantile {
+            Some(quantile) if quantile > 0.5 && quantile < 1.0 => {
+                find_quantile_interval_per_coordinate_with_preprocess(
+                    orig_data.clone(),
+                    dim,
+                    dim,
+                    count,
+                    quantile,
+                    1,
+                    SAMPLE_SIZE,
+                    |vector: &[f32], scratch: &mut [f64]| {
+                        for (dst, &value) in scratch.iter_mut().zip(vector) {
+                            *dst = f64::from(value);
+                        }
+                    },
+                    stopped,
+                )?
+            }
+            _ => {
+                let mut ranges = vec![(f32::MAX, f32::MIN); dim];
+                for vector in orig_data.clone() {
+                    for ((min, max), &value) in ranges.iter_mut().zip(vector.as_ref()) {
+                        *min = min.min(value);
+                        *max = max.max(value);
+                    }
+                }
+                ranges
+            }
+        };
+
+        let metadata = MetadataInt4::new(&ranges, vector_parameters);
+        for vector in orig_data {
+            if stopped.load(Ordering::Relaxed) {
+                return Err(EncodingError::Stopped);
+            }
+            storage_builder
+                .push_vector_data(&metadata.encode_vector(vector.as_ref()))
                 .map_err(|e| {
-                    EncodingError::EncodingError(format!(
-                        "Failed to create metadata directory: {e}",
-                    ))
+                    EncodingError::EncodingError(format!("Failed to push encoded vector: {e}",))
                 })?;
-            atomic_save_json(meta_path, &metadata).map_err(|e| {
-                EncodingError::EncodingError(format!("Failed to save metadata: {e}",))
-            })?;
         }
-
-        Ok(EncodedVectorsU8 {
-            encoded_vectors,
-            metadata,
-            metadata_path: meta_path.map(PathBuf::from),
-        })
+        Ok(Metadata::Int4(metadata))
     }
 
     pub fn load(encoded_vectors: TStorage, meta_path: &Path) -> std::io::Result<Self> {
@@ -336,8 +557,17 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                     DistanceType::L1 => impl_score_l1(q_ptr, v_ptr, metadata.actual_dim),
                 };
 
-                self.metadata
-                    .postprocess_score(score as f32, query.offset, vector_offset)
+                metadata.postprocess_score(score as f32, query.offset, vector_offset)
+            }
+            Metadata::Int4(metadata) => {
+                let (vector_offset, v_ptr) = Self::parse_vec_data(bytes);
+                metadata.score(
+                    query,
+                    vector_offset,
+                    v_ptr,
+                    impl_score_int4_dot,
+                    impl_score_int4_l1,
+                )
             }
         }
     }
@@ -353,9 +583,9 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                     }
                     DistanceType::L1 => impl_score_l1(q_ptr, v_ptr, metadata.actual_dim),
                 };
-                self.metadata
-                    .postprocess_internal_score(score as f32, query_offset, vector_offset)
+                metadata.postprocess_internal_score(score as f32, query_offset, vector_offset)
             }
+            Metadata::Int4(metadata) => self.score_int4_internal(metadata, i, j),
         }
     }
 
@@ -374,8 +604,21 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                         impl_score_l1_neon(q_ptr, v_ptr, metadata.actual_dim as u32)
                     },
                 };
-                self.metadata
-                    .postprocess_score(score, query.offset, vector_offset)
+                metadata.postprocess_score(score, query.offset, vector_offset)
+            }
+            Metadata::Int4(metadata) => {
+                let (vector_offset, v_ptr) = Self::parse_vec_data(bytes);
+                metadata.score(
+                    query,
+                    vector_offset,
+                    v_ptr,
+                    |weights_ptr, codes_ptr, dim| unsafe {
+                        impl_score_int4_dot_neon(weights_ptr, codes_ptr, dim as u32)
+                    },
+                    |alphas_ptr, biases_ptr, codes_ptr, dim| unsafe {
+                        impl_score_int4_l1_neon(alphas_ptr, biases_ptr, codes_ptr, dim as u32)
+                    },
+                )
             }
         }
     }
@@ -395,9 +638,9 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                         impl_score_l1_neon(q_ptr, v_ptr, metadata.actual_dim as u32)
                     },
                 };
-                self.metadata
-                    .postprocess_internal_score(score, query_offset, vector_offset)
+                metadata.postprocess_internal_score(score, query_offset, vector_offset)
             }
+            Metadata::Int4(metadata) => self.score_int4_internal(metadata, i, j),
         }
     }
 
@@ -416,8 +659,21 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                         impl_score_l1_sse(q_ptr, v_ptr, metadata.actual_dim as u32)
                     },
                 };
-                self.metadata
-                    .postprocess_score(score, query.offset, vector_offset)
+                metadata.postprocess_score(score, query.offset, vector_offset)
+            }
+            Metadata::Int4(metadata) => {
+                let (vector_offset, v_ptr) = Self::parse_vec_data(bytes);
+                metadata.score(
+                    query,
+                    vector_offset,
+                    v_ptr,
+                    |weights_ptr, codes_ptr, dim| unsafe {
+                        impl_score_int4_dot_sse(weights_ptr, codes_ptr, dim as u32)
+                    },
+                    |alphas_ptr, biases_ptr, codes_ptr, dim| unsafe {
+                        impl_score_int4_l1_sse(alphas_ptr, biases_ptr, codes_ptr, dim as u32)
+                    },
+                )
             }
         }
     }
@@ -437,9 +693,9 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                         impl_score_l1_sse(q_ptr, v_ptr, metadata.actual_dim as u32)
                     },
                 };
-                self.metadata
-                    .postprocess_internal_score(score, query_offset, vector_offset)
+                metadata.postprocess_internal_score(score, query_offset, vector_offset)
             }
+            Metadata::Int4(metadata) => self.score_int4_internal(metadata, i, j),
         }
     }
 
@@ -458,8 +714,21 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                         impl_score_l1_avx(q_ptr, v_ptr, metadata.actual_dim as u32)
                     },
                 };
-                self.metadata
-                    .postprocess_score(score, query.offset, vector_offset)
+                metadata.postprocess_score(score, query.offset, vector_offset)
+            }
+            Metadata::Int4(metadata) => {
+                let (vector_offset, v_ptr) = Self::parse_vec_data(bytes);
+                metadata.score(
+                    query,
+                    vector_offset,
+                    v_ptr,
+                    |weights_ptr, codes_ptr, dim| unsafe {
+                        impl_score_int4_dot_avx(weights_ptr, codes_ptr, dim as u32)
+                    },
+                    |alphas_ptr, biases_ptr, codes_ptr, dim| unsafe {
+                        impl_score_int4_l1_avx(alphas_ptr, biases_ptr, codes_ptr, dim as u32)
+                    },
+                )
             }
         }
     }
@@ -479,12 +748,26 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
                         impl_score_l1_avx(q_ptr, v_ptr, metadata.actual_dim as u32)
                     },
                 };
-                self.metadata
-                    .postprocess_internal_score(score, query_offset, vector_offset)
+                metadata.postprocess_internal_score(score, query_offset, vector_offset)
             }
+            Metadata::Int4(metadata) => self.score_int4_internal(metadata, i, j),
         }
     }
 
+    fn score_int4_internal(
+        &self,
+        metadata: &MetadataInt4,
+        i: PointOffsetType,
+        j: PointOffsetType,
+    ) -> f32 {
+        let vector_1 = self.encoded_vectors.get_vector_data(i);
+        let vector_2 = self.encoded_vectors.get_vector_data(j);
+        metadata.score_internal(
+            &vector_1[ADDITIONAL_CONSTANT_SIZE..],
+            &vector_2[ADDITIONAL_CONSTANT_SIZE..],
+        )
+    }
+
     fn find_alpha_offset_size_dim<'a>(
         orig_data: impl Iterator<Item = impl AsRef<[f32]> + 'a> + Clone,
     ) -> (f32, f32) {
@@ -524,20 +807,31 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
 
     pub fn get_quantized_vector_offset_and_code(&self, i: PointOffsetType) -> (f32, &[u8]) {
         let (offset, v_ptr) = self.get_vec_ptr(i);
-        let vector_data_size = self.metadata.actual_dim();
+        let vector_data_size = self.metadata.code_size();
         let code = unsafe { std::slice::from_raw_parts(v_ptr, vector_data_size) };
         (offset, code)
     }
 
+    pub fn method(&self) -> ScalarQuantizationMethod {
+        match &self.metadata {
+            Metadata::Int8(_) => ScalarQuantizationMethod::Int8,
+            Metadata::Int4(_) => ScalarQuantizationMethod::Int4,
+        }
+    }
+
+    /// Multiplier of the `Int8` scores, `Int4` has per-dimension scales instead
     pub fn get_multiplier(&self) -> f32 {
         match &self.metadata {
             Metadata::Int8(meta) => meta.multiplier,
+            Metadata::Int4(_) => 1.0,
         }
     }
 
+    /// Shift of the `Int8` scores, `Int4` has per-dimension offsets instead
     pub fn get_shift(&self) -> f32 {
         match &self.metadata {
             Metadata::Int8(metadata) => metadata.get_shift(),
+            Metadata::Int4(_) => 0.0,
         }
     }
 
@@ -576,6 +870,7 @@ impl<TStorage: EncodedStorage> EncodedVectorsU8<TStorage> {
         EncodedQueryU8 {
             offset,
             encoded_query: query,
+            weights: Vec::new(),
         }
     }
 }
@@ -584,9 +879,20 @@ pub fn get_actual_dim(vector_parameters: &VectorParameters) -> usize {
     vector_parameters.dim + (ALIGNMENT - vector_parameters.dim % ALIGNMENT) % ALIGNMENT
 }
 
-pub fn get_quantized_vector_size(vector_parameters: &VectorParameters) -> usize {
-    let actual_dim = get_actual_dim(vector_parameters);
-    actual_dim + ADDITIONAL_CONSTANT_SIZE
+/// `Int4` packs two dimensions in a byte, so dimension is aligned to twice the `ALIGNMENT`
+pub fn get_actual_dim_int4(vector_parameters: &VectorParameters) -> usize {
+    vector_parameters.dim.next_multiple_of(2 * ALIGNMENT)
+}
+
+pub fn get_quantized_vector_size(
+    vector_parameters: &VectorParameters,
+    method: ScalarQuantizationMethod,
+) -> usize {
+    let code_size = match method {
+        ScalarQuantizationMethod::Int8 => get_actual_dim(vector_parameters),
+        ScalarQuantizationMethod::Int4 => get_actual_dim_int4(vector_parameters) / 2,
+    };
+    code_size + ADDITIONAL_CONSTANT_SIZE
 }
 
 impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
@@ -599,6 +905,7 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
     fn encode_query(&self, query: &[f32]) -> EncodedQueryU8 {
         match &self.metadata {
             Metadata::Int8(meta) => Self::encode_int8_query(meta, query),
+            Metadata::Int4(meta) => meta.encode_query(query),
         }
     }
 
@@ -663,9 +970,7 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
     fn quantized_vector_size(&self) -> usize {
         // Actual_dim rounds up vector_dimension to the next multiple of ALIGNMENT.
         // Also add scaling factor to the tally.
-        match &self.metadata {
-            Metadata::Int8(_) => self.metadata.actual_dim() + ADDITIONAL_CONSTANT_SIZE,
-        }
+        self.metadata.code_size() + ADDITIONAL_CONSTANT_SIZE
     }
 
     fn encode_internal_vector(&self, id: PointOffsetType) -> Option<EncodedQueryU8> {
@@ -679,8 +984,14 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
                     encoded_query: unsafe {
                         std::slice::from_raw_parts(q_ptr, metadata.actual_dim).to_vec()
                     },
+                    weights: Vec::new(),
                 })
             }
+            Metadata::Int4(metadata) => {
+                let vector = self.encoded_vectors.get_vector_data(id);
+                let decoded = metadata.decode_vector(&vector[ADDITIONAL_CONSTANT_SIZE..]);
+                Some(metadata.encode_query(&decoded))
+            }
         }
     }
 
@@ -743,7 +1054,7 @@ impl<TStorage: EncodedStorage> EncodedVectors for EncodedVectorsU8<TStorage> {
             .cpu_counter()
             .incr_delta(self.metadata.vector_parameters().dim);
 
-        debug_assert!(bytes.len() >= ADDITIONAL_CONSTANT_SIZE + self.metadata.actual_dim());
+        debug_assert!(bytes.len() >= ADDITIONAL_CONSTANT_SIZE + self.metadata.code_size());
 
         #[cfg(target_arch = "x86_64")]
         if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
@@ -784,6 +1095,34 @@ fn impl_score_l1(q_ptr: *const u8, v_ptr: *const u8, actual_dim: usize) -> i32 {
     }
 }
 
+fn impl_score_int4_dot(weights_ptr: *const f32, codes_ptr: *const u8, actual_dim: usize) -> f32 {
+    unsafe {
+        let codes = std::slice::from_raw_parts(codes_ptr, actual_dim / 2);
+        let mut score = 0.0;
+        for i in 0..actual_dim {
+            score += *weights_ptr.add(i) * f32::from(get_int4_code(codes, i));
+        }
+        score
+    }
+}
+
+fn impl_score_int4_l1(
+    alphas_ptr: *const f32,
+    biases_ptr: *const f32,
+    codes_ptr: *const u8,
+    actual_dim: usize,
+) -> f32 {
+    unsafe {
+        let codes = std::slice::from_raw_parts(codes_ptr, actual_dim / 2);
+        let mut score = 0.0f32;
+        for i in 0..actual_dim {
+            let value = *alphas_ptr.add(i) * f32::from(get_int4_code(codes, i));
+            score += (value + *biases_ptr.add(i)).abs();
+        }
+        score
+    }
+}
+
 #[cfg(target_arch = "x86_64")]
 unsafe extern "C" {
     fn impl_score_dot_avx(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
@@ -791,10 +1130,34 @@ unsafe extern "C" {
 
     fn impl_score_dot_sse(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
     fn impl_score_l1_sse(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
+
+    fn impl_score_int4_dot_avx(weights_ptr: *const f32, codes_ptr: *const u8, dim: u32) -> f32;
+    fn impl_score_int4_l1_avx(
+        alphas_ptr: *const f32,
+        biases_ptr: *const f32,
+        codes_ptr: *const u8,
+        dim: u32,
+    ) -> f32;
+
+    fn impl_score_int4_dot_sse(weights_ptr: *const f32, codes_ptr: *const u8, dim: u32) -> f32;
+    fn impl_score_int4_l1_sse(
+        alphas_ptr: *const f32,
+        biases_ptr: *const f32,
+        codes_ptr: *const u8,
+        dim: u32,
+    ) -> f32;
 }
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 unsafe extern "C" {
     fn impl_score_dot_neon(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
     fn impl_score_l1_neon(query_ptr: *const u8, vector_ptr: *const u8, dim: u32) -> f32;
+
+    fn impl_score_int4_dot_neon(weights_ptr: *const f32, codes_ptr: *const u8, dim: u32) -> f32;
+    fn impl_score_int4_l1_neon(
+        alphas_ptr: *const f32,
+        biases_ptr: *const f32,
+        codes_ptr: *const u8,
+        dim: u32,
+    ) -> f32;
 }
@@ -15,13 +15,7 @@ pub fn kmeans(
     accuracy: f32,
     stopped: &AtomicBool,
 ) -> Result<Vec<f32>, EncodingError> {
-    let pool = rayon::ThreadPoolBuilder::new()
-        .thread_name(|idx| format!("kmeans-{idx}"))
-        .num_threads(max_threads)
-        .build()
-        .map_err(|e| {
-            EncodingError::EncodingError(format!("Failed PQ encoding while thread pool init: {e}"))
-        })?;
+    let pool = build_pool(max_threads)?;
 
     // initial centroids positions are some vectors from data
     let mut centroids = data[0..centroids_count * dim].to_vec();
@@ -48,6 +42,97 @@ pub fn kmeans(
     Ok(centroids)
 }
 
+/// Mini-batch k-means (Sculley, 2010).
+///
+/// Every iteration assigns a random batch of vectors to the nearest centroids and moves
+/// the centroids towards them. The step of a centroid decreases with the number of vectors
+/// assigned to it so far, so the centroids converge to the means of their clusters.
+///
+/// Faster than [`kmeans`] on large data, because an iteration doesn't visit all vectors.
+pub fn mini_batch_kmeans(
+    data: &[f32],
+    centroids_count: usize,
+    dim: usize,
+    max_iterations: usize,
+    batch_size: usize,
+    accuracy: f32,
+    stopped: &AtomicBool,
+) -> Result<Vec<f32>, EncodingError> {
+    let vectors_count = data.len() / dim;
+    let mut rng = rand::rng();
+
+    // initial centroids positions are some vectors from data
+    let mut centroids = data[0..centroids_count * dim].to_vec();
+    let mut assigned_counts = vec![0usize; centroids_count];
+    let mut batch = Vec::with_capacity(batch_size);
+
+    for _ in 0..max_iterations {
+        if stopped.load(Ordering::Relaxed) {
+            return Err(EncodingError::Stopped);
+        }
+
+        // assign the whole batch before moving centroids
+        batch.clear();
+        batch.extend((0..batch_size).map(|_| {
+            let vector_index = rng.random_range(0..vectors_count);
+            let vector_data = &data[dim * vector_index..dim * (vector_index + 1)];
+            let (centroid_index, _) = nearest_centroid(vector_data, &centroids, dim);
+            (vector_index, centroid_index)
+        }));
+
+        let mut diff = 0.0;
+        for &(vector_index, centroid_index) in &batch {
+            assigned_counts[centroid_index] += 1;
+            let learning_rate = 1.0 / assigned_counts[centroid_index] as f32;
+
+            let vector_data = &data[dim * vector_index..dim * (vector_index + 1)];
+            let centroid_data = &mut centroids[dim * centroid_index..dim * (centroid_index + 1)];
+            for (c, v) in centroid_data.iter_mut().zip(vector_data.iter()) {
+                let step = learning_rate * (v - *c);
+                *c += step;
+                diff += step.abs();
+            }
+        }
+
+        if diff < accuracy {
+            break;
+        }
+    }
+
+    Ok(centroids)
+}
+
+/// Find the nearest centroid of each vector.
+///
+/// Returns the index of the centroid and the squared euclidean distance to it.
+pub fn assign_to_centroids(
+    data: &[f32],
+    centroids: &[f32],
+    dim: usize,
+    max_threads: usize,
+) -> Result<Vec<(u32, f32)>, EncodingError> {
+    let pool = build_pool(max_threads)?;
+    let assignments = pool.install(|| {
+        data.par_chunks_exact(dim)
+            .map(|vector_data| {
+                let (centroid_index, distance) = nearest_centroid(vector_data, centroids, dim);
+                (centroid_index as u32, distance)
+            })
+            .collect()
+    });
+    Ok(assignments)
+}
+
+fn build_pool(max_threads: usize) -> Result<ThreadPool, EncodingError> {
+    rayon::ThreadPoolBuilder::new()
+        .thread_name(|idx| format!("kmeans-{idx}"))
+        .num_threads(max_threads)
+        .build()
+        .map_err(|e| {
+            EncodingError::EncodingError(format!("Failed PQ encoding while thread pool init: {e}"))
+        })
+}
+
 fn update_centroids(
     pool: &ThreadPool,
     data: &[f32],
@@ -150,20 +235,72 @@ fn update_indexes(
             .enumerate()
             .for_each(|(i, c)| {
                 let vector_data = &data[dim * i..dim * (i + 1)];
-                let mut min_distance = f32::MAX;
-                let mut min_centroid_index = 0;
-                for (centroid_index, centroid_data) in centroids.chunks_exact(dim).enumerate() {
-                    let distance = vector_data
-                        .iter()
-                        .zip(centroid_data.iter())
-                        .map(|(a, b)| (a - b).powi(2))
-                        .sum();
-                    if distance < min_distance {
-                        min_distance = distance;
-                        min_centroid_index = centroid_index;
-                    }
-                }
+                let (min_centroid_index, _) = nearest_centroid(vector_data, centroids, dim);
                 *c = min_centroid_index as u32;
             })
     });
 }
+
+/// Index of the nearest centroid and the squared euclidean distance to it
+fn nearest_centroid(vector_data: &[f32], centroids: &[f32], dim: usize) -> (usize, f32) {
+    let mut min_distance = f32::MAX;
+    let mut min_centroid_index = 0;
+    for (centroid_index, centroid_data) in centroids.chunks_exact(dim).enumerate() {
+        let distance = vector_data
+            .iter()
+            .zip(centroid_data.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum();
+        if distance < min_distance {
+            min_distance = distance;
+            min_centroid_index = centroid_index;
+        }
+    }
+    (min_centroid_index, min_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIM: usize = 4;
+    const CLUSTERS: usize = 3;
+
+    /// Points around 3 distant centers, interleaved, so the first points are in different clusters
+    fn blobs(count: usize) -> Vec<f32> {
+        let mut rng = rand::rng();
+        (0..count)
+            .flat_map(|i| {
+                let center = (i % CLUSTERS) as f32 * 10.0;
+                (0..DIM)
+                    .map(|_| center + rng.random_range(-1.0..1.0))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn check_clusters(data: &[f32], centroids: &[f32]) {
+        let assignments = assign_to_centroids(data, centroids, DIM, 2).unwrap();
+        for (i, (centroid_index, distance)) in assignments.iter().enumerate() {
+            // points of the same blob are in the same cluster
+            assert_eq!(*centroid_index, assignments[i % CLUSTERS].0);
+            assert!(*distance < DIM as f32);
+        }
+    }
+
+    #[test]
+    fn test_kmeans_blobs() {
+        let data = blobs(300);
+        let stopped = AtomicBool::new(false);
+        let centroids = kmeans(&data, CLUSTERS, DIM, 100, 2, 1e-5, &stopped).unwrap();
+        check_clusters(&data, &centroids);
+    }
+
+    #[test]
+    fn test_mini_batch_kmeans_blobs() {
+        let data = blobs(300);
+        let stopped = AtomicBool::new(false);
+        let centroids = mini_batch_kmeans(&data, CLUSTERS, DIM, 100, 32, 1e-5, &stopped).unwrap();
+        check_clusters(&data, &centroids);
+    }
+}
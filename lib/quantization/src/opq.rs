@@ -0,0 +1,517 @@
+//! Optimized Product Quantization (OPQ) rotation.
+//!
+//! PQ quantizes each chunk of a vector independently, so it works best when the variance
+//! of the data is spread evenly between chunks and dimensions of different chunks are not
+//! correlated. Embeddings are usually anisotropic: a few directions hold most of the variance.
+//!
+//! This module learns an orthogonal rotation as in non-parametric OPQ with eigenvalue
+//! allocation (Ge et al., "Optimized Product Quantization", 2013):
+//! 1. Principal axes of the data are found by eigendecomposition of the covariance matrix,
+//!    so rotated dimensions are not correlated.
+//! 2. Axes are allocated to chunks greedily, so the products of eigenvalues in chunks are balanced.
+//!
+//! The rotation is orthogonal, so dot products and euclidean distances are preserved
+//! and PQ can be trained, encoded and scored in the rotated space.
+
+use std::ops::Range;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use fs_err as fs;
+
+use crate::EncodingError;
+
+/// Count of vectors used to estimate the covariance matrix
+pub const OPQ_SAMPLE_SIZE: usize = 2_000;
+
+/// Eigenvalues below this value are considered as zero in the chunks allocation
+const MIN_EIGENVALUE: f64 = 1e-12;
+
+/// Count of sample vectors or matrix rows to process between checks of the stop flag
+const STOP_CHECK_INTERVAL: usize = 64;
+
+pub struct OpqRotation {
+    dim: usize,
+    /// Row-major `dim x dim` orthogonal matrix, `rotated[i] = matrix[i] * vector`
+    matrix: Vec<f32>,
+}
+
+impl OpqRotation {
+    /// Learn rotation from `sample`, a flattened list of vectors of dimension `dim`.
+    ///
+    /// Dimensions of rotated vectors within each range of `vector_division` belong to one PQ chunk.
+    pub fn train(
+        sample: &[f32],
+        dim: usize,
+        vector_division: &[Range<usize>],
+        stopped: &AtomicBool,
+    ) -> Result<Self, EncodingError> {
+        debug_assert!(dim > 0 && sample.len().is_multiple_of(dim));
+        let count = sample.len() / dim;
+
+        let mut covariance = covariance(sample, dim, count, stopped)?;
+
+        let mut eigenvalues = vec![0.0; dim];
+        let mut off_diagonal = vec![0.0; dim];
+        tridiagonalize(
+            &mut covariance,
+            dim,
+            &mut eigenvalues,
+            &mut off_diagonal,
+            stopped,
+        )?;
+
+        // Eigenvectors are in columns, transpose to make them rows
+        let mut eigenvectors = transpose(&covariance, dim);
+        diagonalize(
+            &mut eigenvectors,
+            dim,
+            &mut eigenvalues,
+            &mut off_diagonal,
+            stopped,
+        )?;
+
+        let allocation = allocate_eigenvalues(&eigenvalues, vector_division);
+        let mut matrix = Vec::with_capacity(dim * dim);
+        for eigenvector_index in allocation {
+            let eigenvector = &eigenvectors[eigenvector_index * dim..(eigenvector_index + 1) * dim];
+            matrix.extend(eigenvector.iter().map(|&value| value as f32));
+        }
+
+        Ok(Self { dim, matrix })
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Rotate `vector` into `rotated`
+    pub fn apply(&self, vector: &[f32], rotated: &mut [f32]) {
+        debug_assert_eq!(vector.len(), self.dim);
+        debug_assert_eq!(rotated.len(), self.dim);
+        for (row, value) in self.matrix.chunks_exact(self.dim).zip(rotated.iter_mut()) {
+            *value = row.iter().zip(vector).map(|(a, b)| a * b).sum();
+        }
+    }
+
+    pub fn rotate(&self, vector: &[f32]) -> Vec<f32> {
+        let mut rotated = vec![0.0; self.dim];
+        self.apply(vector, &mut rotated);
+        rotated
+    }
+
+    pub fn heap_size_bytes(&self) -> usize {
+        self.matrix.capacity() * size_of::<f32>()
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        fs::write(path, bytemuck::cast_slice::<f32, u8>(&self.matrix))
+    }
+
+    pub fn load(path: &Path, dim: usize) -> std::io::Result<Self> {
+        let data = fs::read(path)?;
+        if data.len() != dim * dim * size_of::<f32>() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "OPQ rotation file {} has unexpected size {}",
+                    path.display(),
+                    data.len(),
+                ),
+            ));
+        }
+        Ok(Self {
+            dim,
+            matrix: bytemuck::pod_collect_to_vec(&data),
+        })
+    }
+}
+
+/// Row-major covariance matrix of the sample
+fn covariance(
+    sample: &[f32],
+    dim: usize,
+    count: usize,
+    stopped: &AtomicBool,
+) -> Result<Vec<f64>, EncodingError> {
+    let mut mean = vec![0.0f64; dim];
+    for vector in sample.chunks_exact(dim) {
+        for (m, &value) in mean.iter_mut().zip(vector) {
+            *m += f64::from(value);
+        }
+    }
+    mean.iter_mut().for_each(|m| *m /= count.max(1) as f64);
+
+    let mut centered = vec![0.0f64; dim];
+    let mut covariance = vec![0.0f64; dim * dim];
+    for (vector_index, vector) in sample.chunks_exact(dim).enumerate() {
+        check_stopped(vector_index, stopped)?;
+        for ((c, &value), m) in centered.iter_mut().zip(vector).zip(&mean) {
+            *c = f64::from(value) - m;
+        }
+        // Upper triangle only, the matrix is symmetric
+        for i in 0..dim {
+            let ci = centered[i];
+            let row = &mut covariance[i * dim..(i + 1) * dim];
+            for (r, &cj) in row[i..].iter_mut().zip(&centered[i..]) {
+                *r += ci * cj;
+            }
+        }
+    }
+    for i in 0..dim {
+        for j in i..dim {
+            let value = covariance[i * dim + j] / count.max(1) as f64;
+            covariance[i * dim + j] = value;
+            covariance[j * dim + i] = value;
+        }
+    }
+    Ok(covariance)
+}
+
+fn check_stopped(iteration: usize, stopped: &AtomicBool) -> Result<(), EncodingError> {
+    if iteration.is_multiple_of(STOP_CHECK_INTERVAL) && stopped.load(Ordering::Relaxed) {
+        return Err(EncodingError::Stopped);
+    }
+    Ok(())
+}
+
+fn transpose(matrix: &[f64], dim: usize) -> Vec<f64> {
+    let mut transposed = vec![0.0; dim * dim];
+    for i in 0..dim {
+        for j in 0..dim {
+            transposed[j * dim + i] = matrix[i * dim + j];
+        }
+    }
+    transposed
+}
+
+/// Assign each eigenvector to a position in the rotated vector.
+///
+/// Eigenvalues are taken in descending order and each goes to the chunk with the smallest
+/// product of already assigned eigenvalues, which still has free dimensions.
+/// Returns indices of eigenvectors for each dimension of the rotated vector.
+fn allocate_eigenvalues(eigenvalues: &[f64], vector_division: &[Range<usize>]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..eigenvalues.len()).collect();
+    order.sort_by(|&a, &b| eigenvalues[b].total_cmp(&eigenvalues[a]));
+
+    let mut log_products = vec![0.0f64; vector_division.len()];
+    let mut filled = vec![0usize; vector_division.len()];
+    let mut allocation = vec![0; eigenvalues.len()];
+    for eigenvector_index in order {
+        let chunk = (0..vector_division.len())
+            .filter(|&chunk| filled[chunk] < vector_division[chunk].len())
+            .min_by(|&a, &b| log_products[a].total_cmp(&log_products[b]))
+            .expect("vector division covers all dimensions");
+        allocation[vector_division[chunk].start + filled[chunk]] = eigenvector_index;
+        filled[chunk] += 1;
+        log_products[chunk] += eigenvalues[eigenvector_index].max(MIN_EIGENVALUE).ln();
+    }
+    allocation
+}
+
+/// Householder reduction of the row-major symmetric `matrix` to a tridiagonal form.
+///
+/// On return `matrix` holds the orthogonal transformation, `diagonal` and `off_diagonal`
+/// hold the tridiagonal matrix. Port of `tred2` from EISPACK, as in JAMA.
+fn tridiagonalize(
+    matrix: &mut [f64],
+    n: usize,
+    diagonal: &mut [f64],
+    off_diagonal: &mut [f64],
+    stopped: &AtomicBool,
+) -> Result<(), EncodingError> {
+    let v = matrix;
+    let d = diagonal;
+    let e = off_diagonal;
+    let idx = |i: usize, j: usize| i * n + j;
+
+    d.copy_from_slice(&v[idx(n - 1, 0)..idx(n - 1, 0) + n]);
+
+    for i in (1..n).rev() {
+        check_stopped(i, stopped)?;
+        let scale: f64 = d[..i].iter().map(|x| x.abs()).sum();
+        let mut h = 0.0;
+        if scale == 0.0 {
+            e[i] = d[i - 1];
+            for j in 0..i {
+                d[j] = v[idx(i - 1, j)];
+                v[idx(i, j)] = 0.0;
+                v[idx(j, i)] = 0.0;
+            }
+        } else {
+            for dk in &mut d[..i] {
+                *dk /= scale;
+                h += *dk * *dk;
+            }
+            let mut f = d[i - 1];
+            let mut g = h.sqrt();
+            if f > 0.0 {
+                g = -g;
+            }
+            e[i] = scale * g;
+            h -= f * g;
+            d[i - 1] = f - g;
+            e[..i].fill(0.0);
+
+            for j in 0..i {
+                f = d[j];
+                v[idx(j, i)] = f;
+                g = e[j] + v[idx(j, j)] * f;
+                for k in j + 1..i {
+                    g += v[idx(k, j)] * d[k];
+                    e[k] += v[idx(k, j)] * f;
+                }
+                e[j] = g;
+            }
+            f = 0.0;
+            for j in 0..i {
+                e[j] /= h;
+                f += e[j] * d[j];
+            }
+            let hh = f / (h + h);
+            for j in 0..i {
+                e[j] -= hh * d[j];
+            }
+            for j in 0..i {
+                f = d[j];
+                g = e[j];
+                for k in j..i {
+                    v[idx(k, j)] -= f * e[k] + g * d[k];
+                }
+                d[j] = v[idx(i - 1, j)];
+                v[idx(i, j)] = 0.0;
+            }
+        }
+        d[i] = h;
+    }
+
+    // Accumulate transformations
+    for i in 0..n - 1 {
+        check_stopped(i, stopped)?;
+        v[idx(n - 1, i)] = v[idx(i, i)];
+        v[idx(i, i)] = 1.0;
+        let h = d[i + 1];
+        if h != 0.0 {
+            for k in 0..=i {
+                d[k] = v[idx(k, i + 1)] / h;
+            }
+            for j in 0..=i {
+                let mut g = 0.0;
+                for k in 0..=i {
+                    g += v[idx(k, i + 1)] * v[idx(k, j)];
+                }
+                for k in 0..=i {
+                    v[idx(k, j)] -= g * d[k];
+                }
+            }
+        }
+        for k in 0..=i {
+            v[idx(k, i + 1)] = 0.0;
+        }
+    }
+    for j in 0..n {
+        d[j] = v[idx(n - 1, j)];
+        v[idx(n - 1, j)] = 0.0;
+    }
+    v[idx(n - 1, n - 1)] = 1.0;
+    e[0] = 0.0;
+    Ok(())
+}
+
+/// QL diagonalization of the tridiagonal matrix from [`tridiagonalize`].
+///
+/// `eigenvectors` holds the transposed transformation: on return its rows are the eigenvectors
+/// and `diagonal` holds the eigenvalues. Port of `tql2` from EISPACK, as in JAMA,
+/// with rows instead of columns for the sequential memory access.
+fn diagonalize(
+    eigenvectors: &mut [f64],
+    n: usize,
+    diagonal: &mut [f64],
+    off_diagonal: &mut [f64],
+    stopped: &AtomicBool,
+) -> Result<(), EncodingError> {
+    const MAX_ITERATIONS: usize = 64;
+
+    let d = diagonal;
+    let e = off_diagonal;
+
+    for i in 1..n {
+        e[i - 1] = e[i];
+    }
+    e[n - 1] = 0.0;
+
+    let mut f = 0.0;
+    let mut tst1 = 0.0f64;
+    let eps = f64::EPSILON;
+    for l in 0..n {
+        check_stopped(l, stopped)?;
+        // Find small subdiagonal element
+        tst1 = tst1.max(d[l].abs() + e[l].abs());
+        let mut m = l;
+        while m < n - 1 && e[m].abs() > eps * tst1 {
+            m += 1;
+        }
+
+        // If m == l, d[l] is an eigenvalue, otherwise iterate
+        if m > l {
+            let mut iteration = 0;
+            loop {
+                iteration += 1;
+                if iteration > MAX_ITERATIONS {
+                    return Err(EncodingError::EncodingError(
+                        "OPQ rotation eigendecomposition did not converge".to_string(),
+                    ));
+                }
+
+                // Compute implicit shift
+                let mut g = d[l];
+                let mut p = (d[l + 1] - g) / (2.0 * e[l]);
+                let mut r = p.hypot(1.0);
+                if p < 0.0 {
+                    r = -r;
+                }
+                d[l] = e[l] / (p + r);
+                d[l + 1] = e[l] * (p + r);
+                let dl1 = d[l + 1];
+                let mut h = g - d[l];
+                for di in &mut d[l + 2..n] {
+                    *di -= h;
+                }
+                f += h;
+
+                // Implicit QL transformation
+                p = d[m];
+                let mut c = 1.0;
+                let mut c2 = c;
+                let mut c3 = c;
+                let el1 = e[l + 1];
+                let mut s = 0.0;
+                let mut s2 = 0.0;
+                for i in (l..m).rev() {
+                    c3 = c2;
+                    c2 = c;
+                    s2 = s;
+                    g = c * e[i];
+                    h = c * p;
+                    r = p.hypot(e[i]);
+                    e[i + 1] = s * r;
+                    s = e[i] / r;
+                    c = p / r;
+                    p = c * d[i] - s * g;
+                    d[i + 1] = h + s * (c * g + s * d[i]);
+
+                    // Accumulate transformation
+                    let (head, tail) = eigenvectors.split_at_mut((i + 1) * n);
+                    let row_i = &mut head[i * n..];
+                    let row_next = &mut tail[..n];
+                    for (vi, vn) in row_i.iter_mut().zip(row_next.iter_mut()) {
+                        let h = *vn;
+                        *vn = s * *vi + c * h;
+                        *vi = c * *vi - s * h;
+                    }
+                }
+                p = -s * s2 * c3 * el1 * e[l] / dl1;
+                e[l] = s * p;
+                d[l] = c * p;
+
+                if e[l].abs() <= eps * tst1 {
+                    break;
+                }
+            }
+        }
+        d[l] += f;
+        e[l] = 0.0;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{RngExt, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_eigendecomposition() {
+        let n = 7;
+        let mut rng = StdRng::seed_from_u64(42);
+        let a: Vec<f64> = (0..n * n).map(|_| rng.random_range(-1.0..1.0)).collect();
+        // Symmetric matrix
+        let mut matrix = vec![0.0; n * n];
+        for i in 0..n {
+            for j in 0..n {
+                matrix[i * n + j] = a[i * n + j] + a[j * n + i];
+            }
+        }
+
+        let mut v = matrix.clone();
+        let mut d = vec![0.0; n];
+        let mut e = vec![0.0; n];
+        let not_stopped = AtomicBool::new(false);
+        tridiagonalize(&mut v, n, &mut d, &mut e, &not_stopped).unwrap();
+        let mut eigenvectors = transpose(&v, n);
+        diagonalize(&mut eigenvectors, n, &mut d, &mut e, &not_stopped).unwrap();
+
+        for k in 0..n {
+            let eigenvector = &eigenvectors[k * n..(k + 1) * n];
+            for i in 0..n {
+                let product: f64 = (0..n).map(|j| matrix[i * n + j] * eigenvector[j]).sum();
+                assert!((product - d[k] * eigenvector[i]).abs() < 1e-9);
+            }
+            for l in 0..n {
+                let dot: f64 = (0..n)
+                    .map(|j| eigenvector[j] * eigenvectors[l * n + j])
+                    .sum();
+                let expected = if k == l { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_allocate_eigenvalues() {
+        let eigenvalues = [1.0, 8.0, 2.0, 4.0];
+        let division = [0..2, 2..4];
+        // 8 and 4 go to different chunks, then 2 joins 4 and 1 joins 8
+        assert_eq!(
+            allocate_eigenvalues(&eigenvalues, &division),
+            vec![1, 0, 3, 2]
+        );
+    }
+
+    #[test]
+    fn test_rotation_preserves_distances() {
+        let dim = 12;
+        let count = 500;
+        let mut rng = StdRng::seed_from_u64(42);
+        // Anisotropic data: variance decays with dimension
+        let sample: Vec<f32> = (0..count * dim)
+            .map(|i| rng.random_range(-1.0f32..1.0) * (dim - i % dim) as f32)
+            .collect();
+        let division = [0..4, 4..8, 8..12];
+        let rotation =
+            OpqRotation::train(&sample, dim, &division, &AtomicBool::new(false)).unwrap();
+
+        let x = &sample[..dim];
+        let y = &sample[dim..2 * dim];
+        let (rx, ry) = (rotation.rotate(x), rotation.rotate(y));
+        let dot = |a: &[f32], b: &[f32]| a.iter().zip(b).map(|(a, b)| a * b).sum::<f32>();
+        assert!((dot(x, y) - dot(&rx, &ry)).abs() < 1e-3 * dot(x, x).max(1.0));
+        assert!((dot(x, x) - dot(&rx, &rx)).abs() < 1e-3 * dot(x, x));
+
+        let dir = tempfile::Builder::new().prefix("opq").tempdir().unwrap();
+        let path = dir.path().join("rotation.bin");
+        rotation.save(&path).unwrap();
+        let loaded = OpqRotation::load(&path, dim).unwrap();
+        assert_eq!(loaded.matrix, rotation.matrix);
+        assert_eq!(loaded.rotate(x), rx);
+
+        // Stopped before the first sample vector
+        let stopped = AtomicBool::new(true);
+        assert!(matches!(
+            OpqRotation::train(&sample, dim, &division, &stopped),
+            Err(EncodingError::Stopped),
+        ));
+    }
+}
@@ -27,8 +27,10 @@ mod tests {
 
         let data_path = dir.path().join("data.bin");
         let meta_path = dir.path().join("meta.json");
-        let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+        let quantized_vector_size = encoded_vectors_u8::get_quantized_vector_size(
+            &vector_parameters,
+            ScalarQuantizationMethod::Int8,
+        );
         let _encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(Some(data_path.as_path()), quantized_vector_size),
@@ -72,6 +74,7 @@ mod tests {
             &vector_parameters,
             vectors_count,
             2,
+            false,
             1,
             Some(meta_path.as_path()),
             &AtomicBool::new(false),
@@ -29,8 +29,10 @@ mod tests {
         };
         let zero_vector = vec![0.0; vector_dim];
 
-        let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+        let quantized_vector_size = encoded_vectors_u8::get_quantized_vector_size(
+            &vector_parameters,
+            ScalarQuantizationMethod::Int8,
+        );
         assert_eq!(
             EncodedVectorsU8::encode(
                 (0..vectors_count).map(|_| &zero_vector),
@@ -79,6 +81,7 @@ mod tests {
                 &vector_parameters,
                 vectors_count,
                 2,
+                false,
                 1,
                 None,
                 stopped_ref,
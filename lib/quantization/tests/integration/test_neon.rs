@@ -13,6 +13,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_dot_neon(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -33,7 +34,7 @@ mod tests {
             invert: false,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -57,6 +58,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_l2_neon(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -77,7 +79,7 @@ mod tests {
             invert: false,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -101,6 +103,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_l1_neon(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -121,7 +124,7 @@ mod tests {
             invert: false,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -4,10 +4,11 @@ mod tests {
     use std::time::Duration;
 
     use common::counter::hardware_counter::HardwareCounterCell;
-    use quantization::encoded_storage::TestEncodedStorageBuilder;
+    use quantization::encoded_storage::{TestEncodedStorage, TestEncodedStorageBuilder};
     use quantization::encoded_vectors::{DistanceType, EncodedVectors, VectorParameters};
     use quantization::encoded_vectors_pq::{self, EncodedVectorsPQ};
     use rand::{RngExt, SeedableRng};
+    use tempfile::Builder;
 
     use crate::metrics::{dot_similarity, l1_similarity, l2_similarity};
 
@@ -38,6 +39,7 @@ mod tests {
             &vector_parameters,
             VECTORS_COUNT,
             1,
+            false,
             1,
             None,
             &AtomicBool::new(false),
@@ -76,6 +78,7 @@ mod tests {
             &vector_parameters,
             VECTORS_COUNT,
             1,
+            false,
             1,
             None,
             &AtomicBool::new(false),
@@ -114,6 +117,7 @@ mod tests {
             &vector_parameters,
             VECTORS_COUNT,
             1,
+            false,
             1,
             None,
             &AtomicBool::new(false),
@@ -152,6 +156,7 @@ mod tests {
             &vector_parameters,
             VECTORS_COUNT,
             1,
+            false,
             1,
             None,
             &AtomicBool::new(false),
@@ -190,6 +195,7 @@ mod tests {
             &vector_parameters,
             VECTORS_COUNT,
             1,
+            false,
             1,
             None,
             &AtomicBool::new(false),
@@ -228,6 +234,7 @@ mod tests {
             &vector_parameters,
             VECTORS_COUNT,
             1,
+            false,
             1,
             None,
             &AtomicBool::new(false),
@@ -265,6 +272,7 @@ mod tests {
             &vector_parameters,
             VECTORS_COUNT,
             1,
+            false,
             1,
             None,
             &AtomicBool::new(false),
@@ -301,6 +309,7 @@ mod tests {
             &vector_parameters,
             VECTORS_COUNT,
             1,
+            false,
             1,
             None,
             &AtomicBool::new(false),
@@ -319,6 +328,134 @@ mod tests {
     // this test should be started separately of with `--test-threads=1` flag
     // because `num_threads::num_threads()` is used to check that all encode threads finished
     #[ignore]
+    #[test]
+    fn test_pq_l2_opq() {
+        let dir = Builder::new().prefix("storage_dir").tempdir().unwrap();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        // Correlated dimensions with decaying variance
+        let mixing: Vec<Vec<f32>> = (0..VECTOR_DIM)
+            .map(|_| (0..VECTOR_DIM).map(|_| rng.random::<f32>() - 0.5).collect())
+            .collect();
+        let mut generate = || -> Vec<f32> {
+            let latent: Vec<f32> = (0..VECTOR_DIM)
+                .map(|i| rng.random::<f32>() / (i + 1) as f32)
+                .collect();
+            mixing
+                .iter()
+                .map(|row| row.iter().zip(&latent).map(|(a, b)| a * b).sum())
+                .collect()
+        };
+        let vector_data: Vec<Vec<f32>> = (0..VECTORS_COUNT).map(|_| generate()).collect();
+        let query = generate();
+
+        let vector_parameters = VectorParameters {
+            dim: VECTOR_DIM,
+            deprecated_count: None,
+            distance_type: DistanceType::L2,
+            invert: false,
+        };
+        let data_path = dir.path().join("data.bin");
+        let meta_path = dir.path().join("meta.json");
+        let quantized_vector_size =
+            encoded_vectors_pq::get_quantized_vector_size(&vector_parameters, 1);
+        let encoded = EncodedVectorsPQ::encode(
+            vector_data.iter(),
+            TestEncodedStorageBuilder::new(Some(data_path.as_path()), quantized_vector_size),
+            &vector_parameters,
+            VECTORS_COUNT,
+            1,
+            true,
+            1,
+            Some(meta_path.as_path()),
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        assert_eq!(encoded.files().len(), 3);
+
+        let loaded = EncodedVectorsPQ::<TestEncodedStorage>::load(
+            TestEncodedStorage::from_file(data_path.as_path(), quantized_vector_size).unwrap(),
+            meta_path.as_path(),
+        )
+        .unwrap();
+
+        let query_u8 = encoded.encode_query(&query);
+        let loaded_query_u8 = loaded.encode_query(&query);
+        let counter = HardwareCounterCell::new();
+        for (index, vector) in vector_data.iter().enumerate() {
+            let score = encoded.score_point(&query_u8, index as u32, &counter);
+            let loaded_score = loaded.score_point(&loaded_query_u8, index as u32, &counter);
+            let orginal_score = l2_similarity(&query, vector);
+            assert!((score - orginal_score).abs() < ERROR);
+            assert_eq!(score, loaded_score);
+        }
+    }
+
+    #[test]
+    fn test_pq_opq_reconstruction_error() {
+        let dim = 16;
+        let count = 1000;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        // A few strong latent directions, mixed into all dimensions
+        let mixing: Vec<Vec<f32>> = (0..dim)
+            .map(|_| (0..4).map(|_| rng.random::<f32>() - 0.5).collect())
+            .collect();
+        let vector_data: Vec<Vec<f32>> = (0..count)
+            .map(|_| {
+                let latent: Vec<f32> = (0..4).map(|_| rng.random::<f32>() - 0.5).collect();
+                mixing
+                    .iter()
+                    .map(|row| {
+                        let mixed: f32 = row.iter().zip(&latent).map(|(a, b)| a * b).sum();
+                        mixed + 0.01 * (rng.random::<f32>() - 0.5)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let vector_parameters = VectorParameters {
+            dim,
+            deprecated_count: None,
+            distance_type: DistanceType::L2,
+            invert: false,
+        };
+        let chunk_size = 4;
+        let quantized_vector_size =
+            encoded_vectors_pq::get_quantized_vector_size(&vector_parameters, chunk_size);
+
+        // Mean squared distance between vectors and their reconstructions
+        let reconstruction_error = |opq| {
+            let encoded = EncodedVectorsPQ::encode(
+                vector_data.iter(),
+                TestEncodedStorageBuilder::new(None, quantized_vector_size),
+                &vector_parameters,
+                count,
+                chunk_size,
+                opq,
+                1,
+                None,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+            let counter = HardwareCounterCell::new();
+            let total: f32 = vector_data
+                .iter()
+                .enumerate()
+                .map(|(index, vector)| {
+                    let query = encoded.encode_query(vector);
+                    encoded.score_point(&query, index as u32, &counter)
+                })
+                .sum();
+            total / count as f32
+        };
+
+        let pq_error = reconstruction_error(false);
+        let opq_error = reconstruction_error(true);
+        assert!(
+            opq_error < pq_error,
+            "OPQ error {opq_error} is not lower than PQ error {pq_error}",
+        );
+    }
+
     #[test]
     fn test_encode_panic() {
         let mut rng = rand::rngs::StdRng::seed_from_u64(42);
@@ -359,6 +496,7 @@ mod tests {
                     &vector_parameters,
                     VECTORS_COUNT,
                     1,
+                    false,
                     5,
                     None,
                     &AtomicBool::new(false),
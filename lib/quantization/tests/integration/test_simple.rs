@@ -14,6 +14,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_dot_simple(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -34,7 +35,7 @@ mod tests {
             invert: false,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -58,6 +59,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_l2_simple(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -78,7 +80,7 @@ mod tests {
             invert: false,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -102,6 +104,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_l1_simple(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -126,7 +129,7 @@ mod tests {
             invert: false,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -150,6 +153,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_dot_inverted_simple(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -170,7 +174,7 @@ mod tests {
             invert: true,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -194,6 +198,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_l2_inverted_simple(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -214,7 +219,7 @@ mod tests {
             invert: true,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -238,6 +243,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_l1_inverted_simple(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -262,7 +268,7 @@ mod tests {
             invert: true,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -286,6 +292,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_dot_internal_simple(#[case] method: ScalarQuantizationMethod) {
         let vectors_count: usize = 129;
         let vector_dim = 65;
@@ -305,7 +312,7 @@ mod tests {
             invert: false,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -328,6 +335,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_dot_inverted_internal_simple(#[case] method: ScalarQuantizationMethod) {
         let vectors_count: usize = 129;
         let vector_dim = 65;
@@ -347,7 +355,7 @@ mod tests {
             invert: true,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -370,6 +378,7 @@ mod tests {
 
     #[rstest]
     #[case(ScalarQuantizationMethod::Int8)]
+    #[case(ScalarQuantizationMethod::Int4)]
     fn test_u8_large_quantile(#[case] method: ScalarQuantizationMethod) {
         let vectors_count = 129;
         let vector_dim = 65;
@@ -390,7 +399,7 @@ mod tests {
             invert: false,
         };
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
         let encoded = EncodedVectorsU8::encode(
             vector_data.iter(),
             TestEncodedStorageBuilder::new(None, quantized_vector_size),
@@ -437,7 +446,7 @@ mod tests {
                 invert,
             };
             let quantized_vector_size =
-                encoded_vectors_u8::get_quantized_vector_size(&vector_parameters);
+                encoded_vectors_u8::get_quantized_vector_size(&vector_parameters, method);
 
             let encoded = EncodedVectorsU8::encode(
                 vector_data.iter(),
@@ -445,7 +454,7 @@ mod tests {
                 &vector_parameters,
                 vectors_count,
                 Some(1.0 - f32::EPSILON), // almost 1.0 value, but not 1.0
-                method.clone(),
+                method,
                 None,
                 &AtomicBool::new(false),
             )
@@ -468,4 +477,59 @@ mod tests {
             }
         }
     }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn test_sq_int4_encode_internal(#[case] invert: bool) {
+        let vectors_count = 129;
+        let vector_dim = 70;
+        let error = 1e-3;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let mut vector_data: Vec<Vec<f32>> = Vec::new();
+        for _ in 0..vectors_count {
+            let vector: Vec<f32> = (0..vector_dim)
+                .map(|_| 2.0 * rng.random::<f32>() - 1.0)
+                .collect();
+            vector_data.push(vector);
+        }
+
+        for distance_type in [DistanceType::Dot, DistanceType::L2, DistanceType::L1] {
+            let vector_parameters = VectorParameters {
+                dim: vector_dim,
+                deprecated_count: None,
+                distance_type,
+                invert,
+            };
+            let quantized_vector_size = encoded_vectors_u8::get_quantized_vector_size(
+                &vector_parameters,
+                ScalarQuantizationMethod::Int4,
+            );
+
+            let encoded = EncodedVectorsU8::encode(
+                vector_data.iter(),
+                TestEncodedStorageBuilder::new(None, quantized_vector_size),
+                &vector_parameters,
+                vectors_count,
+                None,
+                ScalarQuantizationMethod::Int4,
+                None,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+            assert_eq!(encoded.quantized_vector_size(), quantized_vector_size);
+
+            // Queries are not quantized, so only restored vectors are comparable exactly
+            let hw = HardwareCounterCell::new();
+            for i in 0..vectors_count {
+                let query_internal = encoded.encode_internal_vector(i as u32).unwrap();
+
+                let score_internal_query = encoded.score_point(&query_internal, 0, &hw);
+                let score_internal = encoded.score_internal(i as u32, 0, &hw);
+
+                assert!((score_internal_query - score_internal).abs() < error);
+            }
+        }
+    }
 }
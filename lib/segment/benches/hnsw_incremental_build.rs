@@ -392,6 +392,7 @@ fn build_hnsw_index<R: Rng + ?Sized>(
             .clone(),
         payload_index: Arc::clone(&segment.payload_index),
         hnsw_config,
+        matryoshka_dim: None,
     };
 
     pub const HNSW_INDEX_CONFIG_FILE: &str = "hnsw_config.json";
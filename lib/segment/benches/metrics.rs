@@ -5,9 +5,16 @@ use criterion::{Criterion, criterion_group, criterion_main};
 use half::f16;
 use rand::rngs::StdRng;
 use rand::{RngExt, SeedableRng};
-use segment::data_types::vectors::{VectorElementTypeByte, VectorElementTypeHalf};
+use segment::data_types::vectors::{
+    PackedBits, VectorElementTypeBit, VectorElementTypeByte, VectorElementTypeHalf,
+};
 use segment::spaces::metric::Metric;
 #[cfg(target_arch = "x86_64")]
+use segment::spaces::metric_bit::avx2::avx2_popcount;
+#[cfg(target_arch = "aarch64")]
+use segment::spaces::metric_bit::neon::neon_popcount;
+use segment::spaces::metric_bit::popcount::{BitOp, popcount_simple};
+#[cfg(target_arch = "x86_64")]
 use segment::spaces::metric_f16::avx::dot::avx_dot_similarity_half;
 #[cfg(target_arch = "x86_64")]
 use segment::spaces::metric_f16::avx::euclid::avx_euclid_similarity_half;
@@ -56,7 +63,9 @@ use segment::spaces::metric_uint::sse2::dot::sse_dot_similarity_bytes;
 use segment::spaces::metric_uint::sse2::euclid::sse_euclid_similarity_bytes;
 #[cfg(target_arch = "x86_64")]
 use segment::spaces::metric_uint::sse2::manhattan::sse_manhattan_similarity_bytes;
-use segment::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use segment::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 
 const DIM: usize = 1024;
 const COUNT: usize = 100_000;
@@ -417,10 +426,72 @@ fn half_metrics_bench(c: &mut Criterion) {
     });
 }
 
+fn bit_metrics_bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bit-metrics-bench-group");
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let packed_dim = PackedBits::packed_dim(DIM);
+    let random_vectors_1: Vec<Vec<PackedBits>> = (0..COUNT)
+        .map(|_| (0..packed_dim).map(|_| PackedBits(rng.random())).collect())
+        .collect();
+    let random_vectors_2: Vec<Vec<PackedBits>> = (0..COUNT)
+        .map(|_| (0..packed_dim).map(|_| PackedBits(rng.random())).collect())
+        .collect();
+
+    group.bench_function("bit-hamming", |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i = (i + 1) % COUNT;
+            <HammingMetric as Metric<VectorElementTypeBit>>::similarity(
+                &random_vectors_1[i],
+                &random_vectors_2[i],
+            )
+        });
+    });
+
+    group.bench_function("bit-jaccard", |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i = (i + 1) % COUNT;
+            <JaccardMetric as Metric<VectorElementTypeBit>>::similarity(
+                &random_vectors_1[i],
+                &random_vectors_2[i],
+            )
+        });
+    });
+
+    group.bench_function("bit-hamming-no-simd", |b| {
+        let mut i = 0;
+        b.iter(|| {
+            i = (i + 1) % COUNT;
+            popcount_simple(&random_vectors_1[i], &random_vectors_2[i], BitOp::Xor)
+        });
+    });
+
+    #[cfg(target_arch = "x86_64")]
+    group.bench_function("bit-hamming-avx2", |b| {
+        let mut i = 0;
+        b.iter(|| unsafe {
+            i = (i + 1) % COUNT;
+            avx2_popcount(&random_vectors_1[i], &random_vectors_2[i], BitOp::Xor)
+        });
+    });
+
+    #[cfg(target_arch = "aarch64")]
+    group.bench_function("bit-hamming-neon", |b| {
+        let mut i = 0;
+        b.iter(|| unsafe {
+            i = (i + 1) % COUNT;
+            neon_popcount(&random_vectors_1[i], &random_vectors_2[i], BitOp::Xor)
+        });
+    });
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default();
-    targets = byte_metrics_bench, half_metrics_bench
+    targets = byte_metrics_bench, half_metrics_bench, bit_metrics_bench
 }
 
 criterion_main!(benches);
@@ -83,6 +83,7 @@ fn make_segment_index<R: Rng + ?Sized>(rng: &mut R, distance: Distance) -> HNSWI
                 quantization_config: None,
                 multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                matryoshka_dim: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -123,6 +124,7 @@ fn make_segment_index<R: Rng + ?Sized>(rng: &mut R, distance: Distance) -> HNSWI
             quantized_vectors: quantized_vectors.clone(),
             payload_index: segment.payload_index.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
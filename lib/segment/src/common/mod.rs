@@ -16,8 +16,10 @@ use std::sync::atomic::AtomicBool;
 
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::vectors::{QueryVector, VectorRef};
-use crate::types::{SegmentConfig, SparseVectorDataConfig, VectorDataConfig, VectorName};
+use crate::data_types::vectors::{QueryVector, VectorInternal, VectorRef};
+use crate::types::{
+    SearchParams, SegmentConfig, SparseVectorDataConfig, VectorDataConfig, VectorName,
+};
 
 pub type Flusher = Box<dyn FnOnce() -> OperationResult<()> + Send>;
 
@@ -123,12 +125,22 @@ fn check_query_sparse_vector(
 
 /// Check that the given vector name and elements are compatible with the given segment config.
 ///
+/// With the `matryoshka` search param, queries are checked against the truncated view of
+/// the vectors instead, see [`VectorDataConfig::matryoshka_dim`].
+///
 /// Returns an error if incompatible.
 pub fn check_query_vectors(
     vector_name: &VectorName,
     query_vectors: &[&QueryVector],
+    params: Option<&SearchParams>,
     segment_config: &SegmentConfig,
 ) -> OperationResult<()> {
+    if params.is_some_and(|params| params.matryoshka) {
+        let vector_config = get_vector_config_or_error(vector_name, segment_config)?;
+        return query_vectors
+            .iter()
+            .try_for_each(|qv| check_matryoshka_query_vector(qv, vector_config));
+    }
     let vector_config = get_vector_config_or_error(vector_name, segment_config);
     if let Ok(vector_config) = vector_config {
         query_vectors
@@ -143,6 +155,38 @@ pub fn check_query_vectors(
     Ok(())
 }
 
+/// Check that the query is a nearest query on the truncated view of the vectors,
+/// see [`VectorDataConfig::matryoshka_dim`].
+fn check_matryoshka_query_vector(
+    query_vector: &QueryVector,
+    vector_config: &VectorDataConfig,
+) -> OperationResult<()> {
+    let Some(matryoshka_dim) = vector_config.matryoshka_dim else {
+        return Err(OperationError::validation_error(
+            "Matryoshka search requires `matryoshka_dim` to be configured for the vector",
+        ));
+    };
+    match query_vector {
+        QueryVector::Nearest(VectorInternal::Dense(vector)) => {
+            if vector.len() != matryoshka_dim {
+                return Err(OperationError::WrongVectorDimension {
+                    expected_dim: matryoshka_dim,
+                    received_dim: vector.len(),
+                });
+            }
+            Ok(())
+        }
+        QueryVector::Nearest(_)
+        | QueryVector::RecommendBestScore(_)
+        | QueryVector::RecommendSumScores(_)
+        | QueryVector::Discover(_)
+        | QueryVector::Context(_)
+        | QueryVector::FeedbackNaive(_) => Err(OperationError::validation_error(
+            "Matryoshka search is only supported for nearest queries with a dense vector",
+        )),
+    }
+}
+
 /// Check that the given named vectors are compatible with the given segment config.
 ///
 /// Returns an error if incompatible.
@@ -1,5 +1,6 @@
 use std::backtrace::Backtrace;
 use std::collections::TryReserveError;
+use std::fmt;
 use std::io::{Error as IoError, ErrorKind};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
@@ -8,6 +9,7 @@ use atomicwrites::Error as AtomicIoError;
 use common::mmap::Error as MmapError;
 use common::universal_io::UniversalIoError;
 use gridstore::error::GridstoreError;
+use itertools::Itertools as _;
 use rayon::ThreadPoolBuildError;
 use thiserror::Error;
 
@@ -76,6 +78,10 @@ pub enum OperationError {
     },
     #[error("The expression {expression} produced a non-finite number")]
     NonFiniteNumber { expression: String },
+    #[error("Point version conflict: {}", .conflicts.iter().join(", "))]
+    VersionConflict {
+        conflicts: Vec<PointVersionConflict>,
+    },
 }
 
 impl OperationError {
@@ -130,6 +136,35 @@ impl OperationError {
     }
 }
 
+/// Describes a point, which version did not match the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointVersionConflict {
+    pub point_id: PointIdType,
+    pub expected: SeqNumberType,
+    /// Current version of the point, `None` if the point does not exist
+    pub actual: Option<SeqNumberType>,
+}
+
+impl fmt::Display for PointVersionConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            point_id,
+            expected,
+            actual,
+        } = self;
+        match actual {
+            Some(actual) => write!(
+                f,
+                "point {point_id} expected version {expected}, found {actual}"
+            ),
+            None => write!(
+                f,
+                "point {point_id} expected version {expected}, but it does not exist"
+            ),
+        }
+    }
+}
+
 /// Contains information regarding last operation error, which should be fixed before next operation could be processed
 #[derive(Debug, Clone)]
 pub struct SegmentFailedState {
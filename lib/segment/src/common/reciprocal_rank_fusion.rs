@@ -112,6 +112,8 @@ mod tests {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_by_values: Vec::new(),
+            stratum: None,
         }
     }
 
@@ -68,6 +68,7 @@ impl From<SegmentConfigV5> for SegmentConfig {
                     },
                     multivector_config: None,
                     datatype: None,
+                    matryoshka_dim: None,
                 };
 
                 (vector_name, new_data)
@@ -216,14 +217,18 @@ mod tests {
         eprintln!("new = {new_segment:#?}");
 
         match &new_segment.vector_data.get("vec1").unwrap().index {
-            Indexes::Plain { .. } => panic!("expected HNSW index"),
+            Indexes::Plain { .. } | Indexes::Vamana(_) | Indexes::Ivf(_) => {
+                panic!("expected HNSW index")
+            }
             Indexes::Hnsw(hnsw) => {
                 assert_eq!(hnsw.m, 20);
             }
         }
 
         match &new_segment.vector_data.get("vec2").unwrap().index {
-            Indexes::Plain { .. } => panic!("expected HNSW index"),
+            Indexes::Plain { .. } | Indexes::Vamana(_) | Indexes::Ivf(_) => {
+                panic!("expected HNSW index")
+            }
             Indexes::Hnsw(hnsw) => {
                 assert_eq!(hnsw.m, 25);
             }
@@ -0,0 +1,108 @@
+use itertools::Itertools;
+use schemars::JsonSchema;
+use serde::Serialize;
+
+use crate::index::field_index::{CardinalityEstimation, PrimaryCondition};
+use crate::types::{PayloadKeyType, SearchParams};
+
+/// How a segment finds the points of a search or a filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchStrategy {
+    /// All matching vectors are scored without approximation, as requested by `exact` param
+    Exact,
+    /// All points of the segment are checked against the filter and scored
+    FullScan,
+    /// Points are selected with payload indexes first, and then checked and scored
+    PayloadIndex,
+    /// Search in the HNSW graph, checking the filter on visited points
+    Hnsw,
+    /// Search in the HNSW graph, which also explores neighbours of filtered out points
+    Acorn,
+    /// Search in the Vamana graph
+    Vamana,
+    /// Search in the nearest lists of the IVF index
+    Ivf,
+    /// Search in the inverted index of sparse vectors
+    SparseIndex,
+}
+
+impl SearchStrategy {
+    /// Strategy of a search, which scores all candidate points without an index
+    pub fn plain(params: Option<&SearchParams>) -> Self {
+        if params.is_some_and(|params| params.exact) {
+            SearchStrategy::Exact
+        } else {
+            SearchStrategy::FullScan
+        }
+    }
+}
+
+/// Number of points matching a filter, estimated from the payload indexes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct EstimatedCardinality {
+    pub min: usize,
+    pub exp: usize,
+    pub max: usize,
+}
+
+/// How a segment executes a single search or filter
+#[derive(Debug, Clone, PartialEq, Serialize, JsonSchema)]
+pub struct SegmentExplanation {
+    pub strategy: SearchStrategy,
+    /// Number of available points in the segment
+    pub available_points: usize,
+    pub estimated_cardinality: EstimatedCardinality,
+    /// Exact number of points in the segment, which match the filter
+    pub actual_cardinality: usize,
+    /// Payload indexes, which can select the matching points without a full scan
+    pub indexes_used: Vec<PayloadKeyType>,
+}
+
+impl SegmentExplanation {
+    pub fn new(
+        strategy: SearchStrategy,
+        available_points: usize,
+        estimation: &CardinalityEstimation,
+        actual_cardinality: usize,
+    ) -> Self {
+        let indexes_used = estimation
+            .primary_clauses
+            .iter()
+            .filter_map(|clause| match clause {
+                PrimaryCondition::Condition(condition) => Some(&condition.key),
+                PrimaryCondition::Composite(composite) => Some(&composite.field),
+                PrimaryCondition::Ids(_) | PrimaryCondition::HasVector(_) => None,
+            })
+            .unique()
+            .cloned()
+            .collect();
+
+        // Plain search only scans all points, if no index can select them
+        let strategy = match strategy {
+            SearchStrategy::FullScan if !estimation.primary_clauses.is_empty() => {
+                SearchStrategy::PayloadIndex
+            }
+            SearchStrategy::Exact
+            | SearchStrategy::FullScan
+            | SearchStrategy::PayloadIndex
+            | SearchStrategy::Hnsw
+            | SearchStrategy::Acorn
+            | SearchStrategy::Vamana
+            | SearchStrategy::Ivf
+            | SearchStrategy::SparseIndex => strategy,
+        };
+
+        Self {
+            strategy,
+            available_points,
+            estimated_cardinality: EstimatedCardinality {
+                min: estimation.min,
+                exp: estimation.exp,
+                max: estimation.max,
+            },
+            actual_cardinality,
+            indexes_used,
+        }
+    }
+}
@@ -9,6 +9,8 @@ pub enum GroupId {
     String(String),
     NumberU64(u64),
     NumberI64(i64),
+    /// Values of several group_by keys, in the order of the keys
+    Tuple(Vec<GroupId>),
 }
 
 impl From<u64> for GroupId {
@@ -41,6 +43,7 @@ impl From<GroupId> for JsonValue {
             GroupId::String(s) => JsonValue::String(s),
             GroupId::NumberU64(n) => json!(n),
             GroupId::NumberI64(n) => json!(n),
+            GroupId::Tuple(ids) => JsonValue::Array(ids.into_iter().map(JsonValue::from).collect()),
         }
     }
 }
@@ -67,7 +70,7 @@ impl GroupId {
         match self {
             GroupId::NumberI64(id) => u64::try_from(*id).ok(),
             GroupId::NumberU64(id) => Some(*id),
-            GroupId::String(_) => None,
+            GroupId::String(_) | GroupId::Tuple(_) => None,
         }
     }
 }
@@ -6,6 +6,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError, ValidationErrors};
 
+use crate::json_path::JsonPath;
+
 // Keyword
 
 #[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
@@ -539,6 +541,107 @@ pub struct DatetimeIndexParams {
     pub enable_hnsw: Option<bool>,
 }
 
+// Composite
+
+/// Maximal number of fields in a composite key, including the indexed field itself
+pub const MAX_COMPOSITE_INDEX_FIELDS: usize = 8;
+
+#[derive(Default, Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompositeIndexType {
+    #[default]
+    Composite,
+}
+
+/// Type of values of a single field of a composite key
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompositeFieldType {
+    Keyword,
+    Integer,
+    Float,
+    Datetime,
+    Uuid,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct CompositeIndexField {
+    /// Payload key of the field
+    pub key: JsonPath,
+    /// Type of values of the field
+    pub r#type: CompositeFieldType,
+}
+
+/// Index over an ordered tuple of payload fields.
+///
+/// The indexed field is the first field of the tuple, `fields` lists the rest in order.
+/// A single lookup can answer equality conditions on a prefix of the tuple,
+/// optionally followed by a range condition on the next field.
+/// The indexed field also gets a regular index of `field_type`.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, PartialEq, Hash, Eq)]
+#[serde(rename_all = "snake_case")]
+pub struct CompositeIndexParams {
+    // Required for OpenAPI schema without anonymous types, versus #[serde(tag = "type")]
+    pub r#type: CompositeIndexType,
+
+    /// Type of values of the indexed field
+    pub field_type: CompositeFieldType,
+
+    /// Other fields of the composite key, in order
+    pub fields: Vec<CompositeIndexField>,
+
+    /// If true, store the regular index of the indexed field on disk. Default: false.
+    /// The composite key itself is always kept in memory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_disk: Option<bool>,
+
+    /// Enable HNSW graph building for this payload field.
+    /// If true, builds additional HNSW links (Need payload_m > 0).
+    /// Default: true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_hnsw: Option<bool>,
+}
+
+impl Validate for CompositeIndexParams {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        validate_composite_index_fields(self.fields.iter().map(|field| &field.key))
+    }
+}
+
+/// Validate keys of the additional fields of a composite index
+pub fn validate_composite_index_fields<K: Ord + fmt::Display>(
+    keys: impl ExactSizeIterator<Item = K>,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    if keys.len() == 0 || keys.len() >= MAX_COMPOSITE_INDEX_FIELDS {
+        let mut error = ValidationError::new("length");
+        error.message = Some(
+            format!(
+                "composite index must have between 1 and {} additional fields",
+                MAX_COMPOSITE_INDEX_FIELDS - 1,
+            )
+            .into(),
+        );
+        errors.add("fields", error);
+    }
+    let mut unique = BTreeSet::new();
+    for key in keys {
+        if unique.contains(&key) {
+            let mut error = ValidationError::new("unique");
+            error.message = Some(format!("field `{key}` is listed more than once").into());
+            errors.add("fields", error);
+            break;
+        }
+        unique.insert(key);
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,6 @@
 pub mod build_index_result;
 pub mod collection_defaults;
+pub mod explain;
 pub mod facets;
 pub mod groups;
 pub mod index;
@@ -7,7 +7,8 @@ use super::primitive::PrimitiveVectorElement;
 use super::tiny_map;
 use super::vectors::{
     DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
-    VectorElementType, VectorElementTypeByte, VectorElementTypeHalf, VectorInternal, VectorRef,
+    VectorElementType, VectorElementTypeBit, VectorElementTypeByte, VectorElementTypeHalf,
+    VectorInternal, VectorRef,
 };
 use crate::common::operation_error::OperationError;
 use crate::types::{VectorDataConfig, VectorName, VectorNameBuf, VectorStorageDatatype};
@@ -346,6 +347,9 @@ impl<'a> NamedVectors<'a> {
             Some(VectorStorageDatatype::Float16) => config
                 .distance
                 .preprocess_vector::<VectorElementTypeHalf>(dense_vector),
+            Some(VectorStorageDatatype::Bit) => config
+                .distance
+                .preprocess_vector::<VectorElementTypeBit>(dense_vector),
         }
     }
 }
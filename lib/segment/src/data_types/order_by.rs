@@ -1,4 +1,7 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::hash::Hash;
+use std::str::FromStr;
 
 use num_cmp::NumCmp;
 use ordered_float::OrderedFloat;
@@ -6,9 +9,11 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::json_path::JsonPath;
 use crate::types::{
-    DateTimePayloadType, FloatPayloadType, IntPayloadType, Order, Range, RangeInterface,
+    DateTimePayloadType, FloatPayloadType, IntPayloadType, KeywordRange, Order, PayloadContainer,
+    PointIdType, Range, RangeInterface,
 };
 
 #[derive(Deserialize, Serialize, JsonSchema, Copy, Clone, Debug, Default, PartialEq, Hash)]
@@ -20,6 +25,14 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// Turn an ascending comparison into the comparison in this direction
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            Direction::Asc => ordering,
+            Direction::Desc => ordering.reverse(),
+        }
+    }
+
     pub fn as_range_from<T>(&self, from: T) -> Range<T> {
         match self {
             Direction::Asc => Range {
@@ -47,13 +60,16 @@ impl From<Direction> for Order {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum StartFrom {
     Integer(IntPayloadType),
 
     Float(FloatPayloadType),
 
+    /// Parsed as a datetime when ordering by a numeric index, compared byte-wise when ordering by a keyword index
+    Keyword(String),
+
     Datetime(DateTimePayloadType),
 }
 
@@ -62,6 +78,7 @@ impl Hash for StartFrom {
         match self {
             StartFrom::Integer(i) => i.hash(state),
             StartFrom::Float(f) => OrderedFloat(*f).hash(state),
+            StartFrom::Keyword(keyword) => keyword.hash(state),
             StartFrom::Datetime(dt) => dt.hash(state),
         }
     }
@@ -82,6 +99,8 @@ impl From<OrderByInterface> for OrderBy {
                 key,
                 direction: None,
                 start_from: None,
+                then_by: Vec::new(),
+                start_after: None,
             },
             OrderByInterface::Struct(order_by) => order_by,
         }
@@ -108,11 +127,155 @@ pub struct OrderBy {
 
     /// Which payload value to start scrolling from. Default is the lowest value for `asc` and the highest for `desc`
     pub start_from: Option<StartFrom>,
+
+    /// Payload keys to order points with equal values of the previous keys, in sequence.
+    /// Values are read from the payload, so these keys don't require an index.
+    /// Strings in a datetime format are ordered as datetimes, like in the datetime index.
+    /// Points without a value for a key go last. Point IDs break the remaining ties.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub then_by: Vec<ThenBy>,
+
+    /// Continue right after this point of previous results, takes precedence over `start_from`.
+    /// Stable across pages, even if many points share the same values.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_after: Option<OrderByCursor>,
+}
+
+/// Additional key of [`OrderBy`], used to order points with equal values of the previous keys.
+#[derive(Deserialize, Serialize, JsonSchema, Validate, Clone, Debug, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct ThenBy {
+    /// Payload key to order by
+    pub key: JsonPath,
+
+    /// Direction of ordering: `asc` or `desc`. Default is ascending.
+    pub direction: Option<Direction>,
+}
+
+impl ThenBy {
+    pub fn direction(&self) -> Direction {
+        self.direction.unwrap_or_default()
+    }
+
+    /// Value to order the point by: the lowest of its values for `asc`, the highest for `desc`.
+    ///
+    /// Datetime strings are converted into timestamps, the same way the datetime index does,
+    /// so that datetimes with different time zones are ordered correctly.
+    pub fn value_of(&self, payload: &impl PayloadContainer) -> Option<OrderValue> {
+        let values = payload
+            .get_value(&self.key)
+            .into_iter()
+            .filter_map(|value| match value {
+                serde_json::Value::String(keyword) => Some(
+                    DateTimePayloadType::from_str(keyword)
+                        .map(|datetime| OrderValue::Int(datetime.timestamp()))
+                        .unwrap_or_else(|_| OrderValue::Keyword(keyword.clone())),
+                ),
+                value => OrderValue::try_from(value.clone()).ok(),
+            });
+        match self.direction() {
+            Direction::Asc => values.min(),
+            Direction::Desc => values.max(),
+        }
+    }
+}
+
+/// Position of a point in ordered results.
+///
+/// Made of the `order_value`, `then_by_values` and `id` of the last point of a page.
+#[derive(Deserialize, Serialize, JsonSchema, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct OrderByCursor {
+    /// Value of the point for `key`
+    pub value: OrderValue,
+
+    /// Values of the point for `then_by` keys, `null` if the point has no value for a key
+    #[serde(default)]
+    pub then_by: Vec<Option<OrderValue>>,
+
+    /// ID of the point
+    pub id: PointIdType,
+}
+
+impl OrderByCursor {
+    pub fn key(&self) -> OrderKey<'_> {
+        OrderKey {
+            value: Some(&self.value),
+            then_by: &self.then_by,
+            id: self.id,
+        }
+    }
+}
+
+impl Hash for OrderByCursor {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Integers and floats may be equal, so hash both as floats
+        fn hash_value<H: std::hash::Hasher>(value: &OrderValue, state: &mut H) {
+            match value {
+                OrderValue::Int(i) => OrderedFloat(*i as f64).hash(state),
+                OrderValue::Float(f) => OrderedFloat(*f).hash(state),
+                OrderValue::Keyword(keyword) => keyword.hash(state),
+            }
+        }
+
+        let Self { value, then_by, id } = self;
+        hash_value(value, state);
+        for value in then_by {
+            value.is_some().hash(state);
+            if let Some(value) = value {
+                hash_value(value, state);
+            }
+        }
+        id.hash(state);
+    }
+}
+
+/// Point of ordered results, with the values it is ordered by
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderedPoint {
+    /// Value of the point for `key`
+    pub value: OrderValue,
+    /// Values of the point for `then_by` keys, empty if there are none
+    pub then_by: Vec<Option<OrderValue>>,
+    pub id: PointIdType,
+}
+
+impl OrderedPoint {
+    pub fn key(&self) -> OrderKey<'_> {
+        OrderKey {
+            value: Some(&self.value),
+            then_by: &self.then_by,
+            id: self.id,
+        }
+    }
+}
+
+/// Borrowed values a point is ordered by, see [`OrderBy::cmp_keys`]
+#[derive(Clone, Copy, Debug)]
+pub struct OrderKey<'a> {
+    pub value: Option<&'a OrderValue>,
+    pub then_by: &'a [Option<OrderValue>],
+    pub id: PointIdType,
 }
 
 impl OrderBy {
     /// Returns a range representation of OrderBy.
     pub fn as_range(&self) -> RangeInterface {
+        if let Some(cursor) = &self.start_after {
+            // Points equal to the cursor are included, to be compared by `then_by` values and IDs
+            return match &cursor.value {
+                OrderValue::Int(i) => {
+                    RangeInterface::Float(self.direction().as_range_from(OrderedFloat(*i as f64)))
+                }
+                OrderValue::Float(f) => {
+                    RangeInterface::Float(self.direction().as_range_from(OrderedFloat(*f)))
+                }
+                OrderValue::Keyword(keyword) => RangeInterface::Keyword(KeywordRange {
+                    keyword: self.direction().as_range_from(keyword.clone()),
+                }),
+            };
+        }
+
         self.start_from
             .as_ref()
             .map(|start_from| match start_from {
@@ -124,6 +287,9 @@ impl OrderBy {
                 StartFrom::Float(f) => {
                     RangeInterface::Float(self.direction().as_range_from(OrderedFloat(*f)))
                 }
+                StartFrom::Keyword(keyword) => RangeInterface::Keyword(KeywordRange {
+                    keyword: self.direction().as_range_from(keyword.clone()),
+                }),
                 StartFrom::Datetime(dt) => {
                     RangeInterface::DateTime(self.direction().as_range_from(*dt))
                 }
@@ -131,16 +297,115 @@ impl OrderBy {
             .unwrap_or_else(|| RangeInterface::Float(Range::default()))
     }
 
+    /// Interpret `start_from` according to the type of the index used for ordering.
+    ///
+    /// Strings are parsed as datetimes for numeric indexes, and kept as is for keyword indexes.
+    pub fn resolve_start_from(&self, keyword_index: bool) -> OperationResult<Cow<'_, Self>> {
+        if let Some(cursor) = &self.start_after {
+            if cursor.then_by.len() != self.then_by.len() {
+                return Err(OperationError::validation_error(format!(
+                    "`start_after` of `order_by` must have {} `then_by` values, got: {}",
+                    self.then_by.len(),
+                    cursor.then_by.len(),
+                )));
+            }
+            let keyword_cursor = matches!(cursor.value, OrderValue::Keyword(_));
+            if keyword_cursor != keyword_index {
+                let expected = if keyword_index {
+                    "a string"
+                } else {
+                    "a number"
+                };
+                return Err(OperationError::validation_error(format!(
+                    "`start_after` value of `order_by` must be {expected} for field `{}`",
+                    self.key,
+                )));
+            }
+            return Ok(Cow::Borrowed(self));
+        }
+
+        let resolved = match (&self.start_from, keyword_index) {
+            (None, _) | (Some(StartFrom::Keyword(_)), true) => None,
+            (Some(StartFrom::Keyword(keyword)), false) => {
+                let datetime = DateTimePayloadType::from_str(keyword).map_err(|_| {
+                    OperationError::validation_error(format!(
+                        "`start_from` of `order_by` must be a number or a datetime for field `{}`, got: {keyword}",
+                        self.key,
+                    ))
+                })?;
+                Some(StartFrom::Datetime(datetime))
+            }
+            (Some(StartFrom::Integer(_) | StartFrom::Float(_) | StartFrom::Datetime(_)), true) => {
+                return Err(OperationError::validation_error(format!(
+                    "`start_from` of `order_by` must be a string for keyword field `{}`",
+                    self.key,
+                )));
+            }
+            (Some(StartFrom::Integer(_) | StartFrom::Float(_) | StartFrom::Datetime(_)), false) => {
+                None
+            }
+        };
+
+        Ok(match resolved {
+            Some(start_from) => Cow::Owned(Self {
+                start_from: Some(start_from),
+                ..self.clone()
+            }),
+            None => Cow::Borrowed(self),
+        })
+    }
+
     pub fn direction(&self) -> Direction {
         self.direction.unwrap_or_default()
     }
 
+    /// Whether points with equal values of `key` have to be read and ordered precisely,
+    /// because of `then_by` keys or `start_after`.
+    pub fn resolves_ties(&self) -> bool {
+        !self.then_by.is_empty() || self.start_after.is_some()
+    }
+
+    /// Compares two points in the order of results.
+    ///
+    /// Points are compared by values of `key`, then by values of `then_by` keys in sequence,
+    /// and finally by IDs in the direction of `key`.
+    pub fn cmp_keys(&self, a: OrderKey, b: OrderKey) -> Ordering {
+        let direction = self.direction();
+        direction
+            .apply(a.value.cmp(&b.value))
+            .then_with(|| {
+                self.then_by
+                    .iter()
+                    .zip(a.then_by.iter().zip(b.then_by))
+                    .map(|(then_by, values)| match values {
+                        (Some(a), Some(b)) => then_by.direction().apply(a.cmp(b)),
+                        (Some(_), None) => Ordering::Less,
+                        (None, Some(_)) => Ordering::Greater,
+                        (None, None) => Ordering::Equal,
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .then_with(|| direction.apply(a.id.cmp(&b.id)))
+    }
+
+    /// Whether the point goes after `start_after`, if any
+    pub fn is_after_cursor(&self, key: OrderKey) -> bool {
+        self.start_after
+            .as_ref()
+            .is_none_or(|cursor| self.cmp_keys(key, cursor.key()).is_gt())
+    }
+
     pub fn start_from(&self) -> OrderValue {
+        if let Some(cursor) = &self.start_after {
+            return cursor.value.clone();
+        }
         self.start_from
             .as_ref()
             .map(|start_from| match start_from {
                 StartFrom::Integer(i) => OrderValue::Int(*i),
                 StartFrom::Float(f) => OrderValue::Float(*f),
+                StartFrom::Keyword(keyword) => OrderValue::Keyword(keyword.clone()),
                 StartFrom::Datetime(dt) => OrderValue::Int(dt.timestamp()),
             })
             .unwrap_or_else(|| match self.direction() {
@@ -158,13 +423,22 @@ fn order_value_float_example() -> FloatPayloadType {
     42.5
 }
 
-#[derive(Debug, Clone, Copy, Serialize, JsonSchema)]
+fn order_value_keyword_example() -> String {
+    "v1.2".to_string()
+}
+
+/// Value used for ordering.
+///
+/// Numbers are ordered before keywords, NaN is ordered after everything else.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum OrderValue {
     #[schemars(example = "order_value_int_example")]
     Int(IntPayloadType),
     #[schemars(example = "order_value_float_example")]
     Float(FloatPayloadType),
+    #[schemars(example = "order_value_keyword_example")]
+    Keyword(String),
 }
 
 #[cfg(any(test, feature = "testing"))]
@@ -173,6 +447,7 @@ impl std::hash::Hash for OrderValue {
         match self {
             OrderValue::Int(i) => i.hash(state),
             OrderValue::Float(f) => f.to_bits().hash(state),
+            OrderValue::Keyword(keyword) => keyword.hash(state),
         }
     }
 }
@@ -188,6 +463,7 @@ impl From<OrderValue> for serde_json::Value {
                 .map(serde_json::Value::Number)
                 .unwrap_or(serde_json::Value::Null),
             OrderValue::Int(value) => serde_json::Value::Number(serde_json::Number::from(value)),
+            OrderValue::Keyword(value) => serde_json::Value::String(value),
         }
     }
 }
@@ -196,11 +472,14 @@ impl TryFrom<serde_json::Value> for OrderValue {
     type Error = ();
 
     fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
-        value
-            .as_i64()
-            .map(Self::from)
-            .or_else(|| value.as_f64().map(Self::from))
-            .ok_or(())
+        match value {
+            serde_json::Value::String(keyword) => Ok(OrderValue::Keyword(keyword)),
+            value => value
+                .as_i64()
+                .map(Self::from)
+                .or_else(|| value.as_f64().map(Self::from))
+                .ok_or(()),
+        }
     }
 }
 
@@ -216,6 +495,18 @@ impl From<IntPayloadType> for OrderValue {
     }
 }
 
+impl OrderValue {
+    /// Rank of the value kind: numbers, then keywords, then NaN
+    fn rank(&self) -> u8 {
+        match self {
+            OrderValue::Int(_) => 0,
+            OrderValue::Float(f) if f.is_nan() => 2,
+            OrderValue::Float(_) => 0,
+            OrderValue::Keyword(_) => 1,
+        }
+    }
+}
+
 impl Eq for OrderValue {}
 
 impl PartialEq for OrderValue {
@@ -225,6 +516,9 @@ impl PartialEq for OrderValue {
             (OrderValue::Int(a), OrderValue::Int(b)) => a == b,
             (OrderValue::Float(a), OrderValue::Int(b)) => a.num_eq(*b),
             (OrderValue::Int(a), OrderValue::Float(b)) => a.num_eq(*b),
+            (OrderValue::Keyword(a), OrderValue::Keyword(b)) => a == b,
+            (OrderValue::Keyword(_), OrderValue::Int(_) | OrderValue::Float(_))
+            | (OrderValue::Int(_) | OrderValue::Float(_), OrderValue::Keyword(_)) => false,
         }
     }
 }
@@ -249,6 +543,11 @@ impl Ord for OrderValue {
                 // Ditto, but the NaN is on the right side of the comparison.
                 a.num_cmp(*b).unwrap_or(std::cmp::Ordering::Less)
             }
+            (OrderValue::Keyword(a), OrderValue::Keyword(b)) => a.cmp(b),
+            (OrderValue::Keyword(_), OrderValue::Int(_) | OrderValue::Float(_))
+            | (OrderValue::Int(_) | OrderValue::Float(_), OrderValue::Keyword(_)) => {
+                self.rank().cmp(&other.rank())
+            }
         }
     }
 }
@@ -257,7 +556,84 @@ impl Ord for OrderValue {
 mod tests {
     use proptest::proptest;
 
-    use crate::data_types::order_by::OrderValue;
+    use crate::data_types::order_by::{
+        Direction, OrderBy, OrderByCursor, OrderValue, OrderedPoint, ThenBy,
+    };
+    use crate::json_path::JsonPath;
+    use crate::types::{DateTimePayloadType, Payload};
+
+    #[test]
+    fn test_then_by_value_of() {
+        let then_by = |direction| ThenBy {
+            key: JsonPath::new("created_at"),
+            direction: Some(direction),
+        };
+        let payload: Payload = serde_json::from_value(serde_json::json!({
+            "created_at": ["2024-01-01T12:00:00+05:00", "2024-01-01T10:00:00Z", "not a date"],
+        }))
+        .unwrap();
+
+        // 12:00 in +05:00 is earlier than 10:00 in UTC, unlike their strings
+        let timestamp = |datetime: &str| {
+            let datetime: DateTimePayloadType = datetime.parse().unwrap();
+            OrderValue::Int(datetime.timestamp())
+        };
+        assert_eq!(
+            then_by(Direction::Asc).value_of(&payload),
+            Some(timestamp("2024-01-01T07:00:00Z")),
+        );
+        // Numbers go before strings, which are not datetimes
+        assert_eq!(
+            then_by(Direction::Desc).value_of(&payload),
+            Some(OrderValue::Keyword("not a date".to_string())),
+        );
+    }
+
+    #[test]
+    fn test_order_by_then_by() {
+        let mut order_by = OrderBy {
+            key: JsonPath::new("priority"),
+            direction: Some(Direction::Desc),
+            start_from: None,
+            then_by: vec![ThenBy {
+                key: JsonPath::new("created_at"),
+                direction: Some(Direction::Asc),
+            }],
+            start_after: None,
+        };
+
+        let point = |value: i64, then_by: Option<i64>, id: u64| OrderedPoint {
+            value: OrderValue::Int(value),
+            then_by: vec![then_by.map(OrderValue::Int)],
+            id: id.into(),
+        };
+
+        let mut points = vec![
+            point(1, Some(10), 1),
+            point(2, None, 2),
+            point(2, Some(20), 3),
+            point(2, Some(10), 4),
+            point(1, Some(10), 0),
+        ];
+        points.sort_by(|a, b| order_by.cmp_keys(a.key(), b.key()));
+
+        let ids: Vec<_> = points.iter().map(|point| point.id).collect();
+        assert_eq!(ids, vec![4.into(), 3.into(), 2.into(), 1.into(), 0.into()]);
+
+        let last = &points[1];
+        order_by.start_after = Some(OrderByCursor {
+            value: last.value.clone(),
+            then_by: last.then_by.clone(),
+            id: last.id,
+        });
+
+        let after: Vec<_> = points
+            .iter()
+            .filter(|point| order_by.is_after_cursor(point.key()))
+            .map(|point| point.id)
+            .collect();
+        assert_eq!(after, vec![2.into(), 1.into(), 0.into()]);
+    }
 
     proptest! {
 
@@ -8,7 +8,10 @@ use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
 use super::named_vectors::CowMultiVector;
 use super::vectors::TypedMultiDenseVector;
-use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte, VectorElementTypeHalf};
+use crate::data_types::vectors::{
+    PackedBits, VectorElementType, VectorElementTypeBit, VectorElementTypeByte,
+    VectorElementTypeHalf,
+};
 use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
 
 pub trait PrimitiveVectorElement
@@ -190,3 +193,53 @@ impl PrimitiveVectorElement for VectorElementTypeByte {
         ))
     }
 }
+
+impl PrimitiveVectorElement for VectorElementTypeBit {
+    fn slice_from_float_cow(vector: Cow<[VectorElementType]>) -> Cow<[Self]> {
+        Cow::Owned(PackedBits::pack(&vector))
+    }
+
+    fn slice_to_float_cow(vector: Cow<[Self]>) -> Cow<[VectorElementType]> {
+        Cow::Owned(PackedBits::unpack(&vector))
+    }
+
+    fn quantization_preprocess<'a>(
+        _quantization_config: &QuantizationConfig,
+        _distance: Distance,
+        vector: Cow<'a, [Self]>,
+    ) -> Cow<'a, [f32]> {
+        Cow::Owned(PackedBits::unpack(&vector))
+    }
+
+    fn datatype() -> VectorStorageDatatype {
+        VectorStorageDatatype::Bit
+    }
+
+    fn from_float_multivector(
+        multivector: CowMultiVector<VectorElementType>,
+    ) -> CowMultiVector<Self> {
+        let multivector = multivector.as_vec_ref();
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .flattened_vectors
+                .chunks_exact(multivector.dim)
+                .flat_map(PackedBits::pack)
+                .collect_vec(),
+            PackedBits::packed_dim(multivector.dim),
+        ))
+    }
+
+    fn into_float_multivector(
+        multivector: CowMultiVector<Self>,
+    ) -> CowMultiVector<VectorElementType> {
+        let multivector = multivector.as_vec_ref();
+        CowMultiVector::Owned(TypedMultiDenseVector::new(
+            multivector
+                .flattened_vectors
+                .chunks_exact(multivector.dim)
+                .flat_map(PackedBits::unpack)
+                .collect_vec(),
+            multivector.dim * PackedBits::DIMS,
+        ))
+    }
+}
@@ -7,8 +7,10 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::cow::SimpleCow;
 use common::types::ScoreType;
+use parking_lot::Mutex;
 use sparse::common::types::{DimId, DimWeight};
 
+use crate::data_types::explain::SearchStrategy;
 use crate::data_types::tiny_map;
 use crate::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
 use crate::types::{ScoredPoint, VectorName, VectorNameBuf};
@@ -113,6 +115,7 @@ impl QueryContext {
         SegmentQueryContext {
             query_context: self,
             deleted_points: None,
+            search_strategy: None,
             hardware_counter: self.hardware_usage_accumulator.get_counter_cell(),
         }
     }
@@ -134,6 +137,8 @@ impl Default for QueryContext {
 pub struct SegmentQueryContext<'a> {
     query_context: &'a QueryContext,
     deleted_points: Option<&'a BitSlice>,
+    /// Receives the strategy, which the search actually used in the segment
+    search_strategy: Option<&'a Mutex<Option<SearchStrategy>>>,
     hardware_counter: HardwareCounterCell,
 }
 
@@ -154,6 +159,7 @@ impl<'a> SegmentQueryContext<'a> {
                 .get(vector_name)
                 .copied(),
             deleted_points: self.deleted_points,
+            search_strategy: self.search_strategy,
             hardware_counter: self.hardware_counter.fork(),
         }
     }
@@ -163,6 +169,21 @@ impl<'a> SegmentQueryContext<'a> {
         self
     }
 
+    /// Record the strategy of the searches in this segment, e.g. to explain them
+    pub fn with_search_strategy(
+        mut self,
+        search_strategy: &'a Mutex<Option<SearchStrategy>>,
+    ) -> Self {
+        self.search_strategy = Some(search_strategy);
+        self
+    }
+
+    /// Strategy, which was recorded by the last search in this segment
+    pub fn recorded_search_strategy(&self) -> Option<SearchStrategy> {
+        self.search_strategy
+            .and_then(|search_strategy| *search_strategy.lock())
+    }
+
     pub fn is_stopped(&self) -> bool {
         self.query_context.is_stopped()
     }
@@ -171,6 +192,7 @@ impl<'a> SegmentQueryContext<'a> {
         Self {
             query_context: self.query_context,
             deleted_points: self.deleted_points,
+            search_strategy: self.search_strategy,
             hardware_counter: self.hardware_counter.fork(),
         }
     }
@@ -191,6 +213,8 @@ pub struct VectorQueryContext<'a> {
 
     deleted_points: Option<&'a BitSlice>,
 
+    search_strategy: Option<&'a Mutex<Option<SearchStrategy>>>,
+
     hardware_counter: HardwareCounterCell,
 }
 
@@ -207,6 +231,13 @@ impl VectorQueryContext<'_> {
         self.deleted_points
     }
 
+    /// Report the strategy, which the index uses to execute the search
+    pub fn record_search_strategy(&self, strategy: SearchStrategy) {
+        if let Some(search_strategy) = self.search_strategy {
+            *search_strategy.lock() = Some(strategy);
+        }
+    }
+
     pub fn is_stopped(&self) -> SimpleCow<'_, AtomicBool> {
         self.is_stopped
             .map(SimpleCow::Borrowed)
@@ -253,6 +284,7 @@ impl Default for VectorQueryContext<'_> {
             idf: None,
             indexed_vectors: None,
             deleted_points: None,
+            search_strategy: None,
             hardware_counter: HardwareCounterCell::new(),
         }
     }
@@ -128,6 +128,7 @@ impl DenseVectorConfig {
             quantization_config: None,
             multivector_config: *multivector_config,
             datatype: *datatype,
+            matryoshka_dim: None,
         }
     }
 }
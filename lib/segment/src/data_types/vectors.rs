@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 use sparse::common::sparse_vector::SparseVector;
 use sparse::common::types::DimId;
 use validator::Validate;
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
 use super::named_vectors::NamedVectors;
 use super::primitive::PrimitiveVectorElement;
@@ -256,6 +257,64 @@ pub type VectorElementTypeHalf = f16;
 
 pub type VectorElementTypeByte = u8;
 
+pub type VectorElementTypeBit = PackedBits;
+
+/// Eight dimensions of a binary vector packed into a byte, the first dimension in the highest bit.
+///
+/// A dimension is set if its value is greater than zero.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Deserialize,
+    Serialize,
+    FromBytes,
+    Immutable,
+    IntoBytes,
+    KnownLayout,
+    bytemuck::Pod,
+    bytemuck::Zeroable,
+)]
+#[serde(transparent)]
+#[repr(transparent)]
+pub struct PackedBits(pub u8);
+
+impl PackedBits {
+    /// Number of dimensions packed into a single element
+    pub const DIMS: usize = u8::BITS as usize;
+
+    /// Number of elements required to store a vector of `dim` dimensions
+    pub fn packed_dim(dim: usize) -> usize {
+        dim.div_ceil(Self::DIMS)
+    }
+
+    pub fn pack(vector: &[VectorElementType]) -> Vec<Self> {
+        vector
+            .chunks(Self::DIMS)
+            .map(|chunk| {
+                let byte = chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, value)| **value > 0.0)
+                    .fold(0u8, |byte, (i, _)| byte | (0x80 >> i));
+                Self(byte)
+            })
+            .collect()
+    }
+
+    pub fn unpack(vector: &[Self]) -> DenseVector {
+        vector
+            .iter()
+            .flat_map(|PackedBits(byte)| {
+                (0..Self::DIMS).map(move |i| if byte & (0x80 >> i) != 0 { 1.0 } else { 0.0 })
+            })
+            .collect()
+    }
+}
+
 pub const DEFAULT_VECTOR_NAME: &VectorName = "";
 
 pub type TypedDenseVector<T> = Vec<T>;
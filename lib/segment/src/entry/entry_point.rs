@@ -11,9 +11,10 @@ use uuid::Uuid;
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use crate::data_types::build_index_result::BuildFieldIndexResult;
+use crate::data_types::explain::SegmentExplanation;
 use crate::data_types::facets::{FacetParams, FacetValue};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::order_by::{OrderBy, OrderValue};
+use crate::data_types::order_by::{OrderBy, OrderedPoint};
 use crate::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use crate::data_types::segment_record::SegmentRecord;
 use crate::data_types::vector_name_config::VectorNameConfig;
@@ -43,6 +44,11 @@ pub trait ReadSegmentEntry {
     /// Returns `None` if point does not exist or is soft-deleted.
     fn point_version(&self, point_id: PointIdType) -> Option<SeqNumberType>;
 
+    /// Get number of writes made to specified point
+    ///
+    /// Returns `None` if point does not exist, is soft-deleted or writes are not counted.
+    fn point_write_count(&self, point_id: PointIdType) -> Option<SeqNumberType>;
+
     #[allow(clippy::too_many_arguments)]
     fn search_batch(
         &self,
@@ -115,7 +121,8 @@ pub trait ReadSegmentEntry {
     ) -> OperationResult<Vec<PointIdType>>;
 
     /// Return points which satisfies filtering condition ordered by the `order_by.key` field,
-    /// starting with `order_by.start_from` value including.
+    /// starting with `order_by.start_from` value including, or right after `order_by.start_after`.
+    /// Points with equal values are ordered by `order_by.then_by` keys.
     ///
     /// Will fail if there is no index for the order_by key.
     /// Cancelled by `is_stopped` flag.
@@ -127,7 +134,7 @@ pub trait ReadSegmentEntry {
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
         deferred_behavior: DeferredBehavior,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>>;
+    ) -> OperationResult<Vec<OrderedPoint>>;
 
     /// Return random points which satisfies filtering condition.
     ///
@@ -172,6 +179,26 @@ pub trait ReadSegmentEntry {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<CardinalityEstimation>;
 
+    /// Explain how the points matching the filter are selected in this segment.
+    fn explain_filter(
+        &self,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SegmentExplanation>;
+
+    /// Explain how a search of the query vector is executed in this segment.
+    ///
+    /// Reports the strategy recorded by a search with the same query context, if there was one.
+    fn explain_search(
+        &self,
+        vector_name: &VectorName,
+        query_vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        query_context: &SegmentQueryContext,
+    ) -> OperationResult<SegmentExplanation>;
+
     fn vector_names(&self) -> HashSet<VectorNameBuf>;
 
     /// Whether this segment is completely empty in terms of points
@@ -451,4 +478,12 @@ pub trait SegmentEntry: NonAppendableSegmentEntry {
         point_id: PointIdType,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<bool>;
+
+    /// Set number of writes made to the point, only used if the collection counts writes
+    fn set_point_write_count(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        write_count: SeqNumberType,
+    ) -> OperationResult<bool>;
 }
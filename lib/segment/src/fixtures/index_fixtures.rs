@@ -91,6 +91,7 @@ impl TestRawScorerProducer {
             self.quantized_vectors.as_ref(),
             None,
             &self.deleted_points,
+            None,
             HardwareCounterCell::new(),
         )
         .unwrap()
@@ -34,6 +34,20 @@ impl IdTrackerRead for IdTrackerEnum {
         }
     }
 
+    fn internal_write_count(&self, internal_id: PointOffsetType) -> Option<SeqNumberType> {
+        match self {
+            IdTrackerEnum::MutableIdTracker(id_tracker) => {
+                id_tracker.internal_write_count(internal_id)
+            }
+            IdTrackerEnum::ImmutableIdTracker(id_tracker) => {
+                id_tracker.internal_write_count(internal_id)
+            }
+            IdTrackerEnum::InMemoryIdTracker(id_tracker) => {
+                id_tracker.internal_write_count(internal_id)
+            }
+        }
+    }
+
     fn internal_id(&self, external_id: PointIdType) -> Option<PointOffsetType> {
         match self {
             IdTrackerEnum::MutableIdTracker(id_tracker) => id_tracker.internal_id(external_id),
@@ -148,6 +162,24 @@ impl IdTracker for IdTrackerEnum {
         }
     }
 
+    fn set_internal_write_count(
+        &mut self,
+        internal_id: PointOffsetType,
+        write_count: SeqNumberType,
+    ) -> OperationResult<()> {
+        match self {
+            IdTrackerEnum::MutableIdTracker(id_tracker) => {
+                id_tracker.set_internal_write_count(internal_id, write_count)
+            }
+            IdTrackerEnum::ImmutableIdTracker(id_tracker) => {
+                id_tracker.set_internal_write_count(internal_id, write_count)
+            }
+            IdTrackerEnum::InMemoryIdTracker(id_tracker) => {
+                id_tracker.set_internal_write_count(internal_id, write_count)
+            }
+        }
+    }
+
     fn set_link(
         &mut self,
         external_id: PointIdType,
@@ -33,6 +33,13 @@ pub trait IdTracker: IdTrackerRead + fmt::Debug {
         version: SeqNumberType,
     ) -> OperationResult<()>;
 
+    /// Set the number of writes made to the point, see [`IdTrackerRead::internal_write_count`]
+    fn set_internal_write_count(
+        &mut self,
+        internal_id: PointOffsetType,
+        write_count: SeqNumberType,
+    ) -> OperationResult<()>;
+
     /// Set mapping
     fn set_link(
         &mut self,
@@ -107,6 +114,14 @@ pub trait IdTrackerRead {
 
     fn internal_version(&self, internal_id: PointOffsetType) -> Option<SeqNumberType>;
 
+    /// Number of writes made to the point, if writes are counted in this segment.
+    ///
+    /// Unlike the internal version, which is the number of the last operation applied to the
+    /// point, the write count doesn't depend on the operation numbering of the replica.
+    fn internal_write_count(&self, _internal_id: PointOffsetType) -> Option<SeqNumberType> {
+        None
+    }
+
     /// Returns internal ID of the point, which is used inside this segment
     ///
     /// Excludes soft deleted points.
@@ -8,6 +8,7 @@ pub(super) mod tests;
 #[allow(dead_code)]
 pub mod read_only;
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::io::{BufReader, BufWriter, Write};
 use std::mem::{size_of, size_of_val};
@@ -26,14 +27,17 @@ pub use self::deleted_storage::DELETED_FILE_NAME;
 use self::deleted_storage::deleted_path;
 pub use self::mappings_storage::{MAPPINGS_FILE_NAME, mappings_path};
 use self::mappings_storage::{load_mapping, store_mapping};
-pub use self::versions_storage::VERSION_MAPPING_FILE_NAME;
-use self::versions_storage::{mmap_size, version_mapping_path};
+pub use self::versions_storage::{VERSION_MAPPING_FILE_NAME, WRITE_COUNTS_FILE_NAME};
+use self::versions_storage::{mmap_size, version_mapping_path, write_counts_path};
 use crate::common::Flusher;
 use crate::common::buffered_update_bitslice::BufferedUpdateBitSlice;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::id_tracker::compressed::compressed_point_mappings::CompressedPointMappings;
 use crate::id_tracker::compressed::versions_store::CompressedVersions;
 use crate::id_tracker::in_memory_id_tracker::InMemoryIdTracker;
+use crate::id_tracker::mutable_id_tracker::versions_storage::{
+    load_versions, store_version_changes,
+};
 use crate::id_tracker::{DELETED_POINT_VERSION, IdTracker, IdTrackerRead, PointMappingsRefEnum};
 use crate::types::{PointIdType, SeqNumberType};
 
@@ -46,6 +50,10 @@ pub struct ImmutableIdTracker<S: UniversalWrite> {
     pub(super) internal_to_version: CompressedVersions,
     internal_to_version_wrapper: SliceBufferedUpdateWrapper<S, SeqNumberType>,
 
+    /// Number of writes made to each point, empty if writes are not counted.
+    /// Points are never written to immutable segments, so the counts don't change.
+    internal_to_write_count: Vec<SeqNumberType>,
+
     pub(super) mappings: CompressedPointMappings,
 }
 
@@ -53,6 +61,10 @@ impl<S> ImmutableIdTracker<S>
 where
     S: UniversalWrite + Send + Sync + 'static,
 {
+    fn write_counts_file(&self) -> Option<PathBuf> {
+        Some(write_counts_path(&self.path)).filter(|path| path.is_file())
+    }
+
     /// Approximate RAM usage in bytes for in-memory data structures.
     ///
     /// ImmutableIdTracker loads all mappings and versions into compressed
@@ -64,19 +76,28 @@ where
             deleted_wrapper: _, // mmap-backed, accounted via files
             internal_to_version,
             internal_to_version_wrapper: _, // mmap-backed, accounted via files
+            internal_to_write_count,
             mappings,
         } = self;
 
-        internal_to_version.ram_usage_bytes() + mappings.ram_usage_bytes()
+        internal_to_version.ram_usage_bytes()
+            + internal_to_write_count.capacity() * size_of::<SeqNumberType>()
+            + mappings.ram_usage_bytes()
     }
 
     pub fn from_in_memory_tracker(
         in_memory_tracker: InMemoryIdTracker,
         path: &Path,
     ) -> OperationResult<Self> {
-        let (internal_to_version, mappings) = in_memory_tracker.into_internal();
+        let (internal_to_version, internal_to_write_count, mappings) =
+            in_memory_tracker.into_internal();
         let compressed_mappings = CompressedPointMappings::from_mappings(mappings);
-        let id_tracker = Self::new(path, &internal_to_version, compressed_mappings)?;
+        let id_tracker = Self::new(
+            path,
+            &internal_to_version,
+            &internal_to_write_count,
+            compressed_mappings,
+        )?;
 
         Ok(id_tracker)
     }
@@ -115,6 +136,13 @@ where
         let internal_to_version_wrapper =
             SliceBufferedUpdateWrapper::new(internal_to_version_file.inner)?;
 
+        let write_counts_path = write_counts_path(segment_path);
+        let internal_to_write_count = if write_counts_path.is_file() {
+            load_versions(&write_counts_path)?
+        } else {
+            vec![]
+        };
+
         let reader = BufReader::new(File::open(mappings_path(segment_path))?);
         let mappings = load_mapping(reader, Some(deleted_bitvec))?;
 
@@ -123,6 +151,7 @@ where
             deleted_wrapper,
             internal_to_version_wrapper,
             internal_to_version,
+            internal_to_write_count,
             mappings,
         })
     }
@@ -130,6 +159,7 @@ where
     pub fn new(
         path: &Path,
         internal_to_version: &[SeqNumberType],
+        internal_to_write_count: &[SeqNumberType],
         mappings: CompressedPointMappings,
     ) -> OperationResult<Self> {
         // Create mmap file for deleted bitvec
@@ -203,6 +233,15 @@ where
         let internal_to_version_wrapper =
             SliceBufferedUpdateWrapper::new(internal_to_version_file.inner)?;
 
+        // Write counts of points are only stored if writes are counted
+        let write_counts: BTreeMap<_, _> = internal_to_write_count
+            .iter()
+            .enumerate()
+            .filter(|(_, write_count)| **write_count > 0)
+            .map(|(internal_id, write_count)| (internal_id as PointOffsetType, *write_count))
+            .collect();
+        store_version_changes(&write_counts_path(path), &write_counts)?;
+
         // Write mappings to disk.
         let file = File::create(mappings_path(path))?;
         let mut writer = BufWriter::new(file);
@@ -221,6 +260,7 @@ where
             deleted_wrapper,
             internal_to_version_wrapper,
             internal_to_version,
+            internal_to_write_count: internal_to_write_count.to_vec(),
             mappings,
         })
     }
@@ -231,6 +271,13 @@ impl<S: UniversalWrite + Send + Sync + 'static> IdTrackerRead for ImmutableIdTra
         self.internal_to_version.get(internal_id)
     }
 
+    fn internal_write_count(&self, internal_id: PointOffsetType) -> Option<SeqNumberType> {
+        self.internal_to_write_count
+            .get(internal_id as usize)
+            .copied()
+            .filter(|&write_count| write_count > 0)
+    }
+
     fn internal_id(&self, external_id: PointIdType) -> Option<PointOffsetType> {
         self.mappings.internal_id(&external_id)
     }
@@ -293,6 +340,17 @@ impl<S: UniversalWrite + Debug + Send + Sync + 'static> IdTracker for ImmutableI
         Ok(())
     }
 
+    fn set_internal_write_count(
+        &mut self,
+        internal_id: PointOffsetType,
+        _write_count: SeqNumberType,
+    ) -> OperationResult<()> {
+        debug_assert!(false, "Can't count writes in immutable tracker");
+        Err(OperationError::service_error(format!(
+            "Can't set write count of point {internal_id} in immutable id tracker",
+        )))
+    }
+
     fn set_link(
         &mut self,
         _external_id: PointIdType,
@@ -336,14 +394,18 @@ impl<S: UniversalWrite + Debug + Send + Sync + 'static> IdTracker for ImmutableI
     }
 
     fn files(&self) -> Vec<PathBuf> {
-        vec![
+        let mut files = vec![
             deleted_path(&self.path),
             mappings_path(&self.path),
             version_mapping_path(&self.path),
-        ]
+        ];
+        files.extend(self.write_counts_file());
+        files
     }
 
     fn immutable_files(&self) -> Vec<PathBuf> {
-        vec![mappings_path(&self.path)]
+        let mut files = vec![mappings_path(&self.path)];
+        files.extend(self.write_counts_file());
+        files
     }
 }
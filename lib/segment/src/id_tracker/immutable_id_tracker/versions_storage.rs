@@ -7,6 +7,13 @@ pub(crate) fn version_mapping_path(base: &Path) -> PathBuf {
     base.join(VERSION_MAPPING_FILE_NAME)
 }
 
+/// Written only if writes to points are counted
+pub const WRITE_COUNTS_FILE_NAME: &str = "id_tracker.write_counts";
+
+pub(crate) fn write_counts_path(base: &Path) -> PathBuf {
+    base.join(WRITE_COUNTS_FILE_NAME)
+}
+
 /// Returns the required mmap filesize for a given length of a slice of type `T`.
 pub(super) fn mmap_size<T>(len: usize) -> usize {
     let item_width = size_of::<T>();
@@ -17,6 +17,8 @@ use crate::types::{PointIdType, SeqNumberType};
 #[derive(Debug, Default)]
 pub struct InMemoryIdTracker {
     internal_to_version: Vec<SeqNumberType>,
+    /// Number of writes made to each point, zero if writes are not counted
+    internal_to_write_count: Vec<SeqNumberType>,
     mappings: PointMappings,
 }
 
@@ -25,18 +27,25 @@ impl InMemoryIdTracker {
         Self::default()
     }
 
-    pub fn into_internal(self) -> (Vec<SeqNumberType>, PointMappings) {
-        (self.internal_to_version, self.mappings)
+    /// Returns point versions, point write counts and point mappings
+    pub fn into_internal(self) -> (Vec<SeqNumberType>, Vec<SeqNumberType>, PointMappings) {
+        (
+            self.internal_to_version,
+            self.internal_to_write_count,
+            self.mappings,
+        )
     }
 
     /// Approximate RAM usage in bytes for in-memory data structures.
     pub fn ram_usage_bytes(&self) -> usize {
         let Self {
             internal_to_version,
+            internal_to_write_count,
             mappings,
         } = self;
 
-        internal_to_version.capacity() * std::mem::size_of::<SeqNumberType>()
+        (internal_to_version.capacity() + internal_to_write_count.capacity())
+            * std::mem::size_of::<SeqNumberType>()
             + mappings.ram_usage_bytes()
     }
 
@@ -47,6 +56,7 @@ impl InMemoryIdTracker {
 
         let mut id_tracker = Self {
             internal_to_version: vec![rand.random(); size as usize],
+            internal_to_write_count: Vec::new(),
             mappings,
         };
 
@@ -64,6 +74,13 @@ impl IdTrackerRead for InMemoryIdTracker {
         self.internal_to_version.get(internal_id as usize).copied()
     }
 
+    fn internal_write_count(&self, internal_id: PointOffsetType) -> Option<SeqNumberType> {
+        self.internal_to_write_count
+            .get(internal_id as usize)
+            .copied()
+            .filter(|&write_count| write_count > 0)
+    }
+
     fn internal_id(&self, external_id: PointIdType) -> Option<PointOffsetType> {
         self.mappings.internal_id(&external_id)
     }
@@ -138,6 +155,26 @@ impl IdTracker for InMemoryIdTracker {
         Ok(())
     }
 
+    fn set_internal_write_count(
+        &mut self,
+        internal_id: PointOffsetType,
+        write_count: SeqNumberType,
+    ) -> OperationResult<()> {
+        if self.external_id(internal_id).is_some() {
+            if let Some(old_write_count) =
+                self.internal_to_write_count.get_mut(internal_id as usize)
+            {
+                *old_write_count = write_count;
+            } else if write_count > 0 {
+                self.internal_to_write_count
+                    .resize(internal_id as usize + 1, 0);
+                self.internal_to_write_count[internal_id as usize] = write_count;
+            }
+        }
+
+        Ok(())
+    }
+
     fn set_link(
         &mut self,
         external_id: PointIdType,
@@ -151,6 +188,7 @@ impl IdTracker for InMemoryIdTracker {
         // Unset version first because it still requires the mapping to exist
         if let Some(internal_id) = self.internal_id(external_id) {
             self.set_internal_version(internal_id, DELETED_POINT_VERSION)?;
+            self.set_internal_write_count(internal_id, 0)?;
         }
         self.mappings.drop(external_id);
         Ok(())
@@ -159,6 +197,7 @@ impl IdTracker for InMemoryIdTracker {
     fn drop_internal(&mut self, internal_id: PointOffsetType) -> OperationResult<()> {
         // Unset version first because it still requires the mapping to exist
         self.set_internal_version(internal_id, DELETED_POINT_VERSION)?;
+        self.set_internal_write_count(internal_id, 0)?;
         if let Some(external_id) = self.mappings.external_id(internal_id) {
             self.mappings.drop(external_id);
         }
@@ -1,6 +1,6 @@
 mod change;
 mod mappings_storage;
-mod versions_storage;
+pub(super) mod versions_storage;
 
 #[cfg(test)]
 pub(super) mod tests;
@@ -25,6 +25,7 @@ use self::mappings_storage::{
 };
 use self::versions_storage::{
     load_versions, reconcile_persisted_version_changes, store_version_changes, versions_path,
+    write_counts_path,
 };
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -53,6 +54,13 @@ pub struct MutableIdTracker {
     /// List of point versions pending to be persisted, will be persisted on flush
     pending_versions: Arc<Mutex<BTreeMap<PointOffsetType, SeqNumberType>>>,
 
+    /// Number of writes made to each point, empty if writes are not counted.
+    /// Zero means that writes to the point are not counted.
+    internal_to_write_count: Vec<SeqNumberType>,
+
+    /// List of point write counts pending to be persisted, will be persisted on flush
+    pending_write_counts: Arc<Mutex<BTreeMap<PointOffsetType, SeqNumberType>>>,
+
     /// List of point mappings pending to be persisted, will be persisted on flush
     pending_mappings: Arc<Mutex<Vec<MappingChange>>>,
 
@@ -131,6 +139,17 @@ impl MutableIdTracker {
             );
         }
 
+        let write_counts_path = write_counts_path(&segment_path);
+        let internal_to_write_count = if write_counts_path.is_file() {
+            load_versions(&write_counts_path).map_err(|err| {
+                OperationError::service_error(format!(
+                    "Failed to load ID tracker write counts: {err}"
+                ))
+            })?
+        } else {
+            vec![]
+        };
+
         #[cfg(debug_assertions)]
         mappings.assert_mappings();
 
@@ -139,6 +158,8 @@ impl MutableIdTracker {
             internal_to_version,
             mappings,
             pending_versions: Default::default(),
+            internal_to_write_count,
+            pending_write_counts: Default::default(),
             pending_mappings: Default::default(),
             is_alive_lock: IsAliveLock::new(),
             mappings_expected_len: Arc::new(AtomicU64::new(mappings_expected_len)),
@@ -152,20 +173,35 @@ impl MutableIdTracker {
             internal_to_version,
             mappings,
             pending_versions: _, // transient, small
-            pending_mappings: _, // transient, small
+            internal_to_write_count,
+            pending_write_counts: _, // transient, small
+            pending_mappings: _,     // transient, small
             is_alive_lock: _,
             mappings_expected_len: _,
         } = self;
 
-        internal_to_version.capacity() * std::mem::size_of::<SeqNumberType>()
+        (internal_to_version.capacity() + internal_to_write_count.capacity())
+            * std::mem::size_of::<SeqNumberType>()
             + mappings.ram_usage_bytes()
     }
 
     pub fn segment_files(segment_path: &Path) -> Vec<PathBuf> {
-        [mappings_path(segment_path), versions_path(segment_path)]
-            .into_iter()
-            .filter(|path| path.is_file())
-            .collect()
+        [
+            mappings_path(segment_path),
+            versions_path(segment_path),
+            write_counts_path(segment_path),
+        ]
+        .into_iter()
+        .filter(|path| path.is_file())
+        .collect()
+    }
+
+    /// Forget the write count of a deleted point, so it is not inherited by a new point
+    fn reset_write_count(&mut self, internal_id: PointOffsetType) -> OperationResult<()> {
+        if self.internal_write_count(internal_id).is_some() {
+            self.set_internal_write_count(internal_id, 0)?;
+        }
+        Ok(())
     }
 }
 
@@ -174,6 +210,13 @@ impl IdTrackerRead for MutableIdTracker {
         self.internal_to_version.get(internal_id as usize).copied()
     }
 
+    fn internal_write_count(&self, internal_id: PointOffsetType) -> Option<SeqNumberType> {
+        self.internal_to_write_count
+            .get(internal_id as usize)
+            .copied()
+            .filter(|&write_count| write_count > 0)
+    }
+
     fn internal_id(&self, external_id: PointIdType) -> Option<PointOffsetType> {
         self.mappings.internal_id(&external_id)
     }
@@ -254,6 +297,22 @@ impl IdTracker for MutableIdTracker {
         Ok(())
     }
 
+    fn set_internal_write_count(
+        &mut self,
+        internal_id: PointOffsetType,
+        write_count: SeqNumberType,
+    ) -> OperationResult<()> {
+        if internal_id as usize >= self.internal_to_write_count.len() {
+            self.internal_to_write_count
+                .resize(internal_id as usize + 1, 0);
+        }
+        self.internal_to_write_count[internal_id as usize] = write_count;
+        self.pending_write_counts
+            .lock()
+            .insert(internal_id, write_count);
+        Ok(())
+    }
+
     fn set_link(
         &mut self,
         external_id: PointIdType,
@@ -273,6 +332,7 @@ impl IdTracker for MutableIdTracker {
             .push(MappingChange::Delete(external_id));
         if let Some(internal_id) = internal_id {
             self.set_internal_version(internal_id, DELETED_POINT_VERSION)?;
+            self.reset_write_count(internal_id)?;
         }
         Ok(())
     }
@@ -286,6 +346,7 @@ impl IdTracker for MutableIdTracker {
         }
 
         self.set_internal_version(internal_id, DELETED_POINT_VERSION)?;
+        self.reset_write_count(internal_id)?;
 
         Ok(())
     }
@@ -344,33 +405,36 @@ impl IdTracker for MutableIdTracker {
     }
 
     /// Creates a flusher function, that persists the removed points in the version database
-    /// and flushes the version database to disk.
+    /// and flushes the version database to disk. Write counts of points are persisted along.
     /// This function should be called _after_ flushing the mapping database.
     fn versions_flusher(&self) -> Flusher {
-        let changes = {
-            let changes_guard = self.pending_versions.lock();
-            if changes_guard.is_empty() {
-                return Box::new(|| Ok(()));
-            }
-            changes_guard.clone()
-        };
+        let changes = self.pending_versions.lock().clone();
+        let write_count_changes = self.pending_write_counts.lock().clone();
+        if changes.is_empty() && write_count_changes.is_empty() {
+            return Box::new(|| Ok(()));
+        }
 
         let versions_path = versions_path(&self.segment_path);
+        let write_counts_path = write_counts_path(&self.segment_path);
 
         let pending_versions_weak = Arc::downgrade(&self.pending_versions);
+        let pending_write_counts_weak = Arc::downgrade(&self.pending_write_counts);
         let is_alive_handle = self.is_alive_lock.handle();
 
         Box::new(move || {
-            let (Some(is_alive_guard), Some(pending_versions_arc)) = (
+            let (Some(is_alive_guard), Some(pending_versions_arc), Some(pending_write_counts_arc)) = (
                 is_alive_handle.lock_if_alive(),
                 pending_versions_weak.upgrade(),
+                pending_write_counts_weak.upgrade(),
             ) else {
                 return Ok(());
             };
 
             store_version_changes(&versions_path, &changes)?;
+            store_version_changes(&write_counts_path, &write_count_changes)?;
 
             reconcile_persisted_version_changes(&pending_versions_arc, changes);
+            reconcile_persisted_version_changes(&pending_write_counts_arc, write_count_changes);
 
             drop(is_alive_guard);
 
@@ -12,6 +12,7 @@ use crate::id_tracker::point_mappings::FileEndianess;
 use crate::types::SeqNumberType;
 
 const FILE_VERSIONS: &str = "mutable_id_tracker.versions";
+const FILE_WRITE_COUNTS: &str = "mutable_id_tracker.write_counts";
 
 pub(super) const VERSION_ELEMENT_SIZE: u64 = size_of::<SeqNumberType>() as u64;
 
@@ -19,7 +20,14 @@ pub(super) fn versions_path(segment_path: &Path) -> PathBuf {
     segment_path.join(FILE_VERSIONS)
 }
 
-pub(super) fn load_versions(versions_path: &Path) -> OperationResult<Vec<SeqNumberType>> {
+/// Point write counts are stored in the same format as versions
+pub(super) fn write_counts_path(segment_path: &Path) -> PathBuf {
+    segment_path.join(FILE_WRITE_COUNTS)
+}
+
+pub(in crate::id_tracker) fn load_versions(
+    versions_path: &Path,
+) -> OperationResult<Vec<SeqNumberType>> {
     let file = File::open(versions_path)?;
 
     let file_len = file.metadata()?.len();
@@ -39,7 +47,7 @@ pub(super) fn load_versions(versions_path: &Path) -> OperationResult<Vec<SeqNumb
 }
 
 /// Store new version changes, appending them to the given file
-pub(super) fn store_version_changes(
+pub(in crate::id_tracker) fn store_version_changes(
     versions_path: &Path,
     changes: &BTreeMap<PointOffsetType, SeqNumberType>,
 ) -> OperationResult<()> {
@@ -0,0 +1,831 @@
+//! Index over an ordered tuple of payload fields.
+//!
+//! Entries are kept in a sorted set of `(tuple of values, point)`. Conditions which fix a prefix
+//! of the tuple with equality, optionally followed by a range on the next field, select one
+//! contiguous run of entries, so they are answered with a single scan instead of intersecting
+//! several independent indexes.
+//!
+//! Multi-value fields are indexed as a cartesian product of their values, so a point matches a
+//! lookup if any combination of its values does. Points with more than [`MAX_KEYS_PER_POINT`]
+//! combinations are not expanded, their values are checked against each lookup instead.
+//!
+//! Values of each point are persisted in Gridstore, entries are rebuilt from it on load.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+use gridstore::config::StorageOptions;
+use gridstore::error::GridstoreError;
+use gridstore::{Blob, Gridstore};
+use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::CardinalityEstimation;
+use super::utils::value_to_integer;
+use crate::common::Flusher;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::index::{CompositeFieldType, CompositeIndexParams};
+use crate::json_path::JsonPath;
+use crate::types::{
+    DateTimePayloadType, FieldCondition, FloatPayloadType, Match, MatchValue, PayloadContainer,
+    Range, RangeInterface, ValueVariants,
+};
+
+/// Maximum number of composite keys a single point is expanded into
+pub const MAX_KEYS_PER_POINT: usize = 64;
+
+/// Single value of a composite key
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum CompositeValue {
+    Keyword(String),
+    /// Integers and datetimes, as microsecond timestamps
+    Integer(i64),
+    Float(OrderedFloat<FloatPayloadType>),
+    Uuid(u128),
+}
+
+/// Values of all fields of a composite key, `None` if the point has no value for a field
+type CompositeKey = Box<[Option<CompositeValue>]>;
+
+/// Distinct values of each field of a composite key for a single point, as persisted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PointValues(Vec<Vec<CompositeValue>>);
+
+impl PointValues {
+    /// Number of composite keys in the cartesian product of the values
+    fn keys_count(&self) -> usize {
+        self.0
+            .iter()
+            .map(|values| values.len().max(1))
+            .fold(1, usize::saturating_mul)
+    }
+
+    fn keys(&self) -> impl Iterator<Item = CompositeKey> + '_ {
+        self.0
+            .iter()
+            .map(|values| {
+                if values.is_empty() {
+                    vec![None]
+                } else {
+                    values.iter().cloned().map(Some).collect()
+                }
+            })
+            .multi_cartesian_product()
+            .map(Vec::into_boxed_slice)
+    }
+
+    /// Whether any combination of the values matches the lookup
+    fn matches(&self, lookup: &CompositeLookup) -> bool {
+        let CompositeLookup { prefix, range } = lookup;
+        let prefix_matches = prefix
+            .iter()
+            .zip(&self.0)
+            .all(|(expected, values)| values.contains(expected));
+        let range_matches = match range {
+            Some(range) => self
+                .0
+                .get(prefix.len())
+                .is_some_and(|values| values.iter().any(|value| range.check(value))),
+            None => true,
+        };
+        prefix_matches && range_matches
+    }
+}
+
+impl Blob for PointValues {
+    fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(&self.0).expect("Composite values serialization should not fail")
+    }
+
+    fn from_bytes(data: &[u8]) -> Self {
+        Self(bincode::deserialize(data).expect("Composite values deserialization should not fail"))
+    }
+}
+
+/// Range condition on a single field of a composite key
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompositeRange {
+    /// Range over integer or float values
+    Number(Range<OrderedFloat<FloatPayloadType>>),
+    /// Range over datetime values, as microsecond timestamps
+    DateTime(Range<i64>),
+}
+
+impl CompositeRange {
+    fn check(&self, value: &CompositeValue) -> bool {
+        match (self, value) {
+            (CompositeRange::Number(range), CompositeValue::Integer(integer)) => {
+                range.check_range(OrderedFloat(*integer as FloatPayloadType))
+            }
+            (CompositeRange::Number(range), CompositeValue::Float(float)) => {
+                range.check_range(*float)
+            }
+            (CompositeRange::DateTime(range), CompositeValue::Integer(timestamp)) => {
+                range.check_range(*timestamp)
+            }
+            (CompositeRange::Number(_) | CompositeRange::DateTime(_), _) => false,
+        }
+    }
+
+    /// Smallest value of the given type which may be in range, to start the scan from
+    fn lower_bound(&self, field_type: CompositeFieldType) -> Option<CompositeValue> {
+        match self {
+            CompositeRange::Number(range) => {
+                let lower = range.gt.into_iter().chain(range.gte).max()?;
+                match field_type {
+                    CompositeFieldType::Integer => {
+                        Some(CompositeValue::Integer(lower.0.floor() as i64))
+                    }
+                    CompositeFieldType::Float => Some(CompositeValue::Float(lower)),
+                    CompositeFieldType::Keyword
+                    | CompositeFieldType::Datetime
+                    | CompositeFieldType::Uuid => None,
+                }
+            }
+            CompositeRange::DateTime(range) => {
+                let lower = range.gt.into_iter().chain(range.gte).max()?;
+                Some(CompositeValue::Integer(lower))
+            }
+        }
+    }
+
+    /// Whether the value, and so all larger values, are above the range
+    fn is_above(&self, value: &CompositeValue) -> bool {
+        match (self, value) {
+            (CompositeRange::Number(range), CompositeValue::Integer(integer)) => {
+                let value = OrderedFloat(*integer as FloatPayloadType);
+                range.lt.is_some_and(|lt| value >= lt) || range.lte.is_some_and(|lte| value > lte)
+            }
+            (CompositeRange::Number(range), CompositeValue::Float(float)) => {
+                range.lt.is_some_and(|lt| *float >= lt) || range.lte.is_some_and(|lte| *float > lte)
+            }
+            (CompositeRange::DateTime(range), CompositeValue::Integer(timestamp)) => {
+                range.lt.is_some_and(|lt| *timestamp >= lt)
+                    || range.lte.is_some_and(|lte| *timestamp > lte)
+            }
+            (CompositeRange::Number(_) | CompositeRange::DateTime(_), _) => false,
+        }
+    }
+}
+
+/// Lookup in a composite index: equality on the first `prefix.len()` fields,
+/// and an optional range on the next one
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeLookup {
+    pub prefix: Vec<CompositeValue>,
+    pub range: Option<CompositeRange>,
+}
+
+impl CompositeLookup {
+    /// Whether the key may be followed by more matching keys in sort order
+    fn is_within_bounds(&self, key: &[Option<CompositeValue>]) -> bool {
+        let prefix_matches = key
+            .iter()
+            .zip(&self.prefix)
+            .all(|(value, expected)| value.as_ref() == Some(expected));
+        let below_range = match (&self.range, key.get(self.prefix.len())) {
+            (Some(range), Some(Some(value))) => !range.is_above(value),
+            _ => true,
+        };
+        prefix_matches && below_range
+    }
+
+    /// Whether the key, which is within bounds, matches the range
+    fn is_in_range(&self, key: &[Option<CompositeValue>]) -> bool {
+        match (&self.range, key.get(self.prefix.len())) {
+            (Some(range), Some(Some(value))) => range.check(value),
+            (Some(_), _) => false,
+            (None, _) => true,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct CompositeIndex {
+    /// Keys of all fields of the composite key, starting with the indexed field
+    keys: Vec<JsonPath>,
+    field_types: Vec<CompositeFieldType>,
+    entries: BTreeSet<(CompositeKey, PointOffsetType)>,
+    /// Number of entries per prefix of the composite key, one map for each prefix length
+    prefix_counts: Vec<BTreeMap<CompositeKey, usize>>,
+    point_values: HashMap<PointOffsetType, PointValues>,
+    /// Points with more than [`MAX_KEYS_PER_POINT`] keys, which are not expanded into entries
+    overflow_points: BTreeSet<PointOffsetType>,
+    max_keys_per_point: usize,
+    storage: Gridstore<PointValues>,
+}
+
+impl CompositeIndex {
+    fn empty(
+        field: &JsonPath,
+        params: &CompositeIndexParams,
+        storage: Gridstore<PointValues>,
+    ) -> Self {
+        let keys: Vec<_> = std::iter::once(field.clone())
+            .chain(params.fields.iter().map(|field| field.key.clone()))
+            .collect();
+        let field_types = std::iter::once(params.field_type)
+            .chain(params.fields.iter().map(|field| field.r#type))
+            .collect();
+        Self {
+            prefix_counts: vec![BTreeMap::new(); keys.len()],
+            keys,
+            field_types,
+            entries: BTreeSet::new(),
+            point_values: HashMap::new(),
+            overflow_points: BTreeSet::new(),
+            max_keys_per_point: 0,
+            storage,
+        }
+    }
+
+    /// Create an empty index, removing any data previously stored at the path
+    pub fn create(
+        path: PathBuf,
+        field: &JsonPath,
+        params: &CompositeIndexParams,
+    ) -> OperationResult<Self> {
+        let mut storage: Gridstore<PointValues> =
+            Gridstore::open_or_create(path, StorageOptions::default()).map_err(|err| {
+                OperationError::service_error(format!(
+                    "failed to create composite index on gridstore: {err}"
+                ))
+            })?;
+        if storage.max_point_offset() > 0 {
+            storage.clear()?;
+        }
+        Ok(Self::empty(field, params, storage))
+    }
+
+    /// Open and load the index from Gridstore storage, `None` if it doesn't exist
+    pub fn open(
+        path: PathBuf,
+        field: &JsonPath,
+        params: &CompositeIndexParams,
+    ) -> OperationResult<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let storage: Gridstore<PointValues> = Gridstore::open(path).map_err(|err| {
+            OperationError::service_error(format!(
+                "failed to open composite index on gridstore: {err}"
+            ))
+        })?;
+
+        let mut stored = Vec::new();
+        let hw_counter = HardwareCounterCell::disposable();
+        storage
+            .iter::<_, GridstoreError>(
+                |id, values| {
+                    stored.push((id, values));
+                    Ok(true)
+                },
+                hw_counter.ref_payload_index_io_write_counter(),
+            )
+            // unwrap safety: never returns an error
+            .unwrap();
+
+        let mut index = Self::empty(field, params, storage);
+        for (id, values) in stored {
+            index.ingest(id, values);
+        }
+        Ok(Some(index))
+    }
+
+    /// Keys of all fields of the composite key, starting with the indexed field
+    pub fn keys(&self) -> &[JsonPath] {
+        &self.keys
+    }
+
+    /// Number of indexed points
+    pub fn points_count(&self) -> usize {
+        self.point_values.len()
+    }
+
+    pub fn add_point(
+        &mut self,
+        id: PointOffsetType,
+        payload: &impl PayloadContainer,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        self.remove_point(id)?;
+
+        let values = PointValues(
+            self.keys
+                .iter()
+                .zip(&self.field_types)
+                .map(|(key, field_type)| {
+                    payload
+                        .get_value(key)
+                        .iter()
+                        .flat_map(|value| match value {
+                            Value::Array(values) => values.iter().collect(),
+                            value => vec![*value],
+                        })
+                        .filter_map(|value| composite_value(*field_type, value))
+                        .unique()
+                        .collect()
+                })
+                .collect(),
+        );
+
+        // Points without the indexed field can't match any lookup
+        if values.0[0].is_empty() {
+            return Ok(());
+        }
+
+        self.storage
+            .put_value(id, &values, hw_counter.ref_payload_index_io_write_counter())
+            .map_err(|err| {
+                OperationError::service_error(format!(
+                    "failed to put value in composite index gridstore: {err}"
+                ))
+            })?;
+        self.ingest(id, values);
+        Ok(())
+    }
+
+    /// Add stored values of the point to the in-memory structures
+    fn ingest(&mut self, id: PointOffsetType, values: PointValues) {
+        let keys_count = values.keys_count();
+        if keys_count > MAX_KEYS_PER_POINT {
+            self.overflow_points.insert(id);
+        } else {
+            for key in values.keys() {
+                for (length, counts) in self.prefix_counts.iter_mut().enumerate() {
+                    *counts.entry(key[..=length].into()).or_default() += 1;
+                }
+                self.entries.insert((key, id));
+            }
+            self.max_keys_per_point = self.max_keys_per_point.max(keys_count);
+        }
+        self.point_values.insert(id, values);
+    }
+
+    pub fn remove_point(&mut self, id: PointOffsetType) -> OperationResult<()> {
+        let Some(values) = self.point_values.remove(&id) else {
+            return Ok(());
+        };
+
+        if !self.overflow_points.remove(&id) {
+            for key in values.keys() {
+                for (length, counts) in self.prefix_counts.iter_mut().enumerate() {
+                    let prefix = &key[..=length];
+                    if let Some(count) = counts.get_mut(prefix) {
+                        *count -= 1;
+                        if *count == 0 {
+                            counts.remove(prefix);
+                        }
+                    }
+                }
+                self.entries.remove(&(key, id));
+            }
+        }
+
+        self.storage.delete_value(id)?;
+        Ok(())
+    }
+
+    /// Plan a lookup which covers as many of the given conditions as possible.
+    ///
+    /// Returns the lookup and positions of the covered conditions,
+    /// or `None` if the condition on the indexed field can't be covered.
+    pub fn plan_lookup(
+        &self,
+        conditions: &[&FieldCondition],
+    ) -> Option<(CompositeLookup, Vec<usize>)> {
+        let mut lookup = CompositeLookup {
+            prefix: Vec::new(),
+            range: None,
+        };
+        let mut covered = Vec::new();
+
+        for (key, field_type) in self.keys.iter().zip(&self.field_types) {
+            let on_field = || {
+                conditions
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, condition)| &condition.key == key)
+            };
+
+            if let Some((position, value)) = on_field().find_map(|(position, condition)| {
+                Some((position, equality_value(*field_type, condition)?))
+            }) {
+                lookup.prefix.push(value);
+                covered.push(position);
+                continue;
+            }
+
+            if let Some((position, range)) = on_field().find_map(|(position, condition)| {
+                Some((position, range_condition(*field_type, condition)?))
+            }) {
+                lookup.range = Some(range);
+                covered.push(position);
+            }
+            break;
+        }
+
+        (!covered.is_empty()).then_some((lookup, covered))
+    }
+
+    /// Smallest key which may match the lookup
+    fn scan_start(&self, lookup: &CompositeLookup) -> CompositeKey {
+        let CompositeLookup { prefix, range } = lookup;
+        let range_field_type = self.field_types.get(prefix.len()).copied();
+
+        prefix
+            .iter()
+            .cloned()
+            .map(Some)
+            .chain(
+                range
+                    .as_ref()
+                    .zip(range_field_type)
+                    .and_then(|(range, field_type)| range.lower_bound(field_type))
+                    .map(Some),
+            )
+            .collect()
+    }
+
+    /// Points matching the lookup. A point may be returned more than once.
+    pub fn filter<'a>(
+        &'a self,
+        lookup: &'a CompositeLookup,
+    ) -> Box<dyn Iterator<Item = PointOffsetType> + 'a> {
+        let expanded = self
+            .entries
+            .range((self.scan_start(lookup), PointOffsetType::MIN)..)
+            .take_while(move |(key, _)| lookup.is_within_bounds(key))
+            .filter(move |(key, _)| lookup.is_in_range(key))
+            .map(|(_, id)| *id);
+        let overflow = self
+            .overflow_points
+            .iter()
+            .copied()
+            .filter(move |id| self.point_values[id].matches(lookup));
+        Box::new(expanded.chain(overflow))
+    }
+
+    /// Number of expanded entries matching the lookup, counted over distinct prefixes
+    fn matched_entries(&self, lookup: &CompositeLookup) -> usize {
+        let CompositeLookup { prefix, range } = lookup;
+        let length = prefix.len() + usize::from(range.is_some());
+        let Some(counts) = length
+            .checked_sub(1)
+            .and_then(|depth| self.prefix_counts.get(depth))
+        else {
+            return 0;
+        };
+
+        if range.is_none() {
+            let key: CompositeKey = prefix.iter().cloned().map(Some).collect();
+            return counts.get(&key).copied().unwrap_or(0);
+        }
+
+        counts
+            .range(self.scan_start(lookup)..)
+            .take_while(|(key, _)| lookup.is_within_bounds(key))
+            .filter(|(key, _)| lookup.is_in_range(key))
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    pub fn estimate_cardinality(&self, lookup: &CompositeLookup) -> CardinalityEstimation {
+        let matched_entries = self.matched_entries(lookup);
+        let overflow_points = self.overflow_points.len();
+        if self.max_keys_per_point <= 1 && overflow_points == 0 {
+            return CardinalityEstimation::exact(matched_entries);
+        }
+
+        let expanded_points = self.points_count() - overflow_points;
+        let max = (matched_entries.min(expanded_points) + overflow_points).min(self.points_count());
+        let min = matched_entries
+            .div_ceil(self.max_keys_per_point.max(1))
+            .min(max);
+        let points_per_entry = if self.entries.is_empty() {
+            0.0
+        } else {
+            expanded_points as f64 / self.entries.len() as f64
+        };
+        let exp = ((matched_entries as f64 * points_per_entry).round() as usize).clamp(min, max);
+        CardinalityEstimation {
+            primary_clauses: vec![],
+            min,
+            exp,
+            max,
+        }
+    }
+
+    pub fn wipe(self) -> OperationResult<()> {
+        self.storage.wipe().map_err(|err| {
+            OperationError::service_error(format!("Failed to wipe composite index: {err}"))
+        })
+    }
+
+    /// Clear gridstore disk cache. Does not affect the in-memory index.
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.storage.clear_cache().map_err(|err| {
+            OperationError::service_error(format!(
+                "Failed to clear composite index gridstore cache: {err}"
+            ))
+        })
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.storage.files()
+    }
+
+    pub fn flusher(&self) -> Flusher {
+        let storage_flusher = self.storage.flusher();
+        Box::new(move || storage_flusher().map_err(OperationError::from))
+    }
+}
+
+fn composite_value(field_type: CompositeFieldType, value: &Value) -> Option<CompositeValue> {
+    match field_type {
+        CompositeFieldType::Keyword => Some(CompositeValue::Keyword(value.as_str()?.to_owned())),
+        CompositeFieldType::Integer => value_to_integer(value).map(CompositeValue::Integer),
+        CompositeFieldType::Float => Some(CompositeValue::Float(OrderedFloat(value.as_f64()?))),
+        CompositeFieldType::Datetime => {
+            let datetime = DateTimePayloadType::from_str(value.as_str()?).ok()?;
+            Some(CompositeValue::Integer(datetime.timestamp()))
+        }
+        CompositeFieldType::Uuid => {
+            let uuid = Uuid::parse_str(value.as_str()?).ok()?;
+            Some(CompositeValue::Uuid(uuid.as_u128()))
+        }
+    }
+}
+
+/// Value of a condition which is a plain `match: value` on the field
+fn equality_value(
+    field_type: CompositeFieldType,
+    condition: &FieldCondition,
+) -> Option<CompositeValue> {
+    let FieldCondition {
+        key: _,
+        r#match: Some(Match::Value(MatchValue { value })),
+        range: None,
+        geo_bounding_box: None,
+        geo_radius: None,
+        geo_polygon: None,
+        values_count: None,
+        is_empty: None,
+        is_null: None,
+    } = condition
+    else {
+        return None;
+    };
+
+    match (field_type, value) {
+        (CompositeFieldType::Keyword, ValueVariants::String(keyword)) => {
+            Some(CompositeValue::Keyword(keyword.clone()))
+        }
+        (CompositeFieldType::Integer, ValueVariants::Integer(integer)) => {
+            Some(CompositeValue::Integer(*integer))
+        }
+        (CompositeFieldType::Uuid, ValueVariants::String(uuid)) => {
+            let uuid = Uuid::parse_str(uuid).ok()?;
+            Some(CompositeValue::Uuid(uuid.as_u128()))
+        }
+        (
+            CompositeFieldType::Keyword
+            | CompositeFieldType::Integer
+            | CompositeFieldType::Float
+            | CompositeFieldType::Datetime
+            | CompositeFieldType::Uuid,
+            _,
+        ) => None,
+    }
+}
+
+/// Range of a condition which is a plain `range` on the field
+fn range_condition(
+    field_type: CompositeFieldType,
+    condition: &FieldCondition,
+) -> Option<CompositeRange> {
+    let FieldCondition {
+        key: _,
+        r#match: None,
+        range: Some(range),
+        geo_bounding_box: None,
+        geo_radius: None,
+        geo_polygon: None,
+        values_count: None,
+        is_empty: None,
+        is_null: None,
+    } = condition
+    else {
+        return None;
+    };
+
+    match (field_type, range) {
+        (CompositeFieldType::Integer | CompositeFieldType::Float, RangeInterface::Float(range)) => {
+            Some(CompositeRange::Number(*range))
+        }
+        (CompositeFieldType::Datetime, RangeInterface::DateTime(range)) => Some(
+            CompositeRange::DateTime(range.map(|datetime| datetime.timestamp())),
+        ),
+        (
+            CompositeFieldType::Keyword
+            | CompositeFieldType::Integer
+            | CompositeFieldType::Float
+            | CompositeFieldType::Datetime
+            | CompositeFieldType::Uuid,
+            _,
+        ) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::data_types::index::{CompositeIndexField, CompositeIndexType};
+    use crate::types::Payload;
+
+    fn params() -> CompositeIndexParams {
+        CompositeIndexParams {
+            r#type: CompositeIndexType::Composite,
+            field_type: CompositeFieldType::Keyword,
+            fields: vec![
+                CompositeIndexField {
+                    key: JsonPath::new("status"),
+                    r#type: CompositeFieldType::Keyword,
+                },
+                CompositeIndexField {
+                    key: JsonPath::new("created_at"),
+                    r#type: CompositeFieldType::Datetime,
+                },
+            ],
+            on_disk: None,
+            enable_hnsw: None,
+        }
+    }
+
+    fn index(path: PathBuf) -> CompositeIndex {
+        let mut index = CompositeIndex::create(path, &JsonPath::new("tenant"), &params()).unwrap();
+        let hw_counter = HardwareCounterCell::new();
+
+        let payloads = [
+            json!({"tenant": "a", "status": "new", "created_at": "2024-01-01T00:00:00Z"}),
+            json!({"tenant": "a", "status": "new", "created_at": "2024-02-01T00:00:00Z"}),
+            json!({"tenant": "a", "status": "done", "created_at": "2024-03-01T00:00:00Z"}),
+            json!({"tenant": "b", "status": "new", "created_at": "2024-03-01T00:00:00Z"}),
+            json!({"tenant": ["a", "b"], "status": "new", "created_at": "2024-04-01T00:00:00Z"}),
+            json!({"tenant": "a", "created_at": "2024-05-01T00:00:00Z"}),
+            json!({"status": "new", "created_at": "2024-05-01T00:00:00Z"}),
+        ];
+        for (id, payload) in payloads.into_iter().enumerate() {
+            let payload: Payload = serde_json::from_value(payload).unwrap();
+            index
+                .add_point(id as PointOffsetType, &payload, &hw_counter)
+                .unwrap();
+        }
+        index
+    }
+
+    fn matching(
+        index: &CompositeIndex,
+        conditions: &[FieldCondition],
+    ) -> (Vec<usize>, Vec<PointOffsetType>) {
+        let conditions = conditions.iter().collect_vec();
+        let (lookup, covered) = index.plan_lookup(&conditions).unwrap();
+        let points = index.filter(&lookup).sorted().dedup().collect();
+        (covered, points)
+    }
+
+    fn keyword(key: &str, value: &str) -> FieldCondition {
+        FieldCondition::new_match(JsonPath::new(key), value.to_string().into())
+    }
+
+    fn after(key: &str, datetime: &str) -> FieldCondition {
+        FieldCondition::new_datetime_range(
+            JsonPath::new(key),
+            Range {
+                lt: None,
+                gt: Some(DateTimePayloadType::from_str(datetime).unwrap()),
+                gte: None,
+                lte: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_prefix_and_range_lookup() {
+        let dir = Builder::new().prefix("composite_index").tempdir().unwrap();
+        let index = index(dir.path().join("tenant"));
+        assert_eq!(index.points_count(), 6);
+
+        let (covered, points) = matching(
+            &index,
+            &[
+                after("created_at", "2024-01-15T00:00:00Z"),
+                keyword("status", "new"),
+                keyword("tenant", "a"),
+            ],
+        );
+        assert_eq!(covered, vec![2, 1, 0]);
+        assert_eq!(points, vec![1, 4]);
+
+        let (covered, points) = matching(&index, &[keyword("tenant", "a")]);
+        assert_eq!(covered, vec![0]);
+        assert_eq!(points, vec![0, 1, 2, 4, 5]);
+
+        // Range on a field after a gap in the prefix is not covered
+        let (covered, points) = matching(
+            &index,
+            &[
+                keyword("tenant", "b"),
+                after("created_at", "2024-01-15T00:00:00Z"),
+            ],
+        );
+        assert_eq!(covered, vec![0]);
+        assert_eq!(points, vec![3, 4]);
+
+        let conditions = [keyword("status", "new")];
+        assert!(
+            index
+                .plan_lookup(&conditions.iter().collect_vec())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_update_and_estimation() {
+        let dir = Builder::new().prefix("composite_index").tempdir().unwrap();
+        let mut index = index(dir.path().join("tenant"));
+        let hw_counter = HardwareCounterCell::new();
+
+        let conditions = [keyword("tenant", "a"), keyword("status", "new")];
+        let conditions = conditions.iter().collect_vec();
+        let (lookup, _) = index.plan_lookup(&conditions).unwrap();
+
+        let estimation = index.estimate_cardinality(&lookup);
+        assert!(estimation.min <= 3 && 3 <= estimation.max);
+
+        index.remove_point(4).unwrap();
+        let payload: Payload =
+            serde_json::from_value(json!({"tenant": "a", "status": "done"})).unwrap();
+        index.add_point(1, &payload, &hw_counter).unwrap();
+
+        assert_eq!(index.filter(&lookup).collect_vec(), vec![0]);
+        assert_eq!(
+            index.estimate_cardinality(&lookup),
+            CardinalityEstimation::exact(1)
+        );
+    }
+
+    #[test]
+    fn test_persistence_and_overflow() {
+        let dir = Builder::new().prefix("composite_index").tempdir().unwrap();
+        let path = dir.path().join("tenant");
+        let mut index = index(path.clone());
+        let hw_counter = HardwareCounterCell::new();
+
+        // Too many combinations of values to be expanded into entries
+        let statuses = (0..MAX_KEYS_PER_POINT)
+            .map(|i| format!("s{i}"))
+            .collect_vec();
+        let payload: Payload = serde_json::from_value(json!({
+            "tenant": ["a", "c"],
+            "status": statuses,
+            "created_at": "2024-06-01T00:00:00Z",
+        }))
+        .unwrap();
+        index.add_point(7, &payload, &hw_counter).unwrap();
+        assert_eq!(index.overflow_points.len(), 1);
+
+        let conditions = [keyword("tenant", "c"), keyword("status", "s42")];
+        let (lookup, _) = index.plan_lookup(&conditions.iter().collect_vec()).unwrap();
+        assert_eq!(index.filter(&lookup).collect_vec(), vec![7]);
+        let estimation = index.estimate_cardinality(&lookup);
+        assert!(estimation.min == 0 && estimation.max == 1);
+
+        index.flusher()().unwrap();
+        let expected = [keyword("tenant", "a")];
+        let (expected, _) = index.plan_lookup(&expected.iter().collect_vec()).unwrap();
+        let expected_points = index.filter(&expected).sorted().collect_vec();
+        let expected_estimation = index.estimate_cardinality(&expected);
+        drop(index);
+
+        let index = CompositeIndex::open(path, &JsonPath::new("tenant"), &params())
+            .unwrap()
+            .unwrap();
+        assert_eq!(index.points_count(), 7);
+        assert_eq!(index.filter(&lookup).collect_vec(), vec![7]);
+        assert_eq!(
+            index.filter(&expected).sorted().collect_vec(),
+            expected_points
+        );
+        assert_eq!(index.estimate_cardinality(&expected), expected_estimation);
+    }
+}
@@ -55,7 +55,7 @@ pub trait FieldIndexRead: PayloadFieldIndexRead {
     where
         'a: 'q;
 
-    /// Borrowed numeric view, if this index is numeric.
+    /// Borrowed ordering view, if this index is numeric or keyword.
     ///
     /// The concrete numeric-index type is opaque per implementation —
     /// this matches the shape of [`PayloadIndexRead::numeric_index_for`].
@@ -247,8 +247,8 @@ impl FieldIndexRead for FieldIndex {
             FieldIndex::IntIndex(index) => Some(NumericFieldIndex::IntIndex(index.inner())),
             FieldIndex::DatetimeIndex(index) => Some(NumericFieldIndex::IntIndex(index.inner())),
             FieldIndex::FloatIndex(index) => Some(NumericFieldIndex::FloatIndex(index.inner())),
+            FieldIndex::KeywordIndex(index) => Some(NumericFieldIndex::KeywordIndex(index)),
             FieldIndex::IntMapIndex(_)
-            | FieldIndex::KeywordIndex(_)
             | FieldIndex::GeoIndex(_)
             | FieldIndex::BoolIndex(_)
             | FieldIndex::UuidMapIndex(_)
@@ -278,11 +278,13 @@ impl<S: UniversalRead> FieldIndexRead for ReadOnlyFieldIndex<S> {
             ReadOnlyFieldIndex::FloatIndex(index) => {
                 Some(ReadOnlyNumericFieldIndex::FloatIndex(index.inner()))
             }
+            ReadOnlyFieldIndex::KeywordIndex(index) => {
+                Some(ReadOnlyNumericFieldIndex::KeywordIndex(index))
+            }
             // UUIDs aren't meaningfully order-by-able as numbers, matching
             // `FieldIndex::as_numeric`.
             ReadOnlyFieldIndex::UuidIndex(_)
             | ReadOnlyFieldIndex::IntMapIndex(_)
-            | ReadOnlyFieldIndex::KeywordIndex(_)
             | ReadOnlyFieldIndex::BoolIndex(_)
             | ReadOnlyFieldIndex::GeoIndex(_)
             | ReadOnlyFieldIndex::FullTextIndex(_)
@@ -59,7 +59,10 @@ impl IndexSelector<'_> {
         id_tracker: &IdTrackerEnum,
         deleted_points: &BitSlice,
     ) -> OperationResult<Option<FieldIndex>> {
-        let index = match (&index_type.index_type, payload_schema.expand().as_ref()) {
+        let index = match (
+            &index_type.index_type,
+            payload_schema.single_field_params().as_ref(),
+        ) {
             (PayloadIndexType::IntIndex, PayloadSchemaParams::Integer(params)) => {
                 // IntIndex only gets created if `range` is true. This will only throw an error if storage is corrupt.
                 //
@@ -148,7 +151,7 @@ impl IndexSelector<'_> {
         create_if_missing: bool,
         deleted_points: &BitSlice,
     ) -> OperationResult<Option<Vec<FieldIndex>>> {
-        let indexes = match payload_schema.expand().as_ref() {
+        let indexes = match payload_schema.single_field_params().as_ref() {
             PayloadSchemaParams::Keyword(_) => self
                 .map_new(field, create_if_missing, deleted_points)?
                 .map(|index| vec![FieldIndex::KeywordIndex(index)]),
@@ -203,6 +206,7 @@ impl IndexSelector<'_> {
             PayloadSchemaParams::Uuid(_) => self
                 .map_new(field, create_if_missing, deleted_points)?
                 .map(|index| vec![FieldIndex::UuidMapIndex(index)]),
+            PayloadSchemaParams::Composite(_) => return Err(composite_not_expanded()),
         };
 
         Ok(indexes)
@@ -215,7 +219,7 @@ impl IndexSelector<'_> {
         payload_schema: &PayloadFieldSchema,
         deleted_points: &BitSlice,
     ) -> OperationResult<Vec<FieldIndexBuilder>> {
-        let builders = match payload_schema.expand().as_ref() {
+        let builders = match payload_schema.single_field_params().as_ref() {
             PayloadSchemaParams::Keyword(_) => {
                 vec![self.map_builder(
                     field,
@@ -290,6 +294,7 @@ impl IndexSelector<'_> {
                     deleted_points,
                 )]
             }
+            PayloadSchemaParams::Composite(_) => return Err(composite_not_expanded()),
         };
 
         Ok(builders)
@@ -562,6 +567,11 @@ impl IndexSelector<'_> {
     }
 }
 
+/// Composite schema is always expanded with [`PayloadFieldSchema::single_field_params`]
+fn composite_not_expanded() -> OperationError {
+    OperationError::service_error("composite index schema is not expanded into a regular index")
+}
+
 fn map_dir(dir: &Path, field: &JsonPath) -> PathBuf {
     dir.join(format!("{}-map", &field.filename()))
 }
@@ -67,6 +67,12 @@ where
         let point_to_values = ImmutablePointToValues::new(point_to_values);
         value_to_points.shrink_to_fit();
 
+        let mut sorted_values = Vec::new();
+        if N::RANGE_LOOKUPS {
+            sorted_values.extend(value_to_points.keys().cloned());
+            sorted_values.sort_unstable();
+        }
+
         // Sort IDs in each slice of points
         // This is very important because we binary search
         for value in value_to_points.keys() {
@@ -91,6 +97,7 @@ where
 
         let mut result = Self {
             value_to_points,
+            sorted_values,
             value_to_points_container,
             deleted_value_to_points_container: BitVec::new(),
             point_to_values,
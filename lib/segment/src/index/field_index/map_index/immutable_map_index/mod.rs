@@ -14,6 +14,9 @@ mod read_ops;
 
 pub struct ImmutableMapIndex<N: MapIndexKey + Key + ?Sized> {
     pub(super) value_to_points: HashMap<<N as MapIndexKey>::Owned, ContainerSegment>,
+    /// All values of `value_to_points` in ascending order, used for range lookups.
+    /// Only kept if [`MapIndexKey::RANGE_LOOKUPS`] is supported.
+    pub(super) sorted_values: Vec<<N as MapIndexKey>::Owned>,
     /// Container holding a slice of point IDs per value. `value_to_point` holds the range per value.
     /// Each slice MUST be sorted so that we can binary search over it.
     pub(super) value_to_points_container: Vec<PointOffsetType>,
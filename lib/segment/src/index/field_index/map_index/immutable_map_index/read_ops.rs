@@ -5,11 +5,12 @@ use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use gridstore::Blob;
 
-use super::super::read_ops::MapIndexRead;
+use super::super::read_ops::{MapIndexRead, sorted_values_in_range};
 use super::super::{IdIter, MapIndexKey};
 use super::{ContainerSegment, ImmutableMapIndex, Storage};
 use crate::common::operation_error::OperationResult;
 use crate::index::payload_config::StorageType;
+use crate::types::Range;
 
 impl<N: MapIndexKey + ?Sized> MapIndexRead<N> for ImmutableMapIndex<N>
 where
@@ -74,6 +75,18 @@ where
         self.value_to_points.keys().try_for_each(|v| f(v.borrow()))
     }
 
+    fn values_in_range<'a>(
+        &'a self,
+        range: Range<&N>,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = Cow<'a, N>> + 'a>
+    where
+        N: 'a,
+    {
+        Ok(sorted_values_in_range(&self.sorted_values, range)
+            .iter()
+            .map(|value| Cow::Borrowed(value.borrow())))
+    }
+
     fn for_each_count_per_value(
         &self,
         deferred_internal_id: Option<PointOffsetType>,
@@ -155,6 +168,7 @@ where
     pub(super) fn compute_ram_usage_bytes(&self) -> usize {
         let Self {
             value_to_points,
+            sorted_values,
             value_to_points_container,
             deleted_value_to_points_container,
             point_to_values,
@@ -171,12 +185,15 @@ where
                 + hashmap_entry_overhead);
         // Account for heap-allocated key data (e.g., long strings)
         let vtp_heap_bytes: usize = value_to_points.keys().map(|k| N::owned_heap_bytes(k)).sum();
+        // Keys of `sorted_values` share heap data with the keys of `value_to_points`
+        let sorted_values_bytes = sorted_values.capacity() * size_of::<<N as MapIndexKey>::Owned>();
         let container_bytes = value_to_points_container.capacity() * size_of::<PointOffsetType>();
         let deleted_bytes = deleted_value_to_points_container
             .capacity()
             .div_ceil(u8::BITS as usize);
         vtp_base_bytes
             + vtp_heap_bytes
+            + sorted_values_bytes
             + container_bytes
             + deleted_bytes
             + point_to_values.ram_usage_bytes()
@@ -10,11 +10,15 @@ use super::BLOCK_SIZE_KEYWORD;
 use crate::index::field_index::stored_point_to_values::StoredValue;
 use crate::types::{IntPayloadType, UuidIntType};
 
-pub trait MapIndexKey: Key + StoredValue + Eq + Display + Debug {
-    type Owned: Borrow<Self> + Hash + Eq + Clone + FromStr + Default + 'static;
+pub trait MapIndexKey: Key + StoredValue + Eq + Ord + Display + Debug {
+    type Owned: Borrow<Self> + Hash + Eq + Ord + Clone + FromStr + Default + 'static;
 
     fn to_owned(&self) -> <Self as MapIndexKey>::Owned;
 
+    /// Whether values are kept sorted for range lookups.
+    /// Only keywords support ranges, numbers are served by numeric indexes.
+    const RANGE_LOOKUPS: bool = false;
+
     fn gridstore_block_size() -> usize {
         size_of::<<Self as MapIndexKey>::Owned>()
     }
@@ -33,6 +37,8 @@ impl MapIndexKey for str {
         EcoString::from(self)
     }
 
+    const RANGE_LOOKUPS: bool = true;
+
     fn gridstore_block_size() -> usize {
         BLOCK_SIZE_KEYWORD
     }
@@ -1,6 +1,8 @@
 use std::borrow::{Borrow, Cow};
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap};
 use std::iter;
+use std::ops::Bound;
 
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
@@ -11,6 +13,7 @@ use super::super::read_ops::MapIndexRead;
 use super::super::{IdIter, MapIndexKey};
 use crate::common::operation_error::OperationResult;
 use crate::index::payload_config::StorageType;
+use crate::types::Range;
 
 /// In-memory state shared by `MutableMapIndex` and `ReadOnlyAppendableMapIndex`.
 ///
@@ -23,6 +26,10 @@ where
 {
     pub(in crate::index::field_index::map_index) map:
         HashMap<<N as MapIndexKey>::Owned, RoaringBitmap>,
+    /// Keys of `map` in ascending order, for range lookups.
+    /// Shares heap data with the keys of `map`.
+    /// Only kept if [`MapIndexKey::RANGE_LOOKUPS`] is supported.
+    pub(in crate::index::field_index::map_index) sorted_values: BTreeSet<<N as MapIndexKey>::Owned>,
     pub(in crate::index::field_index::map_index) point_to_values:
         Vec<Vec<<N as MapIndexKey>::Owned>>,
     /// Amount of point which have at least one indexed payload value
@@ -37,6 +44,7 @@ where
     pub(in crate::index::field_index::map_index) fn empty() -> Self {
         Self {
             map: HashMap::new(),
+            sorted_values: BTreeSet::new(),
             point_to_values: Vec::new(),
             indexed_points: 0,
             values_count: 0,
@@ -57,15 +65,59 @@ where
         if self.point_to_values.len() <= idx as usize {
             self.point_to_values.resize_with(idx as usize + 1, Vec::new);
         }
-        let point_values = &mut self.point_to_values[idx as usize];
-
-        if point_values.is_empty() {
+        if self.point_to_values[idx as usize].is_empty() {
             self.indexed_points += 1;
         }
         self.values_count += 1;
 
-        point_values.push(value.clone());
-        self.map.entry(value).or_default().insert(idx);
+        let value = self.insert_value(idx, value);
+        self.point_to_values[idx as usize].push(value);
+    }
+
+    /// Add the point to the points of the value, keeping the values sorted.
+    ///
+    /// Returns the value as stored in the index, to be shared with `point_to_values`.
+    pub(in crate::index::field_index::map_index) fn insert_value(
+        &mut self,
+        idx: PointOffsetType,
+        value: <N as MapIndexKey>::Owned,
+    ) -> <N as MapIndexKey>::Owned {
+        match self.map.entry(value) {
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().insert(idx);
+                entry.key().clone()
+            }
+            Entry::Vacant(entry) => {
+                let value = entry.key().clone();
+                if N::RANGE_LOOKUPS {
+                    self.sorted_values.insert(value.clone());
+                }
+                entry.insert(RoaringBitmap::from_iter([idx]));
+                value
+            }
+        }
+    }
+
+    /// Bound to seek the first value of the range.
+    /// Of two lower bounds the stricter one is checked for every value.
+    fn lower_bound(range: Range<&N>) -> Bound<&N> {
+        match (range.gt, range.gte) {
+            (Some(gt), Some(gte)) if gte > gt => Bound::Included(gte),
+            (Some(gt), _) => Bound::Excluded(gt),
+            (None, Some(gte)) => Bound::Included(gte),
+            (None, None) => Bound::Unbounded,
+        }
+    }
+
+    /// Bound to seek the last value of the range.
+    /// Of two upper bounds the stricter one is checked for every value.
+    fn upper_bound(range: Range<&N>) -> Bound<&N> {
+        match (range.lt, range.lte) {
+            (Some(lt), Some(lte)) if lte < lt => Bound::Included(lte),
+            (Some(lt), _) => Bound::Excluded(lt),
+            (None, Some(lte)) => Bound::Included(lte),
+            (None, None) => Bound::Unbounded,
+        }
     }
 
     pub(in crate::index::field_index::map_index) fn for_points_values(
@@ -144,6 +196,35 @@ where
         self.map.keys().try_for_each(|v| f(v.borrow()))
     }
 
+    fn values_in_range<'a>(
+        &'a self,
+        range: Range<&N>,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = Cow<'a, N>> + 'a>
+    where
+        N: 'a,
+    {
+        // Find the first and the last value of the range, and iterate values between them
+        let first = self
+            .sorted_values
+            .range::<N, _>((Self::lower_bound(range), Bound::Unbounded))
+            .find(|value| !range.is_below((*value).borrow()));
+        let last = self
+            .sorted_values
+            .range::<N, _>((Bound::Unbounded, Self::upper_bound(range)))
+            .rfind(|value| !range.is_above((*value).borrow()));
+        let values = match (first, last) {
+            (Some(first), Some(last)) if first <= last => Some(
+                self.sorted_values
+                    .range::<<N as MapIndexKey>::Owned, _>(first..=last),
+            ),
+            _ => None,
+        };
+        Ok(values
+            .into_iter()
+            .flatten()
+            .map(|value| Cow::Borrowed(value.borrow())))
+    }
+
     fn for_each_count_per_value(
         &self,
         deferred_internal_id: Option<PointOffsetType>,
@@ -187,6 +268,7 @@ where
     fn ram_usage_bytes(&self) -> usize {
         let Self {
             map,
+            sorted_values,
             point_to_values,
             indexed_points: _,
             values_count: _,
@@ -201,12 +283,15 @@ where
         let map_key_heap_bytes: usize = map.keys().map(|k| N::owned_heap_bytes(k)).sum();
         let map_bitmap_bytes: usize = map.values().map(|bitmap| bitmap.serialized_size()).sum();
         let map_bytes = map_base_bytes + map_key_heap_bytes + map_bitmap_bytes;
+        // Keys of `sorted_values` share heap data with the keys of `map`
+        let sorted_values_bytes = sorted_values.len()
+            * (std::mem::size_of::<<N as MapIndexKey>::Owned>() + std::mem::size_of::<usize>());
         let ptv_bytes: usize = point_to_values.capacity()
             * std::mem::size_of::<Vec<<N as MapIndexKey>::Owned>>()
             + point_to_values
                 .iter()
                 .map(|v| v.capacity() * std::mem::size_of::<<N as MapIndexKey>::Owned>())
                 .sum::<usize>();
-        map_bytes + ptv_bytes
+        map_bytes + sorted_values_bytes + ptv_bytes
     }
 }
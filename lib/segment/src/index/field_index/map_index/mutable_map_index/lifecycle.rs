@@ -101,9 +101,8 @@ where
         let hw_counter_ref = hw_counter.ref_payload_index_io_write_counter();
 
         for value in values.clone() {
-            let entry = self.inner.map.entry(value.into());
-            self.inner.point_to_values[idx as usize].push(entry.key().clone());
-            entry.or_default().insert(idx);
+            let value = self.inner.insert_value(idx, value.into());
+            self.inner.point_to_values[idx as usize].push(value);
         }
 
         let values = values.into_iter().map(Into::into).collect::<Vec<_>>();
@@ -10,6 +10,7 @@ use super::super::super::{IdIter, MapIndexKey};
 use super::ReadOnlyAppendableMapIndex;
 use crate::common::operation_error::OperationResult;
 use crate::index::payload_config::StorageType;
+use crate::types::Range;
 
 impl<N: MapIndexKey + ?Sized, S: UniversalRead> MapIndexRead<N> for ReadOnlyAppendableMapIndex<N, S>
 where
@@ -63,6 +64,16 @@ where
         self.inner.for_each_value(f)
     }
 
+    fn values_in_range<'a>(
+        &'a self,
+        range: Range<&N>,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = Cow<'a, N>> + 'a>
+    where
+        N: 'a,
+    {
+        self.inner.values_in_range(range)
+    }
+
     fn for_each_count_per_value(
         &self,
         deferred_internal_id: Option<PointOffsetType>,
@@ -9,6 +9,7 @@ use super::super::{IdIter, MapIndexKey};
 use super::MutableMapIndex;
 use crate::common::operation_error::OperationResult;
 use crate::index::payload_config::StorageType;
+use crate::types::Range;
 
 impl<N: MapIndexKey + ?Sized> MapIndexRead<N> for MutableMapIndex<N>
 where
@@ -62,6 +63,16 @@ where
         self.inner.for_each_value(f)
     }
 
+    fn values_in_range<'a>(
+        &'a self,
+        range: Range<&N>,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = Cow<'a, N>> + 'a>
+    where
+        N: 'a,
+    {
+        self.inner.values_in_range(range)
+    }
+
     fn for_each_count_per_value(
         &self,
         deferred_internal_id: Option<PointOffsetType>,
@@ -22,8 +22,8 @@ use crate::index::query_estimator::combine_should_estimations;
 use crate::index::query_optimization::optimized_filter::ConditionCheckerFn;
 use crate::payload_storage::condition_checker::INDEXSET_ITER_THRESHOLD;
 use crate::types::{
-    AnyVariants, FieldCondition, Match, MatchAny, MatchExcept, MatchValue, PayloadKeyType,
-    ValueVariants,
+    AnyVariants, FieldCondition, KeywordRange, Match, MatchAny, MatchExcept, MatchValue,
+    PayloadKeyType, RangeInterface, ValueVariants,
 };
 
 impl PayloadFieldIndex for MapIndex<str> {
@@ -62,7 +62,7 @@ impl PayloadFieldIndexRead for MapIndex<str> {
         condition: &FieldCondition,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<CardinalityEstimation>> {
-        Ok(estimate_cardinality_impl(self, condition, hw_counter))
+        estimate_cardinality_impl(self, condition, hw_counter)
     }
 
     fn for_each_payload_block(
@@ -104,7 +104,7 @@ where
         condition: &FieldCondition,
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Option<CardinalityEstimation>> {
-        Ok(estimate_cardinality_impl(self, condition, hw_counter))
+        estimate_cardinality_impl(self, condition, hw_counter)
     }
 
     fn for_each_payload_block(
@@ -134,6 +134,16 @@ fn filter_impl<'a, T: MapIndexRead<str>>(
     condition: &'a FieldCondition,
     hw_counter: &'a HardwareCounterCell,
 ) -> OperationResult<Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>>> {
+    if let Some(RangeInterface::Keyword(KeywordRange { keyword })) = &condition.range {
+        let mut points: Vec<_> = index
+            .values_in_range(keyword.as_deref())?
+            .flat_map(|value| index.get_iterator(&value, hw_counter))
+            .collect();
+        points.sort_unstable();
+        points.dedup();
+        return Ok(Some(Box::new(points.into_iter())));
+    }
+
     let result: Option<Box<dyn Iterator<Item = PointOffsetType> + 'a>> = match &condition.r#match {
         Some(Match::Value(MatchValue { value })) => match value {
             ValueVariants::String(keyword) => {
@@ -171,8 +181,23 @@ fn estimate_cardinality_impl<T: MapIndexRead<str>>(
     index: &T,
     condition: &FieldCondition,
     hw_counter: &HardwareCounterCell,
-) -> Option<CardinalityEstimation> {
-    match &condition.r#match {
+) -> OperationResult<Option<CardinalityEstimation>> {
+    if let Some(RangeInterface::Keyword(KeywordRange { keyword })) = &condition.range {
+        let estimations: Vec<_> = index
+            .values_in_range(keyword.as_deref())?
+            .map(|value| index.match_cardinality(&value, hw_counter))
+            .collect();
+        let estimation = if estimations.is_empty() {
+            CardinalityEstimation::exact(0)
+        } else {
+            combine_should_estimations(&estimations, index.get_indexed_points())
+        };
+        return Ok(Some(estimation.with_primary_clause(
+            PrimaryCondition::Condition(Box::new(condition.clone())),
+        )));
+    }
+
+    let estimation = match &condition.r#match {
         Some(Match::Value(MatchValue { value })) => match value {
             ValueVariants::String(keyword) => {
                 let mut estimation = index.match_cardinality(keyword.as_str(), hw_counter);
@@ -218,7 +243,9 @@ fn estimate_cardinality_impl<T: MapIndexRead<str>>(
             AnyVariants::Integers(_) => None,
         },
         _ => None,
-    }
+    };
+
+    Ok(estimation)
 }
 
 fn for_each_payload_block_impl<T: MapIndexRead<str>>(
@@ -253,7 +280,7 @@ fn condition_checker_impl<'a, T: MapIndexRead<str> + 'a>(
     let FieldCondition {
         key: _,
         r#match,
-        range: _,
+        range,
         geo_radius: _,
         geo_bounding_box: _,
         geo_polygon: _,
@@ -262,8 +289,18 @@ fn condition_checker_impl<'a, T: MapIndexRead<str> + 'a>(
         is_null: _,
     } = condition;
 
-    let cond_match = r#match.as_ref()?;
     let hw_counter = hw_acc.get_counter_cell();
+
+    if let Some(RangeInterface::Keyword(KeywordRange { keyword })) = range {
+        let keyword = keyword.clone();
+        return Some(Box::new(move |point_id: PointOffsetType| {
+            index.check_values_any(point_id, &hw_counter, |value| {
+                keyword.as_deref().check_range(value)
+            })
+        }));
+    }
+
+    let cond_match = r#match.as_ref()?;
     match cond_match {
         Match::Value(MatchValue {
             value: ValueVariants::String(keyword),
@@ -11,6 +11,7 @@ use super::super::{IdIter, MapIndexKey};
 use super::ReadOnlyMapIndex;
 use crate::common::operation_error::OperationResult;
 use crate::index::payload_config::StorageType;
+use crate::types::Range;
 
 /// Dispatcher impl: forwards every [`MapIndexRead`] method to the active
 /// variant. Helper methods (`match_cardinality`, `except_cardinality`,
@@ -99,6 +100,20 @@ where
         }
     }
 
+    fn values_in_range<'a>(
+        &'a self,
+        range: Range<&N>,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = Cow<'a, N>> + 'a>
+    where
+        N: 'a,
+    {
+        let boxed: Box<dyn DoubleEndedIterator<Item = Cow<'a, N>> + 'a> = match self {
+            ReadOnlyMapIndex::Appendable(index) => Box::new(index.values_in_range(range)?),
+            ReadOnlyMapIndex::Immutable(index) => Box::new(index.values_in_range(range)?),
+        };
+        Ok(boxed)
+    }
+
     fn for_each_count_per_value(
         &self,
         deferred_internal_id: Option<PointOffsetType>,
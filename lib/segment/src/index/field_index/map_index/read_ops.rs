@@ -13,6 +13,7 @@ use crate::index::field_index::CardinalityEstimation;
 use crate::index::field_index::stat_tools::number_of_selected_points;
 use crate::index::payload_config::{IndexMutability, StorageType};
 use crate::telemetry::PayloadIndexTelemetry;
+use crate::types::Range;
 
 /// Read-only operations supported by every map-index storage variant
 /// ([`super::mutable_map_index::MutableMapIndex`],
@@ -53,6 +54,17 @@ pub trait MapIndexRead<N: MapIndexKey + ?Sized> {
 
     fn for_each_value(&self, f: impl FnMut(&N) -> OperationResult<()>) -> OperationResult<()>;
 
+    /// Values which lie in the given range, in ascending order.
+    ///
+    /// Values are kept sorted, so only the values of the range are visited.
+    /// Only keys with [`MapIndexKey::RANGE_LOOKUPS`] keep sorted values, others return no values.
+    fn values_in_range<'a>(
+        &'a self,
+        range: Range<&N>,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = Cow<'a, N>> + 'a>
+    where
+        N: 'a;
+
     /// Iterate `(value, count)` pairs.
     ///
     /// `deferred_internal_id` (mutable / mmap only) restricts the count to
@@ -285,6 +297,21 @@ where
         }
     }
 
+    fn values_in_range<'a>(
+        &'a self,
+        range: Range<&N>,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = Cow<'a, N>> + 'a>
+    where
+        N: 'a,
+    {
+        let boxed: Box<dyn DoubleEndedIterator<Item = Cow<'a, N>> + 'a> = match self {
+            MapIndex::Mutable(index) => Box::new(index.values_in_range(range)?),
+            MapIndex::Immutable(index) => Box::new(index.values_in_range(range)?),
+            MapIndex::Mmap(index) => Box::new(index.values_in_range(range)?),
+        };
+        Ok(boxed)
+    }
+
     fn for_each_count_per_value(
         &self,
         deferred_internal_id: Option<PointOffsetType>,
@@ -413,3 +440,13 @@ where
         }
     }
 }
+
+/// Slice of sorted `values` which lies in the given range.
+pub(super) fn sorted_values_in_range<'a, N: MapIndexKey + ?Sized>(
+    values: &'a [<N as MapIndexKey>::Owned],
+    range: Range<&N>,
+) -> &'a [<N as MapIndexKey>::Owned] {
+    let start = values.partition_point(|value| range.is_below(value.borrow()));
+    let end = values.partition_point(|value| !range.is_above(value.borrow()));
+    &values[start..end.max(start)]
+}
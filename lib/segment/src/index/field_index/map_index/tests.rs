@@ -4,6 +4,7 @@ use std::hint::black_box;
 use std::path::Path;
 
 use common::bitvec::BitVec;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
 use ecow::EcoString;
@@ -19,7 +20,8 @@ use crate::index::field_index::{
     CardinalityEstimation, FieldIndexBuilderTrait, PayloadFieldIndex, PayloadFieldIndexRead,
     ValueIndexer,
 };
-use crate::types::{IntPayloadType, PayloadKeyType, UuidIntType};
+use crate::json_path::JsonPath;
+use crate::types::{FieldCondition, IntPayloadType, PayloadKeyType, Range, UuidIntType};
 
 /// Generous default size for the deleted-points bitslice used in tests.
 ///
@@ -251,6 +253,70 @@ fn test_string_disk_map_index(#[case] index_type: IndexType) {
     );
 }
 
+#[rstest]
+#[case(IndexType::MutableGridstore)]
+#[case(IndexType::Mmap)]
+#[case(IndexType::RamMmap)]
+fn test_keyword_range_map_index(#[case] index_type: IndexType) {
+    let data = vec![
+        vec![EcoString::from("v1.10"), EcoString::from("v2.0")],
+        vec![EcoString::from("v1.2")],
+        vec![EcoString::from("v1.9"), EcoString::from("v1.10")],
+        vec![EcoString::from("v0.9")],
+        vec![],
+    ];
+
+    let temp_dir = Builder::new().prefix("store_dir").tempdir().unwrap();
+    save_map_index::<str>(&data, temp_dir.path(), index_type, |v| v.to_string().into());
+    let index = load_map_index::<str>(&data, temp_dir.path(), index_type);
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let range = Range {
+        gte: Some("v1".to_string()),
+        lt: Some("v1.9".to_string()),
+        ..Default::default()
+    };
+
+    // Byte-wise ordering: "v1.10" < "v1.2" < "v1.9"
+    let values: Vec<_> = index.values_in_range(range.as_deref()).unwrap().collect();
+    assert_eq!(values, ["v1.10", "v1.2"]);
+
+    // The stricter of the lower bounds applies
+    let lower_range = Range {
+        gt: Some("v1.10".to_string()),
+        gte: Some("v1".to_string()),
+        ..Default::default()
+    };
+    let values: Vec<_> = index
+        .values_in_range(lower_range.as_deref())
+        .unwrap()
+        .collect();
+    assert_eq!(values, ["v1.2", "v1.9", "v2.0"]);
+
+    let condition = FieldCondition::new_keyword_range(JsonPath::new("version"), range);
+    let points: Vec<_> = index
+        .filter(&condition, &hw_counter)
+        .unwrap()
+        .unwrap()
+        .collect();
+    assert_eq!(points, [0, 1, 2]);
+
+    let estimation = index
+        .estimate_cardinality(&condition, &hw_counter)
+        .unwrap()
+        .unwrap();
+    assert!(estimation.min <= 3 && 3 <= estimation.max);
+
+    let checker = index
+        .condition_checker(&condition, HwMeasurementAcc::new())
+        .unwrap();
+    let checked: Vec<_> = (0..data.len() as PointOffsetType)
+        .filter(|&point_id| checker(point_id))
+        .collect();
+    assert_eq!(checked, points);
+}
+
 #[rstest]
 #[case(IndexType::MutableGridstore)]
 #[case(IndexType::Mmap)]
@@ -1,4 +1,5 @@
 use std::borrow::Borrow;
+use std::iter;
 use std::ops::BitOrAssign;
 use std::path::{Path, PathBuf};
 
@@ -14,7 +15,8 @@ use fs_err as fs;
 
 use super::super::MapIndexKey;
 use super::{
-    CONFIG_PATH, DELETED_PATH, HASHMAP_PATH, Storage, UniversalMapIndex, UniversalMapIndexConfig,
+    CONFIG_PATH, DELETED_PATH, HASHMAP_PATH, SORTED_VALUES_PATH, Storage, UniversalMapIndex,
+    UniversalMapIndexConfig,
 };
 use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult};
@@ -52,6 +54,26 @@ impl<N: MapIndexKey + Key + ?Sized> UniversalMapIndex<N> {
         )?;
         let point_to_values = StoredPointToValues::open(path, do_populate)?;
 
+        let sorted_values = if N::RANGE_LOOKUPS {
+            let sorted_values_path = path.join(SORTED_VALUES_PATH);
+            // Indexes built before sorted values were stored get them on the first open
+            if !sorted_values_path.is_file() {
+                let mut values = Vec::with_capacity(value_to_points.keys_count());
+                value_to_points.for_each_key(|value| {
+                    values.push(MapIndexKey::to_owned(value));
+                    Ok::<_, OperationError>(())
+                })?;
+                values.sort_unstable();
+                Self::store_sorted_values(&sorted_values_path, values.iter().map(Borrow::borrow))?;
+            }
+            Some(StoredPointToValues::open_file(
+                &sorted_values_path,
+                do_populate,
+            )?)
+        } else {
+            None
+        };
+
         let mut deleted = deleted_points.to_owned();
 
         let deleted_payload_mmap = MmapBitSlice::open(
@@ -81,6 +103,7 @@ impl<N: MapIndexKey + Key + ?Sized> UniversalMapIndex<N> {
             storage: Storage {
                 value_to_points,
                 point_to_values,
+                sorted_values,
                 deleted,
             },
             deleted_count,
@@ -126,6 +149,18 @@ impl<N: MapIndexKey + Key + ?Sized> UniversalMapIndex<N> {
             }),
         )?;
 
+        if N::RANGE_LOOKUPS {
+            let mut sorted_values: Vec<&N> = values_to_points
+                .keys()
+                .map(|value| value.borrow())
+                .collect();
+            sorted_values.sort_unstable();
+            Self::store_sorted_values(
+                &path.join(SORTED_VALUES_PATH),
+                sorted_values.iter().copied(),
+            )?;
+        }
+
         {
             let deleted_flags_count = point_to_values.len();
             let _ = create_and_ensure_length(
@@ -160,6 +195,23 @@ impl<N: MapIndexKey + Key + ?Sized> UniversalMapIndex<N> {
         })
     }
 
+    /// Store values in ascending order, one value per entry, so that ranges can be binary searched
+    fn store_sorted_values<'a>(
+        file_name: &Path,
+        sorted_values: impl Iterator<Item = &'a N> + Clone,
+    ) -> OperationResult<()>
+    where
+        N: 'a,
+    {
+        StoredPointToValues::<N, MmapFile>::from_iter_in_file(
+            file_name,
+            sorted_values
+                .enumerate()
+                .map(|(position, value)| (position as PointOffsetType, iter::once(value))),
+        )?;
+        Ok(())
+    }
+
     /// No-op flusher: the on-disk state is build-time only. See the type-level
     /// docs on [`UniversalMapIndex`] for the deletion durability contract.
     pub fn flusher(&self) -> Flusher {
@@ -185,6 +237,9 @@ impl<N: MapIndexKey + Key + ?Sized> UniversalMapIndex<N> {
             self.path.join(CONFIG_PATH),
         ];
         files.extend(self.storage.point_to_values.files());
+        if let Some(sorted_values) = &self.storage.sorted_values {
+            files.extend(sorted_values.files());
+        }
         files
     }
 
@@ -195,6 +250,9 @@ impl<N: MapIndexKey + Key + ?Sized> UniversalMapIndex<N> {
             self.path.join(CONFIG_PATH),
         ];
         files.extend(self.storage.point_to_values.immutable_files());
+        if let Some(sorted_values) = &self.storage.sorted_values {
+            files.extend(sorted_values.immutable_files());
+        }
         files
     }
 
@@ -215,6 +273,9 @@ impl<N: MapIndexKey + Key + ?Sized> UniversalMapIndex<N> {
     pub fn populate(&self) -> OperationResult<()> {
         self.storage.value_to_points.populate()?;
         self.storage.point_to_values.populate()?;
+        if let Some(sorted_values) = &self.storage.sorted_values {
+            sorted_values.populate()?;
+        }
         Ok(())
     }
 
@@ -230,11 +291,15 @@ impl<N: MapIndexKey + Key + ?Sized> UniversalMapIndex<N> {
         let Storage {
             value_to_points,
             point_to_values,
+            sorted_values,
             deleted: _,
         } = storage;
         value_to_points.clear_ram_cache()?;
         clear_disk_cache(&path.join(DELETED_PATH))?;
         point_to_values.clear_cache()?;
+        if let Some(sorted_values) = sorted_values {
+            sorted_values.clear_cache()?;
+        }
         Ok(())
     }
 
@@ -15,6 +15,7 @@ mod read_ops;
 pub(super) const DELETED_PATH: &str = "deleted.bin";
 pub(super) const HASHMAP_PATH: &str = "values_to_points.bin";
 pub(super) const CONFIG_PATH: &str = "mmap_field_index_config.json";
+pub(super) const SORTED_VALUES_PATH: &str = "sorted_values.bin";
 
 /// Immutable map index served directly from a [`UniversalRead`] storage backend.
 ///
@@ -22,7 +23,7 @@ pub(super) const CONFIG_PATH: &str = "mmap_field_index_config.json";
 /// implementation works — e.g. io_uring or disk-cache wrappers.
 ///
 /// On-disk state (`values_to_points.bin`, `deleted.bin`, `point_to_values.*`,
-/// `mmap_field_index_config.json`) is written once during [`Self::build`] and
+/// `sorted_values.bin`, `mmap_field_index_config.json`) is written once during [`Self::build`] and
 /// not mutated afterwards: `deleted.bin` records only the points whose payload
 /// was empty at build time.
 ///
@@ -42,6 +43,9 @@ pub struct UniversalMapIndex<N: MapIndexKey + Key + ?Sized, S: UniversalRead = M
 pub(super) struct Storage<N: MapIndexKey + Key + ?Sized, S: UniversalRead = MmapFile> {
     pub(super) value_to_points: UniversalHashMap<N, PointOffsetType, S>,
     pub(super) point_to_values: StoredPointToValues<N, S>,
+    /// All values in ascending order, one value per entry, used for range lookups.
+    /// Only stored if [`MapIndexKey::RANGE_LOOKUPS`] is supported.
+    pub(super) sorted_values: Option<StoredPointToValues<N, S>>,
     /// In-memory deletion bitmap. Reconstructed at load time as the union of
     /// the build-time empty-payload bits read from `deleted.bin` and the
     /// segment-level deleted bitslice supplied by the id-tracker. Not persisted.
@@ -53,10 +57,11 @@ impl<N: MapIndexKey + Key + ?Sized, S: UniversalRead> Storage<N, S> {
         let Self {
             value_to_points: _,
             point_to_values,
+            sorted_values: _,
             deleted,
         } = self;
 
-        // `value_to_points` is a storage-backed hashmap with no in-memory state.
+        // `value_to_points` and `sorted_values` are storage-backed with no in-memory state.
         point_to_values.ram_usage_bytes() + deleted.capacity().div_ceil(u8::BITS as usize)
     }
 }
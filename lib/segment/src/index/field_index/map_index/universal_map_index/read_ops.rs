@@ -8,14 +8,15 @@ use common::counter::iterator_hw_measurement::HwMeasurementIteratorExt;
 use common::persisted_hashmap::{Key, READ_ENTRY_OVERHEAD};
 use common::types::PointOffsetType;
 use common::universal_io::UniversalRead;
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 
 use super::super::read_ops::MapIndexRead;
 use super::super::{IdIter, MapIndexKey};
 use super::UniversalMapIndex;
-use crate::common::operation_error::OperationResult;
-use crate::index::field_index::stored_point_to_values::ValuesIter;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::index::field_index::stored_point_to_values::{StoredPointToValues, ValuesIter};
 use crate::index::payload_config::StorageType;
+use crate::types::Range;
 
 impl<N: MapIndexKey + Key + ?Sized, S: UniversalRead> MapIndexRead<N> for UniversalMapIndex<N, S> {
     fn check_values_any(
@@ -162,6 +163,37 @@ impl<N: MapIndexKey + Key + ?Sized, S: UniversalRead> MapIndexRead<N> for Univer
         self.storage.value_to_points.for_each_key(f)
     }
 
+    fn values_in_range<'a>(
+        &'a self,
+        range: Range<&N>,
+    ) -> OperationResult<impl DoubleEndedIterator<Item = Cow<'a, N>> + 'a>
+    where
+        N: 'a,
+    {
+        let Some(sorted_values) = &self.storage.sorted_values else {
+            return Ok(Either::Left(iter::empty()));
+        };
+
+        // Binary search the bounds, values of the range are read lazily
+        let start = Self::partition_sorted_values(sorted_values, |value| range.is_below(value))?;
+        let end = Self::partition_sorted_values(sorted_values, |value| !range.is_above(value))?;
+
+        let values = (start..end.max(start)).filter_map(move |position| {
+            match Self::sorted_value(sorted_values, position) {
+                Ok(value) => Some(value),
+                Err(err) => {
+                    debug_assert!(
+                        false,
+                        "Error while reading sorted value {position}: {err:?}"
+                    );
+                    log::error!("Error while reading sorted value {position}: {err:?}");
+                    None
+                }
+            }
+        });
+        Ok(Either::Right(values))
+    }
+
     fn for_each_count_per_value(
         &self,
         deferred_internal_id: Option<PointOffsetType>,
@@ -245,6 +277,38 @@ impl<N: MapIndexKey + Key + ?Sized, S: UniversalRead> UniversalMapIndex<N, S> {
         })
     }
 
+    /// Value at the given position of the sorted values
+    fn sorted_value(
+        sorted_values: &StoredPointToValues<N, S>,
+        position: usize,
+    ) -> OperationResult<Cow<'_, N>> {
+        sorted_values
+            .values_iter(position as PointOffsetType, ConditionedCounter::never())?
+            .and_then(|mut values| values.next())
+            .ok_or_else(|| {
+                OperationError::service_error(format!("Sorted value {position} is missing"))
+            })
+    }
+
+    /// Number of leading sorted values which satisfy the predicate,
+    /// the predicate must hold for all values before any value it doesn't hold for
+    fn partition_sorted_values(
+        sorted_values: &StoredPointToValues<N, S>,
+        predicate: impl Fn(&N) -> bool,
+    ) -> OperationResult<usize> {
+        let mut low = 0;
+        let mut high = sorted_values.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if predicate(&*Self::sorted_value(sorted_values, mid)?) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+
     pub(super) fn make_conditioned_counter<'a>(
         &self,
         hw_counter: &'a HardwareCounterCell,
@@ -3,9 +3,11 @@ use std::ops::Deref;
 use ahash::AHashSet;
 use common::types::PointOffsetType;
 
-use crate::types::{Condition, FieldCondition, PointIdType, VectorNameBuf};
+use crate::index::field_index::composite_index::CompositeLookup;
+use crate::types::{Condition, FieldCondition, PayloadKeyType, PointIdType, VectorNameBuf};
 
 pub mod bool_index;
+pub mod composite_index;
 pub(super) mod facet_index;
 mod field_index_base;
 pub mod full_text_index;
@@ -39,11 +41,22 @@ pub struct ResolvedHasId {
     pub resolved_point_offsets: Vec<PointOffsetType>,
 }
 
+/// Several field conditions, answered by a single lookup in a composite index
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeCondition {
+    /// Indexed field of the composite index
+    pub field: PayloadKeyType,
+    /// Conditions covered by the lookup
+    pub conditions: Vec<FieldCondition>,
+    pub lookup: CompositeLookup,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PrimaryCondition {
     Condition(Box<FieldCondition>),
     Ids(ResolvedHasId),
     HasVector(VectorNameBuf),
+    Composite(Box<CompositeCondition>),
 }
 
 impl From<FieldCondition> for PrimaryCondition {
@@ -140,6 +153,18 @@ impl CardinalityEstimation {
                     | Condition::Filter(_)
                     | Condition::CustomIdChecker(_) => false,
                 },
+                PrimaryCondition::Composite(composite) => match condition {
+                    Condition::Field(field_condition) => {
+                        composite.conditions.contains(field_condition)
+                    }
+                    Condition::IsEmpty(_)
+                    | Condition::IsNull(_)
+                    | Condition::HasId(_)
+                    | Condition::HasVector(_)
+                    | Condition::Nested(_)
+                    | Condition::Filter(_)
+                    | Condition::CustomIdChecker(_) => false,
+                },
             })
     }
 }
@@ -1,22 +1,29 @@
+use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::PointOffsetType;
+use common::universal_io::UniversalRead;
 use itertools::Either;
 
 use super::numeric_index_read::NumericIndexRead;
 use super::{NumericIndexInner, ReadOnlyNumericIndexInner, StreamRange};
 use crate::common::operation_error::OperationResult;
 use crate::data_types::order_by::OrderValue;
-use crate::types::{FloatPayloadType, IntPayloadType, RangeInterface};
+use crate::index::field_index::map_index::MapIndex;
+use crate::index::field_index::map_index::read_only::ReadOnlyMapIndex;
+use crate::index::field_index::map_index::read_ops::MapIndexRead;
+use crate::types::{FloatPayloadType, IntPayloadType, KeywordRange, Range, RangeInterface};
 
-/// Type-erased Int/Float numeric index view for order-by reads.
+/// Type-erased Int/Float/Keyword index view for order-by reads.
 ///
-/// Generic over the two concrete storage backends — `I` serves the
-/// `i64`-typed index, `F` the `f64`-typed one — so the writable and
-/// read-only index hierarchies share one set of impls. Use the
+/// Generic over the three concrete storage backends — `I` serves the
+/// `i64`-typed index, `F` the `f64`-typed one, `K` the keyword one — so the
+/// writable and read-only index hierarchies share one set of impls. Use the
 /// [`NumericFieldIndex`] / [`ReadOnlyNumericFieldIndex`] aliases rather
 /// than spelling out the backend types.
-pub enum NumericFieldIndexView<'a, I, F> {
+pub enum NumericFieldIndexView<'a, I, F, K> {
     IntIndex(&'a I),
     FloatIndex(&'a F),
+    /// Ordered byte-wise by the sorted keys of the map index
+    KeywordIndex(&'a K),
 }
 
 /// [`NumericFieldIndexView`] over the writable storage enum.
@@ -24,6 +31,7 @@ pub type NumericFieldIndex<'a> = NumericFieldIndexView<
     'a,
     NumericIndexInner<IntPayloadType>,
     NumericIndexInner<FloatPayloadType>,
+    MapIndex<str>,
 >;
 
 /// [`NumericFieldIndexView`] over the read-only storage enum.
@@ -31,56 +39,93 @@ pub type ReadOnlyNumericFieldIndex<'a, S> = NumericFieldIndexView<
     'a,
     ReadOnlyNumericIndexInner<IntPayloadType, S>,
     ReadOnlyNumericIndexInner<FloatPayloadType, S>,
+    ReadOnlyMapIndex<str, S>,
 >;
 
-impl<'a, I, F> StreamRange<OrderValue> for NumericFieldIndexView<'a, I, F>
+impl<'a, I, F, K> StreamRange<OrderValue> for NumericFieldIndexView<'a, I, F, K>
 where
     I: NumericIndexRead<IntPayloadType> + StreamRange<IntPayloadType>,
     F: NumericIndexRead<FloatPayloadType> + StreamRange<FloatPayloadType>,
+    K: MapIndexRead<str>,
 {
     fn stream_range(
         &self,
         range: &RangeInterface,
     ) -> OperationResult<impl DoubleEndedIterator<Item = (OrderValue, PointOffsetType)> + '_> {
         Ok(match self {
-            NumericFieldIndexView::IntIndex(index) => Either::Left(
+            NumericFieldIndexView::IntIndex(index) => Either::Left(Either::Left(
                 index
                     .stream_range(range)?
                     .map(|(v, p)| (OrderValue::from(v), p)),
-            ),
-            NumericFieldIndexView::FloatIndex(index) => Either::Right(
+            )),
+            NumericFieldIndexView::FloatIndex(index) => Either::Left(Either::Right(
                 index
                     .stream_range(range)?
                     .map(|(v, p)| (OrderValue::from(v), p)),
-            ),
+            )),
+            NumericFieldIndexView::KeywordIndex(index) => {
+                let range: Range<&str> = match range {
+                    RangeInterface::Keyword(KeywordRange { keyword }) => keyword.as_deref(),
+                    // Order-by without `start_from` is represented as an unbounded float range
+                    RangeInterface::Float(_) | RangeInterface::DateTime(_) => Range::default(),
+                };
+                let values = index.values_in_range(range)?;
+
+                Either::Right(values.flat_map(|value| {
+                    // Ordered reads don't measure index IO, same as numeric indexes
+                    let hw_counter = HardwareCounterCell::disposable();
+                    let mut points: Vec<_> = index.get_iterator(&value, &hw_counter).collect();
+                    points.sort_unstable();
+                    points
+                        .into_iter()
+                        .map(move |point| (OrderValue::Keyword(value.to_string()), point))
+                }))
+            }
         })
     }
 }
 
-impl<'a, I, F> NumericFieldIndexView<'a, I, F>
+impl<'a, I, F, K> NumericFieldIndexView<'a, I, F, K>
 where
     I: NumericIndexRead<IntPayloadType>,
     F: NumericIndexRead<FloatPayloadType>,
+    K: MapIndexRead<str>,
 {
     pub fn get_ordering_values(
         &self,
         idx: PointOffsetType,
     ) -> impl Iterator<Item = OrderValue> + 'a {
         match self {
-            NumericFieldIndexView::IntIndex(index) => Either::Left(
+            NumericFieldIndexView::IntIndex(index) => Either::Left(Either::Left(
                 index
                     .get_values(idx)
                     .into_iter()
                     .flatten()
                     .map(OrderValue::Int),
-            ),
-            NumericFieldIndexView::FloatIndex(index) => Either::Right(
+            )),
+            NumericFieldIndexView::FloatIndex(index) => Either::Left(Either::Right(
                 index
                     .get_values(idx)
                     .into_iter()
                     .flatten()
                     .map(OrderValue::Float),
-            ),
+            )),
+            NumericFieldIndexView::KeywordIndex(index) => {
+                let values: Vec<_> = index
+                    .get_values(idx, &HardwareCounterCell::disposable())
+                    .into_iter()
+                    .flatten()
+                    .map(|value| OrderValue::Keyword(value.into_owned()))
+                    .collect();
+                Either::Right(values.into_iter())
+            }
+        }
+    }
+
+    pub fn is_keyword_index(&self) -> bool {
+        match self {
+            NumericFieldIndexView::IntIndex(_) | NumericFieldIndexView::FloatIndex(_) => false,
+            NumericFieldIndexView::KeywordIndex(_) => true,
         }
     }
 }
@@ -96,21 +141,30 @@ where
 pub trait NumericFieldIndexRead {
     fn get_ordering_values(&self, idx: PointOffsetType) -> impl Iterator<Item = OrderValue> + '_;
 
+    /// Whether the index orders keywords, in which case `start_from` of
+    /// `order_by` is a string rather than a number or a datetime.
+    fn is_keyword_index(&self) -> bool;
+
     fn stream_range(
         &self,
         range: &RangeInterface,
     ) -> OperationResult<impl DoubleEndedIterator<Item = (OrderValue, PointOffsetType)> + '_>;
 }
 
-impl<'a, I, F> NumericFieldIndexRead for NumericFieldIndexView<'a, I, F>
+impl<'a, I, F, K> NumericFieldIndexRead for NumericFieldIndexView<'a, I, F, K>
 where
     I: NumericIndexRead<IntPayloadType> + StreamRange<IntPayloadType>,
     F: NumericIndexRead<FloatPayloadType> + StreamRange<FloatPayloadType>,
+    K: MapIndexRead<str>,
 {
     fn get_ordering_values(&self, idx: PointOffsetType) -> impl Iterator<Item = OrderValue> + '_ {
         NumericFieldIndexView::get_ordering_values(self, idx)
     }
 
+    fn is_keyword_index(&self) -> bool {
+        NumericFieldIndexView::is_keyword_index(self)
+    }
+
     fn stream_range(
         &self,
         range: &RangeInterface,
@@ -32,6 +32,26 @@ use crate::types::{
     FieldCondition, Match, MatchValue, PayloadKeyType, Range, RangeInterface, ValueVariants,
 };
 
+/// Convert the range bounds into the index's storage type `T`.
+///
+/// `T::from_f64_range` / `T::from_u128` are total functions provided by
+/// `Numericable`, so every numeric variant (Int / Float / Datetime /
+/// Uuid) can serve float and datetime ranges. For integer `T`, the
+/// float-range conversion rounds each bound *away* from the matching
+/// set so fractional bounds keep their `f64`-comparison semantics.
+///
+/// Returns `None` for keyword ranges, which compare strings and can't be
+/// served by a numeric index.
+fn typed_range<T: Numericable>(range: &RangeInterface) -> Option<Range<T>> {
+    match range {
+        RangeInterface::Float(float_range) => Some(T::from_f64_range(*float_range)),
+        RangeInterface::DateTime(datetime_range) => {
+            Some(datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128)))
+        }
+        RangeInterface::Keyword(_) => None,
+    }
+}
+
 /// Histogram-driven cardinality estimation for a range condition.
 pub(super) fn range_cardinality<T, I>(
     index: &I,
@@ -46,11 +66,9 @@ where
         return Ok(CardinalityEstimation::exact(0));
     }
 
-    let range = match range {
-        RangeInterface::Float(float_range) => T::from_f64_range(*float_range),
-        RangeInterface::DateTime(datetime_range) => {
-            datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
-        }
+    // Keyword ranges are never served by numeric indexes, see `estimate_cardinality`
+    let Some(range) = typed_range::<T>(range) else {
+        return Ok(CardinalityEstimation::exact(0));
     };
 
     let lbound = if let Some(lte) = range.lte {
@@ -157,13 +175,10 @@ where
         return Ok(None);
     };
 
-    let (start_bound, end_bound) = match range_cond {
-        RangeInterface::Float(float_range) => T::from_f64_range(*float_range),
-        RangeInterface::DateTime(datetime_range) => {
-            datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
-        }
-    }
-    .as_index_key_bounds();
+    let Some(range) = typed_range::<T>(range_cond) else {
+        return Ok(None);
+    };
+    let (start_bound, end_bound) = range.as_index_key_bounds();
 
     // map.range
     // Panics if range start > end. Panics if range start == end and both bounds are Excluded.
@@ -207,6 +222,7 @@ where
     condition
         .range
         .as_ref()
+        .filter(|range| !matches!(range, RangeInterface::Keyword(_)))
         .map(|range| {
             let mut cardinality = range_cardinality(index, range)?;
             cardinality
@@ -324,18 +340,7 @@ where
     } = condition;
 
     let range = range.as_ref()?;
-    // Convert the range bounds into the index's storage type `T`.
-    // `T::from_f64_range` / `T::from_u128` are total functions provided by
-    // `Numericable`, so every numeric variant (Int / Float / Datetime /
-    // Uuid) can serve any `RangeInterface` shape. For integer `T`, the
-    // float-range conversion rounds each bound *away* from the matching
-    // set so fractional bounds keep their `f64`-comparison semantics.
-    let typed_range = match range {
-        RangeInterface::Float(float_range) => T::from_f64_range(*float_range),
-        RangeInterface::DateTime(datetime_range) => {
-            datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
-        }
-    };
+    let typed_range = typed_range::<T>(range)?;
 
     let hw_counter = hw_acc.get_counter_cell();
     Some(Box::new(move |point_id: PointOffsetType| {
@@ -359,11 +364,8 @@ where
     T: Encodable + Numericable + StoredValue + Send + Sync + Default,
     I: NumericIndexRead<T>,
 {
-    let range = match range {
-        RangeInterface::Float(float_range) => T::from_f64_range(*float_range),
-        RangeInterface::DateTime(datetime_range) => {
-            datetime_range.map(|dt| T::from_u128(dt.timestamp() as u128))
-        }
+    let Some(range) = typed_range::<T>(range) else {
+        return Ok(Either::Left(std::iter::empty()));
     };
     let (start_bound, end_bound) = range.as_index_key_bounds();
 
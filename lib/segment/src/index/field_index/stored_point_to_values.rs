@@ -101,6 +101,18 @@ where
         path: &Path,
         iter: impl Iterator<Item = (PointOffsetType, impl Iterator<Item = &'a T>)> + Clone,
     ) -> OperationResult<Self>
+    where
+        T: 'a,
+    {
+        Self::from_iter_in_file(&path.join(POINT_TO_VALUES_PATH), iter)
+    }
+
+    /// Same as [`Self::from_iter`], but with a custom file name,
+    /// e.g. to keep several maps in one directory
+    pub fn from_iter_in_file<'a>(
+        file_name: &Path,
+        iter: impl Iterator<Item = (PointOffsetType, impl Iterator<Item = &'a T>)> + Clone,
+    ) -> OperationResult<Self>
     where
         T: 'a,
     {
@@ -115,9 +127,8 @@ where
         let file_size = PADDING_SIZE + ranges_size + values_size;
 
         // create new file and mmap
-        let file_name = path.join(POINT_TO_VALUES_PATH);
-        create_and_ensure_length(&file_name, file_size)?;
-        let mut mmap = open_write_mmap(&file_name, AdviceSetting::Global, false)?;
+        create_and_ensure_length(file_name, file_size)?;
+        let mut mmap = open_write_mmap(file_name, AdviceSetting::Global, false)?;
 
         // fill mmap file data
         let header = Header {
@@ -159,12 +170,15 @@ where
         mmap.flush()?;
         drop(mmap);
 
-        Self::open(path, true)
+        Self::open_file(file_name, true)
     }
 
     pub fn open(path: &Path, populate: bool) -> OperationResult<Self> {
-        let file_name = path.join(POINT_TO_VALUES_PATH);
+        Self::open_file(&path.join(POINT_TO_VALUES_PATH), populate)
+    }
 
+    /// Same as [`Self::open`], but with a custom file name
+    pub fn open_file(file_name: &Path, populate: bool) -> OperationResult<Self> {
         let open_options = common::universal_io::OpenOptions {
             writeable: false,
             need_sequential: false,
@@ -173,12 +187,12 @@ where
             extra: Default::default(),
         };
 
-        let store = ReadOnly::open(&file_name, open_options)?;
+        let store = ReadOnly::open(file_name, open_options)?;
 
         let header = store.read::<Random, Header>(ReadRange::one(0))?[0];
 
         Ok(Self {
-            file_name,
+            file_name: file_name.to_path_buf(),
             store,
             header,
             phantom: std::marker::PhantomData,
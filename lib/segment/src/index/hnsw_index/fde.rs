@@ -0,0 +1,519 @@
+//! Fixed-dimensional encoding (FDE) of multivectors, as described in MUVERA
+//! <https://arxiv.org/abs/2405.19504>
+//!
+//! Every multivector is encoded into a single dense vector, such that the dot product of a query
+//! encoding and a document encoding approximates the MaxSim score of the original multivectors.
+//! The HNSW graph is built on top of the encodings, and candidates found with the graph are
+//! rescored with the exact multivector comparator.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use common::bitvec::BitSliceExt as _;
+use common::fs::{atomic_save_json, read_json};
+use common::generic_consts::Sequential;
+use common::types::PointOffsetType;
+use rayon::ThreadPool;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::named_vectors::CowVector;
+use crate::data_types::vectors::{
+    DenseVector, QueryVector, TypedMultiDenseVectorRef, VectorElementType, VectorInternal,
+};
+use crate::types::{Distance, FdeConfig, MAX_FDE_ENCODING_DIM, MultiVectorComparator};
+use crate::vector_storage::dense::dense_vector_storage::open_dense_vector_storage;
+use crate::vector_storage::query::TransformInto;
+use crate::vector_storage::{VectorStorage, VectorStorageEnum, VectorStorageRead};
+
+const FDE_DIR: &str = "fde";
+const FDE_CONFIG_FILE: &str = "fde_config.json";
+
+/// Fixed seed, so that encodings of queries and stored vectors always use the same randomness.
+const FDE_SEED: u64 = 0x4D55_5645_5241;
+
+/// Number of points encoded in parallel before they are written into the storage.
+const ENCODE_BATCH_SIZE: usize = 1024;
+
+/// Configuration the encodings of an index were built with
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+struct FdeIndexConfig {
+    #[serde(flatten)]
+    fde: FdeConfig,
+    /// Dimension of inner vectors of the encoded multivectors
+    dim: usize,
+}
+
+#[derive(Debug)]
+pub struct FdeEncoder {
+    config: FdeConfig,
+    dim: usize,
+    /// Size of each bucket after projection
+    dim_proj: usize,
+    /// SimHash hyperplanes with `±1` components, `repetitions * ksim * dim` values
+    hyperplanes: Vec<VectorElementType>,
+    /// Random projections with `±1 / sqrt(dim_proj)` components, `repetitions * dim_proj * dim`
+    /// values. `None` if vectors are not projected.
+    projections: Option<Vec<VectorElementType>>,
+}
+
+impl FdeEncoder {
+    pub fn new(config: FdeConfig, dim: usize) -> Self {
+        let FdeConfig {
+            ksim,
+            dim_proj,
+            repetitions,
+            candidates_factor: _,
+        } = config;
+
+        let mut rng = SplitMix64(FDE_SEED);
+        let hyperplanes = rng.signs(repetitions * ksim * dim, 1.0);
+
+        let (dim_proj, projections) = if dim_proj < dim {
+            let scale = 1.0 / (dim_proj as VectorElementType).sqrt();
+            (
+                dim_proj,
+                Some(rng.signs(repetitions * dim_proj * dim, scale)),
+            )
+        } else {
+            (dim, None)
+        };
+
+        Self {
+            config,
+            dim,
+            dim_proj,
+            hyperplanes,
+            projections,
+        }
+    }
+
+    fn buckets(&self) -> usize {
+        1 << self.config.ksim
+    }
+
+    /// Dimension of the resulting encodings
+    pub fn encoding_dim(&self) -> usize {
+        self.config.repetitions * self.buckets() * self.dim_proj
+    }
+
+    /// Encode a stored multivector.
+    /// Each bucket holds the average of vectors falling into it, empty buckets are filled with
+    /// the vector closest to the bucket by Hamming distance.
+    pub fn encode_document(
+        &self,
+        multi: TypedMultiDenseVectorRef<'_, VectorElementType>,
+    ) -> DenseVector {
+        self.encode(multi, false)
+    }
+
+    /// Encode a query multivector.
+    /// Each bucket holds the sum of vectors falling into it.
+    pub fn encode_query(
+        &self,
+        multi: TypedMultiDenseVectorRef<'_, VectorElementType>,
+    ) -> DenseVector {
+        self.encode(multi, true)
+    }
+
+    fn encode(
+        &self,
+        multi: TypedMultiDenseVectorRef<'_, VectorElementType>,
+        is_query: bool,
+    ) -> DenseVector {
+        let buckets = self.buckets();
+        let block_size = buckets * self.dim_proj;
+        let count = multi.vectors_count();
+
+        let mut encoding = vec![0.0; self.encoding_dim()];
+        let mut bucket_sizes = vec![0usize; buckets];
+        let mut vector_buckets = Vec::with_capacity(count);
+        let mut projected = vec![0.0; count * self.dim_proj];
+
+        for (repetition, block) in encoding.chunks_exact_mut(block_size).enumerate() {
+            bucket_sizes.fill(0);
+            vector_buckets.clear();
+
+            for (vector, projected) in multi
+                .multi_vectors()
+                .zip(projected.chunks_exact_mut(self.dim_proj))
+            {
+                let bucket = self.bucket(repetition, vector);
+                self.project(repetition, vector, projected);
+
+                let target = &mut block[bucket * self.dim_proj..(bucket + 1) * self.dim_proj];
+                for (target, value) in target.iter_mut().zip(projected.iter()) {
+                    *target += value;
+                }
+                bucket_sizes[bucket] += 1;
+                vector_buckets.push(bucket);
+            }
+
+            if is_query {
+                continue;
+            }
+
+            for (bucket, &size) in bucket_sizes.iter().enumerate() {
+                let target = &mut block[bucket * self.dim_proj..(bucket + 1) * self.dim_proj];
+                if size > 0 {
+                    let scale = 1.0 / size as VectorElementType;
+                    target.iter_mut().for_each(|value| *value *= scale);
+                    continue;
+                }
+                // Fill empty bucket with the closest vector
+                let closest = vector_buckets
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, other)| (bucket ^ **other).count_ones())
+                    .map(|(idx, _)| idx);
+                if let Some(closest) = closest {
+                    target.copy_from_slice(
+                        &projected[closest * self.dim_proj..(closest + 1) * self.dim_proj],
+                    );
+                }
+            }
+        }
+
+        encoding
+    }
+
+    /// SimHash bucket of a vector: one bit per hyperplane
+    fn bucket(&self, repetition: usize, vector: &[VectorElementType]) -> usize {
+        let ksim = self.config.ksim;
+        let hyperplanes = &self.hyperplanes[repetition * ksim * self.dim..];
+        hyperplanes
+            .chunks_exact(self.dim)
+            .take(ksim)
+            .enumerate()
+            .fold(0, |bucket, (bit, hyperplane)| {
+                if dot(hyperplane, vector) > 0.0 {
+                    bucket | (1 << bit)
+                } else {
+                    bucket
+                }
+            })
+    }
+
+    fn project(
+        &self,
+        repetition: usize,
+        vector: &[VectorElementType],
+        projected: &mut [VectorElementType],
+    ) {
+        match &self.projections {
+            Some(projections) => {
+                let projection = &projections[repetition * self.dim_proj * self.dim..];
+                for (value, row) in projected.iter_mut().zip(projection.chunks_exact(self.dim)) {
+                    *value = dot(row, vector);
+                }
+            }
+            None => projected.copy_from_slice(vector),
+        }
+    }
+}
+
+fn dot(a: &[VectorElementType], b: &[VectorElementType]) -> VectorElementType {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// Small deterministic generator, so that encodings don't depend on `rand` implementation details
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn signs(&mut self, count: usize, scale: VectorElementType) -> Vec<VectorElementType> {
+        let mut signs = Vec::with_capacity(count);
+        while signs.len() < count {
+            let bits = self.next_u64();
+            let take = (count - signs.len()).min(u64::BITS as usize);
+            signs.extend((0..take).map(|bit| {
+                if (bits >> bit) & 1 == 1 {
+                    scale
+                } else {
+                    -scale
+                }
+            }));
+        }
+        signs
+    }
+}
+
+/// Encodings of all multivectors of a segment, stored within the HNSW index
+#[derive(Debug)]
+pub struct FdeIndex {
+    encoder: FdeEncoder,
+    storage: VectorStorageEnum,
+}
+
+impl FdeIndex {
+    /// Encoding configuration to build the index with, if any
+    pub fn build_config(vector_storage: &VectorStorageEnum) -> Option<FdeConfig> {
+        let multivector_config = vector_storage.try_multi_vector_config()?;
+        let fde = multivector_config.fde?;
+        if multivector_config.comparator != MultiVectorComparator::MaxSim {
+            log::warn!(
+                "Fixed-dimensional encoding is not supported for {:?} comparator, building regular index",
+                multivector_config.comparator,
+            );
+            return None;
+        }
+        if fde.max_encoding_dim() > MAX_FDE_ENCODING_DIM {
+            log::warn!(
+                "Fixed-dimensional encoding of size {} exceeds {MAX_FDE_ENCODING_DIM}, building regular index",
+                fde.max_encoding_dim(),
+            );
+            return None;
+        }
+        match vector_storage.distance() {
+            Distance::Dot | Distance::Cosine => Some(fde),
+            distance @ (Distance::Euclid
+            | Distance::Manhattan
+            | Distance::Hamming
+            | Distance::Jaccard) => {
+                log::warn!(
+                    "Fixed-dimensional encoding is not supported for {distance:?} distance, building regular index"
+                );
+                None
+            }
+        }
+    }
+
+    pub fn exists(index_path: &Path) -> bool {
+        Self::config_path(index_path).exists()
+    }
+
+    fn config_path(index_path: &Path) -> PathBuf {
+        index_path.join(FDE_DIR).join(FDE_CONFIG_FILE)
+    }
+
+    pub fn build(
+        index_path: &Path,
+        config: FdeConfig,
+        vector_storage: &VectorStorageEnum,
+        pool: &ThreadPool,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let dim = vector_storage.try_multi_vector_dim().ok_or_else(|| {
+            OperationError::service_error("Fixed-dimensional encoding requires multivectors")
+        })?;
+        let encoder = FdeEncoder::new(config, dim);
+        let encoding_dim = encoder.encoding_dim();
+
+        let mut storage = open_dense_vector_storage(
+            &index_path.join(FDE_DIR),
+            encoding_dim,
+            Distance::Dot,
+            false,
+        )?;
+
+        let total_vector_count = vector_storage.total_vector_count();
+        let deleted = vector_storage.deleted_vector_bitslice();
+        let encode = |id: PointOffsetType| {
+            if deleted.get_bit(id as usize).unwrap_or(false) {
+                return (vec![0.0; encoding_dim], true);
+            }
+            let encoding = match vector_storage.get_vector::<Sequential>(id) {
+                CowVector::MultiDense(multi) => encoder.encode_document(multi.as_ref()),
+                CowVector::Dense(_) | CowVector::Sparse(_) => vec![0.0; encoding_dim],
+            };
+            (encoding, false)
+        };
+
+        let mut encodings = (0..total_vector_count)
+            .step_by(ENCODE_BATCH_SIZE)
+            .flat_map(|start| {
+                let end = (start + ENCODE_BATCH_SIZE).min(total_vector_count);
+                let batch: Vec<_> = pool.install(|| {
+                    (start as PointOffsetType..end as PointOffsetType)
+                        .into_par_iter()
+                        .map(encode)
+                        .collect()
+                });
+                batch.into_iter()
+            })
+            .map(|(encoding, deleted)| (CowVector::Dense(Cow::Owned(encoding)), deleted));
+        storage.update_from(&mut encodings, stopped)?;
+        storage.flusher()()?;
+
+        atomic_save_json(
+            &Self::config_path(index_path),
+            &FdeIndexConfig { fde: config, dim },
+        )?;
+
+        Ok(Self { encoder, storage })
+    }
+
+    pub fn open(index_path: &Path, populate: bool) -> OperationResult<Self> {
+        let FdeIndexConfig { fde, dim } = read_json(&Self::config_path(index_path))?;
+        let encoder = FdeEncoder::new(fde, dim);
+        let storage = open_dense_vector_storage(
+            &index_path.join(FDE_DIR),
+            encoder.encoding_dim(),
+            Distance::Dot,
+            populate,
+        )?;
+        Ok(Self { encoder, storage })
+    }
+
+    /// Storage of encodings, the graph is built on
+    pub fn storage(&self) -> &VectorStorageEnum {
+        &self.storage
+    }
+
+    /// Number of candidates to retrieve with encodings, to find `top` results after rescoring
+    pub fn candidates(&self, top: usize) -> usize {
+        top.saturating_mul(self.encoder.config.candidates_factor)
+    }
+
+    /// Replace multivectors of the query with their encodings
+    pub fn encode_query(&self, query: QueryVector) -> OperationResult<QueryVector> {
+        query.transform(|vector| match vector {
+            VectorInternal::MultiDense(multi) => Ok(VectorInternal::Dense(
+                self.encoder.encode_query((&multi).into()),
+            )),
+            VectorInternal::Dense(_) | VectorInternal::Sparse(_) => Err(
+                OperationError::service_error("Fixed-dimensional encoding requires multivectors"),
+            ),
+        })
+    }
+
+    pub fn files(&self, index_path: &Path) -> Vec<PathBuf> {
+        let mut files = self.storage.files();
+        files.push(Self::config_path(index_path));
+        files
+    }
+
+    pub fn populate(&self) -> OperationResult<()> {
+        self.storage.populate()
+    }
+
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.storage.clear_cache()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::StdRng;
+    use rand::{Rng, RngExt, SeedableRng};
+
+    use super::*;
+    use crate::data_types::vectors::MultiDenseVectorInternal;
+
+    const DIM: usize = 32;
+    const VECTORS_PER_POINT: usize = 8;
+
+    fn normalized(mut vector: Vec<VectorElementType>) -> Vec<VectorElementType> {
+        let norm = dot(&vector, &vector).sqrt();
+        vector.iter_mut().for_each(|value| *value /= norm);
+        vector
+    }
+
+    fn random_multi<R: Rng + ?Sized>(rng: &mut R) -> MultiDenseVectorInternal {
+        let vectors = (0..VECTORS_PER_POINT)
+            .map(|_| normalized((0..DIM).map(|_| rng.random_range(-0.5..0.5)).collect()))
+            .collect();
+        MultiDenseVectorInternal::try_from(vectors).unwrap()
+    }
+
+    #[test]
+    fn test_fde_encoding_dim() {
+        let config = FdeConfig {
+            ksim: 3,
+            dim_proj: 8,
+            repetitions: 5,
+            candidates_factor: 1,
+        };
+        assert_eq!(FdeEncoder::new(config, DIM).encoding_dim(), 5 * 8 * 8);
+        // No projection if it would not reduce dimension
+        assert_eq!(FdeEncoder::new(config, 4).encoding_dim(), 5 * 8 * 4);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let multi = random_multi(&mut rng);
+        let encoder = FdeEncoder::new(config, DIM);
+        assert_eq!(encoder.encode_document((&multi).into()).len(), 5 * 8 * 8);
+        assert_eq!(encoder.encode_query((&multi).into()).len(), 5 * 8 * 8);
+
+        // Encodings are deterministic
+        assert_eq!(
+            encoder.encode_document((&multi).into()),
+            FdeEncoder::new(config, DIM).encode_document((&multi).into()),
+        );
+    }
+
+    #[test]
+    fn test_fde_config_validation() {
+        use validator::Validate;
+
+        use crate::types::MultiVectorConfig;
+
+        let config = FdeConfig::default();
+        assert!(config.validate().is_ok());
+
+        // 64 * 2^8 * 1024 dimensions per encoding
+        let too_large = FdeConfig {
+            ksim: 8,
+            dim_proj: 1024,
+            repetitions: 64,
+            candidates_factor: 1,
+        };
+        assert!(too_large.max_encoding_dim() > MAX_FDE_ENCODING_DIM);
+        assert!(too_large.validate().is_err());
+
+        let multivector_config = |comparator| MultiVectorConfig {
+            comparator,
+            fde: Some(config),
+        };
+        assert!(
+            multivector_config(MultiVectorComparator::MaxSim)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            multivector_config(MultiVectorComparator::Chamfer)
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_fde_finds_similar_multivector() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let encoder = FdeEncoder::new(FdeConfig::default(), DIM);
+
+        for _ in 0..10 {
+            let query = random_multi(&mut rng);
+            let similar = MultiDenseVectorInternal::try_from(
+                query
+                    .multi_vectors()
+                    .map(|vector| {
+                        vector
+                            .iter()
+                            .map(|value| value + rng.random_range(-0.05..0.05))
+                            .collect()
+                    })
+                    .collect::<Vec<Vec<_>>>(),
+            )
+            .unwrap();
+
+            let query_encoding = encoder.encode_query((&query).into());
+            let score = |document: &MultiDenseVectorInternal| {
+                dot(&query_encoding, &encoder.encode_document(document.into()))
+            };
+
+            let similar_score = score(&similar);
+            for _ in 0..100 {
+                assert!(score(&random_multi(&mut rng)) < similar_score);
+            }
+        }
+    }
+}
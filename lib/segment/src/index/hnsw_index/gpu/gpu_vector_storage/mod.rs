@@ -14,6 +14,7 @@ use common::types::PointOffsetType;
 use gpu_multivectors::GpuMultivectors;
 use gpu_quantization::GpuQuantization;
 use quantization::encoded_vectors_binary::{BitsStoreType, EncodedVectorsBin};
+use quantization::encoded_vectors_u8::ScalarQuantizationMethod;
 use quantization::{EncodedStorage, EncodedVectors, EncodedVectorsPQ, EncodedVectorsU8};
 use zerocopy::IntoBytes;
 
@@ -22,7 +23,7 @@ use crate::common::operation_error::{OperationError, OperationResult, check_proc
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{VectorElementType, VectorElementTypeByte, VectorElementTypeHalf};
 use crate::index::hnsw_index::gpu::GPU_TIMEOUT;
-use crate::types::{Distance, VectorStorageDatatype};
+use crate::types::{Distance, MultiVectorComparator, VectorStorageDatatype};
 use crate::vector_storage::quantized::quantized_vectors::{
     QuantizedVectorStorage, QuantizedVectors,
 };
@@ -96,6 +97,9 @@ impl ShaderBuilderParameters for GpuVectorStorage {
             VectorStorageDatatype::Uint8 => {
                 defines.insert("VECTOR_STORAGE_ELEMENT_UINT8".to_owned(), None);
             }
+            VectorStorageDatatype::Bit => {
+                unreachable!("Bit vectors are rejected on GPU vector storage creation")
+            }
         }
 
         match self.distance {
@@ -111,6 +115,9 @@ impl ShaderBuilderParameters for GpuVectorStorage {
             Distance::Manhattan => {
                 defines.insert("MANHATTAN_DISTANCE".to_owned(), None);
             }
+            Distance::Hamming | Distance::Jaccard => {
+                unreachable!("Binary distances are rejected on GPU vector storage creation")
+            }
         }
 
         if let Some(quantization) = &self.quantization {
@@ -139,6 +146,21 @@ impl GpuVectorStorage {
         force_half_precision: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<Self> {
+        if let Some(multi_vector_config) = vector_storage.try_multi_vector_config() {
+            match multi_vector_config.comparator {
+                MultiVectorComparator::MaxSim => {}
+                MultiVectorComparator::Chamfer
+                | MultiVectorComparator::MeanSim
+                | MultiVectorComparator::MaxMax
+                | MultiVectorComparator::MeanPool => {
+                    return Err(OperationError::from(gpu::GpuError::NotSupported(format!(
+                        "{:?} multivector comparator is not supported on GPU",
+                        multi_vector_config.comparator,
+                    ))));
+                }
+            }
+        }
+
         if let Some(quantized_storage) = quantized_storage {
             let gpu_vector_storage = Self::new_quantized(
                 device.clone(),
@@ -164,6 +186,11 @@ impl GpuVectorStorage {
         quantized_storage: &QuantizedVectorStorage,
         stopped: &AtomicBool,
     ) -> OperationResult<Option<Self>> {
+        // GPU shaders score 8-bit codes only, use original vectors for 4-bit codes
+        if quantized_storage.scalar_quantization_method() == Some(ScalarQuantizationMethod::Int4) {
+            return Ok(None);
+        }
+
         let gpu_vector_storage = match quantized_storage {
             QuantizedVectorStorage::ScalarRam(quantized_storage) => Self::new_sq(
                 device.clone(),
@@ -397,6 +424,16 @@ impl GpuVectorStorage {
         force_half_precision: bool,
         stopped: &AtomicBool,
     ) -> OperationResult<Self> {
+        match vector_storage.distance() {
+            Distance::Cosine | Distance::Euclid | Distance::Dot | Distance::Manhattan => {}
+            Distance::Hamming | Distance::Jaccard => {
+                return Err(OperationError::from(gpu::GpuError::NotSupported(format!(
+                    "{:?} distance is not supported on GPU",
+                    vector_storage.distance(),
+                ))));
+            }
+        }
+
         match vector_storage {
             VectorStorageEnum::DenseVolatile(vector_storage) => {
                 Self::new_dense_f32(device, vector_storage, force_half_precision, stopped)
@@ -448,6 +485,14 @@ impl GpuVectorStorage {
             VectorStorageEnum::DenseAppendableMemmapHalf(vector_storage) => {
                 Self::new_dense_f16(device, vector_storage.as_ref(), stopped)
             }
+            VectorStorageEnum::DenseMemmapBit(_)
+            | VectorStorageEnum::DenseAppendableMemmapBit(_) => Err(OperationError::from(
+                gpu::GpuError::NotSupported("Bit vectors are not supported on GPU".to_string()),
+            )),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(_) => Err(OperationError::from(
+                gpu::GpuError::NotSupported("Bit vectors are not supported on GPU".to_string()),
+            )),
             VectorStorageEnum::SparseVolatile(_) => Err(OperationError::from(
                 gpu::GpuError::NotSupported("Sparse vectors are not supported on GPU".to_string()),
             )),
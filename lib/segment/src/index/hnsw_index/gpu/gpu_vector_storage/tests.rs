@@ -308,6 +308,7 @@ fn test_gpu_vector_storage_pq(
         product: ProductQuantizationConfig {
             always_ram: Some(true),
             compression: crate::types::CompressionRatio::X8,
+            opq: None,
         },
     });
 
@@ -603,6 +604,7 @@ fn get_precision(storage_type: TestStorageType, dim: usize, distance: Distance)
         Distance::Dot => 0.01,
         Distance::Euclid => dim as f32 * 0.001,
         Distance::Manhattan => dim as f32 * 0.001,
+        Distance::Hamming | Distance::Jaccard => unreachable!("not supported on GPU"),
     };
     match storage_type.element_type() {
         TestElementType::Float32 => distance_persision,
@@ -8,6 +8,10 @@
 //!   Regular search, as described in the original HNSW paper.
 //!   Usually used on layer 0.
 //!
+//! - [`GraphLayersBase::search_on_level_below`]
+//!   Variation of `search_on_level` that skips points with scores above a maximum,
+//!   e.g. to continue a search from a cursor. Usually used on layer 0.
+//!
 //! - [`GraphLayersBase::search_on_level_acorn`]
 //!   Variation of `search_on_level` that implements the ACORN-1 algorithm.
 //!   Usually used on layer 0.
@@ -32,7 +36,7 @@ use std::sync::atomic::AtomicBool;
 
 use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
 use common::fs::{atomic_save, read_bin};
-use common::types::{PointOffsetType, ScoredPointOffset};
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
 use fs_err as fs;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -147,6 +151,56 @@ pub trait GraphLayersBase {
         Ok(search_context.nearest)
     }
 
+    /// Variation of [`GraphLayersBase::search_on_level`] that finds `ef` closest points with
+    /// scores of at most `max_score`.
+    ///
+    /// Points above `max_score` are expanded, but don't take places in the beam, so the search
+    /// goes on through them until `ef` points below `max_score` are found.
+    ///
+    /// See [module docs](self) for comparison with other search functions.
+    fn search_on_level_below(
+        &self,
+        level_entry: ScoredPointOffset,
+        level: usize,
+        ef: usize,
+        max_score: ScoreType,
+        points_scorer: &mut FilteredScorer,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<FixedLengthPriorityQueue<ScoredPointOffset>> {
+        let mut visited_list = self.get_visited_list_from_pool();
+        visited_list.check_and_update_visited(level_entry.idx);
+
+        let mut search_context = SearchContext::new(ef);
+        search_context.process_candidate_below(level_entry, max_score);
+
+        let limit = self.get_m(level);
+        let mut points_ids: Vec<PointOffsetType> = Vec::with_capacity(2 * limit);
+
+        while let Some(candidate) = search_context.candidates.pop() {
+            check_process_stopped(is_stopped)?;
+
+            if candidate.score < search_context.lower_bound() {
+                break;
+            }
+
+            points_ids.clear();
+            self.for_each_link(candidate.idx, level, |link| {
+                if !visited_list.check(link) {
+                    points_ids.push(link);
+                }
+            });
+
+            points_scorer
+                .score_points(&mut points_ids, limit)
+                .for_each(|score_point| {
+                    search_context.process_candidate_below(score_point, max_score);
+                    visited_list.check_and_update_visited(score_point.idx);
+                });
+        }
+
+        Ok(search_context.nearest)
+    }
+
     /// Variation of [`GraphLayersBase::search_on_level`] that implements the
     /// ACORN-1 algorithm.
     ///
@@ -560,6 +614,84 @@ impl GraphLayers {
         Ok(nearest.into_iter_sorted().take(top).collect_vec())
     }
 
+    /// Find `top` best points with scores of at most `max_score`.
+    ///
+    /// Unlike [`GraphLayers::search`], points above `max_score` don't count towards `ef`.
+    pub fn search_below(
+        &self,
+        top: usize,
+        ef: usize,
+        max_score: ScoreType,
+        mut points_scorer: FilteredScorer,
+        custom_entry_points: Option<&[PointOffsetType]>,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<Vec<ScoredPointOffset>> {
+        let Some(entry_point) = self.get_entry_point(points_scorer.filters(), custom_entry_points)
+        else {
+            return Ok(Vec::default());
+        };
+
+        let zero_level_entry = self.search_entry(
+            entry_point.point_id,
+            entry_point.level,
+            0,
+            &mut points_scorer,
+            is_stopped,
+        )?;
+        let nearest = self.search_on_level_below(
+            zero_level_entry,
+            0,
+            max(ef, top),
+            max_score,
+            &mut points_scorer,
+            is_stopped,
+        )?;
+        Ok(nearest.into_iter_sorted().take(top).collect_vec())
+    }
+
+    /// Find up to `top` best points with scores of at least `min_score`, and at most `max_score`
+    /// if set.
+    ///
+    /// The beam is bounded by `ef` and `top`, so all points within the radius are found page by
+    /// page, by continuing below the score of the last point of the previous page.
+    #[allow(clippy::too_many_arguments)]
+    pub fn search_in_radius(
+        &self,
+        top: usize,
+        ef: usize,
+        min_score: ScoreType,
+        max_score: Option<ScoreType>,
+        mut points_scorer: FilteredScorer,
+        custom_entry_points: Option<&[PointOffsetType]>,
+        is_stopped: &AtomicBool,
+    ) -> CancellableResult<Vec<ScoredPointOffset>> {
+        let Some(entry_point) = self.get_entry_point(points_scorer.filters(), custom_entry_points)
+        else {
+            return Ok(Vec::default());
+        };
+
+        let zero_level_entry = self.search_entry(
+            entry_point.point_id,
+            entry_point.level,
+            0,
+            &mut points_scorer,
+            is_stopped,
+        )?;
+        let nearest = self.search_on_level_below(
+            zero_level_entry,
+            0,
+            max(ef, top),
+            max_score.unwrap_or(ScoreType::INFINITY),
+            &mut points_scorer,
+            is_stopped,
+        )?;
+        Ok(nearest
+            .into_iter_sorted()
+            .take_while(|point| point.score >= min_score)
+            .take(top)
+            .collect_vec())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn search_with_vectors(
         &self,
@@ -932,4 +1064,84 @@ mod tests {
 
         assert_eq!(reference_top.into_sorted_vec(), graph_search);
     }
+
+    #[test]
+    fn test_search_in_radius() {
+        type M = CosineMetric;
+        let distance = <M as Metric<VectorElementType>>::distance();
+        let num_vectors = 1000;
+        let dim = 8;
+        let ef = 32;
+        let page_size = 10;
+        let in_radius_count = 300;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let (vector_holder, graph_layers) = create_graph_layer_fixture(
+            num_vectors,
+            M,
+            dim,
+            GraphLinksFormat::Plain,
+            false,
+            false,
+            distance,
+            &mut rng,
+        );
+
+        let query = random_vector(&mut rng, dim);
+        let processed_query = distance.preprocess_vector::<VectorElementType>(query);
+        let scorer = vector_holder.scorer(processed_query.clone());
+        let mut reference = (0..vector_holder.storage().total_vector_count() as PointOffsetType)
+            .map(|idx| ScoredPointOffset {
+                idx,
+                score: scorer.score_point(idx),
+            })
+            .collect_vec();
+        reference.sort_unstable_by(|a, b| b.cmp(a));
+        let min_score = reference[in_radius_count - 1].score;
+        reference.truncate(in_radius_count);
+
+        // Page through a radius much larger than `ef`, continuing below the last score
+        let mut found: Vec<ScoredPointOffset> = Vec::new();
+        loop {
+            let max_score = found.last().map(|point| point.score);
+            let page = graph_layers
+                .search_in_radius(
+                    page_size,
+                    ef,
+                    min_score,
+                    max_score,
+                    vector_holder.scorer(processed_query.clone()),
+                    None,
+                    &DEFAULT_STOPPED,
+                )
+                .unwrap();
+            assert!(page.len() <= page_size);
+            assert!(page.is_sorted_by(|a, b| a.score >= b.score));
+            assert!(page.iter().all(|point| {
+                point.score >= min_score && max_score.is_none_or(|max| point.score <= max)
+            }));
+
+            // The last point of the previous page is found again at the cutoff
+            let new_points = page
+                .into_iter()
+                .filter(|point| !found.iter().any(|found| found.idx == point.idx))
+                .collect_vec();
+            if new_points.is_empty() {
+                break;
+            }
+            found.extend(new_points);
+        }
+
+        assert!(found.len() <= in_radius_count);
+        assert!(found.is_sorted_by(|a, b| a.score >= b.score));
+        let recall = found
+            .iter()
+            .filter(|point| reference.contains(point))
+            .count();
+        assert!(
+            recall >= in_radius_count * 9 / 10,
+            "found {recall} of {in_radius_count} points within the radius",
+        );
+    }
 }
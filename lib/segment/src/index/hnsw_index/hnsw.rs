@@ -3,11 +3,13 @@ use std::sync::Arc;
 
 use atomic_refcell::AtomicRefCell;
 
+use self::matryoshka::MatryoshkaGraph;
 use self::telemetry::HNSWSearchesTelemetry;
 use crate::common::BYTES_IN_KB;
 use crate::common::operation_error::OperationResult;
 use crate::id_tracker::IdTrackerEnum;
 use crate::index::hnsw_index::config::HnswGraphConfig;
+use crate::index::hnsw_index::fde::FdeIndex;
 use crate::index::hnsw_index::graph_layers::GraphLayers;
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::types::HnswConfig;
@@ -17,6 +19,7 @@ use crate::vector_storage::{VectorStorageEnum, VectorStorageRead};
 mod build;
 #[cfg(feature = "gpu")]
 mod gpu_build;
+mod matryoshka;
 mod old_index;
 mod search;
 mod telemetry;
@@ -43,6 +46,10 @@ pub struct HNSWIndex {
     config: HnswGraphConfig,
     path: PathBuf,
     graph: GraphLayers,
+    /// Fixed-dimensional encodings of multivectors, if the graph is built on them
+    fde: Option<FdeIndex>,
+    /// Graph over the first dimensions of vectors, for Matryoshka embeddings
+    matryoshka: Option<MatryoshkaGraph>,
     searches_telemetry: HNSWSearchesTelemetry,
     is_on_disk: bool,
 }
@@ -54,6 +61,9 @@ pub struct HnswIndexOpenArgs<'a> {
     pub quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
     pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
     pub hnsw_config: HnswConfig,
+    /// Number of leading dimensions to build an additional graph for, see
+    /// [`crate::types::VectorDataConfig::matryoshka_dim`]
+    pub matryoshka_dim: Option<usize>,
 }
 
 impl HNSWIndex {
@@ -65,6 +75,7 @@ impl HNSWIndex {
             quantized_vectors,
             payload_index,
             hnsw_config,
+            matryoshka_dim,
         } = args;
 
         let config_path = HnswGraphConfig::get_config_path(path);
@@ -100,6 +111,15 @@ impl HNSWIndex {
 
         let graph = GraphLayers::load(path, is_on_disk, do_convert)?;
 
+        let fde = FdeIndex::exists(path)
+            .then(|| FdeIndex::open(path, !is_on_disk))
+            .transpose()?;
+
+        let matryoshka = matryoshka_dim
+            .filter(|_| MatryoshkaGraph::exists(path))
+            .map(|dim| MatryoshkaGraph::open(path, dim, is_on_disk))
+            .transpose()?;
+
         Ok(HNSWIndex {
             id_tracker,
             vector_storage,
@@ -108,6 +128,8 @@ impl HNSWIndex {
             config,
             path: path.to_owned(),
             graph,
+            fde,
+            matryoshka,
             searches_telemetry: HNSWSearchesTelemetry::new(),
             is_on_disk,
         })
@@ -128,7 +150,14 @@ impl HNSWIndex {
 
     /// Read underlying data from disk into disk cache.
     pub fn populate(&self) -> OperationResult<()> {
-        self.graph.populate()
+        self.graph.populate()?;
+        if let Some(fde) = &self.fde {
+            fde.populate()?;
+        }
+        if let Some(matryoshka) = &self.matryoshka {
+            matryoshka.populate()?;
+        }
+        Ok(())
     }
 
     /// Drop disk cache.
@@ -141,10 +170,18 @@ impl HNSWIndex {
             config: _,
             path: _,
             graph,
+            fde,
+            matryoshka,
             searches_telemetry: _,
             is_on_disk: _,
         } = self;
         graph.clear_cache()?;
+        if let Some(fde) = fde {
+            fde.clear_cache()?;
+        }
+        if let Some(matryoshka) = matryoshka {
+            matryoshka.clear_cache()?;
+        }
         Ok(())
     }
 }
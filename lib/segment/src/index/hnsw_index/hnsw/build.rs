@@ -15,6 +15,7 @@ use rand::Rng;
 use rayon::ThreadPool;
 use rayon::prelude::*;
 
+use super::matryoshka::MatryoshkaGraph;
 use super::old_index::OldIndexCandidate;
 use super::telemetry::HNSWSearchesTelemetry;
 use super::{
@@ -29,6 +30,7 @@ use crate::index::field_index::PayloadBlockCondition;
 use crate::index::hnsw_index::HnswM;
 use crate::index::hnsw_index::build_condition_checker::BuildConditionChecker;
 use crate::index::hnsw_index::config::HnswGraphConfig;
+use crate::index::hnsw_index::fde::FdeIndex;
 #[cfg(feature = "gpu")]
 use crate::index::hnsw_index::gpu::get_gpu_groups_count;
 #[cfg(feature = "gpu")]
@@ -71,6 +73,7 @@ impl HNSWIndex {
             quantized_vectors,
             payload_index,
             hnsw_config,
+            matryoshka_dim,
         } = open_args;
         let VectorIndexBuildArgs {
             permit,
@@ -92,6 +95,9 @@ impl HNSWIndex {
 
         let total_vector_count = vector_storage_ref.total_vector_count();
 
+        // Multivectors with fixed-dimensional encoding are indexed by their encodings
+        let fde_config = FdeIndex::build_config(&vector_storage_ref);
+
         let full_scan_threshold = vector_storage_ref
             .size_of_available_vectors_in_bytes()
             .checked_div(total_vector_count)
@@ -149,6 +155,8 @@ impl HNSWIndex {
 
         let old_index = old_indices
             .iter()
+            // Graphs of old indices are built on other vectors than encodings
+            .filter(|_| fde_config.is_none())
             .filter_map(|old_index| {
                 OldIndexCandidate::evaluate(
                     &feature_flags,
@@ -223,6 +231,19 @@ impl HNSWIndex {
 
         let old_index = old_index.map(|old_index| old_index.reuse(total_vector_count));
 
+        let fde = fde_config
+            .map(|fde_config| {
+                FdeIndex::build(path, fde_config, &vector_storage_ref, &pool, stopped)
+            })
+            .transpose()?;
+
+        // Vectors the graph is built on
+        let no_quantized_vectors = None;
+        let (graph_vector_storage, graph_quantized_vectors) = match &fde {
+            Some(fde) => (fde.storage(), &no_quantized_vectors),
+            None => (vector_storage_ref.deref(), quantized_vectors_ref.deref()),
+        };
+
         let mut indexed_vectors = 0;
         for vector_id in id_tracker_ref
             .point_mappings()
@@ -251,15 +272,15 @@ impl HNSWIndex {
             let timer = std::time::Instant::now();
             let gpu_vectors = super::gpu_build::create_gpu_vectors(
                 gpu_device,
-                &vector_storage_ref,
-                &quantized_vectors_ref,
+                graph_vector_storage,
+                graph_quantized_vectors,
                 stopped,
             )?;
             if build_main_graph
                 && let Some(gpu_constructed_graph) = super::gpu_build::build_main_graph_on_gpu(
                     id_tracker_ref.deref(),
-                    &vector_storage_ref,
-                    &quantized_vectors_ref,
+                    graph_vector_storage,
+                    graph_quantized_vectors,
                     gpu_vectors.as_ref(),
                     &graph_layers_builder,
                     deleted_bitslice,
@@ -332,8 +353,8 @@ impl HNSWIndex {
 
                 let points_scorer = FilteredScorer::new_internal(
                     vector_id,
-                    vector_storage_ref.deref(),
-                    quantized_vectors_ref.as_ref(),
+                    graph_vector_storage,
+                    graph_quantized_vectors.as_ref(),
                     None,
                     id_tracker_ref.deleted_point_bitslice(),
                     internal_hardware_counter,
@@ -360,6 +381,12 @@ impl HNSWIndex {
             drop(old_index);
         }
 
+        // Matryoshka embeddings are searched by their first dimensions in a separate graph
+        let matryoshka_dim = MatryoshkaGraph::build_dim(matryoshka_dim, &vector_storage_ref)
+            .filter(|_| config.m > 0);
+        // Payload blocks, which got additional links, to link them in the separate graph too
+        let mut matryoshka_payload_blocks = Vec::new();
+
         if let Some((progress_additional_links, indexed_fields)) = additional_links_params {
             progress_additional_links.start();
 
@@ -458,7 +485,7 @@ impl HNSWIndex {
                     }
 
                     let points_to_index = condition_points(
-                        payload_block.condition,
+                        payload_block.condition.clone(),
                         &payload_index_ref,
                         &vector_storage_ref,
                         stopped,
@@ -508,8 +535,8 @@ impl HNSWIndex {
 
                     build_filtered_graph(
                         id_tracker_ref.deref(),
-                        &vector_storage_ref,
-                        &quantized_vectors_ref,
+                        graph_vector_storage,
+                        graph_quantized_vectors,
                         &mut gpu_insert_context,
                         &payload_index_ref,
                         &pool,
@@ -521,6 +548,9 @@ impl HNSWIndex {
                         &counter,
                     )?;
                     graph_layers_builder.merge_from_other(additional_graph);
+                    if matryoshka_dim.is_some() {
+                        matryoshka_payload_blocks.push(payload_block.condition);
+                    }
                     Ok(())
                 };
 
@@ -538,6 +568,28 @@ impl HNSWIndex {
             debug!("skip building additional HNSW links");
         }
 
+        let matryoshka = matryoshka_dim
+            .map(|dim| {
+                let timer = std::time::Instant::now();
+                let graph = MatryoshkaGraph::build(
+                    path,
+                    dim,
+                    &config,
+                    payload_m,
+                    num_entries,
+                    id_tracker_ref.deref(),
+                    &vector_storage_ref,
+                    &payload_index_ref,
+                    &matryoshka_payload_blocks,
+                    &pool,
+                    rng,
+                    stopped,
+                )?;
+                debug!("Finish matryoshka graph in time {:?}", timer.elapsed());
+                Ok::<_, OperationError>(graph)
+            })
+            .transpose()?;
+
         config.indexed_vector_count.replace(indexed_vectors);
 
         // Always skip loading graph to RAM on build
@@ -550,8 +602,8 @@ impl HNSWIndex {
             .then(|| {
                 // NOTE: the configuration is silently ignored if try_new fails.
                 StorageGraphLinksVectors::try_new(
-                    &vector_storage_ref,
-                    quantized_vectors_ref.as_ref(),
+                    graph_vector_storage,
+                    graph_quantized_vectors.as_ref(),
                 )
             })
             .flatten();
@@ -591,6 +643,8 @@ impl HNSWIndex {
             config,
             path: path.to_owned(),
             graph,
+            fde,
+            matryoshka,
             searches_telemetry: HNSWSearchesTelemetry::new(),
             is_on_disk,
         })
@@ -598,7 +652,7 @@ impl HNSWIndex {
 }
 
 /// Get list of points for indexing, associated with payload block filtering condition
-fn condition_points(
+pub(super) fn condition_points(
     condition: FieldCondition,
     payload_index: &StructPayloadIndex,
     vector_storage: &VectorStorageEnum,
@@ -0,0 +1,246 @@
+//! Additional HNSW graph over the first dimensions of dense vectors, for Matryoshka embeddings.
+//!
+//! Vectors are not copied: the graph is built and searched by scoring prefixes of the vectors
+//! in the original storage.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::cow::BoxCow;
+use common::generic_consts::Random;
+use common::types::PointOffsetType;
+use fs_err as fs;
+use rand::Rng;
+use rayon::ThreadPool;
+use rayon::prelude::*;
+
+use super::build::condition_points;
+use super::{HNSW_USE_HEURISTIC, SINGLE_THREADED_HNSW_BUILD_THRESHOLD};
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::data_types::vectors::{QueryVector, VectorInternal, VectorRef};
+use crate::id_tracker::{IdTrackerEnum, IdTrackerRead};
+use crate::index::hnsw_index::HnswM;
+use crate::index::hnsw_index::build_condition_checker::BuildConditionChecker;
+use crate::index::hnsw_index::config::HnswGraphConfig;
+use crate::index::hnsw_index::graph_layers::GraphLayers;
+use crate::index::hnsw_index::graph_layers_builder::GraphLayersBuilder;
+use crate::index::hnsw_index::graph_links::GraphLinksFormatParam;
+use crate::index::hnsw_index::point_scorer::FilteredScorer;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
+use crate::payload_storage::FilterContext;
+use crate::types::FieldCondition;
+use crate::vector_storage::{VectorStorageEnum, VectorStorageRead};
+
+const MATRYOSHKA_DIR: &str = "matryoshka";
+
+#[derive(Debug)]
+pub struct MatryoshkaGraph {
+    /// Number of leading dimensions the graph is built on
+    dim: usize,
+    graph: GraphLayers,
+}
+
+impl MatryoshkaGraph {
+    fn graph_path(path: &Path) -> PathBuf {
+        path.join(MATRYOSHKA_DIR)
+    }
+
+    pub fn exists(path: &Path) -> bool {
+        GraphLayers::get_path(&Self::graph_path(path)).exists()
+    }
+
+    /// Number of leading dimensions to build the graph on, if the storage supports it
+    pub fn build_dim(dim: Option<usize>, vector_storage: &VectorStorageEnum) -> Option<usize> {
+        let dim = dim?;
+        let vector_dim = vector_storage.try_dense_vector_dim()?;
+        if dim == 0 || dim >= vector_dim {
+            log::warn!(
+                "Matryoshka dimension {dim} must be between 1 and vector dimension {vector_dim}, skipping truncated graph",
+            );
+            return None;
+        }
+        Some(dim)
+    }
+
+    pub fn open(path: &Path, dim: usize, on_disk: bool) -> OperationResult<Self> {
+        let graph = GraphLayers::load(&Self::graph_path(path), on_disk, false)?;
+        Ok(Self { dim, graph })
+    }
+
+    /// Build the graph on the first `dim` dimensions of vectors.
+    ///
+    /// Payload blocks get additional links, like in the main graph, so that filtered searches
+    /// stay connected. Only the blocks, which got additional links in the main graph, are given.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build<R: Rng + ?Sized>(
+        path: &Path,
+        dim: usize,
+        config: &HnswGraphConfig,
+        payload_m: HnswM,
+        num_entries: usize,
+        id_tracker: &IdTrackerEnum,
+        vector_storage: &VectorStorageEnum,
+        payload_index: &StructPayloadIndex,
+        payload_blocks: &[FieldCondition],
+        pool: &ThreadPool,
+        rng: &mut R,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let graph_path = Self::graph_path(path);
+        fs::create_dir_all(&graph_path)?;
+
+        let total_vector_count = vector_storage.total_vector_count();
+        let mut graph_layers_builder = GraphLayersBuilder::new(
+            total_vector_count,
+            HnswM::new(config.m, config.m0),
+            config.ef_construct,
+            num_entries,
+            HNSW_USE_HEURISTIC,
+        );
+
+        let ids: Vec<_> = id_tracker
+            .point_mappings()
+            .iter_internal_excluding(vector_storage.deleted_vector_bitslice())
+            .collect();
+        for &vector_id in &ids {
+            let level = graph_layers_builder.get_random_layer(rng);
+            graph_layers_builder.set_levels(vector_id, level);
+        }
+
+        let link_context = LinkContext {
+            dim,
+            id_tracker,
+            vector_storage,
+            pool,
+            stopped,
+        };
+        link_context.link_points(&graph_layers_builder, &ids, None)?;
+
+        let visited_pool = VisitedPool::new();
+        let mut block_filter_list = visited_pool.get(total_vector_count);
+        for condition in payload_blocks {
+            check_process_stopped(stopped)?;
+
+            let points_to_index =
+                condition_points(condition.clone(), payload_index, vector_storage, stopped)?;
+
+            block_filter_list.next_iteration();
+            for &point_id in &points_to_index {
+                block_filter_list.check_and_update_visited(point_id);
+            }
+
+            let additional_graph = GraphLayersBuilder::new_with_params(
+                total_vector_count,
+                payload_m,
+                config.ef_construct,
+                1,
+                HNSW_USE_HEURISTIC,
+                false,
+            );
+            link_context.link_points(
+                &additional_graph,
+                &points_to_index,
+                Some(&block_filter_list),
+            )?;
+            graph_layers_builder.merge_from_other(additional_graph);
+        }
+
+        // Always skip loading graph to RAM on build, like the main graph
+        let graph = graph_layers_builder.into_graph_layers(
+            &graph_path,
+            GraphLinksFormatParam::Compressed,
+            true,
+        )?;
+
+        Ok(Self { dim, graph })
+    }
+
+    pub fn graph(&self) -> &GraphLayers {
+        &self.graph
+    }
+
+    pub fn files(&self, path: &Path) -> Vec<PathBuf> {
+        self.graph.files(&Self::graph_path(path))
+    }
+
+    pub fn populate(&self) -> OperationResult<()> {
+        self.graph.populate()
+    }
+
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        self.graph.clear_cache()
+    }
+}
+
+/// Shared parameters to link points into a graph by their first dimensions
+struct LinkContext<'a> {
+    dim: usize,
+    id_tracker: &'a IdTrackerEnum,
+    vector_storage: &'a VectorStorageEnum,
+    pool: &'a ThreadPool,
+    stopped: &'a AtomicBool,
+}
+
+impl LinkContext<'_> {
+    /// Link points into the graph, only to the points of the block if `block_filter_list` is set
+    fn link_points(
+        &self,
+        graph_layers_builder: &GraphLayersBuilder,
+        ids: &[PointOffsetType],
+        block_filter_list: Option<&VisitedListHandle>,
+    ) -> OperationResult<()> {
+        let insert_point = |vector_id| {
+            check_process_stopped(self.stopped)?;
+            // No need to accumulate hardware, since this is an internal operation
+            let internal_hardware_counter = HardwareCounterCell::disposable();
+
+            let block_condition_checker =
+                block_filter_list.map(|filter_list| BuildConditionChecker {
+                    filter_list,
+                    current_point: vector_id,
+                });
+            let points_scorer = FilteredScorer::new_matryoshka(
+                truncated_vector(self.vector_storage, vector_id, self.dim)?,
+                self.vector_storage,
+                block_condition_checker
+                    .as_ref()
+                    .map(|checker| BoxCow::Borrowed(checker as &dyn FilterContext)),
+                self.id_tracker.deleted_point_bitslice(),
+                internal_hardware_counter,
+            )?;
+
+            graph_layers_builder.link_new_point(vector_id, points_scorer);
+            Ok::<_, OperationError>(())
+        };
+
+        let first_few = ids.len().min(SINGLE_THREADED_HNSW_BUILD_THRESHOLD);
+        for &vector_id in &ids[..first_few] {
+            insert_point(vector_id)?;
+        }
+        self.pool.install(|| {
+            ids[first_few..]
+                .par_iter()
+                .copied()
+                .try_for_each(insert_point)
+        })
+    }
+}
+
+/// First `dim` dimensions of a stored vector, as a query
+fn truncated_vector(
+    vector_storage: &VectorStorageEnum,
+    vector_id: PointOffsetType,
+    dim: usize,
+) -> OperationResult<QueryVector> {
+    let vector = vector_storage.get_vector::<Random>(vector_id);
+    match vector.as_vec_ref() {
+        VectorRef::Dense(vector) => Ok(QueryVector::Nearest(VectorInternal::from(
+            vector[..dim].to_vec(),
+        ))),
+        VectorRef::Sparse(_) | VectorRef::MultiDense(_) => Err(OperationError::service_error(
+            "Matryoshka graph can only be built on dense vectors",
+        )),
+    }
+}
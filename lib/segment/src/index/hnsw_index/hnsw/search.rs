@@ -2,9 +2,11 @@ use common::bitvec::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::cow::BoxCow;
 use common::types::{DeferredBehavior, PointOffsetType, ScoredPointOffset};
+use itertools::Itertools;
 
 use super::HNSWIndex;
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::explain::SearchStrategy;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorInternal};
 use crate::id_tracker::IdTrackerRead;
@@ -12,8 +14,9 @@ use crate::index::PayloadIndexRead;
 use crate::index::hnsw_index::graph_layers::{GraphLayersWithVectors, SearchAlgorithm};
 use crate::index::hnsw_index::point_scorer::{BatchFilteredSearcher, FilteredScorer};
 use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::sample_estimation::sample_check_cardinality;
 use crate::index::vector_index_search_common::{
-    get_oversampled_top, is_quantized_search, postprocess_search_result,
+    get_oversampled_top, is_quantized_search, max_score, postprocess_search_result,
 };
 use crate::payload_storage::FilterContext;
 use crate::types::{ACORN_MAX_SELECTIVITY_DEFAULT, Filter, SearchParams};
@@ -22,6 +25,112 @@ use crate::vector_storage::query::DiscoverQuery;
 use crate::vector_storage::{VectorStorageEnum, VectorStorageRead, new_raw_scorer};
 
 impl HNSWIndex {
+    /// Whether the graph has no links at all.
+    ///
+    /// If neither `m` nor `payload_m` is set, searches fall back to plain search (optionally, with
+    /// quantization).
+    pub(super) fn is_hnsw_disabled(&self) -> bool {
+        self.config.m == 0 && self.config.payload_m.unwrap_or(0) == 0
+    }
+
+    /// Whether a search without filter should traverse the graph.
+    ///
+    /// Because an HNSW graph is built, we'd normally always assume to search the graph.
+    /// But because a lot of points may be deleted in this graph, it may just be faster
+    /// to do a plain search instead.
+    pub(super) fn is_graph_search_unfiltered(&self) -> bool {
+        !self.is_hnsw_disabled()
+            && self.vector_storage.borrow().available_vector_count()
+                >= self.config.full_scan_threshold
+    }
+
+    /// Whether a filtered search should traverse the graph, rather than score all matching points.
+    pub(super) fn is_graph_search_filtered(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<bool> {
+        let payload_index = self.payload_index.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let available_vector_count = vector_storage.available_vector_count();
+
+        let query_point_cardinality =
+            payload_index.with_view(|v| v.estimate_cardinality(filter, hw_counter))?;
+        let query_cardinality = adjust_to_available_vectors(
+            query_point_cardinality,
+            available_vector_count,
+            id_tracker.available_point_count(),
+        );
+
+        if query_cardinality.max < self.config.full_scan_threshold {
+            // if cardinality is small - use plain index
+            return Ok(false);
+        }
+
+        if query_cardinality.min > self.config.full_scan_threshold {
+            // if cardinality is high enough - use HNSW index
+            return Ok(true);
+        }
+
+        // Fast cardinality estimation is not enough, do sample estimation of cardinality.
+        // The filter context's lifetime is tied to the view, so the sample check
+        // must run inside `with_view`.
+        payload_index.with_view(|v| {
+            let filter_context = v.filter_context(filter, hw_counter)?;
+            Ok::<_, OperationError>(sample_check_cardinality(
+                id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
+                |idx| filter_context.check(idx),
+                self.config.full_scan_threshold,
+                available_vector_count, // Check cardinality among available vectors
+            ))
+        })
+    }
+
+    /// Whether a graph search with the given filter should use ACORN
+    pub(super) fn is_acorn_search(
+        &self,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<bool> {
+        let acorn_enabled = params
+            .and_then(|params| params.acorn)
+            .is_some_and(|acorn| acorn.enable);
+        let acorn_max_selectivity = params
+            .and_then(|params| params.acorn)
+            .and_then(|acorn| acorn.max_selectivity)
+            .map_or(ACORN_MAX_SELECTIVITY_DEFAULT, |v| *v);
+
+        let Some(filter) = filter else {
+            // NOTE: technically we also might want to use ACORN for unfiltered
+            // searches for segments with a lot of deleted points. But in
+            // practice, such segments most likely to be picked by an optimizer
+            // soon.
+            return Ok(false);
+        };
+        if !acorn_enabled || self.config.m0 == 0 {
+            return Ok(false);
+        }
+
+        let available_vector_count = self.vector_storage.borrow().available_vector_count();
+        let selectivity = if available_vector_count == 0 {
+            1.0
+        } else {
+            let query_point_cardinality = self
+                .payload_index
+                .borrow()
+                .with_view(|v| v.estimate_cardinality(filter, hw_counter))?;
+            let query_cardinality = adjust_to_available_vectors(
+                query_point_cardinality,
+                available_vector_count,
+                self.id_tracker.borrow().available_point_count(),
+            );
+            query_cardinality.exp as f64 / available_vector_count as f64
+        };
+        Ok(selectivity <= acorn_max_selectivity)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(super) fn search_with_graph(
         &self,
@@ -35,14 +144,6 @@ impl HNSWIndex {
         let ef = params
             .and_then(|params| params.hnsw_ef)
             .unwrap_or(self.config.ef);
-        let acorn_enabled = params
-            .and_then(|params| params.acorn)
-            .is_some_and(|acorn| acorn.enable);
-        let acorn_max_selectivity = params
-            .and_then(|params| params.acorn)
-            .and_then(|acorn| acorn.max_selectivity)
-            .map_or(ACORN_MAX_SELECTIVITY_DEFAULT, |v| *v);
-
         let is_stopped = vector_query_context.is_stopped();
 
         let id_tracker = self.id_tracker.borrow();
@@ -57,35 +158,33 @@ impl HNSWIndex {
         let hw_counter = vector_query_context.hardware_counter();
         let oversampled_top = get_oversampled_top(quantized_vectors.as_ref(), params, top);
 
-        let mut algorithm = SearchAlgorithm::Hnsw;
-        if acorn_enabled
-            && self.config.m0 != 0
-            && let Some(filter) = filter
-        {
-            // NOTE: technically we also might want to use ACORN for unfiltered
-            // searches for segments with a lot of deleted points. But in
-            // practice, such segments most likely to be picked by an optimizer
-            // soon.
+        let algorithm = if self.is_acorn_search(filter, params, &hw_counter)? {
+            vector_query_context.record_search_strategy(SearchStrategy::Acorn);
+            SearchAlgorithm::Acorn
+        } else {
+            vector_query_context.record_search_strategy(SearchStrategy::Hnsw);
+            SearchAlgorithm::Hnsw
+        };
 
-            let available_vector_count = vector_storage.available_vector_count();
-            let selectivity = if available_vector_count == 0 {
-                1.0
-            } else {
-                let query_point_cardinality =
-                    payload_index.with_view(|v| v.estimate_cardinality(filter, &hw_counter))?;
-                let query_cardinality = adjust_to_available_vectors(
-                    query_point_cardinality,
-                    available_vector_count,
-                    id_tracker.available_point_count(),
-                );
-                query_cardinality.exp as f64 / available_vector_count as f64
-            };
-            if selectivity <= acorn_max_selectivity {
-                algorithm = SearchAlgorithm::Acorn;
+        // Radius is only meaningful for plain distances, so it doesn't apply to custom scores
+        let distance = vector_storage.distance();
+        let max_score = max_score(&[vector], params, distance);
+        let radius_min_score = match vector {
+            QueryVector::Nearest(_) => {
+                params.and_then(|params| Some(distance.preprocess_score_threshold(*params.radius?)))
             }
-        }
+            QueryVector::RecommendBestScore(_)
+            | QueryVector::RecommendSumScores(_)
+            | QueryVector::Discover(_)
+            | QueryVector::Context(_)
+            | QueryVector::FeedbackNaive(_) => None,
+        };
 
         let search_with_vectors = || -> OperationResult<Option<Vec<ScoredPointOffset>>> {
+            if radius_min_score.is_some() || max_score.is_some() {
+                // Radius and continued searches are not implemented for graph with vectors yet
+                return Ok(None);
+            }
             match algorithm {
                 SearchAlgorithm::Hnsw => (),
                 // ACORN is not implemented for graph with vectors yet (but possible)
@@ -114,6 +213,7 @@ impl HNSWIndex {
                         })
                         .transpose()?,
                     deleted_points,
+                    params.and_then(|params| params.multivector_comparator),
                     vector_query_context.hardware_counter(),
                 )?;
                 let Some(link_scorer_filtered_bytes) = link_scorer_filtered.scorer_bytes() else {
@@ -142,11 +242,53 @@ impl HNSWIndex {
             })
         };
 
+        // Searches on the first dimensions of Matryoshka embeddings have a graph of their own
+        let graph = match &self.matryoshka {
+            Some(matryoshka) if params.is_some_and(|params| params.matryoshka) => {
+                matryoshka.graph()
+            }
+            Some(_) | None => &self.graph,
+        };
+
         let regular_search = || -> OperationResult<Vec<ScoredPointOffset>> {
             payload_index.with_view(|payload_index_view| {
                 let filter_context = filter
                     .map(|f| payload_index_view.filter_context(f, &hw_counter))
                     .transpose()?;
+
+                if let Some(fde) = &self.fde {
+                    // Find candidates with encodings, then rescore them with original multivectors
+                    let points_scorer = FilteredScorer::new(
+                        fde.encode_query(vector.to_owned())?,
+                        fde.storage(),
+                        None,
+                        filter_context.map(BoxCow::Owned),
+                        deleted_points,
+                        None,
+                        vector_query_context.hardware_counter(),
+                    )?;
+
+                    let candidates = self.graph.search(
+                        fde.candidates(top),
+                        ef,
+                        algorithm,
+                        points_scorer,
+                        custom_entry_points,
+                        &is_stopped,
+                    )?;
+
+                    // Encodings approximate MaxSim only, so the comparator of the vector
+                    // configuration is used regardless of a per-search override
+                    return rescore_candidates(
+                        candidates,
+                        &vector_storage,
+                        deleted_points,
+                        vector,
+                        top,
+                        vector_query_context.hardware_counter(),
+                    );
+                }
+
                 let points_scorer = construct_search_scorer(
                     vector,
                     &vector_storage,
@@ -157,14 +299,35 @@ impl HNSWIndex {
                     filter_context,
                 )?;
 
-                let search_result = self.graph.search(
-                    oversampled_top,
-                    ef,
-                    algorithm,
-                    points_scorer,
-                    custom_entry_points,
-                    &is_stopped,
-                )?;
+                let search_result = match (radius_min_score, max_score, algorithm) {
+                    (Some(min_score), _, _) => graph.search_in_radius(
+                        oversampled_top,
+                        ef,
+                        min_score,
+                        max_score,
+                        points_scorer,
+                        custom_entry_points,
+                        &is_stopped,
+                    )?,
+                    // ACORN search is not continued from the cursor, points before it are
+                    // skipped afterwards
+                    (None, Some(max_score), SearchAlgorithm::Hnsw) => graph.search_below(
+                        oversampled_top,
+                        ef,
+                        max_score,
+                        points_scorer,
+                        custom_entry_points,
+                        &is_stopped,
+                    )?,
+                    (None, _, _) => graph.search(
+                        oversampled_top,
+                        ef,
+                        algorithm,
+                        points_scorer,
+                        custom_entry_points,
+                        &is_stopped,
+                    )?,
+                };
 
                 postprocess_search_result(
                     search_result,
@@ -225,6 +388,8 @@ impl HNSWIndex {
         params: Option<&SearchParams>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        vector_query_context.record_search_strategy(SearchStrategy::plain(params));
+
         let id_tracker = self.id_tracker.borrow();
         let vector_storage = self.vector_storage.borrow();
         let quantized_vectors = self.quantized_vectors.borrow();
@@ -363,6 +528,32 @@ impl HNSWIndex {
     }
 }
 
+/// Score candidates with original vectors, and keep the `top` best of them
+fn rescore_candidates(
+    candidates: Vec<ScoredPointOffset>,
+    vector_storage: &VectorStorageEnum,
+    deleted_points: &BitSlice,
+    vector: &QueryVector,
+    top: usize,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Vec<ScoredPointOffset>> {
+    let mut scorer = FilteredScorer::new(
+        vector.to_owned(),
+        vector_storage,
+        None,
+        None,
+        deleted_points,
+        None,
+        hardware_counter,
+    )?;
+    let mut ids = candidates.iter().map(|x| x.idx).collect_vec();
+    let mut search_result: Vec<_> = scorer.score_points(&mut ids, 0).collect();
+    search_result.sort_unstable();
+    search_result.reverse();
+    search_result.truncate(top);
+    Ok(search_result)
+}
+
 fn construct_search_scorer<'a>(
     vector: &QueryVector,
     vector_storage: &'a VectorStorageEnum,
@@ -372,6 +563,15 @@ fn construct_search_scorer<'a>(
     hardware_counter: HardwareCounterCell,
     filter_context: Option<Box<dyn FilterContext + 'a>>,
 ) -> OperationResult<FilteredScorer<'a>> {
+    if params.is_some_and(|params| params.matryoshka) {
+        return FilteredScorer::new_matryoshka(
+            vector.to_owned(),
+            vector_storage,
+            filter_context.map(BoxCow::Owned),
+            deleted_points,
+            hardware_counter,
+        );
+    }
     let quantization_enabled = is_quantized_search(quantized_storage, params);
     FilteredScorer::new(
         vector.to_owned(),
@@ -379,6 +579,7 @@ fn construct_search_scorer<'a>(
         quantization_enabled.then_some(quantized_storage).flatten(),
         filter_context.map(BoxCow::Owned),
         deleted_points,
+        params.and_then(|params| params.multivector_comparator),
         hardware_counter,
     )
 }
@@ -394,14 +595,29 @@ fn construct_batch_searcher<'a>(
     hardware_counter: HardwareCounterCell,
     filter_context: Option<Box<dyn FilterContext + 'a>>,
 ) -> OperationResult<BatchFilteredSearcher<'a>> {
-    let quantization_enabled = is_quantized_search(quantized_storage, params);
-    BatchFilteredSearcher::new(
-        vectors,
-        vector_storage,
-        quantization_enabled.then_some(quantized_storage).flatten(),
-        filter_context.map(BoxCow::Owned),
-        top,
-        deleted_points,
-        hardware_counter,
-    )
+    let searcher = if params.is_some_and(|params| params.matryoshka) {
+        BatchFilteredSearcher::new_matryoshka(
+            vectors,
+            vector_storage,
+            filter_context.map(BoxCow::Owned),
+            top,
+            deleted_points,
+            hardware_counter,
+        )
+    } else {
+        let quantization_enabled = is_quantized_search(quantized_storage, params);
+        BatchFilteredSearcher::new(
+            vectors,
+            vector_storage,
+            quantization_enabled.then_some(quantized_storage).flatten(),
+            filter_context.map(BoxCow::Owned),
+            top,
+            deleted_points,
+            params.and_then(|params| params.multivector_comparator),
+            hardware_counter,
+        )
+    };
+    searcher.map(|searcher| {
+        searcher.with_max_score(max_score(vectors, params, vector_storage.distance()))
+    })
 }
@@ -8,13 +8,11 @@ use sparse::common::types::DimId;
 use super::HNSWIndex;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::operation_time_statistics::ScopeDurationMeasurer;
+use crate::data_types::explain::SearchStrategy;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
-use crate::id_tracker::IdTrackerRead;
 use crate::index::hnsw_index::config::HnswGraphConfig;
-use crate::index::query_estimator::adjust_to_available_vectors;
-use crate::index::sample_estimation::sample_check_cardinality;
-use crate::index::{PayloadIndexRead, VectorIndex, VectorIndexRead};
+use crate::index::{VectorIndex, VectorIndexRead};
 use crate::telemetry::VectorIndexSearchesTelemetry;
 use crate::types::{Filter, QuantizationSearchParams, SearchParams};
 use crate::vector_storage::VectorStorageRead;
@@ -32,10 +30,6 @@ impl VectorIndexRead for HNSWIndex {
             return Ok(vec![vec![]; vectors.len()]);
         }
 
-        // If neither `m` nor `payload_m` is set, HNSW doesn't have any links.
-        // And if so, we need to fall back to plain search (optionally, with quantization).
-
-        let is_hnsw_disabled = self.config.m == 0 && self.config.payload_m.unwrap_or(0) == 0;
         let exact = params.is_some_and(|params| params.exact);
 
         let exact_params = if exact {
@@ -54,15 +48,8 @@ impl VectorIndexRead for HNSWIndex {
 
         match filter {
             None => {
-                let vector_storage = self.vector_storage.borrow();
-
                 // Determine whether to do a plain or graph search, and pick search timer aggregator
-                // Because an HNSW graph is built, we'd normally always assume to search the graph.
-                // But because a lot of points may be deleted in this graph, it may just be faster
-                // to do a plain search instead.
-                let plain_search = exact
-                    || is_hnsw_disabled
-                    || vector_storage.available_vector_count() < self.config.full_scan_threshold;
+                let plain_search = exact || !self.is_graph_search_unfiltered();
 
                 // Do plain or graph search
                 if plain_search {
@@ -86,7 +73,7 @@ impl VectorIndexRead for HNSWIndex {
                 // - to use HNSW index with filtering condition
 
                 // if exact search is requested, we should not use HNSW index
-                if exact || is_hnsw_disabled {
+                if exact || self.is_hnsw_disabled() {
                     let _timer = ScopeDurationMeasurer::new(if exact {
                         &self.searches_telemetry.exact_filtered
                     } else {
@@ -104,62 +91,9 @@ impl VectorIndexRead for HNSWIndex {
                     );
                 }
 
-                let payload_index = self.payload_index.borrow();
-                let vector_storage = self.vector_storage.borrow();
-                let id_tracker = self.id_tracker.borrow();
-                let available_vector_count = vector_storage.available_vector_count();
-
                 let hw_counter = query_context.hardware_counter();
 
-                let query_point_cardinality = payload_index
-                    .with_view(|v| v.estimate_cardinality(query_filter, &hw_counter))?;
-                let query_cardinality = adjust_to_available_vectors(
-                    query_point_cardinality,
-                    available_vector_count,
-                    id_tracker.available_point_count(),
-                );
-
-                if query_cardinality.max < self.config.full_scan_threshold {
-                    // if cardinality is small - use plain index
-                    let _timer =
-                        ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
-                    return self.search_vectors_plain(
-                        vectors,
-                        query_filter,
-                        top,
-                        params,
-                        query_context,
-                    );
-                }
-
-                if query_cardinality.min > self.config.full_scan_threshold {
-                    // if cardinality is high enough - use HNSW index
-                    let _timer =
-                        ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
-                    return self.search_vectors_with_graph(
-                        vectors,
-                        filter,
-                        top,
-                        params,
-                        query_context,
-                    );
-                }
-
-                // Fast cardinality estimation is not enough, do sample estimation of cardinality.
-                // The filter context's lifetime is tied to the view, so the sample check
-                // must run inside `with_view` -- the recursive search dispatches re-borrow
-                // payload_index on their own.
-                let use_graph = payload_index.with_view(|v| {
-                    let filter_context = v.filter_context(query_filter, &hw_counter)?;
-                    Ok::<_, OperationError>(sample_check_cardinality(
-                        id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
-                        |idx| filter_context.check(idx),
-                        self.config.full_scan_threshold,
-                        available_vector_count, // Check cardinality among available vectors
-                    ))
-                })?;
-
-                if use_graph {
+                if self.is_graph_search_filtered(query_filter, &hw_counter)? {
                     // if cardinality is high enough - use HNSW index
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
@@ -174,6 +108,32 @@ impl VectorIndexRead for HNSWIndex {
         }
     }
 
+    fn search_strategy(
+        &self,
+        _vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SearchStrategy> {
+        if params.is_some_and(|params| params.exact) {
+            return Ok(SearchStrategy::Exact);
+        }
+
+        let graph_search = match filter {
+            None => self.is_graph_search_unfiltered(),
+            Some(_) if self.is_hnsw_disabled() => false,
+            Some(filter) => self.is_graph_search_filtered(filter, hw_counter)?,
+        };
+
+        if !graph_search {
+            Ok(SearchStrategy::FullScan)
+        } else if self.is_acorn_search(filter, params, hw_counter)? {
+            Ok(SearchStrategy::Acorn)
+        } else {
+            Ok(SearchStrategy::Hnsw)
+        }
+    }
+
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
         let tm = &self.searches_telemetry;
         VectorIndexSearchesTelemetry {
@@ -224,6 +184,12 @@ impl VectorIndex for HNSWIndex {
         if config_path.exists() {
             files.push(config_path);
         }
+        if let Some(fde) = &self.fde {
+            files.extend(fde.files(&self.path));
+        }
+        if let Some(matryoshka) = &self.matryoshka {
+            files.extend(matryoshka.files(&self.path));
+        }
         files
     }
 
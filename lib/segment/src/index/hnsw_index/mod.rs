@@ -4,6 +4,7 @@ mod build_cache;
 pub mod build_condition_checker;
 mod config;
 mod entry_points;
+mod fde;
 pub mod graph_layers;
 pub mod graph_layers_builder;
 mod graph_layers_healer;
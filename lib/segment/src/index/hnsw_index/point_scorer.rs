@@ -11,12 +11,14 @@ use smallvec::SmallVec;
 use crate::common::operation_error::{OperationResult, check_process_stopped};
 use crate::data_types::vectors::QueryVector;
 use crate::payload_storage::FilterContext;
+use crate::types::MultiVectorComparator;
 use crate::vector_storage::common::VECTOR_READ_BATCH_SIZE;
 use crate::vector_storage::quantized::quantized_query_scorer::InternalScorerUnsupported;
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
 use crate::vector_storage::query_scorer::QueryScorerBytes;
 use crate::vector_storage::{
-    RawScorer, VectorStorageEnum, VectorStorageRead, check_deleted_condition, new_raw_scorer,
+    RawScorer, VectorStorageEnum, VectorStorageRead, check_deleted_condition,
+    new_matryoshka_raw_scorer, new_raw_scorer, new_raw_scorer_with_comparator,
 };
 
 /// Scorers composition:
@@ -115,17 +117,28 @@ impl<'a> FilteredScorer<'a> {
     /// Create a new filtered scorer.
     ///
     /// If present, `quantized_vectors` will be used for scoring, otherwise `vectors` will be used.
+    /// If set, `multivector_comparator` overrides the configured comparator of multivectors.
     pub fn new(
         query: QueryVector,
         vectors: &'a VectorStorageEnum,
         quantized_vectors: Option<&'a QuantizedVectors>,
         filter_context: Option<BoxCow<'a, dyn FilterContext + 'a>>,
         point_deleted: &'a BitSlice,
+        multivector_comparator: Option<MultiVectorComparator>,
         hardware_counter: HardwareCounterCell,
     ) -> OperationResult<Self> {
         let raw_scorer = match quantized_vectors {
-            Some(quantized_vectors) => quantized_vectors.raw_scorer(query, hardware_counter)?,
-            None => new_raw_scorer(query, vectors, hardware_counter)?,
+            Some(quantized_vectors) => quantized_vectors.raw_scorer_with_comparator(
+                query,
+                multivector_comparator,
+                hardware_counter,
+            )?,
+            None => new_raw_scorer_with_comparator(
+                query,
+                vectors,
+                multivector_comparator,
+                hardware_counter,
+            )?,
         };
         Ok(FilteredScorer {
             raw_scorer,
@@ -138,6 +151,29 @@ impl<'a> FilteredScorer<'a> {
         })
     }
 
+    /// Create a new filtered scorer, which scores the query against the first dimensions of
+    /// stored vectors, see [`crate::types::VectorDataConfig::matryoshka_dim`].
+    ///
+    /// Quantized vectors can't score the first dimensions only, so original vectors are used.
+    pub fn new_matryoshka(
+        query: QueryVector,
+        vectors: &'a VectorStorageEnum,
+        filter_context: Option<BoxCow<'a, dyn FilterContext + 'a>>,
+        point_deleted: &'a BitSlice,
+        hardware_counter: HardwareCounterCell,
+    ) -> OperationResult<Self> {
+        let raw_scorer = new_matryoshka_raw_scorer(query, vectors, hardware_counter)?;
+        Ok(FilteredScorer {
+            raw_scorer,
+            filters: ScorerFilters {
+                filter_context,
+                point_deleted,
+                vec_deleted: vectors.deleted_vector_bitslice(),
+            },
+            scores_buffer: Vec::new(),
+        })
+    }
+
     pub fn new_internal(
         point_id: PointOffsetType,
         vectors: &'a VectorStorageEnum,
@@ -269,12 +305,16 @@ struct BatchSearch<'a> {
 pub struct BatchFilteredSearcher<'a> {
     scorer_batch: SmallVec<[BatchSearch<'a>; 1]>,
     filters: ScorerFilters<'a>,
+    /// Points with higher scores are skipped
+    max_score: Option<ScoreType>,
 }
 
 impl<'a> BatchFilteredSearcher<'a> {
     /// Create a new batch filtered searcher.
     ///
     /// If present, `quantized_vectors` will be used for scoring, otherwise `vectors` will be used.
+    /// If set, `multivector_comparator` overrides the configured comparator of multivectors.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         queries: &[&QueryVector],
         vectors: &'a VectorStorageEnum,
@@ -282,6 +322,7 @@ impl<'a> BatchFilteredSearcher<'a> {
         filter_context: Option<BoxCow<'a, dyn FilterContext + 'a>>,
         top: usize,
         point_deleted: &'a BitSlice,
+        multivector_comparator: Option<MultiVectorComparator>,
         hardware_counter: HardwareCounterCell,
     ) -> OperationResult<Self> {
         let scorer_batch = queries
@@ -290,10 +331,17 @@ impl<'a> BatchFilteredSearcher<'a> {
                 let query = query.to_owned();
                 let hardware_counter = hardware_counter.fork();
                 let raw_scorer = match quantized_vectors {
-                    Some(quantized_vectors) => {
-                        quantized_vectors.raw_scorer(query, hardware_counter)
-                    }
-                    None => new_raw_scorer(query, vectors, hardware_counter),
+                    Some(quantized_vectors) => quantized_vectors.raw_scorer_with_comparator(
+                        query,
+                        multivector_comparator,
+                        hardware_counter,
+                    ),
+                    None => new_raw_scorer_with_comparator(
+                        query,
+                        vectors,
+                        multivector_comparator,
+                        hardware_counter,
+                    ),
                 };
                 let pq = FixedLengthPriorityQueue::new(top);
                 raw_scorer.map(|raw_scorer| BatchSearch { raw_scorer, pq })
@@ -307,9 +355,47 @@ impl<'a> BatchFilteredSearcher<'a> {
         Ok(Self {
             scorer_batch,
             filters,
+            max_score: None,
         })
     }
 
+    /// Create a new batch filtered searcher, which scores the queries against the first
+    /// dimensions of stored vectors, see [`crate::types::VectorDataConfig::matryoshka_dim`].
+    pub fn new_matryoshka(
+        queries: &[&QueryVector],
+        vectors: &'a VectorStorageEnum,
+        filter_context: Option<BoxCow<'a, dyn FilterContext + 'a>>,
+        top: usize,
+        point_deleted: &'a BitSlice,
+        hardware_counter: HardwareCounterCell,
+    ) -> OperationResult<Self> {
+        let scorer_batch = queries
+            .iter()
+            .map(|&query| {
+                let raw_scorer =
+                    new_matryoshka_raw_scorer(query.to_owned(), vectors, hardware_counter.fork())?;
+                let pq = FixedLengthPriorityQueue::new(top);
+                Ok(BatchSearch { raw_scorer, pq })
+            })
+            .collect::<OperationResult<_>>()?;
+        let filters = ScorerFilters {
+            filter_context,
+            point_deleted,
+            vec_deleted: vectors.deleted_vector_bitslice(),
+        };
+        Ok(Self {
+            scorer_batch,
+            filters,
+            max_score: None,
+        })
+    }
+
+    /// Skip points with scores higher than `max_score`, e.g. to continue a radius search.
+    pub fn with_max_score(mut self, max_score: Option<ScoreType>) -> Self {
+        self.max_score = max_score;
+        self
+    }
+
     /// Create a new batched filtered searcher for testing purposes.
     ///
     /// # Panics
@@ -344,6 +430,7 @@ impl<'a> BatchFilteredSearcher<'a> {
                 point_deleted,
                 vec_deleted: vector_storage.deleted_vector_bitslice(),
             },
+            max_score: None,
         }
     }
 
@@ -414,6 +501,9 @@ impl<'a> BatchFilteredSearcher<'a> {
                 raw_scorer.score_points(&chunk[..chunk_size], &mut scores_buffer[..chunk_size]);
 
                 for i in 0..chunk_size {
+                    if self.max_score.is_some_and(|max| scores_buffer[i] > max) {
+                        continue;
+                    }
                     pq.push(ScoredPointOffset {
                         idx: chunk[i],
                         score: scores_buffer[i],
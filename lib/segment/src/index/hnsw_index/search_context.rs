@@ -38,4 +38,18 @@ impl SearchContext {
             self.candidates.push(score_point);
         }
     }
+
+    /// Like [`SearchContext::process_candidate`], but a point with score above `max_score` is
+    /// only added to candidates, so it is expanded without being counted as found.
+    pub fn process_candidate_below(
+        &mut self,
+        score_point: ScoredPointOffset,
+        max_score: ScoreType,
+    ) {
+        if score_point.score > max_score {
+            self.candidates.push(score_point);
+        } else {
+            self.process_candidate(score_point);
+        }
+    }
 }
@@ -76,6 +76,7 @@ fn test_graph_connectivity() {
             quantized_vectors: Default::default(),
             payload_index: payload_index_ptr,
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
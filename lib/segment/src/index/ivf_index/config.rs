@@ -0,0 +1,37 @@
+use std::path::{Path, PathBuf};
+
+use common::fs::{atomic_save_json, read_json};
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+
+pub const IVF_INDEX_CONFIG_FILE: &str = "ivf_config.json";
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+pub struct IvfIndexConfig {
+    /// Number of lists the vectors are partitioned into. Zero if there are no vectors
+    pub num_lists: usize,
+    /// Default number of lists to scan on search
+    pub nprobe: usize,
+    /// Dimensionality of centroids
+    pub dim: usize,
+    /// We prefer a full scan search upto (excluding) this number of vectors.
+    ///
+    /// Note: this is number of vectors, not KiloBytes.
+    pub full_scan_threshold: usize,
+    pub indexed_vector_count: usize,
+}
+
+impl IvfIndexConfig {
+    pub fn get_config_path(path: &Path) -> PathBuf {
+        path.join(IVF_INDEX_CONFIG_FILE)
+    }
+
+    pub fn load(path: &Path) -> OperationResult<Self> {
+        Ok(read_json(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        Ok(atomic_save_json(path, self)?)
+    }
+}
@@ -0,0 +1,225 @@
+//! Centroids and lists of points of the IVF index.
+//!
+//! Both are kept in RAM, and persisted in a single file:
+//!
+//! ```text
+//! [centroids: num_lists x dim x f32] [offsets: (num_lists + 1) x u32] [point ids: num_points x u32]
+//! ```
+//!
+//! Points of list `i` are stored between `offsets[i]` and `offsets[i + 1]`.
+
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use common::types::{PointOffsetType, ScoreType};
+use fs_err as fs;
+use fs_err::File;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::vectors::VectorElementType;
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{DotProductMetric, EuclidMetric};
+use crate::types::Distance;
+
+pub const IVF_LISTS_FILE: &str = "ivf_lists.bin";
+
+#[derive(Debug)]
+pub struct InvertedLists {
+    dim: usize,
+    centroids: Vec<VectorElementType>,
+    offsets: Vec<u32>,
+    point_ids: Vec<PointOffsetType>,
+}
+
+impl InvertedLists {
+    /// Group points by the list they are assigned to
+    pub fn new(
+        dim: usize,
+        centroids: Vec<VectorElementType>,
+        assignments: &[(PointOffsetType, u32)],
+    ) -> Self {
+        let num_lists = centroids.len() / dim;
+
+        let mut offsets = vec![0u32; num_lists + 1];
+        for &(_, list) in assignments {
+            offsets[list as usize + 1] += 1;
+        }
+        for i in 0..num_lists {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let mut positions = offsets.clone();
+        let mut point_ids = vec![0; assignments.len()];
+        for &(point_id, list) in assignments {
+            let position = &mut positions[list as usize];
+            point_ids[*position as usize] = point_id;
+            *position += 1;
+        }
+
+        Self {
+            dim,
+            centroids,
+            offsets,
+            point_ids,
+        }
+    }
+
+    pub fn get_path(path: &Path) -> PathBuf {
+        path.join(IVF_LISTS_FILE)
+    }
+
+    pub fn num_lists(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn list(&self, list: usize) -> &[PointOffsetType] {
+        &self.point_ids[self.offsets[list] as usize..self.offsets[list + 1] as usize]
+    }
+
+    pub fn into_centroids(self) -> Vec<VectorElementType> {
+        self.centroids
+    }
+
+    fn centroid(&self, list: usize) -> &[VectorElementType] {
+        &self.centroids[list * self.dim..(list + 1) * self.dim]
+    }
+
+    /// Index of the list with the closest centroid to the stored `vector`.
+    ///
+    /// Centroids are scored the same way as for probing, so that points are found in the lists
+    /// their queries scan.
+    pub fn nearest_list(&self, vector: &[VectorElementType], distance: Distance) -> u32 {
+        (0..self.num_lists())
+            .map(|list| (list, centroid_score(distance, vector, self.centroid(list))))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(0, |(list, _)| list as u32)
+    }
+
+    /// Indices of up to `count` lists with the closest centroids to the preprocessed `query`
+    pub fn nearest_lists(
+        &self,
+        query: &[VectorElementType],
+        distance: Distance,
+        count: usize,
+    ) -> Vec<usize> {
+        let mut scores: Vec<(usize, ScoreType)> = (0..self.num_lists())
+            .map(|list| (list, centroid_score(distance, query, self.centroid(list))))
+            .collect();
+        let count = count.min(scores.len());
+        if count == 0 {
+            return Vec::new();
+        }
+        scores.select_nth_unstable_by(count - 1, |(_, a), (_, b)| b.total_cmp(a));
+        scores.truncate(count);
+        scores.sort_unstable_by(|(_, a), (_, b)| b.total_cmp(a));
+        scores.into_iter().map(|(list, _)| list).collect()
+    }
+
+    /// Size of centroids and lists in RAM
+    pub fn ram_usage_bytes(&self) -> usize {
+        size_of_val(self.centroids.as_slice())
+            + size_of_val(self.offsets.as_slice())
+            + size_of_val(self.point_ids.as_slice())
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(bytemuck::cast_slice(&self.centroids))?;
+        writer.write_all(bytemuck::cast_slice(&self.offsets))?;
+        writer.write_all(bytemuck::cast_slice(&self.point_ids))?;
+        let file = writer.into_inner().map_err(|err| err.into_error())?;
+        file.sync_all()?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path, dim: usize, num_lists: usize) -> OperationResult<Self> {
+        let data = fs::read(path)?;
+
+        let centroids_size = num_lists * dim * size_of::<VectorElementType>();
+        let offsets_size = (num_lists + 1) * size_of::<u32>();
+        if data.len() < centroids_size + offsets_size {
+            return Err(OperationError::service_error(format!(
+                "IVF lists file {} is truncated",
+                path.display(),
+            )));
+        }
+        let (centroids, rest) = data.split_at(centroids_size);
+        let (offsets, point_ids) = rest.split_at(offsets_size);
+
+        let offsets: Vec<u32> = bytemuck::pod_collect_to_vec(offsets);
+        let point_ids: Vec<PointOffsetType> = bytemuck::pod_collect_to_vec(point_ids);
+        if offsets.last().copied() != Some(point_ids.len() as u32) {
+            return Err(OperationError::service_error(format!(
+                "IVF lists file {} is inconsistent",
+                path.display(),
+            )));
+        }
+
+        Ok(Self {
+            dim,
+            centroids: bytemuck::pod_collect_to_vec(centroids),
+            offsets,
+            point_ids,
+        })
+    }
+}
+
+/// Score of the centroid for a query or a stored vector, to select the lists to scan or to assign
+fn centroid_score(
+    distance: Distance,
+    query: &[VectorElementType],
+    centroid: &[VectorElementType],
+) -> ScoreType {
+    match distance {
+        // Inner product doesn't follow the euclidean partitioning, so points are assigned to
+        // and found in the lists with the largest product of centroids
+        Distance::Dot => DotProductMetric::similarity(query, centroid),
+        // Stored cosine vectors are normalized, so euclidean distance ranks them the same way
+        Distance::Cosine
+        | Distance::Euclid
+        | Distance::Manhattan
+        | Distance::Hamming
+        | Distance::Jaccard => EuclidMetric::similarity(query, centroid),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::Builder;
+
+    use super::*;
+
+    #[test]
+    fn test_inverted_lists() {
+        let centroids = vec![0.0, 0.0, 10.0, 10.0, -10.0, 10.0];
+        let assignments = [(0, 1), (1, 0), (2, 1), (3, 2), (4, 1)];
+        let lists = InvertedLists::new(2, centroids, &assignments);
+
+        assert_eq!(lists.num_lists(), 3);
+        assert_eq!(lists.list(0), &[1]);
+        assert_eq!(lists.list(1), &[0, 2, 4]);
+        assert_eq!(lists.list(2), &[3]);
+
+        assert_eq!(lists.nearest_list(&[9.0, 8.0], Distance::Euclid), 1);
+        // Points are assigned by the same score the lists are probed with
+        assert_eq!(lists.nearest_list(&[1.0, 1.0], Distance::Euclid), 0);
+        assert_eq!(lists.nearest_list(&[1.0, 1.0], Distance::Dot), 1);
+        assert_eq!(lists.nearest_lists(&[1.0, 1.0], Distance::Dot, 1), vec![1]);
+        assert_eq!(
+            lists.nearest_lists(&[-9.0, 8.0], Distance::Euclid, 2),
+            vec![2, 0],
+        );
+        assert_eq!(
+            lists.nearest_lists(&[1.0, 1.0], Distance::Euclid, 5),
+            vec![0, 1, 2],
+        );
+
+        let dir = Builder::new().prefix("ivf_lists").tempdir().unwrap();
+        let path = InvertedLists::get_path(dir.path());
+        lists.save(&path).unwrap();
+        let loaded = InvertedLists::load(&path, 2, 3).unwrap();
+        assert_eq!(loaded.centroids, lists.centroids);
+        assert_eq!(loaded.offsets, lists.offsets);
+        assert_eq!(loaded.point_ids, lists.point_ids);
+    }
+}
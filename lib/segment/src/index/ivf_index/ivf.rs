@@ -0,0 +1,652 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::cow::BoxCow;
+use common::generic_consts::Random;
+use common::types::{DeferredBehavior, PointOffsetType, ScoredPointOffset, TelemetryDetail};
+use fs_err as fs;
+use log::debug;
+use parking_lot::Mutex;
+use quantization::encoded_vectors_pq::{KMEANS_ACCURACY, KMEANS_MAX_ITERATIONS};
+use quantization::kmeans::kmeans;
+use rand::Rng;
+use rand::seq::IndexedRandom as _;
+use rayon::prelude::*;
+use sparse::common::types::DimId;
+
+use super::config::IvfIndexConfig;
+use super::inverted_lists::InvertedLists;
+use crate::common::BYTES_IN_KB;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::common::operation_time_statistics::{
+    OperationDurationsAggregator, ScopeDurationMeasurer,
+};
+use crate::data_types::explain::SearchStrategy;
+use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::vectors::{QueryVector, VectorElementType, VectorInternal, VectorRef};
+use crate::id_tracker::{IdTrackerEnum, IdTrackerRead};
+use crate::index::hnsw_index::point_scorer::BatchFilteredSearcher;
+use crate::index::plain_vector_index::PlainVectorIndex;
+use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::sample_estimation::sample_check_cardinality;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::vector_index_search_common::{
+    get_oversampled_top, is_quantized_search, postprocess_search_result,
+};
+use crate::index::{PayloadIndexRead, VectorIndex, VectorIndexRead};
+use crate::segment_constructor::VectorIndexBuildArgs;
+use crate::telemetry::VectorIndexSearchesTelemetry;
+use crate::types::{Distance, Filter, IvfConfig, QuantizationSearchParams, SearchParams};
+use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
+use crate::vector_storage::{VectorStorageEnum, VectorStorageRead};
+
+/// Number of sampled vectors per list to train centroids on
+const KMEANS_SAMPLE_SIZE_PER_LIST: usize = 64;
+
+pub struct IvfIndexOpenArgs<'a> {
+    pub path: &'a Path,
+    pub id_tracker: Arc<AtomicRefCell<IdTrackerEnum>>,
+    pub vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    pub quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    pub ivf_config: IvfConfig,
+}
+
+#[derive(Debug)]
+struct IvfSearchesTelemetry {
+    unfiltered_plain: Arc<Mutex<OperationDurationsAggregator>>,
+    filtered_plain: Arc<Mutex<OperationDurationsAggregator>>,
+    unfiltered_lists: Arc<Mutex<OperationDurationsAggregator>>,
+    small_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    large_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl IvfSearchesTelemetry {
+    fn new() -> Self {
+        Self {
+            unfiltered_plain: OperationDurationsAggregator::new(),
+            filtered_plain: OperationDurationsAggregator::new(),
+            unfiltered_lists: OperationDurationsAggregator::new(),
+            small_cardinality: OperationDurationsAggregator::new(),
+            large_cardinality: OperationDurationsAggregator::new(),
+            exact_filtered: OperationDurationsAggregator::new(),
+            exact_unfiltered: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
+/// Inverted file index.
+///
+/// Vectors are partitioned into lists around centroids found with k-means. Only the lists with
+/// the closest centroids to the query are scanned, so the index is cheap to build and small,
+/// while a search scores more vectors than a graph search would.
+#[derive(Debug)]
+pub struct IvfIndex {
+    id_tracker: Arc<AtomicRefCell<IdTrackerEnum>>,
+    vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    config: IvfIndexConfig,
+    path: PathBuf,
+    distance: Distance,
+    /// None if there are no indexed points
+    lists: Option<InvertedLists>,
+    /// Used for exact searches, small filters and queries the lists can't serve
+    plain_index: PlainVectorIndex,
+    payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    searches_telemetry: IvfSearchesTelemetry,
+}
+
+impl IvfIndex {
+    pub fn open(args: IvfIndexOpenArgs<'_>) -> OperationResult<Self> {
+        let IvfIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            ivf_config: _,
+        } = args;
+
+        let config_path = IvfIndexConfig::get_config_path(path);
+        if !config_path.exists() {
+            return Err(OperationError::service_error(format!(
+                "IVF index config not found at {}",
+                config_path.display(),
+            )));
+        }
+        let config = IvfIndexConfig::load(&config_path)?;
+
+        let lists = if config.num_lists > 0 {
+            Some(InvertedLists::load(
+                &InvertedLists::get_path(path),
+                config.dim,
+                config.num_lists,
+            )?)
+        } else {
+            None
+        };
+
+        let distance = vector_storage.borrow().distance();
+        let plain_index = PlainVectorIndex::new(
+            id_tracker.clone(),
+            vector_storage.clone(),
+            quantized_vectors.clone(),
+            payload_index.clone(),
+        );
+
+        Ok(Self {
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            config,
+            path: path.to_owned(),
+            distance,
+            lists,
+            plain_index,
+            payload_index,
+            searches_telemetry: IvfSearchesTelemetry::new(),
+        })
+    }
+
+    pub fn build<R: Rng + ?Sized>(
+        open_args: IvfIndexOpenArgs<'_>,
+        build_args: VectorIndexBuildArgs<'_, R>,
+    ) -> OperationResult<Self> {
+        if IvfIndexConfig::get_config_path(open_args.path).exists() {
+            log::warn!(
+                "IVF index already exists at {:?}, skipping building",
+                open_args.path
+            );
+            debug_assert!(false);
+            return Self::open(open_args);
+        }
+
+        let VectorIndexBuildArgs {
+            permit,
+            old_indices: _,
+            gpu_device: _,
+            rng,
+            stopped,
+            hnsw_global_config: _,
+            feature_flags: _,
+            progress: _,
+        } = build_args;
+
+        let path = open_args.path;
+        let ivf_config = open_args.ivf_config;
+        fs::create_dir_all(path)?;
+
+        {
+            let id_tracker = open_args.id_tracker.borrow();
+            let vector_storage = open_args.vector_storage.borrow();
+
+            let Some(dim) = vector_storage.try_dense_vector_dim() else {
+                return Err(OperationError::service_error(
+                    "IVF index can only be built on dense vectors",
+                ));
+            };
+            let total_vector_count = vector_storage.total_vector_count();
+
+            let full_scan_threshold = vector_storage
+                .size_of_available_vectors_in_bytes()
+                .checked_div(total_vector_count)
+                .and_then(|avg_vector_size| {
+                    ivf_config
+                        .full_scan_threshold
+                        .saturating_mul(BYTES_IN_KB)
+                        .checked_div(avg_vector_size)
+                })
+                .unwrap_or(1);
+
+            let points: Vec<PointOffsetType> = id_tracker
+                .point_mappings()
+                .iter_internal_excluding(vector_storage.deleted_vector_bitslice())
+                .collect();
+
+            let num_lists = if points.is_empty() {
+                0
+            } else {
+                ivf_config
+                    .num_lists
+                    .unwrap_or_else(|| (points.len() as f64).sqrt().round() as usize)
+                    .clamp(1, points.len())
+            };
+
+            debug!(
+                "building IVF index with {num_lists} lists for {} vectors with {} CPUs",
+                points.len(),
+                permit.num_cpus,
+            );
+
+            if num_lists > 0 {
+                // Sampled points are in random order, so k-means starts from random centroids
+                let sample_size = num_lists.saturating_mul(KMEANS_SAMPLE_SIZE_PER_LIST);
+                let mut sample = Vec::with_capacity(sample_size.min(points.len()) * dim);
+                for &point_id in points.sample(rng, sample_size) {
+                    check_process_stopped(stopped)?;
+                    sample.extend_from_slice(&stored_dense_vector(&vector_storage, point_id)?);
+                }
+
+                let centroids = kmeans(
+                    &sample,
+                    num_lists,
+                    dim,
+                    KMEANS_MAX_ITERATIONS,
+                    permit.num_cpus as usize,
+                    KMEANS_ACCURACY,
+                    stopped,
+                )?;
+                drop(sample);
+
+                let distance = vector_storage.distance();
+                let lists = InvertedLists::new(dim, centroids, &[]);
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .thread_name(|idx| format!("ivf-build-{idx}"))
+                    .num_threads(permit.num_cpus as usize)
+                    .build()?;
+                let assignments = pool.install(|| {
+                    points
+                        .par_iter()
+                        .map(|&point_id| {
+                            check_process_stopped(stopped)?;
+                            let vector = stored_dense_vector(&vector_storage, point_id)?;
+                            Ok((point_id, lists.nearest_list(&vector, distance)))
+                        })
+                        .collect::<OperationResult<Vec<_>>>()
+                })?;
+
+                let lists = InvertedLists::new(dim, lists.into_centroids(), &assignments);
+                lists.save(&InvertedLists::get_path(path))?;
+            }
+
+            let config = IvfIndexConfig {
+                num_lists,
+                nprobe: ivf_config.nprobe,
+                dim,
+                full_scan_threshold,
+                indexed_vector_count: points.len(),
+            };
+            config.save(&IvfIndexConfig::get_config_path(path))?;
+        }
+
+        Self::open(open_args)
+    }
+
+    pub fn get_quantized_vectors(&self) -> Arc<AtomicRefCell<Option<QuantizedVectors>>> {
+        self.quantized_vectors.clone()
+    }
+
+    /// Size of centroids and lists, which are kept in RAM
+    pub fn ram_usage_bytes(&self) -> usize {
+        self.lists
+            .as_ref()
+            .map_or(0, |lists| lists.ram_usage_bytes())
+    }
+
+    /// Plain search over all vectors, never restricted to indexed segments
+    fn search_plain(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let params = params.map(|params| SearchParams {
+            indexed_only: false,
+            ..*params
+        });
+        self.plain_index
+            .search(vectors, filter, top, params.as_ref(), query_context)
+    }
+
+    fn search_vectors_with_lists(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        vectors
+            .iter()
+            .map(|&vector| {
+                let result = match vector {
+                    QueryVector::Nearest(VectorInternal::Dense(dense))
+                        if dense.len() == self.config.dim =>
+                    {
+                        self.search_with_lists(vector, dense, filter, top, params, query_context)?
+                    }
+                    // Centroids only tell which lists are close to a full-sized query vector
+                    QueryVector::Nearest(_)
+                    | QueryVector::RecommendBestScore(_)
+                    | QueryVector::RecommendSumScores(_)
+                    | QueryVector::Discover(_)
+                    | QueryVector::Context(_)
+                    | QueryVector::FeedbackNaive(_) => Vec::new(),
+                };
+                // Scanned lists might not contain enough points matching the filter
+                if result.len() < top {
+                    let mut plain_result =
+                        self.search_plain(&[vector], filter, top, params, query_context)?;
+                    return Ok(plain_result.pop().unwrap_or_default());
+                }
+                Ok(result)
+            })
+            .collect()
+    }
+
+    /// Score all points of `nprobe` lists with the closest centroids to the query
+    fn search_with_lists(
+        &self,
+        query_vector: &QueryVector,
+        query: &[VectorElementType],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let Some(lists) = &self.lists else {
+            return Ok(Vec::new());
+        };
+        query_context.record_search_strategy(SearchStrategy::Ivf);
+
+        let nprobe = params
+            .and_then(|params| params.ivf_nprobe)
+            .unwrap_or(self.config.nprobe);
+        let is_stopped = query_context.is_stopped();
+        let hw_counter = query_context.hardware_counter();
+
+        let query = self
+            .distance
+            .preprocess_vector::<VectorElementType>(query.to_vec());
+        let probed_lists = lists.nearest_lists(&query, self.distance, nprobe);
+        hw_counter
+            .cpu_counter()
+            .incr_delta(lists.num_lists() * self.config.dim);
+
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let quantized_storage = self.quantized_vectors.borrow();
+        let deleted_points = query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+        let quantization_enabled = is_quantized_search(quantized_storage.as_ref(), params);
+        let quantized_vectors = quantization_enabled
+            .then_some(quantized_storage.as_ref())
+            .flatten();
+        let oversampled_top = get_oversampled_top(quantized_storage.as_ref(), params, top);
+
+        let payload_index = self.payload_index.borrow();
+        let search_result = payload_index.with_view(|view| {
+            let filter_context = filter
+                .map(|filter| view.filter_context(filter, &hw_counter))
+                .transpose()?;
+            let searcher = BatchFilteredSearcher::new(
+                &[query_vector],
+                &vector_storage,
+                quantized_vectors,
+                filter_context.map(BoxCow::Owned),
+                oversampled_top,
+                deleted_points,
+                params.and_then(|params| params.multivector_comparator),
+                query_context.hardware_counter(),
+            )?;
+            let points = probed_lists
+                .iter()
+                .flat_map(|&list| lists.list(list).iter().copied());
+            let points = id_tracker
+                .point_mappings()
+                .filter_deferred(points, DeferredBehavior::Exclude);
+            searcher.peek_top_iter(points, &is_stopped)
+        })?;
+
+        postprocess_search_result(
+            search_result.into_iter().next().unwrap_or_default(),
+            deleted_points,
+            &vector_storage,
+            quantized_storage.as_ref(),
+            query_vector,
+            params,
+            top,
+            query_context.hardware_counter(),
+        )
+    }
+
+    /// Whether a search without filter should use the lists, rather than score all points
+    fn is_lists_search_unfiltered(&self) -> bool {
+        self.lists.is_some()
+            && self.vector_storage.borrow().available_vector_count()
+                >= self.config.full_scan_threshold
+    }
+
+    /// Whether a filtered search should use the lists, rather than score all matching points
+    fn is_lists_search_filtered(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<bool> {
+        if self.lists.is_none() {
+            return Ok(false);
+        }
+
+        let payload_index = self.payload_index.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let available_vector_count = vector_storage.available_vector_count();
+
+        let query_point_cardinality =
+            payload_index.with_view(|v| v.estimate_cardinality(filter, hw_counter))?;
+        let query_cardinality = adjust_to_available_vectors(
+            query_point_cardinality,
+            available_vector_count,
+            id_tracker.available_point_count(),
+        );
+
+        if query_cardinality.max < self.config.full_scan_threshold {
+            Ok(false)
+        } else if query_cardinality.min > self.config.full_scan_threshold {
+            Ok(true)
+        } else {
+            payload_index.with_view(|v| {
+                let filter_context = v.filter_context(filter, hw_counter)?;
+                Ok::<_, OperationError>(sample_check_cardinality(
+                    id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
+                    |idx| filter_context.check(idx),
+                    self.config.full_scan_threshold,
+                    available_vector_count,
+                ))
+            })
+        }
+    }
+}
+
+fn stored_dense_vector(
+    vector_storage: &VectorStorageEnum,
+    point_id: PointOffsetType,
+) -> OperationResult<Vec<VectorElementType>> {
+    let vector = vector_storage.get_vector::<Random>(point_id);
+    match vector.as_vec_ref() {
+        VectorRef::Dense(vector) => Ok(vector.to_vec()),
+        VectorRef::Sparse(_) | VectorRef::MultiDense(_) => Err(OperationError::service_error(
+            "IVF index can only be built on dense vectors",
+        )),
+    }
+}
+
+impl VectorIndexRead for IvfIndex {
+    fn search(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        if top == 0 {
+            return Ok(vec![vec![]; vectors.len()]);
+        }
+
+        let exact = params.is_some_and(|params| params.exact);
+        if exact {
+            let _timer = ScopeDurationMeasurer::new(if filter.is_some() {
+                &self.searches_telemetry.exact_filtered
+            } else {
+                &self.searches_telemetry.exact_unfiltered
+            });
+            let exact_params = params.map(|params| {
+                let mut params = *params;
+                params.quantization = Some(QuantizationSearchParams {
+                    ignore: true,
+                    rescore: Some(false),
+                    oversampling: None,
+                }); // disable quantization for exact search
+                params
+            });
+            return self.search_plain(vectors, filter, top, exact_params.as_ref(), query_context);
+        }
+
+        // Lists are built on full vectors, so the first dimensions of vectors are searched plainly
+        if params.is_some_and(|params| params.matryoshka) {
+            let _timer = ScopeDurationMeasurer::new(if filter.is_some() {
+                &self.searches_telemetry.filtered_plain
+            } else {
+                &self.searches_telemetry.unfiltered_plain
+            });
+            return self.search_plain(vectors, filter, top, params, query_context);
+        }
+
+        match filter {
+            None => {
+                let plain_search = !self.is_lists_search_unfiltered();
+
+                if plain_search {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_plain);
+                    self.search_plain(vectors, None, top, params, query_context)
+                } else {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_lists);
+                    self.search_vectors_with_lists(vectors, None, top, params, query_context)
+                }
+            }
+            Some(query_filter) => {
+                if self.lists.is_none() {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_plain);
+                    return self.search_plain(vectors, filter, top, params, query_context);
+                }
+
+                let hw_counter = query_context.hardware_counter();
+
+                if self.is_lists_search_filtered(query_filter, &hw_counter)? {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
+                    self.search_vectors_with_lists(vectors, filter, top, params, query_context)
+                } else {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
+                    self.search_plain(vectors, filter, top, params, query_context)
+                }
+            }
+        }
+    }
+
+    fn search_strategy(
+        &self,
+        _vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SearchStrategy> {
+        if params.is_some_and(|params| params.exact) {
+            return Ok(SearchStrategy::Exact);
+        }
+        if params.is_some_and(|params| params.matryoshka) {
+            return Ok(SearchStrategy::FullScan);
+        }
+
+        let lists_search = match filter {
+            None => self.is_lists_search_unfiltered(),
+            Some(filter) => self.is_lists_search_filtered(filter, hw_counter)?,
+        };
+
+        if lists_search {
+            Ok(SearchStrategy::Ivf)
+        } else {
+            Ok(SearchStrategy::FullScan)
+        }
+    }
+
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+        let tm = &self.searches_telemetry;
+        VectorIndexSearchesTelemetry {
+            index_name: None,
+            unfiltered_plain: tm.unfiltered_plain.lock().get_statistics(detail),
+            filtered_plain: tm.filtered_plain.lock().get_statistics(detail),
+            unfiltered_hnsw: tm.unfiltered_lists.lock().get_statistics(detail),
+            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
+            filtered_large_cardinality: tm.large_cardinality.lock().get_statistics(detail),
+            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
+            filtered_sparse: Default::default(),
+            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
+            unfiltered_sparse: Default::default(),
+        }
+    }
+
+    fn indexed_vector_count(&self) -> usize {
+        self.config.indexed_vector_count
+    }
+
+    fn size_of_searchable_vectors_in_bytes(&self) -> usize {
+        self.vector_storage
+            .borrow()
+            .size_of_available_vectors_in_bytes()
+    }
+
+    fn fill_idf_statistics(
+        &self,
+        _idf: &mut HashMap<DimId, usize>,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        // IVF (dense) index doesn't track IDF.
+        Ok(())
+    }
+
+    fn is_index(&self) -> bool {
+        true
+    }
+}
+
+impl VectorIndex for IvfIndex {
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        let lists_path = InvertedLists::get_path(&self.path);
+        if lists_path.exists() {
+            files.push(lists_path);
+        }
+        let config_path = IvfIndexConfig::get_config_path(&self.path);
+        if config_path.exists() {
+            files.push(config_path);
+        }
+        files
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        self.files() // All IVF index files are immutable
+    }
+
+    fn update_vector(
+        &mut self,
+        _id: PointOffsetType,
+        _vector: Option<VectorRef>,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        Err(OperationError::service_error("Cannot update IVF index"))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod config;
+pub mod inverted_lists;
+pub mod ivf;
@@ -17,6 +17,26 @@ impl MemoryReporter for VectorIndexEnum {
                 ComponentMemoryUsage::from_files(index.files(), intent)
             }
 
+            // Vamana: graph with full vectors is read from disk, compressed vectors are in RAM
+            VectorIndexEnum::Vamana(index) => {
+                let mut usage = ComponentMemoryUsage::from_files(
+                    index.graph_files(),
+                    FileStorageIntent::OnDisk,
+                );
+                usage.merge(&ComponentMemoryUsage::from_files(
+                    index.pq_files(),
+                    FileStorageIntent::Cached,
+                ));
+                usage
+            }
+
+            // IVF: centroids and lists are read into heap, files are persistence only
+            VectorIndexEnum::Ivf(index) => ComponentMemoryUsage::from_files_and_ram(
+                index.files(),
+                FileStorageIntent::OnDisk,
+                index.ram_usage_bytes() as u64,
+            ),
+
             // Sparse RAM variants: inverted index is deserialized into heap.
             // Files are persistence only (OnDisk), actual RAM is extra_ram_bytes.
             VectorIndexEnum::SparseRam(index) => ComponentMemoryUsage::from_files_and_ram(
@@ -1,5 +1,6 @@
 pub mod field_index;
 pub mod hnsw_index;
+pub mod ivf_index;
 mod key_encoding;
 mod memory_reporter;
 pub mod payload_config;
@@ -13,6 +14,7 @@ mod sample_estimation;
 pub mod sparse_index;
 mod struct_filter_context;
 pub mod struct_payload_index;
+pub mod vamana_index;
 pub mod vector_index_base;
 mod vector_index_search_common;
 mod visited_pool;
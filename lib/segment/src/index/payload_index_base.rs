@@ -76,7 +76,7 @@ pub trait PayloadIndexRead {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<Box<dyn FilterContext + 'a>>;
 
-    /// Look up a numeric index for the given payload key, if one exists.
+    /// Look up a numeric or keyword index for the given payload key, if one exists.
     ///
     /// Used by ordered reads to stream values from the index in sort order.
     /// The concrete numeric-index type is opaque so each implementation can
@@ -14,12 +14,13 @@ use crate::common::operation_error::OperationResult;
 use crate::common::operation_time_statistics::{
     OperationDurationStatistics, OperationDurationsAggregator, ScopeDurationMeasurer,
 };
+use crate::data_types::explain::SearchStrategy;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::id_tracker::{IdTrackerEnum, IdTrackerRead};
 use crate::index::struct_payload_index::StructPayloadIndex;
 use crate::index::vector_index_search_common::{
-    get_oversampled_top, is_quantized_search, postprocess_search_result,
+    get_oversampled_top, is_quantized_search, max_score, postprocess_search_result,
 };
 use crate::index::{PayloadIndexRead, VectorIndex, VectorIndexRead};
 use crate::telemetry::VectorIndexSearchesTelemetry;
@@ -107,6 +108,8 @@ impl VectorIndexRead for PlainVectorIndex {
             return Ok(vec![vec![]; query_vectors.len()]);
         }
 
+        query_context.record_search_strategy(SearchStrategy::plain(params));
+
         let is_stopped = query_context.is_stopped();
 
         let hw_counter = query_context.hardware_counter();
@@ -127,15 +130,28 @@ impl VectorIndexRead for PlainVectorIndex {
             .then_some(quantized_storage.as_ref())
             .flatten();
         let oversampled_top = get_oversampled_top(quantized_storage.as_ref(), params, top);
-        let batch_searcher = BatchFilteredSearcher::new(
-            query_vectors,
-            &vector_storage,
-            quantized_vectors,
-            None,
-            oversampled_top,
-            deleted_points,
-            query_context.hardware_counter(),
-        )?;
+        let batch_searcher = if params.is_some_and(|params| params.matryoshka) {
+            BatchFilteredSearcher::new_matryoshka(
+                query_vectors,
+                &vector_storage,
+                None,
+                oversampled_top,
+                deleted_points,
+                query_context.hardware_counter(),
+            )?
+        } else {
+            BatchFilteredSearcher::new(
+                query_vectors,
+                &vector_storage,
+                quantized_vectors,
+                None,
+                oversampled_top,
+                deleted_points,
+                params.and_then(|params| params.multivector_comparator),
+                query_context.hardware_counter(),
+            )?
+        }
+        .with_max_score(max_score(query_vectors, params, vector_storage.distance()));
 
         let mut search_results = match filter {
             Some(filter) => {
@@ -168,6 +184,16 @@ impl VectorIndexRead for PlainVectorIndex {
         Ok(search_results)
     }
 
+    fn search_strategy(
+        &self,
+        _vector: &QueryVector,
+        _filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SearchStrategy> {
+        Ok(SearchStrategy::plain(params))
+    }
+
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
         VectorIndexSearchesTelemetry {
             index_name: None,
@@ -219,16 +219,23 @@ pub fn combine_must_estimations(
     }
 }
 
-fn estimate_condition<F>(
+/// Estimation of several `must` conditions at once, with positions of the covered conditions
+pub type GroupEstimation = Option<(CardinalityEstimation, Vec<usize>)>;
+
+fn estimate_condition<F, G>(
     estimator: &F,
+    group_estimator: &G,
     condition: &Condition,
     total: usize,
 ) -> OperationResult<CardinalityEstimation>
 where
     F: Fn(&Condition) -> OperationResult<CardinalityEstimation>,
+    G: Fn(&[Condition]) -> OperationResult<GroupEstimation>,
 {
     match condition {
-        Condition::Filter(filter) => estimate_filter(estimator, filter, total),
+        Condition::Filter(filter) => {
+            estimate_filter_grouped(estimator, group_estimator, filter, total)
+        }
         Condition::Field(_)
         | Condition::IsEmpty(_)
         | Condition::IsNull(_)
@@ -246,18 +253,46 @@ pub fn estimate_filter<F>(
 ) -> OperationResult<CardinalityEstimation>
 where
     F: Fn(&Condition) -> OperationResult<CardinalityEstimation>,
+{
+    let no_groups = |_: &[Condition]| -> OperationResult<GroupEstimation> { Ok(None) };
+    estimate_filter_grouped(estimator, &no_groups, filter, total)
+}
+
+/// Same as [`estimate_filter`], but `group_estimator` may estimate several conditions
+/// of a `must` clause at once, e.g. with a composite index.
+///
+/// Conditions covered by the group estimation are not estimated individually.
+pub fn estimate_filter_grouped<F, G>(
+    estimator: &F,
+    group_estimator: &G,
+    filter: &Filter,
+    total: usize,
+) -> OperationResult<CardinalityEstimation>
+where
+    F: Fn(&Condition) -> OperationResult<CardinalityEstimation>,
+    G: Fn(&[Condition]) -> OperationResult<GroupEstimation>,
 {
     let mut filter_estimations: Vec<CardinalityEstimation> = vec![];
 
     match &filter.must {
         Some(conditions) if !conditions.is_empty() => {
-            filter_estimations.push(estimate_must(estimator, conditions, total)?);
+            filter_estimations.push(estimate_must(
+                estimator,
+                group_estimator,
+                conditions,
+                total,
+            )?);
         }
         Some(_) | None => {}
     }
     match &filter.should {
         Some(conditions) if !conditions.is_empty() => {
-            filter_estimations.push(estimate_should(estimator, conditions, total)?);
+            filter_estimations.push(estimate_should(
+                estimator,
+                group_estimator,
+                conditions,
+                total,
+            )?);
         }
         Some(_) | None => {}
     }
@@ -267,42 +302,53 @@ where
     }) = &filter.min_should
     {
         filter_estimations.push(estimate_min_should(
-            estimator, conditions, *min_count, total,
+            estimator,
+            group_estimator,
+            conditions,
+            *min_count,
+            total,
         )?)
     }
     match &filter.must_not {
-        Some(conditions) if !conditions.is_empty() => {
-            filter_estimations.push(estimate_must_not(estimator, conditions, total)?)
-        }
+        Some(conditions) if !conditions.is_empty() => filter_estimations.push(estimate_must_not(
+            estimator,
+            group_estimator,
+            conditions,
+            total,
+        )?),
         Some(_) | None => {}
     }
 
     Ok(combine_must_estimations(&filter_estimations, total))
 }
 
-fn estimate_should<F>(
+fn estimate_should<F, G>(
     estimator: &F,
+    group_estimator: &G,
     conditions: &[Condition],
     total: usize,
 ) -> OperationResult<CardinalityEstimation>
 where
     F: Fn(&Condition) -> OperationResult<CardinalityEstimation>,
+    G: Fn(&[Condition]) -> OperationResult<GroupEstimation>,
 {
-    let estimate = |x| estimate_condition(estimator, x, total);
+    let estimate = |x| estimate_condition(estimator, group_estimator, x, total);
     let should_estimations: OperationResult<Vec<_>> = conditions.iter().map(estimate).collect();
     Ok(combine_should_estimations(&should_estimations?, total))
 }
 
-fn estimate_min_should<F>(
+fn estimate_min_should<F, G>(
     estimator: &F,
+    group_estimator: &G,
     conditions: &[Condition],
     min_count: usize,
     total: usize,
 ) -> OperationResult<CardinalityEstimation>
 where
     F: Fn(&Condition) -> OperationResult<CardinalityEstimation>,
+    G: Fn(&[Condition]) -> OperationResult<GroupEstimation>,
 {
-    let estimate = |x| estimate_condition(estimator, x, total);
+    let estimate = |x| estimate_condition(estimator, group_estimator, x, total);
     let min_should_estimations: OperationResult<Vec<_>> = conditions.iter().map(estimate).collect();
     Ok(combine_min_should_estimations(
         &min_should_estimations?,
@@ -311,17 +357,31 @@ where
     ))
 }
 
-fn estimate_must<F>(
+fn estimate_must<F, G>(
     estimator: &F,
+    group_estimator: &G,
     conditions: &[Condition],
     total: usize,
 ) -> OperationResult<CardinalityEstimation>
 where
     F: Fn(&Condition) -> OperationResult<CardinalityEstimation>,
+    G: Fn(&[Condition]) -> OperationResult<GroupEstimation>,
 {
-    let estimate = |x| estimate_condition(estimator, x, total);
-    let must_estimations: OperationResult<Vec<_>> = conditions.iter().map(estimate).collect();
-    Ok(combine_must_estimations(&must_estimations?, total))
+    let (mut must_estimations, covered) = match group_estimator(conditions)? {
+        Some((estimation, covered)) => (vec![estimation], covered),
+        None => (vec![], vec![]),
+    };
+    for (position, condition) in conditions.iter().enumerate() {
+        if !covered.contains(&position) {
+            must_estimations.push(estimate_condition(
+                estimator,
+                group_estimator,
+                condition,
+                total,
+            )?);
+        }
+    }
+    Ok(combine_must_estimations(&must_estimations, total))
 }
 
 pub fn invert_estimation(
@@ -336,16 +396,18 @@ pub fn invert_estimation(
     }
 }
 
-fn estimate_must_not<F>(
+fn estimate_must_not<F, G>(
     estimator: &F,
+    group_estimator: &G,
     conditions: &[Condition],
     total: usize,
 ) -> OperationResult<CardinalityEstimation>
 where
     F: Fn(&Condition) -> OperationResult<CardinalityEstimation>,
+    G: Fn(&[Condition]) -> OperationResult<GroupEstimation>,
 {
     let estimate = |x| -> OperationResult<_> {
-        let estimation = estimate_condition(estimator, x, total)?;
+        let estimation = estimate_condition(estimator, group_estimator, x, total)?;
         Ok(invert_estimation(&estimation, total))
     };
     let must_not_estimations: OperationResult<Vec<_>> = conditions.iter().map(estimate).collect();
@@ -477,6 +539,38 @@ mod tests {
         assert!(estimation.min <= estimation.exp);
     }
 
+    #[test]
+    fn grouped_must_estimation_query_test() {
+        let query = Filter {
+            should: None,
+            min_should: None,
+            must: Some(vec![
+                test_condition("color"),
+                test_condition("un-indexed"),
+                test_condition("size"),
+            ]),
+            must_not: None,
+        };
+
+        // Pretend `color` and `size` are covered by a single composite lookup
+        let group_estimator = |conditions: &[Condition]| -> OperationResult<GroupEstimation> {
+            assert_eq!(conditions.len(), 3);
+            let estimation = CardinalityEstimation::exact(5)
+                .with_primary_clause(PrimaryCondition::HasVector("composite".into()));
+            Ok(Some((estimation, vec![0, 2])))
+        };
+
+        let estimation =
+            estimate_filter_grouped(&test_estimator, &group_estimator, &query, TOTAL).unwrap();
+        assert_eq!(
+            estimation.primary_clauses,
+            vec![PrimaryCondition::HasVector("composite".into())],
+        );
+        assert!(estimation.max <= 5);
+        assert!(estimation.min <= estimation.exp);
+        assert!(estimation.exp <= estimation.max);
+    }
+
     #[test]
     fn should_estimation_query_test() {
         let query = Filter {
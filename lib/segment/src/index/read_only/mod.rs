@@ -5,6 +5,7 @@ use common::types::{ScoredPointOffset, TelemetryDetail};
 use sparse::common::types::DimId;
 
 use crate::common::operation_error::OperationResult;
+use crate::data_types::explain::SearchStrategy;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::QueryVector;
 use crate::index::vector_index_base::VectorIndexRead;
@@ -121,6 +122,39 @@ impl VectorIndexRead for VectorIndexReadEnum {
         todo!()
     }
 
+    fn search_strategy(
+        &self,
+        _vector: &QueryVector,
+        _filter: Option<&Filter>,
+        _params: Option<&SearchParams>,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SearchStrategy> {
+        // match self {
+        //     Self::Plain(index) => index.search_strategy(vector, filter, params, hw_counter),
+        //     Self::Hnsw(index) => index.search_strategy(vector, filter, params, hw_counter),
+        //     Self::SparseCompressedImmutableRamF32(index) => {
+        //         index.search_strategy(vector, filter, params, hw_counter)
+        //     }
+        //     Self::SparseCompressedImmutableRamF16(index) => {
+        //         index.search_strategy(vector, filter, params, hw_counter)
+        //     }
+        //     Self::SparseCompressedImmutableRamU8(index) => {
+        //         index.search_strategy(vector, filter, params, hw_counter)
+        //     }
+        //     Self::SparseCompressedMmapF32(index) => {
+        //         index.search_strategy(vector, filter, params, hw_counter)
+        //     }
+        //     Self::SparseCompressedMmapF16(index) => {
+        //         index.search_strategy(vector, filter, params, hw_counter)
+        //     }
+        //     Self::SparseCompressedMmapU8(index) => {
+        //         index.search_strategy(vector, filter, params, hw_counter)
+        //     }
+        // }
+
+        todo!()
+    }
+
     fn get_telemetry_data(&self, _detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
         // match self {
         //     Self::Plain(index) => index.get_telemetry_data(detail),
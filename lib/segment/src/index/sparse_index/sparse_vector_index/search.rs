@@ -8,6 +8,7 @@ use sparse::index::search_context::SearchContext;
 use super::SparseVectorIndex;
 use crate::common::operation_error::OperationResult;
 use crate::common::operation_time_statistics::ScopeDurationMeasurer;
+use crate::data_types::explain::SearchStrategy;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::QueryVector;
 use crate::id_tracker::IdTrackerRead;
@@ -38,6 +39,22 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         ))
     }
 
+    /// Whether a nearest search with the given filter should score all matching points,
+    /// rather than use the inverted index
+    pub(super) fn is_plain_search_filtered(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<bool> {
+        // if cardinality is small - use plain search
+        let query_cardinality = self.get_query_cardinality(filter, hw_counter)?;
+        let threshold = self
+            .config
+            .full_scan_threshold
+            .unwrap_or(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD);
+        Ok(query_cardinality.max < threshold)
+    }
+
     // Search using raw scorer
     pub(super) fn search_scored(
         &self,
@@ -47,6 +64,8 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         prefiltered_points: &mut Option<Vec<PointOffsetType>>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
+        vector_query_context.record_search_strategy(SearchStrategy::FullScan);
+
         let vector_storage = self.vector_storage.borrow();
         let id_tracker = self.id_tracker.borrow();
         let deleted_point_bitslice = vector_query_context
@@ -62,6 +81,7 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
             None,
             top,
             deleted_point_bitslice,
+            None,
             vector_query_context.hardware_counter(),
         )?;
         let hw_counter = vector_query_context.hardware_counter();
@@ -100,6 +120,8 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         prefiltered_points: &mut Option<Vec<PointOffsetType>>,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
+        vector_query_context.record_search_strategy(SearchStrategy::FullScan);
+
         let vector_storage = self.vector_storage.borrow();
         let id_tracker = self.id_tracker.borrow();
 
@@ -160,6 +182,8 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
         top: usize,
         vector_query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<ScoredPointOffset>> {
+        vector_query_context.record_search_strategy(SearchStrategy::SparseIndex);
+
         let vector_storage = self.vector_storage.borrow();
         let id_tracker = self.id_tracker.borrow();
         let deleted_point_bitslice = vector_query_context
@@ -218,14 +242,8 @@ impl<TInvertedIndex: InvertedIndex> SparseVectorIndex<TInvertedIndex> {
 
         match filter {
             Some(filter) => {
-                // if cardinality is small - use plain search
-                let query_cardinality =
-                    self.get_query_cardinality(filter, &vector_query_context.hardware_counter())?;
-                let threshold = self
-                    .config
-                    .full_scan_threshold
-                    .unwrap_or(DEFAULT_SPARSE_FULL_SCAN_THRESHOLD);
-                if query_cardinality.max < threshold {
+                let hw_counter = vector_query_context.hardware_counter();
+                if self.is_plain_search_filtered(filter, &hw_counter)? {
                     let _timer =
                         ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
                     self.search_plain(
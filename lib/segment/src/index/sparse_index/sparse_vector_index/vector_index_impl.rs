@@ -11,6 +11,7 @@ use sparse::index::inverted_index::InvertedIndex;
 
 use super::SparseVectorIndex;
 use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::data_types::explain::SearchStrategy;
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorInternal, VectorRef};
@@ -62,6 +63,29 @@ impl<TInvertedIndex: InvertedIndex> VectorIndexRead for SparseVectorIndex<TInver
         Ok(results)
     }
 
+    fn search_strategy(
+        &self,
+        vector: &QueryVector,
+        filter: Option<&Filter>,
+        _params: Option<&SearchParams>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SearchStrategy> {
+        match vector {
+            QueryVector::Nearest(_) => match filter {
+                Some(filter) if self.is_plain_search_filtered(filter, hw_counter)? => {
+                    Ok(SearchStrategy::FullScan)
+                }
+                Some(_) | None => Ok(SearchStrategy::SparseIndex),
+            },
+            // Other queries score every candidate with the raw scorer
+            QueryVector::RecommendBestScore(_)
+            | QueryVector::RecommendSumScores(_)
+            | QueryVector::Discover(_)
+            | QueryVector::Context(_)
+            | QueryVector::FeedbackNaive(_) => Ok(SearchStrategy::FullScan),
+        }
+    }
+
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
         self.searches_telemetry.get_telemetry_data(detail)
     }
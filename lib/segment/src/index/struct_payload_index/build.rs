@@ -3,8 +3,11 @@ use common::types::PointOffsetType;
 
 use super::StructPayloadIndex;
 use crate::common::operation_error::OperationResult;
+use crate::data_types::index::CompositeIndexParams;
 use crate::id_tracker::IdTrackerRead;
+use crate::index::field_index::composite_index::CompositeIndex;
 use crate::index::field_index::{FieldIndex, FieldIndexBuilderTrait as _};
+use crate::json_path::JsonPath;
 use crate::payload_storage::PayloadStorageRead;
 use crate::types::{PayloadContainer, PayloadFieldSchema, PayloadKeyTypeRef};
 
@@ -53,6 +56,51 @@ impl StructPayloadIndex {
             .collect()
     }
 
+    pub(super) fn build_composite_index(
+        &self,
+        field: PayloadKeyTypeRef,
+        params: &CompositeIndexParams,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<CompositeIndex> {
+        let payload_storage = self.payload.borrow();
+        let id_tracker_borrow = self.id_tracker.borrow();
+        let mut index = CompositeIndex::create(self.composite_dir(field), field, params)?;
+
+        payload_storage.iter(
+            |point_id, point_payload| {
+                if !id_tracker_borrow.is_deleted_point(point_id) {
+                    index.add_point(point_id, point_payload, hw_counter)?;
+                }
+                Ok(true)
+            },
+            hw_counter,
+        )?;
+
+        Ok(index)
+    }
+
+    /// Re-index the point in composite indexes, which include any field matching `is_affected`
+    pub(super) fn update_composite_indexes(
+        &mut self,
+        point_id: PointOffsetType,
+        is_affected: impl Fn(&JsonPath) -> bool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        let is_index_affected =
+            |index: &CompositeIndex| index.keys().iter().any(|key| is_affected(key));
+        if !self.composite_indexes.values().any(is_index_affected) {
+            return Ok(());
+        }
+
+        let payload = self.payload.borrow().get(point_id, hw_counter)?;
+        for index in self.composite_indexes.values_mut() {
+            if is_index_affected(&*index) {
+                index.add_point(point_id, &payload, hw_counter)?;
+            }
+        }
+        Ok(())
+    }
+
     pub(super) fn clear_index_for_point(
         &mut self,
         point_id: PointOffsetType,
@@ -62,6 +110,9 @@ impl StructPayloadIndex {
                 index.remove_point(point_id)?;
             }
         }
+        for index in self.composite_indexes.values_mut() {
+            index.remove_point(point_id)?;
+        }
         Ok(())
     }
 }
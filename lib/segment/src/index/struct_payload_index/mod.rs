@@ -18,6 +18,7 @@ use common::defaults::log_load_timing;
 use fs_err as fs;
 
 use super::field_index::FieldIndex;
+use super::field_index::composite_index::CompositeIndex;
 use super::field_index::index_selector::{
     IndexSelector, IndexSelectorGridstore, IndexSelectorMmap,
 };
@@ -49,6 +50,8 @@ pub struct StructPayloadIndex {
     pub(super) vector_storages: HashMap<VectorNameBuf, Arc<AtomicRefCell<VectorStorageEnum>>>,
     /// Indexes, associated with fields
     pub field_indexes: IndexesMap,
+    /// Composite indexes, associated with the first field of the composite key
+    pub(super) composite_indexes: HashMap<PayloadKeyType, CompositeIndex>,
     config: PayloadConfig,
     /// Root of index persistence dir
     path: PathBuf,
@@ -63,6 +66,10 @@ impl StructPayloadIndex {
         PayloadConfig::get_config_path(&self.path)
     }
 
+    pub(super) fn composite_dir(&self, field: &PayloadKeyType) -> PathBuf {
+        self.path.join(format!("{}-composite", field.filename()))
+    }
+
     pub(super) fn save_config(&self) -> OperationResult<()> {
         let config_path = self.config_path();
         self.config.save(&config_path)
@@ -91,6 +98,27 @@ impl StructPayloadIndex {
         }
 
         self.field_indexes = field_indexes;
+
+        let mut composite_indexes = HashMap::new();
+        for (field, payload_schema) in self.config.indices.iter() {
+            if let Some(params) = payload_schema.schema.as_composite() {
+                let started = Instant::now();
+                let loaded = CompositeIndex::open(self.composite_dir(field), field, params)?;
+                // Build from payload storage if the index was not persisted yet
+                let composite_index = match loaded {
+                    Some(composite_index) => composite_index,
+                    None => self.build_composite_index(
+                        field,
+                        params,
+                        &HardwareCounterCell::disposable(), // Internal operation
+                    )?,
+                };
+                log_load_timing(&self.path, &format!("composite `{field}`"), started);
+                composite_indexes.insert(field.clone(), composite_index);
+            }
+        }
+        self.composite_indexes = composite_indexes;
+
         Ok(())
     }
 
@@ -216,6 +244,7 @@ impl StructPayloadIndex {
             id_tracker,
             vector_storages,
             field_indexes: Default::default(),
+            composite_indexes: Default::default(),
             config,
             path: path.to_owned(),
             visited_pool: Default::default(),
@@ -327,6 +356,9 @@ impl StructPayloadIndex {
                 index.clear_cache()?;
             }
         }
+        for index in self.composite_indexes.values() {
+            index.clear_cache()?;
+        }
         Ok(())
     }
 
@@ -365,6 +397,7 @@ impl StructPayloadIndex {
             id_tracker: &*id_tracker,
             vector_storages: &self.vector_storages,
             field_indexes: &self.field_indexes,
+            composite_indexes: &self.composite_indexes,
             config: &self.config,
             visited_pool: &self.visited_pool,
         };
@@ -48,6 +48,20 @@ impl PayloadIndex for StructPayloadIndex {
             .collect();
         self.field_indexes.insert(field.clone(), field_index);
 
+        // Composite index shares the storage directory with a previous one on the same field
+        if let Some(previous) = self.composite_indexes.remove(&field) {
+            previous.wipe()?;
+        }
+        if let Some(params) = payload_schema.as_composite() {
+            let composite_index = self.build_composite_index(
+                &field,
+                params,
+                &HardwareCounterCell::disposable(), // Internal operation
+            )?;
+            self.composite_indexes
+                .insert(field.clone(), composite_index);
+        }
+
         self.config.indices.insert(
             field,
             PayloadFieldSchemaWithIndexType::new(payload_schema, index_types),
@@ -91,6 +105,7 @@ impl PayloadIndex for StructPayloadIndex {
     fn drop_index(&mut self, field: PayloadKeyTypeRef) -> OperationResult<bool> {
         let removed_config = self.config.indices.remove(field);
         let removed_indexes = self.field_indexes.remove(field);
+        let removed_composite = self.composite_indexes.remove(field);
 
         let is_removed = removed_config.is_some() || removed_indexes.is_some();
 
@@ -99,6 +114,9 @@ impl PayloadIndex for StructPayloadIndex {
                 index.wipe()?;
             }
         }
+        if let Some(index) = removed_composite {
+            index.wipe()?;
+        }
 
         self.save_config()?;
 
@@ -145,6 +163,9 @@ impl PayloadIndex for StructPayloadIndex {
                 }
             }
         }
+        for index in self.composite_indexes.values_mut() {
+            index.add_point(point_id, payload, hw_counter)?;
+        }
         Ok(())
     }
 
@@ -184,6 +205,15 @@ impl PayloadIndex for StructPayloadIndex {
                 }
             }
         }
+        for index in self.composite_indexes.values_mut() {
+            let is_affected = index
+                .keys()
+                .iter()
+                .any(|field| field.is_affected_by_value_set(&payload.0, key.as_ref()));
+            if is_affected {
+                index.add_point(point_id, &updated_payload, hw_counter)?;
+            }
+        }
         Ok(())
     }
 
@@ -198,7 +228,16 @@ impl PayloadIndex for StructPayloadIndex {
                 index.remove_point(point_id)?;
             }
         }
-        self.payload.borrow_mut().delete(point_id, key, hw_counter)
+        let deleted = self
+            .payload
+            .borrow_mut()
+            .delete(point_id, key, hw_counter)?;
+        self.update_composite_indexes(
+            point_id,
+            |field| field.is_affected_by_value_remove(key),
+            hw_counter,
+        )?;
+        Ok(deleted)
     }
 
     fn clear_payload(
@@ -220,6 +259,9 @@ impl PayloadIndex for StructPayloadIndex {
                 flushers.push(index.flusher());
             }
         }
+        for index in self.composite_indexes.values() {
+            flushers.push(index.flusher());
+        }
         flushers.push(self.payload.borrow().flusher());
 
         Box::new(move || {
@@ -236,6 +278,11 @@ impl PayloadIndex for StructPayloadIndex {
             .values()
             .flat_map(|indexes| indexes.iter().flat_map(|index| index.files().into_iter()))
             .collect::<Vec<PathBuf>>();
+        files.extend(
+            self.composite_indexes
+                .values()
+                .flat_map(|index| index.files()),
+        );
         files.push(self.config_path());
         files
     }
@@ -6,8 +6,9 @@ use crate::common::operation_error::OperationResult;
 use crate::id_tracker::IdTrackerRead;
 use crate::index::PayloadIndexRead;
 use crate::index::field_index::{
-    CardinalityEstimation, FieldIndexRead, PrimaryCondition, ResolvedHasId,
+    CardinalityEstimation, CompositeCondition, FieldIndexRead, PrimaryCondition, ResolvedHasId,
 };
+use crate::index::query_estimator::GroupEstimation;
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::struct_filter_context::StructFilterContext;
 use crate::json_path::JsonPath;
@@ -68,9 +69,70 @@ where
                 Ok(Some(Box::new(ids.resolved_point_offsets.iter().copied())))
             }
             PrimaryCondition::HasVector(_) => Ok(None),
+            PrimaryCondition::Composite(composite) => {
+                let Some(composite_index) = self.composite_indexes.get(&composite.field) else {
+                    return Ok(None);
+                };
+                Ok(Some(composite_index.filter(&composite.lookup)))
+            }
         }
     }
 
+    /// Estimate several `must` conditions with a single composite index lookup.
+    ///
+    /// Only used if some composite index covers at least two of the conditions,
+    /// a single condition is estimated as well by the regular index of the field.
+    pub(in crate::index) fn composite_cardinality(
+        &self,
+        conditions: &[Condition],
+    ) -> OperationResult<GroupEstimation> {
+        if self.composite_indexes.is_empty() {
+            return Ok(None);
+        }
+
+        let (positions, field_conditions): (Vec<_>, Vec<_>) = conditions
+            .iter()
+            .enumerate()
+            .filter_map(|(position, condition)| {
+                if let Condition::Field(field_condition) = condition {
+                    Some((position, field_condition))
+                } else {
+                    None
+                }
+            })
+            .unzip();
+
+        let best = self
+            .composite_indexes
+            .iter()
+            .filter_map(|(field, composite_index)| {
+                let (lookup, covered) = composite_index.plan_lookup(&field_conditions)?;
+                (covered.len() >= 2).then(|| {
+                    let estimation = composite_index.estimate_cardinality(&lookup);
+                    (field, lookup, covered, estimation)
+                })
+            })
+            .min_by_key(|(_, _, _, estimation)| estimation.exp);
+
+        let Some((field, lookup, covered, estimation)) = best else {
+            return Ok(None);
+        };
+
+        let composite_condition = CompositeCondition {
+            field: field.clone(),
+            conditions: covered
+                .iter()
+                .map(|&covered| field_conditions[covered].clone())
+                .collect(),
+            lookup,
+        };
+        let estimation = estimation
+            .with_primary_clause(PrimaryCondition::Composite(Box::new(composite_condition)));
+        let covered_positions = covered.iter().map(|&covered| positions[covered]).collect();
+
+        Ok(Some((estimation, covered_positions)))
+    }
+
     pub fn struct_filtered_context<'q>(
         &'q self,
         filter: &'q Filter,
@@ -15,6 +15,7 @@ use atomic_refcell::AtomicRefCell;
 
 use crate::id_tracker::IdTrackerRead;
 use crate::index::field_index::FieldIndexRead;
+use crate::index::field_index::composite_index::CompositeIndex;
 use crate::index::payload_config::PayloadConfig;
 use crate::index::visited_pool::VisitedPool;
 use crate::payload_storage::PayloadStorageRead;
@@ -54,6 +55,7 @@ where
     pub(crate) id_tracker: &'a I,
     pub(crate) vector_storages: &'a HashMap<VectorNameBuf, Arc<AtomicRefCell<V>>>,
     pub(crate) field_indexes: &'a HashMap<PayloadKeyType, Vec<F>>,
+    pub(crate) composite_indexes: &'a HashMap<PayloadKeyType, CompositeIndex>,
     pub(crate) config: &'a PayloadConfig,
     pub(crate) visited_pool: &'a VisitedPool,
 }
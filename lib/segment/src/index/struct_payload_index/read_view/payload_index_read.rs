@@ -16,7 +16,7 @@ use crate::index::field_index::numeric_index::NumericFieldIndexRead;
 use crate::index::field_index::{
     CardinalityEstimation, FacetIndex, FieldIndexRead, PayloadBlockCondition,
 };
-use crate::index::query_estimator::estimate_filter;
+use crate::index::query_estimator::{estimate_filter, estimate_filter_grouped};
 use crate::index::query_optimization::payload_provider::PayloadProvider;
 use crate::index::query_optimization::rescore_formula::FormulaScorer;
 use crate::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
@@ -47,7 +47,8 @@ where
         let available_points = self.available_point_count();
         let estimator =
             |condition: &Condition| self.condition_cardinality(condition, None, hw_counter);
-        estimate_filter(&estimator, query, available_points)
+        let group_estimator = |conditions: &[Condition]| self.composite_cardinality(conditions);
+        estimate_filter_grouped(&estimator, &group_estimator, query, available_points)
     }
 
     fn estimate_nested_cardinality(
@@ -32,6 +32,7 @@ fn smoke_view_over_in_memory_backends() {
     let vector_storages: HashMap<VectorNameBuf, Arc<AtomicRefCell<VectorStorageEnum>>> =
         HashMap::new();
     let field_indexes = IndexesMap::new();
+    let composite_indexes = HashMap::new();
     let config = PayloadConfig::default();
     let visited_pool = VisitedPool::new();
 
@@ -41,6 +42,7 @@ fn smoke_view_over_in_memory_backends() {
             id_tracker: &id_tracker,
             vector_storages: &vector_storages,
             field_indexes: &field_indexes,
+            composite_indexes: &composite_indexes,
             config: &config,
             visited_pool: &visited_pool,
         };
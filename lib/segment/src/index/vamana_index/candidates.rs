@@ -0,0 +1,78 @@
+use common::types::ScoredPointOffset;
+
+/// Candidates of the greedy search, bounded by the list size.
+///
+/// Candidates are kept sorted from the best to the worst score, along with a flag whether the
+/// neighbors of the candidate were visited already.
+pub(super) struct CandidateList {
+    list_size: usize,
+    candidates: Vec<(ScoredPointOffset, bool)>,
+}
+
+impl CandidateList {
+    pub fn new(list_size: usize) -> Self {
+        Self {
+            list_size,
+            candidates: Vec::with_capacity(list_size + 1),
+        }
+    }
+
+    /// Insert a candidate, unless it is worse than all candidates of a full list
+    pub fn insert(&mut self, point: ScoredPointOffset) {
+        let position = self
+            .candidates
+            .partition_point(|(candidate, _)| candidate.score >= point.score);
+        if position >= self.list_size {
+            return;
+        }
+        self.candidates.insert(position, (point, false));
+        self.candidates.truncate(self.list_size);
+    }
+
+    /// Take up to `count` best candidates which were not expanded yet, and mark them as expanded
+    pub fn expand_next(&mut self, count: usize, expanded: &mut Vec<ScoredPointOffset>) {
+        expanded.clear();
+        for (candidate, is_expanded) in &mut self.candidates {
+            if expanded.len() >= count {
+                break;
+            }
+            if !*is_expanded {
+                *is_expanded = true;
+                expanded.push(*candidate);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::PointOffsetType;
+
+    use super::*;
+
+    fn point(idx: PointOffsetType, score: f32) -> ScoredPointOffset {
+        ScoredPointOffset { idx, score }
+    }
+
+    #[test]
+    fn test_candidate_list() {
+        let mut candidates = CandidateList::new(3);
+        candidates.insert(point(1, 0.1));
+        candidates.insert(point(2, 0.5));
+        candidates.insert(point(3, 0.3));
+        candidates.insert(point(4, 0.0)); // worse than a full list
+        candidates.insert(point(5, 0.4)); // pushes out the worst one
+
+        let mut expanded = Vec::new();
+        let mut expand_next = |candidates: &mut CandidateList| {
+            candidates.expand_next(2, &mut expanded);
+            expanded.iter().map(|point| point.idx).collect::<Vec<_>>()
+        };
+        assert_eq!(expand_next(&mut candidates), vec![2, 5]);
+
+        candidates.insert(point(6, 0.9)); // pushes out the worst one, which wasn't expanded
+        assert_eq!(expand_next(&mut candidates), vec![6]);
+
+        assert!(expand_next(&mut candidates).is_empty());
+    }
+}
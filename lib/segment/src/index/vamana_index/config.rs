@@ -0,0 +1,40 @@
+use std::path::{Path, PathBuf};
+
+use common::fs::{atomic_save_json, read_json};
+use common::types::PointOffsetType;
+use serde::{Deserialize, Serialize};
+
+use crate::common::operation_error::OperationResult;
+
+pub const VAMANA_INDEX_CONFIG_FILE: &str = "vamana_config.json";
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+pub struct VamanaGraphConfig {
+    /// Maximal number of links per node
+    pub max_degree: usize,
+    /// Number of candidates to search on construction
+    pub build_list_size: usize,
+    /// Default number of candidates to search
+    pub search_list_size: usize,
+    /// Dimensionality of vectors stored next to the links
+    pub dim: usize,
+    /// Number of nodes in the graph file, including nodes of deleted vectors
+    pub num_nodes: usize,
+    /// Node every search starts from. None if the graph is empty
+    pub entry_point: Option<PointOffsetType>,
+    pub indexed_vector_count: usize,
+}
+
+impl VamanaGraphConfig {
+    pub fn get_config_path(path: &Path) -> PathBuf {
+        path.join(VAMANA_INDEX_CONFIG_FILE)
+    }
+
+    pub fn load(path: &Path) -> OperationResult<Self> {
+        Ok(read_json(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> OperationResult<()> {
+        Ok(atomic_save_json(path, self)?)
+    }
+}
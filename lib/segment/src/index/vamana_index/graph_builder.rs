@@ -0,0 +1,305 @@
+//! In-memory construction of the Vamana graph, see the DiskANN paper.
+//!
+//! Points are inserted twice: first with strict pruning of links, and then with relaxed
+//! pruning, which keeps additional long links and shortens search paths.
+
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::generic_consts::Random;
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset};
+use parking_lot::RwLock;
+use rand::Rng;
+use rand::seq::{IndexedRandom as _, SliceRandom as _};
+use rayon::ThreadPool;
+use rayon::prelude::*;
+
+use super::candidates::CandidateList;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::data_types::vectors::{QueryVector, VectorElementType, VectorInternal, VectorRef};
+use crate::index::hnsw_index::hnsw::SINGLE_THREADED_HNSW_BUILD_THRESHOLD;
+use crate::index::visited_pool::{VisitedListHandle, VisitedPool};
+use crate::types::Distance;
+use crate::vector_storage::{RawScorer, VectorStorageEnum, VectorStorageRead, new_raw_scorer};
+
+/// Relaxation of link pruning on the second pass over the points
+const VAMANA_ALPHA: ScoreType = 1.2;
+
+/// Number of points sampled to find the entry point of the graph
+const ENTRY_POINT_SAMPLE_SIZE: usize = 10_000;
+
+pub(super) struct VamanaGraphBuilder<'a> {
+    max_degree: usize,
+    build_list_size: usize,
+    distance: Distance,
+    vector_storage: &'a VectorStorageEnum,
+    links: Vec<RwLock<Vec<PointOffsetType>>>,
+    entry_point: PointOffsetType,
+    visited_pool: VisitedPool,
+}
+
+impl<'a> VamanaGraphBuilder<'a> {
+    /// Create a builder with the entry point of the graph selected among `points`.
+    ///
+    /// Returns `None` if there are no points to build the graph on.
+    pub fn new<R: Rng + ?Sized>(
+        max_degree: usize,
+        build_list_size: usize,
+        distance: Distance,
+        vector_storage: &'a VectorStorageEnum,
+        points: &[PointOffsetType],
+        rng: &mut R,
+    ) -> OperationResult<Option<Self>> {
+        let Some(entry_point) = find_entry_point(vector_storage, points, rng)? else {
+            return Ok(None);
+        };
+        let num_nodes = vector_storage.total_vector_count();
+        Ok(Some(Self {
+            max_degree,
+            build_list_size,
+            distance,
+            vector_storage,
+            links: (0..num_nodes).map(|_| RwLock::new(Vec::new())).collect(),
+            entry_point,
+            visited_pool: VisitedPool::new(),
+        }))
+    }
+
+    pub fn entry_point(&self) -> PointOffsetType {
+        self.entry_point
+    }
+
+    /// Link all `points` in the graph, with two passes over the points
+    pub fn build<R: Rng + ?Sized>(
+        &self,
+        points: &[PointOffsetType],
+        pool: &ThreadPool,
+        rng: &mut R,
+        stopped: &AtomicBool,
+    ) -> OperationResult<()> {
+        let mut points = points.to_vec();
+        for alpha in [1.0, VAMANA_ALPHA] {
+            points.shuffle(rng);
+
+            // Link first points single-threaded, so that parallel insertions have a graph to start with
+            let first_few = points.len().min(SINGLE_THREADED_HNSW_BUILD_THRESHOLD);
+            for &point_id in &points[..first_few] {
+                check_process_stopped(stopped)?;
+                self.link_point(point_id, alpha)?;
+            }
+            pool.install(|| {
+                points[first_few..].par_iter().try_for_each(|&point_id| {
+                    check_process_stopped(stopped)?;
+                    self.link_point(point_id, alpha)
+                })
+            })?;
+        }
+        Ok(())
+    }
+
+    pub fn into_links(self) -> Vec<Vec<PointOffsetType>> {
+        self.links.into_iter().map(RwLock::into_inner).collect()
+    }
+
+    fn point_scorer(&self, point_id: PointOffsetType) -> OperationResult<Box<dyn RawScorer + 'a>> {
+        // No need to accumulate hardware, since this is an internal operation
+        new_raw_scorer(
+            stored_vector_query(self.vector_storage, point_id)?,
+            self.vector_storage,
+            HardwareCounterCell::disposable(),
+        )
+    }
+
+    fn link_point(&self, point_id: PointOffsetType, alpha: ScoreType) -> OperationResult<()> {
+        let scorer = self.point_scorer(point_id)?;
+
+        let mut visited = self.visited_pool.get(self.links.len());
+        let mut candidates = self.greedy_search(scorer.as_ref(), &mut visited);
+
+        // Keep current links as candidates, they might be still the best ones
+        let current_links = self.links[point_id as usize].read().clone();
+        candidates.extend(score_points(scorer.as_ref(), &current_links));
+        candidates.retain(|candidate| candidate.idx != point_id);
+
+        let new_links = self.robust_prune(scorer.as_ref(), candidates, alpha);
+        *self.links[point_id as usize].write() = new_links.clone();
+
+        // Add backward links, pruning the links of neighbors which have too many of them
+        for neighbor in new_links {
+            let mut neighbor_links = self.links[neighbor as usize].write();
+            if neighbor_links.contains(&point_id) {
+                continue;
+            }
+            if neighbor_links.len() < self.max_degree {
+                neighbor_links.push(point_id);
+                continue;
+            }
+
+            let neighbor_scorer = self.point_scorer(neighbor)?;
+            let mut neighbor_candidates = neighbor_links.clone();
+            neighbor_candidates.push(point_id);
+            let neighbor_candidates = score_points(neighbor_scorer.as_ref(), &neighbor_candidates);
+            *neighbor_links =
+                self.robust_prune(neighbor_scorer.as_ref(), neighbor_candidates, alpha);
+        }
+
+        Ok(())
+    }
+
+    /// Search the graph from the entry point, returning all expanded points
+    fn greedy_search(
+        &self,
+        scorer: &dyn RawScorer,
+        visited: &mut VisitedListHandle,
+    ) -> Vec<ScoredPointOffset> {
+        let mut candidates = CandidateList::new(self.build_list_size);
+        visited.check_and_update_visited(self.entry_point);
+        candidates.insert(ScoredPointOffset {
+            idx: self.entry_point,
+            score: scorer.score_point(self.entry_point),
+        });
+
+        let mut result = Vec::new();
+        let mut expanded = Vec::with_capacity(1);
+        let mut new_links = Vec::new();
+        loop {
+            candidates.expand_next(1, &mut expanded);
+            let Some(&point) = expanded.first() else {
+                break;
+            };
+            result.push(point);
+
+            new_links.clear();
+            new_links.extend(
+                self.links[point.idx as usize]
+                    .read()
+                    .iter()
+                    .copied()
+                    .filter(|&link| !visited.check_and_update_visited(link)),
+            );
+            for point in score_points(scorer, &new_links) {
+                candidates.insert(point);
+            }
+        }
+        result
+    }
+
+    /// Select up to `max_degree` links among `candidates`.
+    ///
+    /// Candidates are picked from the closest one, skipping candidates which are closer to an
+    /// already selected link than to the point itself. With `alpha` above 1, only candidates
+    /// which are much closer to a selected link are skipped.
+    fn robust_prune(
+        &self,
+        scorer: &dyn RawScorer,
+        mut candidates: Vec<ScoredPointOffset>,
+        alpha: ScoreType,
+    ) -> Vec<PointOffsetType> {
+        candidates.sort_unstable_by(|a, b| b.score.total_cmp(&a.score).then(a.idx.cmp(&b.idx)));
+        candidates.dedup_by_key(|candidate| candidate.idx);
+
+        let mut selected: Vec<PointOffsetType> = Vec::with_capacity(self.max_degree);
+        for candidate in candidates {
+            if selected.len() >= self.max_degree {
+                break;
+            }
+            let is_pruned = selected.iter().any(|&link| {
+                let link_score = scorer.score_internal(link, candidate.idx);
+                self.is_closer(link_score, candidate.score, alpha)
+            });
+            if !is_pruned {
+                selected.push(candidate.idx);
+            }
+        }
+        selected
+    }
+
+    /// Whether the candidate is closer to the selected link than to the point, relaxed by `alpha`
+    fn is_closer(&self, link_score: ScoreType, point_score: ScoreType, alpha: ScoreType) -> bool {
+        let to_distance = |score: ScoreType| match self.distance {
+            Distance::Cosine => 1.0 - score,
+            Distance::Euclid
+            | Distance::Dot
+            | Distance::Manhattan
+            | Distance::Hamming
+            | Distance::Jaccard => -score,
+        };
+        let link_distance = to_distance(link_score);
+        let point_distance = to_distance(point_score);
+        // Relaxation only makes sense for non-negative distances
+        if link_distance >= 0.0 {
+            alpha * link_distance <= point_distance
+        } else {
+            link_distance <= point_distance
+        }
+    }
+}
+
+fn score_points(scorer: &dyn RawScorer, points: &[PointOffsetType]) -> Vec<ScoredPointOffset> {
+    let mut scores = vec![0.0; points.len()];
+    scorer.score_points(points, &mut scores);
+    points
+        .iter()
+        .zip(scores)
+        .map(|(&idx, score)| ScoredPointOffset { idx, score })
+        .collect()
+}
+
+/// Stored vector as a query, to score other stored vectors against it
+fn stored_vector_query(
+    vector_storage: &VectorStorageEnum,
+    point_id: PointOffsetType,
+) -> OperationResult<QueryVector> {
+    Ok(QueryVector::Nearest(VectorInternal::from(
+        stored_dense_vector(vector_storage, point_id)?,
+    )))
+}
+
+pub(super) fn stored_dense_vector(
+    vector_storage: &VectorStorageEnum,
+    point_id: PointOffsetType,
+) -> OperationResult<Vec<VectorElementType>> {
+    let vector = vector_storage.get_vector::<Random>(point_id);
+    match vector.as_vec_ref() {
+        VectorRef::Dense(vector) => Ok(vector.to_vec()),
+        VectorRef::Sparse(_) | VectorRef::MultiDense(_) => Err(OperationError::service_error(
+            "Vamana index can only be built on dense vectors",
+        )),
+    }
+}
+
+/// Select the point closest to the centroid of a sample of points, as in DiskANN
+fn find_entry_point<R: Rng + ?Sized>(
+    vector_storage: &VectorStorageEnum,
+    points: &[PointOffsetType],
+    rng: &mut R,
+) -> OperationResult<Option<PointOffsetType>> {
+    let sample: Vec<PointOffsetType> = points
+        .sample(rng, ENTRY_POINT_SAMPLE_SIZE)
+        .copied()
+        .collect();
+    let Some(&first) = sample.first() else {
+        return Ok(None);
+    };
+
+    let mut centroid = stored_dense_vector(vector_storage, first)?;
+    for &point_id in &sample[1..] {
+        let vector = stored_dense_vector(vector_storage, point_id)?;
+        for (sum, value) in centroid.iter_mut().zip(vector) {
+            *sum += value;
+        }
+    }
+    for value in &mut centroid {
+        *value /= sample.len() as VectorElementType;
+    }
+
+    let scorer = new_raw_scorer(
+        QueryVector::Nearest(VectorInternal::from(centroid)),
+        vector_storage,
+        HardwareCounterCell::disposable(),
+    )?;
+    Ok(score_points(scorer.as_ref(), &sample)
+        .into_iter()
+        .max()
+        .map(|point| point.idx))
+}
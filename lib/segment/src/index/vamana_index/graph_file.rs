@@ -0,0 +1,214 @@
+//! On-disk layout of the Vamana graph.
+//!
+//! Vectors are co-located with the links of their nodes, so a single read per visited node
+//! provides both the full vector for scoring and the neighbors to continue the search with:
+//!
+//! ```text
+//! [node_0] [node_1] ... [node_N-1]
+//! node = [vector: dim x f32] [degree: u32] [links: max_degree x u32]
+//! ```
+//!
+//! All nodes have the same size, so the position of a node is computed from its id.
+
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use common::generic_consts::Random;
+use common::mmap::{Advice, AdviceSetting};
+use common::types::PointOffsetType;
+#[cfg(target_os = "linux")]
+use common::universal_io::IoUringFile;
+use common::universal_io::{MmapFile, OpenOptions, Populate, ReadOnly, ReadRange, TypedStorage};
+use fs_err::File;
+
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::vectors::VectorElementType;
+#[cfg(target_os = "linux")]
+use crate::vector_storage::common::get_async_scorer;
+
+pub const VAMANA_GRAPH_FILE: &str = "vamana_graph.bin";
+
+/// Size of every element of a node in the graph file
+const WORD_SIZE: usize = size_of::<u32>();
+
+#[derive(Debug, Clone, Copy)]
+pub struct NodeLayout {
+    pub dim: usize,
+    pub max_degree: usize,
+}
+
+/// Node of the graph as stored on disk
+pub struct NodeRef<'a> {
+    pub vector: &'a [VectorElementType],
+    pub links: &'a [PointOffsetType],
+}
+
+impl NodeLayout {
+    /// Number of 4-byte words per node
+    fn node_len(&self) -> usize {
+        self.dim + 1 + self.max_degree
+    }
+
+    pub fn node_size_bytes(&self) -> usize {
+        self.node_len() * WORD_SIZE
+    }
+
+    fn node_range(&self, point_id: PointOffsetType) -> ReadRange {
+        ReadRange {
+            byte_offset: point_id as u64 * self.node_size_bytes() as u64,
+            length: self.node_len() as u64,
+        }
+    }
+
+    fn parse<'a>(&self, data: &'a [u32]) -> NodeRef<'a> {
+        let (vector, rest) = data.split_at(self.dim);
+        let degree = (rest[0] as usize).min(self.max_degree);
+        NodeRef {
+            vector: bytemuck::cast_slice(vector),
+            links: &rest[1..1 + degree],
+        }
+    }
+
+    /// Write all nodes of the graph into the file at `path`.
+    ///
+    /// Nodes without a vector, e.g. of deleted points, are written with a zero vector.
+    pub fn write(
+        &self,
+        path: &Path,
+        links: &[Vec<PointOffsetType>],
+        mut get_vector: impl FnMut(PointOffsetType) -> OperationResult<Option<Vec<VectorElementType>>>,
+    ) -> OperationResult<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        let zero_vector = vec![0 as VectorElementType; self.dim];
+        let padding = vec![0 as PointOffsetType; self.max_degree];
+
+        for (point_id, node_links) in links.iter().enumerate() {
+            debug_assert!(node_links.len() <= self.max_degree);
+            let vector = get_vector(point_id as PointOffsetType)?;
+            let vector = vector.as_deref().unwrap_or(&zero_vector);
+            if vector.len() != self.dim {
+                return Err(OperationError::service_error(format!(
+                    "Vamana graph expects vectors of dimension {}, got {}",
+                    self.dim,
+                    vector.len(),
+                )));
+            }
+
+            writer.write_all(bytemuck::cast_slice(vector))?;
+            writer.write_all(bytemuck::bytes_of(&(node_links.len() as u32)))?;
+            writer.write_all(bytemuck::cast_slice(node_links))?;
+            writer.write_all(bytemuck::cast_slice(&padding[node_links.len()..]))?;
+        }
+
+        let file = writer.into_inner().map_err(|err| err.into_error())?;
+        file.sync_all()?;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+enum GraphStorage {
+    Mmap(TypedStorage<ReadOnly<MmapFile>, u32>),
+    #[cfg(target_os = "linux")]
+    IoUring(TypedStorage<ReadOnly<IoUringFile>, u32>),
+}
+
+/// Graph file opened for reading nodes
+#[derive(Debug)]
+pub struct VamanaGraphFile {
+    layout: NodeLayout,
+    path: PathBuf,
+    storage: GraphStorage,
+}
+
+impl VamanaGraphFile {
+    pub fn get_path(path: &Path) -> PathBuf {
+        path.join(VAMANA_GRAPH_FILE)
+    }
+
+    pub fn open(path: &Path, layout: NodeLayout, populate: bool) -> OperationResult<Self> {
+        let path = Self::get_path(path);
+        let options = OpenOptions {
+            writeable: false,
+            need_sequential: false,
+            populate: Populate::from(populate),
+            advice: AdviceSetting::Advice(Advice::Random),
+            extra: Default::default(),
+        };
+
+        // Batched reads of visited nodes are served by io_uring, if enabled
+        #[cfg(target_os = "linux")]
+        if get_async_scorer() {
+            match TypedStorage::open(&path, options) {
+                Ok(storage) => {
+                    return Ok(Self {
+                        layout,
+                        path,
+                        storage: GraphStorage::IoUring(storage),
+                    });
+                }
+                Err(err) => {
+                    log::error!("failed to open io_uring based Vamana graph: {err}");
+                }
+            }
+        }
+
+        let storage = GraphStorage::Mmap(TypedStorage::open(&path, options)?);
+        Ok(Self {
+            layout,
+            path,
+            storage,
+        })
+    }
+
+    pub fn layout(&self) -> NodeLayout {
+        self.layout
+    }
+
+    /// Read the given nodes in a single batch, calling `callback` for each of them.
+    ///
+    /// Nodes may be passed to `callback` in any order.
+    pub fn read_nodes(
+        &self,
+        point_ids: &[PointOffsetType],
+        mut callback: impl FnMut(PointOffsetType, NodeRef<'_>),
+    ) -> OperationResult<()> {
+        let layout = self.layout;
+        let ranges = point_ids
+            .iter()
+            .map(|&point_id| (point_id, layout.node_range(point_id)));
+        let callback = |point_id, data: &[u32]| {
+            callback(point_id, layout.parse(data));
+            Ok(())
+        };
+
+        match &self.storage {
+            GraphStorage::Mmap(storage) => storage.read_batch::<Random, _>(ranges, callback)?,
+            #[cfg(target_os = "linux")]
+            GraphStorage::IoUring(storage) => storage.read_batch::<Random, _>(ranges, callback)?,
+        }
+        Ok(())
+    }
+
+    pub fn files(&self) -> Vec<PathBuf> {
+        vec![self.path.clone()]
+    }
+
+    pub fn populate(&self) -> OperationResult<()> {
+        match &self.storage {
+            GraphStorage::Mmap(storage) => storage.populate()?,
+            #[cfg(target_os = "linux")]
+            GraphStorage::IoUring(storage) => storage.populate()?,
+        }
+        Ok(())
+    }
+
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        match &self.storage {
+            GraphStorage::Mmap(storage) => storage.clear_ram_cache()?,
+            #[cfg(target_os = "linux")]
+            GraphStorage::IoUring(storage) => storage.clear_ram_cache()?,
+        }
+        Ok(())
+    }
+}
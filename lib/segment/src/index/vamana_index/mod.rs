@@ -0,0 +1,5 @@
+mod candidates;
+pub mod config;
+mod graph_builder;
+pub mod graph_file;
+pub mod vamana;
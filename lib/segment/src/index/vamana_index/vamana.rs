@@ -0,0 +1,813 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use atomic_refcell::AtomicRefCell;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::fixed_length_priority_queue::FixedLengthPriorityQueue;
+use common::types::{PointOffsetType, ScoreType, ScoredPointOffset, TelemetryDetail};
+use fs_err as fs;
+use log::debug;
+use parking_lot::Mutex;
+use rand::Rng;
+use sparse::common::types::DimId;
+
+use super::candidates::CandidateList;
+use super::config::VamanaGraphConfig;
+use super::graph_builder::{VamanaGraphBuilder, stored_dense_vector};
+use super::graph_file::{NodeLayout, VamanaGraphFile};
+use crate::common::BYTES_IN_KB;
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::common::operation_time_statistics::{
+    OperationDurationsAggregator, ScopeDurationMeasurer,
+};
+use crate::data_types::explain::SearchStrategy;
+use crate::data_types::query_context::VectorQueryContext;
+use crate::data_types::vectors::{
+    DenseVector, QueryVector, VectorElementType, VectorInternal, VectorRef,
+};
+use crate::id_tracker::{IdTrackerEnum, IdTrackerRead};
+use crate::index::plain_vector_index::PlainVectorIndex;
+use crate::index::query_estimator::adjust_to_available_vectors;
+use crate::index::sample_estimation::sample_check_cardinality;
+use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::visited_pool::VisitedPool;
+use crate::index::{PayloadIndexRead, VectorIndex, VectorIndexRead};
+use crate::segment_constructor::VectorIndexBuildArgs;
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
+use crate::telemetry::VectorIndexSearchesTelemetry;
+use crate::types::{
+    CompressionRatio, Distance, Filter, ProductQuantization, ProductQuantizationConfig,
+    QuantizationConfig, QuantizationSearchParams, SearchParams, VamanaConfig,
+};
+use crate::vector_storage::quantized::quantized_vectors::{
+    QuantizedVectors, QuantizedVectorsStorageType,
+};
+use crate::vector_storage::{
+    RawScorer, VectorStorageEnum, VectorStorageRead, check_deleted_condition, new_raw_scorer,
+};
+
+/// Directory of the compressed vectors guiding the graph search
+const VAMANA_PQ_DIR: &str = "pq";
+
+pub struct VamanaIndexOpenArgs<'a> {
+    pub path: &'a Path,
+    pub id_tracker: Arc<AtomicRefCell<IdTrackerEnum>>,
+    pub vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    pub quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    pub payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    pub vamana_config: VamanaConfig,
+}
+
+#[derive(Debug)]
+struct VamanaSearchesTelemetry {
+    unfiltered_plain: Arc<Mutex<OperationDurationsAggregator>>,
+    filtered_plain: Arc<Mutex<OperationDurationsAggregator>>,
+    unfiltered_graph: Arc<Mutex<OperationDurationsAggregator>>,
+    small_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    large_cardinality: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_filtered: Arc<Mutex<OperationDurationsAggregator>>,
+    exact_unfiltered: Arc<Mutex<OperationDurationsAggregator>>,
+}
+
+impl VamanaSearchesTelemetry {
+    fn new() -> Self {
+        Self {
+            unfiltered_plain: OperationDurationsAggregator::new(),
+            filtered_plain: OperationDurationsAggregator::new(),
+            unfiltered_graph: OperationDurationsAggregator::new(),
+            small_cardinality: OperationDurationsAggregator::new(),
+            large_cardinality: OperationDurationsAggregator::new(),
+            exact_filtered: OperationDurationsAggregator::new(),
+            exact_unfiltered: OperationDurationsAggregator::new(),
+        }
+    }
+}
+
+/// Graph index with nodes stored on disk, see the DiskANN paper.
+///
+/// Only product-quantized vectors are kept in RAM. They steer the beam search over the graph,
+/// while visited nodes are read from disk along with their full vectors for exact scoring.
+#[derive(Debug)]
+pub struct VamanaIndex {
+    id_tracker: Arc<AtomicRefCell<IdTrackerEnum>>,
+    vector_storage: Arc<AtomicRefCell<VectorStorageEnum>>,
+    quantized_vectors: Arc<AtomicRefCell<Option<QuantizedVectors>>>,
+    config: VamanaGraphConfig,
+    /// Number of nodes read from disk at once on search
+    beam_width: usize,
+    /// We prefer a full scan search upto (excluding) this number of vectors.
+    ///
+    /// Note: this is number of vectors, not KiloBytes.
+    full_scan_threshold: usize,
+    path: PathBuf,
+    distance: Distance,
+    /// None if there are no points in the graph
+    graph: Option<VamanaGraphFile>,
+    /// Compressed vectors to score neighbors of visited nodes
+    pq_vectors: Option<QuantizedVectors>,
+    /// Used for exact searches, small filters and queries the graph can't serve
+    plain_index: PlainVectorIndex,
+    payload_index: Arc<AtomicRefCell<StructPayloadIndex>>,
+    visited_pool: VisitedPool,
+    searches_telemetry: VamanaSearchesTelemetry,
+}
+
+impl VamanaIndex {
+    fn pq_path(path: &Path) -> PathBuf {
+        path.join(VAMANA_PQ_DIR)
+    }
+
+    fn pq_config(compression: CompressionRatio) -> QuantizationConfig {
+        QuantizationConfig::Product(ProductQuantization {
+            product: ProductQuantizationConfig {
+                compression,
+                always_ram: Some(true),
+                opq: None,
+            },
+        })
+    }
+
+    pub fn open(args: VamanaIndexOpenArgs<'_>) -> OperationResult<Self> {
+        let VamanaIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            vamana_config,
+        } = args;
+
+        let config_path = VamanaGraphConfig::get_config_path(path);
+        if !config_path.exists() {
+            return Err(OperationError::service_error(format!(
+                "Vamana index config not found at {}",
+                config_path.display(),
+            )));
+        }
+        let config = VamanaGraphConfig::load(&config_path)?;
+
+        let (graph, pq_vectors) = if config.entry_point.is_some() {
+            let layout = NodeLayout {
+                dim: config.dim,
+                max_degree: config.max_degree,
+            };
+            let graph = VamanaGraphFile::open(path, layout, false)?;
+            let pq_vectors = QuantizedVectors::load(
+                &Self::pq_config(vamana_config.compression),
+                &vector_storage.borrow(),
+                &Self::pq_path(path),
+                &AtomicBool::new(false),
+            )?
+            .ok_or_else(|| {
+                OperationError::service_error("Compressed vectors of Vamana index are missing")
+            })?;
+            (Some(graph), Some(pq_vectors))
+        } else {
+            (None, None)
+        };
+
+        let (distance, full_scan_threshold) = {
+            let vector_storage = vector_storage.borrow();
+            let full_scan_threshold = vector_storage
+                .size_of_available_vectors_in_bytes()
+                .checked_div(vector_storage.total_vector_count())
+                .and_then(|avg_vector_size| {
+                    vamana_config
+                        .full_scan_threshold
+                        .saturating_mul(BYTES_IN_KB)
+                        .checked_div(avg_vector_size)
+                })
+                .unwrap_or(1);
+            (vector_storage.distance(), full_scan_threshold)
+        };
+        let plain_index = PlainVectorIndex::new(
+            id_tracker.clone(),
+            vector_storage.clone(),
+            quantized_vectors.clone(),
+            payload_index.clone(),
+        );
+
+        Ok(Self {
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            config,
+            beam_width: vamana_config.beam_width,
+            full_scan_threshold,
+            path: path.to_owned(),
+            distance,
+            graph,
+            pq_vectors,
+            plain_index,
+            payload_index,
+            visited_pool: VisitedPool::new(),
+            searches_telemetry: VamanaSearchesTelemetry::new(),
+        })
+    }
+
+    pub fn build<R: Rng + ?Sized>(
+        open_args: VamanaIndexOpenArgs<'_>,
+        build_args: VectorIndexBuildArgs<'_, R>,
+    ) -> OperationResult<Self> {
+        if VamanaGraphConfig::get_config_path(open_args.path).exists() {
+            log::warn!(
+                "Vamana index already exists at {:?}, skipping building",
+                open_args.path
+            );
+            debug_assert!(false);
+            return Self::open(open_args);
+        }
+
+        let VectorIndexBuildArgs {
+            permit,
+            old_indices: _,
+            gpu_device: _,
+            rng,
+            stopped,
+            hnsw_global_config: _,
+            feature_flags: _,
+            progress: _,
+        } = build_args;
+
+        let path = open_args.path;
+        let vamana_config = open_args.vamana_config;
+        fs::create_dir_all(path)?;
+
+        {
+            let id_tracker = open_args.id_tracker.borrow();
+            let vector_storage = open_args.vector_storage.borrow();
+
+            let Some(dim) = vector_storage.try_dense_vector_dim() else {
+                return Err(OperationError::service_error(
+                    "Vamana index can only be built on dense vectors",
+                ));
+            };
+            let total_vector_count = vector_storage.total_vector_count();
+
+            let points: Vec<PointOffsetType> = id_tracker
+                .point_mappings()
+                .iter_internal_excluding(vector_storage.deleted_vector_bitslice())
+                .collect();
+
+            debug!(
+                "building Vamana graph for {} vectors with {} CPUs",
+                points.len(),
+                permit.num_cpus,
+            );
+
+            let pool = rayon::ThreadPoolBuilder::new()
+                .thread_name(|idx| format!("vamana-build-{idx}"))
+                .num_threads(permit.num_cpus as usize)
+                .build()?;
+
+            let builder = VamanaGraphBuilder::new(
+                vamana_config.max_degree,
+                vamana_config.build_list_size,
+                vector_storage.distance(),
+                &vector_storage,
+                &points,
+                rng,
+            )?;
+
+            let entry_point = match builder {
+                Some(builder) => {
+                    builder.build(&points, &pool, rng, stopped)?;
+                    let entry_point = builder.entry_point();
+
+                    let layout = NodeLayout {
+                        dim,
+                        max_degree: vamana_config.max_degree,
+                    };
+                    layout.write(
+                        &VamanaGraphFile::get_path(path),
+                        &builder.into_links(),
+                        |point_id| {
+                            if vector_storage.is_deleted_vector(point_id) {
+                                return Ok(None);
+                            }
+                            stored_dense_vector(&vector_storage, point_id).map(Some)
+                        },
+                    )?;
+
+                    QuantizedVectors::create(
+                        &vector_storage,
+                        &Self::pq_config(vamana_config.compression),
+                        QuantizedVectorsStorageType::Immutable,
+                        &Self::pq_path(path),
+                        permit.num_cpus as usize,
+                        stopped,
+                    )?;
+
+                    Some(entry_point)
+                }
+                None => None,
+            };
+
+            let config = VamanaGraphConfig {
+                max_degree: vamana_config.max_degree,
+                build_list_size: vamana_config.build_list_size,
+                search_list_size: vamana_config.build_list_size,
+                dim,
+                num_nodes: total_vector_count,
+                entry_point,
+                indexed_vector_count: points.len(),
+            };
+            config.save(&VamanaGraphConfig::get_config_path(path))?;
+        }
+
+        Self::open(open_args)
+    }
+
+    /// Read underlying data from disk into disk cache.
+    pub fn populate(&self) -> OperationResult<()> {
+        if let Some(graph) = &self.graph {
+            graph.populate()?;
+        }
+        if let Some(pq_vectors) = &self.pq_vectors {
+            pq_vectors.populate()?;
+        }
+        Ok(())
+    }
+
+    /// Drop disk cache.
+    pub fn clear_cache(&self) -> OperationResult<()> {
+        if let Some(graph) = &self.graph {
+            graph.clear_cache()?;
+        }
+        if let Some(pq_vectors) = &self.pq_vectors {
+            pq_vectors.clear_cache()?;
+        }
+        Ok(())
+    }
+
+    pub fn graph_files(&self) -> Vec<PathBuf> {
+        self.graph
+            .as_ref()
+            .map(|graph| graph.files())
+            .unwrap_or_default()
+    }
+
+    pub fn pq_files(&self) -> Vec<PathBuf> {
+        self.pq_vectors
+            .as_ref()
+            .map(|pq_vectors| pq_vectors.files())
+            .unwrap_or_default()
+    }
+
+    pub fn get_quantized_vectors(&self) -> Arc<AtomicRefCell<Option<QuantizedVectors>>> {
+        self.quantized_vectors.clone()
+    }
+
+    /// Plain search over all vectors, never restricted to indexed segments
+    fn search_plain(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let params = params.map(|params| SearchParams {
+            indexed_only: false,
+            ..*params
+        });
+        self.plain_index
+            .search(vectors, filter, top, params.as_ref(), query_context)
+    }
+
+    fn search_vectors_with_graph(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        let matryoshka = params.is_some_and(|params| params.matryoshka);
+        vectors
+            .iter()
+            .map(|&vector| {
+                if !matryoshka && self.is_graph_query(vector) {
+                    self.search_with_graph(vector, filter, top, params, query_context)
+                } else {
+                    // Vectors of other sizes or their first dimensions can't be compared
+                    // with the ones in the graph
+                    let mut plain_result =
+                        self.search_plain(&[vector], filter, top, params, query_context)?;
+                    Ok(plain_result.pop().unwrap_or_default())
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the graph can serve the query, i.e. all its vectors are full-sized dense vectors
+    fn is_graph_query(&self, query: &QueryVector) -> bool {
+        let is_full_dense = |vector: &VectorInternal| match vector {
+            VectorInternal::Dense(dense) => dense.len() == self.config.dim,
+            VectorInternal::Sparse(_) | VectorInternal::MultiDense(_) => false,
+        };
+        match query {
+            QueryVector::Nearest(vector) => is_full_dense(vector),
+            QueryVector::RecommendBestScore(reco) | QueryVector::RecommendSumScores(reco) => {
+                reco.flat_iter().all(is_full_dense)
+            }
+            QueryVector::Discover(discover) => discover.flat_iter().all(is_full_dense),
+            QueryVector::Context(context) => context.flat_iter().all(is_full_dense),
+            QueryVector::FeedbackNaive(feedback) => feedback.flat_iter().all(is_full_dense),
+        }
+    }
+
+    /// Search over the graph, which visits more nodes until enough of them match the filter.
+    ///
+    /// Filters are only checked on visited nodes, so a filtered search starts with a candidate
+    /// list large enough to find `top` matching points at the expected share of them. If it still
+    /// finds less, the list is doubled, up to visiting the whole graph.
+    fn search_with_graph(
+        &self,
+        query: &QueryVector,
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        query_context.record_search_strategy(SearchStrategy::Vamana);
+
+        let num_nodes = self.config.num_nodes.max(1);
+        let mut list_size = params
+            .and_then(|params| params.hnsw_ef)
+            .unwrap_or(self.config.search_list_size)
+            .max(top);
+        if let Some(filter) = filter {
+            let hw_counter = query_context.hardware_counter();
+            let cardinality = self
+                .payload_index
+                .borrow()
+                .with_view(|view| view.estimate_cardinality(filter, &hw_counter))?;
+            let available_vector_count = self.vector_storage.borrow().available_vector_count();
+            let expected_list_size =
+                top.saturating_mul(available_vector_count) / cardinality.exp.max(1);
+            list_size = list_size.max(expected_list_size);
+        }
+
+        loop {
+            list_size = list_size.min(num_nodes);
+            let result = self.search_graph_once(query, filter, top, list_size, query_context)?;
+            if result.len() >= top || list_size >= num_nodes {
+                return Ok(result);
+            }
+            list_size = list_size.saturating_mul(2);
+        }
+    }
+
+    /// Beam search over the graph.
+    ///
+    /// Candidates are ranked by compressed vectors, and `beam_width` best unvisited candidates
+    /// are read from disk at once. Their full vectors give the exact scores of nearest neighbors
+    /// search, other queries are scored exactly by the vector storage.
+    fn search_graph_once(
+        &self,
+        query: &QueryVector,
+        filter: Option<&Filter>,
+        top: usize,
+        list_size: usize,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<ScoredPointOffset>> {
+        let (Some(graph), Some(pq_vectors), Some(entry_point)) =
+            (&self.graph, &self.pq_vectors, self.config.entry_point)
+        else {
+            return Ok(Vec::new());
+        };
+
+        let is_stopped = query_context.is_stopped();
+        let hw_counter = query_context.hardware_counter();
+
+        let id_tracker = self.id_tracker.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let deleted_points = query_context
+            .deleted_points()
+            .unwrap_or_else(|| id_tracker.deleted_point_bitslice());
+        let deleted_vectors = vector_storage.deleted_vector_bitslice();
+
+        let exact_scorer = match query {
+            QueryVector::Nearest(VectorInternal::Dense(dense)) => ExactScorer::Node(
+                self.distance
+                    .preprocess_vector::<VectorElementType>(dense.clone()),
+            ),
+            QueryVector::Nearest(_)
+            | QueryVector::RecommendBestScore(_)
+            | QueryVector::RecommendSumScores(_)
+            | QueryVector::Discover(_)
+            | QueryVector::Context(_)
+            | QueryVector::FeedbackNaive(_) => ExactScorer::Storage(new_raw_scorer(
+                query.clone(),
+                &vector_storage,
+                query_context.hardware_counter(),
+            )?),
+        };
+        let pq_scorer = pq_vectors.raw_scorer(query.clone(), query_context.hardware_counter())?;
+
+        let payload_index = self.payload_index.borrow();
+        payload_index.with_view(|view| {
+            let filter_context = filter
+                .map(|filter| view.filter_context(filter, &hw_counter))
+                .transpose()?;
+
+            let mut visited = self.visited_pool.get(self.config.num_nodes);
+            visited.check_and_update_visited(entry_point);
+            let mut candidates = CandidateList::new(list_size);
+            candidates.insert(ScoredPointOffset {
+                idx: entry_point,
+                score: pq_scorer.score_point(entry_point),
+            });
+
+            let mut result = FixedLengthPriorityQueue::new(top);
+            let mut beam = Vec::with_capacity(self.beam_width);
+            let mut beam_ids = Vec::with_capacity(self.beam_width);
+            let mut new_links = Vec::new();
+            let mut scores = Vec::new();
+            loop {
+                check_process_stopped(&is_stopped)?;
+
+                candidates.expand_next(self.beam_width, &mut beam);
+                if beam.is_empty() {
+                    break;
+                }
+                beam_ids.clear();
+                beam_ids.extend(beam.iter().map(|point| point.idx));
+
+                new_links.clear();
+                graph.read_nodes(&beam_ids, |point_id, node| {
+                    let is_matching =
+                        check_deleted_condition(point_id, deleted_vectors, deleted_points)
+                            && filter_context
+                                .as_ref()
+                                .is_none_or(|filter_context| filter_context.check(point_id));
+                    if is_matching {
+                        let score = match &exact_scorer {
+                            ExactScorer::Node(query) => {
+                                similarity(self.distance, query, node.vector)
+                            }
+                            ExactScorer::Storage(scorer) => scorer.score_point(point_id),
+                        };
+                        result.push(ScoredPointOffset {
+                            idx: point_id,
+                            score,
+                        });
+                    }
+                    new_links.extend(
+                        node.links
+                            .iter()
+                            .copied()
+                            .filter(|&link| !visited.check_and_update_visited(link)),
+                    );
+                })?;
+                hw_counter
+                    .vector_io_read()
+                    .incr_delta(beam_ids.len() * graph.layout().node_size_bytes());
+
+                scores.clear();
+                scores.resize(new_links.len(), 0.0);
+                pq_scorer.score_points(&new_links, &mut scores);
+                for (&idx, &score) in new_links.iter().zip(&scores) {
+                    candidates.insert(ScoredPointOffset { idx, score });
+                }
+            }
+
+            Ok(result.into_sorted_vec())
+        })
+    }
+
+    /// Whether a search without filter should use the graph, rather than score all points
+    fn is_graph_search_unfiltered(&self) -> bool {
+        self.graph.is_some()
+            && self.vector_storage.borrow().available_vector_count() >= self.full_scan_threshold
+    }
+
+    /// Whether a filtered search should use the graph, rather than score all matching points
+    fn is_graph_search_filtered(
+        &self,
+        filter: &Filter,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<bool> {
+        if self.graph.is_none() {
+            return Ok(false);
+        }
+
+        let payload_index = self.payload_index.borrow();
+        let vector_storage = self.vector_storage.borrow();
+        let id_tracker = self.id_tracker.borrow();
+        let available_vector_count = vector_storage.available_vector_count();
+
+        let query_point_cardinality =
+            payload_index.with_view(|v| v.estimate_cardinality(filter, hw_counter))?;
+        let query_cardinality = adjust_to_available_vectors(
+            query_point_cardinality,
+            available_vector_count,
+            id_tracker.available_point_count(),
+        );
+
+        if query_cardinality.max < self.full_scan_threshold {
+            Ok(false)
+        } else if query_cardinality.min > self.full_scan_threshold {
+            Ok(true)
+        } else {
+            payload_index.with_view(|v| {
+                let filter_context = v.filter_context(filter, hw_counter)?;
+                Ok::<_, OperationError>(sample_check_cardinality(
+                    id_tracker.sample_ids(Some(vector_storage.deleted_vector_bitslice())),
+                    |idx| filter_context.check(idx),
+                    self.full_scan_threshold,
+                    available_vector_count,
+                ))
+            })
+        }
+    }
+}
+
+/// Exact scoring of the points visited by the graph search
+enum ExactScorer<'a> {
+    /// Preprocessed nearest neighbors query, compared with full vectors read along with the links
+    Node(DenseVector),
+    /// Other queries are scored by the vector storage
+    Storage(Box<dyn RawScorer + 'a>),
+}
+
+fn similarity(
+    distance: Distance,
+    query: &[VectorElementType],
+    vector: &[VectorElementType],
+) -> ScoreType {
+    match distance {
+        Distance::Cosine => CosineMetric::similarity(query, vector),
+        Distance::Euclid => EuclidMetric::similarity(query, vector),
+        Distance::Dot => DotProductMetric::similarity(query, vector),
+        Distance::Manhattan => ManhattanMetric::similarity(query, vector),
+        Distance::Hamming => HammingMetric::similarity(query, vector),
+        Distance::Jaccard => JaccardMetric::similarity(query, vector),
+    }
+}
+
+impl VectorIndexRead for VamanaIndex {
+    fn search(
+        &self,
+        vectors: &[&QueryVector],
+        filter: Option<&Filter>,
+        top: usize,
+        params: Option<&SearchParams>,
+        query_context: &VectorQueryContext,
+    ) -> OperationResult<Vec<Vec<ScoredPointOffset>>> {
+        if top == 0 {
+            return Ok(vec![vec![]; vectors.len()]);
+        }
+
+        let exact = params.is_some_and(|params| params.exact);
+        if exact {
+            let _timer = ScopeDurationMeasurer::new(if filter.is_some() {
+                &self.searches_telemetry.exact_filtered
+            } else {
+                &self.searches_telemetry.exact_unfiltered
+            });
+            let exact_params = params.map(|params| {
+                let mut params = *params;
+                params.quantization = Some(QuantizationSearchParams {
+                    ignore: true,
+                    rescore: Some(false),
+                    oversampling: None,
+                }); // disable quantization for exact search
+                params
+            });
+            return self.search_plain(vectors, filter, top, exact_params.as_ref(), query_context);
+        }
+
+        match filter {
+            None => {
+                let plain_search = !self.is_graph_search_unfiltered();
+
+                if plain_search {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_plain);
+                    self.search_plain(vectors, None, top, params, query_context)
+                } else {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.unfiltered_graph);
+                    self.search_vectors_with_graph(vectors, None, top, params, query_context)
+                }
+            }
+            Some(query_filter) => {
+                if self.graph.is_none() {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.filtered_plain);
+                    return self.search_plain(vectors, filter, top, params, query_context);
+                }
+
+                let hw_counter = query_context.hardware_counter();
+
+                if self.is_graph_search_filtered(query_filter, &hw_counter)? {
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.large_cardinality);
+                    self.search_vectors_with_graph(vectors, filter, top, params, query_context)
+                } else {
+                    // Few points match, score only the ones selected by the payload index
+                    let _timer =
+                        ScopeDurationMeasurer::new(&self.searches_telemetry.small_cardinality);
+                    self.search_plain(vectors, filter, top, params, query_context)
+                }
+            }
+        }
+    }
+
+    fn search_strategy(
+        &self,
+        vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SearchStrategy> {
+        if params.is_some_and(|params| params.exact) {
+            return Ok(SearchStrategy::Exact);
+        }
+        if params.is_some_and(|params| params.matryoshka) || !self.is_graph_query(vector) {
+            return Ok(SearchStrategy::FullScan);
+        }
+
+        let graph_search = match filter {
+            None => self.is_graph_search_unfiltered(),
+            Some(filter) => self.is_graph_search_filtered(filter, hw_counter)?,
+        };
+
+        if graph_search {
+            Ok(SearchStrategy::Vamana)
+        } else {
+            Ok(SearchStrategy::FullScan)
+        }
+    }
+
+    fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
+        let tm = &self.searches_telemetry;
+        VectorIndexSearchesTelemetry {
+            index_name: None,
+            unfiltered_plain: tm.unfiltered_plain.lock().get_statistics(detail),
+            filtered_plain: tm.filtered_plain.lock().get_statistics(detail),
+            unfiltered_hnsw: tm.unfiltered_graph.lock().get_statistics(detail),
+            filtered_small_cardinality: tm.small_cardinality.lock().get_statistics(detail),
+            filtered_large_cardinality: tm.large_cardinality.lock().get_statistics(detail),
+            filtered_exact: tm.exact_filtered.lock().get_statistics(detail),
+            filtered_sparse: Default::default(),
+            unfiltered_exact: tm.exact_unfiltered.lock().get_statistics(detail),
+            unfiltered_sparse: Default::default(),
+        }
+    }
+
+    fn indexed_vector_count(&self) -> usize {
+        self.config.indexed_vector_count
+    }
+
+    fn size_of_searchable_vectors_in_bytes(&self) -> usize {
+        self.vector_storage
+            .borrow()
+            .size_of_available_vectors_in_bytes()
+    }
+
+    fn fill_idf_statistics(
+        &self,
+        _idf: &mut HashMap<DimId, usize>,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        // Vamana (dense) index doesn't track IDF.
+        Ok(())
+    }
+
+    fn is_index(&self) -> bool {
+        true
+    }
+}
+
+impl VectorIndex for VamanaIndex {
+    fn files(&self) -> Vec<PathBuf> {
+        let mut files = self.graph_files();
+        files.extend(self.pq_files());
+        let config_path = VamanaGraphConfig::get_config_path(&self.path);
+        if config_path.exists() {
+            files.push(config_path);
+        }
+        files
+    }
+
+    fn immutable_files(&self) -> Vec<PathBuf> {
+        self.files() // All Vamana index files are immutable
+    }
+
+    fn update_vector(
+        &mut self,
+        _id: PointOffsetType,
+        _vector: Option<VectorRef>,
+        _hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<()> {
+        Err(OperationError::service_error("Cannot update Vamana index"))
+    }
+}
@@ -11,9 +11,12 @@ use sparse::index::inverted_index::inverted_index_compressed_mmap::InvertedIndex
 use sparse::index::inverted_index::inverted_index_ram::InvertedIndexRam;
 
 use super::hnsw_index::hnsw::HNSWIndex;
+use super::ivf_index::ivf::IvfIndex;
 use super::plain_vector_index::PlainVectorIndex;
 use super::sparse_index::sparse_vector_index::SparseVectorIndex;
+use super::vamana_index::vamana::VamanaIndex;
 use crate::common::operation_error::OperationResult;
+use crate::data_types::explain::SearchStrategy;
 use crate::data_types::query_context::VectorQueryContext;
 use crate::data_types::vectors::{QueryVector, VectorRef};
 use crate::telemetry::VectorIndexSearchesTelemetry;
@@ -35,6 +38,15 @@ pub trait VectorIndexRead {
         query_context: &VectorQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPointOffset>>>;
 
+    /// Strategy [`VectorIndexRead::search`] would use for the given query, without searching
+    fn search_strategy(
+        &self,
+        vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SearchStrategy>;
+
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry;
 
     /// The number of indexed vectors, currently accessible
@@ -89,6 +101,8 @@ pub trait VectorIndex: VectorIndexRead {
 pub enum VectorIndexEnum {
     Plain(PlainVectorIndex),
     Hnsw(HNSWIndex),
+    Vamana(VamanaIndex),
+    Ivf(IvfIndex),
     SparseRam(SparseVectorIndex<InvertedIndexRam>),
     SparseCompressedImmutableRamF32(SparseVectorIndex<InvertedIndexCompressedImmutableRam<f32>>),
     SparseCompressedImmutableRamF16(SparseVectorIndex<InvertedIndexCompressedImmutableRam<f16>>),
@@ -107,6 +121,8 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(_) => false,
             Self::Hnsw(index) => index.is_on_disk(),
+            Self::Vamana(_) => true,
+            Self::Ivf(_) => false,
             Self::SparseRam(index) => index.inverted_index().is_on_disk(),
             Self::SparseCompressedImmutableRamF32(index) => index.inverted_index().is_on_disk(),
             Self::SparseCompressedImmutableRamF16(index) => index.inverted_index().is_on_disk(),
@@ -121,6 +137,8 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(_) => {}
             Self::Hnsw(index) => index.populate()?,
+            Self::Vamana(index) => index.populate()?,
+            Self::Ivf(_) => {}
             Self::SparseRam(_) => {}
             Self::SparseCompressedImmutableRamF32(_) => {}
             Self::SparseCompressedImmutableRamF16(_) => {}
@@ -136,6 +154,8 @@ impl VectorIndexEnum {
         match self {
             Self::Plain(_) => {}
             Self::Hnsw(index) => index.clear_cache()?,
+            Self::Vamana(index) => index.clear_cache()?,
+            Self::Ivf(_) => {}
             Self::SparseRam(_) => {}
             Self::SparseCompressedImmutableRamF32(_) => {}
             Self::SparseCompressedImmutableRamF16(_) => {}
@@ -151,6 +171,8 @@ impl VectorIndexEnum {
         match self {
             VectorIndexEnum::Plain(_) => None,
             VectorIndexEnum::Hnsw(index) => Some(index),
+            VectorIndexEnum::Vamana(_) => None,
+            VectorIndexEnum::Ivf(_) => None,
             VectorIndexEnum::SparseRam(_) => None,
             VectorIndexEnum::SparseCompressedImmutableRamF32(_) => None,
             VectorIndexEnum::SparseCompressedImmutableRamF16(_) => None,
@@ -178,6 +200,12 @@ impl VectorIndexRead for VectorIndexEnum {
             VectorIndexEnum::Hnsw(index) => {
                 index.search(vectors, filter, top, params, query_context)
             }
+            VectorIndexEnum::Vamana(index) => {
+                index.search(vectors, filter, top, params, query_context)
+            }
+            VectorIndexEnum::Ivf(index) => {
+                index.search(vectors, filter, top, params, query_context)
+            }
             VectorIndexEnum::SparseRam(index) => {
                 index.search(vectors, filter, top, params, query_context)
             }
@@ -202,10 +230,56 @@ impl VectorIndexRead for VectorIndexEnum {
         }
     }
 
+    fn search_strategy(
+        &self,
+        vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SearchStrategy> {
+        match self {
+            VectorIndexEnum::Plain(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::Hnsw(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::Vamana(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::Ivf(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::SparseRam(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::SparseCompressedImmutableRamF32(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::SparseCompressedImmutableRamF16(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::SparseCompressedImmutableRamU8(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::SparseCompressedMmapF32(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::SparseCompressedMmapF16(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+            VectorIndexEnum::SparseCompressedMmapU8(index) => {
+                index.search_strategy(vector, filter, params, hw_counter)
+            }
+        }
+    }
+
     fn get_telemetry_data(&self, detail: TelemetryDetail) -> VectorIndexSearchesTelemetry {
         match self {
             VectorIndexEnum::Plain(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::Hnsw(index) => index.get_telemetry_data(detail),
+            VectorIndexEnum::Vamana(index) => index.get_telemetry_data(detail),
+            VectorIndexEnum::Ivf(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseRam(index) => index.get_telemetry_data(detail),
             VectorIndexEnum::SparseCompressedImmutableRamF32(index) => {
                 index.get_telemetry_data(detail)
@@ -226,6 +300,8 @@ impl VectorIndexRead for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.indexed_vector_count(),
             Self::Hnsw(index) => index.indexed_vector_count(),
+            Self::Vamana(index) => index.indexed_vector_count(),
+            Self::Ivf(index) => index.indexed_vector_count(),
             Self::SparseRam(index) => index.indexed_vector_count(),
             Self::SparseCompressedImmutableRamF32(index) => index.indexed_vector_count(),
             Self::SparseCompressedImmutableRamF16(index) => index.indexed_vector_count(),
@@ -240,6 +316,8 @@ impl VectorIndexRead for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::Hnsw(index) => index.size_of_searchable_vectors_in_bytes(),
+            Self::Vamana(index) => index.size_of_searchable_vectors_in_bytes(),
+            Self::Ivf(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::SparseRam(index) => index.size_of_searchable_vectors_in_bytes(),
             Self::SparseCompressedImmutableRamF32(index) => {
                 index.size_of_searchable_vectors_in_bytes()
@@ -260,6 +338,8 @@ impl VectorIndexRead for VectorIndexEnum {
         match self {
             Self::Plain(_) => false,
             Self::Hnsw(_) => true,
+            Self::Vamana(_) => true,
+            Self::Ivf(_) => true,
             Self::SparseRam(_) => true,
             Self::SparseCompressedImmutableRamF32(_) => true,
             Self::SparseCompressedImmutableRamF16(_) => true,
@@ -276,7 +356,7 @@ impl VectorIndexRead for VectorIndexEnum {
         hw_counter: &HardwareCounterCell,
     ) -> OperationResult<()> {
         match self {
-            Self::Plain(_) | Self::Hnsw(_) => Ok(()),
+            Self::Plain(_) | Self::Hnsw(_) | Self::Vamana(_) | Self::Ivf(_) => Ok(()),
             Self::SparseRam(index) => index.fill_idf_statistics(idf, hw_counter),
             Self::SparseCompressedImmutableRamF32(index) => {
                 index.fill_idf_statistics(idf, hw_counter)
@@ -299,6 +379,8 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             VectorIndexEnum::Plain(index) => index.files(),
             VectorIndexEnum::Hnsw(index) => index.files(),
+            VectorIndexEnum::Vamana(index) => index.files(),
+            VectorIndexEnum::Ivf(index) => index.files(),
             VectorIndexEnum::SparseRam(index) => index.files(),
             VectorIndexEnum::SparseCompressedImmutableRamF32(index) => index.files(),
             VectorIndexEnum::SparseCompressedImmutableRamF16(index) => index.files(),
@@ -313,6 +395,8 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             VectorIndexEnum::Plain(index) => index.immutable_files(),
             VectorIndexEnum::Hnsw(index) => index.immutable_files(),
+            VectorIndexEnum::Vamana(index) => index.immutable_files(),
+            VectorIndexEnum::Ivf(index) => index.immutable_files(),
             VectorIndexEnum::SparseRam(index) => index.immutable_files(),
             VectorIndexEnum::SparseCompressedImmutableRamF32(index) => index.immutable_files(),
             VectorIndexEnum::SparseCompressedImmutableRamF16(index) => index.immutable_files(),
@@ -332,6 +416,8 @@ impl VectorIndex for VectorIndexEnum {
         match self {
             Self::Plain(index) => index.update_vector(id, vector, hw_counter),
             Self::Hnsw(index) => index.update_vector(id, vector, hw_counter),
+            Self::Vamana(index) => index.update_vector(id, vector, hw_counter),
+            Self::Ivf(index) => index.update_vector(id, vector, hw_counter),
             Self::SparseRam(index) => index.update_vector(id, vector, hw_counter),
             Self::SparseCompressedImmutableRamF32(index) => {
                 index.update_vector(id, vector, hw_counter)
@@ -1,13 +1,14 @@
 use common::bitvec::BitSlice;
 use common::counter::hardware_counter::HardwareCounterCell;
-use common::types::ScoredPointOffset;
+use common::types::{ScoreType, ScoredPointOffset};
 use itertools::Itertools;
 
 use crate::common::operation_error::OperationResult;
 use crate::data_types::vectors::QueryVector;
 use crate::index::hnsw_index::point_scorer::FilteredScorer;
 use crate::types::{
-    SearchParams, default_quantization_ignore_value, default_quantization_oversampling_value,
+    Distance, SearchParams, default_quantization_ignore_value,
+    default_quantization_oversampling_value,
 };
 use crate::vector_storage::VectorStorageEnum;
 use crate::vector_storage::quantized::quantized_vectors::QuantizedVectors;
@@ -21,7 +22,9 @@ pub fn is_quantized_search(
         .map(|q| q.ignore)
         .unwrap_or(default_quantization_ignore_value());
     let exact = params.is_some_and(|p| p.exact);
-    quantized_storage.is_some() && !ignore_quantization && !exact
+    // Quantized vectors can't score the first dimensions of vectors only
+    let matryoshka = params.is_some_and(|p| p.matryoshka);
+    quantized_storage.is_some() && !ignore_quantization && !exact && !matryoshka
 }
 
 pub fn get_oversampled_top(
@@ -44,6 +47,32 @@ pub fn get_oversampled_top(
     }
 }
 
+/// Internal score which results of a continued search don't exceed, if all queries are scored
+/// the same way. See [`SearchParams::max_score`].
+pub fn max_score(
+    query_vectors: &[&QueryVector],
+    params: Option<&SearchParams>,
+    distance: Distance,
+) -> Option<ScoreType> {
+    let is_nearest = |vector: &&QueryVector| match vector {
+        QueryVector::Nearest(_) => true,
+        QueryVector::RecommendBestScore(_)
+        | QueryVector::RecommendSumScores(_)
+        | QueryVector::Discover(_)
+        | QueryVector::Context(_)
+        | QueryVector::FeedbackNaive(_) => false,
+    };
+    let distance = if query_vectors.iter().all(is_nearest) {
+        Some(distance)
+    } else if !query_vectors.iter().any(is_nearest) {
+        // Custom scores are not converted
+        None
+    } else {
+        return None;
+    };
+    params.and_then(|params| params.max_score(distance))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn postprocess_search_result(
     mut search_result: Vec<ScoredPointOffset>,
@@ -73,6 +102,7 @@ pub fn postprocess_search_result(
             None,
             None,
             point_deleted,
+            params.and_then(|params| params.multivector_comparator),
             hardware_counter,
         )?;
 
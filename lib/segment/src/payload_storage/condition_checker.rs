@@ -87,6 +87,7 @@ impl ValueChecker for FieldCondition {
                 .is_some_and(|range_interface| match range_interface {
                     RangeInterface::Float(condition) => condition.check_match(payload),
                     RangeInterface::DateTime(condition) => condition.check_match(payload),
+                    RangeInterface::Keyword(condition) => condition.keyword.check_match(payload),
                 })
             || geo_radius
                 .as_ref()
@@ -258,6 +259,14 @@ impl ValueChecker for Range<DateTimePayloadType> {
     }
 }
 
+impl ValueChecker for Range<String> {
+    fn check_match(&self, payload: &Value) -> bool {
+        payload
+            .as_str()
+            .is_some_and(|keyword| self.as_deref().check_range(keyword))
+    }
+}
+
 impl ValueChecker for GeoBoundingBox {
     fn check_match(&self, payload: &Value) -> bool {
         match payload {
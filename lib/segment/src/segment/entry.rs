@@ -13,9 +13,10 @@ use super::Segment;
 use crate::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use crate::common::{Flusher, check_named_vectors, check_vector_name};
 use crate::data_types::build_index_result::BuildFieldIndexResult;
+use crate::data_types::explain::SegmentExplanation;
 use crate::data_types::facets::{FacetParams, FacetValue};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::order_by::{OrderBy, OrderValue};
+use crate::data_types::order_by::{OrderBy, OrderedPoint};
 use crate::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use crate::data_types::segment_record::SegmentRecord;
 use crate::data_types::vector_name_config::VectorNameConfig;
@@ -50,6 +51,10 @@ impl ReadSegmentEntry for Segment {
         self.with_view(|view| view.point_version(point_id))
     }
 
+    fn point_write_count(&self, point_id: PointIdType) -> Option<SeqNumberType> {
+        self.with_view(|view| view.point_write_count(point_id))
+    }
+
     fn search_batch(
         &self,
         vector_name: &VectorName,
@@ -164,7 +169,7 @@ impl ReadSegmentEntry for Segment {
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
         deferred_behavior: DeferredBehavior,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+    ) -> OperationResult<Vec<OrderedPoint>> {
         self.with_view(|view| {
             view.read_ordered_filtered(
                 limit,
@@ -219,6 +224,28 @@ impl ReadSegmentEntry for Segment {
         self.with_view(|view| view.estimate_point_count(filter, hw_counter))
     }
 
+    fn explain_filter(
+        &self,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SegmentExplanation> {
+        self.with_view(|view| view.explain_filter(filter, is_stopped, hw_counter))
+    }
+
+    fn explain_search(
+        &self,
+        vector_name: &VectorName,
+        query_vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        query_context: &SegmentQueryContext,
+    ) -> OperationResult<SegmentExplanation> {
+        self.with_view(|view| {
+            view.explain_search(vector_name, query_vector, filter, params, query_context)
+        })
+    }
+
     fn unique_values(
         &self,
         key: &JsonPath,
@@ -812,6 +839,28 @@ impl SegmentEntry for Segment {
             }),
         })
     }
+
+    fn set_point_write_count(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        write_count: SeqNumberType,
+    ) -> OperationResult<bool> {
+        let internal_id = self.id_tracker.borrow().internal_id(point_id);
+        self.handle_point_version_and_failure(op_num, internal_id, |segment| match internal_id {
+            Some(internal_id) => {
+                segment
+                    .id_tracker
+                    .borrow_mut()
+                    .set_internal_write_count(internal_id, write_count)?;
+
+                Ok((true, Some(internal_id)))
+            }
+            None => Err(OperationError::PointIdError {
+                missed_point_id: point_id,
+            }),
+        })
+    }
 }
 
 // The alias is needed because of self_cell limitation.
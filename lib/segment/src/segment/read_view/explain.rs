@@ -0,0 +1,99 @@
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::DeferredBehavior;
+
+use crate::common::check_query_vectors;
+use crate::common::operation_error::{OperationError, OperationResult};
+use crate::data_types::explain::{SearchStrategy, SegmentExplanation};
+use crate::data_types::query_context::SegmentQueryContext;
+use crate::data_types::vectors::QueryVector;
+use crate::id_tracker::IdTrackerRead;
+use crate::index::{PayloadIndexRead, VectorIndexRead};
+use crate::payload_storage::PayloadStorageRead;
+use crate::segment::read_view::SegmentReadView;
+use crate::segment::vector_data_read::VectorDataRead;
+use crate::types::{Filter, SearchParams, VectorName};
+
+impl<'s, TIdT, TPI, TPS, TVD> SegmentReadView<'s, TIdT, TPI, TPS, TVD>
+where
+    TIdT: IdTrackerRead,
+    TPI: PayloadIndexRead,
+    TPS: PayloadStorageRead,
+    TVD: VectorDataRead,
+{
+    /// Explain how the points matching the filter are selected, e.g. to scroll or count them.
+    pub fn explain_filter(
+        &self,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SegmentExplanation> {
+        self.explain(SearchStrategy::FullScan, filter, is_stopped, hw_counter)
+    }
+
+    /// Explain how a search of the query vector is executed.
+    ///
+    /// Reports the strategy recorded in the query context by the search, which was executed
+    /// with it. Without a recorded strategy, reports the one the vector index would use.
+    pub fn explain_search(
+        &self,
+        vector_name: &VectorName,
+        query_vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        query_context: &SegmentQueryContext,
+    ) -> OperationResult<SegmentExplanation> {
+        check_query_vectors(vector_name, &[query_vector], params, self.segment_config)?;
+        let vector_data = self
+            .vector_data
+            .get(vector_name)
+            .ok_or_else(|| OperationError::vector_name_not_exists(vector_name))?;
+        let vector_query_context = query_context.get_vector_context(vector_name);
+        let hw_counter = vector_query_context.hardware_counter();
+
+        let strategy = match query_context.recorded_search_strategy() {
+            Some(strategy) => strategy,
+            None => vector_data.vector_index().search_strategy(
+                query_vector,
+                filter,
+                params,
+                &hw_counter,
+            )?,
+        };
+
+        self.explain(
+            strategy,
+            filter,
+            &vector_query_context.is_stopped(),
+            &hw_counter,
+        )
+    }
+
+    fn explain(
+        &self,
+        strategy: SearchStrategy,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SegmentExplanation> {
+        let estimation = self.estimate_point_count(filter, hw_counter)?;
+        let actual_cardinality = self
+            .read_filtered(
+                None,
+                None,
+                filter,
+                is_stopped,
+                hw_counter,
+                DeferredBehavior::Exclude,
+            )?
+            .len();
+
+        Ok(SegmentExplanation::new(
+            strategy,
+            self.available_point_count_without_deferred(),
+            &estimation,
+            actual_cardinality,
+        ))
+    }
+}
@@ -1,4 +1,5 @@
 mod deferred;
+mod explain;
 mod facet;
 mod formula_rescore;
 mod info;
@@ -75,6 +76,12 @@ where
             .and_then(|internal_id| self.id_tracker.internal_version(internal_id))
     }
 
+    pub fn point_write_count(&self, point_id: PointIdType) -> Option<SeqNumberType> {
+        self.id_tracker
+            .internal_id(point_id)
+            .and_then(|internal_id| self.id_tracker.internal_write_count(internal_id))
+    }
+
     pub fn read_range(
         &self,
         from: Option<PointIdType>,
@@ -1,4 +1,3 @@
-use std::cmp::Reverse;
 use std::sync::atomic::AtomicBool;
 
 use common::counter::hardware_counter::HardwareCounterCell;
@@ -7,7 +6,7 @@ use common::types::{DeferredBehavior, PointOffsetType};
 use itertools::Either;
 
 use crate::common::operation_error::{OperationError, OperationResult};
-use crate::data_types::order_by::{Direction, OrderBy, OrderValue};
+use crate::data_types::order_by::{Direction, OrderBy, OrderValue, OrderedPoint};
 use crate::id_tracker::IdTrackerRead;
 use crate::index::PayloadIndexRead;
 use crate::index::field_index::numeric_index::NumericFieldIndexRead;
@@ -15,7 +14,7 @@ use crate::payload_storage::PayloadStorageRead;
 use crate::segment::read_view::SegmentReadView;
 use crate::segment::vector_data_read::VectorDataRead;
 use crate::spaces::tools::{peek_top_largest_iterable, peek_top_smallest_iterable};
-use crate::types::{Filter, PointIdType};
+use crate::types::Filter;
 
 impl<'s, TIdT, TPI, TPS, TVD> SegmentReadView<'s, TIdT, TPI, TPS, TVD>
 where
@@ -32,13 +31,14 @@ where
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
         deferred_behavior: DeferredBehavior,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+    ) -> OperationResult<Vec<OrderedPoint>> {
         let numeric_index = self
             .payload_index
             .numeric_index_for(&order_by.key)
             .ok_or_else(|| OperationError::MissingRangeIndexForOrderBy {
                 key: order_by.key.to_string(),
             })?;
+        let order_by = order_by.resolve_start_from(numeric_index.is_keyword_index())?;
 
         let cardinality_estimation = self
             .payload_index
@@ -65,20 +65,27 @@ where
                         Direction::Desc => value <= &start_from,
                     })
                     .map(move |ordering_value| (ordering_value, internal_id))
-            })
-            .filter_map(|(value, internal_id)| {
-                self.id_tracker
-                    .external_id(internal_id)
-                    .map(|external_id| (value, external_id))
             });
 
-        Ok(match order_by.direction() {
+        if order_by.resolves_ties() {
+            let mut values_ids: Vec<_> = values_ids_iterator.collect();
+            values_ids.sort_unstable_by(|(a, _), (b, _)| order_by.direction().apply(a.cmp(b)));
+            return self.read_ordered_with_ties(&order_by, values_ids, limit, hw_counter);
+        }
+
+        let values_ids_iterator = values_ids_iterator.filter_map(|(value, internal_id)| {
+            self.id_tracker
+                .external_id(internal_id)
+                .map(|external_id| (value, external_id))
+        });
+
+        let page = match order_by.direction() {
             Direction::Asc => {
                 let mut page = match limit {
                     Some(limit) => peek_top_smallest_iterable(values_ids_iterator, limit),
                     None => values_ids_iterator.collect(),
                 };
-                page.sort_unstable_by_key(|(value, _)| *value);
+                page.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
                 page
             }
             Direction::Desc => {
@@ -86,10 +93,19 @@ where
                     Some(limit) => peek_top_largest_iterable(values_ids_iterator, limit),
                     None => values_ids_iterator.collect(),
                 };
-                page.sort_unstable_by_key(|(value, _)| Reverse(*value));
+                page.sort_unstable_by(|(a, _), (b, _)| b.cmp(a));
                 page
             }
-        })
+        };
+
+        Ok(page
+            .into_iter()
+            .map(|(value, id)| OrderedPoint {
+                value,
+                then_by: Vec::new(),
+                id,
+            })
+            .collect())
     }
 
     pub fn filtered_read_by_value_stream(
@@ -100,13 +116,14 @@ where
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
         deferred_behavior: DeferredBehavior,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+    ) -> OperationResult<Vec<OrderedPoint>> {
         let numeric_index = self
             .payload_index
             .numeric_index_for(&order_by.key)
             .ok_or_else(|| OperationError::MissingRangeIndexForOrderBy {
                 key: order_by.key.to_string(),
             })?;
+        let order_by = order_by.resolve_start_from(numeric_index.is_keyword_index())?;
 
         let range_iter = numeric_index
             .stream_range(&order_by.as_range())?
@@ -134,18 +151,76 @@ where
             }
         };
 
+        let filtered_iter = filtered_iter.stop_if(is_stopped);
+
+        if order_by.resolves_ties() {
+            return self.read_ordered_with_ties(&order_by, filtered_iter, limit, hw_counter);
+        }
+
         let reads = filtered_iter
-            .stop_if(is_stopped)
             .filter_map(|(value, internal_id)| {
                 self.id_tracker
                     .external_id(internal_id)
-                    .map(|external_id| (value, external_id))
+                    .map(|external_id| OrderedPoint {
+                        value,
+                        then_by: Vec::new(),
+                        id: external_id,
+                    })
             })
             .take(limit.unwrap_or(usize::MAX))
             .collect();
         Ok(reads)
     }
 
+    /// Take `limit` points, ordering the points with equal values of the key by `then_by` values
+    /// and IDs, and skipping the points up to `start_after`.
+    ///
+    /// Expects points sorted by the value of the key. All points with the same value as the
+    /// last point within `limit` are read, so that the ties are resolved precisely.
+    fn read_ordered_with_ties(
+        &self,
+        order_by: &OrderBy,
+        sorted_values_ids: impl IntoIterator<Item = (OrderValue, PointOffsetType)>,
+        limit: Option<usize>,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<Vec<OrderedPoint>> {
+        let limit = limit.unwrap_or(usize::MAX);
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+        let mut points: Vec<OrderedPoint> = Vec::new();
+
+        for (value, internal_id) in sorted_values_ids {
+            if points.len() >= limit && points.last().is_some_and(|last| last.value != value) {
+                break;
+            }
+
+            let Some(id) = self.id_tracker.external_id(internal_id) else {
+                continue;
+            };
+
+            let then_by = if order_by.then_by.is_empty() {
+                Vec::new()
+            } else {
+                let payload = self.payload_storage.payload_ref(internal_id, hw_counter)?;
+                order_by
+                    .then_by
+                    .iter()
+                    .map(|then_by| then_by.value_of(&payload))
+                    .collect()
+            };
+
+            let point = OrderedPoint { value, then_by, id };
+            if order_by.is_after_cursor(point.key()) {
+                points.push(point);
+            }
+        }
+
+        points.sort_unstable_by(|a, b| order_by.cmp_keys(a.key(), b.key()));
+        points.truncate(limit);
+        Ok(points)
+    }
+
     pub fn read_ordered_filtered<'a>(
         &'a self,
         limit: Option<usize>,
@@ -154,7 +229,7 @@ where
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
         deferred_behavior: DeferredBehavior,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+    ) -> OperationResult<Vec<OrderedPoint>> {
         match filter {
             None => self.filtered_read_by_value_stream(
                 order_by,
@@ -188,6 +188,12 @@ where
                         ))
                     })?;
 
+            // Collections, which count writes, report the same version as retrieve does
+            let point_version = self
+                .id_tracker
+                .internal_write_count(point_offset)
+                .unwrap_or(point_version);
+
             let SegmentRecord {
                 id,
                 vectors,
@@ -202,6 +208,8 @@ where
                 vector: vectors.map(VectorStructInternal::from),
                 shard_key: None,
                 order_value: None,
+                then_by_values: Vec::new(),
+                stratum: None,
             });
         }
 
@@ -220,7 +228,7 @@ where
         params: Option<&SearchParams>,
         query_context: &SegmentQueryContext,
     ) -> OperationResult<Vec<Vec<ScoredPoint>>> {
-        check_query_vectors(vector_name, query_vectors, self.segment_config)?;
+        check_query_vectors(vector_name, query_vectors, params, self.segment_config)?;
         let vector_data = self
             .vector_data
             .get(vector_name)
@@ -1,6 +1,7 @@
 mod test_immutable_payload_index_files;
 mod test_vector_name_ops;
 
+use std::cmp::Reverse;
 use std::sync::atomic::AtomicBool;
 
 use ahash::AHashSet;
@@ -23,7 +24,7 @@ use crate::common::operation_error::OperationError::PointIdError;
 use crate::common::{check_named_vectors, check_vector, check_vector_name};
 use crate::data_types::facets::{FacetParams, FacetValue};
 use crate::data_types::named_vectors::NamedVectors;
-use crate::data_types::order_by::OrderBy;
+use crate::data_types::order_by::{Direction, OrderBy, OrderByCursor, ThenBy};
 use crate::data_types::query_context::QueryContext;
 use crate::data_types::vectors::{
     DEFAULT_VECTOR_NAME, QueryVector, VectorInternal, VectorRef, only_default_vector,
@@ -189,6 +190,91 @@ fn test_from_filter_attributes() {
     assert!(results_with_invalid_filter.is_empty());
 }
 
+#[test]
+fn test_read_ordered_with_ties() {
+    init_logger();
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hw_counter = HardwareCounterCell::new();
+    let mut segment = build_simple_segment(dir.path(), 2, Distance::Dot).unwrap();
+
+    const NUM_POINTS: u64 = 30;
+    const PAGE_SIZE: usize = 4;
+
+    // Few values of the key, so pages end in the middle of ties
+    let priority = |id: u64| (id % 3) as i64;
+    let rank = |id: u64| (id % 4 != 0).then_some(((id * 7) % 5) as i64);
+
+    for id in 0..NUM_POINTS {
+        segment
+            .upsert_point(id, id.into(), only_default_vector(&[1.0, 1.0]), &hw_counter)
+            .unwrap();
+        let mut payload = serde_json::json!({ "priority": priority(id) });
+        if let Some(rank) = rank(id) {
+            payload["rank"] = rank.into();
+        }
+        let payload: Payload = serde_json::from_value(payload).unwrap();
+        segment
+            .set_full_payload(id, id.into(), &payload, &hw_counter)
+            .unwrap();
+    }
+    segment
+        .create_field_index(
+            NUM_POINTS,
+            &JsonPath::new("priority"),
+            Some(&PayloadFieldSchema::FieldType(PayloadSchemaType::Integer)),
+            &hw_counter,
+        )
+        .unwrap();
+
+    // Ascending priority, then descending rank with missing ranks last, then ids
+    let mut expected: Vec<u64> = (0..NUM_POINTS).collect();
+    expected.sort_by_key(|&id| (priority(id), rank(id).is_none(), Reverse(rank(id)), id));
+    let expected: Vec<PointIdType> = expected.into_iter().map(PointIdType::from).collect();
+
+    let all_ids: AHashSet<PointIdType> = expected.iter().copied().collect();
+    let filter = Filter::new_must(Condition::HasId(HasIdCondition::from(all_ids)));
+
+    let mut order_by = OrderBy {
+        key: JsonPath::new("priority"),
+        direction: Some(Direction::Asc),
+        start_from: None,
+        then_by: vec![ThenBy {
+            key: JsonPath::new("rank"),
+            direction: Some(Direction::Desc),
+        }],
+        start_after: None,
+    };
+
+    for filter in [None, Some(&filter)] {
+        order_by.start_after = None;
+        let mut ids = Vec::new();
+        loop {
+            let page = segment
+                .read_ordered_filtered(
+                    Some(PAGE_SIZE),
+                    filter,
+                    &order_by,
+                    &AtomicBool::new(false),
+                    &hw_counter,
+                    DeferredBehavior::Exclude,
+                )
+                .unwrap();
+            assert!(page.len() <= PAGE_SIZE);
+            let Some(last) = page.last() else {
+                break;
+            };
+            order_by.start_after = Some(OrderByCursor {
+                value: last.value.clone(),
+                then_by: last.then_by.clone(),
+                id: last.id,
+            });
+            ids.extend(page.iter().map(|point| point.id));
+        }
+        assert_eq!(ids, expected);
+    }
+}
+
 #[rstest]
 #[case::regular(SnapshotFormat::Regular)]
 #[case::streamable(SnapshotFormat::Streamable)]
@@ -786,6 +872,7 @@ fn create_deferred_segment(
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    matryoshka_dim: None,
                 },
             )]),
             sparse_vector_data: HashMap::from_iter([
@@ -1142,6 +1229,8 @@ fn test_deferred_point_read_operations() {
                         key: JsonPath::new("number"),
                         direction: None,
                         start_from: None,
+                        then_by: Vec::new(),
+                        start_after: None,
                     },
                     &AtomicBool::new(false),
                     &hw_counter,
@@ -1149,7 +1238,7 @@ fn test_deferred_point_read_operations() {
                 )
                 .unwrap()
         },
-        |i| i.1,
+        |i| i.id,
         true,
         false,
     );
@@ -98,6 +98,7 @@ fn build_immutable_segment_with_indexed_payload(segments_path: &Path, temp_path:
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    matryoshka_dim: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -145,6 +146,7 @@ fn build_immutable_segment_with_indexed_payload(segments_path: &Path, temp_path:
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                matryoshka_dim: None,
             },
         )]),
         sparse_vector_data: Default::default(),
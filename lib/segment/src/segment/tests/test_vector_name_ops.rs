@@ -34,6 +34,7 @@ fn default_dense_config(dim: usize) -> VectorDataConfig {
         quantization_config: None,
         multivector_config: None,
         datatype: None,
+        matryoshka_dim: None,
     }
 }
 
@@ -406,6 +406,8 @@ impl SegmentBuilder {
 
         let hw_counter = HardwareCounterCell::disposable(); // Disposable counter for internal operations.
 
+        let source_id_trackers = segments.iter().map(|s| s.id_tracker.borrow()).collect_vec();
+
         let internal_id_iter = new_internal_range.zip(points_to_insert.iter());
 
         for (new_internal_id, point_data) in internal_id_iter {
@@ -416,6 +418,10 @@ impl SegmentBuilder {
             let other_payload = payloads[point_data.segment_index.get() as usize]
                 .with_view(|v| v.get_payload_sequential(old_internal_id, &hw_counter))?; // Internal operation, no measurement needed!
 
+            // Only present if writes to points are counted
+            let write_count = source_id_trackers[point_data.segment_index.get() as usize]
+                .internal_write_count(old_internal_id);
+
             match self
                 .id_tracker
                 .internal_id(ExtendedPointId::from(point_data.external_id))
@@ -441,6 +447,10 @@ impl SegmentBuilder {
                         )?;
                         self.id_tracker
                             .set_internal_version(new_internal_id, point_data.version)?;
+                        if let Some(write_count) = write_count {
+                            self.id_tracker
+                                .set_internal_write_count(new_internal_id, write_count)?;
+                        }
                         self.payload_storage
                             .clear(existing_internal_id, &hw_counter)?;
 
@@ -461,6 +471,10 @@ impl SegmentBuilder {
                     )?;
                     self.id_tracker
                         .set_internal_version(new_internal_id, point_data.version)?;
+                    if let Some(write_count) = write_count {
+                        self.id_tracker
+                            .set_internal_write_count(new_internal_id, write_count)?;
+                    }
                 }
             }
 
@@ -546,10 +560,14 @@ impl SegmentBuilder {
 
             let id_tracker = match id_tracker {
                 IdTrackerEnum::InMemoryIdTracker(in_memory_id_tracker) => {
-                    let (versions, mappings) = in_memory_id_tracker.into_internal();
+                    let (versions, write_counts, mappings) = in_memory_id_tracker.into_internal();
                     let compressed_mapping = CompressedPointMappings::from_mappings(mappings);
-                    let immutable_id_tracker =
-                        ImmutableIdTracker::new(temp_dir.path(), &versions, compressed_mapping)?;
+                    let immutable_id_tracker = ImmutableIdTracker::new(
+                        temp_dir.path(),
+                        &versions,
+                        &write_counts,
+                        compressed_mapping,
+                    )?;
                     IdTrackerEnum::ImmutableIdTracker(immutable_id_tracker)
                 }
                 IdTrackerEnum::MutableIdTracker(_) => id_tracker,
@@ -31,12 +31,14 @@ use crate::id_tracker::{IdTrackerEnum, IdTrackerRead};
 use crate::index::VectorIndexEnum;
 use crate::index::hnsw_index::gpu::gpu_devices_manager::LockedGpuDevice;
 use crate::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use crate::index::ivf_index::ivf::{IvfIndex, IvfIndexOpenArgs};
 use crate::index::plain_vector_index::PlainVectorIndex;
 use crate::index::sparse_index::sparse_index_config::SparseIndexType;
 use crate::index::sparse_index::sparse_vector_index::{
     SparseVectorIndex, SparseVectorIndexOpenArgs,
 };
 use crate::index::struct_payload_index::StructPayloadIndex;
+use crate::index::vamana_index::vamana::{VamanaIndex, VamanaIndexOpenArgs};
 use crate::payload_storage::mmap_payload_storage::MmapPayloadStorage;
 use crate::payload_storage::payload_storage_enum::PayloadStorageEnum;
 use crate::segment::{SEGMENT_STATE_FILE, Segment, SegmentVersion, VectorData};
@@ -46,7 +48,8 @@ use crate::types::{
     VectorStorageDatatype, VectorStorageType,
 };
 use crate::vector_storage::dense::dense_vector_storage::{
-    open_dense_vector_storage, open_dense_vector_storage_byte, open_dense_vector_storage_half,
+    open_dense_vector_storage, open_dense_vector_storage_bit, open_dense_vector_storage_byte,
+    open_dense_vector_storage_half,
 };
 use crate::vector_storage::multi_dense::appendable_mmap_multi_dense_vector_storage::{
     open_appendable_memmap_multi_vector_storage, open_appendable_memmap_vector_storage,
@@ -120,6 +123,12 @@ fn open_mmap_vector_storage(
                 vector_config.distance,
                 populate,
             ),
+            VectorStorageDatatype::Bit => open_dense_vector_storage_bit(
+                vector_storage_path,
+                vector_config.size,
+                vector_config.distance,
+                populate,
+            ),
         }
     }
 }
@@ -278,6 +287,25 @@ pub(crate) fn open_vector_index(
             quantized_vectors,
             payload_index,
             hnsw_config: *hnsw_config,
+            matryoshka_dim: vector_config.matryoshka_dim,
+        })?),
+        Indexes::Vamana(vamana_config) => {
+            VectorIndexEnum::Vamana(VamanaIndex::open(VamanaIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                quantized_vectors,
+                payload_index,
+                vamana_config: *vamana_config,
+            })?)
+        }
+        Indexes::Ivf(ivf_config) => VectorIndexEnum::Ivf(IvfIndex::open(IvfIndexOpenArgs {
+            path,
+            id_tracker,
+            vector_storage,
+            quantized_vectors,
+            payload_index,
+            ivf_config: *ivf_config,
         })?),
     })
 }
@@ -309,6 +337,29 @@ pub(crate) fn build_vector_index<R: Rng + ?Sized>(
                 quantized_vectors,
                 payload_index,
                 hnsw_config: *hnsw_config,
+                matryoshka_dim: vector_config.matryoshka_dim,
+            },
+            build_args,
+        )?),
+        Indexes::Vamana(vamana_config) => VectorIndexEnum::Vamana(VamanaIndex::build(
+            VamanaIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                quantized_vectors,
+                payload_index,
+                vamana_config: *vamana_config,
+            },
+            build_args,
+        )?),
+        Indexes::Ivf(ivf_config) => VectorIndexEnum::Ivf(IvfIndex::build(
+            IvfIndexOpenArgs {
+                path,
+                id_tracker,
+                vector_storage,
+                quantized_vectors,
+                payload_index,
+                ivf_config: *ivf_config,
             },
             build_args,
         )?),
@@ -368,6 +419,11 @@ pub(crate) fn create_sparse_vector_index(
         (SparseIndexType::Mmap, VectorStorageDatatype::Uint8) => {
             VectorIndexEnum::SparseCompressedMmapU8(SparseVectorIndex::open(args)?)
         }
+        (SparseIndexType::ImmutableRam | SparseIndexType::Mmap, VectorStorageDatatype::Bit) => {
+            return Err(OperationError::validation_error(
+                "Bit datatype is not supported for sparse vectors",
+            ));
+        }
     };
 
     Ok(vector_index)
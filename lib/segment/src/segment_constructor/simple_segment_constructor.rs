@@ -37,6 +37,7 @@ pub fn build_simple_segment(
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    matryoshka_dim: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -66,6 +67,7 @@ pub fn build_simple_segment_with_payload_storage(
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    matryoshka_dim: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -93,6 +95,7 @@ pub fn build_multivec_segment(
             quantization_config: None,
             multivector_config: None,
             datatype: None,
+            matryoshka_dim: None,
         },
     );
     vectors_config.insert(
@@ -105,6 +108,7 @@ pub fn build_multivec_segment(
             quantization_config: None,
             multivector_config: None,
             datatype: None,
+            matryoshka_dim: None,
         },
     );
 
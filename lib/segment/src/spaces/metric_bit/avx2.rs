@@ -0,0 +1,91 @@
+use std::arch::x86_64::*;
+
+use crate::data_types::vectors::PackedBits;
+use crate::spaces::metric_bit::popcount::BitOp;
+
+/// Counts set bits with a nibble lookup table, see <https://arxiv.org/abs/1611.07612>
+#[target_feature(enable = "avx2")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn avx2_popcount(v1: &[PackedBits], v2: &[PackedBits], op: BitOp) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    debug_assert!(is_x86_feature_detected!("avx2"));
+
+    let mut ptr1: *const u8 = v1.as_ptr().cast();
+    let mut ptr2: *const u8 = v2.as_ptr().cast();
+
+    unsafe {
+        // number of set bits for each 4-bit value
+        let lookup = _mm256_setr_epi8(
+            0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, //
+            0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+        );
+        let low_mask = _mm256_set1_epi8(0x0f);
+        let zero = _mm256_setzero_si256();
+
+        // sum accumulator for 4x64 bit integers
+        let mut acc = _mm256_setzero_si256();
+        let len = v1.len();
+        for _ in 0..len / 32 {
+            // load 32 bytes
+            let p1 = _mm256_loadu_si256(ptr1.cast::<__m256i>());
+            let p2 = _mm256_loadu_si256(ptr2.cast::<__m256i>());
+            ptr1 = ptr1.add(32);
+            ptr2 = ptr2.add(32);
+
+            let bits = match op {
+                BitOp::And => _mm256_and_si256(p1, p2),
+                BitOp::Or => _mm256_or_si256(p1, p2),
+                BitOp::Xor => _mm256_xor_si256(p1, p2),
+            };
+
+            // count bits of the low and high nibbles of each byte
+            let low = _mm256_and_si256(bits, low_mask);
+            let high = _mm256_and_si256(_mm256_srli_epi16(bits, 4), low_mask);
+            let counts = _mm256_add_epi8(
+                _mm256_shuffle_epi8(lookup, low),
+                _mm256_shuffle_epi8(lookup, high),
+            );
+
+            // horizontal sum of each 8 bytes and adding to accumulator
+            acc = _mm256_add_epi64(acc, _mm256_sad_epu8(counts, zero));
+        }
+
+        let mut count = (_mm256_extract_epi64::<0>(acc)
+            + _mm256_extract_epi64::<1>(acc)
+            + _mm256_extract_epi64::<2>(acc)
+            + _mm256_extract_epi64::<3>(acc)) as u32;
+
+        for _ in 0..len % 32 {
+            count += op.apply(*ptr1, *ptr2).count_ones();
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngExt;
+
+    use super::*;
+    use crate::spaces::metric_bit::popcount::popcount_simple;
+
+    #[test]
+    fn test_popcount_avx2() {
+        if is_x86_feature_detected!("avx2") {
+            let mut rng = rand::rng();
+            for len in [32, 33, 64, 100, 127] {
+                let v1: Vec<_> = (0..len).map(|_| PackedBits(rng.random())).collect();
+                let v2: Vec<_> = (0..len).map(|_| PackedBits(rng.random())).collect();
+                for op in [BitOp::And, BitOp::Or, BitOp::Xor] {
+                    let count_simd = unsafe { avx2_popcount(&v1, &v2, op) };
+                    assert_eq!(count_simd, popcount_simple(&v1, &v2, op));
+                }
+            }
+        } else {
+            println!("avx2 test skipped");
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::arch::aarch64::*;
+
+use crate::data_types::vectors::PackedBits;
+use crate::spaces::metric_bit::popcount::BitOp;
+
+#[target_feature(enable = "neon")]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe fn neon_popcount(v1: &[PackedBits], v2: &[PackedBits], op: BitOp) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    let mut ptr1: *const u8 = v1.as_ptr().cast();
+    let mut ptr2: *const u8 = v2.as_ptr().cast();
+
+    unsafe {
+        let mut count = 0;
+        let len = v1.len();
+        for _ in 0..len / 16 {
+            let p1 = vld1q_u8(ptr1);
+            let p2 = vld1q_u8(ptr2);
+            ptr1 = ptr1.add(16);
+            ptr2 = ptr2.add(16);
+
+            let bits = match op {
+                BitOp::And => vandq_u8(p1, p2),
+                BitOp::Or => vorrq_u8(p1, p2),
+                BitOp::Xor => veorq_u8(p1, p2),
+            };
+
+            // count bits of each byte and sum them horizontally
+            count += u32::from(vaddlvq_u8(vcntq_u8(bits)));
+        }
+
+        for _ in 0..len % 16 {
+            count += op.apply(*ptr1, *ptr2).count_ones();
+            ptr1 = ptr1.add(1);
+            ptr2 = ptr2.add(1);
+        }
+
+        count
+    }
+}
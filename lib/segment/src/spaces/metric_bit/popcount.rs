@@ -0,0 +1,77 @@
+use std::ops::{BitAnd, BitOr, BitXor};
+
+use crate::data_types::vectors::PackedBits;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::metric_bit::avx2::avx2_popcount;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::metric_bit::neon::neon_popcount;
+#[cfg(target_arch = "x86_64")]
+use crate::spaces::simple::MIN_DIM_SIZE_AVX;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::spaces::simple::MIN_DIM_SIZE_SIMD;
+
+/// Bitwise operation to combine two binary vectors with before counting set bits
+#[derive(Debug, Clone, Copy)]
+pub enum BitOp {
+    And,
+    Or,
+    Xor,
+}
+
+impl BitOp {
+    #[inline]
+    pub fn apply<T>(self, a: T, b: T) -> T
+    where
+        T: BitAnd<Output = T> + BitOr<Output = T> + BitXor<Output = T>,
+    {
+        match self {
+            BitOp::And => a & b,
+            BitOp::Or => a | b,
+            BitOp::Xor => a ^ b,
+        }
+    }
+}
+
+/// Number of set bits in `op(v1, v2)`
+pub fn popcount(v1: &[PackedBits], v2: &[PackedBits], op: BitOp) -> u32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") && v1.len() >= MIN_DIM_SIZE_AVX {
+            return unsafe { avx2_popcount(v1, v2, op) };
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") && v1.len() >= MIN_DIM_SIZE_SIMD {
+            return unsafe { neon_popcount(v1, v2, op) };
+        }
+    }
+
+    popcount_simple(v1, v2, op)
+}
+
+/// Number of set bits in a binary vector
+pub fn popcount_single(v: &[PackedBits]) -> u32 {
+    popcount(v, v, BitOp::And)
+}
+
+pub fn popcount_simple(v1: &[PackedBits], v2: &[PackedBits], op: BitOp) -> u32 {
+    debug_assert!(v1.len() == v2.len());
+    let v1: &[u8] = bytemuck::cast_slice(v1);
+    let v2: &[u8] = bytemuck::cast_slice(v2);
+
+    // Process 64 bits at a time
+    let mut chunks1 = v1.chunks_exact(size_of::<u64>());
+    let mut chunks2 = v2.chunks_exact(size_of::<u64>());
+    let mut count = 0;
+    for (a, b) in (&mut chunks1).zip(&mut chunks2) {
+        let a = u64::from_ne_bytes(a.try_into().unwrap());
+        let b = u64::from_ne_bytes(b.try_into().unwrap());
+        count += op.apply(a, b).count_ones();
+    }
+    for (a, b) in chunks1.remainder().iter().zip(chunks2.remainder()) {
+        count += op.apply(*a, *b).count_ones();
+    }
+    count
+}
@@ -0,0 +1,26 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeBit};
+use crate::spaces::metric::Metric;
+use crate::spaces::metric_bit::popcount::{BitOp, popcount, popcount_single};
+use crate::spaces::simple::CosineMetric;
+use crate::types::Distance;
+
+/// Vectors are normalized on the fly, as packed bits can not be normalized in preprocessing.
+impl Metric<VectorElementTypeBit> for CosineMetric {
+    fn distance() -> Distance {
+        Distance::Cosine
+    }
+
+    fn similarity(v1: &[VectorElementTypeBit], v2: &[VectorElementTypeBit]) -> ScoreType {
+        let norms = u64::from(popcount_single(v1)) * u64::from(popcount_single(v2));
+        if norms == 0 {
+            return 0.0;
+        }
+        popcount(v1, v2, BitOp::And) as ScoreType / (norms as ScoreType).sqrt()
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
@@ -0,0 +1,21 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeBit};
+use crate::spaces::metric::Metric;
+use crate::spaces::metric_bit::popcount::{BitOp, popcount};
+use crate::spaces::simple::DotProductMetric;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeBit> for DotProductMetric {
+    fn distance() -> Distance {
+        Distance::Dot
+    }
+
+    fn similarity(v1: &[VectorElementTypeBit], v2: &[VectorElementTypeBit]) -> ScoreType {
+        popcount(v1, v2, BitOp::And) as ScoreType
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
@@ -0,0 +1,21 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeBit};
+use crate::spaces::metric::Metric;
+use crate::spaces::metric_bit::popcount::{BitOp, popcount};
+use crate::spaces::simple::HammingMetric;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeBit> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeBit], v2: &[VectorElementTypeBit]) -> ScoreType {
+        -(popcount(v1, v2, BitOp::Xor) as ScoreType)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
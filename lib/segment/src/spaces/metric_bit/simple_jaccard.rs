@@ -0,0 +1,23 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeBit};
+use crate::spaces::metric::Metric;
+use crate::spaces::metric_bit::popcount::{BitOp, popcount};
+use crate::spaces::simple::{JaccardMetric, jaccard_distance};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeBit> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeBit], v2: &[VectorElementTypeBit]) -> ScoreType {
+        let intersection = popcount(v1, v2, BitOp::And);
+        let union = popcount(v1, v2, BitOp::Or);
+        -jaccard_distance(intersection, union)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
@@ -0,0 +1,22 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeBit};
+use crate::spaces::metric::Metric;
+use crate::spaces::metric_bit::popcount::{BitOp, popcount};
+use crate::spaces::simple::ManhattanMetric;
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeBit> for ManhattanMetric {
+    fn distance() -> Distance {
+        Distance::Manhattan
+    }
+
+    fn similarity(v1: &[VectorElementTypeBit], v2: &[VectorElementTypeBit]) -> ScoreType {
+        // Absolute difference of binary values is their XOR
+        -(popcount(v1, v2, BitOp::Xor) as ScoreType)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
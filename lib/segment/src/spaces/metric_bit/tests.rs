@@ -0,0 +1,51 @@
+use common::types::ScoreType;
+use rand::RngExt;
+
+use crate::data_types::vectors::{PackedBits, VectorElementType, VectorElementTypeBit};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
+
+fn assert_same_score<M>(v1: &[VectorElementType], v2: &[VectorElementType])
+where
+    M: Metric<VectorElementType> + Metric<VectorElementTypeBit>,
+{
+    let float_score: ScoreType = <M as Metric<VectorElementType>>::similarity(
+        &<M as Metric<VectorElementType>>::preprocess(v1.to_vec()),
+        &<M as Metric<VectorElementType>>::preprocess(v2.to_vec()),
+    );
+    let bit_score: ScoreType = <M as Metric<VectorElementTypeBit>>::similarity(
+        &PackedBits::pack(v1),
+        &PackedBits::pack(v2),
+    );
+    assert!(
+        (float_score - bit_score).abs() < 1e-4,
+        "{:?}: float score {float_score} != bit score {bit_score}",
+        <M as Metric<VectorElementType>>::distance(),
+    );
+}
+
+fn random_binary(rng: &mut impl RngExt, dim: usize) -> Vec<VectorElementType> {
+    (0..dim)
+        .map(|_| if rng.random_bool(0.3) { 1.0 } else { 0.0 })
+        .collect()
+}
+
+#[test]
+fn test_packed_bits_match_float_scores() {
+    let mut rng = rand::rng();
+    for dim in [8, 16, 64, 136, 256, 512, 1000] {
+        let v1 = random_binary(&mut rng, dim);
+        let v2 = random_binary(&mut rng, dim);
+
+        assert_eq!(PackedBits::unpack(&PackedBits::pack(&v1))[..dim], v1[..]);
+
+        assert_same_score::<HammingMetric>(&v1, &v2);
+        assert_same_score::<JaccardMetric>(&v1, &v2);
+        assert_same_score::<DotProductMetric>(&v1, &v2);
+        assert_same_score::<CosineMetric>(&v1, &v2);
+        assert_same_score::<EuclidMetric>(&v1, &v2);
+        assert_same_score::<ManhattanMetric>(&v1, &v2);
+    }
+}
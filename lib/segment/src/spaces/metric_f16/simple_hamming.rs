@@ -0,0 +1,21 @@
+use common::types::ScoreType;
+use half::f16;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeHalf};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{HammingMetric, hamming_similarity_by};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeHalf> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementTypeHalf], v2: &[VectorElementTypeHalf]) -> ScoreType {
+        hamming_similarity_by(v1, v2, |x| *x > f16::ZERO)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
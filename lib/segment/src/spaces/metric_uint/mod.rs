@@ -1,6 +1,8 @@
 pub mod simple_cosine;
 pub mod simple_dot;
 pub mod simple_euclid;
+pub mod simple_hamming;
+pub mod simple_jaccard;
 pub mod simple_manhattan;
 
 #[cfg(target_arch = "x86_64")]
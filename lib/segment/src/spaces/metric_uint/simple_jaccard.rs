@@ -0,0 +1,20 @@
+use common::types::ScoreType;
+
+use crate::data_types::vectors::{DenseVector, VectorElementTypeByte};
+use crate::spaces::metric::Metric;
+use crate::spaces::simple::{JaccardMetric, jaccard_similarity_by};
+use crate::types::Distance;
+
+impl Metric<VectorElementTypeByte> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementTypeByte], v2: &[VectorElementTypeByte]) -> ScoreType {
+        jaccard_similarity_by(v1, v2, |x| *x > 0)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
@@ -33,6 +33,18 @@ pub struct EuclidMetric;
 #[derive(Clone)]
 pub struct ManhattanMetric;
 
+/// Number of differing dimensions of binary vectors.
+///
+/// Non-binary vectors are binarized, a dimension is set if its value is greater than zero.
+#[derive(Clone)]
+pub struct HammingMetric;
+
+/// Jaccard (Tanimoto) distance of binary vectors: `1 - |a & b| / |a | b|`.
+///
+/// Non-binary vectors are binarized, a dimension is set if its value is greater than zero.
+#[derive(Clone)]
+pub struct JaccardMetric;
+
 impl Metric<VectorElementType> for EuclidMetric {
     fn distance() -> Distance {
         Distance::Euclid
@@ -211,6 +223,77 @@ impl MetricPostProcessing for CosineMetric {
     }
 }
 
+impl Metric<VectorElementType> for HammingMetric {
+    fn distance() -> Distance {
+        Distance::Hamming
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        hamming_similarity_by(v1, v2, |x| *x > 0.0)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for HammingMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
+impl Metric<VectorElementType> for JaccardMetric {
+    fn distance() -> Distance {
+        Distance::Jaccard
+    }
+
+    fn similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
+        jaccard_similarity_by(v1, v2, |x| *x > 0.0)
+    }
+
+    fn preprocess(vector: DenseVector) -> DenseVector {
+        vector
+    }
+}
+
+impl MetricPostProcessing for JaccardMetric {
+    fn postprocess(score: ScoreType) -> ScoreType {
+        score.abs()
+    }
+}
+
+/// Negative Hamming distance of vectors binarized with `is_set`
+pub fn hamming_similarity_by<T>(v1: &[T], v2: &[T], is_set: impl Fn(&T) -> bool) -> ScoreType {
+    -(v1.iter()
+        .zip(v2)
+        .filter(|(a, b)| is_set(a) != is_set(b))
+        .count() as ScoreType)
+}
+
+/// Negative Jaccard distance of vectors binarized with `is_set`
+pub fn jaccard_similarity_by<T>(v1: &[T], v2: &[T], is_set: impl Fn(&T) -> bool) -> ScoreType {
+    let (intersection, union) =
+        v1.iter()
+            .zip(v2)
+            .fold((0usize, 0usize), |(intersection, union), (a, b)| {
+                let (a, b) = (is_set(a), is_set(b));
+                (
+                    intersection + usize::from(a && b),
+                    union + usize::from(a || b),
+                )
+            });
+    -jaccard_distance(intersection as u32, union as u32)
+}
+
+/// Jaccard distance from the sizes of intersection and union, empty vectors are identical
+pub fn jaccard_distance(intersection: u32, union: u32) -> ScoreType {
+    if union == 0 {
+        return 0.0;
+    }
+    1.0 - intersection as ScoreType / union as ScoreType
+}
+
 pub fn euclid_similarity(v1: &[VectorElementType], v2: &[VectorElementType]) -> ScoreType {
     -v1.iter()
         .zip(v2)
@@ -244,6 +327,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_binary_distances() {
+        let v1 = [0.5, 0.0, -1.0, 2.0, 0.1];
+        let v2 = [1.0, 3.0, 0.0, -2.0, 0.3];
+
+        let hamming = <HammingMetric as Metric<VectorElementType>>::similarity(&v1, &v2);
+        assert_eq!(HammingMetric::postprocess(hamming), 2.0);
+
+        let jaccard = <JaccardMetric as Metric<VectorElementType>>::similarity(&v1, &v2);
+        assert_eq!(JaccardMetric::postprocess(jaccard), 0.5);
+
+        let empty = [0.0; 5];
+        let jaccard = <JaccardMetric as Metric<VectorElementType>>::similarity(&empty, &empty);
+        assert_eq!(JaccardMetric::postprocess(jaccard), 0.0);
+    }
+
     #[test]
     fn test_cosine_preprocessing() {
         let res = <CosineMetric as Metric<VectorElementType>>::preprocess(vec![0.0, 0.0, 0.0, 0.0]);
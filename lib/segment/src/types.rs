@@ -31,12 +31,14 @@ use zerocopy::native_endian::U64;
 use crate::common::anonymize::Anonymize;
 use crate::common::operation_error::{OperationError, OperationResult};
 use crate::common::utils::{self, MaybeOneOrMany, MultiValue};
+use crate::data_types::facets::FacetValue;
 use crate::data_types::index::{
-    BoolIndexParams, DatetimeIndexParams, FloatIndexParams, GeoIndexParams, IntegerIndexParams,
-    KeywordIndexParams, TextIndexParams, UuidIndexParams,
+    BoolIndexParams, CompositeFieldType, CompositeIndexParams, DatetimeIndexParams,
+    FloatIndexParams, GeoIndexParams, IntegerIndexParams, KeywordIndexParams, TextIndexParams,
+    UuidIndexParams,
 };
 use crate::data_types::modifier::Modifier;
-use crate::data_types::order_by::OrderValue;
+use crate::data_types::order_by::{OrderKey, OrderValue};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::tiny_map::TinyMap;
 use crate::data_types::vectors::{DenseVector, VectorStructInternal};
@@ -44,7 +46,9 @@ use crate::index::field_index::CardinalityEstimation;
 use crate::index::sparse_index::sparse_index_config::SparseIndexConfig;
 use crate::json_path::JsonPath;
 use crate::spaces::metric::{Metric, MetricPostProcessing};
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
 use crate::types::utils::unordered_hash_unique;
 use crate::utils::maybe_arc::MaybeArc;
 
@@ -314,6 +318,10 @@ pub enum Distance {
     Dot,
     // <https://simple.wikipedia.org/wiki/Manhattan_distance>
     Manhattan,
+    // <https://en.wikipedia.org/wiki/Hamming_distance>
+    Hamming,
+    // <https://en.wikipedia.org/wiki/Jaccard_index>, also known as Tanimoto distance
+    Jaccard,
 }
 
 impl Distance {
@@ -323,6 +331,8 @@ impl Distance {
             Distance::Euclid => EuclidMetric::postprocess(score),
             Distance::Dot => DotProductMetric::postprocess(score),
             Distance::Manhattan => ManhattanMetric::postprocess(score),
+            Distance::Hamming => HammingMetric::postprocess(score),
+            Distance::Jaccard => JaccardMetric::postprocess(score),
         }
     }
 
@@ -332,19 +342,25 @@ impl Distance {
         EuclidMetric: Metric<T>,
         DotProductMetric: Metric<T>,
         ManhattanMetric: Metric<T>,
+        HammingMetric: Metric<T>,
+        JaccardMetric: Metric<T>,
     {
         match self {
             Distance::Cosine => CosineMetric::preprocess(vector),
             Distance::Euclid => EuclidMetric::preprocess(vector),
             Distance::Dot => DotProductMetric::preprocess(vector),
             Distance::Manhattan => ManhattanMetric::preprocess(vector),
+            Distance::Hamming => HammingMetric::preprocess(vector),
+            Distance::Jaccard => JaccardMetric::preprocess(vector),
         }
     }
 
     pub fn distance_order(&self) -> Order {
         match self {
             Distance::Cosine | Distance::Dot => Order::LargeBetter,
-            Distance::Euclid | Distance::Manhattan => Order::SmallBetter,
+            Distance::Euclid | Distance::Manhattan | Distance::Hamming | Distance::Jaccard => {
+                Order::SmallBetter
+            }
         }
     }
 
@@ -355,6 +371,16 @@ impl Distance {
         }
     }
 
+    /// Converts a threshold of postprocessed scores into a threshold of internal similarity
+    /// scores, inverse of [`Distance::postprocess_score`]
+    pub fn preprocess_score_threshold(&self, threshold: ScoreType) -> ScoreType {
+        match self {
+            Distance::Cosine | Distance::Dot => threshold,
+            Distance::Euclid => -(threshold * threshold),
+            Distance::Manhattan | Distance::Hamming | Distance::Jaccard => -threshold.abs(),
+        }
+    }
+
     /// Checks if score satisfies threshold condition
     pub fn check_threshold(&self, score: ScoreType, threshold: ScoreType) -> bool {
         match self.distance_order() {
@@ -375,7 +401,8 @@ pub enum Order {
 pub struct ScoredPoint {
     /// Point id
     pub id: PointIdType,
-    /// Point version
+    /// Point version.
+    /// Number of writes made to the point, if the collection counts them.
     pub version: SeqNumberType,
     /// Points vector distance to the query vector
     pub score: ScoreType,
@@ -387,6 +414,21 @@ pub struct ScoredPoint {
     pub shard_key: Option<ShardKey>,
     /// Order-by value
     pub order_value: Option<OrderValue>,
+    /// Order-by values of `then_by` keys
+    pub then_by_values: Vec<Option<OrderValue>>,
+    /// Value of the field, which the point was drawn for by a stratified sample
+    pub stratum: Option<FacetValue>,
+}
+
+impl ScoredPoint {
+    /// Values the point is ordered by, if it is a result of `order_by` query
+    pub fn order_key(&self) -> OrderKey<'_> {
+        OrderKey {
+            value: self.order_value.as_ref(),
+            then_by: &self.then_by_values,
+            id: self.id,
+        }
+    }
 }
 
 impl Eq for ScoredPoint {}
@@ -415,6 +457,18 @@ impl PartialEq for ScoredPoint {
     }
 }
 
+/// Version, which the point is expected to have before the update is applied.
+/// Used for compare-and-set writes: if the current version of the point differs,
+/// the operation is rejected by the shard, which holds the point.
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub struct ExpectedPointVersion {
+    /// Point id
+    pub id: PointIdType,
+    /// Expected version of the point, as returned with records and search results
+    pub version: SeqNumberType,
+}
+
 /// Type of segment
 #[derive(Debug, Serialize, JsonSchema, Anonymize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -614,8 +668,62 @@ pub struct SearchParams {
     #[validate(nested)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acorn: Option<AcornSearchParams>,
+
+    /// Override the comparator of a multivector for this search.
+    /// If not set, the comparator from the vector configuration is used.
+    /// Ignored by indexes built on fixed-dimensional encodings, which only approximate MaxSim.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multivector_comparator: Option<MultiVectorComparator>,
+
+    /// Params relevant to IVF index
+    /// Number of lists to scan. Larger the value - more accurate the result, more time required for search.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1))]
+    pub ivf_nprobe: Option<usize>,
+
+    /// Radius search. If set, graph search finds points with scores better than the radius.
+    /// Used as `score_threshold`, if it is not set. Results are limited by `limit`,
+    /// use the cursor of the query to get all of them page by page,
+    /// each page continues the graph search below the cursor.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius: Option<OrderedFloat<ScoreType>>,
+
+    /// Search the truncated view of Matryoshka embeddings, see `matryoshka_dim` of the vector.
+    /// The query must have exactly `matryoshka_dim` dimensions, which are compared with the first
+    /// dimensions of stored vectors. Quantized vectors are not used by such searches.
+    #[serde(default)]
+    pub matryoshka: bool,
+
+    /// Score of the cursor position of a search, which continues a previous page.
+    /// Only points with scores not better than it are found, so pages don't search deeper.
+    /// Set by shards from the position of the query, not a part of the API.
+    #[serde(skip)]
+    pub score_start_after: Option<OrderedFloat<ScoreType>>,
 }
 
+impl SearchParams {
+    /// Internal score which points found by a continued search don't exceed.
+    ///
+    /// Scores of nearest queries are converted with the `distance`, scores of custom queries are
+    /// used as is. Slightly loosened, so that points tied with the cursor position are not lost
+    /// by rounding of the score conversion. Points before the position are skipped afterwards.
+    pub fn max_score(&self, distance: Option<Distance>) -> Option<ScoreType> {
+        self.score_start_after.map(|score| {
+            let max_score = match distance {
+                Some(distance) => distance.preprocess_score_threshold(*score),
+                None => *score,
+            };
+            max_score + max_score.abs().max(1.0) * MAX_SCORE_TOLERANCE
+        })
+    }
+}
+
+/// Relative tolerance of converted scores
+const MAX_SCORE_TOLERANCE: ScoreType = 1e-5;
+
 /// Configuration for vectors.
 #[derive(Debug, Deserialize, Validate, Clone, PartialEq, Eq)]
 pub struct VectorsConfigDefaults {
@@ -634,6 +742,13 @@ pub enum Indexes {
     /// Use filterable HNSW index for approximate search. Is very fast even on a very huge collections,
     /// but require additional space to store index and additional time to build it.
     Hnsw(HnswConfig),
+    /// Use disk-resident Vamana graph index for approximate search.
+    /// Only compressed vectors are kept in RAM, full vectors and links are read from disk.
+    Vamana(VamanaConfig),
+    /// Use inverted file (IVF) index for approximate search. Vectors are partitioned into lists
+    /// around k-means centroids, and only the lists closest to the query are scanned.
+    /// Builds much faster than HNSW and requires little extra space, at the cost of search speed.
+    Ivf(IvfConfig),
 }
 
 impl Indexes {
@@ -641,6 +756,8 @@ impl Indexes {
         match self {
             Indexes::Plain {} => false,
             Indexes::Hnsw(_) => true,
+            Indexes::Vamana(_) => true,
+            Indexes::Ivf(_) => true,
         }
     }
 
@@ -648,6 +765,8 @@ impl Indexes {
         match self {
             Indexes::Plain {} => false,
             Indexes::Hnsw(config) => config.on_disk.unwrap_or_default(),
+            Indexes::Vamana(_) => true,
+            Indexes::Ivf(_) => false,
         }
     }
 }
@@ -724,6 +843,176 @@ impl HnswConfig {
     }
 }
 
+/// Config of disk-resident graph index, following Vamana from the DiskANN paper.
+///
+/// Each vector is stored on disk next to the links of its node, so a single read per visited node
+/// is required. The search is guided by product-quantized vectors kept in RAM.
+/// The size of the candidate list on search is set with `hnsw_ef` search param.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize, JsonSchema, Validate, Anonymize,
+)]
+#[serde(rename_all = "snake_case")]
+#[anonymize(false)]
+pub struct VamanaConfig {
+    /// Maximal number of edges per node in the graph. Larger the value - more accurate the search,
+    /// more space required. Default: 64
+    #[serde(default = "default_vamana_max_degree")]
+    #[validate(range(min = 4, max = 512))]
+    pub max_degree: usize,
+    /// Number of candidates to consider during the index building. Larger the value - more accurate
+    /// the graph, more time required to build index. Default: 128
+    #[serde(default = "default_vamana_build_list_size")]
+    #[validate(range(min = 4))]
+    pub build_list_size: usize,
+    /// Number of nodes read from disk at once during search. Larger the value - less round trips
+    /// to disk, but more nodes read per search. Default: 4
+    #[serde(default = "default_vamana_beam_width")]
+    #[validate(range(min = 1, max = 64))]
+    pub beam_width: usize,
+    /// Compression ratio of the product-quantized vectors kept in RAM. Default: x16
+    #[serde(default = "default_vamana_compression")]
+    pub compression: CompressionRatio,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over graph search.
+    /// Same as `full_scan_threshold` of HNSW index. Default: 10000
+    #[serde(
+        default = "default_vamana_full_scan_threshold",
+        alias = "full_scan_threshold_kb"
+    )]
+    pub full_scan_threshold: usize,
+    /// Number of parallel threads used for background index building.
+    /// If 0 - automatically select.
+    #[serde(default = "default_max_indexing_threads")]
+    pub max_indexing_threads: usize,
+}
+
+impl Default for VamanaConfig {
+    fn default() -> Self {
+        Self {
+            max_degree: default_vamana_max_degree(),
+            build_list_size: default_vamana_build_list_size(),
+            beam_width: default_vamana_beam_width(),
+            compression: default_vamana_compression(),
+            full_scan_threshold: default_vamana_full_scan_threshold(),
+            max_indexing_threads: default_max_indexing_threads(),
+        }
+    }
+}
+
+impl VamanaConfig {
+    /// Detect configuration mismatch against `other` that requires rebuilding
+    ///
+    /// Same as [`HnswConfig::mismatch_requires_rebuild`], a change in `max_indexing_threads`
+    /// doesn't affect the final index. Neither do `beam_width` and `full_scan_threshold`, which
+    /// are only used on search.
+    pub fn mismatch_requires_rebuild(&self, other: &Self) -> bool {
+        let VamanaConfig {
+            max_degree,
+            build_list_size,
+            beam_width: _,
+            compression,
+            full_scan_threshold: _,
+            max_indexing_threads: _,
+        } = *self;
+
+        max_degree != other.max_degree
+            || build_list_size != other.build_list_size
+            || compression != other.compression
+    }
+}
+
+const fn default_vamana_max_degree() -> usize {
+    64
+}
+
+const fn default_vamana_build_list_size() -> usize {
+    128
+}
+
+const fn default_vamana_beam_width() -> usize {
+    4
+}
+
+const fn default_vamana_compression() -> CompressionRatio {
+    CompressionRatio::X16
+}
+
+const fn default_vamana_full_scan_threshold() -> usize {
+    DEFAULT_FULL_SCAN_THRESHOLD
+}
+
+/// Config of inverted file (IVF) index.
+///
+/// Vectors are partitioned with k-means, and each partition keeps a list of its points.
+/// On search, only `nprobe` lists with the closest centroids are scanned.
+/// The number of scanned lists can be overridden with `ivf_nprobe` search param.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize, JsonSchema, Validate, Anonymize,
+)]
+#[serde(rename_all = "snake_case")]
+#[anonymize(false)]
+pub struct IvfConfig {
+    /// Number of lists to partition vectors into. Larger the value - less vectors are scanned
+    /// per list, but more lists are required for the same accuracy.
+    /// If not set, the square root of the number of vectors in a segment is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(range(min = 1, max = 65536))]
+    pub num_lists: Option<usize>,
+    /// Number of lists to scan during search. Larger the value - more accurate the search,
+    /// more time required. Default: 16
+    #[serde(default = "default_ivf_nprobe")]
+    #[validate(range(min = 1))]
+    pub nprobe: usize,
+    /// Minimal size threshold (in KiloBytes) below which full-scan is preferred over IVF search.
+    /// Same as `full_scan_threshold` of HNSW index. Default: 10000
+    #[serde(
+        default = "default_ivf_full_scan_threshold",
+        alias = "full_scan_threshold_kb"
+    )]
+    pub full_scan_threshold: usize,
+    /// Number of parallel threads used for background index building.
+    /// If 0 - automatically select.
+    #[serde(default = "default_max_indexing_threads")]
+    pub max_indexing_threads: usize,
+}
+
+impl Default for IvfConfig {
+    fn default() -> Self {
+        Self {
+            num_lists: None,
+            nprobe: default_ivf_nprobe(),
+            full_scan_threshold: default_ivf_full_scan_threshold(),
+            max_indexing_threads: default_max_indexing_threads(),
+        }
+    }
+}
+
+impl IvfConfig {
+    /// Detect configuration mismatch against `other` that requires rebuilding
+    ///
+    /// Same as [`HnswConfig::mismatch_requires_rebuild`], search parameters are stored along
+    /// with the index, so they are applied by rebuilding as well.
+    pub fn mismatch_requires_rebuild(&self, other: &Self) -> bool {
+        let IvfConfig {
+            num_lists,
+            nprobe,
+            full_scan_threshold,
+            max_indexing_threads: _,
+        } = *self;
+
+        num_lists != other.num_lists
+            || nprobe != other.nprobe
+            || full_scan_threshold != other.full_scan_threshold
+    }
+}
+
+const fn default_ivf_nprobe() -> usize {
+    16
+}
+
+const fn default_ivf_full_scan_threshold() -> usize {
+    DEFAULT_FULL_SCAN_THRESHOLD
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Validate, Anonymize, Clone)]
 #[serde(rename_all = "snake_case", default)]
 #[anonymize(false)]
@@ -761,6 +1050,7 @@ pub enum CompressionRatio {
 pub enum ScalarType {
     #[default]
     Int8,
+    Int4,
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, JsonSchema, Validate)]
@@ -768,6 +1058,7 @@ pub enum ScalarType {
 pub struct ScalarQuantizationConfig {
     /// Type of quantization to use
     /// If `int8` - 8 bit quantization will be used
+    /// If `int4` - 4 bit quantization with per-dimension ranges will be used
     pub r#type: ScalarType,
     /// Quantile for quantization. Expected value range in [0.5, 1.0]. If not set - use the whole range of values
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -802,6 +1093,12 @@ pub struct ProductQuantizationConfig {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub always_ram: Option<bool>,
+
+    /// If true - learn an orthogonal rotation (OPQ), which balances variance between chunks,
+    /// and quantize rotated vectors. Improves accuracy on anisotropic data.
+    /// Not applied to Manhattan distance. Default: false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opq: Option<bool>,
 }
 
 impl ProductQuantizationConfig {
@@ -1111,6 +1408,10 @@ pub struct StrictModeConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search_allow_exact: Option<bool>,
 
+    /// Whether radius search is allowed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_allow_radius: Option<bool>,
+
     /// Max oversampling value allowed in search.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search_max_oversampling: Option<f64>,
@@ -1192,6 +1493,7 @@ impl Hash for StrictModeConfig {
             unindexed_filtering_update,
             search_max_hnsw_ef,
             search_allow_exact,
+            search_allow_radius,
             // We skip hashing this field because we cannot reliably hash a float
             search_max_oversampling: _,
             upsert_max_batchsize,
@@ -1215,6 +1517,7 @@ impl Hash for StrictModeConfig {
         unindexed_filtering_update.hash(state);
         search_max_hnsw_ef.hash(state);
         search_allow_exact.hash(state);
+        search_allow_radius.hash(state);
         upsert_max_batchsize.hash(state);
         search_max_batchsize.hash(state);
         max_collection_vector_size_bytes.hash(state);
@@ -1267,6 +1570,10 @@ pub struct StrictModeConfigOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search_allow_exact: Option<bool>,
 
+    /// Whether radius search is allowed or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_allow_radius: Option<bool>,
+
     /// Max oversampling value allowed in search.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[anonymize(false)]
@@ -1344,6 +1651,7 @@ impl From<StrictModeConfig> for StrictModeConfigOutput {
             unindexed_filtering_update,
             search_max_hnsw_ef,
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling,
             upsert_max_batchsize,
             search_max_batchsize,
@@ -1368,6 +1676,7 @@ impl From<StrictModeConfig> for StrictModeConfigOutput {
             unindexed_filtering_update,
             search_max_hnsw_ef,
             search_allow_exact,
+            search_allow_radius,
             search_max_oversampling,
             upsert_max_batchsize,
             search_max_batchsize,
@@ -1610,21 +1919,57 @@ pub enum VectorStorageDatatype {
     Float16,
     // Unsigned 8-bit integer
     Uint8,
+    // Binary vector, 8 dimensions packed into a byte
+    Bit,
 }
 
 #[derive(
-    Debug, Default, Deserialize, Serialize, JsonSchema, Anonymize, Eq, PartialEq, Copy, Clone, Hash,
+    Debug,
+    Default,
+    Deserialize,
+    Serialize,
+    JsonSchema,
+    Anonymize,
+    Validate,
+    Eq,
+    PartialEq,
+    Copy,
+    Clone,
+    Hash,
 )]
+#[validate(schema(function = "validate_multivector_config"))]
 #[serde(rename_all = "snake_case")]
 pub struct MultiVectorConfig {
     /// How to compare multivector points
     pub comparator: MultiVectorComparator,
+    /// If set, the HNSW index is built on fixed-dimensional encodings (MUVERA) of multivectors.
+    /// Candidates found through the encodings are rescored with the exact comparator.
+    /// Requires `Dot` or `Cosine` distance and the `max_sim` comparator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub fde: Option<FdeConfig>,
+}
+
+fn validate_multivector_config(config: &MultiVectorConfig) -> Result<(), ValidationError> {
+    match (config.comparator, config.fde) {
+        (MultiVectorComparator::MaxSim, _) | (_, None) => Ok(()),
+        (
+            MultiVectorComparator::Chamfer
+            | MultiVectorComparator::MeanSim
+            | MultiVectorComparator::MaxMax
+            | MultiVectorComparator::MeanPool,
+            Some(_),
+        ) => Err(ValidationError::new(
+            "fixed-dimensional encoding of multivectors requires the max_sim comparator",
+        )),
+    }
 }
 
 impl MultiVectorConfig {
     fn check_compatible(&self, other: &Self) -> Result<(), String> {
         // Assert multi-vector config fields
-        let Self { comparator } = self;
+        // Fixed-dimensional encoding only affects the index, so it may differ
+        let Self { comparator, fde: _ } = self;
 
         if *comparator != other.comparator {
             return Err(format!(
@@ -1642,8 +1987,20 @@ impl MultiVectorConfig {
 )]
 #[serde(rename_all = "snake_case")]
 pub enum MultiVectorComparator {
+    /// Sum of the best matching similarities for each query vector, as in ColBERT late interaction
     #[default]
     MaxSim,
+    /// Symmetric Chamfer similarity: average of the mean best matching similarities in both directions
+    Chamfer,
+    /// Mean similarity over all pairs of inner vectors.
+    /// For dot product it equals the similarity of the mean-pooled multivectors,
+    /// for other distances vectors are not pooled
+    MeanSim,
+    /// Similarity of the single best matching pair of inner vectors
+    MaxMax,
+    /// Similarity of the mean-pooled multivectors: inner vectors are averaged,
+    /// the average is normalized for cosine distance, and then the distance is applied
+    MeanPool,
 }
 
 impl VectorStorageType {
@@ -1696,6 +2053,11 @@ pub struct VectorDataConfig {
     /// Vector specific configuration to set specific storage element type
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub datatype: Option<VectorStorageDatatype>,
+    /// Number of leading dimensions indexed as a separate truncated view of the vectors.
+    /// Nearest queries with the `matryoshka` search param are scored on the first dimensions
+    /// of stored vectors, and searched in a dedicated graph of the HNSW index.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matryoshka_dim: Option<usize>,
 }
 
 impl VectorDataConfig {
@@ -1706,6 +2068,8 @@ impl VectorDataConfig {
         let is_index_appendable = match self.index {
             Indexes::Plain {} => true,
             Indexes::Hnsw(_) => false,
+            Indexes::Vamana(_) => false,
+            Indexes::Ivf(_) => false,
         };
         let is_storage_appendable = match self.storage_type {
             VectorStorageType::Memory => true,
@@ -1731,6 +2095,7 @@ impl VectorDataConfig {
             quantization_config: _,
             multivector_config,
             datatype,
+            matryoshka_dim,
         } = self;
 
         if *size != other.size {
@@ -1755,6 +2120,13 @@ impl VectorDataConfig {
             ));
         }
 
+        if *matryoshka_dim != other.matryoshka_dim {
+            return Err(format!(
+                "Incompatible configs: expected matryoshka dimension {matryoshka_dim:?}, but got {other_matryoshka_dim:?}",
+                other_matryoshka_dim = other.matryoshka_dim
+            ));
+        }
+
         match (multivector_config, &other.multivector_config) {
             (None, None) => {}
             (Some(this), Some(other)) => {
@@ -1772,6 +2144,91 @@ impl VectorDataConfig {
     }
 }
 
+pub const DEFAULT_FDE_KSIM: usize = 4;
+pub const DEFAULT_FDE_DIM_PROJ: usize = 16;
+pub const DEFAULT_FDE_REPETITIONS: usize = 10;
+pub const DEFAULT_FDE_CANDIDATES_FACTOR: usize = 4;
+/// Upper bound on `repetitions * 2^ksim * dim_proj`, the size of a single encoding
+pub const MAX_FDE_ENCODING_DIM: usize = 16_384;
+
+/// Configuration of fixed-dimensional encoding (FDE) of multivectors.
+///
+/// Each multivector is encoded into a single dense vector of size
+/// `repetitions * 2^ksim * dim_proj`, whose dot product approximates MaxSim.
+/// The encoding size must not exceed 16384.
+#[derive(
+    Debug, Deserialize, Serialize, JsonSchema, Anonymize, Validate, Eq, PartialEq, Copy, Clone, Hash,
+)]
+#[validate(schema(function = "validate_fde_config"))]
+#[serde(rename_all = "snake_case")]
+pub struct FdeConfig {
+    /// Number of SimHash hyperplanes in each repetition, splits the space into `2^ksim` buckets.
+    /// Higher values improve recall at the cost of a larger encoding. Default: 4
+    #[serde(default = "default_fde_ksim")]
+    #[validate(range(min = 1, max = 8))]
+    pub ksim: usize,
+    /// Size of the random projection of each bucket.
+    /// If not less than the vector size, vectors are not projected. Default: 16
+    #[serde(default = "default_fde_dim_proj")]
+    #[validate(range(min = 1, max = 1024))]
+    pub dim_proj: usize,
+    /// Number of independent repetitions of the encoding.
+    /// Higher values improve recall at the cost of a larger encoding. Default: 10
+    #[serde(default = "default_fde_repetitions")]
+    #[validate(range(min = 1, max = 64))]
+    pub repetitions: usize,
+    /// How many candidates to retrieve with the encoding for each requested result,
+    /// before rescoring them with the exact comparator. Default: 4
+    #[serde(default = "default_fde_candidates_factor")]
+    #[validate(range(min = 1, max = 100))]
+    pub candidates_factor: usize,
+}
+
+impl Default for FdeConfig {
+    fn default() -> Self {
+        Self {
+            ksim: DEFAULT_FDE_KSIM,
+            dim_proj: DEFAULT_FDE_DIM_PROJ,
+            repetitions: DEFAULT_FDE_REPETITIONS,
+            candidates_factor: DEFAULT_FDE_CANDIDATES_FACTOR,
+        }
+    }
+}
+
+impl FdeConfig {
+    /// Size of the encoding if vectors are projected to `dim_proj` dimensions
+    pub fn max_encoding_dim(&self) -> usize {
+        self.repetitions
+            .saturating_mul(1usize.checked_shl(self.ksim as u32).unwrap_or(usize::MAX))
+            .saturating_mul(self.dim_proj)
+    }
+}
+
+fn validate_fde_config(config: &FdeConfig) -> Result<(), ValidationError> {
+    if config.max_encoding_dim() > MAX_FDE_ENCODING_DIM {
+        return Err(ValidationError::new(
+            "fixed-dimensional encoding size repetitions * 2^ksim * dim_proj must not exceed 16384",
+        ));
+    }
+    Ok(())
+}
+
+const fn default_fde_ksim() -> usize {
+    DEFAULT_FDE_KSIM
+}
+
+const fn default_fde_dim_proj() -> usize {
+    DEFAULT_FDE_DIM_PROJ
+}
+
+const fn default_fde_repetitions() -> usize {
+    DEFAULT_FDE_REPETITIONS
+}
+
+const fn default_fde_candidates_factor() -> usize {
+    DEFAULT_FDE_CANDIDATES_FACTOR
+}
+
 #[derive(
     Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize, JsonSchema, Anonymize,
 )]
@@ -2196,6 +2653,19 @@ pub enum PayloadSchemaParams {
     Bool(BoolIndexParams),
     Datetime(DatetimeIndexParams),
     Uuid(UuidIndexParams),
+    Composite(CompositeIndexParams),
+}
+
+impl From<CompositeFieldType> for PayloadSchemaType {
+    fn from(field_type: CompositeFieldType) -> Self {
+        match field_type {
+            CompositeFieldType::Keyword => PayloadSchemaType::Keyword,
+            CompositeFieldType::Integer => PayloadSchemaType::Integer,
+            CompositeFieldType::Float => PayloadSchemaType::Float,
+            CompositeFieldType::Datetime => PayloadSchemaType::Datetime,
+            CompositeFieldType::Uuid => PayloadSchemaType::Uuid,
+        }
+    }
 }
 
 impl PayloadSchemaParams {
@@ -2214,6 +2684,7 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Bool(_) => PayloadSchemaType::Bool,
             PayloadSchemaParams::Datetime(_) => PayloadSchemaType::Datetime,
             PayloadSchemaParams::Uuid(_) => PayloadSchemaType::Uuid,
+            PayloadSchemaParams::Composite(composite) => composite.field_type.into(),
         }
     }
 
@@ -2226,7 +2697,8 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Uuid(uuid) => uuid.is_tenant.unwrap_or_default(),
             PayloadSchemaParams::Geo(_)
             | PayloadSchemaParams::Text(_)
-            | PayloadSchemaParams::Bool(_) => false,
+            | PayloadSchemaParams::Bool(_)
+            | PayloadSchemaParams::Composite(_) => false,
         }
     }
 
@@ -2240,6 +2712,7 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Text(i) => i.on_disk.unwrap_or_default(),
             PayloadSchemaParams::Geo(i) => i.on_disk.unwrap_or_default(),
             PayloadSchemaParams::Bool(i) => i.on_disk.unwrap_or_default(),
+            PayloadSchemaParams::Composite(i) => i.on_disk.unwrap_or_default(),
         }
     }
 
@@ -2253,6 +2726,66 @@ impl PayloadSchemaParams {
             PayloadSchemaParams::Text(params) => params.enable_hnsw.unwrap_or(true),
             PayloadSchemaParams::Geo(params) => params.enable_hnsw.unwrap_or(true),
             PayloadSchemaParams::Bool(params) => params.enable_hnsw.unwrap_or(true),
+            PayloadSchemaParams::Composite(params) => params.enable_hnsw.unwrap_or(true),
+        }
+    }
+
+    /// Parameters of the regular index of the field itself.
+    ///
+    /// Same as `self` for all types but composite, which also gets a regular index of its first field.
+    pub fn single_field_params(&self) -> Cow<'_, PayloadSchemaParams> {
+        let PayloadSchemaParams::Composite(composite) = self else {
+            return Cow::Borrowed(self);
+        };
+        let CompositeIndexParams {
+            r#type: _,
+            field_type,
+            fields: _,
+            on_disk,
+            enable_hnsw,
+        } = *composite;
+        let params = match field_type {
+            CompositeFieldType::Keyword => PayloadSchemaParams::Keyword(KeywordIndexParams {
+                on_disk,
+                enable_hnsw,
+                ..Default::default()
+            }),
+            CompositeFieldType::Integer => PayloadSchemaParams::Integer(IntegerIndexParams {
+                on_disk,
+                enable_hnsw,
+                ..Default::default()
+            }),
+            CompositeFieldType::Float => PayloadSchemaParams::Float(FloatIndexParams {
+                on_disk,
+                enable_hnsw,
+                ..Default::default()
+            }),
+            CompositeFieldType::Datetime => PayloadSchemaParams::Datetime(DatetimeIndexParams {
+                on_disk,
+                enable_hnsw,
+                ..Default::default()
+            }),
+            CompositeFieldType::Uuid => PayloadSchemaParams::Uuid(UuidIndexParams {
+                on_disk,
+                enable_hnsw,
+                ..Default::default()
+            }),
+        };
+        Cow::Owned(params)
+    }
+
+    /// Parameters of the composite key, if this is a composite index
+    pub fn as_composite(&self) -> Option<&CompositeIndexParams> {
+        match self {
+            PayloadSchemaParams::Composite(composite) => Some(composite),
+            PayloadSchemaParams::Keyword(_)
+            | PayloadSchemaParams::Integer(_)
+            | PayloadSchemaParams::Float(_)
+            | PayloadSchemaParams::Geo(_)
+            | PayloadSchemaParams::Text(_)
+            | PayloadSchemaParams::Bool(_)
+            | PayloadSchemaParams::Datetime(_)
+            | PayloadSchemaParams::Uuid(_) => None,
         }
     }
 }
@@ -2268,6 +2801,9 @@ impl Validate for PayloadSchemaParams {
             PayloadSchemaParams::Bool(_) => Ok(()),
             PayloadSchemaParams::Datetime(_) => Ok(()),
             PayloadSchemaParams::Uuid(_) => Ok(()),
+            PayloadSchemaParams::Composite(composite_index_params) => {
+                composite_index_params.validate()
+            }
         }
     }
 }
@@ -2337,6 +2873,17 @@ impl Display for PayloadFieldSchema {
                         write!(f, "text")
                     }
                 }
+                PayloadSchemaParams::Composite(composite_params) => {
+                    let fields = composite_params
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let field_type = PayloadSchemaType::from(field.r#type);
+                            format!("{}: {}", field.key, field_type.name())
+                        })
+                        .join(", ");
+                    write!(f, "{} (composite with {fields})", params.name())
+                }
             },
         }
     }
@@ -2350,6 +2897,22 @@ impl PayloadFieldSchema {
         }
     }
 
+    /// Parameters of the regular index of the field itself, see [`PayloadSchemaParams::single_field_params`]
+    pub fn single_field_params(&self) -> Cow<'_, PayloadSchemaParams> {
+        match self {
+            PayloadFieldSchema::FieldType(t) => Cow::Owned(t.expand()),
+            PayloadFieldSchema::FieldParams(p) => p.single_field_params(),
+        }
+    }
+
+    /// Parameters of the composite key, if this is a composite index
+    pub fn as_composite(&self) -> Option<&CompositeIndexParams> {
+        match self {
+            PayloadFieldSchema::FieldType(_) => None,
+            PayloadFieldSchema::FieldParams(p) => p.as_composite(),
+        }
+    }
+
     /// Human-readable type name
     pub fn name(&self) -> &'static str {
         match self {
@@ -2403,10 +2966,35 @@ impl PayloadFieldSchema {
                 PayloadSchemaParams::Geo(_) => false,
                 PayloadSchemaParams::Text(_) => false,
                 PayloadSchemaParams::Datetime(_) => false,
+                PayloadSchemaParams::Composite(composite_index_params) => {
+                    match composite_index_params.field_type {
+                        CompositeFieldType::Keyword
+                        | CompositeFieldType::Integer
+                        | CompositeFieldType::Uuid => true,
+                        CompositeFieldType::Float | CompositeFieldType::Datetime => false,
+                    }
+                }
             },
         }
     }
 
+    /// Check if the index of this type can list the distinct values of the field, see `FacetIndex`
+    pub fn supports_facet(&self) -> bool {
+        match self.single_field_params().as_ref() {
+            PayloadSchemaParams::Keyword(_) => true,
+            PayloadSchemaParams::Integer(integer_index_params) => {
+                integer_index_params.lookup.unwrap_or(true)
+            }
+            PayloadSchemaParams::Uuid(_) => true,
+            PayloadSchemaParams::Bool(_) => true,
+            PayloadSchemaParams::Float(_)
+            | PayloadSchemaParams::Geo(_)
+            | PayloadSchemaParams::Text(_)
+            | PayloadSchemaParams::Datetime(_)
+            | PayloadSchemaParams::Composite(_) => false,
+        }
+    }
+
     pub fn enable_hnsw(&self) -> bool {
         match self {
             PayloadFieldSchema::FieldType(_) => true,
@@ -2700,11 +3288,12 @@ impl From<Vec<IntPayloadType>> for MatchExcept {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, JsonSchema)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum RangeInterface {
     Float(Range<OrderedFloat<FloatPayloadType>>),
     DateTime(Range<DateTimePayloadType>),
+    Keyword(KeywordRange),
 }
 
 impl Hash for RangeInterface {
@@ -2724,6 +3313,13 @@ impl Hash for RangeInterface {
                 gte.hash(state);
                 lte.hash(state);
             }
+            RangeInterface::Keyword(KeywordRange { keyword }) => {
+                let Range { lt, gt, gte, lte } = keyword;
+                lt.hash(state);
+                gt.hash(state);
+                gte.hash(state);
+                lte.hash(state);
+            }
         }
     }
 }
@@ -2731,10 +3327,22 @@ impl Hash for RangeInterface {
 #[derive(serde::Deserialize)]
 #[serde(untagged)]
 enum RangeInterfaceUntagged {
+    // Goes first, as float and datetime ranges would accept any object with unknown fields
+    Keyword(KeywordRange),
     Float(Range<OrderedFloatPayloadType>),
     DateTime(Range<DateTimePayloadType>),
 }
 
+impl From<RangeInterfaceUntagged> for RangeInterface {
+    fn from(parsed: RangeInterfaceUntagged) -> Self {
+        match parsed {
+            RangeInterfaceUntagged::Keyword(r) => RangeInterface::Keyword(r),
+            RangeInterfaceUntagged::Float(r) => RangeInterface::Float(r),
+            RangeInterfaceUntagged::DateTime(r) => RangeInterface::DateTime(r),
+        }
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for RangeInterface {
     /// Parses range bounds, treating string bounds as RFC3339 datetimes for REST/JSON `datetime_range` filters.
     /// Preserves clear user-facing errors when datetime formats are invalid.
@@ -2744,16 +3352,20 @@ impl<'de> serde::Deserialize<'de> for RangeInterface {
         D: serde::Deserializer<'de>,
     {
         if !deserializer.is_human_readable() {
-            return RangeInterfaceUntagged::deserialize(deserializer).map(|parsed| match parsed {
-                RangeInterfaceUntagged::Float(r) => RangeInterface::Float(r),
-                RangeInterfaceUntagged::DateTime(r) => RangeInterface::DateTime(r),
-            });
+            return RangeInterfaceUntagged::deserialize(deserializer).map(RangeInterface::from);
         }
 
         let value = serde_json::Value::deserialize(deserializer)?;
 
-        // If any range bound is a string -> treat as datetime range
         if let Some(obj) = value.as_object() {
+            // Bounds nested under `keyword` -> lexicographic keyword range
+            if obj.contains_key("keyword") {
+                return serde_json::from_value::<KeywordRange>(value)
+                    .map(RangeInterface::Keyword)
+                    .map_err(serde::de::Error::custom);
+            }
+
+            // If any range bound is a string -> treat as datetime range
             let keys = ["lt", "gt", "lte", "gte"];
             let has_string_bound = keys
                 .iter()
@@ -2767,21 +3379,27 @@ impl<'de> serde::Deserialize<'de> for RangeInterface {
         }
 
         // Fallback to existing untagged behavior
-        let parsed = serde_json::from_value::<RangeInterfaceUntagged>(value)
-            .map_err(serde::de::Error::custom)?;
-
-        Ok(match parsed {
-            RangeInterfaceUntagged::Float(r) => RangeInterface::Float(r),
-            RangeInterfaceUntagged::DateTime(r) => RangeInterface::DateTime(r),
-        })
+        serde_json::from_value::<RangeInterfaceUntagged>(value)
+            .map(RangeInterface::from)
+            .map_err(serde::de::Error::custom)
     }
 }
 
+/// Lexicographic range filter request on keyword values.
+///
+/// Values are compared byte-wise, by their UTF-8 representation.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct KeywordRange {
+    /// Bounds of the range
+    pub keyword: Range<String>,
+}
+
 type OrderedFloatPayloadType = OrderedFloat<FloatPayloadType>;
 
 /// Range filter request
 #[macro_rules_attribute::macro_rules_derive(crate::common::macros::schemars_rename_generics)]
-#[derive_args(< OrderedFloatPayloadType > => "Range", < DateTimePayloadType > => "DatetimeRange")]
+#[derive_args(< OrderedFloatPayloadType > => "Range", < DateTimePayloadType > => "DatetimeRange", < String > => "KeywordRangeBounds")]
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Range<T> {
@@ -2808,6 +3426,19 @@ impl<T: Copy> Range<T> {
     }
 }
 
+impl<T: Deref> Range<T> {
+    /// Borrow bounds of the range, e.g. `Range<&str>` from `Range<String>`
+    pub fn as_deref(&self) -> Range<&T::Target> {
+        let Self { lt, gt, gte, lte } = self;
+        Range {
+            lt: lt.as_deref(),
+            gt: gt.as_deref(),
+            gte: gte.as_deref(),
+            lte: lte.as_deref(),
+        }
+    }
+}
+
 impl<T: Copy + PartialOrd> Range<T> {
     pub fn check_range(&self, number: T) -> bool {
         let Self { lt, gt, gte, lte } = self;
@@ -2816,6 +3447,28 @@ impl<T: Copy + PartialOrd> Range<T> {
             && lte.is_none_or(|x| number <= x)
             && gte.is_none_or(|x| number >= x)
     }
+
+    /// Whether the value is smaller than the lower bound of the range
+    pub fn is_below(&self, number: T) -> bool {
+        let Self {
+            lt: _,
+            gt,
+            gte,
+            lte: _,
+        } = self;
+        gt.is_some_and(|x| number <= x) || gte.is_some_and(|x| number < x)
+    }
+
+    /// Whether the value is larger than the upper bound of the range
+    pub fn is_above(&self, number: T) -> bool {
+        let Self {
+            lt,
+            gt: _,
+            gte: _,
+            lte,
+        } = self;
+        lt.is_some_and(|x| number >= x) || lte.is_some_and(|x| number > x)
+    }
 }
 
 /// Values count filter request
@@ -3112,6 +3765,22 @@ impl FieldCondition {
         }
     }
 
+    pub fn new_keyword_range(key: PayloadKeyType, keyword_range: Range<String>) -> Self {
+        Self {
+            key,
+            r#match: None,
+            range: Some(RangeInterface::Keyword(KeywordRange {
+                keyword: keyword_range,
+            })),
+            geo_bounding_box: None,
+            geo_radius: None,
+            geo_polygon: None,
+            values_count: None,
+            is_empty: None,
+            is_null: None,
+        }
+    }
+
     pub fn new_geo_bounding_box(key: PayloadKeyType, geo_bounding_box: GeoBoundingBox) -> Self {
         Self {
             key,
@@ -4134,6 +4803,21 @@ mod tests {
         assert_eq!(datetime, deserialized);
     }
 
+    #[rstest]
+    #[case::cosine(Distance::Cosine, 0.75)]
+    #[case::dot(Distance::Dot, -2.5)]
+    #[case::euclid(Distance::Euclid, 1.5)]
+    #[case::manhattan(Distance::Manhattan, 3.0)]
+    #[case::hamming(Distance::Hamming, 4.0)]
+    #[case::jaccard(Distance::Jaccard, 0.25)]
+    fn test_preprocess_score_threshold(#[case] distance: Distance, #[case] threshold: ScoreType) {
+        let internal = distance.preprocess_score_threshold(threshold);
+        assert_eq!(distance.postprocess_score(internal), threshold);
+        // Internal scores are similarities, so better scores are always larger
+        let better = internal + 0.1;
+        assert!(distance.check_threshold(distance.postprocess_score(better), threshold));
+    }
+
     #[test]
     fn test_datetime_deserialization_equivalency() {
         let datetime_str = "2020-03-01T01:02:03.123456Z";
@@ -4197,6 +4881,28 @@ mod tests {
         assert_eq!(range, restored);
     }
 
+    #[test]
+    fn test_range_interface_keyword() {
+        let json = r#"{ "keyword": { "gte": "v1.10", "lt": "v2" } }"#;
+        let range: RangeInterface = serde_json::from_str(json).unwrap();
+
+        let RangeInterface::Keyword(KeywordRange { keyword }) = &range else {
+            panic!("expected keyword range, got {range:?}");
+        };
+        assert!(keyword.as_deref().check_range("v1.9"));
+        assert!(!keyword.as_deref().check_range("v1.0"));
+        assert!(!keyword.as_deref().check_range("v2"));
+
+        let binary = rmp_serde::to_vec(&range).expect("serialize");
+        let restored: RangeInterface = rmp_serde::from_slice(&binary).expect("deserialize");
+        assert_eq!(range, restored);
+
+        // String bounds without `keyword` are still datetimes
+        let json = r#"{ "gte": "2014-01-01T00:00:00Z" }"#;
+        let range: RangeInterface = serde_json::from_str(json).unwrap();
+        assert!(matches!(range, RangeInterface::DateTime(_)));
+    }
+
     /// Regression test: Non-FieldCondition JSON deserialization uses ConditionUntagged fallback.
     /// Ensures compiler-safe handling of other Condition variants.
     #[test]
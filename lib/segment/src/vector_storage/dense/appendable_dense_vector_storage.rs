@@ -17,7 +17,7 @@ use crate::common::flags::dynamic_stored_flags::DynamicStoredFlags;
 use crate::common::operation_error::{OperationResult, check_process_stopped};
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::{VectorElementType, VectorRef};
+use crate::data_types::vectors::{PackedBits, VectorElementType, VectorRef};
 use crate::types::{Distance, VectorStorageDatatype};
 use crate::vector_storage::chunked_vectors::ChunkedVectors;
 use crate::vector_storage::{
@@ -240,6 +240,27 @@ pub fn open_appendable_memmap_vector_storage_half(
     )))
 }
 
+/// Open storage of binary vectors, `dim` is the number of dimensions before packing
+pub fn open_appendable_memmap_vector_storage_bit(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    madvise: AdviceSetting,
+    populate: bool,
+) -> OperationResult<VectorStorageEnum> {
+    let storage = open_appendable_memmap_vector_storage_impl(
+        path,
+        PackedBits::packed_dim(dim),
+        distance,
+        madvise,
+        populate,
+    )?;
+
+    Ok(VectorStorageEnum::DenseAppendableMemmapBit(Box::new(
+        storage,
+    )))
+}
+
 pub fn open_appendable_memmap_vector_storage_impl<T: PrimitiveVectorElement>(
     path: &Path,
     dim: usize,
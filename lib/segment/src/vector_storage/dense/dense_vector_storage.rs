@@ -17,7 +17,7 @@ use crate::common::Flusher;
 use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
 use crate::data_types::named_vectors::CowVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::VectorRef;
+use crate::data_types::vectors::{PackedBits, VectorRef};
 use crate::types::{Distance, VectorStorageDatatype};
 #[cfg(target_os = "linux")]
 use crate::vector_storage::common::get_async_scorer;
@@ -140,6 +140,31 @@ pub fn open_dense_vector_storage_half(
     Ok(VectorStorageEnum::DenseMemmapHalf(Box::new(mmap_storage)))
 }
 
+/// Open storage of binary vectors, `dim` is the number of dimensions before packing
+pub fn open_dense_vector_storage_bit(
+    path: &Path,
+    dim: usize,
+    distance: Distance,
+    populate: bool,
+) -> OperationResult<VectorStorageEnum> {
+    let dim = PackedBits::packed_dim(dim);
+
+    #[cfg(target_os = "linux")]
+    if get_async_scorer() {
+        match open_dense_vector_storage_impl(path, dim, distance, populate) {
+            Ok(uring_storage) => {
+                return Ok(VectorStorageEnum::DenseUringBit(Box::new(uring_storage)));
+            }
+            Err(err) => {
+                log::error!("failed to open io_uring based vector storage: {err}");
+            }
+        }
+    }
+
+    let mmap_storage = open_dense_vector_storage_impl(path, dim, distance, populate)?;
+    Ok(VectorStorageEnum::DenseMemmapBit(Box::new(mmap_storage)))
+}
+
 pub fn open_dense_vector_storage_byte(
     path: &Path,
     dim: usize,
@@ -240,6 +240,7 @@ mod tests {
     fn test_empty_dense_multi_vector_config() {
         let multi_cfg = MultiVectorConfig {
             comparator: crate::types::MultiVectorComparator::MaxSim,
+            fde: None,
         };
         let storage = EmptyDenseVectorStorage::new(
             64,
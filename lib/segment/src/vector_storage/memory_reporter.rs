@@ -43,6 +43,9 @@ impl MemoryReporter for VectorStorageEnum {
             VectorStorageEnum::DenseMemmapHalf(v) => {
                 from_files_with_on_disk(v.files(), v.is_on_disk())
             }
+            VectorStorageEnum::DenseMemmapBit(v) => {
+                from_files_with_on_disk(v.files(), v.is_on_disk())
+            }
 
             // io_uring dense variants: always on-disk, no mmap caching
             #[cfg(target_os = "linux")]
@@ -57,6 +60,10 @@ impl MemoryReporter for VectorStorageEnum {
             VectorStorageEnum::DenseUringHalf(v) => {
                 ComponentMemoryUsage::from_files(v.files(), FileStorageIntent::OnDisk)
             }
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => {
+                ComponentMemoryUsage::from_files(v.files(), FileStorageIntent::OnDisk)
+            }
 
             // Appendable mmap dense variants: intent depends on populate config
             VectorStorageEnum::DenseAppendableMemmap(v) => {
@@ -68,6 +75,9 @@ impl MemoryReporter for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 from_files_with_on_disk(v.files(), v.is_on_disk())
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => {
+                from_files_with_on_disk(v.files(), v.is_on_disk())
+            }
 
             // Volatile sparse: in-memory
             VectorStorageEnum::SparseVolatile(v) => {
@@ -23,8 +23,8 @@ use crate::data_types::vectors::{
 use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
 use crate::vector_storage::chunked_vectors::{ChunkedVectors, ChunkedVectorsRead};
 use crate::vector_storage::dense::appendable_dense_vector_storage::{
-    open_appendable_memmap_vector_storage_byte, open_appendable_memmap_vector_storage_full,
-    open_appendable_memmap_vector_storage_half,
+    open_appendable_memmap_vector_storage_bit, open_appendable_memmap_vector_storage_byte,
+    open_appendable_memmap_vector_storage_full, open_appendable_memmap_vector_storage_half,
 };
 use crate::vector_storage::{
     MultiVectorStorage, VectorOffset, VectorOffsetType, VectorStorage, VectorStorageEnum,
@@ -409,6 +409,13 @@ pub fn open_appendable_memmap_vector_storage(
             madvise,
             populate,
         ),
+        VectorStorageDatatype::Bit => open_appendable_memmap_vector_storage_bit(
+            vector_storage_path,
+            size,
+            distance,
+            madvise,
+            populate,
+        ),
     }
 }
 
@@ -446,6 +453,9 @@ pub fn open_appendable_memmap_multi_vector_storage(
             madvise,
             populate,
         ),
+        VectorStorageDatatype::Bit => Err(OperationError::validation_error(
+            "Multivectors are not supported for bit vectors",
+        )),
     }
 }
 
@@ -36,9 +36,11 @@ impl VectorStorageEnum {
             | VectorStorageEnum::DenseMemmap(_)
             | VectorStorageEnum::DenseMemmapByte(_)
             | VectorStorageEnum::DenseMemmapHalf(_)
+            | VectorStorageEnum::DenseMemmapBit(_)
             | VectorStorageEnum::DenseAppendableMemmap(_)
             | VectorStorageEnum::DenseAppendableMemmapByte(_)
             | VectorStorageEnum::DenseAppendableMemmapHalf(_)
+            | VectorStorageEnum::DenseAppendableMemmapBit(_)
             | VectorStorageEnum::MultiDenseVolatile(_)
             | VectorStorageEnum::MultiDenseAppendableMemmap(_)
             | VectorStorageEnum::MultiDenseAppendableMemmapByte(_)
@@ -53,7 +55,8 @@ impl VectorStorageEnum {
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(_)
             | VectorStorageEnum::DenseUringByte(_)
-            | VectorStorageEnum::DenseUringHalf(_) => {}
+            | VectorStorageEnum::DenseUringHalf(_)
+            | VectorStorageEnum::DenseUringBit(_) => {}
 
             VectorStorageEnum::SparseVolatile(_) | VectorStorageEnum::SparseMmap(_) => {
                 let stopped = AtomicBool::new(false);
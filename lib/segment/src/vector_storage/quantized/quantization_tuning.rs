@@ -0,0 +1,324 @@
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::time::{Duration, Instant};
+
+use common::bitvec::BitVec;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::PointOffsetType;
+
+use crate::common::operation_error::{OperationError, OperationResult, check_process_stopped};
+use crate::data_types::vectors::{DenseVector, QueryVector, VectorElementType, VectorRef};
+use crate::index::hnsw_index::point_scorer::BatchFilteredSearcher;
+use crate::index::vector_index_search_common::{
+    get_oversampled_top, is_quantized_search, postprocess_search_result,
+};
+use crate::types::{Distance, QuantizationConfig, QuantizationSearchParams, SearchParams};
+use crate::vector_storage::dense::volatile_dense_vector_storage::new_volatile_dense_vector_storage;
+use crate::vector_storage::quantized::quantized_vectors::{
+    QuantizedVectors, QuantizedVectorsStorageType,
+};
+use crate::vector_storage::{VectorStorage, VectorStorageEnum, VectorStorageRead};
+
+/// In-memory sample of dense vectors together with exact nearest neighbours of some of them.
+///
+/// Used to compare quantization configs against exact search without touching real segments.
+/// The first `num_queries` vectors of the sample are used as queries, so the sample is expected
+/// to be randomly ordered.
+pub struct QuantizationTuningSample {
+    vector_storage: VectorStorageEnum,
+    point_deleted: BitVec,
+    queries: Vec<(PointOffsetType, QueryVector)>,
+    /// Exact nearest neighbours of each query, excluding the query itself
+    exact_results: Vec<Vec<PointOffsetType>>,
+    /// Average time of a single exact search over the sample
+    exact_latency: Duration,
+    limit: usize,
+}
+
+/// Quality and cost of a single quantization config, measured on a [`QuantizationTuningSample`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantizationEvaluation {
+    /// Fraction of exact nearest neighbours, which are also found with quantized search
+    pub recall: f64,
+    /// Size of a single quantized vector in bytes
+    pub vector_size_bytes: usize,
+    /// Average time of a single quantized search over the sample
+    pub latency: Duration,
+}
+
+impl QuantizationTuningSample {
+    pub fn new(
+        vectors: Vec<DenseVector>,
+        distance: Distance,
+        num_queries: usize,
+        limit: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<Self> {
+        let Some(dim) = vectors.first().map(|vector| vector.len()) else {
+            return Err(OperationError::validation_error(
+                "Can't tune quantization on an empty sample",
+            ));
+        };
+
+        let hw_counter = HardwareCounterCell::disposable();
+        let mut vector_storage = new_volatile_dense_vector_storage(dim, distance);
+        let mut queries = Vec::with_capacity(num_queries);
+        for (offset, vector) in vectors.into_iter().enumerate() {
+            check_process_stopped(stopped)?;
+            if vector.len() != dim {
+                return Err(OperationError::WrongVectorDimension {
+                    expected_dim: dim,
+                    received_dim: vector.len(),
+                });
+            }
+            let offset = offset as PointOffsetType;
+            let vector = distance.preprocess_vector::<VectorElementType>(vector);
+            vector_storage.insert_vector(offset, VectorRef::from(&vector), &hw_counter)?;
+            if queries.len() < num_queries {
+                queries.push((offset, QueryVector::from(vector)));
+            }
+        }
+
+        let point_deleted = BitVec::repeat(false, vector_storage.total_vector_count());
+        let mut sample = Self {
+            vector_storage,
+            point_deleted,
+            queries,
+            exact_results: Vec::new(),
+            exact_latency: Duration::ZERO,
+            limit,
+        };
+
+        let exact_params = SearchParams {
+            exact: true,
+            ..Default::default()
+        };
+        let (exact_results, exact_latency) = sample.search_queries(None, &exact_params, stopped)?;
+        sample.exact_results = exact_results;
+        sample.exact_latency = exact_latency;
+        Ok(sample)
+    }
+
+    /// Average time of a single exact search over the sample
+    pub fn exact_latency(&self) -> Duration {
+        self.exact_latency
+    }
+
+    /// Quantize the sample with given config in `path` and compare quantized search with exact one.
+    pub fn evaluate(
+        &self,
+        quantization_config: &QuantizationConfig,
+        quantization_params: QuantizationSearchParams,
+        path: &Path,
+        max_threads: usize,
+        stopped: &AtomicBool,
+    ) -> OperationResult<QuantizationEvaluation> {
+        let quantized_vectors = QuantizedVectors::create(
+            &self.vector_storage,
+            quantization_config,
+            QuantizedVectorsStorageType::Immutable,
+            path,
+            max_threads,
+            stopped,
+        )?;
+        let vector_size_bytes = quantized_vectors.get_quantized_vector_layout()?.size();
+
+        let params = SearchParams {
+            quantization: Some(quantization_params),
+            ..Default::default()
+        };
+        let (results, latency) = self.search_queries(Some(&quantized_vectors), &params, stopped)?;
+
+        let expected: usize = self.exact_results.iter().map(Vec::len).sum();
+        let found: usize = results
+            .iter()
+            .zip(&self.exact_results)
+            .map(|(result, exact)| exact.iter().filter(|idx| result.contains(idx)).count())
+            .sum();
+        let recall = if expected == 0 {
+            1.0
+        } else {
+            found as f64 / expected as f64
+        };
+
+        Ok(QuantizationEvaluation {
+            recall,
+            vector_size_bytes,
+            latency,
+        })
+    }
+
+    /// Search `limit` nearest neighbours of every query with a full scan over the sample.
+    ///
+    /// Returns found offsets, excluding the query itself, and average time of a single search.
+    fn search_queries(
+        &self,
+        quantized_vectors: Option<&QuantizedVectors>,
+        params: &SearchParams,
+        stopped: &AtomicBool,
+    ) -> OperationResult<(Vec<Vec<PointOffsetType>>, Duration)> {
+        // One extra result, because the query itself is always among the candidates
+        let top = self.limit + 1;
+        let oversampled_top = get_oversampled_top(quantized_vectors, Some(params), top);
+        let quantized_vectors = quantized_vectors.filter(|&quantized_vectors| {
+            is_quantized_search(Some(quantized_vectors), Some(params))
+        });
+        let num_vectors = self.vector_storage.total_vector_count() as PointOffsetType;
+
+        let timer = Instant::now();
+        let mut results = Vec::with_capacity(self.queries.len());
+        for (query_offset, query) in &self.queries {
+            let hw_counter = HardwareCounterCell::disposable();
+            let searcher = BatchFilteredSearcher::new(
+                &[query],
+                &self.vector_storage,
+                quantized_vectors,
+                None,
+                oversampled_top,
+                &self.point_deleted,
+                None,
+                hw_counter.fork(),
+            )?;
+            let candidates = searcher
+                .peek_top_iter(0..num_vectors, stopped)?
+                .pop()
+                .unwrap_or_default();
+            let result = postprocess_search_result(
+                candidates,
+                &self.point_deleted,
+                &self.vector_storage,
+                quantized_vectors,
+                query,
+                Some(params),
+                top,
+                hw_counter,
+            )?;
+            results.push(
+                result
+                    .into_iter()
+                    .map(|scored| scored.idx)
+                    .filter(|idx| idx != query_offset)
+                    .take(self.limit)
+                    .collect(),
+            );
+        }
+        let latency = timer.elapsed() / self.queries.len().max(1) as u32;
+
+        Ok((results, latency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use tempfile::Builder;
+
+    use super::*;
+    use crate::types::{BinaryQuantizationConfig, ScalarQuantizationConfig, ScalarType};
+
+    const NUM_VECTORS: usize = 500;
+    const DIM: usize = 64;
+
+    fn random_sample(num_queries: usize, limit: usize) -> QuantizationTuningSample {
+        let mut rng = StdRng::seed_from_u64(42);
+        let vectors = (0..NUM_VECTORS)
+            .map(|_| (0..DIM).map(|_| rng.random_range(-1.0..1.0)).collect())
+            .collect();
+        QuantizationTuningSample::new(
+            vectors,
+            Distance::Dot,
+            num_queries,
+            limit,
+            &AtomicBool::new(false),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_scalar_quantization_recall() {
+        let sample = random_sample(20, 10);
+        let dir = Builder::new().prefix("tuning").tempdir().unwrap();
+        let config = QuantizationConfig::from(ScalarQuantizationConfig {
+            r#type: ScalarType::Int8,
+            quantile: None,
+            always_ram: None,
+        });
+
+        let evaluation = sample
+            .evaluate(
+                &config,
+                QuantizationSearchParams {
+                    ignore: false,
+                    rescore: Some(true),
+                    oversampling: Some(2.0),
+                },
+                dir.path(),
+                1,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+
+        assert!(evaluation.recall > 0.9, "recall {}", evaluation.recall);
+        assert!(evaluation.vector_size_bytes < DIM * size_of::<VectorElementType>());
+    }
+
+    #[test]
+    fn test_rescoring_improves_recall() {
+        let sample = random_sample(20, 10);
+        let config = QuantizationConfig::from(BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: None,
+            query_encoding: None,
+        });
+
+        let evaluate = |rescore, oversampling| {
+            let dir = Builder::new().prefix("tuning").tempdir().unwrap();
+            sample
+                .evaluate(
+                    &config,
+                    QuantizationSearchParams {
+                        ignore: false,
+                        rescore: Some(rescore),
+                        oversampling: Some(oversampling),
+                    },
+                    dir.path(),
+                    1,
+                    &AtomicBool::new(false),
+                )
+                .unwrap()
+        };
+
+        let plain = evaluate(false, 1.0);
+        let rescored = evaluate(true, 4.0);
+        assert_eq!(plain.vector_size_bytes, rescored.vector_size_bytes);
+        assert!(rescored.recall > plain.recall);
+    }
+
+    #[test]
+    fn test_ignored_quantization_is_exact() {
+        let sample = random_sample(10, 5);
+        let dir = Builder::new().prefix("tuning").tempdir().unwrap();
+        let config = QuantizationConfig::from(BinaryQuantizationConfig {
+            always_ram: None,
+            encoding: None,
+            query_encoding: None,
+        });
+
+        let evaluation = sample
+            .evaluate(
+                &config,
+                QuantizationSearchParams {
+                    ignore: true,
+                    rescore: None,
+                    oversampling: None,
+                },
+                dir.path(),
+                1,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+
+        assert_eq!(evaluation.recall, 1.0);
+    }
+}
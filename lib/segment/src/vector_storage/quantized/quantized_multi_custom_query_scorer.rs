@@ -7,14 +7,16 @@ use quantization::EncodedVectors;
 
 use crate::data_types::named_vectors::CowMultiVector;
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::{MultiDenseVectorInternal, TypedMultiDenseVector};
+use crate::data_types::vectors::{
+    MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
+};
 use crate::spaces::metric::Metric;
-use crate::types::QuantizationConfig;
+use crate::types::{MultiVectorComparator, QuantizationConfig};
 use crate::vector_storage::quantized::quantized_multivector_storage::{
     MultivectorOffset, MultivectorOffsets, MultivectorOffsetsStorage, QuantizedMultivectorStorage,
 };
 use crate::vector_storage::query::{Query, TransformInto};
-use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::query_scorer::{QueryScorer, mean_vector};
 
 pub struct QuantizedMultiCustomQueryScorer<'a, QuantizedStorage, OffsetStorage, TQuery>
 where
@@ -24,6 +26,7 @@ where
 {
     query: TQuery,
     quantized_multivector_storage: &'a QuantizedMultivectorStorage<QuantizedStorage, OffsetStorage>,
+    comparator: MultiVectorComparator,
     hardware_counter: HardwareCounterCell,
 }
 
@@ -41,6 +44,7 @@ where
             OffsetStorage,
         >,
         quantization_config: &QuantizationConfig,
+        comparator: MultiVectorComparator,
         mut hardware_counter: HardwareCounterCell,
     ) -> Self
     where
@@ -57,6 +61,15 @@ where
     {
         let original_query: TOriginalQuery = raw_query
             .transform(|vector| {
+                // Quantized inner vectors can't be averaged, so the query is pooled before encoding
+                let vector = if comparator == MultiVectorComparator::MeanPool {
+                    MultiDenseVectorInternal::new(
+                        mean_vector(TypedMultiDenseVectorRef::from(&vector)),
+                        vector.dim,
+                    )
+                } else {
+                    vector
+                };
                 let mut preprocessed = Vec::new();
                 for slice in vector.multi_vectors() {
                     preprocessed.extend_from_slice(&TMetric::preprocess(slice.to_vec()));
@@ -88,6 +101,7 @@ where
         Self {
             query,
             quantized_multivector_storage,
+            comparator,
             hardware_counter,
         }
     }
@@ -119,6 +133,7 @@ where
                 self.quantized_multivector_storage.score_multi(
                     query,
                     offset,
+                    self.comparator,
                     &self.hardware_counter,
                 )
             });
@@ -135,8 +150,12 @@ where
         );
         self.query.score_by(|this| {
             // quantized multivector storage handles hardware counter to batch vector IO
-            self.quantized_multivector_storage
-                .score_point(this, idx, &self.hardware_counter)
+            self.quantized_multivector_storage.score_multi(
+                this,
+                multi_vector_offset,
+                self.comparator,
+                &self.hardware_counter,
+            )
         })
     }
 
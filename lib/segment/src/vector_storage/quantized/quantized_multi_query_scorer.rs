@@ -7,13 +7,13 @@ use quantization::EncodedVectors;
 
 use super::quantized_query_scorer::InternalScorerUnsupported;
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::MultiDenseVectorInternal;
+use crate::data_types::vectors::{MultiDenseVectorInternal, TypedMultiDenseVectorRef};
 use crate::spaces::metric::Metric;
-use crate::types::QuantizationConfig;
+use crate::types::{MultiVectorComparator, QuantizationConfig};
 use crate::vector_storage::quantized::quantized_multivector_storage::{
     MultivectorOffset, MultivectorOffsets, MultivectorOffsetsStorage, QuantizedMultivectorStorage,
 };
-use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::query_scorer::{QueryScorer, mean_vector};
 
 pub struct QuantizedMultiQueryScorer<'a, QuantizedStorage, OffsetStorage>
 where
@@ -22,6 +22,7 @@ where
 {
     query: Vec<QuantizedStorage::EncodedQuery>,
     quantized_multivector_storage: &'a QuantizedMultivectorStorage<QuantizedStorage, OffsetStorage>,
+    comparator: MultiVectorComparator,
     hardware_counter: HardwareCounterCell,
 }
 
@@ -38,12 +39,22 @@ where
             OffsetStorage,
         >,
         quantization_config: &QuantizationConfig,
+        comparator: MultiVectorComparator,
         mut hardware_counter: HardwareCounterCell,
     ) -> Self
     where
         TElement: PrimitiveVectorElement,
         TMetric: Metric<TElement>,
     {
+        // Quantized inner vectors can't be averaged, so the query is pooled before encoding
+        let pooled_query = (comparator == MultiVectorComparator::MeanPool).then(|| {
+            MultiDenseVectorInternal::new(
+                mean_vector(TypedMultiDenseVectorRef::from(raw_query)),
+                raw_query.dim,
+            )
+        });
+        let raw_query = pooled_query.as_ref().unwrap_or(raw_query);
+
         let mut query = Vec::new();
         for inner_vector in raw_query.multi_vectors() {
             let inner_preprocessed = TMetric::preprocess(inner_vector.to_vec());
@@ -64,6 +75,7 @@ where
         Self {
             query,
             quantized_multivector_storage,
+            comparator,
             hardware_counter,
         }
     }
@@ -76,6 +88,13 @@ where
         >,
         mut hardware_counter: HardwareCounterCell,
     ) -> Result<Self, InternalScorerUnsupported> {
+        let comparator = quantized_multivector_storage
+            .multi_vector_config()
+            .comparator;
+        // Encoded inner vectors can't be pooled into a query
+        if comparator == MultiVectorComparator::MeanPool {
+            return Err(InternalScorerUnsupported(hardware_counter));
+        }
         let Some(query) = quantized_multivector_storage.encode_internal_vector(point_id) else {
             return Err(InternalScorerUnsupported(hardware_counter));
         };
@@ -86,6 +105,7 @@ where
         Ok(Self {
             query,
             quantized_multivector_storage,
+            comparator,
             hardware_counter,
         })
     }
@@ -115,6 +135,7 @@ where
             scores[idx] = self.quantized_multivector_storage.score_multi(
                 &self.query,
                 offset,
+                self.comparator,
                 &self.hardware_counter,
             );
         }
@@ -128,8 +149,12 @@ where
                 + self.quantized_multivector_storage.quantized_vector_size() * sub_vectors_count,
         );
         // quantized multivector storage handles hardware counter to batch vector IO
-        self.quantized_multivector_storage
-            .score_point(&self.query, idx, &self.hardware_counter)
+        self.quantized_multivector_storage.score_multi(
+            &self.query,
+            multi_vector_offset,
+            self.comparator,
+            &self.hardware_counter,
+        )
     }
 
     fn score(&self, _v2: &()) -> ScoreType {
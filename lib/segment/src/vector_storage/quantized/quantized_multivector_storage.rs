@@ -15,9 +15,10 @@ use smallvec::SmallVec;
 
 use crate::common::operation_error::OperationResult;
 use crate::data_types::vectors::{TypedMultiDenseVectorRef, VectorElementType};
-use crate::types::{MultiVectorComparator, MultiVectorConfig};
+use crate::types::{Distance, MultiVectorComparator, MultiVectorConfig};
 use crate::vector_storage::VectorOffsetType;
 use crate::vector_storage::chunked_vectors::ChunkedVectors;
+use crate::vector_storage::query_scorer::{MultiSimilarityAccumulator, mean_pooled_similarity};
 
 #[derive(
     Copy,
@@ -387,6 +388,8 @@ where
     offsets: TMultivectorOffsetsStorage,
     dim: usize,
     multi_vector_config: MultiVectorConfig,
+    /// Distance of the original vectors, required to pool quantized multivectors
+    distance: Distance,
 }
 
 impl<QuantizedStorage, TMultivectorOffsetsStorage>
@@ -408,28 +411,92 @@ where
         quantized_storage: QuantizedStorage,
         offsets: TMultivectorOffsetsStorage,
         multi_vector_config: MultiVectorConfig,
+        distance: Distance,
     ) -> Self {
         Self {
             quantized_storage,
             offsets,
             dim,
             multi_vector_config,
+            distance,
         }
     }
 
+    pub fn multi_vector_config(&self) -> &MultiVectorConfig {
+        &self.multi_vector_config
+    }
+
+    /// Custom `score_multi` implementation for quantized vectors
+    ///
+    /// For [`MultiVectorComparator::MeanPool`] the query is expected to be already pooled
+    /// into a single vector, normalized for cosine distance.
     pub fn score_multi(
         &self,
         query: &[QuantizedStorage::EncodedQuery],
         offset: MultivectorOffset,
+        comparator: MultiVectorComparator,
         hw_counter: &HardwareCounterCell,
     ) -> ScoreType {
-        match self.multi_vector_config.comparator {
+        match comparator {
             MultiVectorComparator::MaxSim => {
                 self.score_point_max_similarity(query, offset, hw_counter)
             }
+            MultiVectorComparator::Chamfer
+            | MultiVectorComparator::MeanSim
+            | MultiVectorComparator::MaxMax => {
+                let offsets: SmallVec<[_; 8]> =
+                    (offset.start..offset.start + offset.count).collect();
+
+                let mut accumulator = MultiSimilarityAccumulator::new(comparator, query.len());
+
+                self.quantized_storage
+                    .for_each_in_batch(&offsets, |_, vector| {
+                        accumulator.push(
+                            query.iter().map(|query| {
+                                self.quantized_storage.score(query, vector, hw_counter)
+                            }),
+                        );
+                    });
+
+                accumulator.finish()
+            }
+            MultiVectorComparator::MeanPool => {
+                debug_assert_eq!(query.len(), 1);
+                let pairs_mean =
+                    self.score_multi(query, offset, MultiVectorComparator::MeanSim, hw_counter);
+                // Similarity of the pooled query to itself: normalized for cosine,
+                // zero distance for euclid, and not used for other distances
+                let query_self_similarity = || match self.distance {
+                    Distance::Cosine => 1.0,
+                    Distance::Euclid | Distance::Dot | Distance::Manhattan => 0.0,
+                };
+                mean_pooled_similarity(self.distance, pairs_mean, query_self_similarity, || {
+                    self.mean_internal_similarity(offset, offset, hw_counter)
+                })
+            }
         }
     }
 
+    /// Mean similarity over all pairs of stored inner vectors of two points
+    fn mean_internal_similarity(
+        &self,
+        offset_a: MultivectorOffset,
+        offset_b: MultivectorOffset,
+        hw_counter: &HardwareCounterCell,
+    ) -> ScoreType {
+        let mut sum = 0.0;
+        for a in 0..offset_a.count {
+            for b in 0..offset_b.count {
+                sum += self.quantized_storage.score_internal(
+                    offset_a.start + a,
+                    offset_b.start + b,
+                    hw_counter,
+                );
+            }
+        }
+        sum / (offset_a.count.max(1) * offset_b.count.max(1)) as ScoreType
+    }
+
     /// Custom `score_max_similarity` implementation for quantized vectors
     fn score_point_max_similarity(
         &self,
@@ -456,8 +523,8 @@ where
         max_sim.into_iter().sum()
     }
 
-    /// Custom `score_max_similarity` implementation for quantized vectors
-    fn score_internal_max_similarity(
+    /// Custom `score_multi` implementation for quantized vectors, scoring two stored points
+    fn score_internal_multi(
         &self,
         vector_a_index: PointOffsetType,
         vector_b_index: PointOffsetType,
@@ -465,24 +532,50 @@ where
     ) -> ScoreType {
         let offset_a = self.offsets.get_offset(vector_a_index);
         let offset_b = self.offsets.get_offset(vector_b_index);
-        let mut sum = 0.0;
-        for a in 0..offset_a.count {
-            let mut max_sim = ScoreType::NEG_INFINITY;
-            // manual `max_by` for performance
-            for b in 0..offset_b.count {
-                let sim = self.quantized_storage.score_internal(
-                    offset_a.start + a,
-                    offset_b.start + b,
-                    hw_counter,
-                );
-                if sim > max_sim {
-                    max_sim = sim;
+        match self.multi_vector_config.comparator {
+            MultiVectorComparator::MaxSim => {
+                let mut sum = 0.0;
+                for a in 0..offset_a.count {
+                    let mut max_sim = ScoreType::NEG_INFINITY;
+                    // manual `max_by` for performance
+                    for b in 0..offset_b.count {
+                        let sim = self.quantized_storage.score_internal(
+                            offset_a.start + a,
+                            offset_b.start + b,
+                            hw_counter,
+                        );
+                        if sim > max_sim {
+                            max_sim = sim;
+                        }
+                    }
+                    // sum of max similarity
+                    sum += max_sim;
                 }
+                sum
             }
-            // sum of max similarity
-            sum += max_sim;
+            comparator @ (MultiVectorComparator::Chamfer
+            | MultiVectorComparator::MeanSim
+            | MultiVectorComparator::MaxMax) => {
+                let mut accumulator =
+                    MultiSimilarityAccumulator::new(comparator, offset_a.count as usize);
+                for b in 0..offset_b.count {
+                    accumulator.push((0..offset_a.count).map(|a| {
+                        self.quantized_storage.score_internal(
+                            offset_a.start + a,
+                            offset_b.start + b,
+                            hw_counter,
+                        )
+                    }));
+                }
+                accumulator.finish()
+            }
+            MultiVectorComparator::MeanPool => mean_pooled_similarity(
+                self.distance,
+                self.mean_internal_similarity(offset_a, offset_b, hw_counter),
+                || self.mean_internal_similarity(offset_a, offset_a, hw_counter),
+                || self.mean_internal_similarity(offset_b, offset_b, hw_counter),
+            ),
         }
-        sum
     }
 
     pub fn inner_storage(&self) -> &QuantizedStorage {
@@ -540,7 +633,12 @@ where
         hw_counter: &HardwareCounterCell,
     ) -> ScoreType {
         let offset = self.offsets.get_offset(i);
-        self.score_multi(query, offset, hw_counter)
+        self.score_multi(
+            query,
+            offset,
+            self.multi_vector_config.comparator,
+            hw_counter,
+        )
     }
 
     fn score_internal(
@@ -549,9 +647,7 @@ where
         j: PointOffsetType,
         hw_counter: &HardwareCounterCell,
     ) -> ScoreType {
-        match self.multi_vector_config.comparator {
-            MultiVectorComparator::MaxSim => self.score_internal_max_similarity(i, j, hw_counter),
-        }
+        self.score_internal_multi(i, j, hw_counter)
     }
 
     fn quantized_vector_size(&self) -> usize {
@@ -4,7 +4,7 @@ use quantization::EncodedVectors;
 use super::quantized_custom_query_scorer::QuantizedCustomQueryScorer;
 use super::quantized_query_scorer::QuantizedQueryScorer;
 use super::quantized_vectors::QuantizedVectorStorage;
-use crate::common::operation_error::OperationResult;
+use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, QueryVector, VectorElementType, VectorElementTypeByte,
@@ -12,7 +12,7 @@ use crate::data_types::vectors::{
 };
 use crate::spaces::metric::Metric;
 use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
-use crate::types::{Distance, QuantizationConfig, VectorStorageDatatype};
+use crate::types::{Distance, MultiVectorComparator, QuantizationConfig, VectorStorageDatatype};
 use crate::vector_storage::quantized::quantized_multi_custom_query_scorer::QuantizedMultiCustomQueryScorer;
 use crate::vector_storage::quantized::quantized_multi_query_scorer::QuantizedMultiQueryScorer;
 use crate::vector_storage::quantized::quantized_multivector_storage::{
@@ -30,6 +30,7 @@ pub(super) struct QuantizedScorerBuilder<'a> {
     query: QueryVector,
     distance: &'a Distance,
     datatype: VectorStorageDatatype,
+    multivector_comparator: Option<MultiVectorComparator>,
     hardware_counter: HardwareCounterCell,
 }
 
@@ -41,6 +42,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
         query: QueryVector,
         distance: &'a Distance,
         datatype: VectorStorageDatatype,
+        multivector_comparator: Option<MultiVectorComparator>,
         mut hardware_counter: HardwareCounterCell,
     ) -> Self {
         hardware_counter.set_vector_io_read_multiplier(usize::from(quantized_storage.is_on_disk()));
@@ -51,6 +53,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
             query,
             distance,
             datatype,
+            multivector_comparator,
             hardware_counter,
         }
     }
@@ -64,6 +67,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementType, ManhattanMetric>()
                 }
+                Distance::Hamming | Distance::Jaccard => Err(unsupported_distance(self.distance)),
             },
             VectorStorageDatatype::Uint8 => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeByte, CosineMetric>(),
@@ -74,6 +78,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeByte, ManhattanMetric>()
                 }
+                Distance::Hamming | Distance::Jaccard => Err(unsupported_distance(self.distance)),
             },
             VectorStorageDatatype::Float16 => match self.distance {
                 Distance::Cosine => self.build_with_metric::<VectorElementTypeHalf, CosineMetric>(),
@@ -84,7 +89,11 @@ impl<'a> QuantizedScorerBuilder<'a> {
                 Distance::Manhattan => {
                     self.build_with_metric::<VectorElementTypeHalf, ManhattanMetric>()
                 }
+                Distance::Hamming | Distance::Jaccard => Err(unsupported_distance(self.distance)),
             },
+            VectorStorageDatatype::Bit => Err(OperationError::service_error(
+                "Quantized scorer is not supported for bit vectors",
+            )),
         }
     }
 
@@ -183,6 +192,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
             query,
             distance: _,
             datatype: _,
+            multivector_comparator: _,
             hardware_counter,
         } = self;
 
@@ -269,15 +279,23 @@ impl<'a> QuantizedScorerBuilder<'a> {
             query,
             distance: _,
             datatype: _,
+            multivector_comparator,
             hardware_counter,
         } = self;
 
+        let comparator = multivector_comparator.unwrap_or(
+            quantized_multivector_storage
+                .multi_vector_config()
+                .comparator,
+        );
+
         match query {
             QueryVector::Nearest(vector) => {
                 let query_scorer = QuantizedMultiQueryScorer::new_multi::<TElement, TMetric>(
                     &MultiDenseVectorInternal::try_from(vector)?,
                     quantized_multivector_storage,
                     quantization_config,
+                    comparator,
                     hardware_counter,
                 );
                 raw_scorer_from_query_scorer(query_scorer)
@@ -290,6 +308,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
                         RecoBestScoreQuery::from(reco_query),
                         quantized_multivector_storage,
                         quantization_config,
+                        comparator,
                         hardware_counter,
                     );
                 raw_scorer_from_query_scorer(query_scorer)
@@ -302,6 +321,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
                         RecoSumScoresQuery::from(reco_query),
                         quantized_multivector_storage,
                         quantization_config,
+                        comparator,
                         hardware_counter,
                     );
                 raw_scorer_from_query_scorer(query_scorer)
@@ -314,6 +334,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
                         discover_query,
                         quantized_multivector_storage,
                         quantization_config,
+                        comparator,
                         hardware_counter,
                     );
                 raw_scorer_from_query_scorer(query_scorer)
@@ -326,6 +347,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
                         context_query,
                         quantized_multivector_storage,
                         quantization_config,
+                        comparator,
                         hardware_counter,
                     );
                 raw_scorer_from_query_scorer(query_scorer)
@@ -338,6 +360,7 @@ impl<'a> QuantizedScorerBuilder<'a> {
                         feedback_query.into_query(),
                         quantized_multivector_storage,
                         quantization_config,
+                        comparator,
                         hardware_counter,
                     );
                 raw_scorer_from_query_scorer(query_scorer)
@@ -345,3 +368,9 @@ impl<'a> QuantizedScorerBuilder<'a> {
         }
     }
 }
+
+fn unsupported_distance(distance: &Distance) -> OperationError {
+    OperationError::service_error(format!(
+        "Quantized scorer is not supported for {distance:?} distance",
+    ))
+}
@@ -27,10 +27,10 @@ use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{QueryVector, VectorElementType, VectorRef};
 use crate::types::{
     BinaryQuantization, BinaryQuantizationConfig, BinaryQuantizationEncoding,
-    BinaryQuantizationQueryEncoding, CompressionRatio, Distance, MultiVectorConfig,
-    ProductQuantization, ProductQuantizationConfig, QuantizationConfig, ScalarQuantization,
-    ScalarQuantizationConfig, ScalarType, TurboQuantBitSize, TurboQuantQuantizationConfig,
-    TurboQuantization, VectorStorageDatatype,
+    BinaryQuantizationQueryEncoding, CompressionRatio, Distance, MultiVectorComparator,
+    MultiVectorConfig, ProductQuantization, ProductQuantizationConfig, QuantizationConfig,
+    ScalarQuantization, ScalarQuantizationConfig, ScalarType, TurboQuantBitSize,
+    TurboQuantQuantizationConfig, TurboQuantization, VectorStorageDatatype,
 };
 use crate::vector_storage::quantized::quantized_chunked_mmap_storage::{
     QuantizedChunkedMmapStorage, QuantizedChunkedMmapStorageBuilder,
@@ -202,6 +202,36 @@ impl QuantizedVectorStorage {
             QuantizedVectorStorage::TQChunkedMmapMulti(q) => q.is_on_disk(),
         }
     }
+
+    /// Method of the scalar quantization, `None` for other quantization types
+    pub fn scalar_quantization_method(&self) -> Option<ScalarQuantizationMethod> {
+        match self {
+            QuantizedVectorStorage::ScalarRam(q) => Some(q.method()),
+            QuantizedVectorStorage::ScalarMmap(q) => Some(q.method()),
+            QuantizedVectorStorage::ScalarChunkedMmap(q) => Some(q.method()),
+            QuantizedVectorStorage::ScalarRamMulti(q) => Some(q.inner_storage().method()),
+            QuantizedVectorStorage::ScalarMmapMulti(q) => Some(q.inner_storage().method()),
+            QuantizedVectorStorage::ScalarChunkedMmapMulti(q) => Some(q.inner_storage().method()),
+            QuantizedVectorStorage::PQRam(_)
+            | QuantizedVectorStorage::PQMmap(_)
+            | QuantizedVectorStorage::PQChunkedMmap(_)
+            | QuantizedVectorStorage::BinaryRam(_)
+            | QuantizedVectorStorage::BinaryMmap(_)
+            | QuantizedVectorStorage::BinaryChunkedMmap(_)
+            | QuantizedVectorStorage::TQRam(_)
+            | QuantizedVectorStorage::TQMmap(_)
+            | QuantizedVectorStorage::TQChunkedMmap(_)
+            | QuantizedVectorStorage::PQRamMulti(_)
+            | QuantizedVectorStorage::PQMmapMulti(_)
+            | QuantizedVectorStorage::PQChunkedMmapMulti(_)
+            | QuantizedVectorStorage::BinaryRamMulti(_)
+            | QuantizedVectorStorage::BinaryMmapMulti(_)
+            | QuantizedVectorStorage::BinaryChunkedMmapMulti(_)
+            | QuantizedVectorStorage::TQRamMulti(_)
+            | QuantizedVectorStorage::TQMmapMulti(_)
+            | QuantizedVectorStorage::TQChunkedMmapMulti(_) => None,
+        }
+    }
 }
 
 impl QuantizedVectorStorage {
@@ -366,6 +396,16 @@ impl QuantizedVectors {
         &'a self,
         query: QueryVector,
         hardware_counter: HardwareCounterCell,
+    ) -> OperationResult<Box<dyn RawScorer + 'a>> {
+        self.raw_scorer_with_comparator(query, None, hardware_counter)
+    }
+
+    /// Build a raw scorer, overriding the comparator of multivector storages if `comparator` is set.
+    pub fn raw_scorer_with_comparator<'a>(
+        &'a self,
+        query: QueryVector,
+        comparator: Option<MultiVectorComparator>,
+        hardware_counter: HardwareCounterCell,
     ) -> OperationResult<Box<dyn RawScorer + 'a>> {
         QuantizedScorerBuilder::new(
             &self.storage_impl,
@@ -373,6 +413,7 @@ impl QuantizedVectors {
             query,
             &self.distance,
             self.datatype,
+            comparator,
             hardware_counter,
         )
         .build()
@@ -675,6 +716,14 @@ impl QuantizedVectors {
                 max_threads,
                 stopped,
             ),
+            VectorStorageEnum::DenseMemmapBit(_)
+            | VectorStorageEnum::DenseAppendableMemmapBit(_) => Err(
+                OperationError::validation_error("Quantization is not supported for bit vectors"),
+            ),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(_) => Err(OperationError::validation_error(
+                "Quantization is not supported for bit vectors",
+            )),
             VectorStorageEnum::SparseVolatile(_) => Err(OperationError::WrongSparse),
             VectorStorageEnum::SparseMmap(_) => Err(OperationError::WrongSparse),
             VectorStorageEnum::MultiDenseVolatile(v) => Self::create_multi_impl(
@@ -766,7 +815,7 @@ impl QuantizedVectors {
             dim,
             count,
             storage_type,
-        );
+        )?;
 
         let quantized_storage = match quantization_config {
             QuantizationConfig::Scalar(ScalarQuantization {
@@ -867,7 +916,7 @@ impl QuantizedVectors {
             dim,
             inner_vectors_count,
             storage_type,
-        );
+        )?;
 
         let offsets = (0..vectors_count as PointOffsetType)
             .map(|idx| {
@@ -897,6 +946,7 @@ impl QuantizedVectors {
                 scalar_config,
                 storage_type,
                 multi_vector_config,
+                distance,
                 path,
                 on_disk_vector_storage,
                 stopped,
@@ -911,6 +961,7 @@ impl QuantizedVectors {
                     pq_config,
                     storage_type,
                     multi_vector_config,
+                    distance,
                     path,
                     on_disk_vector_storage,
                     max_threads,
@@ -928,6 +979,7 @@ impl QuantizedVectors {
                 binary_config,
                 storage_type,
                 multi_vector_config,
+                distance,
                 path,
                 on_disk_vector_storage,
                 stopped,
@@ -943,6 +995,7 @@ impl QuantizedVectors {
                 turbo_config,
                 storage_type,
                 multi_vector_config,
+                distance,
                 path,
                 on_disk_vector_storage,
                 max_threads,
@@ -1092,8 +1145,10 @@ impl QuantizedVectors {
         let data_path = Self::get_data_path(path, config.storage_type);
         let meta_path = Self::get_meta_path(path);
         if Self::is_ram(scalar_config.always_ram, on_disk_vector_storage) {
-            let quantized_vector_size =
-                encoded_vectors_u8::get_quantized_vector_size(&config.vector_parameters);
+            let quantized_vector_size = encoded_vectors_u8::get_quantized_vector_size(
+                &config.vector_parameters,
+                Self::convert_scalar_encoding(scalar_config.r#type),
+            );
             let quantized_vectors_storage =
                 QuantizedRamStorage::from_file(data_path.as_path(), quantized_vector_size)?;
             Ok(QuantizedVectorStorage::ScalarRam(EncodedVectorsU8::load(
@@ -1101,8 +1156,10 @@ impl QuantizedVectors {
                 &meta_path,
             )?))
         } else {
-            let quantized_vector_size =
-                encoded_vectors_u8::get_quantized_vector_size(&config.vector_parameters);
+            let quantized_vector_size = encoded_vectors_u8::get_quantized_vector_size(
+                &config.vector_parameters,
+                Self::convert_scalar_encoding(scalar_config.r#type),
+            );
             let quantized_vectors_storage =
                 QuantizedStorage::from_file(data_path.as_path(), quantized_vector_size)?;
             Ok(QuantizedVectorStorage::ScalarMmap(EncodedVectorsU8::load(
@@ -1130,8 +1187,10 @@ impl QuantizedVectors {
         let meta_path = Self::get_meta_path(path);
         let offsets_path = Self::get_offsets_path(path, config.storage_type);
         if Self::is_ram(scalar_config.always_ram, on_disk_vector_storage) {
-            let quantized_vector_size =
-                encoded_vectors_u8::get_quantized_vector_size(&config.vector_parameters);
+            let quantized_vector_size = encoded_vectors_u8::get_quantized_vector_size(
+                &config.vector_parameters,
+                Self::convert_scalar_encoding(scalar_config.r#type),
+            );
             let inner_vectors_storage =
                 QuantizedRamStorage::from_file(data_path.as_path(), quantized_vector_size)?;
             let inner_vectors_storage = EncodedVectorsU8::load(inner_vectors_storage, &meta_path)?;
@@ -1142,11 +1201,14 @@ impl QuantizedVectors {
                     inner_vectors_storage,
                     offsets,
                     *multivector_config,
+                    vector_storage.distance(),
                 ),
             ))
         } else {
-            let quantized_vector_size =
-                encoded_vectors_u8::get_quantized_vector_size(&config.vector_parameters);
+            let quantized_vector_size = encoded_vectors_u8::get_quantized_vector_size(
+                &config.vector_parameters,
+                Self::convert_scalar_encoding(scalar_config.r#type),
+            );
             let inner_vectors_storage =
                 QuantizedStorage::from_file(data_path.as_path(), quantized_vector_size)?;
             let inner_vectors_storage = EncodedVectorsU8::load(inner_vectors_storage, &meta_path)?;
@@ -1157,6 +1219,7 @@ impl QuantizedVectors {
                     inner_vectors_storage,
                     offsets,
                     *multivector_config,
+                    vector_storage.distance(),
                 ),
             ))
         }
@@ -1237,6 +1300,7 @@ impl QuantizedVectors {
                     inner_vectors_storage,
                     offsets,
                     *multivector_config,
+                    vector_storage.distance(),
                 ),
             ))
         } else {
@@ -1255,6 +1319,7 @@ impl QuantizedVectors {
                     inner_vectors_storage,
                     offsets,
                     *multivector_config,
+                    vector_storage.distance(),
                 ),
             ))
         }
@@ -1352,6 +1417,7 @@ impl QuantizedVectors {
                         inner_storage,
                         offsets_storage,
                         *multivector_config,
+                        vector_storage.distance(),
                     ),
                 ))
             }
@@ -1372,6 +1438,7 @@ impl QuantizedVectors {
                         inner_vectors_storage,
                         offsets,
                         *multivector_config,
+                        vector_storage.distance(),
                     ),
                 ))
             }
@@ -1392,6 +1459,7 @@ impl QuantizedVectors {
                         inner_vectors_storage,
                         offsets,
                         *multivector_config,
+                        vector_storage.distance(),
                     ),
                 ))
             }
@@ -1496,6 +1564,7 @@ impl QuantizedVectors {
                         inner_storage,
                         offsets_storage,
                         *multivector_config,
+                        vector_storage.distance(),
                     ),
                 ))
             }
@@ -1516,6 +1585,7 @@ impl QuantizedVectors {
                         inner_vectors_storage,
                         offsets,
                         *multivector_config,
+                        vector_storage.distance(),
                     ),
                 ))
             }
@@ -1536,6 +1606,7 @@ impl QuantizedVectors {
                         inner_vectors_storage,
                         offsets,
                         *multivector_config,
+                        vector_storage.distance(),
                     ),
                 ))
             }
@@ -1561,7 +1632,7 @@ impl QuantizedVectors {
 
         let encoding = Self::convert_scalar_encoding(scalar_config.r#type);
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(vector_parameters, encoding);
         let meta_path = Self::get_meta_path(path);
         let data_path = Self::get_data_path(path, storage_type);
         let in_ram = Self::is_ram(scalar_config.always_ram, on_disk_vector_storage);
@@ -1612,6 +1683,7 @@ impl QuantizedVectors {
         scalar_config: &ScalarQuantizationConfig,
         storage_type: QuantizedVectorsStorageType,
         multi_vector_config: MultiVectorConfig,
+        distance: Distance,
         path: &Path,
         on_disk_vector_storage: bool,
         stopped: &AtomicBool,
@@ -1624,7 +1696,7 @@ impl QuantizedVectors {
 
         let encoding = Self::convert_scalar_encoding(scalar_config.r#type);
         let quantized_vector_size =
-            encoded_vectors_u8::get_quantized_vector_size(vector_parameters);
+            encoded_vectors_u8::get_quantized_vector_size(vector_parameters, encoding);
         let meta_path = Self::get_meta_path(path);
         let data_path = Self::get_data_path(path, storage_type);
         let offsets_path = Self::get_offsets_path(path, storage_type);
@@ -1652,6 +1724,7 @@ impl QuantizedVectors {
                     quantized_storage,
                     offsets,
                     multi_vector_config,
+                    distance,
                 ),
             ))
         } else {
@@ -1678,6 +1751,7 @@ impl QuantizedVectors {
                     quantized_storage,
                     offsets,
                     multi_vector_config,
+                    distance,
                 ),
             ))
         }
@@ -1719,6 +1793,7 @@ impl QuantizedVectors {
                 vector_parameters,
                 vectors_count,
                 bucket_size,
+                pq_config.opq.unwrap_or_default(),
                 max_threads,
                 Some(meta_path.as_path()),
                 stopped,
@@ -1735,6 +1810,7 @@ impl QuantizedVectors {
                 vector_parameters,
                 vectors_count,
                 bucket_size,
+                pq_config.opq.unwrap_or_default(),
                 max_threads,
                 Some(meta_path.as_path()),
                 stopped,
@@ -1752,6 +1828,7 @@ impl QuantizedVectors {
         pq_config: &ProductQuantizationConfig,
         storage_type: QuantizedVectorsStorageType,
         multi_vector_config: MultiVectorConfig,
+        distance: Distance,
         path: &Path,
         on_disk_vector_storage: bool,
         max_threads: usize,
@@ -1782,6 +1859,7 @@ impl QuantizedVectors {
                 vector_parameters,
                 inner_vectors_count,
                 bucket_size,
+                pq_config.opq.unwrap_or_default(),
                 max_threads,
                 Some(meta_path.as_path()),
                 stopped,
@@ -1793,6 +1871,7 @@ impl QuantizedVectors {
                     quantized_storage,
                     offsets,
                     multi_vector_config,
+                    distance,
                 ),
             ))
         } else {
@@ -1807,6 +1886,7 @@ impl QuantizedVectors {
                 vector_parameters,
                 inner_vectors_count,
                 bucket_size,
+                pq_config.opq.unwrap_or_default(),
                 max_threads,
                 Some(meta_path.as_path()),
                 stopped,
@@ -1819,6 +1899,7 @@ impl QuantizedVectors {
                     quantized_storage,
                     offsets,
                     multi_vector_config,
+                    distance,
                 ),
             ))
         }
@@ -1912,6 +1993,7 @@ impl QuantizedVectors {
         binary_config: &BinaryQuantizationConfig,
         storage_type: QuantizedVectorsStorageType,
         multi_vector_config: MultiVectorConfig,
+        distance: Distance,
         path: &Path,
         on_disk_vector_storage: bool,
         stopped: &AtomicBool,
@@ -1950,6 +2032,7 @@ impl QuantizedVectors {
                         quantized_storage,
                         offsets,
                         multi_vector_config,
+                        distance,
                     ),
                 ))
             }
@@ -1975,6 +2058,7 @@ impl QuantizedVectors {
                         quantized_storage,
                         offsets,
                         multi_vector_config,
+                        distance,
                     ),
                 ))
             }
@@ -2001,6 +2085,7 @@ impl QuantizedVectors {
                         quantized_storage,
                         offsets,
                         multi_vector_config,
+                        distance,
                     ),
                 ))
             }
@@ -2097,6 +2182,7 @@ impl QuantizedVectors {
         turbo_config: &TurboQuantQuantizationConfig,
         storage_type: QuantizedVectorsStorageType,
         multi_vector_config: MultiVectorConfig,
+        distance: Distance,
         path: &Path,
         on_disk_vector_storage: bool,
         max_threads: usize,
@@ -2137,6 +2223,7 @@ impl QuantizedVectors {
                         quantized_storage,
                         offsets,
                         multi_vector_config,
+                        distance,
                     ),
                 ))
             }
@@ -2164,6 +2251,7 @@ impl QuantizedVectors {
                         quantized_storage,
                         offsets,
                         multi_vector_config,
+                        distance,
                     ),
                 ))
             }
@@ -2192,6 +2280,7 @@ impl QuantizedVectors {
                         quantized_storage,
                         offsets,
                         multi_vector_config,
+                        distance,
                     ),
                 ))
             }
@@ -2248,6 +2337,7 @@ impl QuantizedVectors {
     fn convert_scalar_encoding(encoding: ScalarType) -> ScalarQuantizationMethod {
         match encoding {
             ScalarType::Int8 => ScalarQuantizationMethod::Int8,
+            ScalarType::Int4 => ScalarQuantizationMethod::Int4,
         }
     }
 
@@ -2273,8 +2363,8 @@ impl QuantizedVectors {
         dim: usize,
         deprecated_count: usize,
         storage_type: QuantizedVectorsStorageType,
-    ) -> quantization::VectorParameters {
-        quantization::VectorParameters {
+    ) -> OperationResult<quantization::VectorParameters> {
+        Ok(quantization::VectorParameters {
             dim,
             deprecated_count: match storage_type {
                 QuantizedVectorsStorageType::Mutable => None,
@@ -2293,9 +2383,14 @@ impl QuantizedVectors {
                 Distance::Euclid => quantization::DistanceType::L2,
                 Distance::Dot => quantization::DistanceType::Dot,
                 Distance::Manhattan => quantization::DistanceType::L1,
+                Distance::Hamming | Distance::Jaccard => {
+                    return Err(OperationError::validation_error(format!(
+                        "Quantization is not supported for {distance:?} distance",
+                    )));
+                }
             },
             invert: distance == Distance::Euclid || distance == Distance::Manhattan,
-        }
+        })
     }
 
     fn get_bucket_size(compression: CompressionRatio) -> usize {
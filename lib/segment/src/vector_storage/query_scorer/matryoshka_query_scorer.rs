@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::generic_consts::Random;
+use common::typelevel::False;
+use common::types::{PointOffsetType, ScoreType};
+
+use crate::data_types::primitive::PrimitiveVectorElement;
+use crate::data_types::vectors::{TypedDenseVector, VectorElementType};
+use crate::spaces::metric::Metric;
+use crate::types::Distance;
+use crate::vector_storage::DenseVectorStorage;
+use crate::vector_storage::query_scorer::QueryScorer;
+
+/// Scores a query against the first `query.len()` dimensions of stored vectors.
+///
+/// Used for Matryoshka embeddings, where a prefix of a vector is a valid embedding on its own.
+/// Prefixes of normalized vectors are not normalized, so they are normalized while scoring
+/// for the cosine distance.
+pub struct MatryoshkaQueryScorer<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: DenseVectorStorage<TElement>,
+> {
+    vector_storage: &'a TVectorStorage,
+    query: TypedDenseVector<TElement>,
+    metric: PhantomData<TMetric>,
+    hardware_counter: HardwareCounterCell,
+}
+
+impl<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: DenseVectorStorage<TElement>,
+> MatryoshkaQueryScorer<'a, TElement, TMetric, TVectorStorage>
+{
+    pub fn new(
+        query: TypedDenseVector<VectorElementType>,
+        vector_storage: &'a TVectorStorage,
+        mut hardware_counter: HardwareCounterCell,
+    ) -> Self {
+        debug_assert!(query.len() < vector_storage.vector_dim());
+        let dim = query.len();
+        let preprocessed_vector = TMetric::preprocess(query);
+
+        hardware_counter.set_cpu_multiplier(dim * size_of::<TElement>());
+        if vector_storage.is_on_disk() {
+            hardware_counter.set_vector_io_read_multiplier(dim * size_of::<TElement>());
+        } else {
+            hardware_counter.set_vector_io_read_multiplier(0);
+        }
+
+        Self {
+            query: TypedDenseVector::from(TElement::slice_from_float_cow(Cow::from(
+                preprocessed_vector,
+            ))),
+            vector_storage,
+            metric: PhantomData,
+            hardware_counter,
+        }
+    }
+
+    fn prefix<'v>(&self, vector: &'v [TElement]) -> &'v [TElement] {
+        &vector[..self.query.len()]
+    }
+
+    /// Norm of a vector prefix, if the metric expects normalized vectors
+    fn norm(prefix: &[TElement]) -> Option<ScoreType> {
+        match TMetric::distance() {
+            Distance::Cosine => Some(TMetric::similarity(prefix, prefix).sqrt()),
+            Distance::Euclid
+            | Distance::Dot
+            | Distance::Manhattan
+            | Distance::Hamming
+            | Distance::Jaccard => None,
+        }
+    }
+
+    fn score_prefix(&self, prefix: &[TElement]) -> ScoreType {
+        let score = TMetric::similarity(&self.query, prefix);
+        match Self::norm(prefix) {
+            Some(norm) if norm > 0.0 => score / norm,
+            Some(_) => 0.0,
+            None => score,
+        }
+    }
+}
+
+impl<
+    TElement: PrimitiveVectorElement,
+    TMetric: Metric<TElement>,
+    TVectorStorage: DenseVectorStorage<TElement>,
+> QueryScorer for MatryoshkaQueryScorer<'_, TElement, TMetric, TVectorStorage>
+{
+    type TVector = [TElement];
+
+    #[inline]
+    fn score_stored(&self, idx: PointOffsetType) -> ScoreType {
+        self.hardware_counter.cpu_counter().incr();
+        self.hardware_counter.vector_io_read().incr();
+        let vector = self.vector_storage.get_dense::<Random>(idx);
+        self.score_prefix(self.prefix(&vector))
+    }
+
+    #[inline]
+    fn score_stored_batch(&self, ids: &[PointOffsetType], scores: &mut [ScoreType]) {
+        debug_assert_eq!(ids.len(), scores.len());
+
+        self.hardware_counter.cpu_counter().incr_delta(ids.len());
+        self.hardware_counter.vector_io_read().incr_delta(ids.len());
+
+        self.vector_storage
+            .for_each_in_dense_batch(ids, |idx, vector| {
+                scores[idx] = self.score_prefix(self.prefix(vector));
+            });
+    }
+
+    #[inline]
+    fn score(&self, v2: &[TElement]) -> ScoreType {
+        self.hardware_counter.cpu_counter().incr();
+        self.score_prefix(self.prefix(v2))
+    }
+
+    fn score_internal(&self, point_a: PointOffsetType, point_b: PointOffsetType) -> ScoreType {
+        self.hardware_counter.cpu_counter().incr();
+        let v1 = self.vector_storage.get_dense::<Random>(point_a);
+        let v2 = self.vector_storage.get_dense::<Random>(point_b);
+        let (prefix1, prefix2) = (self.prefix(&v1), self.prefix(&v2));
+        let score = TMetric::similarity(prefix1, prefix2);
+        match (Self::norm(prefix1), Self::norm(prefix2)) {
+            (Some(norm1), Some(norm2)) if norm1 > 0.0 && norm2 > 0.0 => score / (norm1 * norm2),
+            (Some(_), Some(_)) => 0.0,
+            (None, _) | (_, None) => score,
+        }
+    }
+
+    type SupportsBytes = False;
+    fn score_bytes(&self, enabled: Self::SupportsBytes, _: &[u8]) -> ScoreType {
+        match enabled {}
+    }
+}
@@ -1,14 +1,18 @@
+use std::borrow::Cow;
+
 use bytemuck::TransparentWrapper;
 use common::typelevel::{TBool, TOption};
 use common::types::{PointOffsetType, ScoreType};
+use smallvec::{SmallVec, smallvec};
 
 use crate::data_types::primitive::PrimitiveVectorElement;
-use crate::data_types::vectors::TypedMultiDenseVectorRef;
+use crate::data_types::vectors::{DenseVector, TypedMultiDenseVectorRef, VectorElementType};
 use crate::spaces::metric::Metric;
-use crate::types::{MultiVectorComparator, MultiVectorConfig};
+use crate::types::{Distance, MultiVectorComparator};
 use crate::vector_storage::VectorOffset;
 
 pub mod custom_query_scorer;
+pub mod matryoshka_query_scorer;
 pub mod metric_query_scorer;
 pub mod multi_custom_query_scorer;
 pub mod multi_metric_query_scorer;
@@ -90,15 +94,170 @@ pub fn score_max_similarity<T: PrimitiveVectorElement, TMetric: Metric<T>>(
     sum
 }
 
+/// Accumulates similarities between inner vectors of two multivectors `a` and `b`
+/// and combines them according to [`MultiVectorComparator`].
+/// This accumulator is also used in `QuantizedMultivectorStorage` structure for quantized data.
+///
+/// Similarities are pushed by vectors of `b`: each push contains similarities of one vector of `b`
+/// to every vector of `a`, in order.
+///
+/// For [`MultiVectorComparator::MeanPool`] the result is the mean similarity of all pairs,
+/// which is turned into the similarity of pooled multivectors by [`mean_pooled_similarity`].
+pub struct MultiSimilarityAccumulator {
+    comparator: MultiVectorComparator,
+    /// Best similarity for each vector of `a`
+    best_a: SmallVec<[ScoreType; 8]>,
+    /// Sum of best similarities for each vector of `b`
+    best_b_sum: ScoreType,
+    count_b: usize,
+    pairs_sum: ScoreType,
+    pairs_max: ScoreType,
+}
+
+impl MultiSimilarityAccumulator {
+    pub fn new(comparator: MultiVectorComparator, count_a: usize) -> Self {
+        Self {
+            comparator,
+            best_a: smallvec![ScoreType::NEG_INFINITY; count_a],
+            best_b_sum: 0.0,
+            count_b: 0,
+            pairs_sum: 0.0,
+            pairs_max: ScoreType::NEG_INFINITY,
+        }
+    }
+
+    /// Add similarities of the next vector of `b` to all vectors of `a`
+    pub fn push(&mut self, similarities: impl IntoIterator<Item = ScoreType>) {
+        let mut best_b = ScoreType::NEG_INFINITY;
+        for (best_a, sim) in self.best_a.iter_mut().zip(similarities) {
+            if sim > *best_a {
+                *best_a = sim;
+            }
+            if sim > best_b {
+                best_b = sim;
+            }
+            self.pairs_sum += sim;
+        }
+        if best_b > self.pairs_max {
+            self.pairs_max = best_b;
+        }
+        self.best_b_sum += best_b;
+        self.count_b += 1;
+    }
+
+    pub fn finish(self) -> ScoreType {
+        let Self {
+            comparator,
+            best_a,
+            best_b_sum,
+            count_b,
+            pairs_sum,
+            pairs_max,
+        } = self;
+        let count_a = best_a.len().max(1) as ScoreType;
+        let count_b = count_b.max(1) as ScoreType;
+        match comparator {
+            MultiVectorComparator::MaxSim => best_a.iter().sum(),
+            MultiVectorComparator::Chamfer => {
+                let best_a_sum: ScoreType = best_a.iter().sum();
+                (best_a_sum / count_a + best_b_sum / count_b) / 2.0
+            }
+            MultiVectorComparator::MeanSim | MultiVectorComparator::MeanPool => {
+                pairs_sum / (count_a * count_b)
+            }
+            MultiVectorComparator::MaxMax => pairs_max,
+        }
+    }
+}
+
+/// Similarity of the mean-pooled multivectors `a` and `b`, derived from similarities of inner vectors,
+/// for storages which can't average inner vectors, like quantized ones.
+///
+/// `pairs_mean` is the mean similarity over all pairs of inner vectors of `a` and `b`,
+/// `self_a` and `self_b` compute the mean similarities over all pairs of inner vectors within `a` and `b`,
+/// they are only called for distances which need them.
+/// Inner vectors of cosine distance are expected to be normalized.
+pub fn mean_pooled_similarity(
+    distance: Distance,
+    pairs_mean: ScoreType,
+    self_a: impl FnOnce() -> ScoreType,
+    self_b: impl FnOnce() -> ScoreType,
+) -> ScoreType {
+    match distance {
+        // Dot product is bilinear, so the mean of pairs is the dot product of the means
+        Distance::Dot => pairs_mean,
+        // Mean similarities within multivectors are the squared norms of the means
+        Distance::Cosine => {
+            let norms = (self_a() * self_b()).sqrt();
+            if norms > 0.0 { pairs_mean / norms } else { 0.0 }
+        }
+        // Mean squared distance of pairs also includes the spread of inner vectors around the means,
+        // which is a half of the mean squared distance within each multivector
+        Distance::Euclid => pairs_mean - (self_a() + self_b()) / 2.0,
+        // Manhattan distance of the means can't be derived from distances of inner vectors,
+        // the mean distance of pairs is its upper bound. Quantized scores are rescored with
+        // the original vectors anyway
+        Distance::Manhattan => pairs_mean,
+    }
+}
+
+/// Average of inner vectors of the multivector
+pub fn mean_vector<T: PrimitiveVectorElement>(
+    multi_dense: TypedMultiDenseVectorRef<'_, T>,
+) -> DenseVector {
+    let mut mean: DenseVector = Vec::new();
+    for dense in multi_dense.multi_vectors() {
+        let dense = T::slice_to_float_cow(Cow::Borrowed(dense));
+        if mean.is_empty() {
+            mean.resize(dense.len(), 0.0);
+        }
+        for (mean, value) in mean.iter_mut().zip(dense.iter()) {
+            *mean += value;
+        }
+    }
+    let count = multi_dense.vectors_count().max(1) as VectorElementType;
+    mean.iter_mut().for_each(|value| *value /= count);
+    mean
+}
+
+/// Average of inner vectors of the multivector, preprocessed for the metric,
+/// e.g. normalized for cosine distance
+fn mean_pool<T: PrimitiveVectorElement, TMetric: Metric<T>>(
+    multi_dense: TypedMultiDenseVectorRef<'_, T>,
+) -> Vec<T> {
+    let mean = TMetric::preprocess(mean_vector(multi_dense));
+    T::slice_from_float_cow(Cow::Owned(mean)).into_owned()
+}
+
 fn score_multi<T: PrimitiveVectorElement, TMetric: Metric<T>>(
-    multi_vector_config: &MultiVectorConfig,
+    comparator: MultiVectorComparator,
     multi_dense_a: TypedMultiDenseVectorRef<'_, T>,
     multi_dense_b: TypedMultiDenseVectorRef<'_, T>,
 ) -> ScoreType {
-    match multi_vector_config.comparator {
+    match comparator {
         MultiVectorComparator::MaxSim => {
             score_max_similarity::<T, TMetric>(multi_dense_a, multi_dense_b)
         }
+        MultiVectorComparator::Chamfer
+        | MultiVectorComparator::MeanSim
+        | MultiVectorComparator::MaxMax => {
+            debug_assert!(!multi_dense_a.is_empty());
+            debug_assert!(!multi_dense_b.is_empty());
+            let mut accumulator =
+                MultiSimilarityAccumulator::new(comparator, multi_dense_a.vectors_count());
+            for dense_b in multi_dense_b.multi_vectors() {
+                accumulator.push(
+                    multi_dense_a
+                        .multi_vectors()
+                        .map(|dense_a| TMetric::similarity(dense_a, dense_b)),
+                );
+            }
+            accumulator.finish()
+        }
+        MultiVectorComparator::MeanPool => TMetric::similarity(
+            &mean_pool::<T, TMetric>(multi_dense_a),
+            &mean_pool::<T, TMetric>(multi_dense_b),
+        ),
     }
 }
 
@@ -138,7 +297,7 @@ pub fn is_read_with_prefetch_efficient<O: VectorOffset>(ids: &[O]) -> bool {
 mod tests {
     use super::*;
     use crate::data_types::vectors::MultiDenseVectorInternal;
-    use crate::spaces::simple::EuclidMetric;
+    use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric};
 
     #[test]
     fn test_check_ids_rather_contiguous() {
@@ -176,4 +335,106 @@ mod tests {
         // proper value according to theory should be `5.9777255` but we do not apply post-processing step
         assert_eq!(score, -19.);
     }
+
+    #[test]
+    fn test_score_multi_comparators() {
+        let a = MultiDenseVectorInternal::try_from(vec![vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap();
+        let b = MultiDenseVectorInternal::try_from(vec![
+            vec![1.0, 0.0],
+            vec![0.5, 0.0],
+            vec![0.0, 0.25],
+        ])
+        .unwrap();
+        let score =
+            |comparator| score_multi::<f32, DotProductMetric>(comparator, (&a).into(), (&b).into());
+
+        // best for `a`: 1.0, 0.25
+        assert_eq!(score(MultiVectorComparator::MaxSim), 1.25);
+        // best for `b`: 1.0, 0.5, 0.25
+        assert_eq!(
+            score(MultiVectorComparator::Chamfer),
+            (1.25 / 2.0 + 1.75 / 3.0) / 2.0,
+        );
+        // mean of all pairs, equal to the dot product of mean-pooled `a` [0.5, 0.5]
+        // and mean-pooled `b` [0.5, 0.083333]
+        assert!((score(MultiVectorComparator::MeanSim) - 1.75 / 6.0).abs() < 1e-6);
+        assert_eq!(score(MultiVectorComparator::MaxMax), 1.0);
+
+        // Chamfer is symmetric
+        assert_eq!(
+            score_multi::<f32, DotProductMetric>(
+                MultiVectorComparator::Chamfer,
+                (&b).into(),
+                (&a).into()
+            ),
+            score(MultiVectorComparator::Chamfer),
+        );
+
+        // Accumulator matches the direct implementation of MaxSim
+        let mut accumulator = MultiSimilarityAccumulator::new(MultiVectorComparator::MaxSim, 2);
+        for dense_b in b.multi_vectors() {
+            accumulator.push(
+                a.multi_vectors()
+                    .map(|dense_a| DotProductMetric::similarity(dense_a, dense_b)),
+            );
+        }
+        assert_eq!(accumulator.finish(), score(MultiVectorComparator::MaxSim));
+    }
+
+    #[test]
+    fn test_score_multi_mean_pool() {
+        let a = MultiDenseVectorInternal::try_from(vec![vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap();
+        let b = MultiDenseVectorInternal::try_from(vec![vec![1.0, 0.0], vec![0.6, 0.8]]).unwrap();
+
+        // mean-pooled `a` is [0.5, 0.5], mean-pooled `b` is [0.8, 0.4]
+        let dot = score_multi::<f32, DotProductMetric>(
+            MultiVectorComparator::MeanPool,
+            (&a).into(),
+            (&b).into(),
+        );
+        assert!((dot - 0.6).abs() < 1e-6);
+        let euclid = score_multi::<f32, EuclidMetric>(
+            MultiVectorComparator::MeanPool,
+            (&a).into(),
+            (&b).into(),
+        );
+        assert!((euclid + 0.1).abs() < 1e-6);
+        // means are normalized before scoring
+        let cosine = score_multi::<f32, CosineMetric>(
+            MultiVectorComparator::MeanPool,
+            (&a).into(),
+            (&b).into(),
+        );
+        assert!((cosine - 0.6 / (0.5f32.sqrt() * 0.8f32.sqrt())).abs() < 1e-6);
+
+        // Same scores are derived from similarities of inner vectors, as done for quantized storages
+        let mean_sim = |x: &MultiDenseVectorInternal, y: &MultiDenseVectorInternal, distance| {
+            let comparator = MultiVectorComparator::MeanSim;
+            match distance {
+                Distance::Dot => {
+                    score_multi::<f32, DotProductMetric>(comparator, x.into(), y.into())
+                }
+                Distance::Euclid => {
+                    score_multi::<f32, EuclidMetric>(comparator, x.into(), y.into())
+                }
+                Distance::Cosine => {
+                    score_multi::<f32, CosineMetric>(comparator, x.into(), y.into())
+                }
+                Distance::Manhattan => unreachable!(),
+            }
+        };
+        for (distance, expected) in [
+            (Distance::Dot, dot),
+            (Distance::Euclid, euclid),
+            (Distance::Cosine, cosine),
+        ] {
+            let derived = mean_pooled_similarity(
+                distance,
+                mean_sim(&a, &b, distance),
+                || mean_sim(&a, &a, distance),
+                || mean_sim(&b, &b, distance),
+            );
+            assert!((derived - expected).abs() < 1e-6, "{distance:?}");
+        }
+    }
 }
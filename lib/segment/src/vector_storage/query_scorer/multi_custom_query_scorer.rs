@@ -12,6 +12,7 @@ use crate::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
 };
 use crate::spaces::metric::Metric;
+use crate::types::MultiVectorComparator;
 use crate::vector_storage::MultiVectorStorage;
 use crate::vector_storage::query::{Query, TransformInto};
 use crate::vector_storage::query_scorer::QueryScorer;
@@ -25,6 +26,7 @@ pub struct MultiCustomQueryScorer<
 > {
     vector_storage: &'a TVectorStorage,
     query: TQuery,
+    comparator: MultiVectorComparator,
     metric: PhantomData<TMetric>,
     element: PhantomData<TElement>,
     hardware_counter: HardwareCounterCell,
@@ -41,6 +43,7 @@ impl<
     pub fn new<TInputQuery>(
         query: TInputQuery,
         vector_storage: &'a TVectorStorage,
+        comparator: MultiVectorComparator,
         mut hardware_counter: HardwareCounterCell,
     ) -> Self
     where
@@ -73,6 +76,7 @@ impl<
         Self {
             query,
             vector_storage,
+            comparator,
             metric: PhantomData,
             element: PhantomData,
             hardware_counter,
@@ -97,7 +101,7 @@ impl<
             cpu_counter.incr_delta(example.vectors_count() * against_vector_count);
 
             score_multi::<TElement, TMetric>(
-                self.vector_storage.multi_vector_config(),
+                self.comparator,
                 TypedMultiDenseVectorRef::from(example),
                 against,
             )
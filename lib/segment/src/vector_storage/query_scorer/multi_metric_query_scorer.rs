@@ -12,6 +12,7 @@ use crate::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, TypedMultiDenseVector, TypedMultiDenseVectorRef,
 };
 use crate::spaces::metric::Metric;
+use crate::types::MultiVectorComparator;
 use crate::vector_storage::MultiVectorStorage;
 use crate::vector_storage::query_scorer::QueryScorer;
 
@@ -23,6 +24,7 @@ pub struct MultiMetricQueryScorer<
 > {
     vector_storage: &'a TVectorStorage,
     query: TypedMultiDenseVector<TElement>,
+    comparator: MultiVectorComparator,
     metric: PhantomData<TMetric>,
     hardware_counter: HardwareCounterCell,
 }
@@ -37,6 +39,7 @@ impl<
     pub fn new(
         query: &MultiDenseVectorInternal,
         vector_storage: &'a TVectorStorage,
+        comparator: MultiVectorComparator,
         mut hardware_counter: HardwareCounterCell,
     ) -> Self {
         let mut preprocessed = DenseVector::new();
@@ -56,6 +59,7 @@ impl<
         Self {
             query: TElement::from_float_multivector(CowMultiVector::Owned(preprocessed)).to_owned(),
             vector_storage,
+            comparator,
             metric: PhantomData,
             hardware_counter,
         }
@@ -71,11 +75,7 @@ impl<
             // Calculate the amount of comparisons needed for multi vector scoring.
             .incr_delta(multi_dense_a.vectors_count() * multi_dense_b.vectors_count());
 
-        score_multi::<TElement, TMetric>(
-            self.vector_storage.multi_vector_config(),
-            multi_dense_a,
-            multi_dense_b,
-        )
+        score_multi::<TElement, TMetric>(self.comparator, multi_dense_a, multi_dense_b)
     }
 
     fn score_ref(&self, v2: TypedMultiDenseVectorRef<TElement>) -> ScoreType {
@@ -19,10 +19,13 @@ use crate::data_types::vectors::{
     DenseVector, MultiDenseVectorInternal, QueryVector, VectorInternal,
 };
 use crate::spaces::metric::Metric;
-use crate::spaces::simple::{CosineMetric, DotProductMetric, EuclidMetric, ManhattanMetric};
-use crate::types::Distance;
+use crate::spaces::simple::{
+    CosineMetric, DotProductMetric, EuclidMetric, HammingMetric, JaccardMetric, ManhattanMetric,
+};
+use crate::types::{Distance, MultiVectorComparator};
 use crate::vector_storage::query::NaiveFeedbackQuery;
 use crate::vector_storage::query_scorer::QueryScorer;
+use crate::vector_storage::query_scorer::matryoshka_query_scorer::MatryoshkaQueryScorer;
 use crate::vector_storage::query_scorer::metric_query_scorer::MetricQueryScorer;
 use crate::vector_storage::query_scorer::multi_metric_query_scorer::MultiMetricQueryScorer;
 use crate::vector_storage::query_scorer::sparse_metric_query_scorer::SparseMetricQueryScorer;
@@ -53,6 +56,17 @@ pub fn new_raw_scorer<'a>(
     query: QueryVector,
     vector_storage: &'a VectorStorageEnum,
     hc: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    new_raw_scorer_with_comparator(query, vector_storage, None, hc)
+}
+
+/// Create a raw scorer, overriding the comparator of multivector storages if `comparator` is set.
+/// The override is ignored for dense and sparse storages.
+pub fn new_raw_scorer_with_comparator<'a>(
+    query: QueryVector,
+    vector_storage: &'a VectorStorageEnum,
+    comparator: Option<MultiVectorComparator>,
+    hc: HardwareCounterCell,
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
     match vector_storage {
         VectorStorageEnum::DenseVolatile(vs) => raw_scorer_impl(query, vs, hc),
@@ -64,6 +78,7 @@ pub fn new_raw_scorer<'a>(
         VectorStorageEnum::DenseMemmap(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseMemmapByte(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseMemmapHalf(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseMemmapBit(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
 
         #[cfg(target_os = "linux")]
         VectorStorageEnum::DenseUring(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
@@ -71,31 +86,102 @@ pub fn new_raw_scorer<'a>(
         VectorStorageEnum::DenseUringByte(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         #[cfg(target_os = "linux")]
         VectorStorageEnum::DenseUringHalf(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        #[cfg(target_os = "linux")]
+        VectorStorageEnum::DenseUringBit(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
 
         VectorStorageEnum::DenseAppendableMemmap(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseAppendableMemmapByte(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::DenseAppendableMemmapHalf(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseAppendableMemmapBit(vs) => raw_scorer_impl(query, vs.as_ref(), hc),
         VectorStorageEnum::SparseVolatile(vs) => raw_sparse_scorer_volatile(query, vs, hc),
         VectorStorageEnum::SparseMmap(vs) => raw_sparse_scorer_impl(query, vs, hc),
-        VectorStorageEnum::MultiDenseVolatile(vs) => raw_multi_scorer_impl(query, vs, hc),
+        VectorStorageEnum::MultiDenseVolatile(vs) => {
+            raw_multi_scorer_impl(query, vs, comparator, hc)
+        }
         #[cfg(test)]
-        VectorStorageEnum::MultiDenseVolatileByte(vs) => raw_multi_scorer_impl(query, vs, hc),
+        VectorStorageEnum::MultiDenseVolatileByte(vs) => {
+            raw_multi_scorer_impl(query, vs, comparator, hc)
+        }
         #[cfg(test)]
-        VectorStorageEnum::MultiDenseVolatileHalf(vs) => raw_multi_scorer_impl(query, vs, hc),
+        VectorStorageEnum::MultiDenseVolatileHalf(vs) => {
+            raw_multi_scorer_impl(query, vs, comparator, hc)
+        }
         VectorStorageEnum::MultiDenseAppendableMemmap(vs) => {
-            raw_multi_scorer_impl(query, vs.as_ref(), hc)
+            raw_multi_scorer_impl(query, vs.as_ref(), comparator, hc)
         }
         VectorStorageEnum::MultiDenseAppendableMemmapByte(vs) => {
-            raw_multi_scorer_impl(query, vs.as_ref(), hc)
+            raw_multi_scorer_impl(query, vs.as_ref(), comparator, hc)
         }
         VectorStorageEnum::MultiDenseAppendableMemmapHalf(vs) => {
-            raw_multi_scorer_impl(query, vs.as_ref(), hc)
+            raw_multi_scorer_impl(query, vs.as_ref(), comparator, hc)
         }
         VectorStorageEnum::EmptyDense(vs) => raw_scorer_impl(query, vs, hc),
         VectorStorageEnum::EmptySparse(vs) => raw_sparse_scorer_impl(query, vs, hc),
     }
 }
 
+/// Create a raw scorer, which compares a nearest query with the first dimensions of stored
+/// dense vectors, see [`crate::types::VectorDataConfig::matryoshka_dim`].
+pub fn new_matryoshka_raw_scorer<'a>(
+    query: QueryVector,
+    vector_storage: &'a VectorStorageEnum,
+    hc: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>> {
+    match vector_storage {
+        VectorStorageEnum::DenseVolatile(vs) => matryoshka_raw_scorer_impl(query, vs, hc),
+        #[cfg(test)]
+        VectorStorageEnum::DenseVolatileByte(vs) => matryoshka_raw_scorer_impl(query, vs, hc),
+        #[cfg(test)]
+        VectorStorageEnum::DenseVolatileHalf(vs) => matryoshka_raw_scorer_impl(query, vs, hc),
+
+        VectorStorageEnum::DenseMemmap(vs) => matryoshka_raw_scorer_impl(query, vs.as_ref(), hc),
+        VectorStorageEnum::DenseMemmapByte(vs) => {
+            matryoshka_raw_scorer_impl(query, vs.as_ref(), hc)
+        }
+        VectorStorageEnum::DenseMemmapHalf(vs) => {
+            matryoshka_raw_scorer_impl(query, vs.as_ref(), hc)
+        }
+        VectorStorageEnum::DenseMemmapBit(vs) => matryoshka_raw_scorer_impl(query, vs.as_ref(), hc),
+
+        #[cfg(target_os = "linux")]
+        VectorStorageEnum::DenseUring(vs) => matryoshka_raw_scorer_impl(query, vs.as_ref(), hc),
+        #[cfg(target_os = "linux")]
+        VectorStorageEnum::DenseUringByte(vs) => matryoshka_raw_scorer_impl(query, vs.as_ref(), hc),
+        #[cfg(target_os = "linux")]
+        VectorStorageEnum::DenseUringHalf(vs) => matryoshka_raw_scorer_impl(query, vs.as_ref(), hc),
+        #[cfg(target_os = "linux")]
+        VectorStorageEnum::DenseUringBit(vs) => matryoshka_raw_scorer_impl(query, vs.as_ref(), hc),
+
+        VectorStorageEnum::DenseAppendableMemmap(vs) => {
+            matryoshka_raw_scorer_impl(query, vs.as_ref(), hc)
+        }
+        VectorStorageEnum::DenseAppendableMemmapByte(vs) => {
+            matryoshka_raw_scorer_impl(query, vs.as_ref(), hc)
+        }
+        VectorStorageEnum::DenseAppendableMemmapHalf(vs) => {
+            matryoshka_raw_scorer_impl(query, vs.as_ref(), hc)
+        }
+        VectorStorageEnum::DenseAppendableMemmapBit(vs) => {
+            matryoshka_raw_scorer_impl(query, vs.as_ref(), hc)
+        }
+        VectorStorageEnum::EmptyDense(vs) => matryoshka_raw_scorer_impl(query, vs, hc),
+        VectorStorageEnum::SparseVolatile(_)
+        | VectorStorageEnum::SparseMmap(_)
+        | VectorStorageEnum::MultiDenseVolatile(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmap(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmapByte(_)
+        | VectorStorageEnum::MultiDenseAppendableMemmapHalf(_)
+        | VectorStorageEnum::EmptySparse(_) => Err(OperationError::service_error(
+            "Matryoshka search is only supported for dense vectors",
+        )),
+        #[cfg(test)]
+        VectorStorageEnum::MultiDenseVolatileByte(_)
+        | VectorStorageEnum::MultiDenseVolatileHalf(_) => Err(OperationError::service_error(
+            "Matryoshka search is only supported for dense vectors",
+        )),
+    }
+}
+
 pub static DEFAULT_STOPPED: AtomicBool = AtomicBool::new(false);
 
 pub fn raw_sparse_scorer_volatile<'a>(
@@ -198,6 +284,8 @@ where
     EuclidMetric: Metric<TElement>,
     DotProductMetric: Metric<TElement>,
     ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
+    JaccardMetric: Metric<TElement>,
 {
     match vector_storage.distance() {
         Distance::Cosine => new_scorer_with_metric::<TElement, CosineMetric, _>(
@@ -220,6 +308,91 @@ where
             vector_storage,
             hardware_counter,
         ),
+        Distance::Hamming => new_scorer_with_metric::<TElement, HammingMetric, _>(
+            query,
+            vector_storage,
+            hardware_counter,
+        ),
+        Distance::Jaccard => new_scorer_with_metric::<TElement, JaccardMetric, _>(
+            query,
+            vector_storage,
+            hardware_counter,
+        ),
+    }
+}
+
+fn matryoshka_raw_scorer_impl<
+    'a,
+    TElement: PrimitiveVectorElement,
+    TVectorStorage: DenseVectorStorage<TElement>,
+>(
+    query: QueryVector,
+    vector_storage: &'a TVectorStorage,
+    hardware_counter: HardwareCounterCell,
+) -> OperationResult<Box<dyn RawScorer + 'a>>
+where
+    CosineMetric: Metric<TElement>,
+    EuclidMetric: Metric<TElement>,
+    DotProductMetric: Metric<TElement>,
+    ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
+    JaccardMetric: Metric<TElement>,
+{
+    let QueryVector::Nearest(vector) = query else {
+        return Err(OperationError::service_error(
+            "Matryoshka search is only supported for nearest queries",
+        ));
+    };
+    let vector: DenseVector = vector.try_into()?;
+    if vector.is_empty() || vector.len() >= vector_storage.vector_dim() {
+        return Err(OperationError::WrongVectorDimension {
+            expected_dim: vector_storage.vector_dim(),
+            received_dim: vector.len(),
+        });
+    }
+    match vector_storage.distance() {
+        Distance::Cosine => {
+            raw_scorer_from_query_scorer(MatryoshkaQueryScorer::<_, CosineMetric, _>::new(
+                vector,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Euclid => {
+            raw_scorer_from_query_scorer(MatryoshkaQueryScorer::<_, EuclidMetric, _>::new(
+                vector,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Dot => {
+            raw_scorer_from_query_scorer(MatryoshkaQueryScorer::<_, DotProductMetric, _>::new(
+                vector,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Manhattan => {
+            raw_scorer_from_query_scorer(MatryoshkaQueryScorer::<_, ManhattanMetric, _>::new(
+                vector,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Hamming => {
+            raw_scorer_from_query_scorer(MatryoshkaQueryScorer::<_, HammingMetric, _>::new(
+                vector,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
+        Distance::Jaccard => {
+            raw_scorer_from_query_scorer(MatryoshkaQueryScorer::<_, JaccardMetric, _>::new(
+                vector,
+                vector_storage,
+                hardware_counter,
+            ))
+        }
     }
 }
 
@@ -235,8 +408,9 @@ fn new_scorer_with_metric<
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
     match query {
         QueryVector::Nearest(vector) => {
+            let vector: DenseVector = vector.try_into()?;
             let query_scorer = MetricQueryScorer::<_, TMetric, _>::new(
-                vector.try_into()?,
+                vector,
                 vector_storage,
                 hardware_counter_cell,
             );
@@ -304,6 +478,7 @@ pub fn raw_multi_scorer_impl<
 >(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
+    comparator: Option<MultiVectorComparator>,
     hardware_counter: HardwareCounterCell,
 ) -> OperationResult<Box<dyn RawScorer + 'a>>
 where
@@ -311,26 +486,45 @@ where
     EuclidMetric: Metric<TElement>,
     DotProductMetric: Metric<TElement>,
     ManhattanMetric: Metric<TElement>,
+    HammingMetric: Metric<TElement>,
+    JaccardMetric: Metric<TElement>,
 {
+    let comparator = comparator.unwrap_or(vector_storage.multi_vector_config().comparator);
     match vector_storage.distance() {
         Distance::Cosine => new_multi_scorer_with_metric::<_, CosineMetric, _>(
             query,
             vector_storage,
+            comparator,
             hardware_counter,
         ),
         Distance::Euclid => new_multi_scorer_with_metric::<_, EuclidMetric, _>(
             query,
             vector_storage,
+            comparator,
             hardware_counter,
         ),
         Distance::Dot => new_multi_scorer_with_metric::<_, DotProductMetric, _>(
             query,
             vector_storage,
+            comparator,
             hardware_counter,
         ),
         Distance::Manhattan => new_multi_scorer_with_metric::<_, ManhattanMetric, _>(
             query,
             vector_storage,
+            comparator,
+            hardware_counter,
+        ),
+        Distance::Hamming => new_multi_scorer_with_metric::<_, HammingMetric, _>(
+            query,
+            vector_storage,
+            comparator,
+            hardware_counter,
+        ),
+        Distance::Jaccard => new_multi_scorer_with_metric::<_, JaccardMetric, _>(
+            query,
+            vector_storage,
+            comparator,
             hardware_counter,
         ),
     }
@@ -344,6 +538,7 @@ fn new_multi_scorer_with_metric<
 >(
     query: QueryVector,
     vector_storage: &'a TVectorStorage,
+    comparator: MultiVectorComparator,
     hardware_counter: HardwareCounterCell,
 ) -> OperationResult<Box<dyn RawScorer + 'a>> {
     match query {
@@ -351,6 +546,7 @@ fn new_multi_scorer_with_metric<
             let query_scorer = MultiMetricQueryScorer::<_, TMetric, _>::new(
                 &vector.try_into()?,
                 vector_storage,
+                comparator,
                 hardware_counter,
             );
             raw_scorer_from_query_scorer(query_scorer)
@@ -360,6 +556,7 @@ fn new_multi_scorer_with_metric<
             let query_scorer = MultiCustomQueryScorer::<_, TMetric, _, _>::new(
                 RecoBestScoreQuery::from(reco_query),
                 vector_storage,
+                comparator,
                 hardware_counter,
             );
             raw_scorer_from_query_scorer(query_scorer)
@@ -369,6 +566,7 @@ fn new_multi_scorer_with_metric<
             let query_scorer = MultiCustomQueryScorer::<_, TMetric, _, _>::new(
                 RecoSumScoresQuery::from(reco_query),
                 vector_storage,
+                comparator,
                 hardware_counter,
             );
             raw_scorer_from_query_scorer(query_scorer)
@@ -379,6 +577,7 @@ fn new_multi_scorer_with_metric<
             let query_scorer = MultiCustomQueryScorer::<_, TMetric, _, _>::new(
                 discover_query,
                 vector_storage,
+                comparator,
                 hardware_counter,
             );
             raw_scorer_from_query_scorer(query_scorer)
@@ -389,6 +588,7 @@ fn new_multi_scorer_with_metric<
             let query_scorer = MultiCustomQueryScorer::<_, TMetric, _, _>::new(
                 context_query,
                 vector_storage,
+                comparator,
                 hardware_counter,
             );
             raw_scorer_from_query_scorer(query_scorer)
@@ -399,6 +599,7 @@ fn new_multi_scorer_with_metric<
             let query_scorer = MultiCustomQueryScorer::<_, TMetric, _, _>::new(
                 feedback_query.into_query(),
                 vector_storage,
+                comparator,
                 hardware_counter,
             );
             raw_scorer_from_query_scorer(query_scorer)
@@ -105,6 +105,7 @@ fn test_random_score(
         None,
         None,
         deleted_points,
+        None,
         HardwareCounterCell::new(),
     )?;
 
@@ -84,6 +84,7 @@ fn product_x4() -> WithQuantization {
     let config = ProductQuantizationConfig {
         compression: crate::types::CompressionRatio::X4,
         always_ram: Some(true),
+        opq: None,
     }
     .into();
 
@@ -182,6 +183,7 @@ fn scoring_equivalency(
             quantized_vectors.as_ref(),
             None,
             id_tracker.deleted_point_bitslice(),
+            None,
             HardwareCounterCell::new(),
         )?;
 
@@ -249,6 +249,7 @@ fn do_test_score_points(storage: &mut VectorStorageEnum) {
         None,
         None,
         id_tracker.deleted_point_bitslice(),
+        None,
         HardwareCounterCell::new(),
     )
     .unwrap();
@@ -260,6 +261,7 @@ fn do_test_score_points(storage: &mut VectorStorageEnum) {
         None,
         2,
         id_tracker.deleted_point_bitslice(),
+        None,
         HardwareCounterCell::new(),
     )
     .unwrap();
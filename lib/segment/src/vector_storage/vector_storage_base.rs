@@ -26,8 +26,8 @@ use crate::common::operation_error::{OperationError, OperationResult};
 use crate::data_types::named_vectors::{CowMultiVector, CowVector};
 use crate::data_types::primitive::PrimitiveVectorElement;
 use crate::data_types::vectors::{
-    MultiDenseVectorInternal, TypedMultiDenseVectorRef, VectorElementType, VectorElementTypeByte,
-    VectorElementTypeHalf, VectorInternal, VectorRef,
+    MultiDenseVectorInternal, PackedBits, TypedMultiDenseVectorRef, VectorElementType,
+    VectorElementTypeBit, VectorElementTypeByte, VectorElementTypeHalf, VectorInternal, VectorRef,
 };
 use crate::types::{Distance, MultiVectorConfig, VectorStorageDatatype};
 use crate::vector_storage::dense::appendable_dense_vector_storage::AppendableMmapDenseVectorStorage;
@@ -263,6 +263,7 @@ pub enum VectorStorageEnum {
     DenseMemmap(Box<DenseVectorStorageImpl<VectorElementType>>),
     DenseMemmapByte(Box<DenseVectorStorageImpl<VectorElementTypeByte>>),
     DenseMemmapHalf(Box<DenseVectorStorageImpl<VectorElementTypeHalf>>),
+    DenseMemmapBit(Box<DenseVectorStorageImpl<VectorElementTypeBit>>),
 
     #[cfg(target_os = "linux")]
     DenseUring(Box<DenseVectorStorageImpl<VectorElementType, IoUringFile>>),
@@ -270,10 +271,13 @@ pub enum VectorStorageEnum {
     DenseUringByte(Box<DenseVectorStorageImpl<VectorElementTypeByte, IoUringFile>>),
     #[cfg(target_os = "linux")]
     DenseUringHalf(Box<DenseVectorStorageImpl<VectorElementTypeHalf, IoUringFile>>),
+    #[cfg(target_os = "linux")]
+    DenseUringBit(Box<DenseVectorStorageImpl<VectorElementTypeBit, IoUringFile>>),
 
     DenseAppendableMemmap(Box<AppendableMmapDenseVectorStorage<VectorElementType>>),
     DenseAppendableMemmapByte(Box<AppendableMmapDenseVectorStorage<VectorElementTypeByte>>),
     DenseAppendableMemmapHalf(Box<AppendableMmapDenseVectorStorage<VectorElementTypeHalf>>),
+    DenseAppendableMemmapBit(Box<AppendableMmapDenseVectorStorage<VectorElementTypeBit>>),
     SparseVolatile(VolatileSparseVectorStorage),
     SparseMmap(MmapSparseVectorStorage),
     MultiDenseVolatile(VolatileMultiDenseVectorStorage<VectorElementType>),
@@ -303,6 +307,7 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(_) => None,
             VectorStorageEnum::DenseMemmapByte(_) => None,
             VectorStorageEnum::DenseMemmapHalf(_) => None,
+            VectorStorageEnum::DenseMemmapBit(_) => None,
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(_) => None,
@@ -310,10 +315,13 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(_) => None,
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(_) => None,
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(_) => None,
 
             VectorStorageEnum::DenseAppendableMemmap(_) => None,
             VectorStorageEnum::DenseAppendableMemmapByte(_) => None,
             VectorStorageEnum::DenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapBit(_) => None,
             VectorStorageEnum::SparseVolatile(_) => None,
             VectorStorageEnum::SparseMmap(_) => None,
             VectorStorageEnum::MultiDenseVolatile(s) => Some(s.multi_vector_config()),
@@ -329,6 +337,88 @@ impl VectorStorageEnum {
         }
     }
 
+    /// Dimension of vectors, if this is a dense storage
+    pub fn try_dense_vector_dim(&self) -> Option<usize> {
+        match self {
+            VectorStorageEnum::DenseVolatile(s) => Some(s.vector_dim()),
+            #[cfg(test)]
+            VectorStorageEnum::DenseVolatileByte(s) => Some(s.vector_dim()),
+            #[cfg(test)]
+            VectorStorageEnum::DenseVolatileHalf(s) => Some(s.vector_dim()),
+            VectorStorageEnum::DenseMemmap(s) => Some(s.vector_dim()),
+            VectorStorageEnum::DenseMemmapByte(s) => Some(s.vector_dim()),
+            VectorStorageEnum::DenseMemmapHalf(s) => Some(s.vector_dim()),
+            VectorStorageEnum::DenseMemmapBit(s) => Some(s.vector_dim()),
+
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUring(s) => Some(s.vector_dim()),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringByte(s) => Some(s.vector_dim()),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringHalf(s) => Some(s.vector_dim()),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(s) => Some(s.vector_dim()),
+
+            VectorStorageEnum::DenseAppendableMemmap(s) => Some(s.vector_dim()),
+            VectorStorageEnum::DenseAppendableMemmapByte(s) => Some(s.vector_dim()),
+            VectorStorageEnum::DenseAppendableMemmapHalf(s) => Some(s.vector_dim()),
+            VectorStorageEnum::DenseAppendableMemmapBit(s) => Some(s.vector_dim()),
+            VectorStorageEnum::SparseVolatile(_) => None,
+            VectorStorageEnum::SparseMmap(_) => None,
+            VectorStorageEnum::MultiDenseVolatile(_) => None,
+            #[cfg(test)]
+            VectorStorageEnum::MultiDenseVolatileByte(_) => None,
+            #[cfg(test)]
+            VectorStorageEnum::MultiDenseVolatileHalf(_) => None,
+            VectorStorageEnum::MultiDenseAppendableMemmap(_) => None,
+            VectorStorageEnum::MultiDenseAppendableMemmapByte(_) => None,
+            VectorStorageEnum::MultiDenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::EmptyDense(s) => Some(s.vector_dim()),
+            VectorStorageEnum::EmptySparse(_) => None,
+        }
+    }
+
+    /// Dimension of inner vectors, if this is a multivector storage
+    pub fn try_multi_vector_dim(&self) -> Option<usize> {
+        match self {
+            VectorStorageEnum::DenseVolatile(_) => None,
+            #[cfg(test)]
+            VectorStorageEnum::DenseVolatileByte(_) => None,
+            #[cfg(test)]
+            VectorStorageEnum::DenseVolatileHalf(_) => None,
+            VectorStorageEnum::DenseMemmap(_) => None,
+            VectorStorageEnum::DenseMemmapByte(_) => None,
+            VectorStorageEnum::DenseMemmapHalf(_) => None,
+            VectorStorageEnum::DenseMemmapBit(_) => None,
+
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUring(_) => None,
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringByte(_) => None,
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringHalf(_) => None,
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(_) => None,
+
+            VectorStorageEnum::DenseAppendableMemmap(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapByte(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapHalf(_) => None,
+            VectorStorageEnum::DenseAppendableMemmapBit(_) => None,
+            VectorStorageEnum::SparseVolatile(_) => None,
+            VectorStorageEnum::SparseMmap(_) => None,
+            VectorStorageEnum::MultiDenseVolatile(s) => Some(s.vector_dim()),
+            #[cfg(test)]
+            VectorStorageEnum::MultiDenseVolatileByte(s) => Some(s.vector_dim()),
+            #[cfg(test)]
+            VectorStorageEnum::MultiDenseVolatileHalf(s) => Some(s.vector_dim()),
+            VectorStorageEnum::MultiDenseAppendableMemmap(s) => Some(s.vector_dim()),
+            VectorStorageEnum::MultiDenseAppendableMemmapByte(s) => Some(s.vector_dim()),
+            VectorStorageEnum::MultiDenseAppendableMemmapHalf(s) => Some(s.vector_dim()),
+            VectorStorageEnum::EmptyDense(_) => None,
+            VectorStorageEnum::EmptySparse(_) => None,
+        }
+    }
+
     pub(crate) fn default_vector(&self) -> VectorInternal {
         match self {
             VectorStorageEnum::DenseVolatile(v) => VectorInternal::from(vec![1.0; v.vector_dim()]),
@@ -347,6 +437,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmapHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseMemmapBit(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim() * PackedBits::DIMS])
+            }
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => VectorInternal::from(vec![1.0; v.vector_dim()]),
@@ -354,6 +447,10 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => VectorInternal::from(vec![1.0; v.vector_dim()]),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => VectorInternal::from(vec![1.0; v.vector_dim()]),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim() * PackedBits::DIMS])
+            }
 
             VectorStorageEnum::DenseAppendableMemmap(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
@@ -364,6 +461,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 VectorInternal::from(vec![1.0; v.vector_dim()])
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => {
+                VectorInternal::from(vec![1.0; v.vector_dim() * PackedBits::DIMS])
+            }
             VectorStorageEnum::SparseVolatile(_) => VectorInternal::from(SparseVector::default()),
             VectorStorageEnum::SparseMmap(_) => VectorInternal::from(SparseVector::default()),
             VectorStorageEnum::MultiDenseVolatile(v) => {
@@ -401,6 +501,7 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseMemmapByte(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.size_of_available_vectors_in_bytes(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.size_of_available_vectors_in_bytes(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.size_of_available_vectors_in_bytes(),
@@ -408,6 +509,8 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.size_of_available_vectors_in_bytes(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.size_of_available_vectors_in_bytes(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.size_of_available_vectors_in_bytes(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
@@ -416,6 +519,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.size_of_available_vectors_in_bytes()
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => {
+                v.size_of_available_vectors_in_bytes()
+            }
             VectorStorageEnum::SparseVolatile(v) => v.size_of_available_vectors_in_bytes(),
             VectorStorageEnum::SparseMmap(_v) => {
                 unreachable!(
@@ -451,6 +557,7 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(vs) => vs.populate(),
             VectorStorageEnum::DenseMemmapByte(vs) => vs.populate(),
             VectorStorageEnum::DenseMemmapHalf(vs) => vs.populate(),
+            VectorStorageEnum::DenseMemmapBit(vs) => vs.populate(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(vs) => vs.populate(),
@@ -458,10 +565,13 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(vs) => vs.populate(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(vs) => vs.populate(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(vs) => vs.populate(),
 
             VectorStorageEnum::DenseAppendableMemmap(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableMemmapByte(vs) => vs.populate()?,
             VectorStorageEnum::DenseAppendableMemmapHalf(vs) => vs.populate()?,
+            VectorStorageEnum::DenseAppendableMemmapBit(vs) => vs.populate()?,
             VectorStorageEnum::SparseVolatile(_) => {} // Can't populate as it is not mmap
             VectorStorageEnum::SparseMmap(vs) => vs.populate()?,
             VectorStorageEnum::MultiDenseVolatile(_) => {} // Can't populate as it is not mmap
@@ -488,6 +598,7 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseMemmapByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseMemmapHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::DenseMemmapBit(vs) => vs.clear_cache()?,
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(vs) => vs.clear_cache()?,
@@ -495,10 +606,13 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(vs) => vs.clear_cache()?,
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(vs) => vs.clear_cache()?,
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(vs) => vs.clear_cache()?,
 
             VectorStorageEnum::DenseAppendableMemmap(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableMemmapByte(vs) => vs.clear_cache()?,
             VectorStorageEnum::DenseAppendableMemmapHalf(vs) => vs.clear_cache()?,
+            VectorStorageEnum::DenseAppendableMemmapBit(vs) => vs.clear_cache()?,
             VectorStorageEnum::SparseVolatile(_) => {} // Can't populate as it is not mmap
             VectorStorageEnum::SparseMmap(vs) => vs.clear_cache()?,
             VectorStorageEnum::MultiDenseVolatile(_) => {} // Can't populate as it is not mmap
@@ -530,6 +644,7 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.with_dense_bytes_opt::<P, R>(key, f),
             VectorStorageEnum::DenseMemmapByte(v) => v.with_dense_bytes_opt::<P, R>(key, f),
             VectorStorageEnum::DenseMemmapHalf(v) => v.with_dense_bytes_opt::<P, R>(key, f),
+            VectorStorageEnum::DenseMemmapBit(v) => v.with_dense_bytes_opt::<P, R>(key, f),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.with_dense_bytes_opt::<P, R>(key, f),
@@ -537,6 +652,8 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.with_dense_bytes_opt::<P, R>(key, f),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.with_dense_bytes_opt::<P, R>(key, f),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.with_dense_bytes_opt::<P, R>(key, f),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.with_dense_bytes_opt::<P, R>(key, f),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
@@ -545,6 +662,9 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.with_dense_bytes_opt::<P, R>(key, f)
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => {
+                v.with_dense_bytes_opt::<P, R>(key, f)
+            }
             VectorStorageEnum::SparseVolatile(_) => None,
             VectorStorageEnum::SparseMmap(_) => None,
             VectorStorageEnum::MultiDenseVolatile(_) => None,
@@ -571,6 +691,7 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseMemmapByte(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseMemmapHalf(v) => return v.get_dense_vector_layout(),
+            VectorStorageEnum::DenseMemmapBit(v) => return v.get_dense_vector_layout(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => return v.get_dense_vector_layout(),
@@ -578,10 +699,13 @@ impl VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => return v.get_dense_vector_layout(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => return v.get_dense_vector_layout(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => return v.get_dense_vector_layout(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => return v.get_dense_vector_layout(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => return v.get_dense_vector_layout(),
             VectorStorageEnum::SparseVolatile(_) => {}
             VectorStorageEnum::SparseMmap(_) => {}
             VectorStorageEnum::MultiDenseVolatile(_) => {}
@@ -612,6 +736,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.distance(),
             VectorStorageEnum::DenseMemmapByte(v) => v.distance(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.distance(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.distance(),
@@ -619,10 +744,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.distance(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.distance(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.distance(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.distance(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.distance(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.distance(),
             VectorStorageEnum::SparseVolatile(v) => v.distance(),
             VectorStorageEnum::SparseMmap(v) => v.distance(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.distance(),
@@ -648,6 +776,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseMemmapByte(v) => v.datatype(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.datatype(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.datatype(),
@@ -655,10 +784,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.datatype(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.datatype(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.datatype(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.datatype(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.datatype(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.datatype(),
             VectorStorageEnum::SparseVolatile(v) => v.datatype(),
             VectorStorageEnum::SparseMmap(v) => v.datatype(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.datatype(),
@@ -686,6 +818,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.is_on_disk(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.is_on_disk(),
@@ -693,10 +826,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.is_on_disk(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.is_on_disk(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.is_on_disk(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_on_disk(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_on_disk(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.is_on_disk(),
             VectorStorageEnum::SparseVolatile(v) => v.is_on_disk(),
             VectorStorageEnum::SparseMmap(v) => v.is_on_disk(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.is_on_disk(),
@@ -722,6 +858,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.total_vector_count(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.total_vector_count(),
@@ -729,10 +866,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.total_vector_count(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.total_vector_count(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.total_vector_count(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.total_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.total_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.total_vector_count(),
             VectorStorageEnum::SparseVolatile(v) => v.total_vector_count(),
             VectorStorageEnum::SparseMmap(v) => v.total_vector_count(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.total_vector_count(),
@@ -758,6 +898,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::DenseMemmapBit(v) => v.get_vector::<P>(key),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.get_vector::<P>(key),
@@ -765,10 +906,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.get_vector::<P>(key),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.get_vector::<P>(key),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.get_vector::<P>(key),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector::<P>(key),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.get_vector::<P>(key),
             VectorStorageEnum::SparseVolatile(v) => v.get_vector::<P>(key),
             VectorStorageEnum::SparseMmap(v) => v.get_vector::<P>(key),
             VectorStorageEnum::MultiDenseVolatile(v) => v.get_vector::<P>(key),
@@ -798,6 +942,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.read_vectors::<P, U>(keys, callback),
             VectorStorageEnum::DenseMemmapByte(v) => v.read_vectors::<P, U>(keys, callback),
             VectorStorageEnum::DenseMemmapHalf(v) => v.read_vectors::<P, U>(keys, callback),
+            VectorStorageEnum::DenseMemmapBit(v) => v.read_vectors::<P, U>(keys, callback),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.read_vectors::<P, U>(keys, callback),
@@ -805,6 +950,8 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.read_vectors::<P, U>(keys, callback),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.read_vectors::<P, U>(keys, callback),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.read_vectors::<P, U>(keys, callback),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.read_vectors::<P, U>(keys, callback),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
@@ -813,6 +960,9 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.read_vectors::<P, U>(keys, callback)
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => {
+                v.read_vectors::<P, U>(keys, callback)
+            }
             VectorStorageEnum::SparseVolatile(v) => v.read_vectors::<P, U>(keys, callback),
             VectorStorageEnum::SparseMmap(v) => v.read_vectors::<P, U>(keys, callback),
             VectorStorageEnum::MultiDenseVolatile(v) => v.read_vectors::<P, U>(keys, callback),
@@ -844,6 +994,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::DenseMemmapBit(v) => v.get_vector_opt::<P>(key),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.get_vector_opt::<P>(key),
@@ -851,10 +1002,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.get_vector_opt::<P>(key),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.get_vector_opt::<P>(key),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.get_vector_opt::<P>(key),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.get_vector_opt::<P>(key),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::SparseVolatile(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::SparseMmap(v) => v.get_vector_opt::<P>(key),
             VectorStorageEnum::MultiDenseVolatile(v) => v.get_vector_opt::<P>(key),
@@ -880,6 +1034,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseMemmapBit(v) => v.is_deleted_vector(key),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.is_deleted_vector(key),
@@ -887,10 +1042,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.is_deleted_vector(key),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.is_deleted_vector(key),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.is_deleted_vector(key),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.is_deleted_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.is_deleted_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseVolatile(v) => v.is_deleted_vector(key),
             VectorStorageEnum::SparseMmap(v) => v.is_deleted_vector(key),
             VectorStorageEnum::MultiDenseVolatile(v) => v.is_deleted_vector(key),
@@ -916,6 +1074,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.deleted_vector_count(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.deleted_vector_count(),
@@ -923,10 +1082,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.deleted_vector_count(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.deleted_vector_count(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.deleted_vector_count(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_count(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_count(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseVolatile(v) => v.deleted_vector_count(),
             VectorStorageEnum::SparseMmap(v) => v.deleted_vector_count(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.deleted_vector_count(),
@@ -952,6 +1114,7 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.deleted_vector_bitslice(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.deleted_vector_bitslice(),
@@ -959,10 +1122,13 @@ impl VectorStorageRead for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.deleted_vector_bitslice(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.deleted_vector_bitslice(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.deleted_vector_bitslice(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.deleted_vector_bitslice(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseVolatile(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::SparseMmap(v) => v.deleted_vector_bitslice(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.deleted_vector_bitslice(),
@@ -995,6 +1161,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::DenseMemmapByte(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::DenseMemmapHalf(v) => v.insert_vector(key, vector, hw_counter),
+            VectorStorageEnum::DenseMemmapBit(v) => v.insert_vector(key, vector, hw_counter),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.insert_vector(key, vector, hw_counter),
@@ -1002,6 +1169,8 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.insert_vector(key, vector, hw_counter),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.insert_vector(key, vector, hw_counter),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.insert_vector(key, vector, hw_counter),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
@@ -1010,6 +1179,9 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.insert_vector(key, vector, hw_counter)
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => {
+                v.insert_vector(key, vector, hw_counter)
+            }
             VectorStorageEnum::SparseVolatile(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::SparseMmap(v) => v.insert_vector(key, vector, hw_counter),
             VectorStorageEnum::MultiDenseVolatile(v) => v.insert_vector(key, vector, hw_counter),
@@ -1049,6 +1221,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseMemmapByte(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseMemmapHalf(v) => v.update_from(other_vectors, stopped),
+            VectorStorageEnum::DenseMemmapBit(v) => v.update_from(other_vectors, stopped),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.update_from(other_vectors, stopped),
@@ -1056,6 +1229,8 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.update_from(other_vectors, stopped),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.update_from(other_vectors, stopped),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.update_from(other_vectors, stopped),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => {
@@ -1064,6 +1239,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => {
                 v.update_from(other_vectors, stopped)
             }
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::SparseVolatile(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::SparseMmap(v) => v.update_from(other_vectors, stopped),
             VectorStorageEnum::MultiDenseVolatile(v) => v.update_from(other_vectors, stopped),
@@ -1095,6 +1271,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseMemmapByte(v) => v.flusher(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.flusher(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.flusher(),
@@ -1102,10 +1279,13 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.flusher(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.flusher(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.flusher(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.flusher(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.flusher(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.flusher(),
             VectorStorageEnum::SparseVolatile(v) => v.flusher(),
             VectorStorageEnum::SparseMmap(v) => v.flusher(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.flusher(),
@@ -1131,6 +1311,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.files(),
             VectorStorageEnum::DenseMemmapByte(v) => v.files(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.files(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.files(),
@@ -1138,10 +1319,13 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.files(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.files(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.files(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.files(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.files(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.files(),
             VectorStorageEnum::SparseVolatile(v) => v.files(),
             VectorStorageEnum::SparseMmap(v) => v.files(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.files(),
@@ -1167,6 +1351,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.immutable_files(),
             VectorStorageEnum::DenseMemmapByte(v) => v.immutable_files(),
             VectorStorageEnum::DenseMemmapHalf(v) => v.immutable_files(),
+            VectorStorageEnum::DenseMemmapBit(v) => v.immutable_files(),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.immutable_files(),
@@ -1174,10 +1359,13 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.immutable_files(),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.immutable_files(),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.immutable_files(),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.immutable_files(),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.immutable_files(),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.immutable_files(),
             VectorStorageEnum::SparseVolatile(v) => v.immutable_files(),
             VectorStorageEnum::SparseMmap(v) => v.immutable_files(),
             VectorStorageEnum::MultiDenseVolatile(v) => v.immutable_files(),
@@ -1203,6 +1391,7 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseMemmapBit(v) => v.delete_vector(key),
 
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUring(v) => v.delete_vector(key),
@@ -1210,10 +1399,13 @@ impl VectorStorage for VectorStorageEnum {
             VectorStorageEnum::DenseUringByte(v) => v.delete_vector(key),
             #[cfg(target_os = "linux")]
             VectorStorageEnum::DenseUringHalf(v) => v.delete_vector(key),
+            #[cfg(target_os = "linux")]
+            VectorStorageEnum::DenseUringBit(v) => v.delete_vector(key),
 
             VectorStorageEnum::DenseAppendableMemmap(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapByte(v) => v.delete_vector(key),
             VectorStorageEnum::DenseAppendableMemmapHalf(v) => v.delete_vector(key),
+            VectorStorageEnum::DenseAppendableMemmapBit(v) => v.delete_vector(key),
             VectorStorageEnum::SparseVolatile(v) => v.delete_vector(key),
             VectorStorageEnum::SparseMmap(v) => v.delete_vector(key),
             VectorStorageEnum::MultiDenseVolatile(v) => v.delete_vector(key),
@@ -159,6 +159,7 @@ fn test_batch_and_single_request_equivalency() {
             quantized_vectors: quantized_vectors.clone(),
             payload_index: payload_index_ptr,
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use common::progress_tracker::ProgressTracker;
+use common::types::ScoredPointOffset;
+use rand::prelude::StdRng;
+use rand::{Rng, RngExt, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{
+    DEFAULT_VECTOR_NAME, DenseVector, QueryVector, VectorInternal, only_default_vector,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::index::VectorIndexRead;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::segment_constructor::simple_segment_constructor::build_simple_segment;
+use segment::segment_constructor::{VectorIndexBuildArgs, build_segment};
+use segment::types::{
+    Distance, HnswConfig, HnswGlobalConfig, Indexes, SearchParams, SegmentConfig, SeqNumberType,
+    VectorDataConfig, VectorStorageDatatype, VectorStorageType,
+};
+use segment::vector_storage::VectorStorageEnum;
+use tempfile::Builder;
+
+fn random_binary_vector<R: Rng + ?Sized>(rng: &mut R, dim: usize) -> DenseVector {
+    (0..dim)
+        .map(|_| if rng.random_bool(0.5) { 1.0 } else { 0.0 })
+        .collect()
+}
+
+fn scores(result: &[Vec<ScoredPointOffset>]) -> Vec<Vec<f32>> {
+    result
+        .iter()
+        .map(|points| points.iter().map(|point| point.score).collect())
+        .collect()
+}
+
+#[rstest]
+#[case::hamming(Distance::Hamming, 5)]
+#[case::jaccard(Distance::Jaccard, 5)]
+#[case::cosine(Distance::Cosine, 5)]
+fn test_bit_storage_hnsw(#[case] distance: Distance, #[case] max_failures: usize) {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 64;
+    let num_vectors: u64 = 2_000;
+    let top = 5;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir_float = Builder::new()
+        .prefix("segment_dir_float")
+        .tempdir()
+        .unwrap();
+    let dir_bit = Builder::new().prefix("segment_dir_bit").tempdir().unwrap();
+    let hnsw_dir_bit = Builder::new().prefix("hnsw_dir_bit").tempdir().unwrap();
+
+    let config_bit = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: Some(VectorStorageDatatype::Bit),
+                matryoshka_dim: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let mut segment_float = build_simple_segment(dir_float.path(), dim, distance).unwrap();
+    let mut segment_bit = build_segment(dir_bit.path(), &config_bit, None, true).unwrap();
+    {
+        let borrowed_storage = segment_bit.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .borrow();
+        let raw_storage: &VectorStorageEnum = &borrowed_storage;
+        assert!(matches!(
+            raw_storage,
+            &VectorStorageEnum::DenseAppendableMemmapBit(_),
+        ));
+    }
+
+    let hw_counter = HardwareCounterCell::new();
+    let mut vectors = Vec::new();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_binary_vector(&mut rng, dim);
+        segment_float
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment_bit
+            .upsert_point(
+                n as SeqNumberType,
+                idx,
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        vectors.push(vector);
+    }
+
+    // Stored binary vectors are returned unpacked
+    for (n, vector) in vectors.iter().enumerate().take(10) {
+        let stored = segment_bit
+            .vector(DEFAULT_VECTOR_NAME, (n as u64).into(), &hw_counter)
+            .unwrap();
+        assert_eq!(stored, Some(VectorInternal::from(vector.clone())));
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 64,
+        full_scan_threshold: 0,
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+        inline_storage: None,
+    };
+
+    let permit = Arc::new(ResourcePermit::dummy(1));
+    let hnsw_index_bit = HNSWIndex::build(
+        HnswIndexOpenArgs {
+            path: hnsw_dir_bit.path(),
+            id_tracker: segment_bit.id_tracker.clone(),
+            vector_storage: segment_bit.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .clone(),
+            quantized_vectors: segment_bit.vector_data[DEFAULT_VECTOR_NAME]
+                .quantized_vectors
+                .clone(),
+            payload_index: segment_bit.payload_index.clone(),
+            hnsw_config,
+            matryoshka_dim: None,
+        },
+        VectorIndexBuildArgs {
+            permit,
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            progress: ProgressTracker::new_for_test(),
+        },
+    )
+    .unwrap();
+
+    let mut hits = 0;
+    let attempts = 100;
+    for _ in 0..attempts {
+        let query = QueryVector::from(random_binary_vector(&mut rng, dim));
+
+        let plain_result_float = segment_float.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let plain_result_bit = segment_bit.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+
+        // Binary vectors score the same in float and bit storages, point ids may differ on ties
+        let float_scores = scores(&plain_result_float);
+        let bit_scores = scores(&plain_result_bit);
+        for (float_scores, bit_scores) in float_scores.iter().zip(&bit_scores) {
+            for (float_score, bit_score) in float_scores.iter().zip(bit_scores) {
+                assert!((float_score - bit_score).abs() < 1e-4);
+            }
+        }
+
+        let index_result_bit = hnsw_index_bit
+            .search(
+                &[&query],
+                None,
+                top,
+                Some(&SearchParams {
+                    hnsw_ef: Some(64),
+                    ..Default::default()
+                }),
+                &Default::default(),
+            )
+            .unwrap();
+
+        if scores(&index_result_bit) == bit_scores {
+            hits += 1;
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "hits: {hits} of {attempts}"
+    );
+}
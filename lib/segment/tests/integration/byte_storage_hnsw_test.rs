@@ -90,6 +90,7 @@ fn test_byte_storage_hnsw(
                 quantization_config: None,
                 multivector_config: None,
                 datatype: Some(storage_data_type),
+                matryoshka_dim: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -191,6 +192,7 @@ fn test_byte_storage_hnsw(
                 .clone(),
             payload_index: segment_byte.payload_index.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -43,7 +43,7 @@ where
     R: Rng + ?Sized,
 {
     match data_type {
-        VectorStorageDatatype::Float32 => unreachable!(),
+        VectorStorageDatatype::Float32 | VectorStorageDatatype::Bit => unreachable!(),
         VectorStorageDatatype::Float16 => {
             let mut vector = segment::fixtures::payload_fixtures::random_vector(rnd_gen, dim);
             vector.iter_mut().for_each(|x| *x -= 0.5);
@@ -229,6 +229,7 @@ fn test_byte_storage_binary_quantization_hnsw(
                 quantization_config: None,
                 multivector_config: None,
                 datatype: Some(storage_data_type),
+                matryoshka_dim: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -293,6 +294,7 @@ fn test_byte_storage_binary_quantization_hnsw(
         QuantizationVariant::PQ => ProductQuantizationConfig {
             compression: CompressionRatio::X8,
             always_ram: None,
+            opq: None,
         }
         .into(),
         QuantizationVariant::Binary => BinaryQuantizationConfig {
@@ -344,6 +346,7 @@ fn test_byte_storage_binary_quantization_hnsw(
                 .clone(),
             payload_index: segment_byte.payload_index.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -147,6 +147,7 @@ fn exact_search_test() {
                 .clone(),
             payload_index: payload_index_ptr.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -166,6 +166,7 @@ fn _test_filterable_hnsw(
             quantized_vectors: quantized_vectors.clone(),
             payload_index: payload_index_ptr.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -333,6 +334,7 @@ fn test_hnsw_search_top_zero(#[case] num_vectors: u64, #[case] full_scan_thresho
             quantized_vectors: quantized_vectors.clone(),
             payload_index: payload_index_ptr.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -142,6 +142,7 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivector_config: None,
                         datatype: None,
+                        matryoshka_dim: None,
                     },
                 ),
                 (
@@ -154,6 +155,7 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivector_config: None,
                         datatype: None,
+                        matryoshka_dim: None,
                     },
                 ),
                 (
@@ -166,6 +168,7 @@ pub fn build_segment_3(path: &Path) -> Segment {
                         quantization_config: None,
                         multivector_config: None,
                         datatype: None,
+                        matryoshka_dim: None,
                     },
                 ),
             ]),
@@ -53,6 +53,7 @@ fn build_hnsw(
             quantized_vectors: quantized_vectors.clone(),
             payload_index: segment.payload_index.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -111,6 +111,7 @@ fn hnsw_discover_precision() {
             quantized_vectors: quantized_vectors.clone(),
             payload_index: payload_index_ptr,
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -239,6 +240,7 @@ fn filtered_hnsw_discover_precision() {
             quantized_vectors: quantized_vectors.clone(),
             payload_index: payload_index_ptr,
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
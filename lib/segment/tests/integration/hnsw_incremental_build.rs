@@ -148,6 +148,7 @@ fn build_hnsw_index<R: Rng + ?Sized>(
             quantized_vectors: Default::default(),
             payload_index: Arc::clone(&segment.payload_index),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -67,7 +67,11 @@ fn random_test_vector(rng: &mut StdRng, dim: usize, distance: Distance) -> Vec<f
             let l2 = raw.iter().map(|x| x * x).sum::<f32>().sqrt();
             raw.into_iter().map(|x| x / l2).collect()
         }
-        Distance::Dot | Distance::Euclid | Distance::Manhattan => raw,
+        Distance::Dot
+        | Distance::Euclid
+        | Distance::Manhattan
+        | Distance::Hamming
+        | Distance::Jaccard => raw,
     }
 }
 
@@ -169,6 +173,7 @@ fn hnsw_quantized_search_test(
                 .clone(),
             payload_index: segment.payload_index.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -412,6 +417,7 @@ fn hnsw_product_quantization_cosine_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
         }
         .into(),
         false,
@@ -428,6 +434,24 @@ fn hnsw_product_quantization_euclid_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
+        }
+        .into(),
+        false,
+        false,
+    );
+}
+
+#[test]
+fn hnsw_product_quantization_opq_euclid_test() {
+    hnsw_quantized_search_test(
+        Distance::Euclid,
+        1003,
+        64,
+        ProductQuantizationConfig {
+            compression: CompressionRatio::X4,
+            always_ram: Some(true),
+            opq: Some(true),
         }
         .into(),
         false,
@@ -444,6 +468,7 @@ fn hnsw_product_quantization_manhattan_test() {
         ProductQuantizationConfig {
             compression: CompressionRatio::X4,
             always_ram: Some(true),
+            opq: None,
         }
         .into(),
         false,
@@ -870,6 +895,7 @@ fn build_quantized_hnsw_for_compare(
                 .clone(),
             payload_index: segment.payload_index.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -1,6 +1,7 @@
 #![expect(clippy::wildcard_enum_match_arm, reason = "test code")]
 
 mod batch_search_test;
+mod bit_storage_hnsw_test;
 mod byte_storage_hnsw_test;
 mod byte_storage_quantization_test;
 mod disbalanced_vectors_test;
@@ -14,6 +15,10 @@ mod gpu_hnsw_test;
 mod hnsw_discover_test;
 mod hnsw_incremental_build;
 mod hnsw_quantized_search_test;
+mod ivf_index_test;
+mod matryoshka_hnsw_test;
+mod multivector_comparator_test;
+mod multivector_fde_hnsw_test;
 mod multivector_filtrable_hnsw_test;
 mod multivector_hnsw_test;
 mod multivector_quantization_test;
@@ -25,3 +30,4 @@ mod segment_on_disk_snapshot;
 mod segment_tests;
 mod sparse_discover_test;
 mod sparse_vector_index_search_tests;
+mod vamana_index_test;
@@ -0,0 +1,351 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use common::progress_tracker::ProgressTracker;
+use common::types::TelemetryDetail;
+use rand::prelude::StdRng;
+use rand::{RngExt, SeedableRng};
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::index_fixtures::random_vector;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::{PayloadIndex, VectorIndex, VectorIndexRead};
+use segment::json_path::JsonPath;
+use segment::payload_json;
+use segment::segment_constructor::{VectorIndexBuildArgs, build_segment};
+use segment::types::{
+    Condition, Distance, FieldCondition, Filter, HnswConfig, HnswGlobalConfig, Indexes,
+    PayloadSchemaType, SearchParams, SegmentConfig, SeqNumberType, VectorDataConfig,
+    VectorStorageType, WithPayload,
+};
+use tempfile::Builder;
+
+#[test]
+fn test_matryoshka_hnsw() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 32;
+    let matryoshka_dim = 8;
+    let num_points: u64 = 1_000;
+    let top = 10;
+    let attempts = 20;
+    let max_failures = 2;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Cosine,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {}, // uses plain index for comparison
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+                matryoshka_dim: Some(matryoshka_dim),
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment = build_segment(dir.path(), &config, None, true).unwrap();
+    let mut vectors = Vec::with_capacity(num_points as usize);
+    for n in 0..num_points {
+        let vector = random_vector(&mut rng, dim);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        vectors.push(vector);
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 100,
+        full_scan_threshold: 1, // KB
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+        inline_storage: None,
+    };
+
+    let open_args = || HnswIndexOpenArgs {
+        path: hnsw_dir.path(),
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .clone(),
+        payload_index: segment.payload_index.clone(),
+        hnsw_config,
+        matryoshka_dim: Some(matryoshka_dim),
+    };
+
+    let hnsw_index = HNSWIndex::build(
+        open_args(),
+        VectorIndexBuildArgs {
+            permit: Arc::new(ResourcePermit::dummy(1)),
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            progress: ProgressTracker::new_for_test(),
+        },
+    )
+    .unwrap();
+
+    // Truncated graph is persisted along with the main graph
+    assert!(
+        hnsw_index
+            .files()
+            .iter()
+            .any(|file| file.starts_with(hnsw_dir.path().join("matryoshka")))
+    );
+    let reopened_index = HNSWIndex::open(open_args()).unwrap();
+
+    let params = SearchParams {
+        matryoshka: true,
+        ..Default::default()
+    };
+
+    let mut hits = 0;
+    for i in 0..attempts {
+        // Query with the first dimensions of a stored vector, which is the exact best match
+        let point = rng.random_range(0..vectors.len());
+        let query: QueryVector = vectors[point][..matryoshka_dim].to_vec().into();
+
+        let index_result = hnsw_index
+            .search(&[&query], None, top, Some(&params), &Default::default())
+            .unwrap();
+        let reopened_result = reopened_index
+            .search(&[&query], None, top, Some(&params), &Default::default())
+            .unwrap();
+        assert_eq!(index_result, reopened_result);
+
+        // check that search was performed using HNSW index
+        assert_eq!(
+            hnsw_index
+                .get_telemetry_data(TelemetryDetail::default())
+                .unfiltered_hnsw
+                .count,
+            i + 1
+        );
+
+        // segment uses a plain index by configuration
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, Some(&params), &Default::default())
+            .unwrap();
+
+        // Prefixes are normalized for cosine distance
+        assert_eq!(plain_result[0][0].idx, point as u32);
+        assert!((plain_result[0][0].score - 1.0).abs() < 1e-4);
+
+        assert_eq!(index_result[0].len(), top);
+        if index_result[0][0] == plain_result[0][0] {
+            hits += 1;
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "hits: {hits}/{attempts} (expected less than {max_failures} failures)"
+    );
+
+    // Matryoshka search is selected by the param, and requires the configured dimensions
+    let search = |query: QueryVector, params: Option<&SearchParams>| {
+        segment.search(
+            DEFAULT_VECTOR_NAME,
+            &query,
+            &WithPayload::default(),
+            &false.into(),
+            None,
+            top,
+            params,
+        )
+    };
+    assert!(search(vectors[0].clone().into(), None).is_ok());
+    assert!(search(vectors[0][..matryoshka_dim].to_vec().into(), Some(&params)).is_ok());
+    assert!(search(vectors[0][..matryoshka_dim].to_vec().into(), None).is_err());
+    assert!(search(vectors[0].clone().into(), Some(&params)).is_err());
+    assert!(
+        search(
+            vectors[0][..matryoshka_dim + 1].to_vec().into(),
+            Some(&params)
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_matryoshka_hnsw_payload_blocks() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 32;
+    let matryoshka_dim = 8;
+    let num_points: u64 = 2_000;
+    let num_tenants = 50;
+    let top = 10;
+    let attempts = 20;
+    let max_failures = 2;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Cosine,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+                matryoshka_dim: Some(matryoshka_dim),
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let hw_counter = HardwareCounterCell::new();
+    let tenant_key = "tenant";
+
+    let mut segment = build_segment(dir.path(), &config, None, true).unwrap();
+    let mut vectors = Vec::with_capacity(num_points as usize);
+    for n in 0..num_points {
+        let vector = random_vector(&mut rng, dim);
+        let payload = payload_json! {tenant_key: format!("tenant_{}", n % num_tenants)};
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, n.into(), &payload, &hw_counter)
+            .unwrap();
+        vectors.push(vector);
+    }
+    segment
+        .payload_index
+        .borrow_mut()
+        .set_indexed(
+            &JsonPath::new(tenant_key),
+            PayloadSchemaType::Keyword,
+            &hw_counter,
+        )
+        .unwrap();
+
+    let hnsw_index = HNSWIndex::build(
+        HnswIndexOpenArgs {
+            path: hnsw_dir.path(),
+            id_tracker: segment.id_tracker.clone(),
+            vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .clone(),
+            quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+                .quantized_vectors
+                .clone(),
+            payload_index: segment.payload_index.clone(),
+            hnsw_config: HnswConfig {
+                m: 8,
+                ef_construct: 64,
+                full_scan_threshold: 1, // KB
+                max_indexing_threads: 2,
+                on_disk: Some(false),
+                payload_m: None,
+                inline_storage: None,
+            },
+            matryoshka_dim: Some(matryoshka_dim),
+        },
+        VectorIndexBuildArgs {
+            permit: Arc::new(ResourcePermit::dummy(1)),
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            progress: ProgressTracker::new_for_test(),
+        },
+    )
+    .unwrap();
+
+    let params = SearchParams {
+        matryoshka: true,
+        hnsw_ef: Some(16),
+        ..Default::default()
+    };
+
+    let mut hits = 0;
+    for i in 0..attempts {
+        // Few points match the filter, so they are only reachable with links of their block
+        let point = rng.random_range(0..vectors.len());
+        let query: QueryVector = vectors[point][..matryoshka_dim].to_vec().into();
+        let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+            JsonPath::new(tenant_key),
+            format!("tenant_{}", point as u64 % num_tenants).into(),
+        )));
+
+        let index_result = hnsw_index
+            .search(
+                &[&query],
+                Some(&filter),
+                top,
+                Some(&params),
+                &Default::default(),
+            )
+            .unwrap();
+
+        // check that search was performed using HNSW index
+        assert_eq!(
+            hnsw_index
+                .get_telemetry_data(TelemetryDetail::default())
+                .filtered_large_cardinality
+                .count,
+            i + 1
+        );
+
+        assert_eq!(index_result[0].len(), top);
+        for scored_point in &index_result[0] {
+            assert_eq!(
+                u64::from(scored_point.idx) % num_tenants,
+                point as u64 % num_tenants
+            );
+        }
+        if index_result[0][0].idx == point as u32 {
+            hits += 1;
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "hits: {hits}/{attempts} (expected less than {max_failures} failures)"
+    );
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::types::{PointOffsetType, ScoredPointOffset};
+use rand::prelude::StdRng;
+use rand::{Rng, RngExt, SeedableRng};
+use rstest::rstest;
+use segment::data_types::vectors::{
+    DEFAULT_VECTOR_NAME, MultiDenseVectorInternal, QueryVector, only_default_multi_vector,
+};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_multi_vector;
+use segment::index::VectorIndexRead;
+use segment::segment::Segment;
+use segment::segment_constructor::build_segment;
+use segment::types::{
+    Distance, Indexes, MultiVectorComparator, MultiVectorConfig, QuantizationConfig,
+    ScalarQuantizationConfig, SearchParams, SegmentConfig, SeqNumberType, VectorDataConfig,
+    VectorStorageType,
+};
+use segment::vector_storage::quantized::quantized_vectors::{
+    QuantizedVectors, QuantizedVectorsStorageType,
+};
+use tempfile::Builder;
+
+const MAX_VECTORS_COUNT: usize = 4;
+
+fn random_vector<R: Rng + ?Sized>(rng: &mut R, dim: usize) -> MultiDenseVectorInternal {
+    let count = rng.random_range(1..=MAX_VECTORS_COUNT);
+    random_multi_vector(rng, dim, count)
+}
+
+fn scores(result: &[Vec<ScoredPointOffset>]) -> Vec<f32> {
+    result[0].iter().map(|point| point.score).collect()
+}
+
+fn multivector_segment(
+    path: &std::path::Path,
+    dim: usize,
+    comparator: MultiVectorComparator,
+) -> Segment {
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Dot,
+                storage_type: VectorStorageType::InRamChunkedMmap,
+                index: Indexes::Plain {},
+                quantization_config: None,
+                multivector_config: Some(MultiVectorConfig {
+                    comparator,
+                    fde: None,
+                }),
+                datatype: None,
+                matryoshka_dim: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+    build_segment(path, &config, None, true).unwrap()
+}
+
+#[rstest]
+#[case::chamfer(MultiVectorComparator::Chamfer)]
+#[case::mean_sim(MultiVectorComparator::MeanSim)]
+#[case::max_max(MultiVectorComparator::MaxMax)]
+#[case::mean_pool(MultiVectorComparator::MeanPool)]
+fn test_multivector_comparator_override(#[case] comparator: MultiVectorComparator) {
+    let stopped = AtomicBool::new(false);
+    let dim = 16;
+    let num_vectors: u64 = 500;
+    let top = 10;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir_configured = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let dir_default = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let quantized_dir_configured = Builder::new().prefix("quantized_dir").tempdir().unwrap();
+    let quantized_dir_default = Builder::new().prefix("quantized_dir").tempdir().unwrap();
+
+    // Comparator set in the vector configuration
+    let mut segment_configured = multivector_segment(dir_configured.path(), dim, comparator);
+    // Default comparator, overridden per search
+    let mut segment_default =
+        multivector_segment(dir_default.path(), dim, MultiVectorComparator::MaxSim);
+
+    let hw_counter = HardwareCounterCell::new();
+    for n in 0..num_vectors {
+        let idx = n.into();
+        let vector = random_vector(&mut rng, dim);
+        for segment in [&mut segment_configured, &mut segment_default] {
+            segment
+                .upsert_point(
+                    n as SeqNumberType,
+                    idx,
+                    only_default_multi_vector(&vector),
+                    &hw_counter,
+                )
+                .unwrap();
+        }
+    }
+
+    let override_params = SearchParams {
+        multivector_comparator: Some(comparator),
+        ..Default::default()
+    };
+
+    let queries: Vec<QueryVector> = (0..10)
+        .map(|_| random_vector(&mut rng, dim).into())
+        .collect();
+
+    for query in &queries {
+        let result_configured = segment_configured.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[query], None, top, None, &Default::default())
+            .unwrap();
+        let result_override = segment_default.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(
+                &[query],
+                None,
+                top,
+                Some(&override_params),
+                &Default::default(),
+            )
+            .unwrap();
+
+        assert_eq!(scores(&result_configured), scores(&result_override));
+    }
+
+    // Quantized scorers respect the override the same way
+    let quantization_config: QuantizationConfig = ScalarQuantizationConfig {
+        r#type: Default::default(),
+        quantile: None,
+        always_ram: Some(true),
+    }
+    .into();
+    let quantize = |segment: &Segment, path: &std::path::Path| {
+        QuantizedVectors::create(
+            &segment.vector_data[DEFAULT_VECTOR_NAME]
+                .vector_storage
+                .borrow(),
+            &quantization_config,
+            QuantizedVectorsStorageType::Immutable,
+            path,
+            1,
+            &stopped,
+        )
+        .unwrap()
+    };
+    let quantized_configured = quantize(&segment_configured, quantized_dir_configured.path());
+    let quantized_default = quantize(&segment_default, quantized_dir_default.path());
+
+    let points: Vec<PointOffsetType> = (0..num_vectors as PointOffsetType).collect();
+    for query in queries {
+        let scorer_configured = quantized_configured
+            .raw_scorer(query.clone(), HardwareCounterCell::new())
+            .unwrap();
+        let scorer_override = quantized_default
+            .raw_scorer_with_comparator(query, Some(comparator), HardwareCounterCell::new())
+            .unwrap();
+
+        let mut scores_configured = vec![0.0; points.len()];
+        let mut scores_override = vec![0.0; points.len()];
+        scorer_configured.score_points(&points, &mut scores_configured);
+        scorer_override.score_points(&points, &mut scores_override);
+        assert_eq!(scores_configured, scores_override);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use common::progress_tracker::ProgressTracker;
+use common::types::TelemetryDetail;
+use rand::prelude::StdRng;
+use rand::{RngExt, SeedableRng};
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_multi_vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::payload_fixtures::random_multi_vector;
+use segment::index::hnsw_index::hnsw::{HNSWIndex, HnswIndexOpenArgs};
+use segment::index::{VectorIndex, VectorIndexRead};
+use segment::segment_constructor::{VectorIndexBuildArgs, build_segment};
+use segment::types::{
+    Distance, FdeConfig, HnswConfig, HnswGlobalConfig, Indexes, MultiVectorComparator,
+    MultiVectorConfig, SegmentConfig, SeqNumberType, VectorDataConfig, VectorStorageType,
+};
+use tempfile::Builder;
+
+#[test]
+fn test_multivector_fde_hnsw() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 16;
+    let num_points: u64 = 1_000;
+    let top = 10;
+    let attempts = 20;
+    let max_failures = 2;
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let hnsw_dir = Builder::new().prefix("hnsw_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Cosine,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {}, // uses plain index for comparison
+                quantization_config: None,
+                multivector_config: Some(MultiVectorConfig {
+                    comparator: MultiVectorComparator::MaxSim,
+                    fde: Some(FdeConfig::default()),
+                }),
+                datatype: None,
+                matryoshka_dim: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment = build_segment(dir.path(), &config, None, true).unwrap();
+    let mut vectors = Vec::with_capacity(num_points as usize);
+    for n in 0..num_points {
+        let num_vectors = rng.random_range(1..=4);
+        let multi_vec = random_multi_vector(&mut rng, dim, num_vectors);
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_multi_vector(&multi_vec),
+                &hw_counter,
+            )
+            .unwrap();
+        vectors.push(multi_vec);
+    }
+
+    let hnsw_config = HnswConfig {
+        m: 16,
+        ef_construct: 100,
+        full_scan_threshold: 1, // KB
+        max_indexing_threads: 2,
+        on_disk: Some(false),
+        payload_m: None,
+        inline_storage: None,
+    };
+
+    let open_args = || HnswIndexOpenArgs {
+        path: hnsw_dir.path(),
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .clone(),
+        payload_index: segment.payload_index.clone(),
+        hnsw_config,
+        matryoshka_dim: None,
+    };
+
+    let hnsw_index = HNSWIndex::build(
+        open_args(),
+        VectorIndexBuildArgs {
+            permit: Arc::new(ResourcePermit::dummy(1)),
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            progress: ProgressTracker::new_for_test(),
+        },
+    )
+    .unwrap();
+
+    // Encodings are persisted along with the graph
+    assert!(
+        hnsw_index
+            .files()
+            .iter()
+            .any(|file| file.starts_with(hnsw_dir.path().join("fde")))
+    );
+    let reopened_index = HNSWIndex::open(open_args()).unwrap();
+
+    let mut hits = 0;
+    for i in 0..attempts {
+        // Query with a stored multivector, which is the exact best match
+        let query: QueryVector = (&vectors[rng.random_range(0..vectors.len())]).into();
+
+        let index_result = hnsw_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let reopened_result = reopened_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        assert_eq!(index_result, reopened_result);
+
+        // check that search was performed using HNSW index
+        assert_eq!(
+            hnsw_index
+                .get_telemetry_data(TelemetryDetail::default())
+                .unfiltered_hnsw
+                .count,
+            i + 1
+        );
+
+        // segment uses a plain index by configuration
+        let plain_result = segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+
+        // Candidates are rescored with exact MaxSim
+        assert_eq!(index_result[0].len(), top);
+        if index_result[0][0] == plain_result[0][0] {
+            hits += 1;
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "hits: {hits}/{attempts} (expected less than {max_failures} failures)"
+    );
+}
@@ -73,6 +73,7 @@ fn test_multi_filterable_hnsw(
                 quantization_config: None,
                 multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                matryoshka_dim: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -142,6 +143,7 @@ fn test_multi_filterable_hnsw(
             quantized_vectors: quantized_vectors.clone(),
             payload_index: payload_index_ptr,
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
@@ -130,6 +130,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
             quantized_vectors: quantized_vectors.clone(),
             payload_index: segment.payload_index.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit: permit.clone(),
@@ -153,6 +154,7 @@ fn test_single_multi_and_dense_hnsw_equivalency() {
         quantized_vectors: quantized_vectors.clone(),
         payload_index: segment.payload_index.clone(),
         hnsw_config,
+        matryoshka_dim: None,
     })
     .unwrap();
 
@@ -215,6 +215,7 @@ fn test_multivector_quantization_hnsw(
                 quantization_config: None,
                 multivector_config: Some(MultiVectorConfig::default()), // uses multivec config
                 datatype: None,
+                matryoshka_dim: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -267,6 +268,7 @@ fn test_multivector_quantization_hnsw(
         QuantizationVariant::PQ => ProductQuantizationConfig {
             compression: CompressionRatio::X8,
             always_ram: Some(false),
+            opq: None,
         }
         .into(),
         QuantizationVariant::Binary => BinaryQuantizationConfig {
@@ -326,6 +328,7 @@ fn test_multivector_quantization_hnsw(
                 .clone(),
             payload_index: segment.payload_index.clone(),
             hnsw_config,
+            matryoshka_dim: None,
         },
         VectorIndexBuildArgs {
             permit,
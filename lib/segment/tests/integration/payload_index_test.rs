@@ -270,6 +270,7 @@ impl TestSegments {
                     quantization_config: None,
                     multivector_config: None,
                     datatype: None,
+                    matryoshka_dim: None,
                 },
             )]),
             sparse_vector_data: Default::default(),
@@ -323,6 +323,7 @@ fn estimate_build_time(segment: &Segment, stop_delay_millis: Option<u64>) -> (u6
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                matryoshka_dim: None,
             },
         )]),
         sparse_vector_data: Default::default(),
@@ -564,6 +565,7 @@ fn test_segment_builder_rejects_target_with_extra_vector_name() {
             quantization_config: None,
             multivector_config: None,
             datatype: None,
+            matryoshka_dim: None,
         },
     );
 
@@ -611,6 +613,7 @@ fn test_segment_builder_rejects_source_with_extra_vector_name() {
             quantization_config: None,
             multivector_config: None,
             datatype: None,
+            matryoshka_dim: None,
         },
     );
     drop(template);
@@ -124,6 +124,7 @@ fn test_on_disk_segment_snapshot(#[case] format: SnapshotFormat) {
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                matryoshka_dim: None,
             },
         )]),
         sparse_vector_data: Default::default(),
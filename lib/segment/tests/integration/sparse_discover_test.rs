@@ -146,6 +146,7 @@ fn sparse_index_discover_test() {
                 quantization_config: None,
                 multivector_config: None,
                 datatype: None,
+                matryoshka_dim: None,
             },
         )]),
         payload_storage_type: Default::default(),
@@ -0,0 +1,239 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use common::budget::ResourcePermit;
+use common::counter::hardware_counter::HardwareCounterCell;
+use common::flags::FeatureFlags;
+use common::progress_tracker::ProgressTracker;
+use common::types::TelemetryDetail;
+use rand::SeedableRng;
+use rand::prelude::StdRng;
+use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, QueryVector, only_default_vector};
+use segment::entry::entry_point::SegmentEntry;
+use segment::fixtures::index_fixtures::random_vector;
+use segment::index::vamana_index::vamana::{VamanaIndex, VamanaIndexOpenArgs};
+use segment::index::{PayloadIndexRead, VectorIndex, VectorIndexRead};
+use segment::json_path::JsonPath;
+use segment::payload_json;
+use segment::segment_constructor::{VectorIndexBuildArgs, build_segment};
+use segment::types::{
+    CompressionRatio, Condition, Distance, FieldCondition, Filter, HnswGlobalConfig, Indexes,
+    SegmentConfig, SeqNumberType, VamanaConfig, VectorDataConfig, VectorStorageType,
+};
+use segment::vector_storage::query::RecoQuery;
+use tempfile::Builder;
+
+#[test]
+fn test_vamana_index() {
+    let stopped = AtomicBool::new(false);
+
+    let dim = 32;
+    let num_points: u64 = 1_000;
+    let top = 10;
+    let attempts = 20;
+    let max_failures = 2;
+    let int_key = "parity";
+
+    let mut rng = StdRng::seed_from_u64(42);
+
+    let dir = Builder::new().prefix("segment_dir").tempdir().unwrap();
+    let vamana_dir = Builder::new().prefix("vamana_dir").tempdir().unwrap();
+
+    let config = SegmentConfig {
+        vector_data: HashMap::from([(
+            DEFAULT_VECTOR_NAME.to_owned(),
+            VectorDataConfig {
+                size: dim,
+                distance: Distance::Cosine,
+                storage_type: VectorStorageType::default(),
+                index: Indexes::Plain {}, // uses plain index for comparison
+                quantization_config: None,
+                multivector_config: None,
+                datatype: None,
+                matryoshka_dim: None,
+            },
+        )]),
+        sparse_vector_data: Default::default(),
+        payload_storage_type: Default::default(),
+    };
+
+    let hw_counter = HardwareCounterCell::new();
+
+    let mut segment = build_segment(dir.path(), &config, None, true).unwrap();
+    for n in 0..num_points {
+        let vector = random_vector(&mut rng, dim);
+        let payload = payload_json! {int_key: n % 2};
+        segment
+            .upsert_point(
+                n as SeqNumberType,
+                n.into(),
+                only_default_vector(&vector),
+                &hw_counter,
+            )
+            .unwrap();
+        segment
+            .set_full_payload(n as SeqNumberType, n.into(), &payload, &hw_counter)
+            .unwrap();
+    }
+
+    let vamana_config = VamanaConfig {
+        max_degree: 16,
+        build_list_size: 64,
+        beam_width: 4,
+        compression: CompressionRatio::X16,
+        full_scan_threshold: 1, // KB
+        max_indexing_threads: 2,
+    };
+
+    let open_args = || VamanaIndexOpenArgs {
+        path: vamana_dir.path(),
+        id_tracker: segment.id_tracker.clone(),
+        vector_storage: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_storage
+            .clone(),
+        quantized_vectors: segment.vector_data[DEFAULT_VECTOR_NAME]
+            .quantized_vectors
+            .clone(),
+        payload_index: segment.payload_index.clone(),
+        vamana_config,
+    };
+
+    let vamana_index = VamanaIndex::build(
+        open_args(),
+        VectorIndexBuildArgs {
+            permit: Arc::new(ResourcePermit::dummy(1)),
+            old_indices: &[],
+            gpu_device: None,
+            rng: &mut rng,
+            stopped: &stopped,
+            hnsw_global_config: &HnswGlobalConfig::default(),
+            feature_flags: FeatureFlags::default(),
+            progress: ProgressTracker::new_for_test(),
+        },
+    )
+    .unwrap();
+
+    assert_eq!(vamana_index.indexed_vector_count(), num_points as usize);
+    // Graph and compressed vectors are persisted within the index directory
+    let files = vamana_index.files();
+    assert!(!vamana_index.graph_files().is_empty());
+    assert!(!vamana_index.pq_files().is_empty());
+    for file in vamana_index
+        .graph_files()
+        .iter()
+        .chain(&vamana_index.pq_files())
+    {
+        assert!(files.contains(file));
+        assert!(file.starts_with(vamana_dir.path()));
+    }
+    let reopened_index = VamanaIndex::open(open_args()).unwrap();
+
+    let plain_search = |query: &QueryVector, filter: Option<&Filter>| {
+        segment.vector_data[DEFAULT_VECTOR_NAME]
+            .vector_index
+            .borrow()
+            .search(&[query], filter, top, None, &Default::default())
+            .unwrap()
+    };
+
+    let mut hits = 0;
+    for i in 0..attempts {
+        let query: QueryVector = random_vector(&mut rng, dim).into();
+
+        let index_result = vamana_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        let reopened_result = reopened_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        assert_eq!(index_result, reopened_result);
+
+        // check that search was performed using the graph
+        assert_eq!(
+            vamana_index
+                .get_telemetry_data(TelemetryDetail::default())
+                .unfiltered_hnsw
+                .count,
+            i + 1
+        );
+
+        let plain_result = plain_search(&query, None);
+        assert_eq!(index_result[0].len(), top);
+        // Scores are exact, since candidates are rescored with vectors read from disk
+        if index_result[0][0] == plain_result[0][0] {
+            hits += 1;
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "hits: {hits}/{attempts} (expected less than {max_failures} failures)"
+    );
+
+    let filter = Filter::new_must(Condition::Field(FieldCondition::new_match(
+        JsonPath::new(int_key),
+        0_i64.into(),
+    )));
+    let matching_points: HashSet<_> = segment
+        .payload_index
+        .borrow()
+        .with_view(|v| v.query_points(&filter, &hw_counter, &stopped))
+        .unwrap()
+        .into_iter()
+        .collect();
+    let mut hits = 0;
+    for _ in 0..attempts {
+        let query: QueryVector = random_vector(&mut rng, dim).into();
+
+        let index_result = vamana_index
+            .search(&[&query], Some(&filter), top, None, &Default::default())
+            .unwrap();
+        assert_eq!(index_result[0].len(), top);
+        for point in &index_result[0] {
+            assert!(matching_points.contains(&point.idx));
+        }
+
+        let plain_result = plain_search(&query, Some(&filter));
+        if index_result[0][0] == plain_result[0][0] {
+            hits += 1;
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "filtered hits: {hits}/{attempts} (expected less than {max_failures} failures)"
+    );
+
+    // Recommendations are searched over the graph too
+    let graph_searches = vamana_index
+        .get_telemetry_data(TelemetryDetail::default())
+        .unfiltered_hnsw
+        .count;
+    let mut hits = 0;
+    for i in 0..attempts {
+        let query = QueryVector::RecommendBestScore(RecoQuery::new(
+            vec![random_vector(&mut rng, dim).into()],
+            vec![random_vector(&mut rng, dim).into()],
+        ));
+
+        let index_result = vamana_index
+            .search(&[&query], None, top, None, &Default::default())
+            .unwrap();
+        assert_eq!(index_result[0].len(), top);
+        assert_eq!(
+            vamana_index
+                .get_telemetry_data(TelemetryDetail::default())
+                .unfiltered_hnsw
+                .count,
+            graph_searches + i + 1
+        );
+
+        let plain_result = plain_search(&query, None);
+        if index_result[0][0] == plain_result[0][0] {
+            hits += 1;
+        }
+    }
+    assert!(
+        attempts - hits <= max_failures,
+        "recommend hits: {hits}/{attempts} (expected less than {max_failures} failures)"
+    );
+}
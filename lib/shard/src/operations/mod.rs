@@ -42,7 +42,10 @@ impl CollectionUpdateOperations {
     pub fn is_delete_points(&self) -> bool {
         matches!(
             self,
-            Self::PointOperation(point_ops::PointOperations::DeletePoints { .. })
+            Self::PointOperation(
+                point_ops::PointOperations::DeletePoints { .. }
+                    | point_ops::PointOperations::DeletePointsConditional(_)
+            )
         )
     }
 
@@ -66,6 +69,7 @@ impl CollectionUpdateOperations {
                 PointOperations::UpsertPoints(op) => Some(op.point_ids()),
                 PointOperations::UpsertPointsConditional(op) => Some(op.points_op.point_ids()),
                 PointOperations::DeletePoints { .. } => None,
+                PointOperations::DeletePointsConditional(_) => None,
                 PointOperations::DeletePointsByFilter(_) => None,
                 PointOperations::SyncPoints(op) => {
                     Some(op.points.iter().map(|point| point.id).collect())
@@ -352,6 +356,7 @@ mod tests {
                     points: Vec::new(),
                     #[cfg(feature = "api")]
                     shard_key: None,
+                    if_version: None,
                 },
                 Vec::new(),
             );
@@ -380,6 +385,7 @@ mod tests {
                 points: None,
                 filter: None,
                 key: None,
+                if_version: None,
             });
 
             let overwrite = Self::OverwritePayload(SetPayloadOp {
@@ -387,6 +393,7 @@ mod tests {
                 points: None,
                 filter: None,
                 key: None,
+                if_version: None,
             });
 
             let delete = Self::DeletePayload(DeletePayloadOp {
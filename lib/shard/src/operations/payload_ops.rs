@@ -3,7 +3,7 @@ use std::fmt;
 #[cfg(feature = "api")]
 use schemars::JsonSchema;
 use segment::json_path::JsonPath;
-use segment::types::{Filter, Payload, PayloadKeyType, PointIdType};
+use segment::types::{ExpectedPointVersion, Filter, Payload, PayloadKeyType, PointIdType};
 use serde::{self, Deserialize, Serialize};
 use strum::{EnumDiscriminants, EnumIter};
 #[cfg(feature = "api")]
@@ -42,11 +42,11 @@ impl PayloadOps {
         F: Fn(&PointIdType) -> bool,
     {
         match self {
-            Self::SetPayload(op) => retain_opt(op.points.as_mut(), filter),
+            Self::SetPayload(op) => op.retain_point_ids(filter),
             Self::DeletePayload(op) => retain_opt(op.points.as_mut(), filter),
             Self::ClearPayload { points } => points.retain(filter),
             Self::ClearPayloadByFilter(_) => (),
-            Self::OverwritePayload(op) => retain_opt(op.points.as_mut(), filter),
+            Self::OverwritePayload(op) => op.retain_point_ids(filter),
         }
     }
 }
@@ -75,6 +75,11 @@ pub struct SetPayload {
     pub shard_key: Option<api::rest::ShardKeySelector>,
     /// Assigns payload to each point that satisfy this path of property
     pub key: Option<JsonPath>,
+    /// Versions, which points are expected to have.
+    /// If any of them differs, the shard, which holds the point, rejects the payload update.
+    /// Other shards are updated independently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_version: Option<Vec<ExpectedPointVersion>>,
 }
 
 /// This data structure is used inside shard operations queue
@@ -91,6 +96,21 @@ pub struct SetPayloadOp {
     pub filter: Option<Filter>,
     /// Payload selector to indicate property of payload, e.g. `a.b.c`
     pub key: Option<JsonPath>,
+    /// Versions, which points are expected to have before the payload is assigned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_version: Option<Vec<ExpectedPointVersion>>,
+}
+
+impl SetPayloadOp {
+    pub fn retain_point_ids<F>(&mut self, filter: F)
+    where
+        F: Fn(&PointIdType) -> bool,
+    {
+        if let Some(if_version) = &mut self.if_version {
+            if_version.retain(|expected| filter(&expected.id));
+        }
+        retain_opt(self.points.as_mut(), filter);
+    }
 }
 
 /// This data structure is used in API interface and applied across multiple shards
@@ -132,6 +152,8 @@ struct SetPayloadShadow {
     pub filter: Option<Filter>,
     pub shard_key: Option<api::rest::ShardKeySelector>,
     pub key: Option<JsonPath>,
+    #[serde(default)]
+    pub if_version: Option<Vec<ExpectedPointVersion>>,
 }
 
 #[cfg(feature = "api")]
@@ -145,6 +167,7 @@ impl TryFrom<SetPayloadShadow> for SetPayload {
             filter,
             shard_key,
             key,
+            if_version,
         } = value;
 
         if points.is_some() || filter.is_some() {
@@ -154,6 +177,7 @@ impl TryFrom<SetPayloadShadow> for SetPayload {
                 filter,
                 shard_key,
                 key,
+                if_version,
             })
         } else {
             Err(PointsSelectorValidationError)
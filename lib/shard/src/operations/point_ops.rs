@@ -15,7 +15,9 @@ use segment::data_types::vectors::{
     BatchVectorStructInternal, DEFAULT_VECTOR_NAME, DenseVector, MultiDenseVector,
     MultiDenseVectorInternal, VectorInternal, VectorStructInternal,
 };
-use segment::types::{Filter, Payload, PointIdType, VectorNameBuf};
+use segment::types::{
+    ExpectedPointVersion, Filter, Payload, PointIdType, SeqNumberType, VectorNameBuf,
+};
 use serde::{Deserialize, Serialize};
 use sparse::common::types::{DimId, DimWeight};
 use strum::{EnumDiscriminants, EnumIter};
@@ -45,6 +47,11 @@ pub struct PointIdsList {
     #[cfg(feature = "api")]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shard_key: Option<api::rest::ShardKeySelector>,
+    /// Versions, which points are expected to have.
+    /// If any of the points has a different version, the shard, which holds it,
+    /// rejects the deletion. Other shards are updated independently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_version: Option<Vec<ExpectedPointVersion>>,
 }
 
 impl From<Vec<PointIdType>> for PointIdsList {
@@ -53,6 +60,7 @@ impl From<Vec<PointIdType>> for PointIdsList {
             points,
             #[cfg(feature = "api")]
             shard_key: None,
+            if_version: None,
         }
     }
 }
@@ -112,6 +120,8 @@ pub enum PointOperations {
     UpsertPointsConditional(ConditionalInsertOperationInternal),
     /// Delete point if exists
     DeletePoints { ids: Vec<PointIdType> },
+    /// Delete points if they have the expected versions
+    DeletePointsConditional(ConditionalDeleteOperationInternal),
     /// Delete points by given filter criteria
     DeletePointsByFilter(Filter),
     /// Points Sync
@@ -124,6 +134,7 @@ impl PointOperations {
             Self::UpsertPoints(op) => Some(op.point_ids()),
             Self::UpsertPointsConditional(op) => Some(op.points_op.point_ids()),
             Self::DeletePoints { ids } => Some(ids.clone()),
+            Self::DeletePointsConditional(op) => Some(op.ids.clone()),
             Self::DeletePointsByFilter(_) => None,
             Self::SyncPoints(op) => Some(op.points.iter().map(|point| point.id).collect()),
         }
@@ -136,9 +147,13 @@ impl PointOperations {
         match self {
             Self::UpsertPoints(op) => op.retain_point_ids(filter),
             Self::UpsertPointsConditional(op) => {
+                if let Some(if_version) = &mut op.if_version {
+                    if_version.retain(|expected| filter(&expected.id));
+                }
                 op.points_op.retain_point_ids(filter);
             }
             Self::DeletePoints { ids } => ids.retain(filter),
+            Self::DeletePointsConditional(op) => op.retain_point_ids(filter),
             Self::DeletePointsByFilter(_) => (),
             Self::SyncPoints(op) => op.points.retain(|point| filter(&point.id)),
         }
@@ -165,6 +180,17 @@ impl PointInsertOperationsInternal {
         }
     }
 
+    /// Payloads of the inserted points, in the same order as [`Self::point_ids`]
+    pub fn payloads(&self) -> Vec<Option<&Payload>> {
+        match self {
+            Self::PointsBatch(batch) => match &batch.payloads {
+                Some(payloads) => payloads.iter().map(Option::as_ref).collect(),
+                None => vec![None; batch.ids.len()],
+            },
+            Self::PointsList(points) => points.iter().map(|point| point.payload.as_ref()).collect(),
+        }
+    }
+
     pub fn into_point_vec(self) -> Vec<PointStructPersisted> {
         match self {
             PointInsertOperationsInternal::PointsBatch(batch) => {
@@ -177,6 +203,7 @@ impl PointInsertOperationsInternal {
                             id,
                             vector: VectorStructInternal::from(vectors).into(),
                             payload: None,
+                            version: None,
                         })
                         .collect(),
                     Some(payloads) => vectors_iter
@@ -185,6 +212,7 @@ impl PointInsertOperationsInternal {
                             id,
                             vector: VectorStructInternal::from(vectors).into(),
                             payload,
+                            version: None,
                         })
                         .collect(),
                 }
@@ -256,6 +284,31 @@ pub struct ConditionalInsertOperationInternal {
     /// Mode of the upsert operation. If None, defaults to Upsert behavior.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_mode: Option<UpdateMode>,
+    /// Versions, which points are expected to have.
+    /// If any of them differs, the shard, which holds the point, rejects the upsert.
+    /// Other shards are updated independently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub if_version: Option<Vec<ExpectedPointVersion>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Hash)]
+pub struct ConditionalDeleteOperationInternal {
+    /// Points to delete
+    pub ids: Vec<PointIdType>,
+    /// Versions, which points are expected to have.
+    /// If any of them differs, the shard, which holds the point, rejects the deletion.
+    /// Other shards are updated independently.
+    pub if_version: Vec<ExpectedPointVersion>,
+}
+
+impl ConditionalDeleteOperationInternal {
+    pub fn retain_point_ids<F>(&mut self, filter: F)
+    where
+        F: Fn(&PointIdType) -> bool,
+    {
+        self.ids.retain(&filter);
+        self.if_version.retain(|expected| filter(&expected.id));
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Hash)]
@@ -375,6 +428,9 @@ pub struct PointStructPersisted {
     pub vector: VectorStructPersisted,
     /// Payload values (optional)
     pub payload: Option<Payload>,
+    /// Number of writes made to the point, only set if the point is copied from another replica
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<SeqNumberType>,
 }
 
 impl PointStructPersisted {
@@ -398,6 +454,22 @@ impl PointStructPersisted {
         named_vectors
     }
 
+    /// Set versions of points copied from another replica.
+    pub fn set_versions(points: &mut [Self], versions: &[ExpectedPointVersion]) {
+        if versions.is_empty() {
+            return;
+        }
+
+        let versions: HashMap<_, _> = versions
+            .iter()
+            .map(|&ExpectedPointVersion { id, version }| (id, version))
+            .collect();
+
+        for point in points {
+            point.version = versions.get(&point.id).copied();
+        }
+    }
+
     pub fn is_equal_to(&self, segment_record: &SegmentRecord) -> bool {
         let SegmentRecord {
             id,
@@ -442,6 +514,8 @@ impl TryFrom<api::rest::schema::Record> for PointStructPersisted {
             vector,
             shard_key: _,
             order_value: _,
+            then_by_values: _,
+            version,
         } = record;
 
         if vector.is_none() {
@@ -452,6 +526,7 @@ impl TryFrom<api::rest::schema::Record> for PointStructPersisted {
             id,
             payload,
             vector: VectorStructPersisted::from(vector.unwrap()),
+            version,
         })
     }
 }
@@ -465,6 +540,7 @@ impl TryFrom<PointStructPersisted> for api::grpc::qdrant::PointStruct {
             id,
             vector,
             payload,
+            version: _,
         } = value;
 
         let vectors_internal = VectorStructInternal::try_from(vector).map_err(|e| {
@@ -5,9 +5,9 @@ use segment::common::BYTES_IN_KB;
 use segment::data_types::modifier::Modifier;
 use segment::index::sparse_index::sparse_index_config::{SparseIndexConfig, SparseIndexType};
 use segment::types::{
-    Distance, HnswConfig, Indexes, MultiVectorConfig, PayloadStorageType, QuantizationConfig,
-    SegmentConfig, SparseVectorDataConfig, SparseVectorStorageType, VectorDataConfig,
-    VectorNameBuf, VectorStorageDatatype, VectorStorageType,
+    Distance, HnswConfig, Indexes, IvfConfig, MultiVectorConfig, PayloadStorageType,
+    QuantizationConfig, SegmentConfig, SparseVectorDataConfig, SparseVectorStorageType,
+    VamanaConfig, VectorDataConfig, VectorNameBuf, VectorStorageDatatype, VectorStorageType,
 };
 
 pub const TEMP_SEGMENTS_PATH: &str = "temp_segments";
@@ -21,9 +21,34 @@ pub const DEFAULT_VACUUM_MIN_VECTOR_NUMBER: usize = 1000;
 pub struct DenseVectorOptimizerConfig {
     pub on_disk: Option<bool>,
     pub hnsw_config: HnswConfig,
+    /// If set, the disk-resident Vamana index is built instead of HNSW
+    pub vamana_config: Option<VamanaConfig>,
+    /// If set, the IVF index is built instead of HNSW
+    pub ivf_config: Option<IvfConfig>,
     pub quantization_config: Option<QuantizationConfig>,
 }
 
+impl DenseVectorOptimizerConfig {
+    /// Index to build for optimized segments
+    pub fn index(&self) -> Indexes {
+        match (self.vamana_config, self.ivf_config) {
+            (Some(vamana_config), _) => Indexes::Vamana(vamana_config),
+            (None, Some(ivf_config)) => Indexes::Ivf(ivf_config),
+            (None, None) => Indexes::Hnsw(self.hnsw_config),
+        }
+    }
+
+    /// Configured number of threads to build the index with, 0 means auto
+    pub fn max_indexing_threads(&self) -> usize {
+        match self.index() {
+            Indexes::Plain {} => 0,
+            Indexes::Hnsw(hnsw_config) => hnsw_config.max_indexing_threads,
+            Indexes::Vamana(vamana_config) => vamana_config.max_indexing_threads,
+            Indexes::Ivf(ivf_config) => ivf_config.max_indexing_threads,
+        }
+    }
+}
+
 /// Extra configuration for sparse vectors, applied on top of the plain config during optimization.
 #[derive(Debug, Clone, PartialEq)]
 pub struct SparseVectorOptimizerConfig {
@@ -67,9 +92,12 @@ impl SegmentOptimizerConfig {
                 distance,
                 on_disk,
                 hnsw_config,
+                vamana_config,
+                ivf_config,
                 quantization_config,
                 multivector_config,
                 datatype,
+                matryoshka_dim,
             } = input;
             plain_dense_vector_config.insert(
                 name.clone(),
@@ -83,6 +111,7 @@ impl SegmentOptimizerConfig {
                     ),
                     multivector_config,
                     datatype,
+                    matryoshka_dim,
                 },
             );
             dense_vector.insert(
@@ -90,6 +119,8 @@ impl SegmentOptimizerConfig {
                 DenseVectorOptimizerConfig {
                     on_disk,
                     hnsw_config,
+                    vamana_config,
+                    ivf_config,
                     quantization_config,
                 },
             );
@@ -136,9 +167,12 @@ pub struct DenseVectorOptimizerInput {
     pub distance: Distance,
     pub on_disk: Option<bool>,
     pub hnsw_config: HnswConfig,
+    pub vamana_config: Option<VamanaConfig>,
+    pub ivf_config: Option<IvfConfig>,
     pub quantization_config: Option<QuantizationConfig>,
     pub multivector_config: Option<MultiVectorConfig>,
     pub datatype: Option<VectorStorageDatatype>,
+    pub matryoshka_dim: Option<usize>,
 }
 
 /// Per-sparse-vector input for the optimizer builder.
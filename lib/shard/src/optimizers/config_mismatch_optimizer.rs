@@ -83,21 +83,35 @@ impl ConfigMismatchOptimizer {
                 .vector_data
                 .iter()
                 .any(|(vector_name, vector_data)| {
-                    // Check HNSW mismatch
-                    match &vector_data.index {
-                        Indexes::Plain {} => {}
-                        Indexes::Hnsw(effective_hnsw) => {
-                            // Select segment if we have an HNSW mismatch that requires rebuild
-                            let target_hnsw = self
-                                .segment_optimizer_config
-                                .dense_vector
-                                .get(vector_name)
-                                .map(|cfg| cfg.hnsw_config)
-                                .unwrap_or(self.global_hnsw_config);
-                            if effective_hnsw.mismatch_requires_rebuild(&target_hnsw) {
-                                return true;
-                            }
+                    // Check index type and parameters mismatch
+                    let target_index = self
+                        .segment_optimizer_config
+                        .dense_vector
+                        .get(vector_name)
+                        .map_or(Indexes::Hnsw(self.global_hnsw_config), |cfg| cfg.index());
+                    let index_requires_rebuild = match (&vector_data.index, target_index) {
+                        (Indexes::Plain {}, _) => false,
+                        // Select segment if we have an index mismatch that requires rebuild
+                        (Indexes::Hnsw(effective_hnsw), Indexes::Hnsw(target_hnsw)) => {
+                            effective_hnsw.mismatch_requires_rebuild(&target_hnsw)
+                        }
+                        (Indexes::Vamana(effective_vamana), Indexes::Vamana(target_vamana)) => {
+                            effective_vamana.mismatch_requires_rebuild(&target_vamana)
                         }
+                        (Indexes::Ivf(effective_ivf), Indexes::Ivf(target_ivf)) => {
+                            effective_ivf.mismatch_requires_rebuild(&target_ivf)
+                        }
+                        // Select segment if another index type is requested
+                        (
+                            Indexes::Hnsw(_) | Indexes::Vamana(_) | Indexes::Ivf(_),
+                            Indexes::Plain {}
+                            | Indexes::Hnsw(_)
+                            | Indexes::Vamana(_)
+                            | Indexes::Ivf(_),
+                        ) => true,
+                    };
+                    if index_requires_rebuild {
+                        return true;
                     }
 
                     if !vector_data.storage_type.is_empty()
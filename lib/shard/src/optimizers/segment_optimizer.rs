@@ -16,7 +16,7 @@ use segment::index::sparse_index::sparse_index_config::SparseIndexType;
 use segment::segment::Segment;
 use segment::segment_constructor::build_segment;
 use segment::segment_constructor::segment_builder::SegmentBuilder;
-use segment::types::{HnswGlobalConfig, Indexes, VectorStorageType};
+use segment::types::{HnswGlobalConfig, VectorStorageType};
 use uuid::Uuid;
 
 use super::config::SegmentOptimizerConfig;
@@ -28,12 +28,12 @@ use crate::segment_holder::{SegmentHolder, SegmentId};
 
 const BYTES_IN_KB: usize = 1024;
 
-/// Resolves per-vector HNSW max_indexing_threads (0 = auto) and returns the actual thread count.
+/// Resolves per-vector index max_indexing_threads (0 = auto) and returns the actual thread count.
 pub fn max_num_indexing_threads(segment_optimizer_config: &SegmentOptimizerConfig) -> usize {
     let segment_resolution = segment_optimizer_config
         .dense_vector
         .values()
-        .map(|cfg| get_num_indexing_threads(cfg.hnsw_config.max_indexing_threads))
+        .map(|cfg| get_num_indexing_threads(cfg.max_indexing_threads()))
         .max();
     if let Some(segment_resolution) = segment_resolution {
         segment_resolution
@@ -206,12 +206,12 @@ pub trait SegmentOptimizer: Sync {
         let mut vector_data = segment_optimizer_config.plain_dense_vector_config.clone();
         let mut sparse_vector_data = segment_optimizer_config.plain_sparse_vector_config.clone();
 
-        // If indexing, change to HNSW index and quantization
+        // If indexing, change to the configured index and quantization
         if threshold_is_indexed {
             vector_data.iter_mut().for_each(|(vector_name, config)| {
                 if let Some(vector_cfg) = segment_optimizer_config.dense_vector.get(vector_name) {
-                    // Assign HNSW index
-                    config.index = Indexes::Hnsw(vector_cfg.hnsw_config);
+                    // Assign HNSW, Vamana or IVF index
+                    config.index = vector_cfg.index();
                     // Assign quantization config
                     config.quantization_config = vector_cfg.quantization_config.clone();
                 }
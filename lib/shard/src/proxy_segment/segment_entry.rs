@@ -10,9 +10,10 @@ use common::types::{DeferredBehavior, TelemetryDetail};
 use segment::common::Flusher;
 use segment::common::operation_error::{OperationError, OperationResult, SegmentFailedState};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
+use segment::data_types::explain::SegmentExplanation;
 use segment::data_types::facets::{FacetParams, FacetValue};
 use segment::data_types::named_vectors::NamedVectors;
-use segment::data_types::order_by::OrderValue;
+use segment::data_types::order_by::OrderedPoint;
 use segment::data_types::query_context::{FormulaContext, QueryContext, SegmentQueryContext};
 use segment::data_types::segment_record::SegmentRecord;
 use segment::data_types::vector_name_config::VectorNameConfig;
@@ -56,6 +57,15 @@ impl ReadSegmentEntry for ProxySegment {
         Some(wrapped_version)
     }
 
+    fn point_write_count(&self, point_id: PointIdType) -> Option<SeqNumberType> {
+        // Points marked for deletion are absent, same as in `point_version`
+        self.point_version(point_id)?;
+        self.wrapped_segment
+            .get()
+            .read()
+            .point_write_count(point_id)
+    }
+
     fn search_batch(
         &self,
         vector_name: &VectorName,
@@ -311,7 +321,7 @@ impl ReadSegmentEntry for ProxySegment {
         is_stopped: &AtomicBool,
         hw_counter: &HardwareCounterCell,
         deferred_behavior: DeferredBehavior,
-    ) -> OperationResult<Vec<(OrderValue, PointIdType)>> {
+    ) -> OperationResult<Vec<OrderedPoint>> {
         let filter = filter.map(|f| self.changed_vector_names.redact_filter(f));
 
         let read_points = if self.deleted_points.is_empty() {
@@ -557,6 +567,56 @@ impl ReadSegmentEntry for ProxySegment {
         })
     }
 
+    /// Explanation of the wrapped segment.
+    ///
+    /// Actual cardinality may include points, which were deleted after the proxy was created.
+    fn explain_filter(
+        &self,
+        filter: Option<&Filter>,
+        is_stopped: &AtomicBool,
+        hw_counter: &HardwareCounterCell,
+    ) -> OperationResult<SegmentExplanation> {
+        let filter = filter.map(|f| self.changed_vector_names.redact_filter(f));
+        self.wrapped_segment
+            .get()
+            .read()
+            .explain_filter(filter.as_deref(), is_stopped, hw_counter)
+    }
+
+    /// Explanation of the wrapped segment.
+    ///
+    /// Actual cardinality may include points, which were deleted after the proxy was created.
+    fn explain_search(
+        &self,
+        vector_name: &VectorName,
+        query_vector: &QueryVector,
+        filter: Option<&Filter>,
+        params: Option<&SearchParams>,
+        query_context: &SegmentQueryContext,
+    ) -> OperationResult<SegmentExplanation> {
+        let filter = filter.map(|f| self.changed_vector_names.redact_filter(f));
+        let wrapped_segment = self.wrapped_segment.get();
+        let wrapped_segment_guard = wrapped_segment.read();
+
+        // Stale vector is not searched at all, see `search_batch`
+        if self.changed_vector_names.is_wrapped_data_stale(vector_name) {
+            let vector_query_context = query_context.get_vector_context(vector_name);
+            return wrapped_segment_guard.explain_filter(
+                filter.as_deref(),
+                &vector_query_context.is_stopped(),
+                &vector_query_context.hardware_counter(),
+            );
+        }
+
+        wrapped_segment_guard.explain_search(
+            vector_name,
+            query_vector,
+            filter.as_deref(),
+            params,
+            query_context,
+        )
+    }
+
     fn segment_uuid(&self) -> Uuid {
         self.wrapped_segment.get().read().segment_uuid()
     }
@@ -826,6 +886,17 @@ impl SegmentEntry for ProxySegment {
             "Clear payload is disabled for proxy segments: operation {op_num} on point {point_id}",
         )))
     }
+
+    fn set_point_write_count(
+        &mut self,
+        op_num: SeqNumberType,
+        point_id: PointIdType,
+        _write_count: SeqNumberType,
+    ) -> OperationResult<bool> {
+        Err(OperationError::service_error(format!(
+            "Set point write count is disabled for proxy segments: operation {op_num} on point {point_id}",
+        )))
+    }
 }
 
 impl NonAppendableSegmentEntry for ProxySegment {
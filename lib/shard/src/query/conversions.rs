@@ -1,3 +1,4 @@
+use api::conversions::json::json_path_from_proto;
 use api::{grpc, rest};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
@@ -18,7 +19,8 @@ use segment::vector_storage::query::{
 use crate::query::formula::*;
 use crate::query::query_enum::*;
 use crate::query::{
-    FusionInternal, MmrInternal, SampleInternal, ScoringQuery, ShardPrefetch, ShardQueryRequest,
+    FusionInternal, MmrInternal, SampleInternal, ScorePosition, ScoringQuery, ShardPrefetch,
+    ShardQueryRequest, StratifiedSample, WeightedSample,
 };
 
 impl From<rest::schema::SearchRequestInternal> for ShardQueryRequest {
@@ -43,6 +45,7 @@ impl From<rest::schema::SearchRequestInternal> for ShardQueryRequest {
             score_threshold: score_threshold.map(OrderedFloat),
             limit,
             offset: offset.unwrap_or_default(),
+            start_after: None,
             params,
             with_vector: with_vector.unwrap_or_default(),
             with_payload: with_payload.unwrap_or_default(),
@@ -65,6 +68,7 @@ impl TryFrom<grpc::QueryShardPoints> for ShardQueryRequest {
             offset,
             with_payload,
             with_vectors,
+            start_after,
         } = value;
 
         let request = Self {
@@ -79,7 +83,8 @@ impl TryFrom<grpc::QueryShardPoints> for ShardQueryRequest {
             score_threshold: score_threshold.map(OrderedFloat),
             limit: limit as usize,
             offset: offset as usize,
-            params: params.map(SearchParams::from),
+            start_after: start_after.map(ScorePosition::try_from).transpose()?,
+            params: params.map(SearchParams::try_from).transpose()?,
             with_vector: with_vectors
                 .map(WithVector::from)
                 .unwrap_or(WithVector::Bool(false)),
@@ -102,6 +107,7 @@ impl From<ShardQueryRequest> for grpc::QueryShardPoints {
             score_threshold,
             limit,
             offset,
+            start_after,
             params,
             with_vector,
             with_payload,
@@ -123,10 +129,36 @@ impl From<ShardQueryRequest> for grpc::QueryShardPoints {
             offset: offset as u64,
             with_payload: Some(grpc::WithPayloadSelector::from(with_payload)),
             with_vectors: Some(grpc::WithVectorsSelector::from(with_vector)),
+            start_after: start_after.map(grpc::query_shard_points::ScorePosition::from),
         }
     }
 }
 
+impl From<ScorePosition> for grpc::query_shard_points::ScorePosition {
+    fn from(value: ScorePosition) -> Self {
+        let ScorePosition { score, id } = value;
+        Self {
+            score: score.into_inner(),
+            id: Some(grpc::PointId::from(id)),
+        }
+    }
+}
+
+impl TryFrom<grpc::query_shard_points::ScorePosition> for ScorePosition {
+    type Error = tonic::Status;
+
+    fn try_from(value: grpc::query_shard_points::ScorePosition) -> Result<Self, Self::Error> {
+        let grpc::query_shard_points::ScorePosition { score, id } = value;
+        let id = id
+            .ok_or_else(|| tonic::Status::invalid_argument("Score position without point id"))?
+            .try_into()?;
+        Ok(Self {
+            score: OrderedFloat(score),
+            id,
+        })
+    }
+}
+
 impl From<ShardPrefetch> for grpc::query_shard_points::Prefetch {
     fn from(value: ShardPrefetch) -> Self {
         let ShardPrefetch {
@@ -174,7 +206,7 @@ impl TryFrom<grpc::query_shard_points::Prefetch> for ShardPrefetch {
                 .map(|query| ScoringQuery::try_from_grpc_query(query, using))
                 .transpose()?,
             limit: limit as usize,
-            params: params.map(SearchParams::from),
+            params: params.map(SearchParams::try_from).transpose()?,
             filter: filter.map(Filter::try_from).transpose()?,
             score_threshold: score_threshold.map(OrderedFloat),
         };
@@ -205,6 +237,15 @@ impl From<rest::Rrf> for FusionInternal {
     }
 }
 
+impl From<rest::FederatedMerge> for FusionInternal {
+    fn from(value: rest::FederatedMerge) -> Self {
+        match value {
+            rest::FederatedMerge::Fusion(rest::FusionQuery { fusion }) => fusion.into(),
+            rest::FederatedMerge::Rrf(rest::RrfQuery { rrf }) => rrf.into(),
+        }
+    }
+}
+
 impl From<grpc::Fusion> for FusionInternal {
     fn from(fusion: grpc::Fusion) -> Self {
         match fusion {
@@ -234,6 +275,23 @@ impl TryFrom<grpc::Rrf> for FusionInternal {
     }
 }
 
+impl TryFrom<grpc::FederatedMerge> for FusionInternal {
+    type Error = tonic::Status;
+
+    fn try_from(merge: grpc::FederatedMerge) -> Result<Self, Self::Error> {
+        let grpc::FederatedMerge { variant } = merge;
+        match variant {
+            Some(grpc::federated_merge::Variant::Fusion(fusion)) => {
+                FusionInternal::try_from(fusion)
+            }
+            Some(grpc::federated_merge::Variant::Rrf(rrf)) => FusionInternal::try_from(rrf),
+            None => Err(tonic::Status::invalid_argument(
+                "FederatedMerge variant is missing",
+            )),
+        }
+    }
+}
+
 impl TryFrom<i32> for FusionInternal {
     type Error = tonic::Status;
 
@@ -301,6 +359,54 @@ impl From<rest::Sample> for SampleInternal {
     fn from(value: rest::Sample) -> Self {
         match value {
             rest::Sample::Random => SampleInternal::Random,
+            rest::Sample::Stratified(rest::StratifiedSample { key, per_value }) => {
+                SampleInternal::Stratified(StratifiedSample { key, per_value })
+            }
+            rest::Sample::Weighted(rest::WeightedSample { key }) => {
+                SampleInternal::Weighted(WeightedSample { key })
+            }
+        }
+    }
+}
+
+impl TryFrom<grpc::StratifiedSample> for StratifiedSample {
+    type Error = tonic::Status;
+
+    fn try_from(value: grpc::StratifiedSample) -> Result<Self, Self::Error> {
+        let grpc::StratifiedSample { key, per_value } = value;
+        Ok(StratifiedSample {
+            key: json_path_from_proto(&key)?,
+            per_value: per_value as usize,
+        })
+    }
+}
+
+impl From<StratifiedSample> for grpc::StratifiedSample {
+    fn from(value: StratifiedSample) -> Self {
+        let StratifiedSample { key, per_value } = value;
+        grpc::StratifiedSample {
+            key: key.to_string(),
+            per_value: per_value as u64,
+        }
+    }
+}
+
+impl TryFrom<grpc::WeightedSample> for WeightedSample {
+    type Error = tonic::Status;
+
+    fn try_from(value: grpc::WeightedSample) -> Result<Self, Self::Error> {
+        let grpc::WeightedSample { key } = value;
+        Ok(WeightedSample {
+            key: json_path_from_proto(&key)?,
+        })
+    }
+}
+
+impl From<WeightedSample> for grpc::WeightedSample {
+    fn from(value: WeightedSample) -> Self {
+        let WeightedSample { key } = value;
+        grpc::WeightedSample {
+            key: key.to_string(),
         }
     }
 }
@@ -325,14 +431,6 @@ impl TryFrom<i32> for SampleInternal {
     }
 }
 
-impl From<SampleInternal> for grpc::Sample {
-    fn from(value: SampleInternal) -> Self {
-        match value {
-            SampleInternal::Random => grpc::Sample::Random,
-        }
-    }
-}
-
 impl ScoringQuery {
     fn try_from_grpc_query(
         query: grpc::query_shard_points::Query,
@@ -357,6 +455,16 @@ impl ScoringQuery {
             grpc::query_shard_points::query::Score::Sample(sample) => {
                 ScoringQuery::Sample(SampleInternal::try_from(sample)?)
             }
+            grpc::query_shard_points::query::Score::StratifiedSample(stratified) => {
+                ScoringQuery::Sample(SampleInternal::Stratified(StratifiedSample::try_from(
+                    stratified,
+                )?))
+            }
+            grpc::query_shard_points::query::Score::WeightedSample(weighted) => {
+                ScoringQuery::Sample(SampleInternal::Weighted(WeightedSample::try_from(
+                    weighted,
+                )?))
+            }
             grpc::query_shard_points::query::Score::Formula(formula) => ScoringQuery::Formula(
                 ParsedFormula::try_from(FormulaInternal::try_from(formula)?).map_err(|e| {
                     tonic::Status::invalid_argument(format!("failed to parse formula: {e}"))
@@ -398,8 +506,16 @@ impl From<ScoringQuery> for grpc::query_shard_points::Query {
             ScoringQuery::Formula(parsed_formula) => Self {
                 score: Some(Score::Formula(grpc::Formula::from_parsed(parsed_formula))),
             },
-            ScoringQuery::Sample(sample) => Self {
-                score: Some(Score::Sample(grpc::Sample::from(sample) as i32)),
+            ScoringQuery::Sample(SampleInternal::Random) => Self {
+                score: Some(Score::Sample(grpc::Sample::Random as i32)),
+            },
+            ScoringQuery::Sample(SampleInternal::Stratified(stratified)) => Self {
+                score: Some(Score::StratifiedSample(grpc::StratifiedSample::from(
+                    stratified,
+                ))),
+            },
+            ScoringQuery::Sample(SampleInternal::Weighted(weighted)) => Self {
+                score: Some(Score::WeightedSample(grpc::WeightedSample::from(weighted))),
             },
             ScoringQuery::Mmr(MmrInternal {
                 vector,
@@ -554,6 +670,13 @@ impl QueryEnum {
                         };
                         QueryEnum::FeedbackNaive(named)
                     }
+                    // Resolved into a nearest query on the collection level
+                    grpc::feedback_strategy::Variant::Rocchio(_)
+                    | grpc::feedback_strategy::Variant::Reweight(_) => {
+                        return Err(tonic::Status::invalid_argument(
+                            "Rocchio and reweight feedback strategies are not supported in raw queries",
+                        ));
+                    }
                 }
             }
         };
@@ -35,6 +35,8 @@ fn create_scored_point_with_vector(
         vector: Some(VectorStructInternal::Named(vectors)),
         shard_key: None,
         order_value: None,
+        then_by_values: Vec::new(),
+        stratum: None,
     }
 }
 
@@ -48,6 +50,8 @@ fn create_scored_point_without_vector(id: PointIdType) -> ScoredPoint {
         vector: None,
         shard_key: None,
         order_value: None,
+        then_by_values: Vec::new(),
+        stratum: None,
     }
 }
 
@@ -73,6 +77,8 @@ fn create_scored_point_with_sparse_vector(
         vector: Some(VectorStructInternal::Named(vectors)),
         shard_key: None,
         order_value: None,
+        then_by_values: Vec::new(),
+        stratum: None,
     }
 }
 
@@ -97,6 +103,8 @@ fn create_scored_point_with_multi_vector(
         vector: Some(VectorStructInternal::Named(vector_map)),
         shard_key: None,
         order_value: None,
+        then_by_values: Vec::new(),
+        stratum: None,
     }
 }
 
@@ -348,6 +356,7 @@ fn test_mmr_multi_vector() {
     // Test multi-vectors with all supported distance metrics
     let multi_vector_config = MultiVectorConfig {
         comparator: MultiVectorComparator::MaxSim,
+        fde: None,
     };
 
     let multi_vector_name = "multi";
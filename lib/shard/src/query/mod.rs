@@ -4,6 +4,7 @@ pub mod formula;
 pub mod mmr;
 pub mod planned_query;
 pub mod query_enum;
+pub mod sample;
 pub mod scroll;
 mod validation;
 
@@ -16,8 +17,9 @@ use ordered_float::OrderedFloat;
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::VectorInternal;
 use segment::index::query_optimization::rescore_formula::parsed_formula::ParsedFormula;
+use segment::json_path::JsonPath;
 use segment::types::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use self::query_enum::*;
 use crate::search::CoreSearchRequest;
@@ -43,6 +45,12 @@ pub struct ShardQueryRequest {
     pub score_threshold: Option<OrderedFloat<ScoreType>>,
     pub limit: usize,
     pub offset: usize,
+    /// Return only points after this position in results ordered by score.
+    ///
+    /// Searches skip points scored better than the position, and scrolls by ID start from it,
+    /// so the request doesn't search as deep as all previous pages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_after: Option<ScorePosition>,
     /// Search params for when there is no prefetch
     #[serde(skip_serializing_if = "Option::is_none")]
     pub params: Option<SearchParams>,
@@ -71,6 +79,28 @@ impl ShardQueryRequest {
     }
 }
 
+/// Score and ID of the last point of a page.
+///
+/// Points with equal scores are ordered by their IDs, in the same direction as scores.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ScorePosition {
+    pub score: OrderedFloat<ScoreType>,
+    pub id: PointIdType,
+}
+
+impl ScorePosition {
+    /// Whether the point goes after this position in results of the given order
+    pub fn is_before(&self, point: &ScoredPoint, order: Order) -> bool {
+        let ordering = OrderedFloat(point.score)
+            .cmp(&self.score)
+            .then_with(|| point.id.cmp(&self.id));
+        match order {
+            Order::LargeBetter => ordering.is_lt(),
+            Order::SmallBetter => ordering.is_gt(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Hash, Serialize)]
 pub struct ShardPrefetch {
     pub prefetches: Vec<ShardPrefetch>,
@@ -163,9 +193,27 @@ pub enum FusionInternal {
     Dbsf,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
 pub enum SampleInternal {
     Random,
+    Stratified(StratifiedSample),
+    Weighted(WeightedSample),
+}
+
+/// Sample up to `per_value` random points for each distinct value of a payload field
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+pub struct StratifiedSample {
+    /// Payload field to stratify by
+    pub key: JsonPath,
+    /// Number of points to sample for each distinct value
+    pub per_value: usize,
+}
+
+/// Sample points with probability proportional to a numeric payload field
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize)]
+pub struct WeightedSample {
+    /// Payload field holding the weight of the point
+    pub key: JsonPath,
 }
 
 /// Maximal Marginal Relevance configuration
@@ -201,6 +249,7 @@ impl From<CoreSearchRequest> for ShardQueryRequest {
             score_threshold: score_threshold.map(OrderedFloat),
             limit,
             offset,
+            start_after: None,
             params,
             with_vector: with_vector.unwrap_or_default(),
             with_payload: with_payload.unwrap_or_default(),
@@ -2,7 +2,7 @@ use common::types::ScoreType;
 use ordered_float::OrderedFloat;
 use segment::common::operation_error::{OperationError, OperationResult};
 use segment::data_types::vectors::NamedQuery;
-use segment::types::{Filter, SearchParams, WithPayloadInterface, WithVector};
+use segment::types::{Filter, PointIdType, SearchParams, WithPayloadInterface, WithVector};
 
 use super::query_enum::QueryEnum;
 use super::scroll::{QueryScrollRequestInternal, ScrollOrder};
@@ -122,14 +122,34 @@ impl PlannedQuery {
             score_threshold,
             limit,
             offset,
+            start_after,
             with_vector,
             with_payload,
-            params,
+            mut params,
         } = request;
 
         // Adjust limit so that we have enough results when we cut off the offset at a higher level
         let limit = limit + offset;
 
+        // Continue right from the position of the previous page, instead of searching as deep
+        // as all previous pages. Points up to the position are skipped by the shard.
+        let mut scroll_offset_id = None;
+        if let Some(position) = start_after {
+            match &query {
+                Some(ScoringQuery::Vector(_)) => {
+                    params.get_or_insert_default().score_start_after = Some(position.score);
+                }
+                None => scroll_offset_id = Some(position.id),
+                Some(
+                    ScoringQuery::Fusion(_)
+                    | ScoringQuery::OrderBy(_)
+                    | ScoringQuery::Formula(_)
+                    | ScoringQuery::Sample(_)
+                    | ScoringQuery::Mmr(_),
+                ) => {}
+            }
+        }
+
         // Adjust with_vector based on the root query variant
         let with_vector = match &query {
             None
@@ -150,6 +170,7 @@ impl PlannedQuery {
                 with_payload,
                 params,
                 limit,
+                scroll_offset_id,
             )?
         } else {
             self.root_plan_with_prefetches(
@@ -179,6 +200,7 @@ impl PlannedQuery {
         with_payload: WithPayloadInterface,
         params: Option<SearchParams>,
         limit: usize,
+        scroll_offset_id: Option<PointIdType>,
     ) -> OperationResult<RootPlan> {
         let rescore_stages = match &query {
             None => None,
@@ -204,6 +226,7 @@ impl PlannedQuery {
             params,
             score_threshold,
             filter,
+            scroll_offset_id,
         )?];
 
         // Root-level query without prefetches means we won't do any extra rescoring
@@ -332,6 +355,7 @@ fn recurse_prefetches(
                 params,
                 score_threshold.map(OrderedFloat::into_inner),
                 filter,
+                None,
             )?
         } else {
             // This has nested prefetches. Recurse into them
@@ -367,6 +391,7 @@ fn recurse_prefetches(
 /// does not act over prefetched points and will be executed over the segments directly.
 ///
 /// Only `Source::SearchesIdx` or `Source::ScrollsIdx` variants are returned.
+#[expect(clippy::too_many_arguments)]
 fn leaf_source_from_scoring_query(
     core_searches: &mut Vec<CoreSearchRequest>,
     scrolls: &mut Vec<QueryScrollRequestInternal>,
@@ -375,6 +400,7 @@ fn leaf_source_from_scoring_query(
     params: Option<SearchParams>,
     score_threshold: Option<f32>,
     filter: Option<Filter>,
+    scroll_offset_id: Option<PointIdType>,
 ) -> OperationResult<Source> {
     let source = match query {
         Some(ScoringQuery::Vector(query_enum)) => {
@@ -406,6 +432,7 @@ fn leaf_source_from_scoring_query(
                 with_vector: WithVector::from(false),
                 with_payload: WithPayloadInterface::from(false),
                 limit,
+                offset_id: None,
             };
 
             let idx = scrolls.len();
@@ -418,13 +445,14 @@ fn leaf_source_from_scoring_query(
                 "cannot apply Formula without prefetches".to_string(),
             ));
         }
-        Some(ScoringQuery::Sample(SampleInternal::Random)) => {
+        Some(ScoringQuery::Sample(sample)) => {
             let scroll = QueryScrollRequestInternal {
-                scroll_order: ScrollOrder::Random,
+                scroll_order: ScrollOrder::from(sample),
                 filter,
                 with_vector: WithVector::from(false),
                 with_payload: WithPayloadInterface::from(false),
                 limit,
+                offset_id: None,
             };
 
             let idx = scrolls.len();
@@ -463,6 +491,7 @@ fn leaf_source_from_scoring_query(
                 with_vector: WithVector::from(false),
                 with_payload: WithPayloadInterface::from(false),
                 limit,
+                offset_id: scroll_offset_id,
             };
 
             let idx = scrolls.len();
@@ -0,0 +1,365 @@
+use std::collections::HashMap;
+
+use common::types::ScoreType;
+use itertools::{Either, Itertools};
+use ordered_float::OrderedFloat;
+use rand::{Rng, RngExt};
+use segment::data_types::facets::FacetValue;
+use segment::json_path::JsonPath;
+use segment::types::{PointIdType, ScoredPoint};
+use serde_json::Value;
+
+use super::scroll::ScrollOrder;
+use super::{SampleInternal, StratifiedSample, WeightedSample};
+use crate::retrieve::record_internal::RecordInternal;
+
+impl From<SampleInternal> for ScrollOrder {
+    fn from(sample: SampleInternal) -> Self {
+        match sample {
+            SampleInternal::Random => ScrollOrder::Random,
+            SampleInternal::Stratified(stratified) => ScrollOrder::Stratified(stratified),
+            SampleInternal::Weighted(weighted) => ScrollOrder::Weighted(weighted),
+        }
+    }
+}
+
+/// Sampling, which depends on the payload of the points
+#[derive(Debug, Clone, Copy)]
+pub enum PayloadSampling<'a> {
+    Stratified(&'a StratifiedSample),
+    Weighted(&'a WeightedSample),
+}
+
+impl PayloadSampling<'_> {
+    /// Payload field, which the points are sampled by
+    pub fn key(&self) -> &JsonPath {
+        match self {
+            PayloadSampling::Stratified(stratified) => &stratified.key,
+            PayloadSampling::Weighted(weighted) => &weighted.key,
+        }
+    }
+
+    /// Draw up to `limit` points, given the values of the sampled field of all candidates
+    pub fn sample<'a, V: IntoIterator<Item = &'a Value>>(
+        &self,
+        points: impl IntoIterator<Item = (PointIdType, V)>,
+        limit: usize,
+        rng: &mut impl Rng,
+    ) -> Vec<SampledPoint> {
+        match self {
+            PayloadSampling::Stratified(stratified) => stratified.sample(points, limit, rng),
+            PayloadSampling::Weighted(weighted) => weighted.sample(points, limit, rng),
+        }
+    }
+}
+
+/// Point, drawn by a sampling which depends on the payload
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampledPoint {
+    pub id: PointIdType,
+    /// Sampling key, points with larger keys are drawn first
+    pub score: ScoreType,
+    /// Value of the stratification field, which the point was drawn for
+    pub stratum: Option<FacetValue>,
+}
+
+impl SampledPoint {
+    pub fn into_scored_point(self, record: RecordInternal) -> ScoredPoint {
+        ScoredPoint {
+            id: self.id,
+            version: 0,
+            score: self.score,
+            payload: record.payload,
+            vector: record.vector,
+            shard_key: record.shard_key,
+            order_value: None,
+            then_by_values: Vec::new(),
+            // Results of several shards are restricted per value by it
+            stratum: self.stratum,
+        }
+    }
+}
+
+impl StratifiedSample {
+    /// Draw up to `per_value` random points for each distinct value of the field,
+    /// and up to `limit` points in total.
+    ///
+    /// Points with several values may be drawn for any of them, but only once.
+    /// Points without a keyword, integer or bool value are never drawn, same as they have no facet.
+    pub fn sample<'a, V: IntoIterator<Item = &'a Value>>(
+        &self,
+        points: impl IntoIterator<Item = (PointIdType, V)>,
+        limit: usize,
+        rng: &mut impl Rng,
+    ) -> Vec<SampledPoint> {
+        let members = points.into_iter().flat_map(|(id, values)| {
+            flatten_values(values)
+                .filter_map(stratum_of)
+                .unique()
+                .map(move |stratum| (stratum, id))
+        });
+        self.sample_members(members, limit, rng)
+    }
+
+    /// Same as [`Self::sample`], given the values of the field each point is a member of.
+    ///
+    /// Used with the values of the payload index, which only lists the candidates of each value.
+    pub fn sample_members(
+        &self,
+        members: impl IntoIterator<Item = (FacetValue, PointIdType)>,
+        limit: usize,
+        rng: &mut impl Rng,
+    ) -> Vec<SampledPoint> {
+        // A point has the same key in all of its strata, so it is drawn for the first of them
+        let mut keys: HashMap<PointIdType, OrderedFloat<ScoreType>> = HashMap::new();
+        let mut strata: HashMap<FacetValue, Vec<(OrderedFloat<ScoreType>, PointIdType)>> =
+            HashMap::new();
+
+        for (stratum, id) in members {
+            let key = *keys
+                .entry(id)
+                .or_insert_with(|| OrderedFloat(rng.random::<ScoreType>()));
+            strata
+                .entry(normalize_stratum(stratum))
+                .or_default()
+                .push((key, id));
+        }
+
+        strata
+            .into_iter()
+            .flat_map(|(stratum, keys)| {
+                keys.into_iter()
+                    .k_largest(self.per_value)
+                    .map(move |(key, id)| (key, id, stratum.clone()))
+            })
+            .sorted_unstable_by(|a, b| b.cmp(a))
+            .unique_by(|(_, id, _)| *id)
+            .take(limit)
+            .map(|(key, id, stratum)| SampledPoint {
+                id,
+                score: key.into_inner(),
+                stratum: Some(stratum),
+            })
+            .collect()
+    }
+
+    /// Keep up to `per_value` points for each stratum in the results, merged from several shards.
+    ///
+    /// Expects the points to be ordered by score.
+    pub fn restrict_merged(&self, points: Vec<ScoredPoint>) -> Vec<ScoredPoint> {
+        let mut counts: HashMap<Option<FacetValue>, usize> = HashMap::new();
+
+        points
+            .into_iter()
+            .filter_map(|point| {
+                let count = counts.entry(point.stratum.clone()).or_default();
+                *count += 1;
+                (*count <= self.per_value).then_some(point)
+            })
+            .collect()
+    }
+}
+
+impl WeightedSample {
+    /// Draw up to `limit` points without replacement, with probability proportional to the weight.
+    ///
+    /// Uses the keys of Efraimidis and Spirakis: `ln(u) / weight`, so keys of several shards are
+    /// comparable, and the merged top is a weighted sample as well.
+    /// Points without a positive numeric value are never drawn.
+    pub fn sample<'a, V: IntoIterator<Item = &'a Value>>(
+        &self,
+        points: impl IntoIterator<Item = (PointIdType, V)>,
+        limit: usize,
+        rng: &mut impl Rng,
+    ) -> Vec<SampledPoint> {
+        points
+            .into_iter()
+            .filter_map(|(id, values)| {
+                let weight = flatten_values(values)
+                    .find_map(Value::as_f64)
+                    .filter(|weight| weight.is_finite() && *weight > 0.0)?;
+                // Excludes zero, so the logarithm is finite
+                let uniform = 1.0 - rng.random::<f64>();
+                let key = uniform.ln() / weight;
+                Some((OrderedFloat(key as ScoreType), id))
+            })
+            .k_largest(limit)
+            .map(|(key, id)| SampledPoint {
+                id,
+                score: key.into_inner(),
+                stratum: None,
+            })
+            .collect()
+    }
+}
+
+fn flatten_values<'a>(
+    values: impl IntoIterator<Item = &'a Value>,
+) -> impl Iterator<Item = &'a Value> {
+    values.into_iter().flat_map(|value| match value {
+        Value::Array(array) => Either::Left(array.iter()),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Object(_) => {
+            Either::Right(std::iter::once(value))
+        }
+    })
+}
+
+/// Stratum of the value, `None` if the value can't form a stratum
+fn stratum_of(value: &Value) -> Option<FacetValue> {
+    match value {
+        Value::Bool(bool) => Some(FacetValue::Bool(*bool)),
+        Value::Number(number) => number.as_i64().map(FacetValue::Int),
+        Value::String(string) => Some(FacetValue::Keyword(string.clone())),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+/// UUIDs are strata of their string representation, same as they are read from the payload
+/// and as they are sent between the shards
+fn normalize_stratum(stratum: FacetValue) -> FacetValue {
+    match stratum {
+        FacetValue::Uuid(uuid) => FacetValue::Keyword(uuid::Uuid::from_u128(uuid).to_string()),
+        FacetValue::Keyword(_) | FacetValue::Int(_) | FacetValue::Bool(_) => stratum,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use serde_json::json;
+
+    use super::*;
+
+    fn points(values: &[Value]) -> impl Iterator<Item = (PointIdType, Vec<&Value>)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(id, value)| (PointIdType::from(id as u64), vec![value]))
+    }
+
+    fn point_index(id: PointIdType) -> usize {
+        match id {
+            PointIdType::NumId(id) => id as usize,
+            PointIdType::Uuid(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_stratified_sample_per_value() {
+        let values = (0..100)
+            .map(|i| match i % 10 {
+                0 => json!("rare"),
+                1..=3 => json!(["common", 1]),
+                4 => Value::Null,
+                _ => json!("common"),
+            })
+            .collect_vec();
+
+        let sample = StratifiedSample {
+            key: "category".parse().unwrap(),
+            per_value: 3,
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+        let sampled = sample.sample(points(&values), 100, &mut rng);
+
+        let counts = sampled
+            .iter()
+            .counts_by(|point| point.stratum.clone().unwrap());
+        assert_eq!(counts[&FacetValue::Keyword("rare".to_string())], 3);
+        assert!(counts.contains_key(&FacetValue::Int(1)));
+        assert!(counts.values().all(|&count| count <= 3));
+        assert!(sampled.len() >= 6);
+        assert!(sampled.iter().map(|point| point.id).all_unique());
+        assert!(sampled.is_sorted_by(|a, b| a.score >= b.score));
+        // Points without a value are not sampled
+        assert!(
+            sampled
+                .iter()
+                .all(|point| values[point_index(point.id)] != Value::Null)
+        );
+
+        let sampled = sample.sample(points(&values), 4, &mut rng);
+        assert_eq!(sampled.len(), 4);
+    }
+
+    #[test]
+    fn test_restrict_merged_strata() {
+        let sample = StratifiedSample {
+            key: "category".parse().unwrap(),
+            per_value: 1,
+        };
+        let point = |id: u64, score: ScoreType, stratum: &str| ScoredPoint {
+            id: id.into(),
+            version: 0,
+            score,
+            payload: None,
+            vector: None,
+            shard_key: None,
+            order_value: None,
+            then_by_values: Vec::new(),
+            stratum: Some(FacetValue::Keyword(stratum.to_string())),
+        };
+
+        let restricted = sample.restrict_merged(vec![
+            point(1, 0.9, "a"),
+            point(2, 0.8, "b"),
+            point(3, 0.7, "a"),
+            point(4, 0.6, "c"),
+        ]);
+
+        assert_eq!(
+            restricted.iter().map(|point| point.id).collect_vec(),
+            vec![1.into(), 2.into(), 4.into()],
+        );
+        assert!(restricted.iter().all(|point| point.stratum.is_some()));
+    }
+
+    #[test]
+    fn test_stratified_sample_members() {
+        let sample = StratifiedSample {
+            key: "category".parse().unwrap(),
+            per_value: 2,
+        };
+        let uuid = uuid::Uuid::new_v4();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let members = [
+            (FacetValue::Uuid(uuid.as_u128()), 1.into()),
+            (FacetValue::Keyword(uuid.to_string()), 2.into()),
+            (FacetValue::Keyword(uuid.to_string()), 3.into()),
+            (FacetValue::Bool(true), 4.into()),
+        ];
+        let sampled = sample.sample_members(members, 10, &mut rng);
+
+        // UUIDs and their strings form the same stratum
+        let counts = sampled
+            .iter()
+            .counts_by(|point| point.stratum.clone().unwrap());
+        assert_eq!(counts[&FacetValue::Keyword(uuid.to_string())], 2);
+        assert_eq!(counts[&FacetValue::Bool(true)], 1);
+        assert_eq!(sampled.len(), 3);
+    }
+
+    #[test]
+    fn test_weighted_sample_prefers_heavy_points() {
+        let sample = WeightedSample {
+            key: "weight".parse().unwrap(),
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let values = vec![json!(1), json!(1000), json!(0), json!(-5), json!("heavy")];
+
+        let mut heavy_first = 0;
+        for _ in 0..100 {
+            let sampled = sample.sample(points(&values), 2, &mut rng);
+            // Only positive numbers are weights
+            assert_eq!(sampled.len(), 2);
+            assert!(sampled.iter().all(|point| point.score <= 0.0));
+            if sampled[0].id == PointIdType::from(1) {
+                heavy_first += 1;
+            }
+        }
+        assert!(heavy_first > 95);
+    }
+}
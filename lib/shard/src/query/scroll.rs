@@ -1,6 +1,7 @@
 use segment::data_types::order_by::OrderBy;
-use segment::types::{Filter, WithPayloadInterface, WithVector};
+use segment::types::{Filter, PointIdType, WithPayloadInterface, WithVector};
 
+use super::{StratifiedSample, WeightedSample};
 use crate::operation_rate_cost;
 
 /// Scroll request, used as a part of query request
@@ -20,6 +21,9 @@ pub struct QueryScrollRequestInternal {
 
     /// Order the records by a payload field.
     pub scroll_order: ScrollOrder,
+
+    /// Start scrolling by ID from this point, inclusive. Ignored by other orders.
+    pub offset_id: Option<PointIdType>,
 }
 
 impl QueryScrollRequestInternal {
@@ -38,4 +42,6 @@ pub enum ScrollOrder {
     ById,
     ByField(OrderBy),
     Random,
+    Stratified(StratifiedSample),
+    Weighted(WeightedSample),
 }
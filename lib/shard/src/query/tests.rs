@@ -56,6 +56,7 @@ fn test_try_from_double_rescore() {
         score_threshold: None,
         limit: 10,
         offset: 0,
+        start_after: None,
         params: Some(SearchParams {
             exact: true,
             ..Default::default()
@@ -137,6 +138,7 @@ fn test_try_from_no_prefetch() {
         score_threshold: Some(OrderedFloat(0.5)),
         limit: 10,
         offset: 12,
+        start_after: None,
         params: Some(SearchParams::default()),
         with_vector: WithVector::Bool(true),
         with_payload: WithPayloadInterface::Bool(true),
@@ -224,6 +226,7 @@ fn test_try_from_hybrid_query() {
         score_threshold: None,
         limit: 50,
         offset: 0,
+        start_after: None,
         params: None,
         with_payload: WithPayloadInterface::Bool(false),
         with_vector: WithVector::Bool(true),
@@ -296,6 +299,7 @@ fn test_try_from_rrf_without_source() {
         score_threshold: None,
         limit: 50,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(true),
         with_payload: WithPayloadInterface::Bool(false),
@@ -345,6 +349,7 @@ fn test_base_params_mapping_in_try_from() {
         score_threshold: Some(OrderedFloat(0.666)),
         limit: 50,
         offset: 49,
+        start_after: None,
 
         // these params will be ignored because we have a prefetch
         params: top_level_params,
@@ -442,6 +447,7 @@ fn test_detect_max_depth() {
         score_threshold: None,
         limit: 10,
         offset: 0,
+        start_after: None,
         params: None,
         with_vector: WithVector::Bool(true),
         with_payload: WithPayloadInterface::Bool(false),
@@ -540,6 +546,7 @@ fn test_from_batch_of_requests() {
             score_threshold: None,
             limit: 10,
             offset: 0,
+            start_after: None,
             params: None,
             with_payload: WithPayloadInterface::Bool(false),
             with_vector: WithVector::Bool(false),
@@ -552,6 +559,7 @@ fn test_from_batch_of_requests() {
             score_threshold: None,
             limit: 20,
             offset: 0,
+            start_after: None,
             params: None,
             with_payload: WithPayloadInterface::Bool(false),
             with_vector: WithVector::Bool(false),
@@ -580,6 +588,7 @@ fn test_from_batch_of_requests() {
             score_threshold: None,
             limit: 10,
             offset: 0,
+            start_after: None,
             params: None,
             with_payload: WithPayloadInterface::Bool(true),
             with_vector: WithVector::Bool(true),
@@ -650,3 +659,49 @@ fn test_from_batch_of_requests() {
     assert_eq!(planned_query.scrolls[0].limit, 20);
     assert_eq!(planned_query.scrolls[1].limit, 50);
 }
+
+#[test]
+fn test_planned_query_start_after() {
+    let position = ScorePosition {
+        score: OrderedFloat(0.8),
+        id: 10.into(),
+    };
+    let request = ShardQueryRequest {
+        prefetches: vec![],
+        query: Some(ScoringQuery::Vector(QueryEnum::Nearest(
+            NamedQuery::default_dense(vec![1.0, 2.0, 3.0]),
+        ))),
+        filter: None,
+        score_threshold: None,
+        limit: 10,
+        offset: 0,
+        start_after: Some(position),
+        params: None,
+        with_vector: WithVector::Bool(false),
+        with_payload: WithPayloadInterface::Bool(false),
+    };
+
+    // Searches skip points scored better than the position
+    let planned_query = PlannedQuery::try_from(vec![request.clone()]).unwrap();
+    assert_eq!(planned_query.searches[0].limit, 10);
+    assert_eq!(
+        planned_query.searches[0].params.unwrap().score_start_after,
+        Some(position.score),
+    );
+
+    // Scrolls by ID start from the position
+    let planned_query = PlannedQuery::try_from(vec![ShardQueryRequest {
+        query: None,
+        ..request.clone()
+    }])
+    .unwrap();
+    assert_eq!(planned_query.scrolls[0].offset_id, Some(position.id));
+
+    // Requests without a position search from the top
+    let planned_query = PlannedQuery::try_from(vec![ShardQueryRequest {
+        start_after: None,
+        ..request
+    }])
+    .unwrap();
+    assert_eq!(planned_query.searches[0].params, None);
+}
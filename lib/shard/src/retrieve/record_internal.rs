@@ -1,7 +1,7 @@
-use segment::data_types::order_by::OrderValue;
+use segment::data_types::order_by::{OrderKey, OrderValue};
 use segment::data_types::segment_record::SegmentRecord;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, VectorRef, VectorStructInternal};
-use segment::types::{Payload, PointIdType, ShardKey, VectorName};
+use segment::types::{Payload, PointIdType, SeqNumberType, ShardKey, VectorName};
 
 use crate::operations::point_ops::{PointStructPersisted, VectorStructPersisted};
 
@@ -18,6 +18,10 @@ pub struct RecordInternal {
     pub shard_key: Option<ShardKey>,
     /// Order value, if used for order_by
     pub order_value: Option<OrderValue>,
+    /// Order values of `then_by` keys, if used for order_by
+    pub then_by_values: Vec<Option<OrderValue>>,
+    /// Number of writes made to the point, if the collection counts them
+    pub version: Option<SeqNumberType>,
 }
 
 impl RecordInternal {
@@ -28,6 +32,17 @@ impl RecordInternal {
             vector: None,
             shard_key: None,
             order_value: None,
+            then_by_values: Vec::new(),
+            version: None,
+        }
+    }
+
+    /// Values the record is ordered by, if it is a result of `order_by` scroll
+    pub fn order_key(&self) -> OrderKey<'_> {
+        OrderKey {
+            value: self.order_value.as_ref(),
+            then_by: &self.then_by_values,
+            id: self.id,
         }
     }
 
@@ -58,6 +73,8 @@ impl From<SegmentRecord> for RecordInternal {
             vector: vectors.map(VectorStructInternal::from),
             shard_key: None,
             order_value: None,
+            then_by_values: Vec::new(),
+            version: None,
         }
     }
 }
@@ -73,6 +90,8 @@ impl TryFrom<RecordInternal> for PointStructPersisted {
             vector,
             shard_key: _,
             order_value: _,
+            then_by_values: _,
+            version,
         } = record;
 
         if vector.is_none() {
@@ -83,6 +102,8 @@ impl TryFrom<RecordInternal> for PointStructPersisted {
             id,
             payload,
             vector: VectorStructPersisted::from(vector.unwrap()),
+            // Points are copied between replicas this way, so the version must be preserved
+            version,
         })
     }
 }
@@ -91,7 +112,7 @@ impl TryFrom<RecordInternal> for PointStructPersisted {
 impl From<RecordInternal> for api::grpc::qdrant::RetrievedPoint {
     fn from(record: RecordInternal) -> Self {
         use api::conversions::json::payload_to_proto;
-        use api::grpc::conversions::convert_shard_key_to_grpc;
+        use api::grpc::conversions::{convert_shard_key_to_grpc, then_by_values_to_proto};
 
         let RecordInternal {
             id,
@@ -99,6 +120,8 @@ impl From<RecordInternal> for api::grpc::qdrant::RetrievedPoint {
             vector,
             shard_key,
             order_value,
+            then_by_values,
+            version,
         } = record;
         Self {
             id: Some(id.into()),
@@ -106,6 +129,8 @@ impl From<RecordInternal> for api::grpc::qdrant::RetrievedPoint {
             vectors: vector.map(api::grpc::qdrant::VectorsOutput::from),
             shard_key: shard_key.map(convert_shard_key_to_grpc),
             order_value: order_value.map(From::from),
+            version,
+            then_by_values: then_by_values_to_proto(then_by_values),
         }
     }
 }
@@ -119,6 +144,8 @@ impl From<RecordInternal> for api::rest::Record {
             vector,
             shard_key,
             order_value,
+            then_by_values,
+            version,
         } = value;
         Self {
             id,
@@ -126,6 +153,8 @@ impl From<RecordInternal> for api::rest::Record {
             vector: vector.map(api::rest::VectorStructOutput::from),
             shard_key,
             order_value,
+            then_by_values,
+            version,
         }
     }
 }
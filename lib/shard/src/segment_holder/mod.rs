@@ -41,6 +41,15 @@ pub type SegmentId = usize;
 /// All occurrences of a point across segments: (segment_id, version, is_deferred).
 type PointOccurrences = SmallVec<[(SegmentId, SeqNumberType, bool); 2]>;
 
+/// Versions of the latest copy of a point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointVersions {
+    /// Number of the last operation applied to the point, local to the replica
+    pub op_num: SeqNumberType,
+    /// Number of writes made to the point, same on all replicas
+    pub version: SeqNumberType,
+}
+
 #[derive(Debug, Default)]
 pub struct SegmentHolder {
     /// Keep segments sorted by their ID for deterministic iteration order
@@ -74,6 +83,10 @@ pub struct SegmentHolder {
 
     /// The amount of currently running optimizations.
     pub running_optimizations: ProcessCounter,
+
+    /// Count writes made to each point, so they can be used as point versions.
+    /// Set from the collection parameters, writes are not counted by default.
+    pub point_versions: bool,
 }
 
 impl Drop for SegmentHolder {
@@ -668,8 +681,17 @@ impl SegmentHolder {
 
             let can_apply_operation = !write_segment.is_proxy() && write_segment.is_appendable();
 
+            // Number of writes made to the point, including this one
+            let write_count = self
+                .point_versions
+                .then(|| write_segment.point_write_count(point_id).unwrap_or(0) + 1);
+
             let is_applied = if can_apply_operation {
-                point_operation(point_id, write_segment)?
+                let is_applied = point_operation(point_id, write_segment)?;
+                if let Some(write_count) = write_count {
+                    write_segment.set_point_write_count(op_num, point_id, write_count)?;
+                }
+                is_applied
             } else {
                 self.aloha_random_write(
                     &appendable_segments,
@@ -695,6 +717,13 @@ impl SegmentHolder {
                         )?;
                         appendable_write_segment
                             .set_full_payload(op_num, point_id, &payload, hw_counter)?;
+                        if let Some(write_count) = write_count {
+                            appendable_write_segment.set_point_write_count(
+                                op_num,
+                                point_id,
+                                write_count,
+                            )?;
+                        }
 
                         // Keep the source of the CoW operation as the deferred point is invisible until indexing.
                         if !appendable_write_segment.point_is_deferred(point_id) {
@@ -748,6 +777,43 @@ impl SegmentHolder {
         existing_points
     }
 
+    /// Out of a list of point IDs, select versions of the latest copy of each point across all segments.
+    ///
+    /// Points, which don't exist in any segment, are not included in the result.
+    pub fn select_point_versions(
+        &self,
+        ids: &[PointIdType],
+    ) -> AHashMap<PointIdType, PointVersions> {
+        let mut versions = AHashMap::with_capacity(ids.len());
+        if ids.is_empty() {
+            return versions;
+        }
+
+        for (_segment_id, segment) in self.iter() {
+            let segment_guard = segment.get().read();
+            for &id in ids {
+                let Some(op_num) = segment_guard.point_version(id) else {
+                    continue;
+                };
+                let current = PointVersions {
+                    op_num,
+                    // Writes are only counted if the collection has point versions enabled
+                    version: segment_guard.point_write_count(id).unwrap_or(0),
+                };
+                versions
+                    .entry(id)
+                    .and_modify(|latest: &mut PointVersions| {
+                        if current.op_num > latest.op_num {
+                            *latest = current;
+                        }
+                    })
+                    .or_insert(current);
+            }
+        }
+
+        versions
+    }
+
     /// Create a new appendable segment and add it to the segment holder.
     ///
     /// The segment configuration is sourced from the given collection parameters.
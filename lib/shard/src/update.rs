@@ -6,19 +6,20 @@ use ahash::{AHashMap, AHashSet};
 use common::counter::hardware_counter::HardwareCounterCell;
 use common::types::DeferredBehavior;
 use parking_lot::RwLockWriteGuard;
-use segment::common::operation_error::{OperationError, OperationResult};
+use segment::common::operation_error::{OperationError, OperationResult, PointVersionConflict};
 use segment::data_types::build_index_result::BuildFieldIndexResult;
 use segment::data_types::named_vectors::NamedVectors;
 use segment::entry::entry_point::SegmentEntry;
 use segment::json_path::JsonPath;
 use segment::types::{
-    Condition, Filter, Payload, PayloadFieldSchema, PayloadKeyType, PayloadKeyTypeRef, PointIdType,
-    SeqNumberType, VectorNameBuf, WithPayload, WithVector,
+    Condition, ExpectedPointVersion, Filter, Payload, PayloadFieldSchema, PayloadKeyType,
+    PayloadKeyTypeRef, PointIdType, SeqNumberType, VectorNameBuf, WithPayload, WithVector,
 };
 
 use crate::operations::payload_ops::PayloadOps;
 use crate::operations::point_ops::{
-    ConditionalInsertOperationInternal, PointOperations, PointStructPersisted, UpdateMode,
+    ConditionalDeleteOperationInternal, ConditionalInsertOperationInternal, PointOperations,
+    PointStructPersisted, UpdateMode,
 };
 use crate::operations::vector_ops::{PointVectorsPersisted, UpdateVectorsOp, VectorOperations};
 use crate::operations::{
@@ -42,6 +43,11 @@ pub fn process_point_operation(
             conditional_upsert(segments, op_num, operation, hw_counter)
         }
         PointOperations::DeletePoints { ids } => delete_points(segments, op_num, &ids, hw_counter),
+        PointOperations::DeletePointsConditional(operation) => {
+            let ConditionalDeleteOperationInternal { ids, if_version } = operation;
+            check_point_versions(segments, op_num, &if_version)?;
+            delete_points(segments, op_num, &ids, hw_counter)
+        }
         PointOperations::DeletePointsByFilter(filter) => {
             delete_points_by_filter(segments, op_num, &filter, hw_counter)
         }
@@ -104,6 +110,9 @@ pub fn process_payload_operation(
 ) -> OperationResult<usize> {
     match payload_operation {
         PayloadOps::SetPayload(sp) => {
+            if let Some(if_version) = &sp.if_version {
+                check_point_versions(segments, op_num, if_version)?;
+            }
             let payload: Payload = sp.payload;
             if let Some(points) = sp.points {
                 set_payload(segments, op_num, &payload, &points, &sp.key, hw_counter)
@@ -135,6 +144,9 @@ pub fn process_payload_operation(
             clear_payload_by_filter(segments, op_num, filter, hw_counter)
         }
         PayloadOps::OverwritePayload(sp) => {
+            if let Some(if_version) = &sp.if_version {
+                check_point_versions(segments, op_num, if_version)?;
+            }
             let payload: Payload = sp.payload;
             if let Some(points) = sp.points {
                 overwrite_payload(segments, op_num, &payload, &points, hw_counter)
@@ -221,6 +233,20 @@ where
         )?;
 
         res += updated_points.len();
+
+        // Points copied from another replica keep their number of writes
+        if segments.point_versions {
+            let copied_points: Vec<_> = ids_chunk
+                .iter()
+                .copied()
+                .filter(|id| updated_points.contains(id) && points_map[id].version.is_some())
+                .collect();
+            segments.apply_points(&copied_points, hw_counter, |id, _, write_segment| {
+                let write_count = points_map[&id].version.unwrap_or_default();
+                write_segment.set_point_write_count(op_num, id, write_count)
+            })?;
+        }
+
         // Insert new points, which was not updated or existed
         let new_point_ids = ids_chunk
             .iter()
@@ -247,6 +273,10 @@ where
                     point.payload.as_ref(),
                     hw_counter,
                 )?);
+                if segments.point_versions {
+                    let write_count = point.version.unwrap_or(1);
+                    write_segment.set_point_write_count(op_num, point_id, write_count)?;
+                }
             }
             RwLockWriteGuard::unlock_fair(write_segment);
         };
@@ -268,8 +298,13 @@ pub fn conditional_upsert(
         mut points_op,
         condition,
         update_mode,
+        if_version,
     } = operation;
 
+    if let Some(if_version) = &if_version {
+        check_point_versions(segments, op_num, if_version)?;
+    }
+
     let point_ids = points_op.point_ids();
     let update_mode = update_mode.unwrap_or_default();
 
@@ -309,6 +344,53 @@ pub fn conditional_upsert(
     Ok(upserted_points)
 }
 
+/// Check that points currently have the expected versions.
+///
+/// If any of the points has a different version or doesn't exist, the operation must not be
+/// applied to this shard. The returned error lists every conflicting point.
+///
+/// Versions are compared with the number of writes made to the points,
+/// which is only counted if the collection has point versions enabled.
+pub fn check_point_versions(
+    segments: &SegmentHolder,
+    op_num: SeqNumberType,
+    if_version: &[ExpectedPointVersion],
+) -> OperationResult<()> {
+    if !segments.point_versions {
+        return Err(OperationError::validation_error(
+            "Point versions are not enabled for the collection",
+        ));
+    }
+
+    let point_ids: Vec<_> = if_version.iter().map(|expected| expected.id).collect();
+    let current_versions = segments.select_point_versions(&point_ids);
+
+    let conflicts: Vec<_> = if_version
+        .iter()
+        .filter_map(|&ExpectedPointVersion { id, version }| {
+            let current = current_versions.get(&id);
+            // Point was already updated by this or a later operation, which means we're
+            // re-applying operations from WAL. Segments will skip outdated changes by themselves.
+            let is_replay = current.is_some_and(|current| current.op_num >= op_num);
+            let actual = current.map(|current| current.version);
+            (actual != Some(version) && !is_replay).then_some(PointVersionConflict {
+                point_id: id,
+                expected: version,
+                actual,
+            })
+        })
+        .collect();
+
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+
+    // The operation is declined by this shard, so we suggest this op_num to the segment-holder to make WAL acknowledge it.
+    segments.bump_max_segment_version_overwrite(op_num);
+
+    Err(OperationError::VersionConflict { conflicts })
+}
+
 /// Upsert to a point ID with the specified vectors and payload in the given segment.
 ///
 /// If the payload is None, the existing payload will be cleared.
@@ -557,7 +639,10 @@ pub fn sync_points(
 
             for (id, stored_record) in stored_records {
                 let point = id_to_point.get(&id).unwrap();
-                if !point.is_equal_to(&stored_record) {
+                let is_same_version = point.version.is_none()
+                    || !segments.point_versions
+                    || point.version == segment.point_write_count(id);
+                if !point.is_equal_to(&stored_record) || !is_same_version {
                     points_to_update.push(*point);
                     updated += 1;
                 }
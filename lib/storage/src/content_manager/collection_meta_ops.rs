@@ -5,6 +5,9 @@ use collection::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
     WalConfigDiff,
 };
+use collection::operations::payload_validation::{
+    PayloadValidationConfig, PayloadValidationConfigDiff,
+};
 use collection::operations::types::{
     SparseVectorParams, SparseVectorsConfig, VectorsConfig, VectorsConfigDiff,
 };
@@ -178,6 +181,15 @@ pub struct CreateCollection {
     /// such as creation time, migration data, inference model info, etc.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Schema to validate payloads of inserted and updated points against.
+    /// If none - payloads are not validated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub payload_validation: Option<PayloadValidationConfig>,
+    /// If true - count writes made to each point and return them as point versions.
+    /// Can only be set on collection creation. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub point_versions: Option<bool>,
 }
 
 /// Operation for creating new collection and (optionally) specify index params
@@ -225,6 +237,11 @@ impl CreateCollectionOperation {
             }
         }
 
+        // Collection-level quantization applies to all vectors, so all of them must support it
+        if create_collection.quantization_config.is_some() {
+            create_collection.vectors.check_quantization_supported()?;
+        }
+
         // validate vector names are unique between dense and sparse vectors
         if let Some(sparse_config) = &create_collection.sparse_vectors {
             if sparse_config.contains_key(DEFAULT_VECTOR_NAME) {
@@ -293,6 +310,11 @@ pub struct UpdateCollection {
     /// To remove metadata, set it to an empty object.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Payload>,
+    /// Payload schema to replace the current one with. If none - it is left unchanged.
+    /// To disable validation, set it to `Disabled`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[validate(nested)]
+    pub payload_validation: Option<PayloadValidationConfigDiff>,
 }
 
 /// Operation for updating parameters of the existing collection
@@ -317,6 +339,7 @@ impl UpdateCollectionOperation {
                 sparse_vectors: None,
                 strict_mode_config: None,
                 metadata: None,
+                payload_validation: None,
             },
             shard_replica_changes: None,
         }
@@ -487,6 +510,7 @@ impl From<CollectionConfigInternal> for CreateCollection {
             strict_mode_config,
             uuid,
             metadata,
+            payload_validation,
         } = value;
 
         let CollectionParams {
@@ -499,6 +523,7 @@ impl From<CollectionConfigInternal> for CreateCollection {
             read_fan_out_delay_ms: _,
             on_disk_payload,
             sparse_vectors,
+            point_versions,
         } = params;
 
         Self {
@@ -516,6 +541,8 @@ impl From<CollectionConfigInternal> for CreateCollection {
             strict_mode_config,
             uuid,
             metadata,
+            payload_validation,
+            point_versions: Some(point_versions),
         }
     }
 }
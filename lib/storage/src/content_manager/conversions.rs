@@ -2,12 +2,16 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use api::conversions::json;
+use api::grpc::conversions::version_conflicts_to_status;
 use api::grpc::qdrant as grpc;
 use chrono::{DateTime, Utc};
 use collection::operations::config_diff::{
     CollectionParamsDiff, HnswConfigDiff, OptimizersConfigDiff, QuantizationConfigDiff,
 };
 use collection::operations::conversions::sharding_method_from_proto;
+use collection::operations::payload_validation::{
+    PayloadValidationConfig, PayloadValidationConfigDiff,
+};
 use collection::operations::types::{SparseVectorsConfig, VectorsConfigDiff};
 use segment::types::{StrictModeConfig, StrictModeMultivectorConfig, StrictModeSparseConfig};
 use tonic::Status;
@@ -51,8 +55,14 @@ impl From<StorageError> for Status {
             }
             StorageError::ShardUnavailable { .. } => tonic::Code::Unavailable,
             StorageError::EmptyPartialSnapshot { .. } => tonic::Code::FailedPrecondition,
+            StorageError::VersionConflict { .. } => tonic::Code::Aborted,
+        };
+        // Conflicting points are passed in the status details, so clients can handle them
+        let mut status = if let StorageError::VersionConflict { conflicts } = &error {
+            version_conflicts_to_status(error.to_string(), conflicts.clone())
+        } else {
+            Status::new(error_code, error.to_string())
         };
-        let mut status = Status::new(error_code, error.to_string());
         // add metadata headers
         for (header_key, header_value) in metadata_headers {
             if let Ok(metadata) = MetadataValue::from_str(&header_value) {
@@ -85,6 +95,8 @@ impl TryFrom<grpc::CreateCollection> for CollectionMetaOperations {
             sparse_vectors_config,
             strict_mode_config,
             metadata,
+            payload_validation,
+            point_versions,
         } = value;
         let op = CreateCollectionOperation::new(
             collection_name,
@@ -115,6 +127,10 @@ impl TryFrom<grpc::CreateCollection> for CollectionMetaOperations {
                 } else {
                     Some(json::proto_to_payloads(metadata)?)
                 },
+                payload_validation: payload_validation
+                    .map(PayloadValidationConfig::try_from)
+                    .transpose()?,
+                point_versions,
             },
         )?;
         Ok(CollectionMetaOperations::CreateCollection(op))
@@ -130,6 +146,7 @@ pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
         unindexed_filtering_update,
         search_max_hnsw_ef,
         search_allow_exact,
+        search_allow_radius,
         search_max_oversampling,
         upsert_max_batchsize,
         search_max_batchsize,
@@ -153,6 +170,7 @@ pub fn strict_mode_from_api(value: grpc::StrictModeConfig) -> StrictModeConfig {
         unindexed_filtering_update,
         search_max_hnsw_ef: search_max_hnsw_ef.map(|i| i as usize),
         search_allow_exact,
+        search_allow_radius,
         search_max_oversampling: search_max_oversampling.map(f64::from),
         upsert_max_batchsize: upsert_max_batchsize.map(|i| i as usize),
         search_max_batchsize: search_max_batchsize.map(|i| i as usize),
@@ -185,6 +203,7 @@ impl TryFrom<grpc::UpdateCollection> for CollectionMetaOperations {
             sparse_vectors_config,
             strict_mode_config,
             metadata,
+            payload_validation,
         } = value;
         Ok(Self::UpdateCollection(UpdateCollectionOperation::new(
             collection_name,
@@ -210,6 +229,9 @@ impl TryFrom<grpc::UpdateCollection> for CollectionMetaOperations {
                 } else {
                     Some(json::proto_to_payloads(metadata)?)
                 },
+                payload_validation: payload_validation
+                    .map(PayloadValidationConfigDiff::try_from)
+                    .transpose()?,
             },
         )))
     }
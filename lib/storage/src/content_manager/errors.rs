@@ -4,6 +4,8 @@ use std::time::Duration;
 
 use collection::operations::types::CollectionError;
 use collection::shards::shard::ShardId;
+use itertools::Itertools as _;
+use segment::common::operation_error::PointVersionConflict;
 use tempfile::PersistError;
 use thiserror::Error;
 
@@ -46,6 +48,10 @@ pub enum StorageError {
     ShardUnavailable { description: String },
     #[error("Partial snapshot for shard {shard_id} contains no changes")]
     EmptyPartialSnapshot { shard_id: ShardId },
+    #[error("Version conflict: {}", .conflicts.iter().join(", "))]
+    VersionConflict {
+        conflicts: Vec<PointVersionConflict>,
+    },
 }
 
 impl StorageError {
@@ -178,6 +184,10 @@ impl StorageError {
             CollectionError::ShardUnavailable { .. } => StorageError::ShardUnavailable {
                 description: overriding_description,
             },
+            // Conflicts are reported by the shards, which hold the points, so they are kept as is
+            CollectionError::VersionConflict { conflicts } => {
+                StorageError::VersionConflict { conflicts }
+            }
         }
     }
 }
@@ -237,6 +247,9 @@ impl From<CollectionError> for StorageError {
             CollectionError::ShardUnavailable { description } => {
                 StorageError::ShardUnavailable { description }
             }
+            CollectionError::VersionConflict { conflicts } => {
+                StorageError::VersionConflict { conflicts }
+            }
         }
     }
 }
@@ -3,7 +3,9 @@ use std::sync::LazyLock;
 
 use collection::collection_state;
 use collection::config::ShardingMethod;
-use collection::events::{CollectionDeletedEvent, IndexCreatedEvent};
+use collection::events::{
+    CollectionDeletedEvent, IndexCreatedEvent, PayloadValidationUpdatedEvent,
+};
 use collection::shards::collection_shard_distribution::CollectionShardDistribution;
 use collection::shards::replica_set::replica_set_state::ReplicaState;
 use collection::shards::transfer::ShardTransfer;
@@ -156,6 +158,7 @@ impl TableOfContent {
             sparse_vectors,
             strict_mode_config: strict_mode,
             metadata,
+            payload_validation,
         } = operation.update_collection;
         let collection = self
             .get_collection_unchecked(&operation.collection_name)
@@ -199,6 +202,15 @@ impl TableOfContent {
             collection.update_metadata(metadata).await?;
         }
 
+        if let Some(payload_validation) = payload_validation {
+            collection
+                .update_payload_validation(payload_validation.into_config())
+                .await?;
+            issues::publish(PayloadValidationUpdatedEvent {
+                collection_id: operation.collection_name.clone(),
+            });
+        }
+
         collection.print_warnings().await;
 
         // Recreate optimizers
@@ -43,6 +43,8 @@ impl TableOfContent {
             strict_mode_config,
             uuid,
             metadata,
+            payload_validation,
+            point_versions,
         } = operation;
 
         {
@@ -144,6 +146,7 @@ impl TableOfContent {
             )?,
             read_fan_out_factor: None,
             read_fan_out_delay_ms: None,
+            point_versions: point_versions.unwrap_or_default(),
         };
         let wal_config = self.storage_config.wal.update_opt(wal_config_diff.as_ref());
 
@@ -158,12 +161,22 @@ impl TableOfContent {
             .update_opt(hnsw_config_diff.as_ref());
 
         let quantization_config = match quantization_config {
+            // Default quantization is skipped for collections with vectors which can't be quantized
             None => self
                 .storage_config
                 .collection
                 .as_ref()
-                .and_then(|i| i.quantization.clone()),
-            Some(diff) => Some(diff),
+                .and_then(|i| i.quantization.clone())
+                .filter(|_| {
+                    collection_params
+                        .vectors
+                        .check_quantization_supported()
+                        .is_ok()
+                }),
+            Some(diff) => {
+                collection_params.vectors.check_quantization_supported()?;
+                Some(diff)
+            }
         };
 
         let strict_mode_config = match strict_mode_config {
@@ -198,6 +211,7 @@ impl TableOfContent {
             strict_mode_config,
             uuid,
             metadata,
+            payload_validation,
         };
 
         // No shard key mapping on creation, shard keys are set up after creating the collection
@@ -1,30 +1,49 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
+use api::rest::models::Explanation;
 use collection::collection::Collection;
+use collection::collection::clustering::{
+    CollectionClusteringRequest, CollectionClusteringResponse,
+};
 use collection::collection::distance_matrix::{
     CollectionSearchMatrixRequest, CollectionSearchMatrixResponse,
 };
+use collection::collection::federated_query::{
+    FederatedCollectionResult, MAX_FEDERATED_COLLECTIONS, check_federated_vector_schemas,
+    merge_federated_results,
+};
+use collection::collection::near_duplicates::{
+    CollectionNearDuplicatesRequest, CollectionNearDuplicatesResponse,
+};
+use collection::collection::quantization_tuning::{
+    CollectionQuantizationTuningRequest, CollectionQuantizationTuningResponse,
+};
 use collection::config::ShardingMethod;
 use collection::grouping::GroupBy;
 use collection::grouping::group_by::GroupRequest;
+use collection::lookup::join::WithJoin;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::point_ops::WriteOrdering;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::*;
 use collection::operations::universal_query::collection_query::CollectionQueryRequest;
+use collection::operations::universal_query::shard_query::FusionInternal;
 use collection::operations::{CollectionUpdateOperations, OperationWithClockTag};
 use collection::shards::shard_trait::WaitUntil;
 use collection::{discovery, recommendations};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use futures::TryStreamExt as _;
 use futures::stream::FuturesUnordered;
+use itertools::Itertools;
 use segment::data_types::facets::{FacetParams, FacetResponse};
-use segment::types::{ScoredPoint, ShardKey};
+use segment::types::{Payload, PointIdType, ScoredPoint, ShardKey};
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
 use shard::search::CoreSearchRequestBatch;
 
 use super::TableOfContent;
+use crate::content_manager::collection_verification::check_strict_mode_toc;
 use crate::content_manager::errors::{StorageError, StorageResult};
 use crate::rbac::Auth;
 use crate::rbac::auditable_operation::AuditableOperation;
@@ -230,6 +249,95 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// Embed payload of points from another collection into the payloads of the given points.
+    ///
+    /// The key is fetched from `collection_name` for the points, which don't have it in the
+    /// returned payload. Referenced points are retrieved from all shards of the other collection,
+    /// which requires read access to it and is subject to its strict mode.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn join_payloads(
+        &self,
+        collection_name: &str,
+        with_join: &WithJoin,
+        points: Vec<(PointIdType, &mut Option<Payload>)>,
+        read_consistency: Option<ReadConsistency>,
+        auth: &Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<()> {
+        let missing_keys = points
+            .iter()
+            .filter(|(_, payload)| !with_join.has_key(payload.as_ref()))
+            .map(|(id, _)| *id)
+            .collect_vec();
+
+        let mut fetched_keys = HashMap::new();
+        if !missing_keys.is_empty() {
+            let request = with_join.key_request(missing_keys);
+            let collection_pass = auth.check_point_op(collection_name, &request, "join")?;
+            let collection = self.get_collection(&collection_pass).await?;
+            fetched_keys = collection
+                .retrieve(
+                    request,
+                    read_consistency,
+                    &ShardSelectorInternal::All,
+                    timeout,
+                    hw_measurement_acc.clone(),
+                )
+                .await?
+                .into_iter()
+                .filter_map(|record| Some((record.id, record.payload?)))
+                .collect();
+        }
+
+        let references = points
+            .iter()
+            .map(|(id, payload)| {
+                let payload = payload
+                    .as_ref()
+                    .filter(|payload| with_join.has_key(Some(payload)))
+                    .or_else(|| fetched_keys.get(id));
+                with_join.reference(payload)
+            })
+            .collect_vec();
+
+        let Some(request) = with_join.point_request(&references) else {
+            return Ok(());
+        };
+
+        check_strict_mode_toc(
+            &request,
+            timeout.map(|timeout| timeout.as_secs() as usize),
+            &with_join.collection_name,
+            self,
+            auth,
+        )
+        .await?;
+        let collection_pass = auth.check_point_op(&with_join.collection_name, &request, "join")?;
+        let collection = self.get_collection(&collection_pass).await?;
+        let records = collection
+            .retrieve(
+                request,
+                read_consistency,
+                &ShardSelectorInternal::All,
+                timeout,
+                // Usage is reported for the whole request, but accounted to the other collection
+                hw_measurement_acc.with_metrics_drain(
+                    self.get_collection_hw_metrics(with_join.collection_name.clone()),
+                ),
+            )
+            .await?;
+
+        with_join.embed(
+            points
+                .into_iter()
+                .map(|(_, payload)| payload)
+                .zip(references),
+            records,
+        )?;
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn group(
         &self,
@@ -387,6 +495,165 @@ impl TableOfContent {
             .map_err(|err| err.into())
     }
 
+    /// Explain how the query is executed by the local shards of the collection.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn explain_query(
+        &self,
+        collection_name: &str,
+        request: CollectionQueryRequest,
+        shard_selection: ShardSelectorInternal,
+        read_consistency: Option<ReadConsistency>,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Explanation> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "explain_query")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .explain_query(
+                request,
+                shard_selection,
+                |name| self.get_collection_opt(name),
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Explain how the search is executed by the local shards of the collection.
+    pub async fn explain_search(
+        &self,
+        collection_name: &str,
+        request: CoreSearchRequest,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Explanation> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "explain_search")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .explain_search(request, &shard_selection, timeout, hw_measurement_acc)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Explain how the local shards of the collection find the points to count.
+    pub async fn explain_count(
+        &self,
+        collection_name: &str,
+        request: CountRequestInternal,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Explanation> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "explain_count")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        collection
+            .explain_count(
+                request.filter.as_ref(),
+                &shard_selection,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Run the same query in several collections and merge the results.
+    ///
+    /// Access is checked for each collection separately.
+    /// Returned points are tagged with the name of their collection.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn federated_query(
+        &self,
+        collection_names: &[String],
+        request: CollectionQueryRequest,
+        fusion: Option<FusionInternal>,
+        read_consistency: Option<ReadConsistency>,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> StorageResult<Vec<(String, ScoredPoint)>> {
+        if request.cursor.is_some() {
+            return Err(StorageError::bad_input(
+                "Cursor is not supported when querying multiple collections",
+            ));
+        }
+
+        if collection_names.len() > MAX_FEDERATED_COLLECTIONS {
+            return Err(StorageError::bad_input(format!(
+                "Too many collections to query: {}, maximum is {MAX_FEDERATED_COLLECTIONS}",
+                collection_names.len(),
+            )));
+        }
+        if let Some(duplicate) = collection_names.iter().duplicates().next() {
+            return Err(StorageError::bad_input(format!(
+                "Collection `{duplicate}` is queried more than once",
+            )));
+        }
+
+        let CollectionQueryRequest { limit, offset, .. } = request;
+
+        // Each collection has to return enough points to apply the offset after merging
+        let collection_request = CollectionQueryRequest {
+            limit: limit + offset,
+            offset: 0,
+            ..request
+        };
+
+        let mut collections = Vec::with_capacity(collection_names.len());
+        let mut schemas = Vec::with_capacity(collection_names.len());
+        for collection_name in collection_names {
+            let collection_pass =
+                auth.check_point_op(collection_name, &collection_request, "federated_query")?;
+            let collection = self.get_collection(&collection_pass).await?;
+            let schema = collection.query_vector_schemas(&collection_request).await?;
+            schemas.push((collection_name.clone(), schema));
+            collections.push((collection_name, collection));
+        }
+        check_federated_vector_schemas(&schemas)?;
+
+        let queries = collections
+            .into_iter()
+            .map(|(collection_name, collection)| {
+                let collection_request = collection_request.clone();
+                // Usage is reported for the whole request, but accounted to each collection
+                let hw_measurement_acc = hw_measurement_acc
+                    .with_metrics_drain(self.get_collection_hw_metrics(collection_name.clone()));
+
+                async move {
+                    let order = collection.query_result_order(&collection_request).await?;
+                    let points = collection
+                        .query_batch(
+                            vec![(collection_request, ShardSelectorInternal::All)],
+                            |name| self.get_collection_opt(name),
+                            read_consistency,
+                            timeout,
+                            hw_measurement_acc,
+                        )
+                        .await?
+                        .pop()
+                        .unwrap_or_default();
+                    Ok::<_, StorageError>(FederatedCollectionResult {
+                        collection_name: collection_name.clone(),
+                        order,
+                        points,
+                    })
+                }
+            })
+            .collect_vec();
+
+        let results = futures::future::try_join_all(queries).await?;
+        merge_federated_results(results, fusion.as_ref(), offset, limit).map_err(StorageError::from)
+    }
+
     // Return unique values for a payload key, and a count of points for each value.
     #[allow(clippy::too_many_arguments)]
     pub async fn facet(
@@ -443,6 +710,91 @@ impl TableOfContent {
             .map_err(StorageError::from)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn cluster_points(
+        &self,
+        collection_name: &str,
+        request: CollectionClusteringRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> Result<CollectionClusteringResponse, StorageError> {
+        let collection_pass = auth.check_point_op(collection_name, &request, "cluster_points")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .cluster_points(
+                request,
+                shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn find_near_duplicates(
+        &self,
+        collection_name: &str,
+        request: CollectionNearDuplicatesRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> Result<CollectionNearDuplicatesResponse, StorageError> {
+        let collection_pass =
+            auth.check_point_op(collection_name, &request, "find_near_duplicates")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+
+        collection
+            .find_near_duplicates(
+                request,
+                shard_selection,
+                read_consistency,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn tune_quantization(
+        &self,
+        collection_name: &str,
+        request: CollectionQuantizationTuningRequest,
+        read_consistency: Option<ReadConsistency>,
+        shard_selection: ShardSelectorInternal,
+        auth: Auth,
+        timeout: Option<Duration>,
+        hw_measurement_acc: HwMeasurementAcc,
+    ) -> Result<CollectionQuantizationTuningResponse, StorageError> {
+        let collection_pass =
+            auth.check_point_op(collection_name, &request, "tune_quantization")?;
+
+        let collection = self.get_collection(&collection_pass).await?;
+        let temp_dir = self.optional_temp_or_storage_temp_path()?;
+
+        collection
+            .tune_quantization(
+                request,
+                shard_selection,
+                read_consistency,
+                &temp_dir,
+                timeout,
+                hw_measurement_acc,
+            )
+            .await
+            .map_err(StorageError::from)
+    }
+
     /// # Cancel safety
     ///
     /// This method is cancel safe.
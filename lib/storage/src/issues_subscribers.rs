@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
-use collection::events::{CollectionDeletedEvent, IndexCreatedEvent, SlowQueryEvent};
-use collection::problems::UnindexedField;
+use collection::events::{
+    CollectionDeletedEvent, IndexCreatedEvent, PayloadSchemaViolationEvent,
+    PayloadValidationUpdatedEvent, SlowQueryEvent,
+};
+use collection::problems::{PayloadSchemaViolation, UnindexedField};
 use issues::Code;
 use issues::broker::Subscriber;
 
@@ -39,3 +42,28 @@ impl Subscriber<IndexCreatedEvent> for UnindexedFieldSubscriber {
         ));
     }
 }
+
+#[derive(Clone, Copy)]
+pub struct PayloadSchemaViolationSubscriber;
+
+impl Subscriber<PayloadSchemaViolationEvent> for PayloadSchemaViolationSubscriber {
+    fn notify(&self, event: Arc<PayloadSchemaViolationEvent>) {
+        PayloadSchemaViolation::submit_all(&event.collection_id, event.violations.clone());
+    }
+}
+
+impl Subscriber<PayloadValidationUpdatedEvent> for PayloadSchemaViolationSubscriber {
+    fn notify(&self, event: Arc<PayloadValidationUpdatedEvent>) {
+        issues::solve_by_filter::<PayloadSchemaViolation, _>(|code| {
+            PayloadSchemaViolation::get_collection_name(code) == event.collection_id
+        });
+    }
+}
+
+impl Subscriber<CollectionDeletedEvent> for PayloadSchemaViolationSubscriber {
+    fn notify(&self, event: Arc<CollectionDeletedEvent>) {
+        issues::solve_by_filter::<PayloadSchemaViolation, _>(|code| {
+            PayloadSchemaViolation::get_collection_name(code) == event.collection_id
+        });
+    }
+}
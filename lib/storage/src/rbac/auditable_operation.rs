@@ -16,6 +16,7 @@ impl AuditableOperation for CollectionUpdateOperations {
                 PointOperations::UpsertPoints(_) => "upsert_points",
                 PointOperations::UpsertPointsConditional(_) => "upsert_points_conditional",
                 PointOperations::DeletePoints { .. } => "delete_points",
+                PointOperations::DeletePointsConditional(_) => "delete_points_conditional",
                 PointOperations::DeletePointsByFilter(_) => "delete_points_by_filter",
                 PointOperations::SyncPoints(_) => "sync_points",
             },
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
 
 use api::rest::{LookupLocation, SearchRequestInternal};
+use collection::collection::clustering::CollectionClusteringRequest;
 use collection::collection::distance_matrix::CollectionSearchMatrixRequest;
+use collection::collection::near_duplicates::CollectionNearDuplicatesRequest;
+use collection::collection::quantization_tuning::CollectionQuantizationTuningRequest;
 use collection::grouping::group_by::{GroupRequest, SourceRequest};
 use collection::lookup::WithLookup;
 use collection::operations::CollectionUpdateOperations;
@@ -297,6 +300,48 @@ impl CheckableCollectionOperation for CollectionSearchMatrixRequest {
     }
 }
 
+impl CheckableCollectionOperation for CollectionClusteringRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: self.write_payload,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
+impl CheckableCollectionOperation for CollectionNearDuplicatesRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: self.delete_duplicates,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
+impl CheckableCollectionOperation for CollectionQuantizationTuningRequest {
+    fn access_requirements(&self) -> AccessRequirements {
+        AccessRequirements {
+            write: false,
+            manage: false,
+            extras: false,
+        }
+    }
+
+    fn check_access(&self, _access: &CollectionAccessList) -> StorageResult<()> {
+        Ok(())
+    }
+}
+
 impl CheckableCollectionOperation for CollectionUpdateOperations {
     fn access_requirements(&self) -> AccessRequirements {
         match self {
@@ -368,10 +413,10 @@ mod tests_ops {
     use api::rest::{self, LookupLocation, RecommendStrategy, SearchRequestInternal};
     use collection::operations::payload_ops::PayloadOpsDiscriminants;
     use collection::operations::point_ops::{
-        BatchPersisted, BatchVectorStructPersisted, ConditionalInsertOperationInternal,
-        PointInsertOperationsInternal, PointInsertOperationsInternalDiscriminants,
-        PointOperationsDiscriminants, PointStructPersisted, PointSyncOperation,
-        VectorStructPersisted,
+        BatchPersisted, BatchVectorStructPersisted, ConditionalDeleteOperationInternal,
+        ConditionalInsertOperationInternal, PointInsertOperationsInternal,
+        PointInsertOperationsInternalDiscriminants, PointOperationsDiscriminants,
+        PointStructPersisted, PointSyncOperation, VectorStructPersisted,
     };
     use collection::operations::query_enum::QueryEnum;
     use collection::operations::types::{ContextExamplePair, RecommendExample, UsingVector};
@@ -385,8 +430,8 @@ mod tests_ops {
     use segment::data_types::order_by::OrderByInterface;
     use segment::data_types::vectors::NamedQuery;
     use segment::types::{
-        Condition, ExtendedPointId, Filter, Payload, PointIdType, SearchParams,
-        WithPayloadInterface, WithVector,
+        Condition, ExpectedPointVersion, ExtendedPointId, Filter, Payload, PointIdType,
+        SearchParams, WithPayloadInterface, WithVector,
     };
     use shard::operations::payload_ops::{DeletePayloadOp, PayloadOps, SetPayloadOp};
     use shard::operations::point_ops::{PointIdsList, PointOperations};
@@ -524,6 +569,57 @@ mod tests_ops {
         );
     }
 
+    #[test]
+    fn test_collection_clustering_request() {
+        let op = CollectionClusteringRequest {
+            using: "vector".into(),
+            filter: None,
+            clusters: 10,
+            sample_size: Some(1000),
+            method: rest::ClusteringMethod::Kmeans,
+            max_iterations: 100,
+            batch_size: 1024,
+            write_payload: false,
+        };
+
+        assert_allowed(&op, &Access::Global(GlobalAccessMode::Read));
+        assert_allowed(
+            &op,
+            &AccessCollectionBuilder::new().add("col", false).into(),
+        );
+
+        // Writing cluster ids into payload requires write access
+        assert_requires_whole_write_access(&CollectionClusteringRequest {
+            write_payload: true,
+            ..op
+        });
+    }
+
+    #[test]
+    fn test_collection_near_duplicates_request() {
+        let op = CollectionNearDuplicatesRequest {
+            using: "vector".into(),
+            filter: None,
+            score_threshold: 0.9,
+            limit_per_point: 10,
+            params: None,
+            sample_size: None,
+            delete_duplicates: false,
+        };
+
+        assert_allowed(&op, &Access::Global(GlobalAccessMode::Read));
+        assert_allowed(
+            &op,
+            &AccessCollectionBuilder::new().add("col", false).into(),
+        );
+
+        // Deleting duplicates requires write access
+        assert_requires_whole_write_access(&CollectionNearDuplicatesRequest {
+            delete_duplicates: true,
+            ..op
+        });
+    }
+
     #[test]
     fn test_count_request_internal() {
         let op = CountRequestInternal {
@@ -554,7 +650,7 @@ mod tests_ops {
                 with_vector: Some(WithVector::Bool(true)),
                 score_threshold: Some(42.0),
             }),
-            group_by: "path".parse().unwrap(),
+            group_by: vec!["path".parse().unwrap()],
             group_size: 100,
             limit: 100,
             with_lookup: Some(WithLookup {
@@ -562,6 +658,7 @@ mod tests_ops {
                 with_payload: Some(WithPayloadInterface::Bool(true)),
                 with_vectors: Some(WithVector::Bool(true)),
             }),
+            subgroups: None,
         };
 
         assert_allowed(&op, &Access::Global(GlobalAccessMode::Manage));
@@ -734,6 +831,7 @@ mod tests_ops {
                                 id: ExtendedPointId::NumId(12345),
                                 vector: VectorStructPersisted::Single(vec![0.0, 1.0, 2.0]),
                                 payload: None,
+                                version: None,
                             }])
                         }
                     };
@@ -749,6 +847,7 @@ mod tests_ops {
                     id: ExtendedPointId::NumId(12345),
                     vector: VectorStructPersisted::Single(vec![0.0, 1.0, 2.0]),
                     payload: None,
+                    version: None,
                 }]);
 
                 let filter = make_filter_from_ids(vec![ExtendedPointId::NumId(12345)]);
@@ -758,6 +857,7 @@ mod tests_ops {
                         points_op: inner,
                         condition: filter,
                         update_mode: None,
+                        if_version: None,
                     }),
                 );
 
@@ -772,6 +872,19 @@ mod tests_ops {
                 check_collection_update_operations_delete_points(&op);
             }
 
+            PointOperationsDiscriminants::DeletePointsConditional => {
+                let op = CollectionUpdateOperations::PointOperation(
+                    PointOperations::DeletePointsConditional(ConditionalDeleteOperationInternal {
+                        ids: vec![ExtendedPointId::NumId(12345)],
+                        if_version: vec![ExpectedPointVersion {
+                            id: ExtendedPointId::NumId(12345),
+                            version: 1,
+                        }],
+                    }),
+                );
+                check_collection_update_operations_delete_points(&op);
+            }
+
             PointOperationsDiscriminants::DeletePointsByFilter => {
                 let op = CollectionUpdateOperations::PointOperation(
                     PointOperations::DeletePointsByFilter(make_filter_from_ids(vec![
@@ -795,7 +908,8 @@ mod tests_ops {
     }
 
     /// Tests for [`CollectionUpdateOperations::PointOperation`] with
-    /// [`PointOperations::DeletePoints`] and [`PointOperations::DeletePointsByFilter`].
+    /// [`PointOperations::DeletePoints`], [`PointOperations::DeletePointsConditional`] and
+    /// [`PointOperations::DeletePointsByFilter`].
     fn check_collection_update_operations_delete_points(op: &CollectionUpdateOperations) {
         assert_allowed(op, &Access::Global(GlobalAccessMode::Manage));
         assert_forbidden(op, &Access::Global(GlobalAccessMode::Read));
@@ -825,6 +939,7 @@ mod tests_ops {
                         PointIdsList {
                             points: vec![ExtendedPointId::NumId(12345)],
                             shard_key: None,
+                            if_version: None,
                         },
                         vec!["vector".into()],
                     ));
@@ -861,6 +976,7 @@ mod tests_ops {
                     points: Some(vec![ExtendedPointId::NumId(12345)]),
                     filter: None,
                     key: None,
+                    if_version: None,
                 }),
                 PayloadOpsDiscriminants::DeletePayload => {
                     PayloadOps::DeletePayload(DeletePayloadOp {
@@ -883,6 +999,7 @@ mod tests_ops {
                         points: Some(vec![ExtendedPointId::NumId(12345)]),
                         filter: None,
                         key: None,
+                        if_version: None,
                     })
                 }
             };
@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use collection::collection::recall_monitor::RecallMonitorConfig;
 use collection::common::snapshots_manager::SnapshotsConfig;
 use collection::config::{WalConfig, default_on_disk_payload};
 use collection::operations::config_diff::OptimizersConfigDiff;
@@ -118,6 +119,10 @@ pub struct StorageConfig {
     /// Maximum number of collections to allow in the cluster.
     #[serde(default)]
     pub max_collections: Option<usize>,
+    /// If provided - periodically measure recall of vector indexes of all collections.
+    #[validate(nested)]
+    #[serde(default)]
+    pub recall_monitor: Option<RecallMonitorConfig>,
 }
 
 impl StorageConfig {
@@ -140,6 +145,7 @@ impl StorageConfig {
             self.hnsw_global_config.clone(),
             self.performance.load_concurrency.clone(),
             common::defaults::search_thread_count(self.performance.max_search_threads),
+            self.recall_monitor,
         )
     }
 }
@@ -71,6 +71,7 @@ fn test_alias_operation() {
         shard_transfer_method: None,
         collection: None,
         max_collections: None,
+        recall_monitor: None,
     };
 
     let (propose_sender, _propose_receiver) = std::sync::mpsc::channel();
@@ -112,6 +113,8 @@ fn test_alias_operation() {
                             strict_mode_config: None,
                             uuid: None,
                             metadata: None,
+                            payload_validation: None,
+                            point_versions: None,
                         },
                     )
                     .unwrap(),
@@ -0,0 +1,48 @@
+use actix_web::{Responder, post, web};
+use actix_web_validator::{Json, Path, Query};
+use api::rest::ClusteringRequest;
+use storage::dispatcher::Dispatcher;
+use tokio::time::Instant;
+
+use crate::actix::api::CollectionPath;
+use crate::actix::api::read_params::ReadParams;
+use crate::actix::auth::ActixAuth;
+use crate::actix::helpers::{get_request_hardware_counter, process_response};
+use crate::common::query::do_cluster_points;
+use crate::settings::ServiceConfig;
+
+#[post("/collections/{collection_name}/points/clustering")]
+async fn cluster_points(
+    dispatcher: web::Data<Dispatcher>,
+    collection: Path<CollectionPath>,
+    request: Json<ClusteringRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+) -> impl Responder {
+    let timing = Instant::now();
+
+    let request_hw_counter = get_request_hardware_counter(
+        &dispatcher,
+        collection.collection_name.clone(),
+        service_config.hardware_reporting(),
+        None,
+    );
+
+    let response = do_cluster_points(
+        &dispatcher,
+        &collection.collection_name,
+        request.into_inner(),
+        params.consistency,
+        auth,
+        params.timeout(),
+        request_hw_counter.get_counter(),
+    )
+    .await;
+
+    process_response(response, timing, request_hw_counter.to_rest_api())
+}
+
+pub fn config_clustering_api(cfg: &mut web::ServiceConfig) {
+    cfg.service(cluster_points);
+}
@@ -7,7 +7,7 @@ use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
 
 use super::CollectionPath;
-use crate::actix::api::read_params::ReadParams;
+use crate::actix::api::read_params::{ExplainParams, ReadParams};
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{self, get_request_hardware_counter, process_response_error};
 use crate::common::query::do_count_points;
@@ -19,6 +19,7 @@ async fn count_points(
     collection: Path<CollectionPath>,
     request: Json<CountRequest>,
     params: Query<ReadParams>,
+    explain_params: Query<ExplainParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> impl Responder {
@@ -53,18 +54,52 @@ async fn count_points(
     );
 
     let timing = Instant::now();
+    let toc = dispatcher.toc(&auth, &pass);
+    let explain_request = explain_params
+        .explain
+        .then(|| (count_request.clone(), shard_selector.clone()));
 
     let result = do_count_points(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.collection_name,
         count_request,
         params.consistency,
         params.timeout(),
         shard_selector,
-        auth,
+        auth.clone(),
         request_hw_counter.get_counter(),
     )
     .await;
 
-    helpers::process_response(result, timing, request_hw_counter.to_rest_api())
+    // Explain only successful requests
+    let explanation = match explain_request.filter(|_| result.is_ok()) {
+        Some((count_request, shard_selector)) => {
+            let explanation = toc
+                .explain_count(
+                    &collection.collection_name,
+                    count_request,
+                    shard_selector,
+                    auth,
+                    params.timeout(),
+                    // The explanation executes the count again, which is billed as well
+                    request_hw_counter.get_counter(),
+                )
+                .await;
+            match explanation {
+                Ok(explanation) => Some(explanation),
+                Err(err) => {
+                    return process_response_error(err, timing, request_hw_counter.to_rest_api());
+                }
+            }
+        }
+        None => None,
+    };
+
+    helpers::process_response_with_explain(
+        result,
+        timing,
+        request_hw_counter.to_rest_api(),
+        None,
+        explanation,
+    )
 }
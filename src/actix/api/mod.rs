@@ -5,6 +5,7 @@ use validator::Validate;
 
 pub mod audit_api;
 pub mod cluster_api;
+pub mod clustering_api;
 pub mod collections_api;
 pub mod count_api;
 pub mod debug_api;
@@ -12,7 +13,9 @@ pub mod discover_api;
 pub mod facet_api;
 pub mod issues_api;
 pub mod local_shard_api;
+pub mod near_duplicates_api;
 pub mod profiler_api;
+pub mod quantization_api;
 pub mod query_api;
 pub mod read_params;
 pub mod recommend_api;
@@ -1,18 +1,26 @@
+use std::sync::Arc;
+
 use actix_web::{Responder, post, web};
 use actix_web_validator::{Json, Path, Query};
 use api::rest::models::InferenceUsage;
-use api::rest::{QueryGroupsRequest, QueryRequest, QueryRequestBatch, QueryResponse};
+use api::rest::{
+    FederatedQueryRequest, FederatedQueryResponse, FederatedScoredPoint, QueryGroupsRequest,
+    QueryRequest, QueryRequestBatch, QueryResponse,
+};
+use collection::lookup::join::WithJoin;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
+use common::counter::hardware_accumulator::HwMeasurementAcc;
 use itertools::Itertools;
 use storage::content_manager::collection_verification::{
     check_strict_mode, check_strict_mode_batch,
 };
 use storage::content_manager::errors::StorageError;
+use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
 use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
 
 use super::CollectionPath;
-use super::read_params::ReadParams;
+use super::read_params::{ExplainParams, ReadParams};
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{self, get_request_hardware_counter};
 use crate::common::inference::api_keys::InferenceApiKeys;
@@ -34,6 +42,7 @@ async fn query_points(
     collection: Path<CollectionPath>,
     request: Json<QueryRequest>,
     params: Query<ReadParams>,
+    explain_params: Query<ExplainParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
     api_keys: InferenceApiKeys,
@@ -41,6 +50,7 @@ async fn query_points(
     let QueryRequest {
         internal: query_request,
         shard_key,
+        with_join,
     } = request.into_inner();
 
     let request_hw_counter = get_request_hardware_counter(
@@ -57,6 +67,7 @@ async fn query_points(
     };
     let hw_measurement_acc = request_hw_counter.get_counter();
     let mut inference_usage = InferenceUsage::default();
+    let mut explanation = None;
 
     let inference_params = InferenceParams::new(api_keys, params.timeout());
 
@@ -75,34 +86,83 @@ async fn query_points(
         )
         .await?;
 
-        let points = dispatcher
-            .toc(&auth, &pass)
+        let pagination = request.pagination();
+        let toc = dispatcher.toc(&auth, &pass);
+
+        // The request is consumed by the query, so keep a copy to explain it afterwards
+        let explain_request = explain_params
+            .explain
+            .then(|| (request.clone(), shard_selection.clone()));
+
+        let points = toc
             .query_batch(
                 &collection.collection_name,
                 vec![(request, shard_selection)],
                 params.consistency,
-                auth,
+                auth.clone(),
                 params.timeout(),
-                hw_measurement_acc,
+                hw_measurement_acc.clone(),
             )
             .await?
             .pop()
             .ok_or_else(|| {
                 StorageError::service_error("Expected at least one response for one query")
-            })?
+            })?;
+
+        if let Some((request, shard_selection)) = explain_request {
+            explanation = Some(
+                toc.explain_query(
+                    &collection.collection_name,
+                    request,
+                    shard_selection,
+                    params.consistency,
+                    auth.clone(),
+                    params.timeout(),
+                    // The explanation executes the request again, which is billed as well
+                    hw_measurement_acc.clone(),
+                )
+                .await?,
+            );
+        }
+
+        let next_cursor = pagination
+            .and_then(|pagination| pagination.next_cursor(&points))
+            .map(|cursor| cursor.encode());
+
+        let mut points = points
             .into_iter()
             .map(api::rest::ScoredPoint::from)
             .collect_vec();
 
-        Ok(QueryResponse { points })
+        if let Some(with_join) = with_join {
+            toc.join_payloads(
+                &collection.collection_name,
+                &WithJoin::from(with_join),
+                points
+                    .iter_mut()
+                    .map(|point| (point.id, &mut point.payload))
+                    .collect(),
+                params.consistency,
+                &auth,
+                params.timeout(),
+                hw_measurement_acc,
+            )
+            .await?;
+        }
+
+        Ok(QueryResponse {
+            points,
+            next_cursor,
+        })
     }
     .await;
 
-    helpers::process_response_with_inference_usage(
+    helpers::process_response_with_explain(
         result,
         timing,
         request_hw_counter.to_rest_api(),
         inference_usage.into_non_empty(),
+        explanation,
     )
 }
 
@@ -134,11 +194,13 @@ async fn query_points_batch(
 
     let result = async {
         let mut batch = Vec::with_capacity(searches.len());
+        let mut joins = Vec::with_capacity(searches.len());
 
         for request_item in searches {
             let QueryRequest {
                 internal,
                 shard_key,
+                with_join,
             } = request_item;
 
             let CollectionQueryRequestWithUsage { request, usage } =
@@ -152,6 +214,7 @@ async fn query_points_batch(
             };
 
             batch.push((request, shard_selection));
+            joins.push(with_join.map(WithJoin::from));
         }
 
         let pass = check_strict_mode_batch(
@@ -164,25 +227,55 @@ async fn query_points_batch(
         )
         .await?;
 
-        let res = dispatcher
-            .toc(&auth, &pass)
+        let paginations = batch
+            .iter()
+            .map(|(request, _)| request.pagination())
+            .collect_vec();
+
+        let toc = dispatcher.toc(&auth, &pass);
+        let mut res = toc
             .query_batch(
                 &collection.collection_name,
                 batch,
                 params.consistency,
-                auth,
+                auth.clone(),
                 params.timeout(),
-                hw_measurement_acc,
+                hw_measurement_acc.clone(),
             )
             .await?
             .into_iter()
-            .map(|response| QueryResponse {
+            .zip(paginations)
+            .map(|(response, pagination)| QueryResponse {
+                next_cursor: pagination
+                    .and_then(|pagination| pagination.next_cursor(&response))
+                    .map(|cursor| cursor.encode()),
                 points: response
                     .into_iter()
                     .map(api::rest::ScoredPoint::from)
                     .collect_vec(),
             })
             .collect_vec();
+
+        for (response, with_join) in res.iter_mut().zip(joins) {
+            let Some(with_join) = with_join else {
+                continue;
+            };
+            toc.join_payloads(
+                &collection.collection_name,
+                &with_join,
+                response
+                    .points
+                    .iter_mut()
+                    .map(|point| (point.id, &mut point.payload))
+                    .collect(),
+                params.consistency,
+                &auth,
+                params.timeout(),
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+        }
+
         Ok(res)
     }
     .await;
@@ -265,8 +358,87 @@ async fn query_points_groups(
     )
 }
 
+#[post("/query")]
+async fn federated_query(
+    dispatcher: web::Data<Dispatcher>,
+    request: Json<FederatedQueryRequest>,
+    params: Query<ReadParams>,
+    service_config: web::Data<ServiceConfig>,
+    ActixAuth(auth): ActixAuth,
+    api_keys: InferenceApiKeys,
+) -> impl Responder {
+    let FederatedQueryRequest {
+        collections,
+        internal: query_request,
+        merge,
+    } = request.into_inner();
+
+    // Hardware usage is accounted to the metrics of each queried collection by the query itself
+    let request_hw_counter = RequestHwCounter::new(
+        HwMeasurementAcc::new_with_metrics_drain(Arc::default()),
+        service_config.hardware_reporting(),
+    );
+    let timing = Instant::now();
+    let hw_measurement_acc = request_hw_counter.get_counter();
+    let mut inference_usage = InferenceUsage::default();
+
+    let inference_params = InferenceParams::new(api_keys, params.timeout());
+
+    let result = async {
+        let CollectionQueryRequestWithUsage { request, usage } =
+            convert_query_request_from_rest(query_request, &inference_params).await?;
+
+        inference_usage.merge_opt(usage);
+
+        let mut pass = None;
+        for collection_name in &collections {
+            pass = Some(
+                check_strict_mode(
+                    &request,
+                    params.timeout_as_secs(),
+                    collection_name,
+                    &dispatcher,
+                    &auth,
+                )
+                .await?,
+            );
+        }
+        let pass = pass.ok_or_else(|| StorageError::bad_input("No collections to query"))?;
+
+        let points = dispatcher
+            .toc(&auth, &pass)
+            .federated_query(
+                &collections,
+                request,
+                merge.map(From::from),
+                params.consistency,
+                auth,
+                params.timeout(),
+                hw_measurement_acc,
+            )
+            .await?
+            .into_iter()
+            .map(|(collection, point)| FederatedScoredPoint {
+                collection,
+                point: api::rest::ScoredPoint::from(point),
+            })
+            .collect_vec();
+
+        Ok(FederatedQueryResponse { points })
+    }
+    .await;
+
+    helpers::process_response_with_inference_usage(
+        result,
+        timing,
+        request_hw_counter.to_rest_api(),
+        inference_usage.into_non_empty(),
+    )
+}
+
 pub fn config_query_api(cfg: &mut web::ServiceConfig) {
     cfg.service(query_points);
     cfg.service(query_points_batch);
     cfg.service(query_points_groups);
+    cfg.service(federated_query);
 }
@@ -25,6 +25,13 @@ impl ReadParams {
     }
 }
 
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Deserialize, JsonSchema, Validate)]
+pub struct ExplainParams {
+    /// If true, the response also explains how each shard and segment executed the request
+    #[serde(default)]
+    pub explain: bool,
+}
+
 fn deserialize_read_consistency<'de, D>(
     deserializer: D,
 ) -> Result<Option<ReadConsistency>, D::Error>
@@ -2,11 +2,11 @@ use std::time::Duration;
 
 use actix_web::{Responder, get, post, web};
 use actix_web_validator::{Json, Path, Query};
+use collection::lookup::join::WithJoin;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{PointRequest, PointRequestInternal, ScrollRequest};
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use futures::TryFutureExt;
 use itertools::Itertools;
 use segment::types::{PointIdType, WithPayloadInterface};
 use serde::Deserialize;
@@ -146,6 +146,7 @@ async fn get_points(
     let PointRequest {
         point_request,
         shard_key,
+        with_join,
     } = request.into_inner();
 
     let shard_selection = match shard_key {
@@ -161,22 +162,43 @@ async fn get_points(
     );
     let timing = Instant::now();
 
-    let res = do_get_points(
-        dispatcher.toc(&auth, &pass),
-        &collection.collection_name,
-        point_request,
-        params.consistency,
-        params.timeout(),
-        shard_selection,
-        auth,
-        request_hw_counter.get_counter(),
-    )
-    .map_ok(|response| {
-        response
-            .into_iter()
-            .map(api::rest::Record::from)
-            .collect_vec()
-    })
+    let toc = dispatcher.toc(&auth, &pass);
+    let hw_measurement_acc = request_hw_counter.get_counter();
+
+    let res = async {
+        let mut points = do_get_points(
+            toc,
+            &collection.collection_name,
+            point_request,
+            params.consistency,
+            params.timeout(),
+            shard_selection,
+            auth.clone(),
+            hw_measurement_acc.clone(),
+        )
+        .await?
+        .into_iter()
+        .map(api::rest::Record::from)
+        .collect_vec();
+
+        if let Some(with_join) = with_join {
+            toc.join_payloads(
+                &collection.collection_name,
+                &WithJoin::from(with_join),
+                points
+                    .iter_mut()
+                    .map(|point| (point.id, &mut point.payload))
+                    .collect(),
+                params.consistency,
+                &auth,
+                params.timeout(),
+                hw_measurement_acc,
+            )
+            .await?;
+        }
+
+        Ok(points)
+    }
     .await;
 
     process_response(res, timing, request_hw_counter.to_rest_api())
@@ -194,6 +216,7 @@ async fn scroll_points(
     let ScrollRequest {
         scroll_request,
         shard_key,
+        with_join,
     } = request.into_inner();
 
     let pass = match check_strict_mode(
@@ -222,18 +245,42 @@ async fn scroll_points(
     );
     let timing = Instant::now();
 
-    let res = dispatcher
-        .toc(&auth, &pass)
-        .scroll(
-            &collection.collection_name,
-            scroll_request,
-            params.consistency,
-            params.timeout(),
-            shard_selection,
-            auth,
-            request_hw_counter.get_counter(),
-        )
-        .await;
+    let toc = dispatcher.toc(&auth, &pass);
+    let hw_measurement_acc = request_hw_counter.get_counter();
+
+    let res = async {
+        let mut result = toc
+            .scroll(
+                &collection.collection_name,
+                scroll_request,
+                params.consistency,
+                params.timeout(),
+                shard_selection,
+                auth.clone(),
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+
+        if let Some(with_join) = with_join {
+            toc.join_payloads(
+                &collection.collection_name,
+                &WithJoin::from(with_join),
+                result
+                    .points
+                    .iter_mut()
+                    .map(|point| (point.id, &mut point.payload))
+                    .collect(),
+                params.consistency,
+                &auth,
+                params.timeout(),
+                hw_measurement_acc,
+            )
+            .await?;
+        }
+
+        Ok(result)
+    }
+    .await;
 
     process_response(res, timing, request_hw_counter.to_rest_api())
 }
@@ -12,10 +12,11 @@ use storage::dispatcher::Dispatcher;
 use tokio::time::Instant;
 
 use super::CollectionPath;
-use super::read_params::ReadParams;
+use super::read_params::{ExplainParams, ReadParams};
 use crate::actix::auth::ActixAuth;
 use crate::actix::helpers::{
     get_request_hardware_counter, process_response, process_response_error,
+    process_response_with_explain,
 };
 use crate::common::query::{
     do_core_search_points, do_search_batch_points, do_search_point_groups, do_search_points_matrix,
@@ -28,6 +29,7 @@ async fn search_points(
     collection: Path<CollectionPath>,
     request: Json<SearchRequest>,
     params: Query<ReadParams>,
+    explain_params: Query<ExplainParams>,
     service_config: web::Data<ServiceConfig>,
     ActixAuth(auth): ActixAuth,
 ) -> HttpResponse {
@@ -62,14 +64,19 @@ async fn search_points(
     );
 
     let timing = Instant::now();
+    let toc = dispatcher.toc(&auth, &pass);
+    let search_request = CoreSearchRequest::from(search_request);
+    let explain_request = explain_params
+        .explain
+        .then(|| (search_request.clone(), shard_selection.clone()));
 
     let result = do_core_search_points(
-        dispatcher.toc(&auth, &pass),
+        toc,
         &collection.collection_name,
-        search_request.into(),
+        search_request,
         params.consistency,
         shard_selection,
-        auth,
+        auth.clone(),
         params.timeout(),
         request_hw_counter.get_counter(),
     )
@@ -81,7 +88,37 @@ async fn search_points(
             .collect_vec()
     });
 
-    process_response(result, timing, request_hw_counter.to_rest_api())
+    // Explain only successful requests
+    let explanation = match explain_request.filter(|_| result.is_ok()) {
+        Some((search_request, shard_selection)) => {
+            let explanation = toc
+                .explain_search(
+                    &collection.collection_name,
+                    search_request,
+                    shard_selection,
+                    auth,
+                    params.timeout(),
+                    // The explanation executes the search again, which is billed as well
+                    request_hw_counter.get_counter(),
+                )
+                .await;
+            match explanation {
+                Ok(explanation) => Some(explanation),
+                Err(err) => {
+                    return process_response_error(err, timing, request_hw_counter.to_rest_api());
+                }
+            }
+        }
+        None => None,
+    };
+
+    process_response_with_explain(
+        result,
+        timing,
+        request_hw_counter.to_rest_api(),
+        None,
+        explanation,
+    )
 }
 
 #[post("/collections/{collection_name}/points/search/batch")]
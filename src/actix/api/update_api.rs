@@ -56,6 +56,7 @@ async fn upsert_points(
         StrictModeCheckedTocProvider::new(&dispatcher),
         collection.into_inner().collection_name,
         operation,
+        Vec::new(),
         InternalUpdateParams::default(),
         params.into_inner(),
         auth,
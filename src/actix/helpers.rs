@@ -5,7 +5,10 @@ use actix_web::http::header;
 use actix_web::http::header::HeaderMap;
 use actix_web::rt::time::Instant;
 use actix_web::{HttpResponse, ResponseError, http};
-use api::rest::models::{ApiResponse, ApiStatus, HardwareUsage, InferenceUsage, Usage};
+use api::rest::VersionConflictResult;
+use api::rest::models::{
+    ApiResponse, ApiStatus, Explanation, HardwareUsage, InferenceUsage, Usage,
+};
 use collection::operations::types::CollectionError;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use serde::Serialize;
@@ -46,6 +49,7 @@ pub fn accepted_response(
         status: ApiStatus::Accepted,
         time: timing.elapsed().as_secs_f64(),
         usage,
+        explain: None,
     })
 }
 
@@ -55,6 +59,20 @@ pub fn process_response_with_inference_usage<T>(
     hardware_usage: Option<HardwareUsage>,
     inference_usage: Option<InferenceUsage>,
 ) -> HttpResponse
+where
+    T: Serialize,
+{
+    process_response_with_explain(response, timing, hardware_usage, inference_usage, None)
+}
+
+/// Same as [`process_response_with_inference_usage`], but also reports how the request was executed
+pub fn process_response_with_explain<T>(
+    response: Result<T, StorageError>,
+    timing: Instant,
+    hardware_usage: Option<HardwareUsage>,
+    inference_usage: Option<InferenceUsage>,
+    explain: Option<Explanation>,
+) -> HttpResponse
 where
     T: Serialize,
 {
@@ -67,6 +85,7 @@ where
                 hardware: hardware_usage,
                 inference: inference_usage,
             }),
+            explain,
         }),
         Err(err) => process_response_error_with_inference_usage(
             err,
@@ -96,17 +115,25 @@ pub fn process_response_error_with_inference_usage(
 ) -> HttpResponse {
     log_service_error(&err);
 
+    // Conflicting points are returned as the result, so clients can handle them
+    let result = if let StorageError::VersionConflict { conflicts } = &err {
+        Some(VersionConflictResult::from(conflicts.clone()))
+    } else {
+        None
+    };
+
     let error = HttpError::from(err);
     let http_code = error.status_code();
     let headers = error.headers();
-    let json_body = ApiResponse::<()> {
-        result: None,
+    let json_body = ApiResponse {
+        result,
         status: ApiStatus::Error(error.to_string()),
         time: timing.elapsed().as_secs_f64(),
         usage: Some(Usage {
             hardware: hardware_usage,
             inference: inference_usage,
         }),
+        explain: None,
     };
 
     let mut response_builder = HttpResponse::build(http_code);
@@ -130,6 +157,7 @@ pub fn already_in_progress_response() -> HttpResponse {
         status: ApiStatus::AlreadyInProgress,
         time: 0.0,
         usage: None,
+        explain: None,
     })
 }
 
@@ -234,6 +262,7 @@ impl HttpError {
             StorageError::InferenceError { .. } => {}
             StorageError::ShardUnavailable { .. } => {}
             StorageError::EmptyPartialSnapshot { .. } => {}
+            StorageError::VersionConflict { .. } => {}
         }
         headers
     }
@@ -256,6 +285,7 @@ impl ResponseError for HttpError {
             StorageError::RateLimitExceeded { .. } => http::StatusCode::TOO_MANY_REQUESTS,
             StorageError::ShardUnavailable { .. } => http::StatusCode::SERVICE_UNAVAILABLE,
             StorageError::EmptyPartialSnapshot { .. } => http::StatusCode::NOT_MODIFIED,
+            StorageError::VersionConflict { .. } => http::StatusCode::CONFLICT,
         }
     }
 }
@@ -27,13 +27,16 @@ use storage::rbac::{Access, Auth};
 
 use crate::actix::api::audit_api::config_audit_api;
 use crate::actix::api::cluster_api::config_cluster_api;
+use crate::actix::api::clustering_api::config_clustering_api;
 use crate::actix::api::collections_api::config_collections_api;
 use crate::actix::api::count_api::count_points;
 use crate::actix::api::debug_api::config_debugger_api;
 use crate::actix::api::discover_api::config_discover_api;
 use crate::actix::api::issues_api::config_issues_api;
 use crate::actix::api::local_shard_api::config_local_shard_api;
+use crate::actix::api::near_duplicates_api::config_near_duplicates_api;
 use crate::actix::api::profiler_api::config_profiler_api;
+use crate::actix::api::quantization_api::config_quantization_api;
 use crate::actix::api::query_api::config_query_api;
 use crate::actix::api::recommend_api::config_recommend_api;
 use crate::actix::api::retrieve_api::{get_point, get_points, scroll_points};
@@ -158,6 +161,9 @@ pub fn init(
                 .configure(config_discover_api)
                 .configure(config_query_api)
                 .configure(config_facet_api)
+                .configure(config_quantization_api)
+                .configure(config_clustering_api)
+                .configure(config_near_duplicates_api)
                 .configure(config_shards_api)
                 .configure(config_issues_api)
                 .configure(config_debugger_api)
@@ -274,6 +280,7 @@ fn validation_error_handler(
         status: ApiStatus::Error(msg),
         time: 0.0,
         usage: None,
+        explain: None,
     });
     error::InternalError::from_response(err, response).into()
 }
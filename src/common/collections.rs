@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::num::NonZeroU32;
 use std::sync::Arc;
 use std::time::Duration;
@@ -7,6 +7,8 @@ use api::grpc::qdrant::CollectionExists;
 use api::rest::models::{
     CollectionDescription, CollectionsResponse, ShardKeyDescription, ShardKeysResponse,
 };
+use api::rest::{QuantizationTuningRequest, QuantizationTuningResponse};
+use collection::collection::quantization_tuning::CollectionQuantizationTuningRequest;
 use collection::config::ShardingMethod;
 #[cfg(feature = "staging")]
 use collection::operations::cluster_ops::TestSlowDownOperation;
@@ -15,10 +17,13 @@ use collection::operations::cluster_ops::{
     ReplicatePoints, ReplicatePointsOperation, ReplicateShardOperation, ReshardingDirection,
     RestartTransfer, RestartTransferOperation, StartResharding,
 };
+use collection::operations::config_diff::QuantizationConfigDiff;
+use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::snapshot_ops::SnapshotDescription;
 use collection::operations::types::{
     AliasDescription, CollectionClusterInfo, CollectionInfo, CollectionsAliasesResponse,
+    VectorParamsDiff, VectorsConfigDiff,
 };
 use collection::operations::verification::new_unchecked_verification_pass;
 use collection::shards::replica_set;
@@ -28,6 +33,7 @@ use collection::shards::shard::{PeerId, ShardId, ShardsPlacement};
 use collection::shards::transfer::{
     ShardTransfer, ShardTransferKey, ShardTransferMethod, ShardTransferRestart,
 };
+use common::counter::hardware_accumulator::HwMeasurementAcc;
 use itertools::Itertools;
 use rand::prelude::SliceRandom;
 use rand::seq::IteratorRandom;
@@ -245,6 +251,74 @@ pub async fn do_get_collection_cluster(
     Ok(collection.cluster_info(toc.this_peer_id).await?)
 }
 
+/// Evaluate quantization configs on a sample of points.
+///
+/// If requested, the cheapest config reaching the target recall is applied to the vector.
+pub async fn do_tune_quantization(
+    dispatcher: &Dispatcher,
+    collection_name: &str,
+    request: QuantizationTuningRequest,
+    read_consistency: Option<ReadConsistency>,
+    auth: Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<QuantizationTuningResponse, StorageError> {
+    let apply = request.apply.unwrap_or_default();
+    if apply && request.target_recall.is_none() {
+        return Err(StorageError::bad_input(
+            "`target_recall` is required to apply the recommended quantization config",
+        ));
+    }
+
+    let shard_selection = match request.shard_key.clone() {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+    let request = CollectionQuantizationTuningRequest::from(request);
+    let vector_name = request.using.clone();
+
+    let pass = new_unchecked_verification_pass();
+    let tuning_response = dispatcher
+        .toc(&auth, &pass)
+        .tune_quantization(
+            collection_name,
+            request,
+            read_consistency,
+            shard_selection,
+            auth.clone(),
+            timeout,
+            hw_measurement_acc,
+        )
+        .await?;
+
+    let recommended = tuning_response
+        .recommended()
+        .map(|result| result.quantization.clone());
+    let mut response = QuantizationTuningResponse::from(tuning_response);
+
+    if apply && let Some(quantization) = recommended {
+        let vector_params_diff = VectorParamsDiff {
+            hnsw_config: None,
+            quantization_config: Some(QuantizationConfigDiff::from(quantization)),
+            on_disk: None,
+        };
+        let mut operation = UpdateCollectionOperation::new_empty(collection_name.to_string());
+        operation.update_collection.vectors = Some(VectorsConfigDiff(BTreeMap::from([(
+            vector_name,
+            vector_params_diff,
+        )])));
+        response.applied = dispatcher
+            .submit_collection_meta_op(
+                CollectionMetaOperations::UpdateCollection(operation),
+                auth,
+                timeout,
+            )
+            .await?;
+    }
+
+    Ok(response)
+}
+
 pub async fn do_update_collection_cluster(
     dispatcher: &Dispatcher,
     collection_name: String,
@@ -190,6 +190,7 @@ pub fn collect_query_request(request: &QueryRequestInternal) -> BatchAccum {
         with_vector: _,
         with_payload: _,
         lookup_from: _,
+        cursor: _,
     } = request;
 
     if let Some(query) = query {
@@ -207,8 +208,8 @@ pub fn collect_query_request(request: &QueryRequestInternal) -> BatchAccum {
 
 #[cfg(test)]
 mod tests {
-    use api::rest::QueryBaseGroupRequest;
     use api::rest::schema::{DiscoverQuery, Document, Image, InferenceObject, NearestQuery};
+    use api::rest::{GroupByInterface, QueryBaseGroupRequest};
     use serde_json::json;
 
     use super::*;
@@ -357,10 +358,11 @@ mod tests {
             with_payload: None,
             lookup_from: None,
             group_request: QueryBaseGroupRequest {
-                group_by: "test".parse().unwrap(),
+                group_by: GroupByInterface::Field("test".parse().unwrap()),
                 group_size: None,
                 limit: None,
                 with_lookup: None,
+                subgroups: None,
             },
         };
 
@@ -186,6 +186,8 @@ pub(crate) fn collect_query(query: &Query, batch: &mut BatchAccumGrpc) -> Result
         query::Variant::Fusion(_) => {}
         query::Variant::Rrf(_) => {}
         query::Variant::Sample(_) => {}
+        query::Variant::StratifiedSample(_) => {}
+        query::Variant::WeightedSample(_) => {}
         query::Variant::Formula(_) => {}
         query::Variant::NearestWithMmr(nearest_with_mmr) => {
             nearest_with_mmr
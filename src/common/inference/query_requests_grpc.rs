@@ -7,8 +7,11 @@ use collection::operations::universal_query::collection_query::{
     CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, FeedbackInternal,
     FeedbackStrategy, Mmr, NearestWithMmr, Query, VectorInputInternal, VectorQuery,
 };
+use collection::operations::universal_query::cursor::QueryCursor;
 use collection::operations::universal_query::formula::FormulaInternal;
-use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
+use collection::operations::universal_query::shard_query::{
+    FusionInternal, SampleInternal, StratifiedSample, WeightedSample,
+};
 use ordered_float::OrderedFloat;
 use segment::data_types::order_by::OrderBy;
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, MultiDenseVectorInternal, VectorInternal};
@@ -92,15 +95,16 @@ pub async fn convert_query_point_groups_from_grpc(
             .transpose()?
             .unwrap_or(CollectionQueryRequest::DEFAULT_WITH_PAYLOAD),
         lookup_from: lookup_from.map(LookupLocation::try_from).transpose()?,
-        group_by: json_path_from_proto(&group_by)?,
+        group_by: vec![json_path_from_proto(&group_by)?],
         group_size: group_size
             .map(|s| s as usize)
             .unwrap_or(CollectionQueryRequest::DEFAULT_GROUP_SIZE),
         limit: limit
             .map(|l| l as usize)
             .unwrap_or(CollectionQueryRequest::DEFAULT_LIMIT),
-        params: params.map(From::from),
+        params: params.map(TryFrom::try_from).transpose()?,
         with_lookup: with_lookup.map(TryFrom::try_from).transpose()?,
+        subgroups: None,
     };
 
     Ok((request, usage.unwrap_or_default().into()))
@@ -127,8 +131,17 @@ pub async fn convert_query_points_from_grpc(
         shard_key_selector: _,
         lookup_from,
         timeout: _,
+        cursor,
+        with_join: _,
+        explain: _,
     } = query;
 
+    let cursor = cursor
+        .as_deref()
+        .map(QueryCursor::decode)
+        .transpose()
+        .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
     let mut batch = BatchAccumGrpc::new();
 
     if let Some(q) = &query {
@@ -168,7 +181,7 @@ pub async fn convert_query_points_from_grpc(
             offset: offset
                 .map(|o| o as usize)
                 .unwrap_or(CollectionQueryRequest::DEFAULT_OFFSET),
-            params: params.map(From::from),
+            params: params.map(TryFrom::try_from).transpose()?,
             with_vector: with_vectors
                 .map(From::from)
                 .unwrap_or(CollectionQueryRequest::DEFAULT_WITH_VECTOR),
@@ -177,6 +190,7 @@ pub async fn convert_query_points_from_grpc(
                 .transpose()?
                 .unwrap_or(CollectionQueryRequest::DEFAULT_WITH_PAYLOAD),
             lookup_from: lookup_from.map(LookupLocation::try_from).transpose()?,
+            cursor,
         },
         usage.unwrap_or_default().into(),
     ))
@@ -215,7 +229,7 @@ fn convert_prefetch_with_inferred(
         limit: limit
             .map(|l| l as usize)
             .unwrap_or(CollectionQueryRequest::DEFAULT_LIMIT),
-        params: params.map(SearchParams::from),
+        params: params.map(SearchParams::try_from).transpose()?,
         lookup_from: lookup_from.map(LookupLocation::try_from).transpose()?,
     })
 }
@@ -296,6 +310,12 @@ fn convert_query_with_inferred(
         Variant::Rrf(rrf) => Query::Fusion(FusionInternal::try_from(rrf)?),
         Variant::Formula(formula) => Query::Formula(FormulaInternal::try_from(formula)?),
         Variant::Sample(sample) => Query::Sample(SampleInternal::try_from(sample)?),
+        Variant::StratifiedSample(stratified) => Query::Sample(SampleInternal::Stratified(
+            StratifiedSample::try_from(stratified)?,
+        )),
+        Variant::WeightedSample(weighted) => Query::Sample(SampleInternal::Weighted(
+            WeightedSample::try_from(weighted)?,
+        )),
         Variant::NearestWithMmr(grpc::NearestInputWithMmr { nearest, mmr }) => {
             let nearest =
                 nearest.ok_or_else(|| Status::invalid_argument("nearest vector is missing"))?;
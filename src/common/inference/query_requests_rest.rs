@@ -1,10 +1,12 @@
 use api::rest::models::InferenceUsage;
 use api::rest::schema as rest;
+use collection::grouping::group_by::SubgroupRequest;
 use collection::lookup::WithLookup;
 use collection::operations::universal_query::collection_query::{
     CollectionPrefetch, CollectionQueryGroupsRequest, CollectionQueryRequest, FeedbackInternal,
     FeedbackStrategy, Mmr, NearestWithMmr, Query, VectorInputInternal, VectorQuery,
 };
+use collection::operations::universal_query::cursor::QueryCursor;
 use collection::operations::universal_query::formula::FormulaInternal;
 use collection::operations::universal_query::shard_query::{FusionInternal, SampleInternal};
 use ordered_float::OrderedFloat;
@@ -80,11 +82,12 @@ pub async fn convert_query_groups_request_from_rest(
         limit: group_request
             .limit
             .unwrap_or(CollectionQueryRequest::DEFAULT_LIMIT),
-        group_by: group_request.group_by,
+        group_by: group_request.group_by.into_paths(),
         group_size: group_request
             .group_size
             .unwrap_or(CollectionQueryRequest::DEFAULT_GROUP_SIZE),
         with_lookup: group_request.with_lookup.map(WithLookup::from),
+        subgroups: group_request.subgroups.map(SubgroupRequest::from),
     };
 
     Ok(CollectionQueryGroupsRequestWithUsage {
@@ -114,8 +117,11 @@ pub async fn convert_query_request_from_rest(
         with_vector,
         with_payload,
         lookup_from,
+        cursor,
     } = request;
 
+    let cursor = cursor.as_deref().map(QueryCursor::decode).transpose()?;
+
     let prefetch = prefetch
         .map(|prefetches| {
             prefetches
@@ -142,6 +148,7 @@ pub async fn convert_query_request_from_rest(
         with_vector: with_vector.unwrap_or(CollectionQueryRequest::DEFAULT_WITH_VECTOR),
         with_payload: with_payload.unwrap_or(CollectionQueryRequest::DEFAULT_WITH_PAYLOAD),
         lookup_from,
+        cursor,
     };
     Ok(CollectionQueryRequestWithUsage {
         request: collection_query_request,
@@ -147,6 +147,7 @@ pub async fn convert_point_struct(
             id,
             vector: converted_vector_struct,
             payload,
+            version: None,
         };
 
         converted_points.push(converted);
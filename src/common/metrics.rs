@@ -240,6 +240,9 @@ impl CollectionsTelemetry {
 
         let mut vector_count_by_name = Vec::with_capacity(num_collections);
 
+        // Recall of vector indexes, measured by the recall monitor
+        let mut vector_recall = Vec::with_capacity(num_collections);
+
         // Shard transfers
         let mut shard_transfers_in = Vec::with_capacity(num_collections);
         let mut shard_transfers_out = Vec::with_capacity(num_collections);
@@ -329,6 +332,13 @@ impl CollectionsTelemetry {
                 ))
             }
 
+            for (vec_name, recall) in collection.recall.iter().flatten() {
+                vector_recall.push(gauge(
+                    recall.recall,
+                    &[("collection", &collection.id), ("vector", vec_name)],
+                ))
+            }
+
             let points_excluded_from_index_only = collection
                 .shards
                 .iter()
@@ -451,6 +461,14 @@ impl CollectionsTelemetry {
             prefix,
         ));
 
+        metrics.push_metric(metric_family(
+            "collection_vector_recall",
+            "recall@k of vector index measured on local shards, grouped by vector name",
+            MetricType::GAUGE,
+            vector_recall,
+            prefix,
+        ));
+
         metrics.push_metric(metric_family(
             "collection_indexed_only_excluded_points",
             "amount of points excluded in indexed_only requests",
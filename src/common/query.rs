@@ -1,22 +1,33 @@
 use std::time::Duration;
 
-use api::rest::SearchGroupsRequestInternal;
+use api::rest::{
+    ClusteringRequest, ClusteringResponse, NearDuplicatesRequest, NearDuplicatesResponse,
+    SearchGroupsRequestInternal,
+};
+use collection::collection::clustering::CollectionClusteringRequest;
 use collection::collection::distance_matrix::*;
+use collection::collection::near_duplicates::CollectionNearDuplicatesRequest;
 use collection::common::batching::batch_requests;
 use collection::grouping::group_by::GroupRequest;
 use collection::operations::consistency_params::ReadConsistency;
+use collection::operations::point_ops::{PointIdsList, PointsSelector, WriteOrdering};
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::*;
 use collection::operations::universal_query::collection_query::*;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
-use segment::types::ScoredPoint;
+use segment::types::{Payload, ScoredPoint};
+use shard::operations::payload_ops::SetPayload;
 use shard::retrieve::record_internal::RecordInternal;
 use shard::scroll::ScrollRequestInternal;
 use shard::search::CoreSearchRequestBatch;
 use storage::content_manager::errors::StorageError;
 use storage::content_manager::toc::TableOfContent;
+use storage::dispatcher::Dispatcher;
 use storage::rbac::Auth;
 
+use crate::common::strict_mode::{CheckedTocProvider as _, StrictModeCheckedTocProvider};
+use crate::common::update::{InternalUpdateParams, UpdateParams, do_delete_points, do_set_payload};
+
 #[allow(clippy::too_many_arguments)]
 pub async fn do_core_search_points(
     toc: &TableOfContent,
@@ -361,3 +372,179 @@ pub async fn do_search_points_matrix(
     )
     .await
 }
+
+/// Cluster points of the collection by a dense vector.
+///
+/// If requested, the cluster id of every clustered point is written into its payload.
+/// Payload is written cluster by cluster within the remaining timeout, so on failure
+/// only a part of the points may be updated.
+pub async fn do_cluster_points(
+    dispatcher: &Dispatcher,
+    collection_name: &str,
+    request: ClusteringRequest,
+    read_consistency: Option<ReadConsistency>,
+    auth: Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<ClusteringResponse, StorageError> {
+    let start = std::time::Instant::now();
+    let with_assignments = request.with_assignments.unwrap_or(true);
+    let payload_key = request.payload_key.clone();
+    let shard_key = request.shard_key.clone();
+
+    let shard_selection = match shard_key.clone() {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+    let request = CollectionClusteringRequest::from(request);
+
+    let toc = StrictModeCheckedTocProvider::new(dispatcher)
+        .check_strict_mode(
+            &request,
+            collection_name,
+            timeout.map(|timeout| timeout.as_secs() as usize),
+            &auth,
+        )
+        .await?;
+
+    let clustering_response = toc
+        .cluster_points(
+            collection_name,
+            request,
+            read_consistency,
+            shard_selection,
+            auth.clone(),
+            timeout,
+            hw_measurement_acc.clone(),
+        )
+        .await?;
+
+    if let Some(payload_key) = payload_key {
+        let members = clustering_response.cluster_members();
+        for (cluster, points) in members.into_iter().enumerate() {
+            if points.is_empty() {
+                continue;
+            }
+
+            let payload = Payload::from(serde_json::Map::from_iter([(
+                payload_key.clone(),
+                serde_json::Value::from(cluster),
+            )]));
+            let operation = SetPayload {
+                payload,
+                points: Some(points),
+                filter: None,
+                shard_key: shard_key.clone(),
+                key: None,
+                if_version: None,
+            };
+            let remaining_timeout = match timeout {
+                Some(timeout) => match timeout.checked_sub(start.elapsed()) {
+                    Some(remaining) if !remaining.is_zero() => Some(remaining),
+                    Some(_) | None => {
+                        return Err(StorageError::timeout(timeout, "cluster_points"));
+                    }
+                },
+                None => None,
+            };
+            let params = UpdateParams {
+                wait: true,
+                ordering: WriteOrdering::default(),
+                timeout: remaining_timeout,
+            };
+
+            do_set_payload(
+                StrictModeCheckedTocProvider::new(dispatcher),
+                collection_name.to_string(),
+                operation,
+                InternalUpdateParams::default(),
+                params,
+                auth.clone(),
+                hw_measurement_acc.clone(),
+            )
+            .await?;
+        }
+    }
+
+    let mut response = ClusteringResponse::from(clustering_response);
+    if !with_assignments {
+        response.assignments = None;
+    }
+
+    Ok(response)
+}
+
+/// Find groups of near-duplicate points of the collection.
+///
+/// If requested, only the representative of every group is kept and the rest of its points are deleted.
+pub async fn do_find_near_duplicates(
+    dispatcher: &Dispatcher,
+    collection_name: &str,
+    request: NearDuplicatesRequest,
+    read_consistency: Option<ReadConsistency>,
+    auth: Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<NearDuplicatesResponse, StorageError> {
+    let delete_duplicates = request.delete_duplicates.unwrap_or(false);
+    let shard_key = request.shard_key.clone();
+
+    let shard_selection = match shard_key.clone() {
+        None => ShardSelectorInternal::All,
+        Some(shard_keys) => shard_keys.into(),
+    };
+    let request = CollectionNearDuplicatesRequest::from(request);
+
+    let toc = StrictModeCheckedTocProvider::new(dispatcher)
+        .check_strict_mode(
+            &request,
+            collection_name,
+            timeout.map(|timeout| timeout.as_secs() as usize),
+            &auth,
+        )
+        .await?;
+
+    let duplicates_response = toc
+        .find_near_duplicates(
+            collection_name,
+            request,
+            read_consistency,
+            shard_selection,
+            auth.clone(),
+            timeout,
+            hw_measurement_acc.clone(),
+        )
+        .await?;
+
+    let duplicate_ids = duplicates_response.duplicate_ids();
+    let mut response = NearDuplicatesResponse::from(duplicates_response);
+
+    if delete_duplicates && !duplicate_ids.is_empty() {
+        let deleted = duplicate_ids.len();
+        let points = PointsSelector::PointIdsSelector(PointIdsList {
+            points: duplicate_ids,
+            shard_key,
+            if_version: None,
+        });
+        let params = UpdateParams {
+            wait: true,
+            ordering: WriteOrdering::default(),
+            timeout,
+        };
+
+        do_delete_points(
+            StrictModeCheckedTocProvider::new(dispatcher),
+            collection_name.to_string(),
+            points,
+            InternalUpdateParams::default(),
+            params,
+            auth,
+            hw_measurement_acc,
+        )
+        .await?;
+
+        response.deleted = deleted;
+    }
+
+    Ok(response)
+}
@@ -4,7 +4,11 @@ use std::time::Duration;
 use api::rest::models::InferenceUsage;
 use api::rest::*;
 use collection::collection::Collection;
+use collection::events::PayloadSchemaViolationEvent;
 use collection::operations::conversions::write_ordering_from_proto;
+use collection::operations::payload_validation::{
+    PayloadSchemaViolation, PayloadValidationConfig, PayloadValidationMode,
+};
 use collection::operations::point_ops::*;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{CollectionError, CollectionResult, UpdateResult};
@@ -12,9 +16,12 @@ use collection::operations::vector_ops::*;
 use collection::operations::verification::*;
 use collection::shards::shard::ShardId;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
+use itertools::Itertools as _;
 use schemars::JsonSchema;
 use segment::json_path::JsonPath;
-use segment::types::{Filter, PayloadFieldSchema, PayloadKeyType, StrictModeConfig};
+use segment::types::{
+    ExpectedPointVersion, Filter, PayloadFieldSchema, PayloadKeyType, PointIdType, StrictModeConfig,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::DurationSeconds;
 use shard::operations::payload_ops::*;
@@ -314,6 +321,7 @@ pub async fn do_upsert_points(
     toc_provider: impl CheckedTocProvider,
     collection_name: String,
     operation: PointInsertOperations,
+    point_versions: Vec<ExpectedPointVersion>,
     internal_params: InternalUpdateParams,
     params: UpdateParams,
     auth: Auth,
@@ -321,7 +329,6 @@ pub async fn do_upsert_points(
     hw_measurement_acc: HwMeasurementAcc,
 ) -> Result<(UpdateResult, Option<models::InferenceUsage>), StorageError> {
     use point_ops::UpdateMode;
-    use segment::types::Filter;
 
     // The REST handler already runs this via `actix_web_validator::Json`, but the
     // gRPC handler does not — without this, empty vectors entering through gRPC
@@ -339,18 +346,26 @@ pub async fn do_upsert_points(
         )
         .await?;
 
-    let (operation, shard_key, usage, update_filter, update_mode) = match operation {
+    let (operation, shard_key, usage, update_filter, update_mode, if_version) = match operation {
         PointInsertOperations::PointsBatch(batch) => {
             let PointsBatch {
                 batch,
                 shard_key,
                 update_filter,
                 update_mode,
+                if_version,
             } = batch;
             let (batch, usage) = convert_batch(batch, inference_params).await?;
             let operation = PointInsertOperationsInternal::PointsBatch(batch);
             let update_mode = update_mode.map(rest_update_mode_to_internal);
-            (operation, shard_key, usage, update_filter, update_mode)
+            (
+                operation,
+                shard_key,
+                usage,
+                update_filter,
+                update_mode,
+                if_version,
+            )
         }
         PointInsertOperations::PointsList(list) => {
             let PointsList {
@@ -358,12 +373,21 @@ pub async fn do_upsert_points(
                 shard_key,
                 update_filter,
                 update_mode,
+                if_version,
             } = list;
-            let (list, usage) =
+            let (mut list, usage) =
                 convert_point_struct(points, InferenceType::Update, inference_params).await?;
+            PointStructPersisted::set_versions(&mut list, &point_versions);
             let operation = PointInsertOperationsInternal::PointsList(list);
             let update_mode = update_mode.map(rest_update_mode_to_internal);
-            (operation, shard_key, usage, update_filter, update_mode)
+            (
+                operation,
+                shard_key,
+                usage,
+                update_filter,
+                update_mode,
+                if_version,
+            )
         }
     };
 
@@ -377,32 +401,37 @@ pub async fn do_upsert_points(
         let collection = toc.get_collection(&collection_pass).await?;
         let vectors_config = collection.vectors_config().await;
         validate_vector_dimensions(&operation, &vectors_config)?;
+
+        check_payload_schema(&collection, &collection_name, |config| {
+            operation
+                .payloads()
+                .into_iter()
+                .flat_map(|payload| config.validate_payload(payload))
+                .unique_by(|violation| violation.field.clone())
+                .collect()
+        })
+        .await?;
+    }
+
+    if let Some(if_version) = &if_version {
+        check_if_version_points(if_version, &operation.point_ids())?;
     }
 
-    // Decide which operation to use based on update_filter and update_mode
-    let operation = match (update_filter, update_mode) {
-        // If update_filter is provided, always use conditional upsert
-        (Some(condition), mode) => CollectionUpdateOperations::PointOperation(
+    // Decide which operation to use based on update_filter, update_mode and if_version
+    let operation = match (update_filter, update_mode, if_version) {
+        // Default: regular upsert
+        (None, None | Some(UpdateMode::Upsert), None) => {
+            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(operation))
+        }
+        // If update_filter, non-default update_mode or versions are provided, use conditional upsert
+        (condition, update_mode, if_version) => CollectionUpdateOperations::PointOperation(
             PointOperations::UpsertPointsConditional(ConditionalInsertOperationInternal {
                 points_op: operation,
-                condition,
-                update_mode: mode,
+                condition: condition.unwrap_or_default(), // Empty filter matches all existing points
+                update_mode,
+                if_version,
             }),
         ),
-        // If update_mode is InsertOnly or UpdateOnly, use conditional upsert with empty filter
-        (None, Some(UpdateMode::InsertOnly)) | (None, Some(UpdateMode::UpdateOnly)) => {
-            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPointsConditional(
-                ConditionalInsertOperationInternal {
-                    points_op: operation,
-                    condition: Filter::default(), // Empty filter matches all existing points
-                    update_mode,
-                },
-            ))
-        }
-        // Default: regular upsert
-        (None, None) | (None, Some(UpdateMode::Upsert)) => {
-            CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(operation))
-        }
     };
 
     let result = update(
@@ -420,6 +449,55 @@ pub async fn do_upsert_points(
     Ok((result, usage))
 }
 
+/// Check payloads against the payload schema of the collection, if it has one.
+///
+/// Violations are reported as collection issues, and fail the operation in `reject` mode.
+async fn check_payload_schema(
+    collection: &Collection,
+    collection_name: &str,
+    validate: impl FnOnce(&PayloadValidationConfig) -> Vec<PayloadSchemaViolation>,
+) -> Result<(), StorageError> {
+    let Some(config) = collection.payload_validation_config().await else {
+        return Ok(());
+    };
+
+    let violations = validate(&config);
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    let description = violations.iter().join("; ");
+    issues::publish(PayloadSchemaViolationEvent {
+        collection_id: collection_name.to_string(),
+        violations,
+    });
+
+    match config.mode {
+        PayloadValidationMode::Reject => Err(StorageError::bad_input(format!(
+            "Payload does not match the payload schema of the collection: {description}"
+        ))),
+        PayloadValidationMode::Warn => Ok(()),
+    }
+}
+
+/// Check that versions are only expected for points, which are affected by the operation
+fn check_if_version_points(
+    if_version: &[ExpectedPointVersion],
+    point_ids: &[PointIdType],
+) -> Result<(), StorageError> {
+    let point_ids: ahash::AHashSet<_> = point_ids.iter().collect();
+    if let Some(expected) = if_version
+        .iter()
+        .find(|expected| !point_ids.contains(&expected.id))
+    {
+        return Err(StorageError::bad_input(format!(
+            "if_version refers to point {}, which is not affected by the operation",
+            expected.id,
+        )));
+    }
+    Ok(())
+}
+
 /// Convert REST UpdateMode to internal UpdateMode
 fn rest_update_mode_to_internal(mode: api::rest::schema::UpdateMode) -> point_ops::UpdateMode {
     match mode {
@@ -443,9 +521,23 @@ pub async fn do_delete_points(
         .await?;
 
     let (operation, shard_key) = match points {
-        PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
-            (PointOperations::DeletePoints { ids: points }, shard_key)
-        }
+        PointsSelector::PointIdsSelector(PointIdsList {
+            points,
+            shard_key,
+            if_version,
+        }) => match if_version {
+            Some(if_version) => {
+                check_if_version_points(&if_version, &points)?;
+                (
+                    PointOperations::DeletePointsConditional(ConditionalDeleteOperationInternal {
+                        ids: points,
+                        if_version,
+                    }),
+                    shard_key,
+                )
+            }
+            None => (PointOperations::DeletePoints { ids: points }, shard_key),
+        },
         PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
             (PointOperations::DeletePointsByFilter(filter), shard_key)
         }
@@ -615,14 +707,32 @@ pub async fn do_set_payload(
         filter,
         shard_key,
         key,
+        if_version,
     } = operation;
 
+    if let Some(if_version) = &if_version {
+        check_if_version_points(if_version, points.as_deref().unwrap_or_default())?;
+    }
+
+    {
+        let collection_pass = auth
+            .unlogged_access()
+            .check_collection_access(&collection_name, AccessRequirements::new())?;
+        let collection = toc.get_collection(&collection_pass).await?;
+        check_payload_schema(&collection, &collection_name, |config| match &key {
+            Some(key) => config.validate_nested_key(&key.first_key),
+            None => config.validate_partial_payload(&payload),
+        })
+        .await?;
+    }
+
     let operation =
         CollectionUpdateOperations::PayloadOperation(PayloadOps::SetPayload(SetPayloadOp {
             payload,
             points,
             filter,
             key,
+            if_version,
         }));
 
     update(
@@ -662,8 +772,24 @@ pub async fn do_overwrite_payload(
         filter,
         shard_key,
         key: _,
+        if_version,
     } = operation;
 
+    if let Some(if_version) = &if_version {
+        check_if_version_points(if_version, points.as_deref().unwrap_or_default())?;
+    }
+
+    {
+        let collection_pass = auth
+            .unlogged_access()
+            .check_collection_access(&collection_name, AccessRequirements::new())?;
+        let collection = toc.get_collection(&collection_pass).await?;
+        check_payload_schema(&collection, &collection_name, |config| {
+            config.validate_payload(Some(&payload))
+        })
+        .await?;
+    }
+
     let operation =
         CollectionUpdateOperations::PayloadOperation(PayloadOps::OverwritePayload(SetPayloadOp {
             payload,
@@ -671,6 +797,7 @@ pub async fn do_overwrite_payload(
             filter,
             // overwrite operation doesn't support payload selector
             key: None,
+            if_version,
         }));
 
     update(
@@ -745,7 +872,16 @@ pub async fn do_clear_payload(
         .await?;
 
     let (point_operation, shard_key) = match points {
-        PointsSelector::PointIdsSelector(PointIdsList { points, shard_key }) => {
+        PointsSelector::PointIdsSelector(PointIdsList {
+            points,
+            shard_key,
+            if_version,
+        }) => {
+            if if_version.is_some() {
+                return Err(StorageError::bad_input(
+                    "if_version is not supported for clear payload operation",
+                ));
+            }
             (PayloadOps::ClearPayload { points }, shard_key)
         }
         PointsSelector::FilterSelector(FilterSelector { filter, shard_key }) => {
@@ -807,6 +943,7 @@ pub async fn do_batch_update_points(
                     toc_provider.clone(),
                     collection_name.clone(),
                     operation.upsert,
+                    Vec::new(),
                     internal_params,
                     params,
                     auth.clone(),
@@ -1280,6 +1417,7 @@ pub async fn update(
         | CollectionUpdateOperations::PointOperation(PointOperations::UpsertPoints(_))
         | CollectionUpdateOperations::PointOperation(PointOperations::UpsertPointsConditional(_))
         | CollectionUpdateOperations::PointOperation(PointOperations::DeletePoints { .. })
+        | CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsConditional(_))
         | CollectionUpdateOperations::PointOperation(PointOperations::DeletePointsByFilter(_))
         | CollectionUpdateOperations::PayloadOperation(_) => {
             get_shard_selector_for_update(shard_id, shard_key)
@@ -1588,6 +1588,8 @@ mod tests {
                                 strict_mode_config: None,
                                 uuid: None,
                                 metadata: None,
+                                payload_validation: None,
+                                point_versions: None,
                             },
                         )
                         .unwrap(),
@@ -1,8 +1,11 @@
 use std::time::Duration;
 
-use collection::events::{CollectionDeletedEvent, IndexCreatedEvent, SlowQueryEvent};
+use collection::events::{
+    CollectionDeletedEvent, IndexCreatedEvent, PayloadSchemaViolationEvent,
+    PayloadValidationUpdatedEvent, SlowQueryEvent,
+};
 use collection::problems::unindexed_field;
-use storage::issues_subscribers::UnindexedFieldSubscriber;
+use storage::issues_subscribers::{PayloadSchemaViolationSubscriber, UnindexedFieldSubscriber};
 
 use crate::settings::Settings;
 
@@ -17,4 +20,14 @@ pub fn setup_subscribers(settings: &Settings) {
     issues::broker::add_subscriber::<SlowQueryEvent>(Box::new(unindexed_subscriber));
     issues::broker::add_subscriber::<IndexCreatedEvent>(Box::new(unindexed_subscriber));
     issues::broker::add_subscriber::<CollectionDeletedEvent>(Box::new(unindexed_subscriber));
+
+    let payload_schema_subscriber = PayloadSchemaViolationSubscriber;
+
+    issues::broker::add_subscriber::<PayloadSchemaViolationEvent>(Box::new(
+        payload_schema_subscriber,
+    ));
+    issues::broker::add_subscriber::<PayloadValidationUpdatedEvent>(Box::new(
+        payload_schema_subscriber,
+    ));
+    issues::broker::add_subscriber::<CollectionDeletedEvent>(Box::new(payload_schema_subscriber));
 }
@@ -57,6 +57,7 @@ pub async fn handle_existing_collections(
             strict_mode_config,
             uuid,
             metadata,
+            payload_validation,
         } = config;
 
         let shards_number = params.shard_number.get();
@@ -79,6 +80,8 @@ pub async fn handle_existing_collections(
                 strict_mode_config,
                 uuid,
                 metadata,
+                payload_validation,
+                point_versions: Some(params.point_versions),
             },
         )
         .expect("Failed to create collection operation");
@@ -1,11 +1,13 @@
 #![allow(dead_code)]
 
-use api::rest::models::{CollectionsResponse, ShardKeysResponse, Usage, VersionInfo};
+use api::rest::models::{CollectionsResponse, Explanation, ShardKeysResponse, Usage, VersionInfo};
 use api::rest::schema::PointInsertOperations;
 use api::rest::{
-    FacetRequest, FacetResponse, QueryGroupsRequest, QueryRequest, QueryRequestBatch,
-    QueryResponse, Record, ScoredPoint, SearchMatrixOffsetsResponse, SearchMatrixPairsResponse,
-    SearchMatrixRequest, UpdateVectors,
+    ClusteringRequest, ClusteringResponse, FacetRequest, FacetResponse, FederatedQueryRequest,
+    FederatedQueryResponse, NearDuplicatesRequest, NearDuplicatesResponse,
+    QuantizationTuningRequest, QuantizationTuningResponse, QueryGroupsRequest, QueryRequest,
+    QueryRequestBatch, QueryResponse, Record, ScoredPoint, SearchMatrixOffsetsResponse,
+    SearchMatrixPairsResponse, SearchMatrixRequest, UpdateVectors,
 };
 use collection::operations::cluster_ops::ClusterOperations;
 use collection::operations::consistency_params::ReadConsistency;
@@ -102,6 +104,15 @@ struct AllDefinitions {
     bp: OptimizationsResponse,
     bq: DistributedTelemetryData,
     br: segment::data_types::vector_name_config::VectorNameConfig,
+    bs: QuantizationTuningRequest,
+    bt: QuantizationTuningResponse,
+    bu: FederatedQueryRequest,
+    bv: FederatedQueryResponse,
+    bw: Explanation,
+    bx: ClusteringRequest,
+    by: ClusteringResponse,
+    bz: NearDuplicatesRequest,
+    ca: NearDuplicatesResponse,
 }
 
 fn save_schema<T: JsonSchema>() {
@@ -6,14 +6,15 @@ use api::grpc::qdrant::{
     ClearPayloadPoints, CountPoints, CountResponse, CreateFieldIndexCollection,
     CreateVectorNameRequest, DeleteFieldIndexCollection, DeletePayloadPoints, DeletePointVectors,
     DeletePoints, DeleteVectorNameRequest, DiscoverBatchPoints, DiscoverBatchResponse,
-    DiscoverPoints, DiscoverResponse, FacetCounts, FacetResponse, GetPoints, GetResponse,
-    PointsOperationResponse, QueryBatchPoints, QueryBatchResponse, QueryGroupsResponse,
-    QueryPointGroups, QueryPoints, QueryResponse, RecommendBatchPoints, RecommendBatchResponse,
-    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
-    ScrollPoints, ScrollResponse, SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse,
-    SearchMatrixOffsets, SearchMatrixOffsetsResponse, SearchMatrixPairs, SearchMatrixPairsResponse,
-    SearchMatrixPoints, SearchPointGroups, SearchPoints, SearchResponse, SetPayloadPoints,
-    UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
+    DiscoverPoints, DiscoverResponse, FacetCounts, FacetResponse, FederatedQueryPoints,
+    FederatedQueryResponse, GetPoints, GetResponse, PointsOperationResponse, QueryBatchPoints,
+    QueryBatchResponse, QueryGroupsResponse, QueryPointGroups, QueryPoints, QueryResponse,
+    RecommendBatchPoints, RecommendBatchResponse, RecommendGroupsResponse, RecommendPointGroups,
+    RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse, SearchBatchPoints,
+    SearchBatchResponse, SearchGroupsResponse, SearchMatrixOffsets, SearchMatrixOffsetsResponse,
+    SearchMatrixPairs, SearchMatrixPairsResponse, SearchMatrixPoints, SearchPointGroups,
+    SearchPoints, SearchResponse, SetPayloadPoints, UpdateBatchPoints, UpdateBatchResponse,
+    UpdatePointVectors, UpsertPoints,
 };
 use api::grpc::{PointsOperationResponseInternal, Usage};
 use collection::operations::types::CoreSearchRequest;
@@ -79,6 +80,7 @@ impl Points for PointsService {
         upsert(
             StrictModeCheckedTocProvider::new(&self.dispatcher),
             request.into_inner(),
+            Vec::new(),
             InternalUpdateParams::default(),
             auth,
             inference_params,
@@ -712,6 +714,33 @@ impl Points for PointsService {
 
         Ok(res)
     }
+    async fn federated_query(
+        &self,
+        mut request: Request<FederatedQueryPoints>,
+    ) -> Result<Response<FederatedQueryResponse>, Status> {
+        validate(request.get_ref())?;
+        let auth = extract_auth(&mut request);
+        let timeout = request.get_ref().timeout.map(Duration::from_secs);
+        let api_keys = extract_inference_auth(&request);
+        let inference_params = InferenceParams::new(api_keys, timeout);
+        // Hardware usage is accounted to the metrics of each queried collection by the query itself
+        let hw_metrics = RequestHwCounter::new(
+            HwMeasurementAcc::new_with_metrics_drain(Arc::default()),
+            self.service_config.hardware_reporting(),
+        );
+
+        let res = federated_query(
+            StrictModeCheckedTocProvider::new(&self.dispatcher),
+            request.into_inner(),
+            auth,
+            hw_metrics,
+            inference_params,
+        )
+        .await?;
+
+        Ok(res)
+    }
+
     async fn facet(
         &self,
         mut request: Request<FacetCounts>,
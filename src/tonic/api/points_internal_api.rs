@@ -24,7 +24,7 @@ use common::counter::hardware_accumulator::HwMeasurementAcc;
 use itertools::Itertools;
 use segment::data_types::facets::{FacetParams, FacetResponse};
 use segment::json_path::JsonPath;
-use segment::types::Filter;
+use segment::types::{ExpectedPointVersion, Filter};
 use storage::content_manager::toc::TableOfContent;
 use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
 use storage::rbac::Auth;
@@ -70,14 +70,17 @@ impl PointsInternalService {
             shard_id,
             clock_tag,
             wait_override,
+            point_versions,
         } = sync_points_internal;
 
         let sync_points = extract_internal_request(sync_points)?;
+        let point_versions = point_versions_from_grpc(point_versions)?;
 
         // Exclude the `inference_usage` for internal call
         let (response, _inference_usage) = sync(
             self.toc.clone(),
             sync_points,
+            point_versions,
             InternalUpdateParams::from_grpc(shard_id, clock_tag, wait_override),
             auth,
             inference_params,
@@ -99,9 +102,11 @@ impl PointsInternalService {
             shard_id,
             clock_tag,
             wait_override,
+            point_versions,
         } = upsert_points_internal;
 
         let upsert_points = extract_internal_request(upsert_points)?;
+        let point_versions = point_versions_from_grpc(point_versions)?;
 
         let hw_metrics = self.get_request_collection_hw_usage_counter_for_internal(
             upsert_points.collection_name.clone(),
@@ -110,6 +115,7 @@ impl PointsInternalService {
         upsert(
             StrictModeCheckedInternalTocProvider::new(&self.toc),
             upsert_points,
+            point_versions,
             InternalUpdateParams::from_grpc(shard_id, clock_tag, wait_override),
             auth,
             inference_params.clone(),
@@ -1019,3 +1025,12 @@ fn extract_internal_request<T>(request: Option<T>) -> Result<T, tonic::Status> {
         tonic::Status::invalid_argument(format!("{} is missing", any::type_name::<T>()))
     })
 }
+
+fn point_versions_from_grpc(
+    point_versions: Vec<api::grpc::qdrant::ExpectedPointVersion>,
+) -> Result<Vec<ExpectedPointVersion>, tonic::Status> {
+    point_versions
+        .into_iter()
+        .map(ExpectedPointVersion::try_from)
+        .collect()
+}
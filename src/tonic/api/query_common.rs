@@ -3,27 +3,30 @@ use std::time::{Duration, Instant};
 use api::conversions::json::json_path_from_proto;
 use api::grpc::qdrant::{
     BatchResult, CoreSearchPoints, CountPoints, CountResponse, DiscoverBatchResponse,
-    DiscoverPoints, DiscoverResponse, FacetCounts, FacetResponse, GetPoints, GetResponse,
-    GroupsResult, QueryBatchResponse, QueryGroupsResponse, QueryPointGroups, QueryPoints,
-    QueryResponse, ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse,
-    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
-    ScrollPoints, ScrollResponse, SearchBatchResponse, SearchGroupsResponse, SearchMatrixPoints,
-    SearchParams, SearchPointGroups, SearchPoints, SearchResponse, WithVectorsSelector,
+    DiscoverPoints, DiscoverResponse, FacetCounts, FacetResponse, FederatedQueryPoints,
+    FederatedQueryResponse, FederatedScoredPoint, GetPoints, GetResponse, GroupsResult,
+    QueryBatchResponse, QueryGroupsResponse, QueryPointGroups, QueryPoints, QueryResponse,
+    ReadConsistency as ReadConsistencyGrpc, RecommendBatchResponse, RecommendGroupsResponse,
+    RecommendPointGroups, RecommendPoints, RecommendResponse, ScrollPoints, ScrollResponse,
+    SearchBatchResponse, SearchGroupsResponse, SearchMatrixPoints, SearchPointGroups, SearchPoints,
+    SearchResponse, WithVectorsSelector,
 };
 use api::grpc::{InferenceUsage, Usage};
 use collection::collection::distance_matrix::{
     CollectionSearchMatrixRequest, CollectionSearchMatrixResponse,
 };
+use collection::lookup::join::WithJoin;
 use collection::operations::consistency_params::ReadConsistency;
 use collection::operations::conversions::try_discover_request_from_grpc;
 use collection::operations::shard_selector_internal::ShardSelectorInternal;
 use collection::operations::types::{CoreSearchRequest, PointRequestInternal};
+use collection::operations::universal_query::shard_query::FusionInternal;
 use collection::shards::shard::ShardId;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use segment::data_types::facets::FacetParams;
 use segment::data_types::order_by::{OrderBy, OrderByInterface};
 use segment::data_types::vectors::{DEFAULT_VECTOR_NAME, NamedQuery, VectorInternal};
-use segment::types::{ExtendedPointId, ScoredPoint};
+use segment::types::{ExtendedPointId, Payload, ScoredPoint};
 use shard::count::CountRequestInternal;
 use shard::query::query_enum::QueryEnum;
 use shard::retrieve::record_internal::RecordInternal;
@@ -82,6 +85,7 @@ pub async fn search(
         timeout,
         shard_key_selector,
         sparse_indices,
+        explain,
     } = search_points;
 
     let vector_internal =
@@ -95,7 +99,7 @@ pub async fn search(
     let search_request = CoreSearchRequest {
         query: QueryEnum::Nearest(NamedQuery::from(vector_struct)),
         filter: filter.map(|f| f.try_into()).transpose()?,
-        params: params.map(SearchParams::into),
+        params: params.map(TryInto::try_into).transpose()?,
         limit: limit as usize,
         offset: offset.unwrap_or_default() as usize,
         with_payload: with_payload.map(|wp| wp.try_into()).transpose()?,
@@ -117,6 +121,12 @@ pub async fn search(
         .await?;
 
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+    let timeout = timeout.map(Duration::from_secs);
+
+    // The request is consumed by the search, so keep a copy to explain it afterwards
+    let explain_request = explain
+        .unwrap_or_default()
+        .then(|| (search_request.clone(), shard_selector.clone()));
 
     let timing = Instant::now();
     let scored_points = do_core_search_points(
@@ -125,16 +135,33 @@ pub async fn search(
         search_request,
         read_consistency,
         shard_selector,
-        auth,
-        timeout.map(Duration::from_secs),
+        auth.clone(),
+        timeout,
         hw_measurement_acc.get_counter(),
     )
     .await?;
 
+    let explanation = match explain_request {
+        Some((search_request, shard_selector)) => Some(
+            toc.explain_search(
+                &collection_name,
+                search_request,
+                shard_selector,
+                auth,
+                timeout,
+                // The explanation executes the search again, which is billed as well
+                hw_measurement_acc.get_counter(),
+            )
+            .await?,
+        ),
+        None => None,
+    };
+
     let response = SearchResponse {
         result: scored_points.into_iter().map(ScoredPoint::into).collect(),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::from_hardware_usage(hw_measurement_acc.to_grpc_api()).into_non_empty(),
+        explain: explanation.map(From::from),
     };
 
     Ok(Response::new(response))
@@ -587,6 +614,7 @@ pub async fn scroll(
         shard_key_selector,
         order_by,
         timeout,
+        with_join,
     } = scroll_points;
 
     let scroll_request = ScrollRequestInternal {
@@ -618,14 +646,30 @@ pub async fn scroll(
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector)?;
 
     let timing = Instant::now();
-    let scrolled_points = do_scroll_points(
+    let mut scrolled_points = do_scroll_points(
         toc,
         &collection_name,
         scroll_request,
         read_consistency,
         timeout,
         shard_selector,
-        auth,
+        auth.clone(),
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    join_payloads(
+        toc,
+        &collection_name,
+        with_join,
+        scrolled_points
+            .points
+            .iter_mut()
+            .map(|point| (point.id, &mut point.payload))
+            .collect(),
+        read_consistency,
+        &auth,
+        timeout,
         request_hw_counter.get_counter(),
     )
     .await?;
@@ -662,6 +706,7 @@ pub async fn count(
         read_consistency,
         shard_key_selector,
         timeout,
+        explain,
     } = count_points;
 
     let count_request = CountRequestInternal {
@@ -683,6 +728,11 @@ pub async fn count(
 
     let shard_selector = convert_shard_selector_for_read(shard_selection, shard_key_selector)?;
 
+    // The request is consumed by the count, so keep a copy to explain it afterwards
+    let explain_request = explain
+        .unwrap_or_default()
+        .then(|| (count_request.clone(), shard_selector.clone()));
+
     let timing = Instant::now();
 
     let count_result = do_count_points(
@@ -692,15 +742,32 @@ pub async fn count(
         read_consistency,
         timeout,
         shard_selector,
-        auth,
+        auth.clone(),
         request_hw_counter.get_counter(),
     )
     .await?;
 
+    let explanation = match explain_request {
+        Some((count_request, shard_selector)) => Some(
+            toc.explain_count(
+                &collection_name,
+                count_request,
+                shard_selector,
+                auth,
+                timeout,
+                // The explanation executes the count again, which is billed as well
+                request_hw_counter.get_counter(),
+            )
+            .await?,
+        ),
+        None => None,
+    };
+
     let response = CountResponse {
         result: Some(count_result.into()),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::from_hardware_usage(request_hw_counter.to_grpc_api()).into_non_empty(),
+        explain: explanation.map(From::from),
     };
 
     Ok(Response::new(response))
@@ -721,6 +788,7 @@ pub async fn get(
         read_consistency,
         shard_key_selector,
         timeout,
+        with_join,
     } = get_points;
 
     let point_request = PointRequestInternal {
@@ -750,14 +818,29 @@ pub async fn get(
 
     let timeout = timeout.map(Duration::from_secs);
 
-    let records = do_get_points(
+    let mut records = do_get_points(
         toc,
         &collection_name,
         point_request,
         read_consistency,
         timeout,
         shard_selector,
-        auth,
+        auth.clone(),
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    join_payloads(
+        toc,
+        &collection_name,
+        with_join,
+        records
+            .iter_mut()
+            .map(|record| (record.id, &mut record.payload))
+            .collect(),
+        read_consistency,
+        &auth,
+        timeout,
         request_hw_counter.get_counter(),
     )
     .await?;
@@ -771,6 +854,35 @@ pub async fn get(
     Ok(Response::new(response))
 }
 
+/// Embed payload of points from another collection, if the request has a join
+#[allow(clippy::too_many_arguments)]
+async fn join_payloads(
+    toc: &TableOfContent,
+    collection_name: &str,
+    with_join: Option<api::grpc::qdrant::WithJoin>,
+    points: Vec<(ExtendedPointId, &mut Option<Payload>)>,
+    read_consistency: Option<ReadConsistency>,
+    auth: &Auth,
+    timeout: Option<Duration>,
+    hw_measurement_acc: HwMeasurementAcc,
+) -> Result<(), Status> {
+    let Some(with_join) = with_join else {
+        return Ok(());
+    };
+
+    toc.join_payloads(
+        collection_name,
+        &WithJoin::try_from(with_join)?,
+        points,
+        read_consistency,
+        auth,
+        timeout,
+        hw_measurement_acc,
+    )
+    .await?;
+    Ok(())
+}
+
 pub async fn query(
     toc_provider: impl CheckedTocProvider,
     query_points: QueryPoints,
@@ -788,6 +900,8 @@ pub async fn query(
         .transpose()?;
     let collection_name = query_points.collection_name.clone();
     let timeout = query_points.timeout;
+    let with_join = query_points.with_join.clone();
+    let explain = query_points.explain.unwrap_or_default();
     let (request, inference_usage) =
         convert_query_points_from_grpc(query_points, inference_params).await?;
 
@@ -802,14 +916,55 @@ pub async fn query(
 
     let timeout = timeout.map(Duration::from_secs);
 
+    let pagination = request.pagination();
+
+    // The request is consumed by the query, so keep a copy to explain it afterwards
+    let explain_request = explain.then(|| (request.clone(), shard_selector.clone()));
+
     let timing = Instant::now();
-    let scored_points = do_query_points(
+    let mut scored_points = do_query_points(
         toc,
         &collection_name,
         request,
         read_consistency,
         shard_selector,
-        auth,
+        auth.clone(),
+        timeout,
+        request_hw_counter.get_counter(),
+    )
+    .await?;
+
+    let explanation = match explain_request {
+        Some((request, shard_selector)) => Some(
+            toc.explain_query(
+                &collection_name,
+                request,
+                shard_selector,
+                read_consistency,
+                auth.clone(),
+                timeout,
+                // The explanation executes the request again, which is billed as well
+                request_hw_counter.get_counter(),
+            )
+            .await?,
+        ),
+        None => None,
+    };
+
+    let next_cursor = pagination
+        .and_then(|pagination| pagination.next_cursor(&scored_points))
+        .map(|cursor| cursor.encode());
+
+    join_payloads(
+        toc,
+        &collection_name,
+        with_join,
+        scored_points
+            .iter_mut()
+            .map(|point| (point.id, &mut point.payload))
+            .collect(),
+        read_consistency,
+        &auth,
         timeout,
         request_hw_counter.get_counter(),
     )
@@ -819,6 +974,8 @@ pub async fn query(
         result: scored_points.into_iter().map(ScoredPoint::into).collect(),
         time: timing.elapsed().as_secs_f64(),
         usage: Usage::new(request_hw_counter.to_grpc_api(), Some(inference_usage)).into_non_empty(),
+        next_cursor,
+        explain: explanation.map(From::from),
     };
 
     Ok(Response::new(response))
@@ -837,11 +994,13 @@ pub async fn query_batch(
 ) -> Result<Response<QueryBatchResponse>, Status> {
     let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
     let mut requests = Vec::with_capacity(points.len());
+    let mut joins = Vec::with_capacity(points.len());
     let mut total_inference_usage = InferenceUsage::default();
 
     for query_points in points {
         let shard_key_selector = query_points.shard_key_selector.clone();
         let shard_selector = convert_shard_selector_for_read(None, shard_key_selector)?;
+        joins.push(query_points.with_join.clone());
         let (request, usage) =
             convert_query_points_from_grpc(query_points, inference_params.clone()).await?;
         total_inference_usage.merge(usage);
@@ -859,17 +1018,34 @@ pub async fn query_batch(
         .await?;
 
     let timing = Instant::now();
-    let scored_points = do_query_batch_points(
+    let mut scored_points = do_query_batch_points(
         toc,
         collection_name,
         requests,
         read_consistency,
-        auth,
+        auth.clone(),
         timeout,
         request_hw_counter.get_counter(),
     )
     .await?;
 
+    for (points, with_join) in scored_points.iter_mut().zip(joins) {
+        join_payloads(
+            toc,
+            collection_name,
+            with_join,
+            points
+                .iter_mut()
+                .map(|point| (point.id, &mut point.payload))
+                .collect(),
+            read_consistency,
+            &auth,
+            timeout,
+            request_hw_counter.get_counter(),
+        )
+        .await?;
+    }
+
     let response = QueryBatchResponse {
         result: scored_points
             .into_iter()
@@ -944,6 +1120,101 @@ pub async fn query_groups(
     Ok(Response::new(response))
 }
 
+pub async fn federated_query(
+    toc_provider: impl CheckedTocProvider,
+    request: FederatedQueryPoints,
+    auth: Auth,
+    request_hw_counter: RequestHwCounter,
+    inference_params: InferenceParams,
+) -> Result<Response<FederatedQueryResponse>, Status> {
+    let FederatedQueryPoints {
+        collections,
+        prefetch,
+        query,
+        using,
+        filter,
+        params,
+        score_threshold,
+        limit,
+        offset,
+        with_vectors,
+        with_payload,
+        read_consistency,
+        lookup_from,
+        timeout,
+        merge,
+    } = request;
+
+    let read_consistency = ReadConsistency::try_from_optional(read_consistency)?;
+    let fusion = merge.map(FusionInternal::try_from).transpose()?;
+
+    // The query is the same for each collection
+    let query_points = QueryPoints {
+        collection_name: String::new(),
+        prefetch,
+        query,
+        using,
+        filter,
+        params,
+        score_threshold,
+        limit,
+        offset,
+        with_vectors,
+        with_payload,
+        read_consistency: None,
+        shard_key_selector: None,
+        lookup_from,
+        timeout,
+        cursor: None,
+        with_join: None,
+        explain: None,
+    };
+    let (request, inference_usage) =
+        convert_query_points_from_grpc(query_points, inference_params).await?;
+
+    let mut toc = None;
+    for collection_name in &collections {
+        toc = Some(
+            toc_provider
+                .check_strict_mode(
+                    &request,
+                    collection_name,
+                    timeout.map(|i| i as usize),
+                    &auth,
+                )
+                .await?,
+        );
+    }
+    let toc = toc.ok_or_else(|| Status::invalid_argument("No collections to query"))?;
+
+    let timing = Instant::now();
+    let points = toc
+        .federated_query(
+            &collections,
+            request,
+            fusion,
+            read_consistency,
+            auth,
+            timeout.map(Duration::from_secs),
+            request_hw_counter.get_counter(),
+        )
+        .await?;
+
+    let response = FederatedQueryResponse {
+        result: points
+            .into_iter()
+            .map(|(collection_name, point)| FederatedScoredPoint {
+                collection_name,
+                point: Some(point.into()),
+            })
+            .collect(),
+        time: timing.elapsed().as_secs_f64(),
+        usage: Usage::new(request_hw_counter.to_grpc_api(), Some(inference_usage)).into_non_empty(),
+    };
+
+    Ok(Response::new(response))
+}
+
 pub async fn facet(
     toc_provider: impl CheckedTocProvider,
     facet_counts: FacetCounts,
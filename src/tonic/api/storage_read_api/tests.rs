@@ -73,6 +73,7 @@ fn test_storage_config(storage_path: &Path) -> StorageConfig {
         shard_transfer_method: None,
         collection: None,
         max_collections: None,
+        recall_monitor: None,
     }
 }
 
@@ -14,15 +14,16 @@ use api::grpc::qdrant::{
     DeleteFullSnapshotRequest, DeletePayloadPoints, DeletePointVectors, DeletePoints,
     DeleteShardSnapshotRequest, DeleteSnapshotRequest, DeleteSnapshotResponse,
     DeleteVectorNameRequest, DiscoverBatchPoints, DiscoverBatchResponse, DiscoverPoints,
-    DiscoverResponse, FacetCounts, FacetResponse, GetPoints, GetResponse, ListFullSnapshotsRequest,
-    ListShardSnapshotsRequest, ListSnapshotsRequest, ListSnapshotsResponse,
-    PointsOperationResponse, QueryBatchPoints, QueryBatchResponse, QueryGroupsResponse,
-    QueryPointGroups, QueryPoints, QueryResponse, RecommendBatchPoints, RecommendBatchResponse,
-    RecommendGroupsResponse, RecommendPointGroups, RecommendPoints, RecommendResponse,
-    RecoverShardSnapshotRequest, RecoverSnapshotResponse, ScrollPoints, ScrollResponse,
-    SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse, SearchMatrixOffsetsResponse,
-    SearchMatrixPairsResponse, SearchMatrixPoints, SearchPointGroups, SearchPoints, SearchResponse,
-    SetPayloadPoints, UpdateBatchPoints, UpdateBatchResponse, UpdatePointVectors, UpsertPoints,
+    DiscoverResponse, FacetCounts, FacetResponse, FederatedQueryPoints, FederatedQueryResponse,
+    GetPoints, GetResponse, ListFullSnapshotsRequest, ListShardSnapshotsRequest,
+    ListSnapshotsRequest, ListSnapshotsResponse, PointsOperationResponse, QueryBatchPoints,
+    QueryBatchResponse, QueryGroupsResponse, QueryPointGroups, QueryPoints, QueryResponse,
+    RecommendBatchPoints, RecommendBatchResponse, RecommendGroupsResponse, RecommendPointGroups,
+    RecommendPoints, RecommendResponse, RecoverShardSnapshotRequest, RecoverSnapshotResponse,
+    ScrollPoints, ScrollResponse, SearchBatchPoints, SearchBatchResponse, SearchGroupsResponse,
+    SearchMatrixOffsetsResponse, SearchMatrixPairsResponse, SearchMatrixPoints, SearchPointGroups,
+    SearchPoints, SearchResponse, SetPayloadPoints, UpdateBatchPoints, UpdateBatchResponse,
+    UpdatePointVectors, UpsertPoints,
 };
 use tonic::{Request, Response, Status};
 
@@ -308,6 +309,14 @@ impl<T: Points> Points for PointsTelemetryWrapper<T> {
         Ok(resp)
     }
 
+    /// Queries several collections, so it is passed through as-is.
+    async fn federated_query(
+        &self,
+        request: Request<FederatedQueryPoints>,
+    ) -> Result<Response<FederatedQueryResponse>, Status> {
+        self.inner.federated_query(request).await
+    }
+
     async fn facet(
         &self,
         request: Request<FacetCounts>,
@@ -472,7 +481,10 @@ mod tests {
 
     // Points
     macro_rules! mock_and_test_points {
-        ($($method:ident($req:ident) -> $resp:ident),* $(,)?) => {
+        (
+            with_cn: { $($method:ident($req:ident) -> $resp:ident),* $(,)? }
+            passthrough: { $($pt_method:ident($pt_req:ident) -> $pt_resp:ident),* $(,)? }
+        ) => {
             struct MockPoints;
 
             #[tonic::async_trait]
@@ -483,6 +495,11 @@ mod tests {
                         Ok(Response::new(Default::default()))
                     }
                 )*
+                $(
+                    async fn $pt_method(&self, r: Request<$pt_req>) -> Result<Response<$pt_resp>, Status> {
+                        Ok(Response::new(Default::default()))
+                    }
+                )*
             }
 
             $(
@@ -502,40 +519,60 @@ mod tests {
                     );
                 }
             )*
+
+            $(
+                #[tokio::test]
+                async fn $pt_method() {
+                    let w = PointsTelemetryWrapper::new(MockPoints);
+                    let r = w
+                        .$pt_method(Request::new($pt_req::default()))
+                        .await
+                        .unwrap();
+                    assert!(
+                        r.extensions().get::<CollectionName>().is_none(),
+                        "passthrough method should not attach CollectionName",
+                    );
+                }
+            )*
         };
     }
 
     mock_and_test_points! {
-        upsert(UpsertPoints) -> PointsOperationResponse,
-        delete(DeletePoints) -> PointsOperationResponse,
-        get(GetPoints) -> GetResponse,
-        update_vectors(UpdatePointVectors) -> PointsOperationResponse,
-        delete_vectors(DeletePointVectors) -> PointsOperationResponse,
-        set_payload(SetPayloadPoints) -> PointsOperationResponse,
-        overwrite_payload(SetPayloadPoints) -> PointsOperationResponse,
-        delete_payload(DeletePayloadPoints) -> PointsOperationResponse,
-        clear_payload(ClearPayloadPoints) -> PointsOperationResponse,
-        update_batch(UpdateBatchPoints) -> UpdateBatchResponse,
-        create_field_index(CreateFieldIndexCollection) -> PointsOperationResponse,
-        delete_field_index(DeleteFieldIndexCollection) -> PointsOperationResponse,
-        create_vector_name(CreateVectorNameRequest) -> PointsOperationResponse,
-        delete_vector_name(DeleteVectorNameRequest) -> PointsOperationResponse,
-        search(SearchPoints) -> SearchResponse,
-        search_batch(SearchBatchPoints) -> SearchBatchResponse,
-        search_groups(SearchPointGroups) -> SearchGroupsResponse,
-        scroll(ScrollPoints) -> ScrollResponse,
-        recommend(RecommendPoints) -> RecommendResponse,
-        recommend_batch(RecommendBatchPoints) -> RecommendBatchResponse,
-        recommend_groups(RecommendPointGroups) -> RecommendGroupsResponse,
-        discover(DiscoverPoints) -> DiscoverResponse,
-        discover_batch(DiscoverBatchPoints) -> DiscoverBatchResponse,
-        count(CountPoints) -> CountResponse,
-        query(QueryPoints) -> QueryResponse,
-        query_batch(QueryBatchPoints) -> QueryBatchResponse,
-        query_groups(QueryPointGroups) -> QueryGroupsResponse,
-        facet(FacetCounts) -> FacetResponse,
-        search_matrix_pairs(SearchMatrixPoints) -> SearchMatrixPairsResponse,
-        search_matrix_offsets(SearchMatrixPoints) -> SearchMatrixOffsetsResponse,
+        with_cn: {
+            upsert(UpsertPoints) -> PointsOperationResponse,
+            delete(DeletePoints) -> PointsOperationResponse,
+            get(GetPoints) -> GetResponse,
+            update_vectors(UpdatePointVectors) -> PointsOperationResponse,
+            delete_vectors(DeletePointVectors) -> PointsOperationResponse,
+            set_payload(SetPayloadPoints) -> PointsOperationResponse,
+            overwrite_payload(SetPayloadPoints) -> PointsOperationResponse,
+            delete_payload(DeletePayloadPoints) -> PointsOperationResponse,
+            clear_payload(ClearPayloadPoints) -> PointsOperationResponse,
+            update_batch(UpdateBatchPoints) -> UpdateBatchResponse,
+            create_field_index(CreateFieldIndexCollection) -> PointsOperationResponse,
+            delete_field_index(DeleteFieldIndexCollection) -> PointsOperationResponse,
+            create_vector_name(CreateVectorNameRequest) -> PointsOperationResponse,
+            delete_vector_name(DeleteVectorNameRequest) -> PointsOperationResponse,
+            search(SearchPoints) -> SearchResponse,
+            search_batch(SearchBatchPoints) -> SearchBatchResponse,
+            search_groups(SearchPointGroups) -> SearchGroupsResponse,
+            scroll(ScrollPoints) -> ScrollResponse,
+            recommend(RecommendPoints) -> RecommendResponse,
+            recommend_batch(RecommendBatchPoints) -> RecommendBatchResponse,
+            recommend_groups(RecommendPointGroups) -> RecommendGroupsResponse,
+            discover(DiscoverPoints) -> DiscoverResponse,
+            discover_batch(DiscoverBatchPoints) -> DiscoverBatchResponse,
+            count(CountPoints) -> CountResponse,
+            query(QueryPoints) -> QueryResponse,
+            query_batch(QueryBatchPoints) -> QueryBatchResponse,
+            query_groups(QueryPointGroups) -> QueryGroupsResponse,
+            facet(FacetCounts) -> FacetResponse,
+            search_matrix_pairs(SearchMatrixPoints) -> SearchMatrixPairsResponse,
+            search_matrix_offsets(SearchMatrixPoints) -> SearchMatrixOffsetsResponse,
+        }
+        passthrough: {
+            federated_query(FederatedQueryPoints) -> FederatedQueryResponse,
+        }
     }
 
     // Snapshots
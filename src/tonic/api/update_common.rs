@@ -16,14 +16,17 @@ use api::grpc::{HardwareUsage, InferenceUsage, Usage};
 use api::rest::schema::{PointInsertOperations, PointsList};
 use api::rest::{PointStruct, PointVectors, ShardKeySelector, UpdateVectors, VectorStruct};
 use collection::operations::CollectionUpdateOperations;
-use collection::operations::conversions::try_points_selector_from_grpc;
+use collection::operations::conversions::{
+    try_if_version_from_grpc, try_points_selector_from_grpc,
+};
 use collection::operations::payload_ops::DeletePayload;
 use collection::operations::point_ops::{self, PointOperations, PointSyncOperation};
 use collection::operations::vector_ops::DeleteVectors;
 use common::counter::hardware_accumulator::HwMeasurementAcc;
 use itertools::Itertools;
 use segment::types::{
-    ExtendedPointId, Filter, PayloadFieldSchema, PayloadSchemaParams, PayloadSchemaType,
+    ExpectedPointVersion, ExtendedPointId, Filter, PayloadFieldSchema, PayloadSchemaParams,
+    PayloadSchemaType,
 };
 use storage::content_manager::toc::TableOfContent;
 use storage::content_manager::toc::request_hw_counter::RequestHwCounter;
@@ -40,6 +43,7 @@ use crate::common::update::*;
 pub async fn upsert(
     toc_provider: impl CheckedTocProvider,
     upsert_points: UpsertPoints,
+    point_versions: Vec<ExpectedPointVersion>,
     internal_params: InternalUpdateParams,
     auth: Auth,
     inference_params: InferenceParams,
@@ -54,6 +58,7 @@ pub async fn upsert(
         update_filter,
         timeout,
         update_mode,
+        if_version,
     } = upsert_points;
 
     let points: Result<_, _> = points.into_iter().map(PointStruct::try_from).collect();
@@ -67,6 +72,7 @@ pub async fn upsert(
             .map(segment::types::Filter::try_from)
             .transpose()?,
         update_mode: update_mode.map(grpc_update_mode_to_rest),
+        if_version: try_if_version_from_grpc(if_version)?,
     });
 
     let timing = Instant::now();
@@ -74,6 +80,7 @@ pub async fn upsert(
         toc_provider,
         collection_name,
         operation,
+        point_versions,
         internal_params,
         UpdateParams::from_grpc(wait, ordering, timeout)?,
         auth,
@@ -114,13 +121,25 @@ pub async fn delete(
         ordering,
         shard_key_selector,
         timeout,
+        if_version,
     } = delete_points;
 
-    let points_selector = match points {
+    let mut points_selector = match points {
         None => return Err(Status::invalid_argument("PointSelector is missing")),
         Some(p) => try_points_selector_from_grpc(p, shard_key_selector)?,
     };
 
+    if let Some(if_version) = try_if_version_from_grpc(if_version)? {
+        match &mut points_selector {
+            point_ops::PointsSelector::PointIdsSelector(ids) => ids.if_version = Some(if_version),
+            point_ops::PointsSelector::FilterSelector(_) => {
+                return Err(Status::invalid_argument(
+                    "if_version is not supported for delete by filter",
+                ));
+            }
+        }
+    }
+
     let timing = Instant::now();
     let result = do_delete_points(
         toc_provider,
@@ -267,6 +286,7 @@ pub async fn set_payload(
         shard_key_selector,
         key,
         timeout,
+        if_version,
     } = set_payload_points;
     let key = key.map(|k| json_path_from_proto(&k)).transpose()?;
 
@@ -279,6 +299,7 @@ pub async fn set_payload(
             .map(ShardKeySelector::try_from)
             .transpose()?,
         key,
+        if_version: try_if_version_from_grpc(if_version)?,
     };
 
     let timing = Instant::now();
@@ -313,6 +334,7 @@ pub async fn overwrite_payload(
         ordering,
         shard_key_selector,
         timeout,
+        if_version,
         ..
     } = set_payload_points;
 
@@ -326,6 +348,7 @@ pub async fn overwrite_payload(
             .transpose()?,
         // overwrite operation don't support indicate path of property
         key: None,
+        if_version: try_if_version_from_grpc(if_version)?,
     };
 
     let timing = Instant::now();
@@ -472,7 +495,9 @@ pub async fn update_batch(
                         update_filter,
                         timeout,
                         update_mode,
+                        if_version: Vec::new(),
                     },
+                    Vec::new(),
                     internal_params,
                     auth.clone(),
                     inference_params.clone(),
@@ -490,6 +515,7 @@ pub async fn update_batch(
                         ordering,
                         shard_key_selector: None,
                         timeout,
+                        if_version: Vec::new(),
                     },
                     internal_params,
                     auth.clone(),
@@ -516,6 +542,7 @@ pub async fn update_batch(
                         shard_key_selector,
                         key,
                         timeout,
+                        if_version: Vec::new(),
                     },
                     internal_params,
                     auth.clone(),
@@ -543,6 +570,7 @@ pub async fn update_batch(
                         // overwrite operation doesn't support it
                         key: None,
                         timeout,
+                        if_version: Vec::new(),
                     },
                     internal_params,
                     auth.clone(),
@@ -673,6 +701,7 @@ pub async fn update_batch(
                         ordering,
                         shard_key_selector,
                         timeout,
+                        if_version: Vec::new(),
                     },
                     internal_params,
                     auth.clone(),
@@ -842,6 +871,7 @@ pub async fn delete_field_index_internal(
 pub async fn sync(
     toc: Arc<TableOfContent>,
     sync_points: SyncPoints,
+    point_versions: Vec<ExpectedPointVersion>,
     internal_params: InternalUpdateParams,
     auth: Auth,
     inference_params: InferenceParams,
@@ -862,8 +892,9 @@ pub async fn sync(
 
     // No actual inference should happen here, as we are just syncing existing points
     // So, this function is used for consistency only
-    let (points, usage) =
+    let (mut points, usage) =
         convert_point_struct(point_structs?, InferenceType::Update, inference_params).await?;
+    point_ops::PointStructPersisted::set_versions(&mut points, &point_versions);
 
     let operation = PointSyncOperation {
         points,
@@ -993,6 +1024,17 @@ fn convert_field_type(
                         TryFrom::try_from(uuid_index_params).map(PayloadSchemaParams::Uuid)
                     })
                 }
+                // Composite index, field type is the type of the indexed field
+                IndexParams::CompositeIndexParams(composite_index_params) => {
+                    match segment::data_types::index::CompositeIndexParams::try_from(
+                        composite_index_params,
+                    ) {
+                        Ok(params) => (FieldType::from(PayloadSchemaType::from(params.field_type))
+                            == field_type)
+                            .then_some(Ok(PayloadSchemaParams::Composite(params))),
+                        Err(err) => Some(Err(err)),
+                    }
+                }
             }
             .ok_or_else(|| {
                 Status::invalid_argument(format!(
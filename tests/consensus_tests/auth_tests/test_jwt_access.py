@@ -593,6 +593,9 @@ ACTION_ACCESS = {
         "POST /collections/{collection_name}/points/query/groups",
         "qdrant.Points/QueryGroups",
     ),
+    "federated_query": EndpointAccess(
+        True, True, True, "POST /query", "qdrant.Points/FederatedQuery"
+    ),
     "search_points_matrix_offsets": EndpointAccess(
         True,
         True,
@@ -1957,6 +1960,23 @@ def test_search_points_matrix_pairs():
     )
 
 
+def test_federated_query():
+    check_access(
+        "federated_query",
+        rest_request={"collections": [COLL_NAME], "query": [0.1, 0.2, 0.3, 0.4]},
+        grpc_request={
+            "collections": [COLL_NAME],
+            "query": {
+                "nearest": {
+                    "dense": {
+                        "data": [0.1, 0.2, 0.3, 0.4]
+                    }
+                }
+            },
+        },
+    )
+
+
 def test_facet():
     check_access(
         "facet",
@@ -1580,6 +1580,60 @@ def test_strict_mode_formula_expression(collection_name):
     assert query_ok.ok
 
 
+def test_strict_mode_stratified_sample(collection_name):
+
+    def query_request():
+        return request_with_validation(
+            api='/collections/{collection_name}/points/query',
+            method="POST",
+            path_params={'collection_name': collection_name},
+            body={
+                "query": {
+                    "sample": {
+                        "stratified": {"key": "city", "per_value": 1}
+                    }
+                },
+                "with_payload": True,
+            }
+        )
+
+    # No restriction, query succeeds
+    query_ok = query_request()
+    assert query_ok.ok
+
+    set_strict_mode(collection_name, {
+        "enabled": True,
+        "unindexed_filtering_retrieve": False,
+    })
+
+    # Now it should fail
+    query_fail = query_request()
+    assert not query_fail.ok
+    assert "city" in query_fail.json()['status']['error']
+
+    # Create index on `city`
+    request_with_validation(
+        api='/collections/{collection_name}/index',
+        method="PUT",
+        path_params={'collection_name': collection_name},
+        query_params={'wait': 'true'},
+        body={
+            "field_name": "city",
+            "field_schema": "keyword",
+        }
+    ).raise_for_status()
+
+    # Query succeeds with the index, and reports the value each point is drawn for
+    query_ok = query_request()
+    assert query_ok.ok
+    points = query_ok.json()['result']['points']
+    strata = [point['stratum'] for point in points]
+    assert len(strata) == len(set(strata))
+    for point in points:
+        city = point['payload']['city']
+        assert point['stratum'] in (city if isinstance(city, list) else [city])
+
+
 def test_strict_mode_read_rate_limiting_small_replenish(collection_name):
     """
     If our read rate limit capacity is larger, test that when exhausting it